@@ -119,5 +119,48 @@ delete from block_range_root;
 vacuum;
  "#,
         ),
+        // Migration 10
+        // Add `cardano_block` and `cardano_block_range_root` tables.
+        // The stored transactions are kept, the blocks are rebuilt by the importers that scan
+        // again the chain up to the highest stored transaction.
+        SqlMigration::new(
+            10,
+            r#"
+create table cardano_block (
+    block_hash      text      not null,
+    block_number    integer   not null,
+    slot_number     integer   not null,
+    primary key (block_hash)
+);
+create index cardano_block_block_number_index on cardano_block(block_number);
+create index cardano_block_slot_number_index on cardano_block(slot_number);
+
+create table cardano_block_range_root (
+    start         integer   not null,
+    end           integer   not null,
+    merkle_root   text      not null,
+    primary key (start, end)
+);
+"#,
+        ),
+        // Migration 11
+        // Add `cardano_tx_index` table that indexes the transactions by output address and by
+        // metadata label.
+        // The stored transactions are kept, the index is filled by the aggregator importer that
+        // scans again the chain when the transactions filters extraction is enabled.
+        SqlMigration::new(
+            11,
+            r#"
+create table cardano_tx_index (
+    filter_kind         text      not null,
+    filter_value        text      not null,
+    transaction_hash    text      not null,
+    block_number        integer   not null,
+    slot_number         integer   not null,
+    primary key (filter_kind, filter_value, transaction_hash)
+);
+create index cardano_tx_index_block_number_index on cardano_tx_index(block_number);
+"#,
+        ),
     ]
 }
@@ -71,19 +71,8 @@ impl Hydrator {
                 SignedEntityType::CardanoImmutableFilesFull(beacon)
             }
             SignedEntityTypeDiscriminants::CardanoTransactions => {
-                #[derive(Deserialize)]
-                struct CardanoTransactionsBeacon {
-                    epoch: Epoch,
-                    block_number: BlockNumber,
-                }
-
-                let beacon: CardanoTransactionsBeacon =
-                    serde_json::from_str(beacon_str).map_err(|e| {
-                        HydrationError::InvalidData(format!(
-                        "Invalid Beacon JSON in open_message.beacon: '{beacon_str}'. Error: {e}"
-                    ))
-                    })?;
-                SignedEntityType::CardanoTransactions(beacon.epoch, beacon.block_number)
+                let (epoch, block_number) = Self::read_epoch_and_block_number_beacon(beacon_str)?;
+                SignedEntityType::CardanoTransactions(epoch, block_number)
             }
             SignedEntityTypeDiscriminants::CardanoDatabase => {
                 let beacon: CardanoDbBeacon = serde_json::from_str(beacon_str).map_err(|e| {
@@ -93,10 +82,40 @@ impl Hydrator {
                 })?;
                 SignedEntityType::CardanoDatabase(beacon)
             }
+            SignedEntityTypeDiscriminants::CardanoBlocks => {
+                let (epoch, block_number) = Self::read_epoch_and_block_number_beacon(beacon_str)?;
+                SignedEntityType::CardanoBlocks(epoch, block_number)
+            }
+            SignedEntityTypeDiscriminants::CardanoProtocolParameters => {
+                let epoch: Epoch = serde_json::from_str(beacon_str).map_err(|e| {
+                    HydrationError::InvalidData(format!(
+                        "Invalid Epoch JSON representation '{beacon_str}. Error: {e}'."
+                    ))
+                })?;
+                SignedEntityType::CardanoProtocolParameters(epoch)
+            }
         };
 
         Ok(signed_entity)
     }
+
+    fn read_epoch_and_block_number_beacon(
+        beacon_str: &str,
+    ) -> Result<(Epoch, BlockNumber), HydrationError> {
+        #[derive(Deserialize)]
+        struct EpochAndBlockNumberBeacon {
+            epoch: Epoch,
+            block_number: BlockNumber,
+        }
+
+        let beacon: EpochAndBlockNumberBeacon = serde_json::from_str(beacon_str).map_err(|e| {
+            HydrationError::InvalidData(format!(
+                "Invalid Beacon JSON in open_message.beacon: '{beacon_str}'. Error: {e}"
+            ))
+        })?;
+
+        Ok((beacon.epoch, beacon.block_number))
+    }
 }
 
 #[cfg(test)]
@@ -126,4 +145,28 @@ mod tests {
 
         assert_eq!(expected, signed_entity);
     }
+
+    #[test]
+    fn hydrate_cardano_blocks_signed_entity_type() {
+        let expected = SignedEntityType::CardanoBlocks(Epoch(35), BlockNumber(77));
+        let signed_entity = Hydrator::hydrate_signed_entity_type(
+            SignedEntityTypeDiscriminants::CardanoBlocks.index(),
+            &expected.get_json_beacon().unwrap(),
+        )
+        .unwrap();
+
+        assert_eq!(expected, signed_entity);
+    }
+
+    #[test]
+    fn hydrate_cardano_protocol_parameters_signed_entity_type() {
+        let expected = SignedEntityType::CardanoProtocolParameters(Epoch(35));
+        let signed_entity = Hydrator::hydrate_signed_entity_type(
+            SignedEntityTypeDiscriminants::CardanoProtocolParameters.index(),
+            &expected.get_json_beacon().unwrap(),
+        )
+        .unwrap();
+
+        assert_eq!(expected, signed_entity);
+    }
 }
@@ -0,0 +1,23 @@
+//! Migration module for the immutable file digests cache store
+//!
+use crate::database::SqlMigration;
+
+/// Get all the migrations required by this version of the software.
+/// There shall be one migration per database version. There could be several
+/// statements per migration.
+pub fn get_migrations() -> Vec<SqlMigration> {
+    vec![
+        // Migration 1
+        // Add the `immutable_file_digest` table.
+        SqlMigration::new(
+            1,
+            r#"
+create table immutable_file_digest (
+    immutable_file_name     text    not null,
+    digest                  text    not null,
+    primary key (immutable_file_name)
+);
+"#,
+        ),
+    ]
+}
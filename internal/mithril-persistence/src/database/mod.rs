@@ -4,6 +4,7 @@
 pub mod cardano_transaction_migration;
 mod db_version;
 mod hydrator;
+pub mod immutable_file_digest_cache_migration;
 pub(crate) mod query;
 pub mod record;
 pub mod repository;
@@ -32,4 +33,14 @@ pub(crate) mod test_helper {
             .build()?;
         Ok(connection)
     }
+
+    /// In-memory sqlite database with immutable file digests cache migrations applied
+    pub fn immutable_file_digest_cache_db_connection() -> StdResult<ConnectionThreadSafe> {
+        let connection = ConnectionBuilder::open_memory()
+            .with_migrations(
+                crate::database::immutable_file_digest_cache_migration::get_migrations(),
+            )
+            .build()?;
+        Ok(connection)
+    }
 }
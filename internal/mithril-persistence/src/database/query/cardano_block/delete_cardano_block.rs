@@ -0,0 +1,104 @@
+use anyhow::Context;
+use sqlite::Value;
+
+use mithril_common::entities::BlockNumber;
+use mithril_common::StdResult;
+
+use crate::database::record::CardanoBlockRecord;
+use crate::sqlite::{Query, SourceAlias, SqLiteEntity, WhereCondition};
+
+/// Query to delete old [CardanoBlockRecord] from the sqlite database
+pub struct DeleteCardanoBlockQuery {
+    condition: WhereCondition,
+}
+
+impl Query for DeleteCardanoBlockQuery {
+    type Entity = CardanoBlockRecord;
+
+    fn filters(&self) -> WhereCondition {
+        self.condition.clone()
+    }
+
+    fn get_definition(&self, condition: &str) -> String {
+        // it is important to alias the fields with the same name as the table
+        // since the table cannot be aliased in a RETURNING statement in SQLite.
+        let projection = Self::Entity::get_projection()
+            .expand(SourceAlias::new(&[("{:cardano_block:}", "cardano_block")]));
+
+        format!("delete from cardano_block where {condition} returning {projection}")
+    }
+}
+
+impl DeleteCardanoBlockQuery {
+    pub fn below_block_number_threshold(block_number_threshold: BlockNumber) -> StdResult<Self> {
+        let threshold = Value::Integer(block_number_threshold.try_into().with_context(|| {
+            format!("Failed to convert threshold `{block_number_threshold}` to i64")
+        })?);
+
+        Ok(Self {
+            condition: WhereCondition::new("block_number < ?*", vec![threshold]),
+        })
+    }
+
+    pub fn above_block_number_threshold(block_number_threshold: BlockNumber) -> StdResult<Self> {
+        let threshold = Value::Integer(block_number_threshold.try_into().with_context(|| {
+            format!("Failed to convert threshold `{block_number_threshold}` to i64")
+        })?);
+
+        Ok(Self {
+            condition: WhereCondition::new("block_number > ?*", vec![threshold]),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use mithril_common::entities::SlotNumber;
+
+    use crate::database::query::cardano_block::test_helper::{block_record, insert_blocks};
+    use crate::database::query::GetCardanoBlockQuery;
+    use crate::database::test_helper::cardano_tx_db_connection;
+    use crate::sqlite::ConnectionExtensions;
+
+    use super::*;
+
+    fn blocks_dataset() -> Vec<CardanoBlockRecord> {
+        vec![
+            block_record(BlockNumber(10), SlotNumber(50)),
+            block_record(BlockNumber(11), SlotNumber(51)),
+            block_record(BlockNumber(12), SlotNumber(52)),
+        ]
+    }
+
+    #[test]
+    fn test_delete_blocks_below_block_number_threshold() {
+        let connection = cardano_tx_db_connection().unwrap();
+        insert_blocks(&connection, blocks_dataset());
+
+        let cursor = connection
+            .fetch(DeleteCardanoBlockQuery::below_block_number_threshold(BlockNumber(11)).unwrap())
+            .unwrap();
+        assert_eq!(1, cursor.count());
+
+        let records: Vec<CardanoBlockRecord> = connection
+            .fetch_collect(GetCardanoBlockQuery::all())
+            .unwrap();
+        assert_eq!(blocks_dataset()[1..].to_vec(), records);
+    }
+
+    #[test]
+    fn test_delete_blocks_above_block_number_threshold() {
+        let connection = cardano_tx_db_connection().unwrap();
+        insert_blocks(&connection, blocks_dataset());
+
+        let cursor = connection
+            .fetch(DeleteCardanoBlockQuery::above_block_number_threshold(BlockNumber(10)).unwrap())
+            .unwrap();
+        assert_eq!(2, cursor.count());
+
+        let records: Vec<CardanoBlockRecord> = connection
+            .fetch_collect(GetCardanoBlockQuery::all())
+            .unwrap();
+        assert_eq!(blocks_dataset()[..1].to_vec(), records);
+    }
+}
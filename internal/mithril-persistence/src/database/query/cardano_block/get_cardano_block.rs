@@ -0,0 +1,249 @@
+use std::ops::Range;
+
+use sqlite::Value;
+
+use mithril_common::entities::{BlockHash, BlockNumber, BlockRange, SlotNumber};
+
+use crate::database::record::CardanoBlockRecord;
+use crate::sqlite::{Query, SourceAlias, SqLiteEntity, WhereCondition};
+
+/// Simple queries to retrieve [CardanoBlockRecord] from the sqlite database.
+pub struct GetCardanoBlockQuery {
+    condition: WhereCondition,
+}
+
+impl GetCardanoBlockQuery {
+    pub fn all() -> Self {
+        Self {
+            condition: WhereCondition::default(),
+        }
+    }
+
+    pub fn by_block_hashes(blocks_hashes: Vec<BlockHash>, up_to_or_equal: BlockNumber) -> Self {
+        let hashes_values = blocks_hashes.into_iter().map(Value::String).collect();
+        let condition =
+            WhereCondition::where_in("block_hash", hashes_values).and_where(WhereCondition::new(
+                "block_number <= ?*",
+                vec![Value::Integer(*up_to_or_equal as i64)],
+            ));
+
+        Self { condition }
+    }
+
+    pub fn by_block_ranges(block_ranges: Vec<BlockRange>) -> Self {
+        let mut condition = WhereCondition::default();
+        for block_range in block_ranges {
+            condition = condition.or_where(WhereCondition::new(
+                "(block_number >= ?* and block_number < ?*)",
+                vec![
+                    Value::Integer(*block_range.start as i64),
+                    Value::Integer(*block_range.end as i64),
+                ],
+            ))
+        }
+
+        Self { condition }
+    }
+
+    pub fn between_blocks(range: Range<BlockNumber>) -> Self {
+        let condition = WhereCondition::new(
+            "block_number >= ?*",
+            vec![Value::Integer(*range.start as i64)],
+        )
+        .and_where(WhereCondition::new(
+            "block_number < ?*",
+            vec![Value::Integer(*range.end as i64)],
+        ));
+
+        Self { condition }
+    }
+
+    pub fn with_highest_block_number_below_slot_number(slot_number: SlotNumber) -> Self {
+        Self {
+            condition: WhereCondition::new(
+                "block_number = (select max(block_number) from cardano_block where slot_number <= ?*)",
+                vec![Value::Integer(*slot_number as i64)],
+            ),
+        }
+    }
+
+    pub fn with_highest_block_number() -> Self {
+        Self {
+            condition: WhereCondition::new(
+                "block_number = (select max(block_number) from cardano_block)",
+                vec![],
+            ),
+        }
+    }
+
+    pub fn with_highest_indexed_block_number() -> Self {
+        Self {
+            condition: WhereCondition::new(
+                "block_number = (select max(block_number) from cardano_tx_index)",
+                vec![],
+            ),
+        }
+    }
+}
+
+impl Query for GetCardanoBlockQuery {
+    type Entity = CardanoBlockRecord;
+
+    fn filters(&self) -> WhereCondition {
+        self.condition.clone()
+    }
+
+    fn get_definition(&self, condition: &str) -> String {
+        let aliases = SourceAlias::new(&[("{:cardano_block:}", "cardano_block")]);
+        let projection = Self::Entity::get_projection().expand(aliases);
+
+        format!("select {projection} from cardano_block where {condition} order by block_number, block_hash")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use mithril_common::entities::CardanoTransactionFilter;
+
+    use crate::database::query::cardano_block::test_helper::{block_record, insert_blocks};
+    use crate::database::query::InsertCardanoTransactionIndexQuery;
+    use crate::database::record::CardanoTransactionIndexRecord;
+    use crate::database::test_helper::cardano_tx_db_connection;
+    use crate::sqlite::ConnectionExtensions;
+
+    use super::*;
+
+    #[test]
+    fn by_block_hashes_only_returns_blocks_up_to_the_given_block_number() {
+        let connection = cardano_tx_db_connection().unwrap();
+        insert_blocks(
+            &connection,
+            vec![
+                block_record(BlockNumber(10), SlotNumber(50)),
+                block_record(BlockNumber(11), SlotNumber(51)),
+                block_record(BlockNumber(12), SlotNumber(52)),
+            ],
+        );
+
+        let records: Vec<CardanoBlockRecord> = connection
+            .fetch_collect(GetCardanoBlockQuery::by_block_hashes(
+                vec![
+                    "block-hash-10".to_string(),
+                    "block-hash-12".to_string(),
+                    "block-hash-99".to_string(),
+                ],
+                BlockNumber(11),
+            ))
+            .unwrap();
+
+        assert_eq!(vec![block_record(BlockNumber(10), SlotNumber(50))], records);
+    }
+
+    #[test]
+    fn by_block_ranges_returns_all_blocks_in_the_given_ranges() {
+        let connection = cardano_tx_db_connection().unwrap();
+        let blocks = vec![
+            block_record(BlockNumber(10), SlotNumber(50)),
+            block_record(BlockRange::LENGTH + 1, SlotNumber(150)),
+            block_record(BlockRange::LENGTH * 2 + 1, SlotNumber(250)),
+        ];
+        insert_blocks(&connection, blocks.clone());
+
+        let records: Vec<CardanoBlockRecord> = connection
+            .fetch_collect(GetCardanoBlockQuery::by_block_ranges(vec![
+                BlockRange::from_block_number(BlockNumber(0)),
+                BlockRange::from_block_number(BlockRange::LENGTH * 2),
+            ]))
+            .unwrap();
+
+        assert_eq!(vec![blocks[0].clone(), blocks[2].clone()], records);
+    }
+
+    #[test]
+    fn with_highest_block_number_below_slot_number() {
+        let connection = cardano_tx_db_connection().unwrap();
+        insert_blocks(
+            &connection,
+            vec![
+                block_record(BlockNumber(10), SlotNumber(50)),
+                block_record(BlockNumber(11), SlotNumber(55)),
+                block_record(BlockNumber(12), SlotNumber(60)),
+            ],
+        );
+
+        let record: Option<CardanoBlockRecord> = connection
+            .fetch_first(
+                GetCardanoBlockQuery::with_highest_block_number_below_slot_number(SlotNumber(58)),
+            )
+            .unwrap();
+
+        assert_eq!(Some(block_record(BlockNumber(11), SlotNumber(55))), record);
+    }
+
+    #[test]
+    fn with_highest_block_number() {
+        let connection = cardano_tx_db_connection().unwrap();
+
+        let record: Option<CardanoBlockRecord> = connection
+            .fetch_first(GetCardanoBlockQuery::with_highest_block_number())
+            .unwrap();
+        assert_eq!(None, record);
+
+        insert_blocks(
+            &connection,
+            vec![
+                block_record(BlockNumber(10), SlotNumber(50)),
+                block_record(BlockNumber(12), SlotNumber(60)),
+                block_record(BlockNumber(11), SlotNumber(55)),
+            ],
+        );
+
+        let record: Option<CardanoBlockRecord> = connection
+            .fetch_first(GetCardanoBlockQuery::with_highest_block_number())
+            .unwrap();
+        assert_eq!(Some(block_record(BlockNumber(12), SlotNumber(60))), record);
+    }
+
+    #[test]
+    fn with_highest_indexed_block_number() {
+        let connection = cardano_tx_db_connection().unwrap();
+        insert_blocks(
+            &connection,
+            vec![
+                block_record(BlockNumber(10), SlotNumber(50)),
+                block_record(BlockNumber(11), SlotNumber(55)),
+                block_record(BlockNumber(12), SlotNumber(60)),
+            ],
+        );
+
+        let record: Option<CardanoBlockRecord> = connection
+            .fetch_first(GetCardanoBlockQuery::with_highest_indexed_block_number())
+            .unwrap();
+        assert_eq!(None, record);
+
+        connection
+            .fetch_first(
+                InsertCardanoTransactionIndexQuery::insert_many(vec![
+                    CardanoTransactionIndexRecord::new(
+                        CardanoTransactionFilter::MetadataLabel(674),
+                        "tx-hash-10",
+                        BlockNumber(10),
+                        SlotNumber(50),
+                    ),
+                    CardanoTransactionIndexRecord::new(
+                        CardanoTransactionFilter::MetadataLabel(674),
+                        "tx-hash-11",
+                        BlockNumber(11),
+                        SlotNumber(55),
+                    ),
+                ])
+                .unwrap(),
+            )
+            .unwrap();
+
+        let record: Option<CardanoBlockRecord> = connection
+            .fetch_first(GetCardanoBlockQuery::with_highest_indexed_block_number())
+            .unwrap();
+        assert_eq!(Some(block_record(BlockNumber(11), SlotNumber(55))), record);
+    }
+}
@@ -0,0 +1,86 @@
+use std::iter::repeat_n;
+
+use sqlite::Value;
+
+use mithril_common::StdResult;
+
+use crate::database::record::CardanoBlockRecord;
+use crate::sqlite::{Query, SourceAlias, SqLiteEntity, WhereCondition};
+
+/// Query to insert [CardanoBlockRecord] in the sqlite database
+pub struct InsertCardanoBlockQuery {
+    condition: WhereCondition,
+}
+
+impl InsertCardanoBlockQuery {
+    /// Query that insert multiples records.
+    pub fn insert_many(blocks_records: Vec<CardanoBlockRecord>) -> StdResult<Self> {
+        let columns = "(block_hash, block_number, slot_number)";
+        let values_columns: Vec<&str> = repeat_n("(?*, ?*, ?*)", blocks_records.len()).collect();
+
+        let values: StdResult<Vec<Value>> =
+            blocks_records
+                .into_iter()
+                .try_fold(vec![], |mut vec, record| {
+                    vec.append(&mut vec![
+                        Value::String(record.block_hash),
+                        Value::Integer(record.block_number.try_into()?),
+                        Value::Integer(record.slot_number.try_into()?),
+                    ]);
+                    Ok(vec)
+                });
+        let condition = WhereCondition::new(
+            format!("{columns} values {}", values_columns.join(", ")).as_str(),
+            values?,
+        );
+
+        Ok(Self { condition })
+    }
+}
+
+impl Query for InsertCardanoBlockQuery {
+    type Entity = CardanoBlockRecord;
+
+    fn filters(&self) -> WhereCondition {
+        self.condition.clone()
+    }
+
+    fn get_definition(&self, condition: &str) -> String {
+        let aliases = SourceAlias::new(&[("{:cardano_block:}", "cardano_block")]);
+        let projection = Self::Entity::get_projection().expand(aliases);
+
+        format!("insert or ignore into cardano_block {condition} returning {projection}")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use mithril_common::entities::{BlockNumber, SlotNumber};
+
+    use crate::database::query::GetCardanoBlockQuery;
+    use crate::database::test_helper::cardano_tx_db_connection;
+    use crate::sqlite::ConnectionExtensions;
+
+    use super::*;
+
+    #[test]
+    fn test_insert_blocks_ignore_already_stored_blocks() {
+        let connection = cardano_tx_db_connection().unwrap();
+        let block = CardanoBlockRecord::new("block-hash-10", BlockNumber(10), SlotNumber(50));
+
+        let inserted: Vec<CardanoBlockRecord> = connection
+            .fetch_collect(InsertCardanoBlockQuery::insert_many(vec![block.clone()]).unwrap())
+            .unwrap();
+        assert_eq!(vec![block.clone()], inserted);
+
+        let inserted: Vec<CardanoBlockRecord> = connection
+            .fetch_collect(InsertCardanoBlockQuery::insert_many(vec![block.clone()]).unwrap())
+            .unwrap();
+        assert!(inserted.is_empty());
+
+        let stored: Vec<CardanoBlockRecord> = connection
+            .fetch_collect(GetCardanoBlockQuery::all())
+            .unwrap();
+        assert_eq!(vec![block], stored);
+    }
+}
@@ -0,0 +1,92 @@
+use anyhow::Context;
+use sqlite::Value;
+
+use mithril_common::entities::{BlockNumber, BlockRange};
+use mithril_common::StdResult;
+
+use crate::database::record::BlockRangeRootRecord;
+use crate::sqlite::{Query, SourceAlias, SqLiteEntity, WhereCondition};
+
+/// Query to delete old blocks [BlockRangeRootRecord] from the sqlite database
+pub struct DeleteCardanoBlockRangeRootQuery {
+    condition: WhereCondition,
+}
+
+impl Query for DeleteCardanoBlockRangeRootQuery {
+    type Entity = BlockRangeRootRecord;
+
+    fn filters(&self) -> WhereCondition {
+        self.condition.clone()
+    }
+
+    fn get_definition(&self, condition: &str) -> String {
+        // it is important to alias the fields with the same name as the table
+        // since the table cannot be aliased in a RETURNING statement in SQLite.
+        let aliases = SourceAlias::new(&[("{:block_range_root:}", "cardano_block_range_root")]);
+        let projection = Self::Entity::get_projection().expand(aliases);
+
+        format!("delete from cardano_block_range_root where {condition} returning {projection}")
+    }
+}
+
+impl DeleteCardanoBlockRangeRootQuery {
+    pub fn contains_or_above_block_number_threshold(
+        block_number_threshold: BlockNumber,
+    ) -> StdResult<Self> {
+        let block_range = BlockRange::from_block_number(block_number_threshold);
+        let threshold = Value::Integer(block_range.start.try_into().with_context(|| {
+            format!("Failed to convert threshold `{block_number_threshold}` to i64")
+        })?);
+
+        Ok(Self {
+            condition: WhereCondition::new("start >= ?*", vec![threshold]),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use mithril_common::crypto_helper::MKTreeNode;
+
+    use crate::database::query::{GetCardanoBlockRangeRootQuery, InsertCardanoBlockRangeRootQuery};
+    use crate::database::test_helper::cardano_tx_db_connection;
+    use crate::sqlite::ConnectionExtensions;
+
+    use super::*;
+
+    #[test]
+    fn test_delete_block_range_roots_contains_or_above_block_number_threshold() {
+        let connection = cardano_tx_db_connection().unwrap();
+        let dataset: Vec<BlockRangeRootRecord> = [
+            (
+                BlockRange::from_block_number(BlockRange::LENGTH),
+                MKTreeNode::from_hex("AAAA").unwrap(),
+            ),
+            (
+                BlockRange::from_block_number(BlockRange::LENGTH * 2),
+                MKTreeNode::from_hex("BBBB").unwrap(),
+            ),
+        ]
+        .into_iter()
+        .map(BlockRangeRootRecord::from)
+        .collect();
+        connection
+            .fetch_first(InsertCardanoBlockRangeRootQuery::insert_many(dataset.clone()).unwrap())
+            .unwrap();
+
+        let cursor = connection
+            .fetch(
+                DeleteCardanoBlockRangeRootQuery::contains_or_above_block_number_threshold(
+                    BlockRange::LENGTH * 2 + 1,
+                )
+                .unwrap(),
+            )
+            .unwrap();
+        assert_eq!(1, cursor.count());
+
+        let records: Vec<BlockRangeRootRecord> = connection
+            .fetch_collect(GetCardanoBlockRangeRootQuery::all())
+            .unwrap();
+        assert_eq!(dataset[..1].to_vec(), records);
+    }
+}
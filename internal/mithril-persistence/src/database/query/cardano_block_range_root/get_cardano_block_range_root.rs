@@ -0,0 +1,127 @@
+use sqlite::Value;
+
+use mithril_common::entities::BlockNumber;
+
+use crate::database::record::BlockRangeRootRecord;
+use crate::sqlite::{Query, SourceAlias, SqLiteEntity, WhereCondition};
+
+/// Simple queries to retrieve the blocks [BlockRangeRootRecord] from the sqlite database.
+pub struct GetCardanoBlockRangeRootQuery {
+    condition: WhereCondition,
+}
+
+impl GetCardanoBlockRangeRootQuery {
+    pub fn all() -> Self {
+        Self {
+            condition: WhereCondition::default(),
+        }
+    }
+
+    pub fn contains_or_below_block_number(block_number: BlockNumber) -> Self {
+        Self {
+            condition: WhereCondition::new(
+                "start < ?*",
+                vec![Value::Integer(*block_number as i64)],
+            ),
+        }
+    }
+
+    pub fn highest() -> Self {
+        Self {
+            condition: WhereCondition::new(
+                "end = (select max(end) from cardano_block_range_root)",
+                vec![],
+            ),
+        }
+    }
+}
+
+impl Query for GetCardanoBlockRangeRootQuery {
+    type Entity = BlockRangeRootRecord;
+
+    fn filters(&self) -> WhereCondition {
+        self.condition.clone()
+    }
+
+    fn get_definition(&self, condition: &str) -> String {
+        let aliases = SourceAlias::new(&[("{:block_range_root:}", "cardano_block_range_root")]);
+        let projection = Self::Entity::get_projection().expand(aliases);
+
+        format!(
+            "select {projection} from cardano_block_range_root where {condition} order by start, end"
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use mithril_common::crypto_helper::MKTreeNode;
+    use mithril_common::entities::BlockRange;
+
+    use crate::database::query::InsertCardanoBlockRangeRootQuery;
+    use crate::database::test_helper::cardano_tx_db_connection;
+    use crate::sqlite::ConnectionExtensions;
+
+    use super::*;
+
+    #[test]
+    fn test_get_contains_or_below_block_number() {
+        let connection = cardano_tx_db_connection().unwrap();
+        let dataset: Vec<BlockRangeRootRecord> = [
+            (
+                BlockRange::from_block_number(BlockNumber(15)),
+                MKTreeNode::from_hex("AAAA").unwrap(),
+            ),
+            (
+                BlockRange::from_block_number(BlockRange::LENGTH + 15),
+                MKTreeNode::from_hex("BBBB").unwrap(),
+            ),
+        ]
+        .into_iter()
+        .map(BlockRangeRootRecord::from)
+        .collect();
+        connection
+            .fetch_first(InsertCardanoBlockRangeRootQuery::insert_many(dataset.clone()).unwrap())
+            .unwrap();
+
+        let records: Vec<BlockRangeRootRecord> = connection
+            .fetch_collect(
+                GetCardanoBlockRangeRootQuery::contains_or_below_block_number(BlockRange::LENGTH),
+            )
+            .unwrap();
+
+        assert_eq!(dataset[..1].to_vec(), records);
+    }
+
+    #[test]
+    fn test_get_highest() {
+        let connection = cardano_tx_db_connection().unwrap();
+
+        let record: Option<BlockRangeRootRecord> = connection
+            .fetch_first(GetCardanoBlockRangeRootQuery::highest())
+            .unwrap();
+        assert_eq!(None, record);
+
+        let dataset: Vec<BlockRangeRootRecord> = [
+            (
+                BlockRange::from_block_number(BlockNumber(15)),
+                MKTreeNode::from_hex("AAAA").unwrap(),
+            ),
+            (
+                BlockRange::from_block_number(BlockRange::LENGTH + 15),
+                MKTreeNode::from_hex("BBBB").unwrap(),
+            ),
+        ]
+        .into_iter()
+        .map(BlockRangeRootRecord::from)
+        .collect();
+        connection
+            .fetch_first(InsertCardanoBlockRangeRootQuery::insert_many(dataset.clone()).unwrap())
+            .unwrap();
+
+        let record: Option<BlockRangeRootRecord> = connection
+            .fetch_first(GetCardanoBlockRangeRootQuery::highest())
+            .unwrap();
+        assert_eq!(dataset.last().cloned(), record);
+    }
+}
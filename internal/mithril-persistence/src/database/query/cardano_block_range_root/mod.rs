@@ -0,0 +1,7 @@
+mod delete_cardano_block_range_root;
+mod get_cardano_block_range_root;
+mod insert_cardano_block_range_root;
+
+pub use delete_cardano_block_range_root::*;
+pub use get_cardano_block_range_root::*;
+pub use insert_cardano_block_range_root::*;
@@ -0,0 +1,114 @@
+use anyhow::Context;
+use sqlite::Value;
+
+use mithril_common::entities::BlockNumber;
+use mithril_common::StdResult;
+
+use crate::database::record::CardanoTransactionIndexRecord;
+use crate::sqlite::{Query, SourceAlias, SqLiteEntity, WhereCondition};
+
+/// Query to delete old [CardanoTransactionIndexRecord] from the sqlite database
+pub struct DeleteCardanoTransactionIndexQuery {
+    condition: WhereCondition,
+}
+
+impl Query for DeleteCardanoTransactionIndexQuery {
+    type Entity = CardanoTransactionIndexRecord;
+
+    fn filters(&self) -> WhereCondition {
+        self.condition.clone()
+    }
+
+    fn get_definition(&self, condition: &str) -> String {
+        // it is important to alias the fields with the same name as the table
+        // since the table cannot be aliased in a RETURNING statement in SQLite.
+        let projection = Self::Entity::get_projection().expand(SourceAlias::new(&[(
+            "{:cardano_tx_index:}",
+            "cardano_tx_index",
+        )]));
+
+        format!("delete from cardano_tx_index where {condition} returning {projection}")
+    }
+}
+
+impl DeleteCardanoTransactionIndexQuery {
+    pub fn below_block_number_threshold(block_number_threshold: BlockNumber) -> StdResult<Self> {
+        let threshold = Value::Integer(block_number_threshold.try_into().with_context(|| {
+            format!("Failed to convert threshold `{block_number_threshold}` to i64")
+        })?);
+
+        Ok(Self {
+            condition: WhereCondition::new("block_number < ?*", vec![threshold]),
+        })
+    }
+
+    pub fn above_block_number_threshold(block_number_threshold: BlockNumber) -> StdResult<Self> {
+        let threshold = Value::Integer(block_number_threshold.try_into().with_context(|| {
+            format!("Failed to convert threshold `{block_number_threshold}` to i64")
+        })?);
+
+        Ok(Self {
+            condition: WhereCondition::new("block_number > ?*", vec![threshold]),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use mithril_common::entities::SlotNumber;
+
+    use crate::database::query::cardano_transaction_index::test_helper::{
+        address_record, insert_index_entries,
+    };
+    use crate::database::query::GetCardanoTransactionIndexQuery;
+    use crate::database::test_helper::cardano_tx_db_connection;
+    use crate::sqlite::ConnectionExtensions;
+
+    use super::*;
+
+    fn index_dataset() -> Vec<CardanoTransactionIndexRecord> {
+        vec![
+            address_record("addr-1", BlockNumber(10), SlotNumber(50)),
+            address_record("addr-1", BlockNumber(11), SlotNumber(51)),
+            address_record("addr-1", BlockNumber(12), SlotNumber(52)),
+        ]
+    }
+
+    #[test]
+    fn test_delete_index_entries_below_block_number_threshold() {
+        let connection = cardano_tx_db_connection().unwrap();
+        insert_index_entries(&connection, index_dataset());
+
+        let cursor = connection
+            .fetch(
+                DeleteCardanoTransactionIndexQuery::below_block_number_threshold(BlockNumber(11))
+                    .unwrap(),
+            )
+            .unwrap();
+        assert_eq!(1, cursor.count());
+
+        let records: Vec<CardanoTransactionIndexRecord> = connection
+            .fetch_collect(GetCardanoTransactionIndexQuery::all())
+            .unwrap();
+        assert_eq!(index_dataset()[1..].to_vec(), records);
+    }
+
+    #[test]
+    fn test_delete_index_entries_above_block_number_threshold() {
+        let connection = cardano_tx_db_connection().unwrap();
+        insert_index_entries(&connection, index_dataset());
+
+        let cursor = connection
+            .fetch(
+                DeleteCardanoTransactionIndexQuery::above_block_number_threshold(BlockNumber(10))
+                    .unwrap(),
+            )
+            .unwrap();
+        assert_eq!(2, cursor.count());
+
+        let records: Vec<CardanoTransactionIndexRecord> = connection
+            .fetch_collect(GetCardanoTransactionIndexQuery::all())
+            .unwrap();
+        assert_eq!(index_dataset()[..1].to_vec(), records);
+    }
+}
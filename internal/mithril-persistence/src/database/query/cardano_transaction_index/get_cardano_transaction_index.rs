@@ -0,0 +1,114 @@
+use sqlite::Value;
+
+use mithril_common::entities::{BlockRange, CardanoTransactionFilter};
+
+use crate::database::record::CardanoTransactionIndexRecord;
+use crate::sqlite::{Query, SourceAlias, SqLiteEntity, WhereCondition};
+
+/// Simple queries to retrieve [CardanoTransactionIndexRecord] from the sqlite database.
+pub struct GetCardanoTransactionIndexQuery {
+    condition: WhereCondition,
+}
+
+impl GetCardanoTransactionIndexQuery {
+    pub fn all() -> Self {
+        Self {
+            condition: WhereCondition::default(),
+        }
+    }
+
+    /// Query the index entries matching the given filter in the given block ranges.
+    ///
+    /// Note: the block ranges must not be empty, otherwise the entries of all blocks are returned.
+    pub fn by_filter_in_block_ranges(
+        filter: &CardanoTransactionFilter,
+        block_ranges: Vec<BlockRange>,
+    ) -> Self {
+        let mut block_ranges_condition = WhereCondition::default();
+        for block_range in block_ranges {
+            block_ranges_condition = block_ranges_condition.or_where(WhereCondition::new(
+                "(block_number >= ?* and block_number < ?*)",
+                vec![
+                    Value::Integer(*block_range.start as i64),
+                    Value::Integer(*block_range.end as i64),
+                ],
+            ))
+        }
+        let condition = WhereCondition::new(
+            "filter_kind = ?*",
+            vec![Value::String(filter.kind().to_string())],
+        )
+        .and_where(WhereCondition::new(
+            "filter_value = ?*",
+            vec![Value::String(filter.value())],
+        ))
+        .and_where(block_ranges_condition);
+
+        Self { condition }
+    }
+}
+
+impl Query for GetCardanoTransactionIndexQuery {
+    type Entity = CardanoTransactionIndexRecord;
+
+    fn filters(&self) -> WhereCondition {
+        self.condition.clone()
+    }
+
+    fn get_definition(&self, condition: &str) -> String {
+        let aliases = SourceAlias::new(&[("{:cardano_tx_index:}", "cardano_tx_index")]);
+        let projection = Self::Entity::get_projection().expand(aliases);
+
+        format!("select {projection} from cardano_tx_index where {condition} order by block_number, transaction_hash")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use mithril_common::entities::{BlockNumber, SlotNumber};
+
+    use crate::database::query::cardano_transaction_index::test_helper::{
+        address_record, insert_index_entries,
+    };
+    use crate::database::test_helper::cardano_tx_db_connection;
+    use crate::sqlite::ConnectionExtensions;
+
+    use super::*;
+
+    #[test]
+    fn by_filter_in_block_ranges_only_returns_entries_of_the_filter_in_the_given_ranges() {
+        let connection = cardano_tx_db_connection().unwrap();
+        let records = vec![
+            address_record("addr-1", BlockNumber(10), SlotNumber(50)),
+            address_record("addr-2", BlockNumber(11), SlotNumber(51)),
+            address_record("addr-1", BlockRange::LENGTH + 1, SlotNumber(150)),
+            address_record("addr-1", BlockRange::LENGTH * 2 + 1, SlotNumber(250)),
+            CardanoTransactionIndexRecord::new(
+                CardanoTransactionFilter::MetadataLabel(674),
+                "tx-hash-12",
+                BlockNumber(12),
+                SlotNumber(52),
+            ),
+        ];
+        insert_index_entries(&connection, records.clone());
+
+        let found: Vec<CardanoTransactionIndexRecord> = connection
+            .fetch_collect(GetCardanoTransactionIndexQuery::by_filter_in_block_ranges(
+                &CardanoTransactionFilter::Address("addr-1".to_string()),
+                vec![
+                    BlockRange::from_block_number(BlockNumber(0)),
+                    BlockRange::from_block_number(BlockRange::LENGTH * 2),
+                ],
+            ))
+            .unwrap();
+        assert_eq!(vec![records[0].clone(), records[3].clone()], found);
+
+        let found: Vec<CardanoTransactionIndexRecord> = connection
+            .fetch_collect(GetCardanoTransactionIndexQuery::by_filter_in_block_ranges(
+                &CardanoTransactionFilter::MetadataLabel(674),
+                vec![BlockRange::from_block_number(BlockNumber(0))],
+            ))
+            .unwrap();
+        assert_eq!(vec![records[4].clone()], found);
+    }
+}
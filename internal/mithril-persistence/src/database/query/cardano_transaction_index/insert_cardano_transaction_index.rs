@@ -0,0 +1,106 @@
+use std::iter::repeat_n;
+
+use sqlite::Value;
+
+use mithril_common::StdResult;
+
+use crate::database::record::CardanoTransactionIndexRecord;
+use crate::sqlite::{Query, SourceAlias, SqLiteEntity, WhereCondition};
+
+/// Query to insert [CardanoTransactionIndexRecord] in the sqlite database
+pub struct InsertCardanoTransactionIndexQuery {
+    condition: WhereCondition,
+}
+
+impl InsertCardanoTransactionIndexQuery {
+    /// Query that insert multiples records.
+    pub fn insert_many(index_records: Vec<CardanoTransactionIndexRecord>) -> StdResult<Self> {
+        let columns = "(filter_kind, filter_value, transaction_hash, block_number, slot_number)";
+        let values_columns: Vec<&str> =
+            repeat_n("(?*, ?*, ?*, ?*, ?*)", index_records.len()).collect();
+
+        let values: StdResult<Vec<Value>> =
+            index_records
+                .into_iter()
+                .try_fold(vec![], |mut vec, record| {
+                    vec.append(&mut vec![
+                        Value::String(record.filter.kind().to_string()),
+                        Value::String(record.filter.value()),
+                        Value::String(record.transaction_hash),
+                        Value::Integer(record.block_number.try_into()?),
+                        Value::Integer(record.slot_number.try_into()?),
+                    ]);
+                    Ok(vec)
+                });
+        let condition = WhereCondition::new(
+            format!("{columns} values {}", values_columns.join(", ")).as_str(),
+            values?,
+        );
+
+        Ok(Self { condition })
+    }
+}
+
+impl Query for InsertCardanoTransactionIndexQuery {
+    type Entity = CardanoTransactionIndexRecord;
+
+    fn filters(&self) -> WhereCondition {
+        self.condition.clone()
+    }
+
+    fn get_definition(&self, condition: &str) -> String {
+        let aliases = SourceAlias::new(&[("{:cardano_tx_index:}", "cardano_tx_index")]);
+        let projection = Self::Entity::get_projection().expand(aliases);
+
+        format!("insert or ignore into cardano_tx_index {condition} returning {projection}")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use mithril_common::entities::{BlockNumber, CardanoTransactionFilter, SlotNumber};
+
+    use crate::database::query::GetCardanoTransactionIndexQuery;
+    use crate::database::test_helper::cardano_tx_db_connection;
+    use crate::sqlite::ConnectionExtensions;
+
+    use super::*;
+
+    #[test]
+    fn test_insert_index_entries_ignore_already_stored_entries() {
+        let connection = cardano_tx_db_connection().unwrap();
+        let records = vec![
+            CardanoTransactionIndexRecord::new(
+                CardanoTransactionFilter::Address("addr-1".to_string()),
+                "tx-hash-1",
+                BlockNumber(10),
+                SlotNumber(50),
+            ),
+            CardanoTransactionIndexRecord::new(
+                CardanoTransactionFilter::MetadataLabel(674),
+                "tx-hash-1",
+                BlockNumber(10),
+                SlotNumber(50),
+            ),
+        ];
+
+        let inserted: Vec<CardanoTransactionIndexRecord> = connection
+            .fetch_collect(
+                InsertCardanoTransactionIndexQuery::insert_many(records.clone()).unwrap(),
+            )
+            .unwrap();
+        assert_eq!(records, inserted);
+
+        let inserted: Vec<CardanoTransactionIndexRecord> = connection
+            .fetch_collect(
+                InsertCardanoTransactionIndexQuery::insert_many(records.clone()).unwrap(),
+            )
+            .unwrap();
+        assert!(inserted.is_empty());
+
+        let stored: Vec<CardanoTransactionIndexRecord> = connection
+            .fetch_collect(GetCardanoTransactionIndexQuery::all())
+            .unwrap();
+        assert_eq!(records.len(), stored.len());
+    }
+}
@@ -0,0 +1,39 @@
+mod delete_cardano_transaction_index;
+mod get_cardano_transaction_index;
+mod insert_cardano_transaction_index;
+
+pub use delete_cardano_transaction_index::*;
+pub use get_cardano_transaction_index::*;
+pub use insert_cardano_transaction_index::*;
+
+#[cfg(test)]
+mod test_helper {
+    use mithril_common::entities::{BlockNumber, CardanoTransactionFilter, SlotNumber};
+
+    use crate::database::record::CardanoTransactionIndexRecord;
+    use crate::sqlite::{ConnectionExtensions, SqliteConnection};
+
+    use super::*;
+
+    pub fn insert_index_entries(
+        connection: &SqliteConnection,
+        records: Vec<CardanoTransactionIndexRecord>,
+    ) {
+        connection
+            .fetch_first(InsertCardanoTransactionIndexQuery::insert_many(records).unwrap())
+            .unwrap();
+    }
+
+    pub fn address_record(
+        address: &str,
+        block_number: BlockNumber,
+        slot_number: SlotNumber,
+    ) -> CardanoTransactionIndexRecord {
+        CardanoTransactionIndexRecord::new(
+            CardanoTransactionFilter::Address(address.to_string()),
+            format!("tx-hash-{}-{}", block_number, address),
+            block_number,
+            slot_number,
+        )
+    }
+}
@@ -0,0 +1,137 @@
+use sqlite::Value;
+
+use mithril_common::entities::ImmutableFileName;
+
+use crate::database::record::ImmutableFileDigestCacheRecord;
+use crate::sqlite::{Query, SourceAlias, SqLiteEntity, WhereCondition};
+
+/// Query to delete [ImmutableFileDigestCacheRecord] from the sqlite database
+pub struct DeleteImmutableFileDigestCacheQuery {
+    condition: WhereCondition,
+}
+
+impl Query for DeleteImmutableFileDigestCacheQuery {
+    type Entity = ImmutableFileDigestCacheRecord;
+
+    fn filters(&self) -> WhereCondition {
+        self.condition.clone()
+    }
+
+    fn get_definition(&self, condition: &str) -> String {
+        // it is important to alias the fields with the same name as the table
+        // since the table cannot be aliased in a RETURNING statement in SQLite.
+        let projection = Self::Entity::get_projection().expand(SourceAlias::new(&[(
+            "{:immutable_file_digest:}",
+            "immutable_file_digest",
+        )]));
+
+        format!("delete from immutable_file_digest where {condition} returning {projection}")
+    }
+}
+
+impl DeleteImmutableFileDigestCacheQuery {
+    pub fn all() -> Self {
+        Self {
+            condition: WhereCondition::default(),
+        }
+    }
+
+    pub fn by_immutable_file_names(immutable_file_names: Vec<ImmutableFileName>) -> Self {
+        let names_values = immutable_file_names
+            .into_iter()
+            .map(Value::String)
+            .collect();
+
+        Self {
+            condition: WhereCondition::where_in("immutable_file_name", names_values),
+        }
+    }
+
+    /// Delete the records which digest is not a hex encoded SHA256 hash
+    pub fn with_malformed_digest() -> Self {
+        Self {
+            condition: WhereCondition::new(
+                "length(digest) != 64 or digest glob '*[^0-9a-f]*'",
+                vec![],
+            ),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::database::query::immutable_file_digest_cache::test_helper::insert_immutable_file_digests;
+    use crate::database::query::GetImmutableFileDigestCacheQuery;
+    use crate::database::test_helper::immutable_file_digest_cache_db_connection;
+    use crate::sqlite::ConnectionExtensions;
+
+    use super::*;
+
+    fn digests_dataset() -> Vec<ImmutableFileDigestCacheRecord> {
+        vec![
+            ImmutableFileDigestCacheRecord::new("00001.chunk", "digest-1"),
+            ImmutableFileDigestCacheRecord::new("00001.primary", "digest-2"),
+        ]
+    }
+
+    #[test]
+    fn test_delete_all_digests() {
+        let connection = immutable_file_digest_cache_db_connection().unwrap();
+        insert_immutable_file_digests(&connection, digests_dataset());
+
+        let cursor = connection
+            .fetch(DeleteImmutableFileDigestCacheQuery::all())
+            .unwrap();
+        assert_eq!(2, cursor.count());
+
+        let records: Vec<ImmutableFileDigestCacheRecord> = connection
+            .fetch_collect(GetImmutableFileDigestCacheQuery::all())
+            .unwrap();
+        assert!(records.is_empty());
+    }
+
+    #[test]
+    fn test_delete_digests_by_immutable_file_names() {
+        let connection = immutable_file_digest_cache_db_connection().unwrap();
+        insert_immutable_file_digests(&connection, digests_dataset());
+
+        let cursor = connection
+            .fetch(
+                DeleteImmutableFileDigestCacheQuery::by_immutable_file_names(vec![
+                    "00001.chunk".to_string()
+                ]),
+            )
+            .unwrap();
+        assert_eq!(1, cursor.count());
+
+        let records: Vec<ImmutableFileDigestCacheRecord> = connection
+            .fetch_collect(GetImmutableFileDigestCacheQuery::all())
+            .unwrap();
+        assert_eq!(digests_dataset()[1..].to_vec(), records);
+    }
+
+    #[test]
+    fn test_delete_digests_with_malformed_digest() {
+        let connection = immutable_file_digest_cache_db_connection().unwrap();
+        let valid_record = ImmutableFileDigestCacheRecord::new("00001.chunk", "a1".repeat(32));
+        insert_immutable_file_digests(
+            &connection,
+            vec![
+                valid_record.clone(),
+                ImmutableFileDigestCacheRecord::new("00001.primary", "a1"),
+                ImmutableFileDigestCacheRecord::new("00001.secondary", "z1".repeat(32)),
+                ImmutableFileDigestCacheRecord::new("00002.chunk", "A1".repeat(32)),
+            ],
+        );
+
+        let cursor = connection
+            .fetch(DeleteImmutableFileDigestCacheQuery::with_malformed_digest())
+            .unwrap();
+        assert_eq!(3, cursor.count());
+
+        let records: Vec<ImmutableFileDigestCacheRecord> = connection
+            .fetch_collect(GetImmutableFileDigestCacheQuery::all())
+            .unwrap();
+        assert_eq!(vec![valid_record], records);
+    }
+}
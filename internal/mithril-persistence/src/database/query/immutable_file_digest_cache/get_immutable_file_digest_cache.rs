@@ -0,0 +1,107 @@
+use sqlite::Value;
+
+use mithril_common::entities::ImmutableFileName;
+
+use crate::database::record::ImmutableFileDigestCacheRecord;
+use crate::sqlite::{Query, SourceAlias, SqLiteEntity, WhereCondition};
+
+/// Simple queries to retrieve [ImmutableFileDigestCacheRecord] from the sqlite database.
+pub struct GetImmutableFileDigestCacheQuery {
+    condition: WhereCondition,
+}
+
+impl GetImmutableFileDigestCacheQuery {
+    pub fn all() -> Self {
+        Self {
+            condition: WhereCondition::default(),
+        }
+    }
+
+    pub fn by_immutable_file_names(immutable_file_names: Vec<ImmutableFileName>) -> Self {
+        let names_values = immutable_file_names
+            .into_iter()
+            .map(Value::String)
+            .collect();
+
+        Self {
+            condition: WhereCondition::where_in("immutable_file_name", names_values),
+        }
+    }
+}
+
+impl Query for GetImmutableFileDigestCacheQuery {
+    type Entity = ImmutableFileDigestCacheRecord;
+
+    fn filters(&self) -> WhereCondition {
+        self.condition.clone()
+    }
+
+    fn get_definition(&self, condition: &str) -> String {
+        let aliases = SourceAlias::new(&[("{:immutable_file_digest:}", "immutable_file_digest")]);
+        let projection = Self::Entity::get_projection().expand(aliases);
+
+        format!(
+            "select {projection} from immutable_file_digest where {condition} order by immutable_file_name"
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::database::query::immutable_file_digest_cache::test_helper::insert_immutable_file_digests;
+    use crate::database::test_helper::immutable_file_digest_cache_db_connection;
+    use crate::sqlite::ConnectionExtensions;
+
+    use super::*;
+
+    #[test]
+    fn test_get_digests_by_immutable_file_names() {
+        let connection = immutable_file_digest_cache_db_connection().unwrap();
+        insert_immutable_file_digests(
+            &connection,
+            vec![
+                ImmutableFileDigestCacheRecord::new("00001.chunk", "digest-1"),
+                ImmutableFileDigestCacheRecord::new("00001.primary", "digest-2"),
+                ImmutableFileDigestCacheRecord::new("00001.secondary", "digest-3"),
+            ],
+        );
+
+        let records: Vec<ImmutableFileDigestCacheRecord> = connection
+            .fetch_collect(GetImmutableFileDigestCacheQuery::by_immutable_file_names(
+                vec![
+                    "00001.secondary".to_string(),
+                    "00001.chunk".to_string(),
+                    "00002.chunk".to_string(),
+                ],
+            ))
+            .unwrap();
+
+        assert_eq!(
+            vec![
+                ImmutableFileDigestCacheRecord::new("00001.chunk", "digest-1"),
+                ImmutableFileDigestCacheRecord::new("00001.secondary", "digest-3"),
+            ],
+            records
+        );
+    }
+
+    #[test]
+    fn test_get_digests_by_immutable_file_names_with_empty_list() {
+        let connection = immutable_file_digest_cache_db_connection().unwrap();
+        insert_immutable_file_digests(
+            &connection,
+            vec![ImmutableFileDigestCacheRecord::new(
+                "00001.chunk",
+                "digest-1",
+            )],
+        );
+
+        let records: Vec<ImmutableFileDigestCacheRecord> = connection
+            .fetch_collect(GetImmutableFileDigestCacheQuery::by_immutable_file_names(
+                vec![],
+            ))
+            .unwrap();
+
+        assert!(records.is_empty());
+    }
+}
@@ -0,0 +1,88 @@
+use std::iter::repeat_n;
+
+use sqlite::Value;
+
+use crate::database::record::ImmutableFileDigestCacheRecord;
+use crate::sqlite::{Query, SourceAlias, SqLiteEntity, WhereCondition};
+
+/// Query to insert or replace [ImmutableFileDigestCacheRecord] in the sqlite database
+pub struct InsertImmutableFileDigestCacheQuery {
+    condition: WhereCondition,
+}
+
+impl InsertImmutableFileDigestCacheQuery {
+    /// Query that insert multiples records, replacing the digest of the already stored files.
+    pub fn insert_many(records: Vec<ImmutableFileDigestCacheRecord>) -> Self {
+        let columns = "(immutable_file_name, digest)";
+        let values_columns: Vec<&str> = repeat_n("(?*, ?*)", records.len()).collect();
+        let values = records
+            .into_iter()
+            .flat_map(|record| {
+                [
+                    Value::String(record.immutable_file_name),
+                    Value::String(record.digest),
+                ]
+            })
+            .collect();
+        let condition = WhereCondition::new(
+            format!("{columns} values {}", values_columns.join(", ")).as_str(),
+            values,
+        );
+
+        Self { condition }
+    }
+}
+
+impl Query for InsertImmutableFileDigestCacheQuery {
+    type Entity = ImmutableFileDigestCacheRecord;
+
+    fn filters(&self) -> WhereCondition {
+        self.condition.clone()
+    }
+
+    fn get_definition(&self, condition: &str) -> String {
+        let aliases = SourceAlias::new(&[("{:immutable_file_digest:}", "immutable_file_digest")]);
+        let projection = Self::Entity::get_projection().expand(aliases);
+
+        format!("insert or replace into immutable_file_digest {condition} returning {projection}")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::database::query::GetImmutableFileDigestCacheQuery;
+    use crate::database::test_helper::immutable_file_digest_cache_db_connection;
+    use crate::sqlite::ConnectionExtensions;
+
+    use super::*;
+
+    #[test]
+    fn test_insert_digests_replace_already_stored_digests() {
+        let connection = immutable_file_digest_cache_db_connection().unwrap();
+
+        let inserted: Vec<ImmutableFileDigestCacheRecord> = connection
+            .fetch_collect(InsertImmutableFileDigestCacheQuery::insert_many(vec![
+                ImmutableFileDigestCacheRecord::new("00001.chunk", "digest-1"),
+                ImmutableFileDigestCacheRecord::new("00001.primary", "digest-2"),
+            ]))
+            .unwrap();
+        assert_eq!(2, inserted.len());
+
+        connection
+            .apply(InsertImmutableFileDigestCacheQuery::insert_many(vec![
+                ImmutableFileDigestCacheRecord::new("00001.chunk", "updated-digest-1"),
+            ]))
+            .unwrap();
+
+        let stored: Vec<ImmutableFileDigestCacheRecord> = connection
+            .fetch_collect(GetImmutableFileDigestCacheQuery::all())
+            .unwrap();
+        assert_eq!(
+            vec![
+                ImmutableFileDigestCacheRecord::new("00001.chunk", "updated-digest-1"),
+                ImmutableFileDigestCacheRecord::new("00001.primary", "digest-2"),
+            ],
+            stored
+        );
+    }
+}
@@ -0,0 +1,24 @@
+mod delete_immutable_file_digest_cache;
+mod get_immutable_file_digest_cache;
+mod insert_immutable_file_digest_cache;
+
+pub use delete_immutable_file_digest_cache::*;
+pub use get_immutable_file_digest_cache::*;
+pub use insert_immutable_file_digest_cache::*;
+
+#[cfg(test)]
+mod test_helper {
+    use crate::database::record::ImmutableFileDigestCacheRecord;
+    use crate::sqlite::{ConnectionExtensions, SqliteConnection};
+
+    use super::*;
+
+    pub fn insert_immutable_file_digests(
+        connection: &SqliteConnection,
+        records: Vec<ImmutableFileDigestCacheRecord>,
+    ) {
+        connection
+            .apply(InsertImmutableFileDigestCacheQuery::insert_many(records))
+            .unwrap();
+    }
+}
@@ -0,0 +1,80 @@
+use sqlite::Row;
+
+use mithril_common::entities::{BlockHash, BlockNumber, CardanoBlock, SlotNumber};
+
+use crate::database::Hydrator;
+use crate::sqlite::{HydrationError, Projection, SqLiteEntity};
+
+/// Cardano block record is the representation of a cardano block.
+#[derive(Debug, PartialEq, Clone)]
+pub struct CardanoBlockRecord {
+    /// Unique hash of the block
+    pub block_hash: BlockHash,
+
+    /// Number of the block
+    pub block_number: BlockNumber,
+
+    /// Slot number of the block
+    pub slot_number: SlotNumber,
+}
+
+impl CardanoBlockRecord {
+    /// CardanoBlockRecord factory
+    pub fn new<T: Into<BlockHash>>(
+        block_hash: T,
+        block_number: BlockNumber,
+        slot_number: SlotNumber,
+    ) -> Self {
+        Self {
+            block_hash: block_hash.into(),
+            block_number,
+            slot_number,
+        }
+    }
+}
+
+impl From<CardanoBlock> for CardanoBlockRecord {
+    fn from(block: CardanoBlock) -> Self {
+        Self {
+            block_hash: block.block_hash,
+            block_number: block.block_number,
+            slot_number: block.slot_number,
+        }
+    }
+}
+
+impl From<CardanoBlockRecord> for CardanoBlock {
+    fn from(other: CardanoBlockRecord) -> CardanoBlock {
+        CardanoBlock {
+            block_hash: other.block_hash,
+            block_number: other.block_number,
+            slot_number: other.slot_number,
+        }
+    }
+}
+
+impl SqLiteEntity for CardanoBlockRecord {
+    fn hydrate(row: Row) -> Result<Self, HydrationError>
+    where
+        Self: Sized,
+    {
+        let block_hash = row.read::<&str, _>(0);
+        let block_number =
+            Hydrator::try_to_u64("cardano_block.block_number", row.read::<i64, _>(1))?;
+        let slot_number = Hydrator::try_to_u64("cardano_block.slot_number", row.read::<i64, _>(2))?;
+
+        Ok(Self {
+            block_hash: block_hash.to_string(),
+            block_number: BlockNumber(block_number),
+            slot_number: SlotNumber(slot_number),
+        })
+    }
+
+    fn get_projection() -> Projection {
+        Projection::from(&[
+            ("block_hash", "{:cardano_block:}.block_hash", "text"),
+            ("block_number", "{:cardano_block:}.block_number", "int"),
+            ("slot_number", "{:cardano_block:}.slot_number", "int"),
+        ])
+    }
+}
@@ -0,0 +1,143 @@
+use sqlite::Row;
+
+use mithril_common::entities::{
+    BlockNumber, CardanoTransactionFilter, CardanoTransactionIndexEntry, SlotNumber,
+    TransactionHash,
+};
+
+use crate::database::Hydrator;
+use crate::sqlite::{HydrationError, Projection, SqLiteEntity};
+
+/// Cardano transaction index record is the representation of an entry of the index of the
+/// cardano transactions by output address or by metadata label.
+#[derive(Debug, PartialEq, Clone)]
+pub struct CardanoTransactionIndexRecord {
+    /// Filter matched by the transaction
+    pub filter: CardanoTransactionFilter,
+
+    /// Hash of the matching transaction
+    pub transaction_hash: TransactionHash,
+
+    /// Block number of the matching transaction
+    pub block_number: BlockNumber,
+
+    /// Slot number of the matching transaction
+    pub slot_number: SlotNumber,
+}
+
+impl CardanoTransactionIndexRecord {
+    /// CardanoTransactionIndexRecord factory
+    pub fn new<T: Into<TransactionHash>>(
+        filter: CardanoTransactionFilter,
+        transaction_hash: T,
+        block_number: BlockNumber,
+        slot_number: SlotNumber,
+    ) -> Self {
+        Self {
+            filter,
+            transaction_hash: transaction_hash.into(),
+            block_number,
+            slot_number,
+        }
+    }
+
+    /// Rebuild a [CardanoTransactionFilter] from its kind and value as stored in the database.
+    pub(crate) fn hydrate_filter(
+        kind: &str,
+        value: &str,
+    ) -> Result<CardanoTransactionFilter, HydrationError> {
+        match kind {
+            "address" => Ok(CardanoTransactionFilter::Address(value.to_string())),
+            "metadata_label" => value
+                .parse::<u64>()
+                .map(CardanoTransactionFilter::MetadataLabel)
+                .map_err(|e| {
+                    HydrationError::InvalidData(format!(
+                        "Invalid metadata label '{value}' in cardano_tx_index. Error = {e}"
+                    ))
+                }),
+            _ => Err(HydrationError::InvalidData(format!(
+                "Unknown filter kind '{kind}' in cardano_tx_index"
+            ))),
+        }
+    }
+}
+
+impl From<CardanoTransactionIndexEntry> for CardanoTransactionIndexRecord {
+    fn from(entry: CardanoTransactionIndexEntry) -> Self {
+        Self {
+            filter: entry.filter,
+            transaction_hash: entry.transaction_hash,
+            block_number: entry.block_number,
+            slot_number: entry.slot_number,
+        }
+    }
+}
+
+impl From<CardanoTransactionIndexRecord> for CardanoTransactionIndexEntry {
+    fn from(other: CardanoTransactionIndexRecord) -> CardanoTransactionIndexEntry {
+        CardanoTransactionIndexEntry {
+            filter: other.filter,
+            transaction_hash: other.transaction_hash,
+            block_number: other.block_number,
+            slot_number: other.slot_number,
+        }
+    }
+}
+
+impl SqLiteEntity for CardanoTransactionIndexRecord {
+    fn hydrate(row: Row) -> Result<Self, HydrationError>
+    where
+        Self: Sized,
+    {
+        let filter_kind = row.read::<&str, _>(0);
+        let filter_value = row.read::<&str, _>(1);
+        let transaction_hash = row.read::<&str, _>(2);
+        let block_number =
+            Hydrator::try_to_u64("cardano_tx_index.block_number", row.read::<i64, _>(3))?;
+        let slot_number =
+            Hydrator::try_to_u64("cardano_tx_index.slot_number", row.read::<i64, _>(4))?;
+
+        Ok(Self {
+            filter: Self::hydrate_filter(filter_kind, filter_value)?,
+            transaction_hash: transaction_hash.to_string(),
+            block_number: BlockNumber(block_number),
+            slot_number: SlotNumber(slot_number),
+        })
+    }
+
+    fn get_projection() -> Projection {
+        Projection::from(&[
+            ("filter_kind", "{:cardano_tx_index:}.filter_kind", "text"),
+            ("filter_value", "{:cardano_tx_index:}.filter_value", "text"),
+            (
+                "transaction_hash",
+                "{:cardano_tx_index:}.transaction_hash",
+                "text",
+            ),
+            ("block_number", "{:cardano_tx_index:}.block_number", "int"),
+            ("slot_number", "{:cardano_tx_index:}.slot_number", "int"),
+        ])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn hydrate_filter_from_kind_and_value() {
+        assert_eq!(
+            CardanoTransactionFilter::Address("addr_test1".to_string()),
+            CardanoTransactionIndexRecord::hydrate_filter("address", "addr_test1").unwrap()
+        );
+        assert_eq!(
+            CardanoTransactionFilter::MetadataLabel(674),
+            CardanoTransactionIndexRecord::hydrate_filter("metadata_label", "674").unwrap()
+        );
+        CardanoTransactionIndexRecord::hydrate_filter("metadata_label", "not-a-label")
+            .expect_err("An invalid metadata label should fail to hydrate");
+        CardanoTransactionIndexRecord::hydrate_filter("unknown", "value")
+            .expect_err("An unknown filter kind should fail to hydrate");
+    }
+}
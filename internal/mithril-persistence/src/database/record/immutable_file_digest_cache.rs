@@ -0,0 +1,54 @@
+use sqlite::Row;
+
+use mithril_common::entities::{HexEncodedDigest, ImmutableFileName};
+
+use crate::sqlite::{HydrationError, Projection, SqLiteEntity};
+
+/// Immutable file digest cache record is the cached digest of an immutable file.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct ImmutableFileDigestCacheRecord {
+    /// Immutable file name
+    pub immutable_file_name: ImmutableFileName,
+
+    /// Digest of the immutable file
+    pub digest: HexEncodedDigest,
+}
+
+impl ImmutableFileDigestCacheRecord {
+    /// ImmutableFileDigestCacheRecord factory
+    pub fn new<T: Into<ImmutableFileName>, U: Into<HexEncodedDigest>>(
+        immutable_file_name: T,
+        digest: U,
+    ) -> Self {
+        Self {
+            immutable_file_name: immutable_file_name.into(),
+            digest: digest.into(),
+        }
+    }
+}
+
+impl SqLiteEntity for ImmutableFileDigestCacheRecord {
+    fn hydrate(row: Row) -> Result<Self, HydrationError>
+    where
+        Self: Sized,
+    {
+        let immutable_file_name = row.read::<&str, _>(0).to_string();
+        let digest = row.read::<&str, _>(1).to_string();
+
+        Ok(Self {
+            immutable_file_name,
+            digest,
+        })
+    }
+
+    fn get_projection() -> Projection {
+        Projection::from(&[
+            (
+                "immutable_file_name",
+                "{:immutable_file_digest:}.immutable_file_name",
+                "text",
+            ),
+            ("digest", "{:immutable_file_digest:}.digest", "text"),
+        ])
+    }
+}
@@ -1,7 +1,13 @@
 //! Shared database records
 
 mod block_range_root;
+mod cardano_block;
 mod cardano_transaction;
+mod cardano_transaction_index;
+mod immutable_file_digest_cache;
 
 pub use block_range_root::*;
+pub use cardano_block::*;
 pub use cardano_transaction::*;
+pub use cardano_transaction_index::*;
+pub use immutable_file_digest_cache::*;
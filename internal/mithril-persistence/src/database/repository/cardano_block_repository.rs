@@ -0,0 +1,228 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+
+use mithril_common::crypto_helper::{MKTreeNode, MKTreeStorer};
+use mithril_common::entities::{BlockHash, BlockNumber, BlockRange};
+use mithril_common::signable_builder::BlockRangeRootRetriever;
+use mithril_common::StdResult;
+
+use crate::database::query::{GetCardanoBlockQuery, GetCardanoBlockRangeRootQuery};
+use crate::database::record::{BlockRangeRootRecord, CardanoBlockRecord};
+use crate::sqlite::{ConnectionExtensions, SqliteConnectionPool};
+
+/// ## Cardano block repository
+///
+/// This is a business oriented layer to read the Cardano blocks, and their block range roots,
+/// stored alongside the Cardano transactions.
+///
+/// The blocks are written by the [CardanoTransactionRepository][super::CardanoTransactionRepository].
+pub struct CardanoBlockRepository {
+    connection_pool: Arc<SqliteConnectionPool>,
+}
+
+impl CardanoBlockRepository {
+    /// Instantiate service
+    pub fn new(connection_pool: Arc<SqliteConnectionPool>) -> Self {
+        Self { connection_pool }
+    }
+
+    /// Return all the [CardanoBlockRecord]s in the database.
+    pub async fn get_all_blocks(&self) -> StdResult<Vec<CardanoBlockRecord>> {
+        self.connection_pool
+            .connection()?
+            .fetch_collect(GetCardanoBlockQuery::all())
+    }
+
+    /// Get the [CardanoBlockRecord] for the given block hashes, up to a block number
+    pub async fn get_block_by_hashes<T: Into<BlockHash>>(
+        &self,
+        hashes: Vec<T>,
+        up_to: BlockNumber,
+    ) -> StdResult<Vec<CardanoBlockRecord>> {
+        let query = GetCardanoBlockQuery::by_block_hashes(
+            hashes.into_iter().map(Into::into).collect(),
+            up_to,
+        );
+        self.connection_pool.connection()?.fetch_collect(query)
+    }
+
+    /// Get the [CardanoBlockRecord] for the given block ranges.
+    pub async fn get_block_by_block_ranges(
+        &self,
+        block_ranges: Vec<BlockRange>,
+    ) -> StdResult<Vec<CardanoBlockRecord>> {
+        let mut blocks = vec![];
+        for block_range in block_ranges {
+            let block_range_blocks: Vec<CardanoBlockRecord> = self
+                .connection_pool
+                .connection()?
+                .fetch_collect(GetCardanoBlockQuery::by_block_ranges(vec![block_range]))?;
+            blocks.extend(block_range_blocks);
+        }
+
+        Ok(blocks)
+    }
+
+    /// Retrieve all the blocks Block Range Roots in database up to the block range that contains
+    /// the given block number.
+    pub async fn retrieve_block_range_roots_up_to(
+        &self,
+        block_number: BlockNumber,
+    ) -> StdResult<Box<dyn Iterator<Item = (BlockRange, MKTreeNode)> + '_>> {
+        let block_range_roots = self
+            .connection_pool
+            .connection()?
+            .fetch(GetCardanoBlockRangeRootQuery::contains_or_below_block_number(block_number))?
+            .map(|record| -> (BlockRange, MKTreeNode) { record.into() })
+            .collect::<Vec<_>>();
+
+        Ok(Box::new(block_range_roots.into_iter()))
+    }
+
+    /// Retrieve all the blocks [BlockRangeRootRecord] in database.
+    pub fn get_all_block_range_root(&self) -> StdResult<Vec<BlockRangeRootRecord>> {
+        self.connection_pool
+            .connection()?
+            .fetch_collect(GetCardanoBlockRangeRootQuery::all())
+    }
+}
+
+#[async_trait]
+impl<S: MKTreeStorer> BlockRangeRootRetriever<S> for CardanoBlockRepository {
+    async fn retrieve_block_range_roots<'a>(
+        &'a self,
+        up_to_beacon: BlockNumber,
+    ) -> StdResult<Box<dyn Iterator<Item = (BlockRange, MKTreeNode)> + 'a>> {
+        self.retrieve_block_range_roots_up_to(up_to_beacon).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use mithril_common::entities::{CardanoBlock, SlotNumber};
+
+    use crate::database::repository::CardanoTransactionRepository;
+    use crate::database::test_helper::cardano_tx_db_connection;
+
+    use super::*;
+
+    fn build_repositories() -> (CardanoTransactionRepository, CardanoBlockRepository) {
+        let connection_pool =
+            Arc::new(SqliteConnectionPool::build(1, cardano_tx_db_connection).unwrap());
+
+        (
+            CardanoTransactionRepository::new(connection_pool.clone()),
+            CardanoBlockRepository::new(connection_pool),
+        )
+    }
+
+    #[tokio::test]
+    async fn repository_store_blocks_and_get_them_by_hashes() {
+        let (transaction_repository, block_repository) = build_repositories();
+        transaction_repository
+            .store_blocks(vec![
+                CardanoBlock::new("block-hash-10", BlockNumber(10), SlotNumber(50)),
+                CardanoBlock::new("block-hash-11", BlockNumber(11), SlotNumber(51)),
+                CardanoBlock::new("block-hash-12", BlockNumber(12), SlotNumber(52)),
+            ])
+            .await
+            .unwrap();
+
+        let blocks = block_repository
+            .get_block_by_hashes(vec!["block-hash-10", "block-hash-12"], BlockNumber(100))
+            .await
+            .unwrap();
+
+        assert_eq!(
+            vec![
+                CardanoBlockRecord::new("block-hash-10", BlockNumber(10), SlotNumber(50)),
+                CardanoBlockRecord::new("block-hash-12", BlockNumber(12), SlotNumber(52)),
+            ],
+            blocks
+        );
+    }
+
+    #[tokio::test]
+    async fn repository_retrieve_block_range_roots_up_to() {
+        let (transaction_repository, block_repository) = build_repositories();
+        let block_range_roots = vec![
+            (
+                BlockRange::from_block_number(BlockNumber(15)),
+                MKTreeNode::from_hex("AAAA").unwrap(),
+            ),
+            (
+                BlockRange::from_block_number(BlockRange::LENGTH + 15),
+                MKTreeNode::from_hex("BBBB").unwrap(),
+            ),
+        ];
+        transaction_repository
+            .create_cardano_block_range_roots(block_range_roots.clone())
+            .await
+            .unwrap();
+        // Block range roots computed from the transactions must not leak in the blocks ones
+        transaction_repository
+            .create_block_range_roots(vec![(
+                BlockRange::from_block_number(BlockNumber(15)),
+                MKTreeNode::from_hex("CCCC").unwrap(),
+            )])
+            .await
+            .unwrap();
+
+        let retrieved_block_ranges: Vec<(BlockRange, MKTreeNode)> = block_repository
+            .retrieve_block_range_roots_up_to(BlockRange::LENGTH * 2)
+            .await
+            .unwrap()
+            .collect();
+
+        assert_eq!(block_range_roots, retrieved_block_ranges);
+    }
+
+    #[tokio::test]
+    async fn rolled_back_blocks_and_block_range_roots_are_removed() {
+        let (transaction_repository, block_repository) = build_repositories();
+        transaction_repository
+            .store_blocks(vec![
+                CardanoBlock::new("block-hash-1", BlockRange::LENGTH, SlotNumber(50)),
+                CardanoBlock::new("block-hash-2", BlockRange::LENGTH * 2, SlotNumber(60)),
+                CardanoBlock::new("block-hash-3", BlockRange::LENGTH * 3, SlotNumber(70)),
+            ])
+            .await
+            .unwrap();
+        transaction_repository
+            .create_cardano_block_range_roots(vec![
+                (
+                    BlockRange::from_block_number(BlockRange::LENGTH),
+                    MKTreeNode::from_hex("AAAA").unwrap(),
+                ),
+                (
+                    BlockRange::from_block_number(BlockRange::LENGTH * 2),
+                    MKTreeNode::from_hex("BBBB").unwrap(),
+                ),
+                (
+                    BlockRange::from_block_number(BlockRange::LENGTH * 3),
+                    MKTreeNode::from_hex("CCCC").unwrap(),
+                ),
+            ])
+            .await
+            .unwrap();
+
+        // Blocks are used to find the rollback point even if there is no transactions stored
+        transaction_repository
+            .remove_rolled_back_transactions_and_block_range_by_slot_number(SlotNumber(65))
+            .await
+            .unwrap();
+
+        assert_eq!(
+            vec![
+                CardanoBlockRecord::new("block-hash-1", BlockRange::LENGTH, SlotNumber(50)),
+                CardanoBlockRecord::new("block-hash-2", BlockRange::LENGTH * 2, SlotNumber(60)),
+            ],
+            block_repository.get_all_blocks().await.unwrap()
+        );
+        assert_eq!(
+            1,
+            block_repository.get_all_block_range_root().unwrap().len()
+        );
+    }
+}
@@ -6,22 +6,34 @@ use async_trait::async_trait;
 
 use mithril_common::crypto_helper::{MKTreeNode, MKTreeStorer};
 use mithril_common::entities::{
-    BlockHash, BlockNumber, BlockRange, CardanoTransaction, ChainPoint, SlotNumber, TransactionHash,
+    BlockHash, BlockNumber, BlockRange, CardanoTransaction, CardanoTransactionFilter, ChainPoint,
+    SlotNumber, TransactionHash,
 };
 use mithril_common::signable_builder::BlockRangeRootRetriever;
 use mithril_common::StdResult;
 
 use crate::database::query::{
-    DeleteBlockRangeRootQuery, DeleteCardanoTransactionQuery, GetBlockRangeRootQuery,
-    GetCardanoTransactionQuery, InsertBlockRangeRootQuery, InsertCardanoTransactionQuery,
+    DeleteBlockRangeRootQuery, DeleteCardanoBlockQuery, DeleteCardanoBlockRangeRootQuery,
+    DeleteCardanoTransactionIndexQuery, DeleteCardanoTransactionQuery, GetBlockRangeRootQuery,
+    GetCardanoBlockQuery, GetCardanoBlockRangeRootQuery, GetCardanoTransactionIndexQuery,
+    GetCardanoTransactionQuery, InsertBlockRangeRootQuery, InsertCardanoBlockQuery,
+    InsertCardanoBlockRangeRootQuery, InsertCardanoTransactionIndexQuery,
+    InsertCardanoTransactionQuery,
+};
+use crate::database::record::{
+    BlockRangeRootRecord, CardanoBlockRecord, CardanoTransactionIndexRecord,
+    CardanoTransactionRecord,
 };
-use crate::database::record::{BlockRangeRootRecord, CardanoTransactionRecord};
 use crate::sqlite::{ConnectionExtensions, SqliteConnection, SqliteConnectionPool};
 
 /// ## Cardano transaction repository
 ///
 /// This is a business oriented layer to perform actions on the database through
 /// queries.
+///
+/// The blocks scanned alongside the transactions are also written through this repository so
+/// that rollbacks and pruning are applied to both in the same database transaction, they are
+/// read through the [CardanoBlockRepository][super::CardanoBlockRepository].
 pub struct CardanoTransactionRepository {
     connection_pool: Arc<SqliteConnectionPool>,
 }
@@ -113,6 +125,18 @@ impl CardanoTransactionRepository {
         connection.fetch_collect(InsertBlockRangeRootQuery::insert_many(records)?)
     }
 
+    /// Create new [BlockRangeRootRecord]s computed from the blocks in the database.
+    pub async fn create_cardano_block_range_roots<T: Into<BlockRangeRootRecord>>(
+        &self,
+        block_ranges: Vec<T>,
+    ) -> StdResult<Vec<BlockRangeRootRecord>> {
+        let records: Vec<BlockRangeRootRecord> =
+            block_ranges.into_iter().map(|tx| tx.into()).collect();
+        let connection = self.connection_pool.connection()?;
+
+        connection.fetch_collect(InsertCardanoBlockRangeRootQuery::insert_many(records)?)
+    }
+
     /// Get the highest [ChainPoint] of the cardano transactions stored in the database.
     pub async fn get_transaction_highest_chain_point(&self) -> StdResult<Option<ChainPoint>> {
         let first_transaction_with_highest_block_number = self
@@ -169,6 +193,39 @@ impl CardanoTransactionRepository {
             .fetch_first(GetBlockRangeRootQuery::highest())
     }
 
+    /// Get the highest [ChainPoint] of the cardano blocks stored in the database.
+    pub async fn get_block_highest_chain_point(&self) -> StdResult<Option<ChainPoint>> {
+        let block_with_highest_block_number = self
+            .connection_pool
+            .connection()?
+            .fetch_first(GetCardanoBlockQuery::with_highest_block_number())?;
+
+        Ok(block_with_highest_block_number.map(|record| {
+            ChainPoint::new(record.slot_number, record.block_number, record.block_hash)
+        }))
+    }
+
+    /// Get the [ChainPoint] of the highest block with an entry in the transactions index.
+    pub async fn get_index_highest_chain_point(&self) -> StdResult<Option<ChainPoint>> {
+        let highest_indexed_block = self
+            .connection_pool
+            .connection()?
+            .fetch_first(GetCardanoBlockQuery::with_highest_indexed_block_number())?;
+
+        Ok(highest_indexed_block.map(|record| {
+            ChainPoint::new(record.slot_number, record.block_number, record.block_hash)
+        }))
+    }
+
+    /// Retrieve the blocks block range root with the highest bounds in the database.
+    pub async fn retrieve_highest_cardano_block_range_root(
+        &self,
+    ) -> StdResult<Option<BlockRangeRootRecord>> {
+        self.connection_pool
+            .connection()?
+            .fetch_first(GetCardanoBlockRangeRootQuery::highest())
+    }
+
     /// Retrieve all the [CardanoTransaction] in database.
     pub async fn get_all(&self) -> StdResult<Vec<CardanoTransaction>> {
         let records = self
@@ -215,14 +272,109 @@ impl CardanoTransactionRepository {
         Ok(())
     }
 
+    /// Store the given blocks in the database.
+    ///
+    /// The storage is done in chunks to avoid exceeding sqlite binding limitations.
+    pub async fn store_blocks<T: Into<CardanoBlockRecord> + Clone>(
+        &self,
+        blocks: Vec<T>,
+    ) -> StdResult<()> {
+        const DB_TRANSACTION_SIZE: usize = 100000;
+        for blocks_in_db_transaction_chunk in blocks.chunks(DB_TRANSACTION_SIZE) {
+            let connection = self.connection_pool.connection()?;
+            let transaction = connection.begin_transaction()?;
+
+            // Chunk blocks to avoid an error when we exceed sqlite binding limitations
+            for blocks_in_chunk in blocks_in_db_transaction_chunk.chunks(100) {
+                let records: Vec<CardanoBlockRecord> =
+                    blocks_in_chunk.iter().cloned().map(Into::into).collect();
+                connection
+                    .apply(InsertCardanoBlockQuery::insert_many(records)?)
+                    .with_context(|| "CardanoTransactionRepository can not store blocks")?;
+            }
+
+            transaction.commit()?;
+        }
+        Ok(())
+    }
+
+    /// Store the given entries of the index of the transactions by output address and by
+    /// metadata label in the database.
+    ///
+    /// The storage is done in chunks to avoid exceeding sqlite binding limitations.
+    pub async fn store_transactions_index_entries<
+        T: Into<CardanoTransactionIndexRecord> + Clone,
+    >(
+        &self,
+        index_entries: Vec<T>,
+    ) -> StdResult<()> {
+        const DB_TRANSACTION_SIZE: usize = 100000;
+        for entries_in_db_transaction_chunk in index_entries.chunks(DB_TRANSACTION_SIZE) {
+            let connection = self.connection_pool.connection()?;
+            let transaction = connection.begin_transaction()?;
+
+            // Chunk entries to avoid an error when we exceed sqlite binding limitations
+            for entries_in_chunk in entries_in_db_transaction_chunk.chunks(100) {
+                let records: Vec<CardanoTransactionIndexRecord> =
+                    entries_in_chunk.iter().cloned().map(Into::into).collect();
+                connection
+                    .apply(InsertCardanoTransactionIndexQuery::insert_many(records)?)
+                    .with_context(|| {
+                        "CardanoTransactionRepository can not store transactions index entries"
+                    })?;
+            }
+
+            transaction.commit()?;
+        }
+        Ok(())
+    }
+
+    /// Get the [CardanoTransactionIndexRecord]s matching the given filter in the given block
+    /// ranges.
+    pub async fn get_transactions_index_entries_by_filter(
+        &self,
+        filter: &CardanoTransactionFilter,
+        block_ranges: Vec<BlockRange>,
+    ) -> StdResult<Vec<CardanoTransactionIndexRecord>> {
+        if block_ranges.is_empty() {
+            return Ok(vec![]);
+        }
+
+        self.connection_pool.connection()?.fetch_collect(
+            GetCardanoTransactionIndexQuery::by_filter_in_block_ranges(filter, block_ranges),
+        )
+    }
+
+    /// Return all the [CardanoBlockRecord]s in the database where block number is in the
+    /// given range.
+    pub async fn get_blocks_in_range_blocks(
+        &self,
+        range: Range<BlockNumber>,
+    ) -> StdResult<Vec<CardanoBlockRecord>> {
+        self.connection_pool
+            .connection()?
+            .fetch_collect(GetCardanoBlockQuery::between_blocks(range))
+    }
+
     /// Get the closest block number above a given slot number
+    ///
+    /// The stored blocks are used if any, otherwise the block number is deduced from the stored
+    /// transactions.
     pub async fn get_closest_block_number_above_slot_number(
         &self,
         slot_number: SlotNumber,
     ) -> StdResult<Option<BlockNumber>> {
+        let connection = self.connection_pool.connection()?;
+        let block_record = connection.fetch_first(
+            GetCardanoBlockQuery::with_highest_block_number_below_slot_number(slot_number),
+        )?;
+        if let Some(block_record) = block_record {
+            return Ok(Some(block_record.block_number));
+        }
+
         let query =
             GetCardanoTransactionQuery::with_highest_block_number_below_slot_number(slot_number);
-        let record = self.connection_pool.connection()?.fetch_first(query)?;
+        let record = connection.fetch_first(query)?;
 
         Ok(record.map(|r| r.block_number))
     }
@@ -257,26 +409,35 @@ impl CardanoTransactionRepository {
         Ok(transactions)
     }
 
-    /// Prune the transactions older than the given number of blocks (based on the block range root
-    /// stored).
+    /// Prune the transactions, blocks and transactions index entries older than the given number
+    /// of blocks (based on the block range root stored).
     pub async fn prune_transaction(&self, number_of_blocks_to_keep: BlockNumber) -> StdResult<()> {
         if let Some(highest_block_range_start) = self
             .get_highest_start_block_number_for_block_range_roots()
             .await?
         {
             let threshold = highest_block_range_start - number_of_blocks_to_keep;
-            let query = DeleteCardanoTransactionQuery::below_block_number_threshold(threshold)?;
 
             let connection = self.connection_pool.connection()?;
-            connection.fetch_first(query)?;
+            let transaction = connection.begin_transaction()?;
+            connection.fetch_first(DeleteCardanoTransactionQuery::below_block_number_threshold(
+                threshold,
+            )?)?;
+            connection.fetch_first(DeleteCardanoBlockQuery::below_block_number_threshold(
+                threshold,
+            )?)?;
+            connection.fetch_first(
+                DeleteCardanoTransactionIndexQuery::below_block_number_threshold(threshold)?,
+            )?;
+            transaction.commit()?;
         }
 
         Ok(())
     }
 
-    /// Remove transactions and block range roots that are in a rolled-back fork
+    /// Remove transactions, blocks and block range roots that are in a rolled-back fork
     ///
-    /// * Remove transactions with block number strictly greater than the given block number
+    /// * Remove transactions, blocks and transactions index entries with block number strictly greater than the given block number
     /// * Remove block range roots that have lower bound range strictly above the given block number
     pub async fn remove_rolled_back_transactions_and_block_range_by_block_number(
         &self,
@@ -286,18 +447,26 @@ impl CardanoTransactionRepository {
         let transaction = connection.begin_transaction()?;
         let query = DeleteCardanoTransactionQuery::above_block_number_threshold(block_number)?;
         connection.fetch_first(query)?;
+        let query = DeleteCardanoBlockQuery::above_block_number_threshold(block_number)?;
+        connection.fetch_first(query)?;
+        let query = DeleteCardanoTransactionIndexQuery::above_block_number_threshold(block_number)?;
+        connection.fetch_first(query)?;
 
         let query =
             DeleteBlockRangeRootQuery::contains_or_above_block_number_threshold(block_number)?;
         connection.fetch_first(query)?;
+        let query = DeleteCardanoBlockRangeRootQuery::contains_or_above_block_number_threshold(
+            block_number,
+        )?;
+        connection.fetch_first(query)?;
         transaction.commit()?;
 
         Ok(())
     }
 
-    /// Remove transactions and block range roots that are in a rolled-back fork
+    /// Remove transactions, blocks and block range roots that are in a rolled-back fork
     ///
-    /// * Remove transactions with closest block number strictly greater than the given slot number if exists
+    /// * Remove transactions and blocks with closest block number strictly greater than the given slot number if exists
     /// * Remove block range roots that have lower bound range strictly above the aforementioned block number
     pub async fn remove_rolled_back_transactions_and_block_range_by_slot_number(
         &self,
@@ -327,6 +496,7 @@ impl<S: MKTreeStorer> BlockRangeRootRetriever<S> for CardanoTransactionRepositor
 
 #[cfg(test)]
 mod tests {
+    use mithril_common::entities::CardanoBlock;
     use mithril_common::test_utils::CardanoTransactionsBuilder;
 
     use crate::database::query::GetBlockRangeRootQuery;
@@ -720,6 +890,111 @@ mod tests {
         );
     }
 
+    #[tokio::test]
+    async fn repository_get_block_highest_chain_point() {
+        let connection = cardano_tx_db_connection().unwrap();
+        let repository = CardanoTransactionRepository::new(Arc::new(
+            SqliteConnectionPool::build_from_connection(connection),
+        ));
+
+        let highest_beacon = repository.get_block_highest_chain_point().await.unwrap();
+        assert_eq!(None, highest_beacon);
+
+        repository
+            .store_blocks(vec![
+                CardanoBlock::new("block-hash-10", BlockNumber(10), SlotNumber(50)),
+                CardanoBlock::new("block-hash-25", BlockNumber(25), SlotNumber(51)),
+            ])
+            .await
+            .unwrap();
+
+        let highest_beacon = repository.get_block_highest_chain_point().await.unwrap();
+        assert_eq!(
+            Some(ChainPoint {
+                slot_number: SlotNumber(51),
+                block_number: BlockNumber(25),
+                block_hash: "block-hash-25".to_string()
+            }),
+            highest_beacon
+        );
+    }
+
+    #[tokio::test]
+    async fn repository_get_index_highest_chain_point() {
+        let connection = cardano_tx_db_connection().unwrap();
+        let repository = CardanoTransactionRepository::new(Arc::new(
+            SqliteConnectionPool::build_from_connection(connection),
+        ));
+        repository
+            .store_blocks(vec![
+                CardanoBlock::new("block-hash-10", BlockNumber(10), SlotNumber(50)),
+                CardanoBlock::new("block-hash-25", BlockNumber(25), SlotNumber(51)),
+            ])
+            .await
+            .unwrap();
+
+        let highest_beacon = repository.get_index_highest_chain_point().await.unwrap();
+        assert_eq!(None, highest_beacon);
+
+        repository
+            .store_transactions_index_entries(vec![CardanoTransactionIndexRecord::new(
+                CardanoTransactionFilter::MetadataLabel(674),
+                "tx-hash-10",
+                BlockNumber(10),
+                SlotNumber(50),
+            )])
+            .await
+            .unwrap();
+
+        let highest_beacon = repository.get_index_highest_chain_point().await.unwrap();
+        assert_eq!(
+            Some(ChainPoint {
+                slot_number: SlotNumber(50),
+                block_number: BlockNumber(10),
+                block_hash: "block-hash-10".to_string()
+            }),
+            highest_beacon
+        );
+    }
+
+    #[tokio::test]
+    async fn repository_retrieve_highest_cardano_block_range_root() {
+        let connection = cardano_tx_db_connection().unwrap();
+        let repository = CardanoTransactionRepository::new(Arc::new(
+            SqliteConnectionPool::build_from_connection(connection),
+        ));
+
+        let highest_block_range_root = repository
+            .retrieve_highest_cardano_block_range_root()
+            .await
+            .unwrap();
+        assert_eq!(None, highest_block_range_root);
+
+        let block_range_roots = vec![
+            (
+                BlockRange::from_block_number(BlockNumber(15)),
+                MKTreeNode::from_hex("AAAA").unwrap(),
+            ),
+            (
+                BlockRange::from_block_number(BlockNumber(30)),
+                MKTreeNode::from_hex("BBBB").unwrap(),
+            ),
+        ];
+        repository
+            .create_cardano_block_range_roots(block_range_roots.clone())
+            .await
+            .unwrap();
+
+        let highest_block_range_root = repository
+            .retrieve_highest_cardano_block_range_root()
+            .await
+            .unwrap();
+        assert_eq!(
+            Some(BlockRangeRootRecord::from(block_range_roots[1].clone())),
+            highest_block_range_root
+        );
+    }
+
     #[tokio::test]
     async fn repository_get_transaction_highest_chain_point_with_transactions_with_same_block_number_in_db(
     ) {
@@ -1150,6 +1425,129 @@ mod tests {
         assert_eq!(28, transaction_result.len());
     }
 
+    #[tokio::test]
+    async fn repository_store_and_get_transactions_index_entries_by_filter() {
+        let repository = CardanoTransactionRepository::new(Arc::new(
+            SqliteConnectionPool::build(1, cardano_tx_db_connection).unwrap(),
+        ));
+        let address = CardanoTransactionFilter::Address("addr-1".to_string());
+        let index_records = vec![
+            CardanoTransactionIndexRecord::new(
+                address.clone(),
+                "tx-hash-1",
+                BlockNumber(10),
+                SlotNumber(50),
+            ),
+            CardanoTransactionIndexRecord::new(
+                CardanoTransactionFilter::MetadataLabel(674),
+                "tx-hash-1",
+                BlockNumber(10),
+                SlotNumber(50),
+            ),
+            CardanoTransactionIndexRecord::new(
+                address.clone(),
+                "tx-hash-2",
+                BlockRange::LENGTH + 2,
+                SlotNumber(150),
+            ),
+        ];
+
+        repository
+            .store_transactions_index_entries(index_records.clone())
+            .await
+            .unwrap();
+
+        let found = repository
+            .get_transactions_index_entries_by_filter(
+                &address,
+                vec![
+                    BlockRange::from_block_number(BlockNumber(0)),
+                    BlockRange::from_block_number(BlockRange::LENGTH),
+                ],
+            )
+            .await
+            .unwrap();
+        assert_eq!(
+            vec![index_records[0].clone(), index_records[2].clone()],
+            found
+        );
+
+        let found = repository
+            .get_transactions_index_entries_by_filter(&address, vec![])
+            .await
+            .unwrap();
+        assert_eq!(Vec::<CardanoTransactionIndexRecord>::new(), found);
+    }
+
+    #[tokio::test]
+    async fn repository_prune_and_rollback_transactions_index_entries() {
+        let repository = CardanoTransactionRepository::new(Arc::new(
+            SqliteConnectionPool::build(1, cardano_tx_db_connection).unwrap(),
+        ));
+        let address = CardanoTransactionFilter::Address("addr-1".to_string());
+        let all_block_ranges = vec![
+            BlockRange::from_block_number(BlockNumber(0)),
+            BlockRange::from_block_number(BlockRange::LENGTH),
+            BlockRange::from_block_number(BlockRange::LENGTH * 2),
+        ];
+        repository
+            .store_transactions_index_entries(vec![
+                CardanoTransactionIndexRecord::new(
+                    address.clone(),
+                    "tx-hash-1",
+                    BlockNumber(0),
+                    SlotNumber(50),
+                ),
+                CardanoTransactionIndexRecord::new(
+                    address.clone(),
+                    "tx-hash-2",
+                    BlockRange::LENGTH + 1,
+                    SlotNumber(150),
+                ),
+                CardanoTransactionIndexRecord::new(
+                    address.clone(),
+                    "tx-hash-3",
+                    BlockRange::LENGTH * 2 + 1,
+                    SlotNumber(250),
+                ),
+            ])
+            .await
+            .unwrap();
+        repository
+            .create_block_range_roots(vec![(
+                BlockRange::from_block_number(BlockRange::LENGTH),
+                MKTreeNode::from_hex("AAAA").unwrap(),
+            )])
+            .await
+            .unwrap();
+
+        repository
+            .prune_transaction(BlockRange::LENGTH - 1)
+            .await
+            .unwrap();
+        let remaining_hashes: Vec<TransactionHash> = repository
+            .get_transactions_index_entries_by_filter(&address, all_block_ranges.clone())
+            .await
+            .unwrap()
+            .into_iter()
+            .map(|r| r.transaction_hash)
+            .collect();
+        assert_eq!(vec!["tx-hash-2", "tx-hash-3"], remaining_hashes);
+
+        repository
+            .remove_rolled_back_transactions_and_block_range_by_block_number(BlockRange::LENGTH * 2)
+            .await
+            .unwrap();
+        let remaining_hashes: Vec<TransactionHash> = repository
+            .get_transactions_index_entries_by_filter(&address, all_block_ranges)
+            .await
+            .unwrap()
+            .into_iter()
+            .map(|r| r.transaction_hash)
+            .collect();
+        assert_eq!(vec!["tx-hash-2"], remaining_hashes);
+    }
+
     #[tokio::test]
     async fn get_highest_start_block_number_for_block_range_roots() {
         let connection = cardano_tx_db_connection().unwrap();
@@ -0,0 +1,728 @@
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+use anyhow::{anyhow, Context};
+use async_trait::async_trait;
+use slog::{info, warn, Logger};
+
+use mithril_common::digesters::cache::{
+    CacheProviderResult, ImmutableDigesterCacheGetError, ImmutableDigesterCacheStoreError,
+    ImmutableFileDigestCacheProvider,
+};
+use mithril_common::digesters::ImmutableFile;
+use mithril_common::entities::{HexEncodedDigest, ImmutableFileName};
+use mithril_common::logging::LoggerExtensions;
+use mithril_common::StdResult;
+
+use crate::database::query::{
+    DeleteImmutableFileDigestCacheQuery, GetImmutableFileDigestCacheQuery,
+    InsertImmutableFileDigestCacheQuery,
+};
+use crate::database::record::ImmutableFileDigestCacheRecord;
+use crate::database::{immutable_file_digest_cache_migration, ApplicationNodeType};
+use crate::sqlite::{
+    ConnectionBuilder, ConnectionExtensions, ConnectionOptions, SqliteConnectionPool,
+};
+
+/// A [ImmutableFileDigestCacheProvider] storing the digests in a SQLite database.
+///
+/// Only the given digests are written when storing, and the connection pool allows concurrent
+/// reads of the cache.
+pub struct SqliteImmutableFileDigestCacheProvider {
+    connection_pool: Arc<SqliteConnectionPool>,
+    logger: Logger,
+}
+
+impl SqliteImmutableFileDigestCacheProvider {
+    /// Maximum number of digests bound in a single query, to stay under sqlite binding limitations
+    const CHUNK_SIZE: usize = 100;
+
+    /// Instantiate service
+    pub fn new(connection_pool: Arc<SqliteConnectionPool>, logger: Logger) -> Self {
+        Self {
+            connection_pool,
+            logger: logger.new_with_component_name::<Self>(),
+        }
+    }
+
+    /// Store the given digests, replacing the digests already stored for the same files.
+    pub async fn store_digests(
+        &self,
+        digest_per_filenames: Vec<(ImmutableFileName, HexEncodedDigest)>,
+    ) -> StdResult<()> {
+        let connection = self.connection_pool.connection()?;
+        let transaction = connection.begin_transaction()?;
+
+        for digests_in_chunk in digest_per_filenames.chunks(Self::CHUNK_SIZE) {
+            let records = digests_in_chunk
+                .iter()
+                .map(|(filename, digest)| ImmutableFileDigestCacheRecord::new(filename, digest))
+                .collect();
+            connection.apply(InsertImmutableFileDigestCacheQuery::insert_many(records))?;
+        }
+
+        transaction.commit()?;
+        Ok(())
+    }
+
+    /// Return the stored digests of the given files.
+    pub async fn get_digests(
+        &self,
+        immutable_file_names: Vec<ImmutableFileName>,
+    ) -> StdResult<BTreeMap<ImmutableFileName, HexEncodedDigest>> {
+        let connection = self.connection_pool.connection()?;
+        let mut digests = BTreeMap::new();
+
+        for names_in_chunk in immutable_file_names.chunks(Self::CHUNK_SIZE) {
+            let records: Vec<ImmutableFileDigestCacheRecord> = connection.fetch_collect(
+                GetImmutableFileDigestCacheQuery::by_immutable_file_names(names_in_chunk.to_vec()),
+            )?;
+            digests.extend(
+                records
+                    .into_iter()
+                    .map(|record| (record.immutable_file_name, record.digest)),
+            );
+        }
+
+        Ok(digests)
+    }
+
+    /// Return all the stored digests.
+    pub async fn get_all_digests(
+        &self,
+    ) -> StdResult<BTreeMap<ImmutableFileName, HexEncodedDigest>> {
+        let records: Vec<ImmutableFileDigestCacheRecord> = self
+            .connection_pool
+            .connection()?
+            .fetch_collect(GetImmutableFileDigestCacheQuery::all())?;
+
+        Ok(records
+            .into_iter()
+            .map(|record| (record.immutable_file_name, record.digest))
+            .collect())
+    }
+
+    /// Delete all the stored digests.
+    pub async fn delete_all(&self) -> StdResult<()> {
+        self.connection_pool
+            .connection()?
+            .apply(DeleteImmutableFileDigestCacheQuery::all())
+    }
+
+    /// Check the integrity of the cache database.
+    ///
+    /// Fails if the database is corrupted, the malformed digests are removed so they are computed
+    /// again. Returns the number of removed digests.
+    pub async fn check_integrity(&self) -> StdResult<usize> {
+        let connection = self.connection_pool.connection()?;
+        let quick_check_result: String = connection
+            .query_single_cell("pragma quick_check", &[])
+            .with_context(|| "Failed to check the immutable file digests cache integrity")?;
+        if quick_check_result != "ok" {
+            return Err(anyhow!(
+                "Immutable file digests cache database is corrupted: {quick_check_result}"
+            ));
+        }
+
+        let removed_records = connection
+            .fetch(DeleteImmutableFileDigestCacheQuery::with_malformed_digest())?
+            .count();
+        if removed_records > 0 {
+            warn!(
+                self.logger,
+                "Removed {removed_records} malformed digests from the immutable file digests cache"
+            );
+        }
+
+        Ok(removed_records)
+    }
+
+    /// Import the digests of a JSON cache file, as written by the
+    /// [JsonImmutableFileDigestCacheProvider][mithril_common::digesters::cache::JsonImmutableFileDigestCacheProvider],
+    /// then remove the file.
+    ///
+    /// Returns the number of imported digests, nothing is done if the file does not exist.
+    /// A file that can't be deserialized is removed without importing anything.
+    pub async fn migrate_from_json_cache(&self, json_cache_file: &Path) -> StdResult<usize> {
+        if !json_cache_file.exists() {
+            return Ok(0);
+        }
+
+        let json_content = tokio::fs::read_to_string(json_cache_file)
+            .await
+            .with_context(|| {
+                format!(
+                    "Failed to read JSON digests cache file `{}`",
+                    json_cache_file.display()
+                )
+            })?;
+        let nb_digests = match serde_json::from_str::<BTreeMap<ImmutableFileName, HexEncodedDigest>>(
+            &json_content,
+        ) {
+            Ok(digests) => {
+                let nb_digests = digests.len();
+                self.store_digests(digests.into_iter().collect()).await?;
+                nb_digests
+            }
+            Err(error) => {
+                warn!(
+                    self.logger,
+                    "Skipped the migration of the corrupted JSON digests cache file";
+                    "json_cache_file" => json_cache_file.display(),
+                    "error" => ?error
+                );
+                0
+            }
+        };
+
+        self.remove_json_cache(json_cache_file).await?;
+        info!(
+            self.logger,
+            "Migrated {nb_digests} digests from the JSON digests cache file";
+            "json_cache_file" => json_cache_file.display()
+        );
+
+        Ok(nb_digests)
+    }
+
+    /// Remove a JSON cache file without importing its digests, nothing is done if the file does
+    /// not exist.
+    pub async fn remove_json_cache(&self, json_cache_file: &Path) -> StdResult<()> {
+        if json_cache_file.exists() {
+            tokio::fs::remove_file(json_cache_file)
+                .await
+                .with_context(|| {
+                    format!(
+                        "Failed to remove JSON digests cache file `{}`",
+                        json_cache_file.display()
+                    )
+                })?;
+        }
+
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl ImmutableFileDigestCacheProvider for SqliteImmutableFileDigestCacheProvider {
+    async fn store(
+        &self,
+        digest_per_filenames: Vec<(ImmutableFileName, HexEncodedDigest)>,
+    ) -> CacheProviderResult<()> {
+        self.store_digests(digest_per_filenames)
+            .await
+            .map_err(ImmutableDigesterCacheStoreError::StoreError)?;
+
+        Ok(())
+    }
+
+    async fn get(
+        &self,
+        immutables: Vec<ImmutableFile>,
+    ) -> CacheProviderResult<BTreeMap<ImmutableFile, Option<HexEncodedDigest>>> {
+        let mut digests = self
+            .get_digests(immutables.iter().map(|i| i.filename.clone()).collect())
+            .await
+            .map_err(ImmutableDigesterCacheGetError::StoreError)?;
+
+        Ok(immutables
+            .into_iter()
+            .map(|immutable| {
+                let digest = digests.remove(&immutable.filename);
+                (immutable, digest)
+            })
+            .collect())
+    }
+
+    async fn reset(&self) -> CacheProviderResult<()> {
+        self.delete_all()
+            .await
+            .map_err(ImmutableDigesterCacheStoreError::StoreError)?;
+
+        Ok(())
+    }
+}
+
+/// A [SqliteImmutableFileDigestCacheProvider] builder.
+pub struct SqliteImmutableFileDigestCacheProviderBuilder {
+    sqlite_file: PathBuf,
+    connection_pool_size: usize,
+    json_cache_file_to_migrate: Option<PathBuf>,
+    reset_digests_cache: bool,
+    node_type: ApplicationNodeType,
+    logger: Logger,
+}
+
+impl SqliteImmutableFileDigestCacheProviderBuilder {
+    /// [SqliteImmutableFileDigestCacheProviderBuilder] factory.
+    pub fn new(sqlite_file: &Path) -> Self {
+        Self {
+            sqlite_file: sqlite_file.to_path_buf(),
+            connection_pool_size: 1,
+            json_cache_file_to_migrate: None,
+            reset_digests_cache: false,
+            node_type: ApplicationNodeType::Signer,
+            logger: Logger::root(slog::Discard, slog::o!()),
+        }
+    }
+
+    /// Set the number of connections to the cache database, allowing as many concurrent reads.
+    pub fn with_connection_pool_size(mut self, connection_pool_size: usize) -> Self {
+        self.connection_pool_size = connection_pool_size.max(1);
+        self
+    }
+
+    /// Set a JSON cache file which digests must be imported in the SQLite cache.
+    pub fn with_json_cache_to_migrate(mut self, json_cache_file: &Path) -> Self {
+        self.json_cache_file_to_migrate = Some(json_cache_file.to_path_buf());
+        self
+    }
+
+    /// Set if existing cached values in the provider must be reset.
+    pub fn should_reset_digests_cache(mut self, should_reset: bool) -> Self {
+        self.reset_digests_cache = should_reset;
+        self
+    }
+
+    /// Set the node type (default: [ApplicationNodeType::Signer]).
+    pub fn with_node_type(mut self, node_type: ApplicationNodeType) -> Self {
+        self.node_type = node_type;
+        self
+    }
+
+    /// Set the [Logger] to use.
+    pub fn with_logger(mut self, logger: Logger) -> Self {
+        self.logger = logger;
+        self
+    }
+
+    /// Build a [SqliteImmutableFileDigestCacheProvider] based on the parameters previously set.
+    pub async fn build(self) -> StdResult<SqliteImmutableFileDigestCacheProvider> {
+        let connection_builder = || {
+            ConnectionBuilder::open_file(&self.sqlite_file)
+                .with_node_type(self.node_type.clone())
+                .with_options(&[ConnectionOptions::EnableWriteAheadLog])
+                .with_logger(self.logger.clone())
+        };
+        // Apply the migrations once before opening the pooled connections
+        let _connection = connection_builder()
+            .with_migrations(immutable_file_digest_cache_migration::get_migrations())
+            .build()
+            .with_context(|| {
+                format!(
+                    "Failed to open immutable file digests cache database `{}`",
+                    self.sqlite_file.display()
+                )
+            })?;
+        let connection_pool = Arc::new(SqliteConnectionPool::build(
+            self.connection_pool_size,
+            || connection_builder().build(),
+        )?);
+        let cache_provider =
+            SqliteImmutableFileDigestCacheProvider::new(connection_pool, self.logger.clone());
+
+        if self.reset_digests_cache {
+            cache_provider.reset().await.with_context(|| {
+                format!(
+                    "Failure when resetting digests cache database `{}`",
+                    self.sqlite_file.display()
+                )
+            })?;
+            // The digests of the JSON cache are discarded too, as they would be reset anyway
+            if let Some(json_cache_file) = &self.json_cache_file_to_migrate {
+                cache_provider.remove_json_cache(json_cache_file).await?;
+            }
+        } else {
+            cache_provider.check_integrity().await?;
+            if let Some(json_cache_file) = &self.json_cache_file_to_migrate {
+                cache_provider
+                    .migrate_from_json_cache(json_cache_file)
+                    .await?;
+            }
+        }
+
+        info!(
+            self.logger,
+            "Storing/Getting immutables digests cache from: {}",
+            self.sqlite_file.display()
+        );
+
+        Ok(cache_provider)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use mithril_common::test_utils::TempDir;
+
+    use crate::database::test_helper::immutable_file_digest_cache_db_connection;
+
+    use super::*;
+
+    fn provider_for_test() -> SqliteImmutableFileDigestCacheProvider {
+        let connection = immutable_file_digest_cache_db_connection().unwrap();
+
+        SqliteImmutableFileDigestCacheProvider::new(
+            Arc::new(SqliteConnectionPool::build_from_connection(connection)),
+            Logger::root(slog::Discard, slog::o!()),
+        )
+    }
+
+    fn immutable(filename: &str) -> ImmutableFile {
+        ImmutableFile {
+            path: PathBuf::from(filename),
+            number: 1,
+            filename: filename.to_string(),
+        }
+    }
+
+    fn digest(seed: &str) -> HexEncodedDigest {
+        seed.repeat(64 / seed.len())
+    }
+
+    #[tokio::test]
+    async fn get_after_store_return_stored_digests_and_none_for_unknown_files() {
+        let provider = provider_for_test();
+        provider
+            .store(vec![
+                ("00001.chunk".to_string(), digest("a1")),
+                ("00001.primary".to_string(), digest("b2")),
+            ])
+            .await
+            .unwrap();
+
+        let result = provider
+            .get(vec![
+                immutable("00001.chunk"),
+                immutable("00001.primary"),
+                immutable("00001.secondary"),
+            ])
+            .await
+            .unwrap();
+
+        assert_eq!(
+            BTreeMap::from([
+                (immutable("00001.chunk"), Some(digest("a1"))),
+                (immutable("00001.primary"), Some(digest("b2"))),
+                (immutable("00001.secondary"), None),
+            ]),
+            result
+        );
+    }
+
+    #[tokio::test]
+    async fn store_only_add_or_replace_the_given_digests() {
+        let provider = provider_for_test();
+        provider
+            .store(vec![
+                ("00001.chunk".to_string(), digest("a1")),
+                ("00001.primary".to_string(), digest("b2")),
+            ])
+            .await
+            .unwrap();
+
+        provider
+            .store(vec![
+                ("00001.primary".to_string(), digest("c3")),
+                ("00001.secondary".to_string(), digest("d4")),
+            ])
+            .await
+            .unwrap();
+
+        let result = provider
+            .get_digests(vec![
+                "00001.chunk".to_string(),
+                "00001.primary".to_string(),
+                "00001.secondary".to_string(),
+            ])
+            .await
+            .unwrap();
+        assert_eq!(
+            BTreeMap::from([
+                ("00001.chunk".to_string(), digest("a1")),
+                ("00001.primary".to_string(), digest("c3")),
+                ("00001.secondary".to_string(), digest("d4")),
+            ]),
+            result
+        );
+    }
+
+    #[tokio::test]
+    async fn store_and_get_more_digests_than_the_chunk_size() {
+        let provider = provider_for_test();
+        let digests: Vec<(ImmutableFileName, HexEncodedDigest)> = (0..250)
+            .map(|number| (format!("{number:05}.chunk"), digest("e5")))
+            .collect();
+
+        provider.store(digests.clone()).await.unwrap();
+
+        let result = provider
+            .get_digests(digests.iter().map(|(name, _)| name.clone()).collect())
+            .await
+            .unwrap();
+        assert_eq!(BTreeMap::from_iter(digests), result);
+    }
+
+    #[tokio::test]
+    async fn reset_remove_all_digests() {
+        let provider = provider_for_test();
+        provider
+            .store(vec![("00001.chunk".to_string(), digest("a1"))])
+            .await
+            .unwrap();
+
+        provider.reset().await.unwrap();
+
+        let result = provider.get(vec![immutable("00001.chunk")]).await.unwrap();
+        assert_eq!(BTreeMap::from([(immutable("00001.chunk"), None)]), result);
+    }
+
+    #[tokio::test]
+    async fn check_integrity_remove_malformed_digests() {
+        let provider = provider_for_test();
+        provider
+            .store(vec![
+                ("00001.chunk".to_string(), digest("a1")),
+                ("00001.primary".to_string(), "not-a-digest".to_string()),
+            ])
+            .await
+            .unwrap();
+
+        let removed = provider.check_integrity().await.unwrap();
+
+        assert_eq!(1, removed);
+        let result = provider
+            .get_digests(vec!["00001.chunk".to_string(), "00001.primary".to_string()])
+            .await
+            .unwrap();
+        assert_eq!(
+            BTreeMap::from([("00001.chunk".to_string(), digest("a1"))]),
+            result
+        );
+    }
+
+    #[tokio::test]
+    async fn migrate_from_json_cache_import_digests_and_remove_the_json_file() {
+        let dir = TempDir::create("sqlite_digests_cache_provider", "migrate_from_json_cache");
+        let json_cache_file = dir.join("immutables_digests.json");
+        let json_digests = BTreeMap::from([
+            ("00001.chunk".to_string(), digest("a1")),
+            ("00001.primary".to_string(), digest("b2")),
+        ]);
+        std::fs::write(
+            &json_cache_file,
+            serde_json::to_string(&json_digests).unwrap(),
+        )
+        .unwrap();
+        let provider = provider_for_test();
+
+        let imported = provider
+            .migrate_from_json_cache(&json_cache_file)
+            .await
+            .unwrap();
+
+        assert_eq!(2, imported);
+        assert!(!json_cache_file.exists());
+        let result = provider
+            .get_digests(json_digests.keys().cloned().collect())
+            .await
+            .unwrap();
+        assert_eq!(json_digests, result);
+    }
+
+    #[tokio::test]
+    async fn migrate_from_json_cache_does_nothing_if_the_json_file_does_not_exist() {
+        let dir = TempDir::create(
+            "sqlite_digests_cache_provider",
+            "migrate_without_json_cache",
+        );
+        let provider = provider_for_test();
+
+        let imported = provider
+            .migrate_from_json_cache(&dir.join("immutables_digests.json"))
+            .await
+            .unwrap();
+
+        assert_eq!(0, imported);
+    }
+
+    #[tokio::test]
+    async fn migrate_from_json_cache_skip_and_remove_a_corrupted_json_file() {
+        let dir = TempDir::create(
+            "sqlite_digests_cache_provider",
+            "migrate_corrupted_json_cache",
+        );
+        let json_cache_file = dir.join("immutables_digests.json");
+        std::fs::write(&json_cache_file, "{\"00001.chunk\": ").unwrap();
+        let provider = provider_for_test();
+
+        let imported = provider
+            .migrate_from_json_cache(&json_cache_file)
+            .await
+            .unwrap();
+
+        assert_eq!(0, imported);
+        assert!(!json_cache_file.exists());
+        assert_eq!(BTreeMap::new(), provider.get_all_digests().await.unwrap());
+    }
+
+    #[tokio::test]
+    async fn get_all_digests_return_all_stored_digests() {
+        let provider = provider_for_test();
+        let digests = BTreeMap::from([
+            ("00001.chunk".to_string(), digest("a1")),
+            ("00001.primary".to_string(), digest("b2")),
+        ]);
+        provider
+            .store(digests.clone().into_iter().collect())
+            .await
+            .unwrap();
+
+        let result = provider.get_all_digests().await.unwrap();
+
+        assert_eq!(digests, result);
+    }
+
+    mod builder {
+        use super::*;
+
+        #[tokio::test]
+        async fn build_with_reset_discard_the_json_cache_without_importing_it() {
+            let dir = TempDir::create(
+                "sqlite_digests_cache_provider",
+                "build_with_reset_and_json_cache",
+            );
+            let json_cache_file = dir.join("immutables_digests.json");
+            std::fs::write(
+                &json_cache_file,
+                serde_json::to_string(&BTreeMap::from([("00001.chunk".to_string(), digest("a1"))]))
+                    .unwrap(),
+            )
+            .unwrap();
+
+            let provider =
+                SqliteImmutableFileDigestCacheProviderBuilder::new(&dir.join("cache.sqlite3"))
+                    .with_json_cache_to_migrate(&json_cache_file)
+                    .should_reset_digests_cache(true)
+                    .build()
+                    .await
+                    .unwrap();
+
+            assert!(!json_cache_file.exists());
+            assert_eq!(BTreeMap::new(), provider.get_all_digests().await.unwrap());
+        }
+
+        #[tokio::test]
+        async fn build_with_a_connection_pool_sharing_the_same_database() {
+            let dir = TempDir::create("sqlite_digests_cache_provider", "build_with_pool");
+            let provider =
+                SqliteImmutableFileDigestCacheProviderBuilder::new(&dir.join("cache.sqlite3"))
+                    .with_connection_pool_size(3)
+                    .build()
+                    .await
+                    .unwrap();
+
+            provider
+                .store(vec![("00001.chunk".to_string(), digest("a1"))])
+                .await
+                .unwrap();
+
+            let connections = [
+                provider.connection_pool.connection().unwrap(),
+                provider.connection_pool.connection().unwrap(),
+                provider.connection_pool.connection().unwrap(),
+            ];
+            for connection in &connections {
+                let records: Vec<ImmutableFileDigestCacheRecord> = connection
+                    .fetch_collect(GetImmutableFileDigestCacheQuery::all())
+                    .unwrap();
+                assert_eq!(
+                    vec![ImmutableFileDigestCacheRecord::new(
+                        "00001.chunk",
+                        digest("a1")
+                    )],
+                    records
+                );
+            }
+        }
+
+        #[tokio::test]
+        async fn build_keep_digests_stored_by_a_previous_provider() {
+            let dir = TempDir::create("sqlite_digests_cache_provider", "build_keep_digests");
+            let sqlite_file = dir.join("cache.sqlite3");
+            {
+                let provider = SqliteImmutableFileDigestCacheProviderBuilder::new(&sqlite_file)
+                    .build()
+                    .await
+                    .unwrap();
+                provider
+                    .store(vec![("00001.chunk".to_string(), digest("a1"))])
+                    .await
+                    .unwrap();
+            }
+
+            let provider = SqliteImmutableFileDigestCacheProviderBuilder::new(&sqlite_file)
+                .build()
+                .await
+                .unwrap();
+
+            let result = provider.get(vec![immutable("00001.chunk")]).await.unwrap();
+            assert_eq!(
+                BTreeMap::from([(immutable("00001.chunk"), Some(digest("a1")))]),
+                result
+            );
+        }
+
+        #[tokio::test]
+        async fn build_with_reset_remove_stored_digests() {
+            let dir = TempDir::create("sqlite_digests_cache_provider", "build_with_reset");
+            let sqlite_file = dir.join("cache.sqlite3");
+            {
+                let provider = SqliteImmutableFileDigestCacheProviderBuilder::new(&sqlite_file)
+                    .build()
+                    .await
+                    .unwrap();
+                provider
+                    .store(vec![("00001.chunk".to_string(), digest("a1"))])
+                    .await
+                    .unwrap();
+            }
+
+            let provider = SqliteImmutableFileDigestCacheProviderBuilder::new(&sqlite_file)
+                .should_reset_digests_cache(true)
+                .build()
+                .await
+                .unwrap();
+
+            let result = provider.get(vec![immutable("00001.chunk")]).await.unwrap();
+            assert_eq!(BTreeMap::from([(immutable("00001.chunk"), None)]), result);
+        }
+
+        #[tokio::test]
+        async fn build_migrate_the_given_json_cache() {
+            let dir = TempDir::create("sqlite_digests_cache_provider", "build_migrate_json");
+            let json_cache_file = dir.join("immutables_digests.json");
+            std::fs::write(
+                &json_cache_file,
+                serde_json::to_string(&BTreeMap::from([("00001.chunk".to_string(), digest("a1"))]))
+                    .unwrap(),
+            )
+            .unwrap();
+
+            let provider =
+                SqliteImmutableFileDigestCacheProviderBuilder::new(&dir.join("cache.sqlite3"))
+                    .with_json_cache_to_migrate(&json_cache_file)
+                    .build()
+                    .await
+                    .unwrap();
+
+            let result = provider.get(vec![immutable("00001.chunk")]).await.unwrap();
+            assert_eq!(
+                BTreeMap::from([(immutable("00001.chunk"), Some(digest("a1")))]),
+                result
+            );
+            assert!(!json_cache_file.exists());
+        }
+    }
+}
@@ -1,4 +1,8 @@
 //! Shared database repositories
+mod cardano_block_repository;
 mod cardano_transaction_repository;
+mod immutable_file_digest_cache_provider;
 
+pub use cardano_block_repository::*;
 pub use cardano_transaction_repository::*;
+pub use immutable_file_digest_cache_provider::*;
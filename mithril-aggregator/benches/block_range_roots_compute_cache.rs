@@ -0,0 +1,95 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
+use slog::Logger;
+
+use mithril_aggregator::services::BlockRangeRootsMerkleMapPool;
+use mithril_common::crypto_helper::{
+    MKTreeNode, MKTreeStoreInMemory, MKTreeStoreSqlite, MKTreeStorer,
+};
+use mithril_common::entities::{BlockNumber, BlockRange};
+use mithril_common::signable_builder::BlockRangeRootRetriever;
+use mithril_common::StdResult;
+
+/// Number of block range roots of a Cardano mainnet with ~11.5M blocks
+const MAINNET_TOTAL_BLOCK_RANGE_ROOTS: u64 = 770_000;
+
+/// Size of the Merkle maps pool of the provers
+const MK_MAP_POOL_SIZE: usize = 3;
+
+struct GeneratedBlockRangeRootRetriever {
+    block_range_roots: Vec<(BlockRange, MKTreeNode)>,
+}
+
+impl GeneratedBlockRangeRootRetriever {
+    fn new(total_block_range_roots: u64) -> Self {
+        let block_range_roots = (0..total_block_range_roots)
+            .map(|i| {
+                (
+                    BlockRange::from_block_number(BlockNumber(i * *BlockRange::LENGTH)),
+                    MKTreeNode::from(format!("block_range_root-{i}")),
+                )
+            })
+            .collect();
+
+        Self { block_range_roots }
+    }
+}
+
+#[async_trait]
+impl<S: MKTreeStorer> BlockRangeRootRetriever<S> for GeneratedBlockRangeRootRetriever {
+    async fn retrieve_block_range_roots<'a>(
+        &'a self,
+        up_to_beacon: BlockNumber,
+    ) -> StdResult<Box<dyn Iterator<Item = (BlockRange, MKTreeNode)> + 'a>> {
+        let total_block_ranges = (*up_to_beacon / *BlockRange::LENGTH) as usize;
+
+        Ok(Box::new(
+            self.block_range_roots
+                .iter()
+                .take(total_block_ranges)
+                .cloned(),
+        ))
+    }
+}
+
+fn run_bench<S: MKTreeStorer + 'static>(c: &mut Criterion, store_name: &str) {
+    let runtime = tokio::runtime::Runtime::new().unwrap();
+    let retriever = Arc::new(GeneratedBlockRangeRootRetriever::new(
+        MAINNET_TOTAL_BLOCK_RANGE_ROOTS,
+    ));
+
+    let mut group = c.benchmark_group(format!("Compute cache - {store_name}"));
+    for total_block_range_roots in [10_000, 100_000, MAINNET_TOTAL_BLOCK_RANGE_ROOTS] {
+        let up_to = BlockNumber(total_block_range_roots * *BlockRange::LENGTH);
+        let pool = BlockRangeRootsMerkleMapPool::<S>::new(
+            retriever.clone(),
+            MK_MAP_POOL_SIZE,
+            Logger::root(slog::Discard, slog::o!()),
+        );
+        group.bench_with_input(
+            BenchmarkId::from_parameter(format!(
+                "compute_cache({total_block_range_roots} block range roots)"
+            )),
+            &up_to,
+            |b, &up_to| {
+                b.to_async(&runtime)
+                    .iter(|| async { pool.compute_cache(up_to).await.unwrap() });
+            },
+        );
+    }
+    group.finish();
+}
+
+fn bench_compute_cache(c: &mut Criterion) {
+    run_bench::<MKTreeStoreInMemory>(c, "in memory store");
+    run_bench::<MKTreeStoreSqlite>(c, "sqlite store");
+}
+
+criterion_group! {
+    name = benches;
+    config = Criterion::default().sample_size(10);
+    targets = bench_compute_cache
+}
+criterion_main!(benches);
@@ -0,0 +1,144 @@
+use anyhow::{anyhow, Context};
+use async_trait::async_trait;
+use std::sync::Arc;
+
+use mithril_common::{
+    entities::{CardanoProtocolParameters, Certificate, Epoch, ProtocolMessagePartKey},
+    signable_builder::CardanoProtocolParametersRetriever,
+    StdResult,
+};
+
+use crate::ArtifactBuilder;
+
+/// A [CardanoProtocolParameters] artifact builder
+pub struct CardanoProtocolParametersArtifactBuilder {
+    protocol_parameters_retriever: Arc<dyn CardanoProtocolParametersRetriever>,
+}
+
+impl CardanoProtocolParametersArtifactBuilder {
+    /// CardanoProtocolParameters artifact builder factory
+    pub fn new(protocol_parameters_retriever: Arc<dyn CardanoProtocolParametersRetriever>) -> Self {
+        Self {
+            protocol_parameters_retriever,
+        }
+    }
+}
+
+#[async_trait]
+impl ArtifactBuilder<Epoch, CardanoProtocolParameters>
+    for CardanoProtocolParametersArtifactBuilder
+{
+    async fn compute_artifact(
+        &self,
+        epoch: Epoch,
+        certificate: &Certificate,
+    ) -> StdResult<CardanoProtocolParameters> {
+        let protocol_parameters = self
+            .protocol_parameters_retriever
+            .retrieve(epoch)
+            .await?
+            .ok_or_else(|| anyhow!("No protocol parameters found for epoch '{}'", epoch))?;
+        let cardano_protocol_parameters =
+            CardanoProtocolParameters::new(epoch, protocol_parameters);
+
+        let certified_hash = certificate
+            .protocol_message
+            .get_message_part(&ProtocolMessagePartKey::CardanoProtocolParametersHash)
+            .with_context(|| {
+                format!(
+                    "Can not find CardanoProtocolParametersHash protocol message part in certificate '{}'",
+                    certificate.hash
+                )
+            })?;
+        if certified_hash != &cardano_protocol_parameters.hash {
+            return Err(anyhow!(
+                "The protocol parameters hash '{}' of epoch '{}' does not match the certified hash '{}'",
+                cardano_protocol_parameters.hash,
+                epoch,
+                certified_hash
+            ));
+        }
+
+        Ok(cardano_protocol_parameters)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use mithril_common::{entities::LedgerProtocolParameters, test_utils::fake_data};
+    use mockall::{mock, predicate::eq};
+
+    use super::*;
+
+    mock! {
+        pub CardanoProtocolParametersRetrieverImpl {}
+
+        #[async_trait]
+        impl CardanoProtocolParametersRetriever for CardanoProtocolParametersRetrieverImpl {
+            async fn retrieve(&self, epoch: Epoch) -> StdResult<Option<LedgerProtocolParameters>>;
+        }
+    }
+
+    fn certificate_with_protocol_parameters_hash(hash: &str) -> Certificate {
+        let mut certificate = fake_data::certificate("whatever".to_string());
+        certificate.protocol_message.set_message_part(
+            ProtocolMessagePartKey::CardanoProtocolParametersHash,
+            hash.to_string(),
+        );
+
+        certificate
+    }
+
+    #[tokio::test]
+    async fn compute_artifact_returns_valid_artifact() {
+        let epoch = Epoch(4);
+        let protocol_parameters = LedgerProtocolParameters::dummy();
+        let expected = CardanoProtocolParameters::new(epoch, protocol_parameters.clone());
+        let certificate = certificate_with_protocol_parameters_hash(&expected.hash);
+        let mut mock_retriever = MockCardanoProtocolParametersRetrieverImpl::new();
+        mock_retriever
+            .expect_retrieve()
+            .with(eq(epoch))
+            .return_once(move |_| Ok(Some(protocol_parameters)));
+        let builder = CardanoProtocolParametersArtifactBuilder::new(Arc::new(mock_retriever));
+
+        let cardano_protocol_parameters =
+            builder.compute_artifact(epoch, &certificate).await.unwrap();
+
+        assert_eq!(expected, cardano_protocol_parameters);
+    }
+
+    #[tokio::test]
+    async fn compute_artifact_returns_error_if_no_protocol_parameters_found_for_epoch() {
+        let epoch = Epoch(4);
+        let certificate = certificate_with_protocol_parameters_hash("whatever");
+        let mut mock_retriever = MockCardanoProtocolParametersRetrieverImpl::new();
+        mock_retriever
+            .expect_retrieve()
+            .with(eq(epoch))
+            .return_once(move |_| Ok(None));
+        let builder = CardanoProtocolParametersArtifactBuilder::new(Arc::new(mock_retriever));
+
+        builder
+            .compute_artifact(epoch, &certificate)
+            .await
+            .expect_err("Should return error");
+    }
+
+    #[tokio::test]
+    async fn compute_artifact_returns_error_if_protocol_parameters_do_not_match_certified_hash() {
+        let epoch = Epoch(4);
+        let certificate = certificate_with_protocol_parameters_hash("another-hash");
+        let mut mock_retriever = MockCardanoProtocolParametersRetrieverImpl::new();
+        mock_retriever
+            .expect_retrieve()
+            .with(eq(epoch))
+            .return_once(move |_| Ok(Some(LedgerProtocolParameters::dummy())));
+        let builder = CardanoProtocolParametersArtifactBuilder::new(Arc::new(mock_retriever));
+
+        builder
+            .compute_artifact(epoch, &certificate)
+            .await
+            .expect_err("Should return error");
+    }
+}
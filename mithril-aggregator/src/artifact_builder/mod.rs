@@ -1,15 +1,19 @@
 //! The module used for building artifact
+mod cardano_blocks;
 mod cardano_database;
 mod cardano_database_artifacts;
 mod cardano_immutable_files_full;
+mod cardano_protocol_parameters;
 mod cardano_stake_distribution;
 mod cardano_transactions;
 mod interface;
 mod mithril_stake_distribution;
 
+pub use cardano_blocks::*;
 pub use cardano_database::*;
 pub use cardano_database_artifacts::*;
 pub use cardano_immutable_files_full::*;
+pub use cardano_protocol_parameters::*;
 pub use cardano_stake_distribution::*;
 pub use cardano_transactions::*;
 pub use interface::*;
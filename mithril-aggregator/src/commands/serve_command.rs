@@ -23,8 +23,8 @@ use mithril_doc::{Documenter, DocumenterDefault, StructDoc};
 use mithril_metric::MetricsServer;
 
 use crate::{
-    dependency_injection::DependenciesBuilder, tools::VacuumTracker, DefaultConfiguration,
-    ServeCommandConfiguration,
+    dependency_injection::DependenciesBuilder, tools::VacuumTracker, ConfigurationSource,
+    DefaultConfiguration, ServeCommandConfiguration,
 };
 
 const VACUUM_MINIMUM_INTERVAL: TimeDelta = TimeDelta::weeks(1);
@@ -129,6 +129,9 @@ impl ServeCommand {
             .try_deserialize()
             .with_context(|| "configuration deserialize error")?;
         debug!(root_logger, "SERVE command"; "config" => format!("{config:?}"));
+        config
+            .check_protocol_parameters_security()
+            .with_context(|| "Protocol parameters do not meet the security requirement")?;
         let mut dependencies_builder =
             DependenciesBuilder::new(root_logger.clone(), Arc::new(config.clone()));
 
@@ -1,16 +1,29 @@
-use anyhow::Context;
-use clap::{Parser, Subcommand};
+use anyhow::{anyhow, Context};
+use clap::{Args, Parser, Subcommand};
 use config::{builder::DefaultState, ConfigBuilder, Map, Value};
+use rand_chacha::ChaCha20Rng;
+use rand_core::{OsRng, RngCore, SeedableRng};
 use serde::{Deserialize, Serialize};
 use slog::{debug, Logger};
-use std::{collections::HashMap, path::PathBuf, sync::Arc};
+use std::{
+    collections::{BTreeMap, HashMap},
+    path::{Path, PathBuf},
+    sync::Arc,
+};
 
+use mithril_common::certificate_chain::{CertificateChainBundle, CertificateChainCheckpoint};
+use mithril_common::crypto_helper::{
+    ProtocolParameters, ProtocolParametersAnalysis, ProtocolQuorumSimulationReport,
+    ProtocolQuorumSimulator, ProtocolSecurityTarget, ProtocolSimulatedParty, ProtocolStake,
+};
+use mithril_common::entities::{Epoch, ProtocolMessage, StakeDistribution};
 use mithril_common::StdResult;
 use mithril_doc::{Documenter, StructDoc};
 use mithril_persistence::sqlite::{SqliteCleaner, SqliteCleaningTask};
+use mithril_persistence::store::StakeStorer;
 
 use crate::{
-    database::repository::{CertificateRepository, SignedEntityStore},
+    database::repository::{CertificateRepository, SignedEntityStore, StakePoolStore},
     dependency_injection::DependenciesBuilder,
     extract_all,
     tools::CertificatesHashMigrator,
@@ -58,6 +71,9 @@ impl ToolsCommand {
             command_path,
             ToolsSubCommand,
             RecomputeCertificatesHash = { RecomputeCertificatesHashCommand },
+            AnalyzeProtocolParameters = { AnalyzeProtocolParametersCommand },
+            SimulateQuorum = { SimulateQuorumCommand },
+            ExportCertificateChainBundle = { ExportCertificateChainBundleCommand },
         )
     }
 }
@@ -71,6 +87,19 @@ pub enum ToolsSubCommand {
     /// Since it will modify the aggregator sqlite database it's strongly recommended to backup it
     /// before running this command.
     RecomputeCertificatesHash(RecomputeCertificatesHashCommand),
+
+    /// Analyze the security of protocol parameters applied to a stake distribution, or search
+    /// protocol parameters meeting a security target.
+    AnalyzeProtocolParameters(AnalyzeProtocolParametersCommand),
+
+    /// Simulate the signature of random messages with the production lottery, replaying a stake
+    /// distribution with per pool participation probabilities, to measure how often the quorum
+    /// is not reached.
+    SimulateQuorum(SimulateQuorumCommand),
+
+    /// Export the certificate chain of a certificate, back to the genesis certificate or to a
+    /// checkpoint, as a portable bundle that can be verified without access to an aggregator.
+    ExportCertificateChainBundle(ExportCertificateChainBundleCommand),
 }
 
 impl ToolsSubCommand {
@@ -81,6 +110,11 @@ impl ToolsSubCommand {
     ) -> StdResult<()> {
         match self {
             Self::RecomputeCertificatesHash(cmd) => cmd.execute(root_logger, config_builder).await,
+            Self::AnalyzeProtocolParameters(cmd) => cmd.execute(root_logger, config_builder).await,
+            Self::SimulateQuorum(cmd) => cmd.execute(root_logger, config_builder).await,
+            Self::ExportCertificateChainBundle(cmd) => {
+                cmd.execute(root_logger, config_builder).await
+            }
         }
     }
 }
@@ -136,11 +170,437 @@ impl RecomputeCertificatesHashCommand {
     }
 }
 
+/// Analyze protocol parameters command.
+#[derive(Parser, Debug, Clone)]
+#[clap(group(
+    clap::ArgGroup::new("stake_distribution")
+        .required(true)
+        .args(["epoch", "stake_distribution_file"])
+))]
+pub struct AnalyzeProtocolParametersCommand {
+    /// Quorum parameter.
+    #[clap(long, required_unless_present = "search")]
+    k: Option<u64>,
+
+    /// Security parameter, upper bound on indices.
+    #[clap(long, required_unless_present = "search")]
+    m: Option<u64>,
+
+    /// `f` in phi(w) = 1 - (1 - f)^w, where w is the stake of a participant.
+    #[clap(long, required_unless_present = "search")]
+    phi_f: Option<f64>,
+
+    /// Ratio of the total stake assumed to be held by an adversary.
+    #[clap(long)]
+    adversarial_stake_ratio: f64,
+
+    /// Epoch of the stake distribution to read from the aggregator database.
+    #[clap(long)]
+    epoch: Option<u64>,
+
+    /// JSON file of the stake distribution, mapping each pool id to its stake.
+    #[clap(long)]
+    stake_distribution_file: Option<PathBuf>,
+
+    #[clap(flatten)]
+    search_args: ProtocolParametersSearchArgs,
+}
+
+/// Arguments of the search of protocol parameters meeting a security target.
+#[derive(Args, Debug, Clone)]
+pub struct ProtocolParametersSearchArgs {
+    /// Search protocol parameters meeting the security target instead of analyzing given ones.
+    #[clap(long)]
+    search: bool,
+
+    /// Minimum security level, in bits, against the adversary.
+    #[clap(long, default_value_t = 100.0)]
+    min_security_level: f64,
+
+    /// Minimum probability that the honest signers reach a quorum without the adversary.
+    #[clap(long, default_value_t = 0.99)]
+    min_honest_quorum_probability: f64,
+
+    /// Values of `phi_f` to search (comma separated list).
+    #[clap(long, value_delimiter = ',', default_value = "0.2")]
+    search_phi_f: Vec<f64>,
+
+    /// Smallest value of `m` to search.
+    #[clap(long, default_value_t = 1000)]
+    search_m_min: u64,
+
+    /// Largest value of `m` to search.
+    #[clap(long, default_value_t = 30000)]
+    search_m_max: u64,
+
+    /// Step between the values of `m` to search.
+    #[clap(long, default_value_t = 1000)]
+    search_m_step: u64,
+}
+
+impl AnalyzeProtocolParametersCommand {
+    pub async fn execute(
+        &self,
+        root_logger: Logger,
+        config_builder: ConfigBuilder<DefaultState>,
+    ) -> StdResult<()> {
+        debug!(root_logger, "ANALYZE PROTOCOL PARAMETERS command"; "command" => ?self);
+        let stakes: Vec<ProtocolStake> = read_stake_distribution(
+            self.stake_distribution_file.as_deref(),
+            self.epoch,
+            root_logger,
+            config_builder,
+        )
+        .await?
+        .into_values()
+        .collect();
+
+        if self.search_args.search {
+            let target = ProtocolSecurityTarget {
+                adversarial_stake_ratio: self.adversarial_stake_ratio,
+                min_security_level: self.search_args.min_security_level,
+                min_honest_quorum_probability: self.search_args.min_honest_quorum_probability,
+            };
+            let m_values = (self.search_args.search_m_min..=self.search_args.search_m_max)
+                .step_by(self.search_args.search_m_step.max(1) as usize);
+            let found = target.search_parameters(&self.search_args.search_phi_f, m_values);
+            println!(
+                "Found {} protocol parameters meeting the target",
+                found.len()
+            );
+
+            for params in found {
+                let analysis =
+                    ProtocolParametersAnalysis::new(params, self.adversarial_stake_ratio, &stakes);
+                println!("{}", Self::format_analysis(&analysis));
+            }
+        } else {
+            let params = ProtocolParameters {
+                k: self.k.with_context(|| "Missing 'k' protocol parameter")?,
+                m: self.m.with_context(|| "Missing 'm' protocol parameter")?,
+                phi_f: self
+                    .phi_f
+                    .with_context(|| "Missing 'phi_f' protocol parameter")?,
+            };
+            let analysis =
+                ProtocolParametersAnalysis::new(params, self.adversarial_stake_ratio, &stakes);
+            println!("{}", Self::format_analysis(&analysis));
+        }
+
+        Ok(())
+    }
+
+    fn format_analysis(analysis: &ProtocolParametersAnalysis) -> String {
+        format!(
+            "k = {}, m = {}, phi_f = {}\n\
+            \tsecurity level: {:.1} bits against {} of adversarial stake\n\
+            \thonest quorum probability: {:.6}\n\
+            \texpected signers: {:.1}\n\
+            \texpected aggregated signers: {:.1}\n\
+            \texpected certificate multi-signature size: {} bytes\n\
+            \tlotteries per signer: {}, expected lotteries won by the largest signer: {:.1}",
+            analysis.params.k,
+            analysis.params.m,
+            analysis.params.phi_f,
+            analysis.security_level,
+            analysis.adversarial_stake_ratio,
+            analysis.honest_quorum_probability,
+            analysis.expected_signers,
+            analysis.expected_aggregated_signers,
+            analysis.expected_aggregate_signature_size,
+            analysis.lotteries_per_signer,
+            analysis.max_expected_won_lotteries,
+        )
+    }
+
+    pub fn extract_config(command_path: String) -> HashMap<String, StructDoc> {
+        HashMap::from([(command_path, ToolsCommandConfiguration::extract())])
+    }
+}
+
+/// Simulate quorum command.
+#[derive(Parser, Debug, Clone)]
+#[clap(group(
+    clap::ArgGroup::new("stake_distribution")
+        .required(true)
+        .args(["epoch", "stake_distribution_file"])
+))]
+pub struct SimulateQuorumCommand {
+    /// Quorum parameter.
+    #[clap(long)]
+    k: u64,
+
+    /// Security parameter, upper bound on indices.
+    #[clap(long)]
+    m: u64,
+
+    /// `f` in phi(w) = 1 - (1 - f)^w, where w is the stake of a participant.
+    #[clap(long)]
+    phi_f: f64,
+
+    /// Epoch of the stake distribution to read from the aggregator database.
+    #[clap(long)]
+    epoch: Option<u64>,
+
+    /// JSON file of the stake distribution, mapping each pool id to its stake.
+    #[clap(long)]
+    stake_distribution_file: Option<PathBuf>,
+
+    /// JSON file mapping pool ids to their probability to take part in a signing round.
+    #[clap(long)]
+    participation_file: Option<PathBuf>,
+
+    /// Probability to take part in a signing round of the pools missing from the participation
+    /// file.
+    #[clap(long, default_value_t = 1.0)]
+    default_participation_probability: f64,
+
+    /// Number of random messages to sign.
+    #[clap(long, default_value_t = 1000)]
+    runs: u64,
+
+    /// Seed of the random generator, to reproduce a simulation.
+    #[clap(long)]
+    seed: Option<u64>,
+}
+
+impl SimulateQuorumCommand {
+    pub async fn execute(
+        &self,
+        root_logger: Logger,
+        config_builder: ConfigBuilder<DefaultState>,
+    ) -> StdResult<()> {
+        debug!(root_logger, "SIMULATE QUORUM command"; "command" => ?self);
+        let stake_distribution = read_stake_distribution(
+            self.stake_distribution_file.as_deref(),
+            self.epoch,
+            root_logger,
+            config_builder,
+        )
+        .await?;
+        let participation = self.read_participation()?;
+        let parties: Vec<ProtocolSimulatedParty> = stake_distribution
+            .into_iter()
+            .map(|(party_id, stake)| ProtocolSimulatedParty {
+                stake,
+                participation_probability: participation
+                    .get(&party_id)
+                    .copied()
+                    .unwrap_or(self.default_participation_probability),
+            })
+            .collect();
+
+        let seed = self.seed.unwrap_or_else(|| OsRng.next_u64());
+        let mut rng = ChaCha20Rng::seed_from_u64(seed);
+        let params = ProtocolParameters {
+            k: self.k,
+            m: self.m,
+            phi_f: self.phi_f,
+        };
+        let simulator = ProtocolQuorumSimulator::new(params, &parties, &mut rng)
+            .with_context(|| "Could not register the simulated parties")?;
+        let report = simulator
+            .run(self.runs, &mut rng)
+            .with_context(|| "Quorum simulation failed")?;
+        println!("{}", Self::format_report(&report, seed));
+
+        Ok(())
+    }
+
+    fn read_participation(&self) -> StdResult<HashMap<String, f64>> {
+        match &self.participation_file {
+            Some(path) => {
+                let file = std::fs::File::open(path).with_context(|| {
+                    format!("Could not open participation file '{}'", path.display())
+                })?;
+
+                serde_json::from_reader(file).with_context(|| {
+                    format!("Could not parse participation file '{}'", path.display())
+                })
+            }
+            None => Ok(HashMap::new()),
+        }
+    }
+
+    fn format_report(report: &ProtocolQuorumSimulationReport, seed: u64) -> String {
+        let format_histogram = |histogram: &BTreeMap<usize, u64>| {
+            histogram
+                .iter()
+                .map(|(value, occurrences)| format!("\t\t{value}: {occurrences}"))
+                .collect::<Vec<_>>()
+                .join("\n")
+        };
+        let format_mean =
+            |mean: Option<f64>| mean.map_or("n/a".to_string(), |mean| format!("{mean:.1}"));
+
+        format!(
+            "k = {}, m = {}, phi_f = {}, {} runs (seed {seed})\n\
+            \tquorum failures: {} ({:.4}%)\n\
+            \tmean signers needed: {}\n\
+            \tmean certificate multi-signature size: {} bytes\n\
+            \tsigners needed distribution:\n{}\n\
+            \tcertificate multi-signature size distribution (bytes):\n{}",
+            report.params.k,
+            report.params.m,
+            report.params.phi_f,
+            report.runs,
+            report.quorum_failures,
+            report.quorum_failure_rate() * 100.0,
+            format_mean(report.mean_signers_needed()),
+            format_mean(report.mean_aggregate_signature_size()),
+            format_histogram(&report.signers_needed),
+            format_histogram(&report.aggregate_signature_sizes),
+        )
+    }
+
+    pub fn extract_config(command_path: String) -> HashMap<String, StructDoc> {
+        HashMap::from([(command_path, ToolsCommandConfiguration::extract())])
+    }
+}
+
+/// Export certificate chain bundle command.
+#[derive(Parser, Debug, Clone)]
+pub struct ExportCertificateChainBundleCommand {
+    /// Hash of the certificate at the start of the exported chain.
+    #[clap(long)]
+    certificate_hash: String,
+
+    /// JSON file of the protocol message computed from the artifact certified by the certificate.
+    #[clap(long)]
+    artifact_message_file: PathBuf,
+
+    /// JSON file of a checkpoint at which the exported chain ends, instead of the genesis
+    /// certificate.
+    #[clap(long)]
+    checkpoint_file: Option<PathBuf>,
+
+    /// Path of the certificate chain bundle file to write.
+    #[clap(long)]
+    output: PathBuf,
+}
+
+impl ExportCertificateChainBundleCommand {
+    pub async fn execute(
+        &self,
+        root_logger: Logger,
+        config_builder: ConfigBuilder<DefaultState>,
+    ) -> StdResult<()> {
+        let config: ToolsCommandConfiguration = config_builder
+            .build()
+            .with_context(|| "configuration build error")?
+            .try_deserialize()
+            .with_context(|| "configuration deserialize error")?;
+        debug!(root_logger, "EXPORT CERTIFICATE CHAIN BUNDLE command"; "command" => ?self, "config" => format!("{config:?}"));
+        let artifact_message = self.read_artifact_message()?;
+        let checkpoint = self.read_checkpoint()?;
+        let mut dependencies_builder =
+            DependenciesBuilder::new(root_logger, Arc::new(config.clone()));
+        let dependencies_container = dependencies_builder
+            .create_tools_command_container()
+            .await
+            .with_context(|| "Failed to create the tools command dependencies container")?;
+
+        let certificate_repository =
+            CertificateRepository::new(dependencies_container.db_connection.clone());
+        let bundle = CertificateChainBundle::build(
+            &certificate_repository,
+            &self.certificate_hash,
+            artifact_message,
+            checkpoint,
+        )
+        .await
+        .with_context(|| {
+            format!(
+                "Could not build the certificate chain bundle of certificate '{}'",
+                self.certificate_hash
+            )
+        })?;
+        bundle.write_to_file(&self.output)?;
+        println!(
+            "Certificate chain bundle of {} certificates written to '{}'",
+            bundle.certificates.len(),
+            self.output.display()
+        );
+
+        Ok(())
+    }
+
+    fn read_artifact_message(&self) -> StdResult<ProtocolMessage> {
+        let path = &self.artifact_message_file;
+        let file = std::fs::File::open(path).with_context(|| {
+            format!("Could not open artifact message file '{}'", path.display())
+        })?;
+
+        serde_json::from_reader(file)
+            .with_context(|| format!("Could not parse artifact message file '{}'", path.display()))
+    }
+
+    fn read_checkpoint(&self) -> StdResult<Option<CertificateChainCheckpoint>> {
+        match &self.checkpoint_file {
+            Some(path) => {
+                let file = std::fs::File::open(path).with_context(|| {
+                    format!("Could not open checkpoint file '{}'", path.display())
+                })?;
+
+                serde_json::from_reader(file).map(Some).with_context(|| {
+                    format!("Could not parse checkpoint file '{}'", path.display())
+                })
+            }
+            None => Ok(None),
+        }
+    }
+
+    pub fn extract_config(command_path: String) -> HashMap<String, StructDoc> {
+        HashMap::from([(command_path, ToolsCommandConfiguration::extract())])
+    }
+}
+
+/// Read a stake distribution from a JSON file, or from the aggregator database for an epoch.
+async fn read_stake_distribution(
+    stake_distribution_file: Option<&Path>,
+    epoch: Option<u64>,
+    root_logger: Logger,
+    config_builder: ConfigBuilder<DefaultState>,
+) -> StdResult<StakeDistribution> {
+    if let Some(path) = stake_distribution_file {
+        let file = std::fs::File::open(path).with_context(|| {
+            format!(
+                "Could not open stake distribution file '{}'",
+                path.display()
+            )
+        })?;
+
+        return serde_json::from_reader(file).with_context(|| {
+            format!(
+                "Could not parse stake distribution file '{}'",
+                path.display()
+            )
+        });
+    }
+
+    let epoch = Epoch(epoch.with_context(|| "Missing stake distribution epoch")?);
+    let config: ToolsCommandConfiguration = config_builder
+        .build()
+        .with_context(|| "configuration build error")?
+        .try_deserialize()
+        .with_context(|| "configuration deserialize error")?;
+    let mut dependencies_builder = DependenciesBuilder::new(root_logger, Arc::new(config.clone()));
+    let dependencies_container = dependencies_builder
+        .create_tools_command_container()
+        .await
+        .with_context(|| "Failed to create the tools command dependencies container")?;
+
+    StakePoolStore::new(dependencies_container.db_connection.clone(), None)
+        .get_stakes(epoch)
+        .await?
+        .ok_or_else(|| anyhow!("No stake distribution found for epoch {epoch}"))
+}
+
 #[cfg(test)]
 mod tests {
     use std::sync::Arc;
 
-    use mithril_common::temp_dir;
+    use mithril_common::{temp_dir, temp_dir_create};
 
     use crate::test_tools::TestLogger;
 
@@ -159,4 +619,210 @@ mod tests {
             .await
             .expect("Expected container creation to succeed without panicking");
     }
+
+    #[test]
+    fn analyze_protocol_parameters_requires_parameters_unless_searching() {
+        AnalyzeProtocolParametersCommand::try_parse_from([
+            "analyze-protocol-parameters",
+            "--adversarial-stake-ratio",
+            "0.33",
+            "--epoch",
+            "10",
+        ])
+        .expect_err("Should fail because the protocol parameters are not set");
+
+        AnalyzeProtocolParametersCommand::try_parse_from([
+            "analyze-protocol-parameters",
+            "--adversarial-stake-ratio",
+            "0.33",
+            "--epoch",
+            "10",
+            "--search",
+        ])
+        .unwrap();
+    }
+
+    #[test]
+    fn analyze_protocol_parameters_requires_a_stake_distribution() {
+        AnalyzeProtocolParametersCommand::try_parse_from([
+            "analyze-protocol-parameters",
+            "--k",
+            "5",
+            "--m",
+            "100",
+            "--phi-f",
+            "0.65",
+            "--adversarial-stake-ratio",
+            "0.33",
+        ])
+        .expect_err("Should fail because no stake distribution source is set");
+    }
+
+    #[tokio::test]
+    async fn analyze_protocol_parameters_with_stake_distribution_file() {
+        let stake_distribution_file = temp_dir_create!().join("stake_distribution.json");
+        std::fs::write(
+            &stake_distribution_file,
+            r#"{ "pool1": 1000, "pool2": 2000, "pool3": 3000 }"#,
+        )
+        .unwrap();
+        let command = AnalyzeProtocolParametersCommand::try_parse_from([
+            "analyze-protocol-parameters",
+            "--k",
+            "5",
+            "--m",
+            "100",
+            "--phi-f",
+            "0.65",
+            "--adversarial-stake-ratio",
+            "0.33",
+            "--stake-distribution-file",
+            stake_distribution_file.to_str().unwrap(),
+        ])
+        .unwrap();
+
+        command
+            .execute(TestLogger::stdout(), config::Config::builder())
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn analyze_protocol_parameters_fails_without_stake_distribution_for_epoch() {
+        let command = AnalyzeProtocolParametersCommand::try_parse_from([
+            "analyze-protocol-parameters",
+            "--search",
+            "--adversarial-stake-ratio",
+            "0.33",
+            "--epoch",
+            "10",
+        ])
+        .unwrap();
+        let config_builder = config::Config::builder()
+            .set_default(
+                "data_stores_directory",
+                temp_dir!().join("stores").to_string_lossy().to_string(),
+            )
+            .unwrap();
+
+        command
+            .execute(TestLogger::stdout(), config_builder)
+            .await
+            .expect_err("Should fail because there is no stake distribution for the epoch");
+    }
+
+    #[test]
+    fn simulate_quorum_requires_a_stake_distribution() {
+        SimulateQuorumCommand::try_parse_from([
+            "simulate-quorum",
+            "--k",
+            "5",
+            "--m",
+            "100",
+            "--phi-f",
+            "0.65",
+        ])
+        .expect_err("Should fail because no stake distribution source is set");
+    }
+
+    #[tokio::test]
+    async fn simulate_quorum_with_stake_distribution_and_participation_files() {
+        let dir = temp_dir_create!();
+        let stake_distribution_file = dir.join("stake_distribution.json");
+        std::fs::write(
+            &stake_distribution_file,
+            r#"{ "pool1": 1000, "pool2": 2000, "pool3": 3000 }"#,
+        )
+        .unwrap();
+        let participation_file = dir.join("participation.json");
+        std::fs::write(&participation_file, r#"{ "pool1": 0.5, "pool2": 0.0 }"#).unwrap();
+        let command = SimulateQuorumCommand::try_parse_from([
+            "simulate-quorum",
+            "--k",
+            "5",
+            "--m",
+            "100",
+            "--phi-f",
+            "0.65",
+            "--stake-distribution-file",
+            stake_distribution_file.to_str().unwrap(),
+            "--participation-file",
+            participation_file.to_str().unwrap(),
+            "--runs",
+            "3",
+            "--seed",
+            "42",
+        ])
+        .unwrap();
+
+        command
+            .execute(TestLogger::stdout(), config::Config::builder())
+            .await
+            .unwrap();
+    }
+
+    #[test]
+    fn export_certificate_chain_bundle_requires_a_certificate_hash_an_artifact_message_and_an_output(
+    ) {
+        ExportCertificateChainBundleCommand::try_parse_from([
+            "export-certificate-chain-bundle",
+            "--artifact-message-file",
+            "artifact-message.json",
+            "--output",
+            "bundle.json",
+        ])
+        .expect_err("Should fail because the certificate hash is not set");
+
+        ExportCertificateChainBundleCommand::try_parse_from([
+            "export-certificate-chain-bundle",
+            "--certificate-hash",
+            "certificate-123",
+            "--output",
+            "bundle.json",
+        ])
+        .expect_err("Should fail because the artifact message file is not set");
+
+        ExportCertificateChainBundleCommand::try_parse_from([
+            "export-certificate-chain-bundle",
+            "--certificate-hash",
+            "certificate-123",
+            "--artifact-message-file",
+            "artifact-message.json",
+        ])
+        .expect_err("Should fail because the output is not set");
+    }
+
+    #[tokio::test]
+    async fn export_certificate_chain_bundle_fails_for_unknown_certificate() {
+        let dir = temp_dir_create!();
+        let output = dir.join("bundle.json");
+        let artifact_message_file = dir.join("artifact-message.json");
+        std::fs::write(
+            &artifact_message_file,
+            serde_json::to_string(&ProtocolMessage::new()).unwrap(),
+        )
+        .unwrap();
+        let command = ExportCertificateChainBundleCommand::try_parse_from([
+            "export-certificate-chain-bundle",
+            "--certificate-hash",
+            "unknown-certificate",
+            "--artifact-message-file",
+            artifact_message_file.to_str().unwrap(),
+            "--output",
+            output.to_str().unwrap(),
+        ])
+        .unwrap();
+        let config_builder = config::Config::builder()
+            .set_default(
+                "data_stores_directory",
+                dir.join("stores").to_string_lossy().to_string(),
+            )
+            .unwrap();
+
+        command
+            .execute(TestLogger::stdout(), config_builder)
+            .await
+            .expect_err("Should fail because the certificate does not exist");
+        assert!(!output.exists());
+    }
 }
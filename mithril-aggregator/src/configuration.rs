@@ -1,4 +1,4 @@
-use anyhow::Context;
+use anyhow::{anyhow, Context};
 use config::{ConfigError, Map, Source, Value, ValueKind};
 use serde::{Deserialize, Serialize};
 use std::collections::{BTreeSet, HashMap, HashSet};
@@ -7,7 +7,9 @@ use std::str::FromStr;
 
 use mithril_cli_helper::{register_config_value, serde_deserialization};
 use mithril_common::chain_observer::ChainObserverType;
-use mithril_common::crypto_helper::{ManifestSigner, ProtocolGenesisSigner};
+use mithril_common::crypto_helper::{
+    ManifestSigner, ProtocolGenesisSigner, ProtocolParameters as ProtocolParametersCrypto,
+};
 use mithril_common::entities::{
     BlockNumber, CardanoTransactionsSigningConfig, CompressionAlgorithm,
     HexEncodedGenesisVerificationKey, HexEncodedKey, ProtocolParameters, SignedEntityConfig,
@@ -98,6 +100,13 @@ pub trait ConfigurationSource {
         panic!("protocol_parameters is not implemented.");
     }
 
+    /// Security requirement that the protocol parameters must meet
+    fn protocol_parameters_security_requirement(
+        &self,
+    ) -> Option<ProtocolParametersSecurityRequirement> {
+        panic!("protocol_parameters_security_requirement is not implemented.");
+    }
+
     /// Type of snapshot uploader to use
     fn snapshot_uploader_type(&self) -> SnapshotUploaderType {
         panic!("snapshot_uploader_type is not implemented.");
@@ -225,6 +234,25 @@ pub trait ConfigurationSource {
         panic!("allow_unparsable_block is not implemented.");
     }
 
+    /// If set, the transactions filters (addresses and metadata labels) are extracted from the
+    /// scanned blocks and indexed, allowing to search Cardano transactions by filter.
+    fn enable_cardano_transactions_filters_extraction(&self) -> bool {
+        panic!("enable_cardano_transactions_filters_extraction is not implemented.");
+    }
+
+    /// If set, the Cardano blocks are read from the completed immutable files of the Cardano node
+    /// database instead of the chain synchronization with the Cardano node.
+    fn read_blocks_from_immutable_files(&self) -> bool {
+        panic!("read_blocks_from_immutable_files is not implemented.");
+    }
+
+    /// If set, the Merkle trees of the Cardano transactions and blocks signable builders and
+    /// provers are stored on disk instead of in memory, bounding the memory usage at the cost of
+    /// slower computations.
+    fn use_disk_backed_merkle_tree_store(&self) -> bool {
+        panic!("use_disk_backed_merkle_tree_store is not implemented.");
+    }
+
     /// Cardano transactions prover cache pool size
     fn cardano_transactions_prover_cache_pool_size(&self) -> usize {
         panic!("cardano_transactions_prover_cache_pool_size is not implemented.");
@@ -245,6 +273,14 @@ pub trait ConfigurationSource {
         panic!("cardano_transactions_prover_max_hashes_allowed_by_request is not implemented.");
     }
 
+    /// Maximum number of block ranges allowed by request to the prover of the Cardano transactions
+    /// searched by output address or by metadata label
+    fn cardano_transactions_prover_max_block_ranges_allowed_by_request(&self) -> usize {
+        panic!(
+            "cardano_transactions_prover_max_block_ranges_allowed_by_request is not implemented."
+        );
+    }
+
     /// The maximum number of roll forwards during a poll of the block streamer when importing transactions.
     fn cardano_transactions_block_streamer_max_roll_forwards_per_poll(&self) -> usize {
         panic!(
@@ -359,6 +395,27 @@ pub trait ConfigurationSource {
         }
     }
 
+    /// Check that the protocol parameters meet the security requirement, if any.
+    fn check_protocol_parameters_security(&self) -> StdResult<()> {
+        let Some(requirement) = self.protocol_parameters_security_requirement() else {
+            return Ok(());
+        };
+        let protocol_parameters = self.protocol_parameters();
+        let security_level = ProtocolParametersCrypto::from(protocol_parameters.clone())
+            .security_level(requirement.adversarial_stake_ratio);
+
+        if security_level < requirement.min_security_level {
+            return Err(anyhow!(
+                "Protocol parameters {protocol_parameters:?} provide a security level of {security_level:.1} bits \
+                against an adversary holding {} of the stake, below the required {} bits",
+                requirement.adversarial_stake_ratio,
+                requirement.min_security_level
+            ));
+        }
+
+        Ok(())
+    }
+
     /// Check if the aggregator is running in follower mode.
     fn is_follower_aggregator(&self) -> bool {
         self.leader_aggregator_endpoint().is_some()
@@ -419,6 +476,11 @@ pub struct ServeCommandConfiguration {
     #[example = "`{ k: 5, m: 100, phi_f: 0.65 }`"]
     pub protocol_parameters: ProtocolParameters,
 
+    /// Security requirement that the protocol parameters must meet, the aggregator refuses to
+    /// start if they don't.
+    #[example = "`{ adversarial_stake_ratio: 0.33, min_security_level: 100 }`"]
+    pub protocol_parameters_security_requirement: Option<ProtocolParametersSecurityRequirement>,
+
     /// Type of snapshot uploader to use
     #[example = "`gcp` or `local`"]
     pub snapshot_uploader_type: SnapshotUploaderType,
@@ -513,6 +575,19 @@ pub struct ServeCommandConfiguration {
     /// Will be ignored on (pre)production networks.
     pub allow_unparsable_block: bool,
 
+    /// If set, the transactions filters (addresses and metadata labels) are extracted from the
+    /// scanned blocks and indexed, allowing to search Cardano transactions by filter.
+    pub enable_cardano_transactions_filters_extraction: bool,
+
+    /// If set, the Cardano blocks are read from the completed immutable files of the Cardano node
+    /// database instead of the chain synchronization with the Cardano node.
+    pub read_blocks_from_immutable_files: bool,
+
+    /// If set, the Merkle trees of the Cardano transactions and blocks signable builders and
+    /// provers are stored on disk instead of in memory, bounding the memory usage at the cost of
+    /// slower computations.
+    pub use_disk_backed_merkle_tree_store: bool,
+
     /// Cardano transactions prover cache pool size
     pub cardano_transactions_prover_cache_pool_size: usize,
 
@@ -526,6 +601,10 @@ pub struct ServeCommandConfiguration {
     /// Maximum number of transactions hashes allowed by request to the prover of the Cardano transactions
     pub cardano_transactions_prover_max_hashes_allowed_by_request: usize,
 
+    /// Maximum number of block ranges allowed by request to the prover of the Cardano transactions
+    /// searched by output address or by metadata label
+    pub cardano_transactions_prover_max_block_ranges_allowed_by_request: usize,
+
     /// The maximum number of roll forwards during a poll of the block streamer when importing transactions.
     pub cardano_transactions_block_streamer_max_roll_forwards_per_poll: usize,
 
@@ -563,6 +642,17 @@ pub enum SnapshotUploaderType {
     Local,
 }
 
+/// Security requirement that the protocol parameters must meet
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+pub struct ProtocolParametersSecurityRequirement {
+    /// Ratio of the total stake assumed to be held by an adversary.
+    pub adversarial_stake_ratio: f64,
+
+    /// Minimum security level, in bits: the adversary must not be able to reach a quorum on its
+    /// own with a probability above `2^-min_security_level`.
+    pub min_security_level: f64,
+}
+
 /// [Zstandard][CompressionAlgorithm::Zstandard] specific parameters
 #[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
 pub struct ZstandardCompressionParameters {
@@ -637,6 +727,7 @@ impl ServeCommandConfiguration {
                 m: 100,
                 phi_f: 0.95,
             },
+            protocol_parameters_security_requirement: None,
             snapshot_uploader_type: SnapshotUploaderType::Local,
             snapshot_bucket_name: None,
             snapshot_use_cdn_domain: false,
@@ -668,6 +759,9 @@ impl ServeCommandConfiguration {
             cexplorer_pools_url: None,
             signer_importer_run_interval: 1,
             allow_unparsable_block: false,
+            enable_cardano_transactions_filters_extraction: false,
+            read_blocks_from_immutable_files: false,
+            use_disk_backed_merkle_tree_store: false,
             cardano_transactions_prover_cache_pool_size: 3,
             cardano_transactions_database_connection_pool_size: 5,
             cardano_transactions_signing_config: CardanoTransactionsSigningConfig {
@@ -675,6 +769,7 @@ impl ServeCommandConfiguration {
                 step: BlockNumber(15),
             },
             cardano_transactions_prover_max_hashes_allowed_by_request: 100,
+            cardano_transactions_prover_max_block_ranges_allowed_by_request: 10,
             cardano_transactions_block_streamer_max_roll_forwards_per_poll: 1000,
             enable_metrics_server: true,
             metrics_server_ip: "0.0.0.0".to_string(),
@@ -727,6 +822,12 @@ impl ConfigurationSource for ServeCommandConfiguration {
         self.protocol_parameters.clone()
     }
 
+    fn protocol_parameters_security_requirement(
+        &self,
+    ) -> Option<ProtocolParametersSecurityRequirement> {
+        self.protocol_parameters_security_requirement
+    }
+
     fn snapshot_uploader_type(&self) -> SnapshotUploaderType {
         self.snapshot_uploader_type
     }
@@ -819,6 +920,18 @@ impl ConfigurationSource for ServeCommandConfiguration {
         self.allow_unparsable_block
     }
 
+    fn enable_cardano_transactions_filters_extraction(&self) -> bool {
+        self.enable_cardano_transactions_filters_extraction
+    }
+
+    fn read_blocks_from_immutable_files(&self) -> bool {
+        self.read_blocks_from_immutable_files
+    }
+
+    fn use_disk_backed_merkle_tree_store(&self) -> bool {
+        self.use_disk_backed_merkle_tree_store
+    }
+
     fn cardano_transactions_prover_cache_pool_size(&self) -> usize {
         self.cardano_transactions_prover_cache_pool_size
     }
@@ -835,6 +948,10 @@ impl ConfigurationSource for ServeCommandConfiguration {
         self.cardano_transactions_prover_max_hashes_allowed_by_request
     }
 
+    fn cardano_transactions_prover_max_block_ranges_allowed_by_request(&self) -> usize {
+        self.cardano_transactions_prover_max_block_ranges_allowed_by_request
+    }
+
     fn cardano_transactions_block_streamer_max_roll_forwards_per_poll(&self) -> usize {
         self.cardano_transactions_block_streamer_max_roll_forwards_per_poll
     }
@@ -918,6 +1035,19 @@ pub struct DefaultConfiguration {
     /// Will be ignored on (pre)production networks.
     pub allow_unparsable_block: String,
 
+    /// If set, the transactions filters (addresses and metadata labels) are extracted from the
+    /// scanned blocks and indexed, allowing to search Cardano transactions by filter.
+    pub enable_cardano_transactions_filters_extraction: String,
+
+    /// If set, the Cardano blocks are read from the completed immutable files of the Cardano node
+    /// database instead of the chain synchronization with the Cardano node.
+    pub read_blocks_from_immutable_files: String,
+
+    /// If set, the Merkle trees of the Cardano transactions and blocks signable builders and
+    /// provers are stored on disk instead of in memory, bounding the memory usage at the cost of
+    /// slower computations.
+    pub use_disk_backed_merkle_tree_store: String,
+
     /// Cardano transactions prover cache pool size
     pub cardano_transactions_prover_cache_pool_size: u32,
 
@@ -930,6 +1060,10 @@ pub struct DefaultConfiguration {
     /// Maximum number of transactions hashes allowed by request to the prover of the Cardano transactions
     pub cardano_transactions_prover_max_hashes_allowed_by_request: u32,
 
+    /// Maximum number of block ranges allowed by request to the prover of the Cardano transactions
+    /// searched by output address or by metadata label
+    pub cardano_transactions_prover_max_block_ranges_allowed_by_request: u32,
+
     /// The maximum number of roll forwards during a poll of the block streamer when importing transactions.
     pub cardano_transactions_block_streamer_max_roll_forwards_per_poll: u32,
 
@@ -963,6 +1097,9 @@ impl Default for DefaultConfiguration {
             snapshot_use_cdn_domain: "false".to_string(),
             signer_importer_run_interval: 720,
             allow_unparsable_block: "false".to_string(),
+            enable_cardano_transactions_filters_extraction: "false".to_string(),
+            read_blocks_from_immutable_files: "false".to_string(),
+            use_disk_backed_merkle_tree_store: "false".to_string(),
             cardano_transactions_prover_cache_pool_size: 10,
             cardano_transactions_database_connection_pool_size: 10,
             cardano_transactions_signing_config: CardanoTransactionsSigningConfig {
@@ -970,6 +1107,7 @@ impl Default for DefaultConfiguration {
                 step: BlockNumber(120),
             },
             cardano_transactions_prover_max_hashes_allowed_by_request: 100,
+            cardano_transactions_prover_max_block_ranges_allowed_by_request: 10,
             cardano_transactions_block_streamer_max_roll_forwards_per_poll: 10000,
             enable_metrics_server: "false".to_string(),
             metrics_server_ip: "0.0.0.0".to_string(),
@@ -1018,6 +1156,13 @@ impl Source for DefaultConfiguration {
         register_config_value!(result, &namespace, myself.snapshot_use_cdn_domain);
         register_config_value!(result, &namespace, myself.signer_importer_run_interval);
         register_config_value!(result, &namespace, myself.allow_unparsable_block);
+        register_config_value!(
+            result,
+            &namespace,
+            myself.enable_cardano_transactions_filters_extraction
+        );
+        register_config_value!(result, &namespace, myself.read_blocks_from_immutable_files);
+        register_config_value!(result, &namespace, myself.use_disk_backed_merkle_tree_store);
         register_config_value!(
             result,
             &namespace,
@@ -1033,6 +1178,11 @@ impl Source for DefaultConfiguration {
             &namespace,
             myself.cardano_transactions_prover_max_hashes_allowed_by_request
         );
+        register_config_value!(
+            result,
+            &namespace,
+            myself.cardano_transactions_prover_max_block_ranges_allowed_by_request
+        );
         register_config_value!(
             result,
             &namespace,
@@ -1130,6 +1280,47 @@ mod test {
         );
     }
 
+    #[test]
+    fn check_protocol_parameters_security_succeeds_without_requirement() {
+        let config = ServeCommandConfiguration {
+            protocol_parameters: ProtocolParameters::new(1, 10, 0.95),
+            protocol_parameters_security_requirement: None,
+            ..ServeCommandConfiguration::new_sample(temp_dir!())
+        };
+
+        config.check_protocol_parameters_security().unwrap();
+    }
+
+    #[test]
+    fn check_protocol_parameters_security_succeeds_if_parameters_meet_the_requirement() {
+        let config = ServeCommandConfiguration {
+            protocol_parameters: ProtocolParameters::new(2422, 20973, 0.2),
+            protocol_parameters_security_requirement: Some(ProtocolParametersSecurityRequirement {
+                adversarial_stake_ratio: 0.33,
+                min_security_level: 100.0,
+            }),
+            ..ServeCommandConfiguration::new_sample(temp_dir!())
+        };
+
+        config.check_protocol_parameters_security().unwrap();
+    }
+
+    #[test]
+    fn check_protocol_parameters_security_fails_if_parameters_are_below_the_requirement() {
+        let config = ServeCommandConfiguration {
+            protocol_parameters: ProtocolParameters::new(5, 100, 0.65),
+            protocol_parameters_security_requirement: Some(ProtocolParametersSecurityRequirement {
+                adversarial_stake_ratio: 0.33,
+                min_security_level: 100.0,
+            }),
+            ..ServeCommandConfiguration::new_sample(temp_dir!())
+        };
+
+        config
+            .check_protocol_parameters_security()
+            .expect_err("Parameters below the security requirement should be refused");
+    }
+
     #[test]
     fn allow_http_serve_directory() {
         let config = ServeCommandConfiguration {
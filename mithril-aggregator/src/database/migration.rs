@@ -239,5 +239,31 @@ create table immutable_file_digest (
 drop table pending_certificate;
         "#,
         ),
+        // Migration 36
+        // Add the `aggregation_summary` column to the `open_message` table.
+        SqlMigration::new(
+            36,
+            r#"
+alter table open_message add column aggregation_summary text null;
+        "#,
+        ),
+        // Migration 37
+        // Add the `signed_entity_type` record for 'CardanoBlocks'
+        SqlMigration::new(
+            37,
+            r#"
+insert into signed_entity_type (signed_entity_type_id, name)
+    values  (5, 'Cardano Blocks');
+        "#,
+        ),
+        // Migration 38
+        // Add the `signed_entity_type` record for 'CardanoProtocolParameters'
+        SqlMigration::new(
+            38,
+            r#"
+insert into signed_entity_type (signed_entity_type_id, name)
+    values  (6, 'Cardano Protocol Parameters');
+        "#,
+        ),
     ]
 }
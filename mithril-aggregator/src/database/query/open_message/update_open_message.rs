@@ -1,10 +1,12 @@
 use anyhow::Context;
 use sqlite::Value;
+use uuid::Uuid;
 
 use mithril_common::StdResult;
 use mithril_persistence::sqlite::{Query, SourceAlias, SqLiteEntity, WhereCondition};
 
 use crate::database::record::OpenMessageRecord;
+use crate::entities::AggregationSummary;
 
 /// Query to update [OpenMessageRecord] in the sqlite database
 pub struct UpdateOpenMessageQuery {
@@ -15,7 +17,7 @@ impl UpdateOpenMessageQuery {
     pub fn one(open_message: &OpenMessageRecord) -> StdResult<Self> {
         let expression = "epoch_setting_id = ?*, beacon = ?*, \
 signed_entity_type_id = ?*, protocol_message = ?*, is_certified = ?*, \
-is_expired = ?*, expires_at = ?* where open_message_id = ?*";
+is_expired = ?*, expires_at = ?*, aggregation_summary = ?* where open_message_id = ?*";
         let beacon_str = open_message.signed_entity_type.get_json_beacon()?;
         let parameters = vec![
             Value::Integer(
@@ -33,6 +35,12 @@ is_expired = ?*, expires_at = ?* where open_message_id = ?*";
                 .expires_at
                 .map(|d| Value::String(d.to_rfc3339()))
                 .unwrap_or(Value::Null),
+            open_message
+                .aggregation_summary
+                .as_ref()
+                .map(|summary| serde_json::to_string(summary).map(Value::String))
+                .transpose()?
+                .unwrap_or(Value::Null),
             Value::String(open_message.open_message_id.to_string()),
         ];
 
@@ -40,6 +48,22 @@ is_expired = ?*, expires_at = ?* where open_message_id = ?*";
             condition: WhereCondition::new(expression, parameters),
         })
     }
+
+    /// Update only the aggregation summary of the open message with the given id
+    pub fn aggregation_summary(
+        open_message_id: &Uuid,
+        aggregation_summary: &AggregationSummary,
+    ) -> StdResult<Self> {
+        let expression = "aggregation_summary = ?* where open_message_id = ?*";
+        let parameters = vec![
+            Value::String(serde_json::to_string(aggregation_summary)?),
+            Value::String(open_message_id.to_string()),
+        ];
+
+        Ok(Self {
+            condition: WhereCondition::new(expression, parameters),
+        })
+    }
 }
 
 impl Query for UpdateOpenMessageQuery {
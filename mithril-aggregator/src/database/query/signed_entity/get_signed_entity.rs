@@ -73,6 +73,19 @@ impl GetSignedEntityRecordQuery {
             ),
         }
     }
+
+    pub fn cardano_protocol_parameters_by_epoch(epoch: Epoch) -> Self {
+        let signed_entity_type_id =
+            SignedEntityTypeDiscriminants::CardanoProtocolParameters.index() as i64;
+        let epoch = *epoch as i64;
+
+        Self {
+            condition: WhereCondition::new(
+                "signed_entity_type_id = ?* and beacon = ?*",
+                vec![Value::Integer(signed_entity_type_id), Value::Integer(epoch)],
+            ),
+        }
+    }
 }
 
 impl Query for GetSignedEntityRecordQuery {
@@ -95,7 +108,9 @@ impl Query for GetSignedEntityRecordQuery {
 mod tests {
     use chrono::DateTime;
     use mithril_common::{
-        entities::{CardanoDbBeacon, SignedEntityType},
+        entities::{
+            CardanoDbBeacon, CardanoProtocolParameters, LedgerProtocolParameters, SignedEntityType,
+        },
         test_utils::fake_data,
     };
     use mithril_persistence::sqlite::ConnectionExtensions;
@@ -183,6 +198,38 @@ mod tests {
         );
     }
 
+    #[test]
+    fn cardano_protocol_parameters_by_epoch_returns_only_cardano_protocol_parameters_records_of_the_epoch(
+    ) {
+        let cardano_protocol_parameters_records: Vec<SignedEntityRecord> = (3..=5)
+            .map(|epoch| {
+                CardanoProtocolParameters::new(Epoch(epoch), LedgerProtocolParameters::dummy())
+                    .into()
+            })
+            .collect();
+        let cardano_stake_distribution_record: SignedEntityRecord =
+            fake_data::cardano_stake_distribution(Epoch(4)).into();
+
+        let connection = create_database(
+            &[
+                cardano_protocol_parameters_records.clone(),
+                vec![cardano_stake_distribution_record],
+            ]
+            .concat(),
+        );
+
+        let records_retrieved: Vec<SignedEntityRecord> = connection
+            .fetch_collect(
+                GetSignedEntityRecordQuery::cardano_protocol_parameters_by_epoch(Epoch(4)),
+            )
+            .unwrap();
+
+        assert_eq!(
+            vec![cardano_protocol_parameters_records[1].clone()],
+            records_retrieved,
+        );
+    }
+
     #[test]
     fn test_get_signed_entity_records() {
         let signed_entity_records = SignedEntityRecord::fake_records(5);
@@ -6,6 +6,8 @@ use mithril_common::entities::{Epoch, ProtocolMessage, SignedEntityType};
 use mithril_persistence::database::Hydrator;
 use mithril_persistence::sqlite::{HydrationError, Projection, SqLiteEntity};
 
+use crate::entities::AggregationSummary;
+
 /// ## OpenMessage
 ///
 /// An open message is a message open for signatures. Every signer may send a
@@ -36,6 +38,9 @@ pub struct OpenMessageRecord {
 
     /// Message expiration datetime, if it exists.
     pub expires_at: Option<DateTime<Utc>>,
+
+    /// Summary of the last aggregation attempt that did not reach the quorum, if any.
+    pub aggregation_summary: Option<AggregationSummary>,
 }
 
 impl OpenMessageRecord {
@@ -55,6 +60,7 @@ impl OpenMessageRecord {
             is_expired: false,
             created_at: Utc::now(),
             expires_at: None,
+            aggregation_summary: None,
         }
     }
 }
@@ -102,6 +108,16 @@ impl SqLiteEntity for OpenMessageRecord {
                 "Could not turn open_message.expires_at field value '{datetime}' to rfc3339 Datetime. Error: {e}"
             ))
         })).transpose()?.map(|datetime| datetime.with_timezone(&Utc));
+        let aggregation_summary = row.read::<Option<&str>, _>(9);
+        let aggregation_summary = aggregation_summary
+            .map(|summary| {
+                serde_json::from_str(summary).map_err(|e| {
+                    HydrationError::InvalidData(format!(
+                        "Invalid aggregation summary JSON representation '{summary}'. Error: {e}"
+                    ))
+                })
+            })
+            .transpose()?;
         let open_message = Self {
             open_message_id,
             epoch: Epoch(epoch_val),
@@ -111,6 +127,7 @@ impl SqLiteEntity for OpenMessageRecord {
             is_expired,
             created_at,
             expires_at,
+            aggregation_summary,
         };
 
         Ok(open_message)
@@ -143,6 +160,11 @@ impl SqLiteEntity for OpenMessageRecord {
             ("is_expired", "{:open_message:}.is_expired", "bool"),
             ("created_at", "{:open_message:}.created_at", "text"),
             ("expires_at", "{:open_message:}.expires_at", "text"),
+            (
+                "aggregation_summary",
+                "{:open_message:}.aggregation_summary",
+                "text",
+            ),
         ])
     }
 }
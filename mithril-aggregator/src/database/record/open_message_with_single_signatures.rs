@@ -6,6 +6,7 @@ use mithril_common::entities::{Epoch, ProtocolMessage, SignedEntityType, SingleS
 use mithril_persistence::sqlite::{HydrationError, Projection, SqLiteEntity};
 
 use crate::database::record::OpenMessageRecord;
+use crate::entities::AggregationSummary;
 
 /// Open Message with associated single signatures if any.
 #[derive(Debug, Clone)]
@@ -36,6 +37,9 @@ pub struct OpenMessageWithSingleSignaturesRecord {
 
     /// Message expiration datetime, if it exists.
     pub expires_at: Option<DateTime<Utc>>,
+
+    /// Summary of the last aggregation attempt that did not reach the quorum, if any.
+    pub aggregation_summary: Option<AggregationSummary>,
 }
 
 impl From<OpenMessageWithSingleSignaturesRecord> for OpenMessageRecord {
@@ -49,6 +53,7 @@ impl From<OpenMessageWithSingleSignaturesRecord> for OpenMessageRecord {
             is_expired: value.is_expired,
             created_at: value.created_at,
             expires_at: value.expires_at,
+            aggregation_summary: value.aggregation_summary,
         }
     }
 }
@@ -58,7 +63,7 @@ impl SqLiteEntity for OpenMessageWithSingleSignaturesRecord {
     where
         Self: Sized,
     {
-        let single_signatures = &row.read::<&str, _>(9);
+        let single_signatures = &row.read::<&str, _>(10);
         let single_signatures: Vec<SingleSignature> = serde_json::from_str(single_signatures)
             .map_err(|e| {
                 HydrationError::InvalidData(format!(
@@ -78,6 +83,7 @@ impl SqLiteEntity for OpenMessageWithSingleSignaturesRecord {
             single_signatures,
             created_at: open_message.created_at,
             expires_at: open_message.expires_at,
+            aggregation_summary: open_message.aggregation_summary,
         };
 
         Ok(open_message)
@@ -110,6 +116,11 @@ impl SqLiteEntity for OpenMessageWithSingleSignaturesRecord {
             ("is_expired", "{:open_message:}.is_expired", "bool"),
             ("created_at", "{:open_message:}.created_at", "text"),
             ("expires_at", "{:open_message:}.expires_at", "text"),
+            (
+                "aggregation_summary",
+                "{:open_message:}.aggregation_summary",
+                "text",
+            ),
             (
                 "single_signatures",
                 "case when {:single_signature:}.signer_id is null then json('[]') \
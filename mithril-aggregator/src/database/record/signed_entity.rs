@@ -3,12 +3,16 @@ use serde::{Deserialize, Serialize};
 
 use mithril_common::crypto_helper::ProtocolParameters;
 use mithril_common::entities::{
-    BlockNumber, CardanoDatabaseSnapshot, Epoch, SignedEntityType, Snapshot, StakeDistribution,
+    BlockNumber, CardanoDatabaseSnapshot, Epoch, LedgerProtocolParameters, SignedEntityType,
+    Snapshot, StakeDistribution,
 };
 #[cfg(test)]
-use mithril_common::entities::{CardanoStakeDistribution, MithrilStakeDistribution};
+use mithril_common::entities::{
+    CardanoProtocolParameters, CardanoStakeDistribution, MithrilStakeDistribution,
+};
 use mithril_common::messages::{
     CardanoDatabaseSnapshotListItemMessage, CardanoDatabaseSnapshotMessage,
+    CardanoProtocolParametersListItemMessage, CardanoProtocolParametersMessage,
     CardanoStakeDistributionListItemMessage, CardanoStakeDistributionMessage,
     CardanoTransactionSnapshotListItemMessage, CardanoTransactionSnapshotMessage,
     MithrilStakeDistributionListItemMessage, MithrilStakeDistributionMessage,
@@ -45,6 +49,25 @@ impl From<CardanoStakeDistribution> for SignedEntityRecord {
     }
 }
 
+#[cfg(test)]
+impl From<CardanoProtocolParameters> for SignedEntityRecord {
+    fn from(cardano_protocol_parameters: CardanoProtocolParameters) -> Self {
+        let entity = serde_json::to_string(&cardano_protocol_parameters).unwrap();
+
+        SignedEntityRecord {
+            signed_entity_id: cardano_protocol_parameters.hash.clone(),
+            signed_entity_type: SignedEntityType::CardanoProtocolParameters(
+                cardano_protocol_parameters.epoch,
+            ),
+            certificate_id: format!("certificate-{}", cardano_protocol_parameters.hash),
+            artifact: entity,
+            created_at: DateTime::parse_from_rfc3339("2023-01-19T13:43:05.618857482Z")
+                .unwrap()
+                .with_timezone(&Utc),
+        }
+    }
+}
+
 #[cfg(test)]
 impl From<MithrilStakeDistribution> for SignedEntityRecord {
     fn from(mithril_stake_distribution: MithrilStakeDistribution) -> Self {
@@ -377,6 +400,48 @@ impl TryFrom<SignedEntityRecord> for CardanoStakeDistributionListItemMessage {
     }
 }
 
+impl TryFrom<SignedEntityRecord> for CardanoProtocolParametersMessage {
+    type Error = StdError;
+
+    fn try_from(value: SignedEntityRecord) -> Result<Self, Self::Error> {
+        #[derive(Deserialize)]
+        struct TmpCardanoProtocolParameters {
+            hash: String,
+            protocol_parameters: LedgerProtocolParameters,
+        }
+        let artifact = serde_json::from_str::<TmpCardanoProtocolParameters>(&value.artifact)?;
+        let message = CardanoProtocolParametersMessage {
+            epoch: value.signed_entity_type.get_epoch(),
+            hash: artifact.hash,
+            certificate_hash: value.certificate_id,
+            protocol_parameters: artifact.protocol_parameters,
+            created_at: value.created_at,
+        };
+
+        Ok(message)
+    }
+}
+
+impl TryFrom<SignedEntityRecord> for CardanoProtocolParametersListItemMessage {
+    type Error = StdError;
+
+    fn try_from(value: SignedEntityRecord) -> Result<Self, Self::Error> {
+        #[derive(Deserialize)]
+        struct TmpCardanoProtocolParameters {
+            hash: String,
+        }
+        let artifact = serde_json::from_str::<TmpCardanoProtocolParameters>(&value.artifact)?;
+        let message = CardanoProtocolParametersListItemMessage {
+            epoch: value.signed_entity_type.get_epoch(),
+            hash: artifact.hash,
+            certificate_hash: value.certificate_id,
+            created_at: value.created_at,
+        };
+
+        Ok(message)
+    }
+}
+
 impl SqLiteEntity for SignedEntityRecord {
     fn hydrate(row: sqlite::Row) -> Result<Self, HydrationError>
     where
@@ -0,0 +1,33 @@
+use async_trait::async_trait;
+
+use mithril_common::entities::{BlockHash, BlockNumber, BlockRange, CardanoBlock};
+use mithril_common::StdResult;
+use mithril_persistence::database::repository::CardanoBlockRepository;
+
+use crate::services::CardanoBlocksRetriever;
+
+#[async_trait]
+impl CardanoBlocksRetriever for CardanoBlockRepository {
+    async fn get_by_hashes(
+        &self,
+        hashes: Vec<BlockHash>,
+        up_to: BlockNumber,
+    ) -> StdResult<Vec<CardanoBlock>> {
+        self.get_block_by_hashes(hashes, up_to).await.map(|v| {
+            v.into_iter()
+                .map(|record| record.into())
+                .collect::<Vec<CardanoBlock>>()
+        })
+    }
+
+    async fn get_by_block_ranges(
+        &self,
+        block_ranges: Vec<BlockRange>,
+    ) -> StdResult<Vec<CardanoBlock>> {
+        self.get_block_by_block_ranges(block_ranges).await.map(|v| {
+            v.into_iter()
+                .map(|record| record.into())
+                .collect::<Vec<CardanoBlock>>()
+        })
+    }
+}
@@ -4,7 +4,8 @@ use async_trait::async_trait;
 
 use mithril_common::crypto_helper::MKTreeNode;
 use mithril_common::entities::{
-    BlockNumber, BlockRange, CardanoTransaction, ChainPoint, SlotNumber, TransactionHash,
+    BlockNumber, BlockRange, CardanoBlock, CardanoTransaction, CardanoTransactionFilter,
+    CardanoTransactionIndexEntry, ChainPoint, SlotNumber, TransactionHash,
 };
 use mithril_common::StdResult;
 use mithril_persistence::database::repository::CardanoTransactionRepository;
@@ -17,6 +18,14 @@ impl TransactionStore for CardanoTransactionRepository {
         self.get_transaction_highest_chain_point().await
     }
 
+    async fn get_highest_block_beacon(&self) -> StdResult<Option<ChainPoint>> {
+        self.get_block_highest_chain_point().await
+    }
+
+    async fn get_highest_index_beacon(&self) -> StdResult<Option<ChainPoint>> {
+        self.get_index_highest_chain_point().await
+    }
+
     async fn get_highest_block_range(&self) -> StdResult<Option<BlockRange>> {
         let record = self.retrieve_highest_block_range_root().await?;
         Ok(record.map(|record| record.range))
@@ -47,6 +56,40 @@ impl TransactionStore for CardanoTransactionRepository {
         Ok(())
     }
 
+    async fn store_blocks(&self, blocks: Vec<CardanoBlock>) -> StdResult<()> {
+        self.store_blocks(blocks).await
+    }
+
+    async fn get_blocks_in_range(&self, range: Range<BlockNumber>) -> StdResult<Vec<CardanoBlock>> {
+        self.get_blocks_in_range_blocks(range).await.map(|v| {
+            v.into_iter()
+                .map(|record| record.into())
+                .collect::<Vec<CardanoBlock>>()
+        })
+    }
+
+    async fn get_highest_blocks_block_range(&self) -> StdResult<Option<BlockRange>> {
+        let record = self.retrieve_highest_cardano_block_range_root().await?;
+        Ok(record.map(|record| record.range))
+    }
+
+    async fn store_blocks_block_range_roots(
+        &self,
+        block_ranges: Vec<(BlockRange, MKTreeNode)>,
+    ) -> StdResult<()> {
+        if !block_ranges.is_empty() {
+            self.create_cardano_block_range_roots(block_ranges).await?;
+        }
+        Ok(())
+    }
+
+    async fn store_transactions_index_entries(
+        &self,
+        index_entries: Vec<CardanoTransactionIndexEntry>,
+    ) -> StdResult<()> {
+        self.store_transactions_index_entries(index_entries).await
+    }
+
     async fn remove_rolled_back_transactions_and_block_range(
         &self,
         slot_number: SlotNumber,
@@ -84,4 +127,18 @@ impl TransactionsRetriever for CardanoTransactionRepository {
                     .collect::<Vec<CardanoTransaction>>()
             })
     }
+
+    async fn get_hashes_by_filter(
+        &self,
+        filter: &CardanoTransactionFilter,
+        block_ranges: Vec<BlockRange>,
+    ) -> StdResult<Vec<TransactionHash>> {
+        self.get_transactions_index_entries_by_filter(filter, block_ranges)
+            .await
+            .map(|v| {
+                v.into_iter()
+                    .map(|record| record.transaction_hash)
+                    .collect::<Vec<TransactionHash>>()
+            })
+    }
 }
@@ -1,6 +1,7 @@
 use std::sync::Arc;
 
 use chrono::Utc;
+use uuid::Uuid;
 
 use mithril_common::entities::{Epoch, ProtocolMessage, SignedEntityType};
 use mithril_common::StdResult;
@@ -11,6 +12,7 @@ use crate::database::query::{
     InsertOpenMessageQuery, UpdateOpenMessageQuery,
 };
 use crate::database::record::{OpenMessageRecord, OpenMessageWithSingleSignaturesRecord};
+use crate::entities::AggregationSummary;
 
 /// ## Open message repository
 ///
@@ -91,6 +93,19 @@ impl OpenMessageRepository {
         message.ok_or_else(|| panic!("Updating an open_message should not return nothing."))
     }
 
+    /// Persist the aggregation summary of an open message without touching its other columns.
+    pub async fn update_open_message_aggregation_summary(
+        &self,
+        open_message_id: &Uuid,
+        aggregation_summary: &AggregationSummary,
+    ) -> StdResult<Option<OpenMessageRecord>> {
+        self.connection
+            .fetch_first(UpdateOpenMessageQuery::aggregation_summary(
+                open_message_id,
+                aggregation_summary,
+            )?)
+    }
+
     /// Remove all the [OpenMessageRecord] for the strictly previous epochs of the given epoch in the database.
     /// It returns the number of messages removed.
     pub async fn clean_epoch(&self, epoch: Epoch) -> StdResult<usize> {
@@ -104,6 +119,8 @@ impl OpenMessageRepository {
 
 #[cfg(test)]
 mod tests {
+    use chrono::DateTime;
+
     use mithril_common::entities::{BlockNumber, CardanoDbBeacon};
 
     use crate::database::record::SingleSignatureRecord;
@@ -111,6 +128,7 @@ mod tests {
         insert_epoch_settings, insert_single_signatures_in_db, main_db_connection,
         setup_single_signature_records,
     };
+    use crate::entities::RejectedSingleSignature;
 
     use super::*;
 
@@ -135,7 +153,8 @@ mod tests {
                     }}',
                     1,
                     0,
-                    '2021-07-27T01:02:44.505640275+00:00'
+                    '2021-07-27T01:02:44.505640275+00:00',
+                    null
                 );
 
                 insert into single_signature values(
@@ -287,6 +306,101 @@ mod tests {
         assert_eq!(open_message_updated, open_message_saved);
     }
 
+    #[tokio::test]
+    async fn repository_update_open_message_aggregation_summary() {
+        let connection = get_connection().await;
+        let repository = OpenMessageRepository::new(connection.clone());
+        let signed_entity_type =
+            SignedEntityType::CardanoImmutableFilesFull(CardanoDbBeacon::default());
+        let open_message = repository
+            .create_open_message(Epoch(1), &signed_entity_type, &ProtocolMessage::new())
+            .await
+            .unwrap();
+        assert_eq!(None, open_message.aggregation_summary);
+
+        let mut open_message_updated = open_message;
+        open_message_updated.aggregation_summary = Some(AggregationSummary {
+            quorum: 10,
+            unique_indexes: 4,
+            valid_signatures: 1,
+            valid_signers_stake: 100,
+            total_stake: 1000,
+            rejected_signatures: vec![RejectedSingleSignature {
+                party_id: Some("party_id".to_string()),
+                signer_index: 2,
+                reason: "Lottery for this epoch was lost.".to_string(),
+            }],
+            computed_at: DateTime::parse_from_rfc3339("2024-10-18T10:00:00Z")
+                .unwrap()
+                .with_timezone(&Utc),
+        });
+        repository
+            .update_open_message(&open_message_updated)
+            .await
+            .unwrap();
+
+        let open_message_saved = repository
+            .get_open_message(&signed_entity_type)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(open_message_updated, open_message_saved);
+    }
+
+    #[tokio::test]
+    async fn repository_update_only_the_aggregation_summary_of_an_open_message() {
+        let connection = get_connection().await;
+        let repository = OpenMessageRepository::new(connection.clone());
+        let signed_entity_type =
+            SignedEntityType::CardanoImmutableFilesFull(CardanoDbBeacon::default());
+        let open_message = repository
+            .create_open_message(Epoch(1), &signed_entity_type, &ProtocolMessage::new())
+            .await
+            .unwrap();
+        // Certify the open message behind the back of a reader holding a stale record
+        repository
+            .update_open_message(&OpenMessageRecord {
+                is_certified: true,
+                ..open_message.clone()
+            })
+            .await
+            .unwrap();
+        let aggregation_summary = AggregationSummary {
+            quorum: 10,
+            unique_indexes: 4,
+            valid_signatures: 1,
+            valid_signers_stake: 100,
+            total_stake: 1000,
+            rejected_signatures: vec![],
+            computed_at: DateTime::parse_from_rfc3339("2024-10-18T10:00:00Z")
+                .unwrap()
+                .with_timezone(&Utc),
+        };
+
+        repository
+            .update_open_message_aggregation_summary(
+                &open_message.open_message_id,
+                &aggregation_summary,
+            )
+            .await
+            .unwrap()
+            .expect("the open message should be updated");
+
+        let open_message_saved = repository
+            .get_open_message(&signed_entity_type)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(
+            OpenMessageRecord {
+                is_certified: true,
+                aggregation_summary: Some(aggregation_summary),
+                ..open_message
+            },
+            open_message_saved
+        );
+    }
+
     #[tokio::test]
     async fn repository_clean_open_message() {
         let connection = get_connection().await;
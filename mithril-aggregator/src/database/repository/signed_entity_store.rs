@@ -50,6 +50,12 @@ pub trait SignedEntityStorer: Sync + Send {
         epoch: Epoch,
     ) -> StdResult<Option<SignedEntityRecord>>;
 
+    /// Get Cardano protocol parameters signed entity by epoch
+    async fn get_cardano_protocol_parameters_signed_entity_by_epoch(
+        &self,
+        epoch: Epoch,
+    ) -> StdResult<Option<SignedEntityRecord>>;
+
     /// Perform an update for all the given signed entities.
     async fn update_signed_entities(
         &self,
@@ -141,6 +147,14 @@ impl SignedEntityStorer for SignedEntityStore {
             .fetch_first(GetSignedEntityRecordQuery::cardano_stake_distribution_by_epoch(epoch))
     }
 
+    async fn get_cardano_protocol_parameters_signed_entity_by_epoch(
+        &self,
+        epoch: Epoch,
+    ) -> StdResult<Option<SignedEntityRecord>> {
+        self.connection
+            .fetch_first(GetSignedEntityRecordQuery::cardano_protocol_parameters_by_epoch(epoch))
+    }
+
     async fn update_signed_entities(
         &self,
         signed_entities: Vec<SignedEntityRecord>,
@@ -165,7 +179,10 @@ impl SignedEntityStorer for SignedEntityStore {
 #[cfg(test)]
 mod tests {
     use mithril_common::{
-        entities::{Epoch, MithrilStakeDistribution, Snapshot},
+        entities::{
+            CardanoProtocolParameters, Epoch, LedgerProtocolParameters, MithrilStakeDistribution,
+            Snapshot,
+        },
         signable_builder::SignedEntity,
         test_utils::fake_data,
     };
@@ -362,4 +379,29 @@ mod tests {
 
         assert_eq!(Some(expected_record), record);
     }
+
+    #[tokio::test]
+    async fn get_cardano_protocol_parameters_signed_entity_by_epoch_when_signed_entity_found_for_epoch(
+    ) {
+        let cardano_protocol_parameters =
+            CardanoProtocolParameters::new(Epoch(4), LedgerProtocolParameters::dummy());
+
+        let expected_record: SignedEntityRecord = cardano_protocol_parameters.into();
+
+        let connection = main_db_connection().unwrap();
+        insert_signed_entities(&connection, vec![expected_record.clone()]).unwrap();
+        let store = SignedEntityStore::new(Arc::new(connection));
+
+        let record = store
+            .get_cardano_protocol_parameters_signed_entity_by_epoch(Epoch(4))
+            .await
+            .unwrap();
+        assert_eq!(Some(expected_record), record);
+
+        let record = store
+            .get_cardano_protocol_parameters_signed_entity_by_epoch(Epoch(5))
+            .await
+            .unwrap();
+        assert_eq!(None, record);
+    }
 }
@@ -6,7 +6,9 @@ use mithril_common::cardano_block_scanner::{BlockScanner, CardanoBlockScanner};
 use mithril_common::chain_observer::{
     CardanoCliRunner, ChainObserver, ChainObserverBuilder, ChainObserverType, FakeObserver,
 };
-use mithril_common::chain_reader::{ChainBlockReader, PallasChainReader};
+use mithril_common::chain_reader::{
+    ChainBlockReader, ImmutableFilesChainReader, PallasChainReader,
+};
 use mithril_common::digesters::{CardanoImmutableDigester, ImmutableDigester};
 use mithril_common::entities::SignedEntityTypeDiscriminants;
 use mithril_signed_entity_preloader::{
@@ -31,12 +33,16 @@ impl DependenciesBuilder {
                     .configuration
                     .get_network()
                     .with_context(|| "Dependencies Builder can not get Cardano network while building the chain observer")?;
-                let chain_observer_builder = ChainObserverBuilder::new(
+                let mut chain_observer_builder = ChainObserverBuilder::new(
                     chain_observer_type,
                     cardano_node_socket_path,
                     cardano_network,
                     cardano_cli_runner.as_deref(),
                 );
+                if chain_observer_type == &ChainObserverType::LedgerState {
+                    chain_observer_builder = chain_observer_builder
+                        .with_cardano_db_path(&self.configuration.db_directory());
+                }
 
                 chain_observer_builder
                     .build()
@@ -71,13 +77,30 @@ impl DependenciesBuilder {
     }
 
     async fn build_chain_block_reader(&mut self) -> Result<Arc<Mutex<dyn ChainBlockReader>>> {
-        let chain_block_reader = PallasChainReader::new(
-            &self.configuration.cardano_node_socket_path(),
-            self.configuration.get_network()?,
-            self.root_logger(),
-        );
-
-        Ok(Arc::new(Mutex::new(chain_block_reader)))
+        let extract_transactions_filters = self
+            .configuration
+            .enable_cardano_transactions_filters_extraction();
+        let chain_block_reader: Arc<Mutex<dyn ChainBlockReader>> =
+            if self.configuration.read_blocks_from_immutable_files() {
+                Arc::new(Mutex::new(
+                    ImmutableFilesChainReader::new(
+                        &self.configuration.db_directory(),
+                        self.root_logger(),
+                    )
+                    .with_transactions_filters_extraction(extract_transactions_filters),
+                ))
+            } else {
+                Arc::new(Mutex::new(
+                    PallasChainReader::new(
+                        &self.configuration.cardano_node_socket_path(),
+                        self.configuration.get_network()?,
+                        self.root_logger(),
+                    )
+                    .with_transactions_filters_extraction(extract_transactions_filters),
+                ))
+            };
+
+        Ok(chain_block_reader)
     }
 
     /// Chain reader
@@ -124,10 +147,13 @@ impl DependenciesBuilder {
     pub async fn create_cardano_transactions_preloader(
         &mut self,
     ) -> Result<Arc<CardanoTransactionsPreloader>> {
-        let activation = self
+        let allowed_discriminants = self
             .configuration
-            .compute_allowed_signed_entity_types_discriminants()?
-            .contains(&SignedEntityTypeDiscriminants::CardanoTransactions);
+            .compute_allowed_signed_entity_types_discriminants()?;
+        // Cardano blocks are imported alongside the Cardano transactions
+        let activation = allowed_discriminants
+            .contains(&SignedEntityTypeDiscriminants::CardanoTransactions)
+            || allowed_discriminants.contains(&SignedEntityTypeDiscriminants::CardanoBlocks);
         let cardano_transactions_preloader = CardanoTransactionsPreloader::new(
             self.get_signed_entity_type_lock().await?,
             self.get_transactions_importer().await?,
@@ -184,6 +210,16 @@ mod tests {
         .await;
     }
 
+    #[tokio::test]
+    async fn cardano_transactions_preloader_activated_with_cardano_blocks_signed_entity_type_in_configuration(
+    ) {
+        assert_cardano_transactions_preloader_activation(
+            SignedEntityTypeDiscriminants::CardanoBlocks.to_string(),
+            true,
+        )
+        .await;
+    }
+
     async fn assert_cardano_transactions_preloader_activation(
         signed_entity_types: String,
         expected_activation: bool,
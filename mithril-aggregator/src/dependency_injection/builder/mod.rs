@@ -8,7 +8,7 @@ use std::{path::PathBuf, sync::Arc};
 use tokio::{
     sync::{
         mpsc::{UnboundedReceiver, UnboundedSender},
-        Mutex,
+        Mutex, Notify,
     },
     time::Duration,
 };
@@ -29,7 +29,10 @@ use mithril_common::{
     TickerService,
 };
 use mithril_persistence::{
-    database::repository::CardanoTransactionRepository,
+    database::repository::{
+        CardanoBlockRepository, CardanoTransactionRepository,
+        SqliteImmutableFileDigestCacheProvider,
+    },
     sqlite::{SqliteConnection, SqliteConnectionPool},
 };
 use mithril_signed_entity_lock::SignedEntityTypeLock;
@@ -48,9 +51,9 @@ use crate::{
     file_uploaders::FileUploader,
     http_server::routes::router::{self, RouterConfig, RouterState},
     services::{
-        AggregatorClient, CertifierService, MessageService, MithrilSignerRegistrationFollower,
-        ProverService, SignedEntityService, SignerSynchronizer, Snapshotter,
-        StakeDistributionService, UpkeepService,
+        AggregatorClient, CardanoBlocksProverService, CertifierService, MessageService,
+        MithrilSignerRegistrationFollower, ProverService, SignedEntityService, SignerSynchronizer,
+        Snapshotter, StakeDistributionService, UpkeepService,
     },
     tools::file_archiver::FileArchiver,
     AggregatorConfig, AggregatorRunner, AggregatorRuntime, ImmutableFileDigestMapper,
@@ -87,6 +90,8 @@ macro_rules! get_dependency {
 const SQLITE_FILE: &str = "aggregator.sqlite3";
 const SQLITE_FILE_CARDANO_TRANSACTION: &str = "cardano-transaction.sqlite3";
 const SQLITE_MONITORING_FILE: &str = "monitoring.sqlite3";
+const SQLITE_FILE_IMMUTABLE_FILE_DIGESTS_CACHE: &str = "immutable-file-digests-cache.sqlite3";
+const SQLITE_IMMUTABLE_FILE_DIGESTS_CACHE_CONNECTION_POOL_SIZE: usize = 4;
 const CARDANO_DB_ARTIFACTS_DIR: &str = "cardano-database";
 const SNAPSHOT_ARTIFACTS_DIR: &str = "cardano-immutable-files-full";
 
@@ -151,6 +156,9 @@ pub struct DependenciesBuilder {
     /// Cardano transactions repository.
     pub transaction_repository: Option<Arc<CardanoTransactionRepository>>,
 
+    /// Cardano blocks repository.
+    pub cardano_block_repository: Option<Arc<CardanoBlockRepository>>,
+
     /// Cardano block scanner.
     pub block_scanner: Option<Arc<dyn BlockScanner>>,
 
@@ -163,6 +171,10 @@ pub struct DependenciesBuilder {
     /// Immutable cache provider service.
     pub immutable_cache_provider: Option<Arc<dyn ImmutableFileDigestCacheProvider>>,
 
+    /// SQLite immutable file digests cache, shared by the cache provider and the digest mapper.
+    pub sqlite_immutable_file_digest_cache_provider:
+        Option<Arc<SqliteImmutableFileDigestCacheProvider>>,
+
     /// Immutable file digest mapper service.
     pub immutable_file_digest_mapper: Option<Arc<dyn ImmutableFileDigestMapper>>,
 
@@ -241,6 +253,9 @@ pub struct DependenciesBuilder {
     /// Certifier service
     pub certifier_service: Option<Arc<dyn CertifierService>>,
 
+    /// Notifier of the open messages reaching the quorum
+    pub quorum_notifier: Option<Arc<Notify>>,
+
     /// Epoch service.
     pub epoch_service: Option<EpochServiceWrapper>,
 
@@ -253,6 +268,9 @@ pub struct DependenciesBuilder {
     /// Prover service
     pub prover_service: Option<Arc<dyn ProverService>>,
 
+    /// Cardano blocks prover service
+    pub cardano_blocks_prover_service: Option<Arc<dyn CardanoBlocksProverService>>,
+
     /// Signed Entity Type Lock
     pub signed_entity_type_lock: Option<Arc<SignedEntityTypeLock>>,
 
@@ -296,9 +314,11 @@ impl DependenciesBuilder {
             chain_block_reader: None,
             block_scanner: None,
             transaction_repository: None,
+            cardano_block_repository: None,
             immutable_digester: None,
             immutable_file_observer: None,
             immutable_cache_provider: None,
+            sqlite_immutable_file_digest_cache_provider: None,
             immutable_file_digest_mapper: None,
             digester: None,
             file_archiver: None,
@@ -324,10 +344,12 @@ impl DependenciesBuilder {
             signable_builder_service: None,
             signed_entity_service: None,
             certifier_service: None,
+            quorum_notifier: None,
             epoch_service: None,
             signed_entity_storer: None,
             message_service: None,
             prover_service: None,
+            cardano_blocks_prover_service: None,
             signed_entity_type_lock: None,
             transactions_importer: None,
             upkeep_service: None,
@@ -386,6 +408,7 @@ impl DependenciesBuilder {
             signer_getter: self.get_signer_store().await?,
             message_service: self.get_message_service().await?,
             prover_service: self.get_prover_service().await?,
+            cardano_blocks_prover_service: self.get_cardano_blocks_prover_service().await?,
             signed_entity_type_lock: self.get_signed_entity_type_lock().await?,
             upkeep_service: self.get_upkeep_service().await?,
             single_signer_authenticator: self.get_single_signature_authenticator().await?,
@@ -413,7 +436,8 @@ impl DependenciesBuilder {
         .map_err(|e| DependenciesBuilderError::Initialization {
             message: "Cannot initialize Aggregator runtime.".to_string(),
             error: Some(e.into()),
-        })?;
+        })?
+        .with_quorum_notifier(self.get_quorum_notifier().await?);
 
         Ok(runtime)
     }
@@ -435,10 +459,16 @@ impl DependenciesBuilder {
                 cardano_transactions_prover_max_hashes_allowed_by_request: self
                     .configuration
                     .cardano_transactions_prover_max_hashes_allowed_by_request(),
+                cardano_transactions_prover_max_block_ranges_allowed_by_request: self
+                    .configuration
+                    .cardano_transactions_prover_max_block_ranges_allowed_by_request(),
                 cardano_db_artifacts_directory: self.get_cardano_db_artifacts_dir()?,
                 snapshot_directory: snapshot_dir.join(SNAPSHOT_ARTIFACTS_DIR),
                 cardano_node_version: self.configuration.cardano_node_version(),
                 allow_http_serve_directory: self.configuration.allow_http_serve_directory(),
+                allow_cardano_transactions_search: self
+                    .configuration
+                    .enable_cardano_transactions_filters_extraction(),
                 origin_tag_white_list: self.configuration.compute_origin_tag_white_list(),
             },
         );
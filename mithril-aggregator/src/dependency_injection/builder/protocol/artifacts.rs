@@ -4,10 +4,12 @@ use std::path::PathBuf;
 use std::sync::Arc;
 
 use mithril_common::crypto_helper::ManifestSigner;
+use mithril_common::signable_builder::ChainObserverProtocolParametersRetriever;
 
 use crate::artifact_builder::{
-    AncillaryArtifactBuilder, AncillaryFileUploader, CardanoDatabaseArtifactBuilder,
-    CardanoImmutableFilesFullArtifactBuilder, CardanoStakeDistributionArtifactBuilder,
+    AncillaryArtifactBuilder, AncillaryFileUploader, CardanoBlocksArtifactBuilder,
+    CardanoDatabaseArtifactBuilder, CardanoImmutableFilesFullArtifactBuilder,
+    CardanoProtocolParametersArtifactBuilder, CardanoStakeDistributionArtifactBuilder,
     CardanoTransactionsArtifactBuilder, DigestArtifactBuilder, DigestFileUploader,
     DigestSnapshotter, ImmutableArtifactBuilder, ImmutableFilesUploader,
     MithrilStakeDistributionArtifactBuilder,
@@ -60,12 +62,22 @@ impl DependenciesBuilder {
             self.build_cardano_database_artifact_builder(cardano_node_version)
                 .await?,
         );
+        let cardano_blocks_prover_service = self.get_cardano_blocks_prover_service().await?;
+        let cardano_blocks_artifact_builder = Arc::new(CardanoBlocksArtifactBuilder::new(
+            cardano_blocks_prover_service.clone(),
+        ));
+        let cardano_protocol_parameters_artifact_builder =
+            Arc::new(CardanoProtocolParametersArtifactBuilder::new(Arc::new(
+                ChainObserverProtocolParametersRetriever::new(self.get_chain_observer().await?),
+            )));
         let dependencies = SignedEntityServiceArtifactsDependencies::new(
             mithril_stake_distribution_artifact_builder,
             cardano_immutable_files_full_artifact_builder,
             cardano_transactions_artifact_builder,
             cardano_stake_distribution_artifact_builder,
             cardano_database_artifact_builder,
+            cardano_blocks_artifact_builder,
+            cardano_protocol_parameters_artifact_builder,
         );
         let signed_entity_service = Arc::new(MithrilSignedEntityService::new(
             signed_entity_storer,
@@ -86,6 +98,14 @@ impl DependenciesBuilder {
                 .compute_cache(signed_entity.artifact.block_number)
                 .await?;
         }
+        if let Some(signed_entity) = signed_entity_service
+            .get_last_cardano_blocks_snapshot()
+            .await?
+        {
+            cardano_blocks_prover_service
+                .compute_cache(signed_entity.artifact.block_number)
+                .await?;
+        }
 
         Ok(signed_entity_service)
     }
@@ -1,5 +1,6 @@
 use anyhow::Context;
 use std::sync::Arc;
+use tokio::sync::Notify;
 
 use mithril_common::certificate_chain::{CertificateVerifier, MithrilCertificateVerifier};
 use mithril_common::crypto_helper::{
@@ -46,6 +47,7 @@ impl DependenciesBuilder {
         let genesis_verifier = self.get_genesis_verifier().await?;
         let multi_signer = self.get_multi_signer().await?;
         let epoch_service = self.get_epoch_service().await?;
+        let quorum_notifier = self.get_quorum_notifier().await?;
         let logger = self.root_logger();
 
         let certifier = Arc::new(MithrilCertifierService::new(
@@ -57,6 +59,7 @@ impl DependenciesBuilder {
             genesis_verifier,
             multi_signer,
             epoch_service,
+            quorum_notifier,
             logger,
         ));
 
@@ -72,6 +75,15 @@ impl DependenciesBuilder {
         get_dependency!(self.certifier_service)
     }
 
+    async fn build_quorum_notifier(&mut self) -> Result<Arc<Notify>> {
+        Ok(Arc::new(Notify::new()))
+    }
+
+    /// Notifier of the open messages reaching the quorum, used to wake up the runtime
+    pub async fn get_quorum_notifier(&mut self) -> Result<Arc<Notify>> {
+        get_dependency!(self.quorum_notifier)
+    }
+
     async fn build_multi_signer(&mut self) -> Result<Arc<dyn MultiSigner>> {
         let multi_signer =
             MultiSignerImpl::new(self.get_epoch_service().await?, self.root_logger());
@@ -1,10 +1,13 @@
 use std::sync::Arc;
 
-use mithril_common::crypto_helper::MKTreeStoreInMemory;
+use mithril_common::crypto_helper::{MKTreeStoreInMemory, MKTreeStoreSqlite};
 
 use crate::dependency_injection::{DependenciesBuilder, Result};
 use crate::get_dependency;
-use crate::services::{MithrilProverService, ProverService};
+use crate::services::{
+    CardanoBlocksProverService, MithrilCardanoBlocksProverService, MithrilProverService,
+    ProverService,
+};
 impl DependenciesBuilder {
     /// Build Prover service
     pub async fn build_prover_service(&mut self) -> Result<Arc<dyn ProverService>> {
@@ -14,18 +17,67 @@ impl DependenciesBuilder {
         let transaction_retriever = self.get_transaction_repository().await?;
         let block_range_root_retriever = self.get_transaction_repository().await?;
         let logger = self.root_logger();
-        let prover_service = MithrilProverService::<MKTreeStoreInMemory>::new(
-            transaction_retriever,
-            block_range_root_retriever,
-            mk_map_pool_size,
-            logger,
-        );
+        let prover_service: Arc<dyn ProverService> =
+            if self.configuration.use_disk_backed_merkle_tree_store() {
+                Arc::new(MithrilProverService::<MKTreeStoreSqlite>::new(
+                    transaction_retriever,
+                    block_range_root_retriever,
+                    mk_map_pool_size,
+                    logger,
+                ))
+            } else {
+                Arc::new(MithrilProverService::<MKTreeStoreInMemory>::new(
+                    transaction_retriever,
+                    block_range_root_retriever,
+                    mk_map_pool_size,
+                    logger,
+                ))
+            };
 
-        Ok(Arc::new(prover_service))
+        Ok(prover_service)
     }
 
     /// [ProverService] service
     pub async fn get_prover_service(&mut self) -> Result<Arc<dyn ProverService>> {
         get_dependency!(self.prover_service)
     }
+
+    /// Build Cardano blocks prover service
+    pub async fn build_cardano_blocks_prover_service(
+        &mut self,
+    ) -> Result<Arc<dyn CardanoBlocksProverService>> {
+        let mk_map_pool_size = self
+            .configuration
+            .cardano_transactions_prover_cache_pool_size();
+        let blocks_retriever = self.get_cardano_block_repository().await?;
+        let block_range_root_retriever = self.get_cardano_block_repository().await?;
+        let logger = self.root_logger();
+        let prover_service: Arc<dyn CardanoBlocksProverService> =
+            if self.configuration.use_disk_backed_merkle_tree_store() {
+                Arc::new(MithrilCardanoBlocksProverService::<MKTreeStoreSqlite>::new(
+                    blocks_retriever,
+                    block_range_root_retriever,
+                    mk_map_pool_size,
+                    logger,
+                ))
+            } else {
+                Arc::new(
+                    MithrilCardanoBlocksProverService::<MKTreeStoreInMemory>::new(
+                        blocks_retriever,
+                        block_range_root_retriever,
+                        mk_map_pool_size,
+                        logger,
+                    ),
+                )
+            };
+
+        Ok(prover_service)
+    }
+
+    /// [CardanoBlocksProverService] service
+    pub async fn get_cardano_blocks_prover_service(
+        &mut self,
+    ) -> Result<Arc<dyn CardanoBlocksProverService>> {
+        get_dependency!(self.cardano_blocks_prover_service)
+    }
 }
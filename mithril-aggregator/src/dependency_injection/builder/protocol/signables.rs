@@ -1,10 +1,13 @@
 use std::sync::Arc;
 
-use mithril_common::crypto_helper::MKTreeStoreInMemory;
+use mithril_common::crypto_helper::{MKTreeStoreInMemory, MKTreeStoreSqlite};
+use mithril_common::entities::BlockNumber;
 use mithril_common::signable_builder::{
-    CardanoDatabaseSignableBuilder, CardanoImmutableFilesFullSignableBuilder,
+    CardanoBlocksSignableBuilder, CardanoDatabaseSignableBuilder,
+    CardanoImmutableFilesFullSignableBuilder, CardanoProtocolParametersSignableBuilder,
     CardanoStakeDistributionSignableBuilder, CardanoTransactionsSignableBuilder,
-    MithrilSignableBuilderService, MithrilStakeDistributionSignableBuilder, SignableBuilderService,
+    ChainObserverProtocolParametersRetriever, MithrilSignableBuilderService,
+    MithrilStakeDistributionSignableBuilder, SignableBuilder, SignableBuilderService,
     SignableBuilderServiceDependencies, SignableSeedBuilder, TransactionsImporter,
 };
 
@@ -23,12 +26,37 @@ impl DependenciesBuilder {
         ));
         let transactions_importer = self.get_transactions_importer().await?;
         let block_range_root_retriever = self.get_transaction_repository().await?;
-        let cardano_transactions_builder = Arc::new(CardanoTransactionsSignableBuilder::<
-            MKTreeStoreInMemory,
-        >::new(
-            transactions_importer,
-            block_range_root_retriever,
-        ));
+        let cardano_block_repository = self.get_cardano_block_repository().await?;
+        let (cardano_transactions_builder, cardano_blocks_builder): (
+            Arc<dyn SignableBuilder<BlockNumber>>,
+            Arc<dyn SignableBuilder<BlockNumber>>,
+        ) = if self.configuration.use_disk_backed_merkle_tree_store() {
+            (
+                Arc::new(
+                    CardanoTransactionsSignableBuilder::<MKTreeStoreSqlite>::new(
+                        transactions_importer.clone(),
+                        block_range_root_retriever,
+                    ),
+                ),
+                Arc::new(CardanoBlocksSignableBuilder::<MKTreeStoreSqlite>::new(
+                    transactions_importer,
+                    cardano_block_repository,
+                )),
+            )
+        } else {
+            (
+                Arc::new(
+                    CardanoTransactionsSignableBuilder::<MKTreeStoreInMemory>::new(
+                        transactions_importer.clone(),
+                        block_range_root_retriever,
+                    ),
+                ),
+                Arc::new(CardanoBlocksSignableBuilder::<MKTreeStoreInMemory>::new(
+                    transactions_importer,
+                    cardano_block_repository,
+                )),
+            )
+        };
         let cardano_stake_distribution_builder = Arc::new(
             CardanoStakeDistributionSignableBuilder::new(self.get_stake_store().await?),
         );
@@ -37,12 +65,18 @@ impl DependenciesBuilder {
             &self.configuration.db_directory(),
             self.root_logger(),
         ));
+        let cardano_protocol_parameters_builder =
+            Arc::new(CardanoProtocolParametersSignableBuilder::new(Arc::new(
+                ChainObserverProtocolParametersRetriever::new(self.get_chain_observer().await?),
+            )));
         let signable_builders_dependencies = SignableBuilderServiceDependencies::new(
             mithril_stake_distribution_builder,
             immutable_signable_builder,
             cardano_transactions_builder,
             cardano_stake_distribution_builder,
             cardano_database_signable_builder,
+            cardano_blocks_builder,
+            cardano_protocol_parameters_builder,
         );
         let signable_builder_service = Arc::new(MithrilSignableBuilderService::new(
             seed_signable_builder,
@@ -74,11 +108,17 @@ impl DependenciesBuilder {
     }
 
     async fn build_transactions_importer(&mut self) -> Result<Arc<dyn TransactionsImporter>> {
-        let transactions_importer = Arc::new(CardanoTransactionsImporter::new(
-            self.get_block_scanner().await?,
-            self.get_transaction_repository().await?,
-            self.root_logger(),
-        ));
+        let transactions_importer = Arc::new(
+            CardanoTransactionsImporter::new(
+                self.get_block_scanner().await?,
+                self.get_transaction_repository().await?,
+                self.root_logger(),
+            )
+            .with_transactions_filters_extraction(
+                self.configuration
+                    .enable_cardano_transactions_filters_extraction(),
+            ),
+        );
 
         Ok(transactions_importer)
     }
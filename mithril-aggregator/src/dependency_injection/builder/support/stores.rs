@@ -4,17 +4,25 @@ use std::sync::Arc;
 use std::time::Duration;
 
 use mithril_common::digesters::cache::ImmutableFileDigestCacheProvider;
-use mithril_persistence::database::repository::CardanoTransactionRepository;
+use mithril_persistence::database::repository::{
+    CardanoBlockRepository, CardanoTransactionRepository, SqliteImmutableFileDigestCacheProvider,
+    SqliteImmutableFileDigestCacheProviderBuilder,
+};
+use mithril_persistence::database::ApplicationNodeType;
 
 use crate::database::repository::{
     CertificateRepository, EpochSettingsStore, ImmutableFileDigestRepository,
     OpenMessageRepository, SignedEntityStore, SignedEntityStorer, SignerRegistrationStore,
     SignerStore, StakePoolStore,
 };
+use crate::dependency_injection::builder::{
+    SQLITE_FILE_IMMUTABLE_FILE_DIGESTS_CACHE,
+    SQLITE_IMMUTABLE_FILE_DIGESTS_CACHE_CONNECTION_POOL_SIZE,
+};
 use crate::dependency_injection::{DependenciesBuilder, DependenciesBuilderError, Result};
 use crate::get_dependency;
 use crate::{
-    CExplorerSignerRetriever, EpochSettingsStorer, ImmutableFileDigestMapper,
+    CExplorerSignerRetriever, EpochSettingsStorer, ExecutionEnvironment, ImmutableFileDigestMapper,
     ProtocolParametersRetriever, SignersImporter, VerificationKeyStorer,
 };
 
@@ -141,21 +149,56 @@ impl DependenciesBuilder {
         get_dependency!(self.epoch_settings_store)
     }
 
-    async fn build_immutable_cache_provider(
+    async fn build_sqlite_immutable_file_digest_cache_provider(
         &mut self,
-    ) -> Result<Arc<dyn ImmutableFileDigestCacheProvider>> {
-        let cache_provider =
-            ImmutableFileDigestRepository::new(self.get_sqlite_connection().await?);
-        if self.configuration.reset_digests_cache() {
+    ) -> Result<Arc<SqliteImmutableFileDigestCacheProvider>> {
+        let cache_provider = SqliteImmutableFileDigestCacheProviderBuilder::new(
+            &self
+                .configuration
+                .get_sqlite_dir()
+                .join(SQLITE_FILE_IMMUTABLE_FILE_DIGESTS_CACHE),
+        )
+        .with_connection_pool_size(SQLITE_IMMUTABLE_FILE_DIGESTS_CACHE_CONNECTION_POOL_SIZE)
+        .should_reset_digests_cache(self.configuration.reset_digests_cache())
+        .with_node_type(ApplicationNodeType::Aggregator)
+        .with_logger(self.root_logger())
+        .build()
+        .await
+        .with_context(|| "Dependencies Builder can not build immutable file digests cache")?;
+
+        // Digests previously cached in the aggregator database are imported once in the cache
+        if !self.configuration.reset_digests_cache()
+            && cache_provider.get_all_digests().await?.is_empty()
+        {
+            let legacy_digests =
+                ImmutableFileDigestRepository::new(self.get_sqlite_connection().await?)
+                    .get_immutable_file_digest_map()
+                    .await?;
             cache_provider
-                .reset()
-                .await
-                .with_context(|| "Failure occurred when resetting immutable file digest cache")?;
+                .store_digests(legacy_digests.into_iter().collect())
+                .await?;
         }
 
         Ok(Arc::new(cache_provider))
     }
 
+    /// Get the SQLite immutable file digests cache
+    pub async fn get_sqlite_immutable_file_digest_cache_provider(
+        &mut self,
+    ) -> Result<Arc<SqliteImmutableFileDigestCacheProvider>> {
+        get_dependency!(self.sqlite_immutable_file_digest_cache_provider)
+    }
+
+    async fn build_immutable_cache_provider(
+        &mut self,
+    ) -> Result<Arc<dyn ImmutableFileDigestCacheProvider>> {
+        let cache_provider: Arc<dyn ImmutableFileDigestCacheProvider> = self
+            .get_sqlite_immutable_file_digest_cache_provider()
+            .await?;
+
+        Ok(cache_provider)
+    }
+
     /// Get an [ImmutableFileDigestCacheProvider]
     pub async fn get_immutable_cache_provider(
         &mut self,
@@ -179,12 +222,34 @@ impl DependenciesBuilder {
         get_dependency!(self.transaction_repository)
     }
 
+    async fn build_cardano_block_repository(&mut self) -> Result<Arc<CardanoBlockRepository>> {
+        let block_repository = CardanoBlockRepository::new(
+            self.get_sqlite_connection_cardano_transaction_pool()
+                .await?,
+        );
+
+        Ok(Arc::new(block_repository))
+    }
+
+    /// Cardano block repository.
+    pub async fn get_cardano_block_repository(&mut self) -> Result<Arc<CardanoBlockRepository>> {
+        get_dependency!(self.cardano_block_repository)
+    }
+
     async fn build_immutable_file_digest_mapper(
         &mut self,
     ) -> Result<Arc<dyn ImmutableFileDigestMapper>> {
-        let mapper = ImmutableFileDigestRepository::new(self.get_sqlite_connection().await?);
-
-        Ok(Arc::new(mapper))
+        let mapper: Arc<dyn ImmutableFileDigestMapper> = match self.configuration.environment() {
+            ExecutionEnvironment::Production => {
+                self.get_sqlite_immutable_file_digest_cache_provider()
+                    .await?
+            }
+            _ => Arc::new(ImmutableFileDigestRepository::new(
+                self.get_sqlite_connection().await?,
+            )),
+        };
+
+        Ok(mapper)
     }
 
     /// Immutable digest mapper.
@@ -25,8 +25,9 @@ use crate::{
     entities::AggregatorEpochSettings,
     event_store::{EventMessage, TransmitterService},
     services::{
-        CertifierService, EpochService, MessageService, ProverService, SignedEntityService,
-        SignerRecorder, SignerSynchronizer, StakeDistributionService, UpkeepService,
+        CardanoBlocksProverService, CertifierService, EpochService, MessageService, ProverService,
+        SignedEntityService, SignerRecorder, SignerSynchronizer, StakeDistributionService,
+        UpkeepService,
     },
     EpochSettingsStorer, MetricsService, SignerRegisterer, SignerRegistrationRoundOpener,
     SingleSignatureAuthenticator, VerificationKeyStorer,
@@ -111,6 +112,9 @@ pub struct ServeCommandDependenciesContainer {
     /// Prover service
     pub prover_service: Arc<dyn ProverService>,
 
+    /// Cardano blocks prover service
+    pub cardano_blocks_prover_service: Arc<dyn CardanoBlocksProverService>,
+
     /// Signed Entity Type Lock
     pub signed_entity_type_lock: Arc<SignedEntityTypeLock>,
 
@@ -0,0 +1,155 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use mithril_common::crypto_helper::{ProtocolAggregationReport, ProtocolSignatureStatus};
+use mithril_common::entities::{PartyId, Stake};
+use mithril_common::protocol::SingleSignaturesAggregator;
+
+/// ## AggregationSummary
+///
+/// Summary of the last attempt to aggregate the single signatures of an open message, persisted
+/// to explain why the quorum was not reached.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AggregationSummary {
+    /// Number of unique lottery indexes required to reach the quorum
+    pub quorum: u64,
+
+    /// Number of unique lottery indexes covered by the valid single signatures
+    pub unique_indexes: u64,
+
+    /// Number of valid single signatures
+    pub valid_signatures: u64,
+
+    /// Stake of the signers of the valid single signatures
+    pub valid_signers_stake: Stake,
+
+    /// Total stake of the registered signers
+    pub total_stake: Stake,
+
+    /// Single signatures that were not part of the aggregation
+    pub rejected_signatures: Vec<RejectedSingleSignature>,
+
+    /// Date and time of the aggregation attempt
+    pub computed_at: DateTime<Utc>,
+}
+
+/// A single signature that was not part of the aggregation
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RejectedSingleSignature {
+    /// Party id of the signer, if known
+    pub party_id: Option<PartyId>,
+
+    /// Index of the signer in the protocol registration
+    pub signer_index: u64,
+
+    /// Reason of the rejection
+    pub reason: String,
+}
+
+impl AggregationSummary {
+    /// Summarize the aggregation report of the given single signatures aggregator
+    pub fn from_aggregator(aggregator: &SingleSignaturesAggregator) -> Self {
+        Self::from_report(&aggregator.aggregation_report(), |signer_index| {
+            aggregator.get_party_id(signer_index).cloned()
+        })
+    }
+
+    fn from_report(
+        report: &ProtocolAggregationReport,
+        get_party_id: impl Fn(u64) -> Option<PartyId>,
+    ) -> Self {
+        let rejected_signatures = report
+            .signatures
+            .iter()
+            .filter_map(|signature| {
+                let reason = match &signature.status {
+                    ProtocolSignatureStatus::Valid => return None,
+                    ProtocolSignatureStatus::AlreadySubmitted => {
+                        "A signature was already submitted by the signer".to_string()
+                    }
+                    ProtocolSignatureStatus::Invalid(error) => error.to_string(),
+                };
+
+                Some(RejectedSingleSignature {
+                    party_id: get_party_id(signature.signer_index),
+                    signer_index: signature.signer_index,
+                    reason,
+                })
+            })
+            .collect();
+
+        Self {
+            quorum: report.params.k,
+            unique_indexes: report.unique_indexes_count(),
+            valid_signatures: report.valid_signatures_count(),
+            valid_signers_stake: report.valid_signers_stake(),
+            total_stake: report.total_stake,
+            rejected_signatures,
+            computed_at: Utc::now(),
+        }
+    }
+
+    /// Check if the valid single signatures reach the quorum
+    pub fn is_quorum_reached(&self) -> bool {
+        self.unique_indexes >= self.quorum
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use mithril_common::entities::{ProtocolMessage, ProtocolMessagePartKey};
+    use mithril_common::protocol::SignerBuilder;
+    use mithril_common::test_utils::MithrilFixtureBuilder;
+
+    use super::*;
+
+    #[test]
+    fn summarize_the_valid_and_rejected_single_signatures() {
+        let fixture = MithrilFixtureBuilder::default().with_signers(3).build();
+        let multi_signer = SignerBuilder::new(
+            &fixture.signers_with_stake(),
+            &fixture.protocol_parameters(),
+        )
+        .unwrap()
+        .build_multi_signer();
+        let message = ProtocolMessage::default();
+        let mut other_message = ProtocolMessage::default();
+        other_message.set_message_part(
+            ProtocolMessagePartKey::SnapshotDigest,
+            "a_digest".to_string(),
+        );
+        let signers_fixture = fixture.signers_fixture();
+        let valid_signature = signers_fixture[0].sign(&message).unwrap();
+        let signature_of_other_message = signers_fixture[1].sign(&other_message).unwrap();
+
+        let mut aggregator = multi_signer.incremental_aggregator(&message);
+        aggregator.add_single_signature(&valid_signature).unwrap();
+        let _ = aggregator.add_single_signature(&signature_of_other_message);
+        let summary = AggregationSummary::from_aggregator(&aggregator);
+
+        assert_eq!(fixture.protocol_parameters().k, summary.quorum);
+        assert_eq!(
+            valid_signature.won_indexes.len() as u64,
+            summary.unique_indexes
+        );
+        assert_eq!(1, summary.valid_signatures);
+        let signers_with_stake = fixture.signers_with_stake();
+        let valid_signer = signers_with_stake
+            .iter()
+            .find(|signer| signer.party_id == valid_signature.party_id)
+            .unwrap();
+        assert_eq!(valid_signer.stake, summary.valid_signers_stake);
+        assert_eq!(
+            signers_with_stake
+                .iter()
+                .map(|signer| signer.stake)
+                .sum::<Stake>(),
+            summary.total_stake
+        );
+        assert_eq!(1, summary.rejected_signatures.len());
+        assert_eq!(
+            Some(signature_of_other_message.party_id),
+            summary.rejected_signatures[0].party_id
+        );
+    }
+}
@@ -2,12 +2,14 @@
 //!
 //! This module provide domain entities for the services & state machine.
 //!
+mod aggregation_summary;
 mod aggregator_epoch_settings;
 mod leader_aggregator_epoch_settings;
 mod open_message;
 mod signer_registration_message;
 mod signer_ticker_message;
 
+pub use aggregation_summary::{AggregationSummary, RejectedSingleSignature};
 pub use aggregator_epoch_settings::AggregatorEpochSettings;
 pub use leader_aggregator_epoch_settings::LeaderAggregatorEpochSettings;
 pub use open_message::OpenMessage;
@@ -128,6 +128,7 @@ mod test {
             is_expired: false,
             created_at,
             expires_at: None,
+            aggregation_summary: None,
         };
         let expected = OpenMessage {
             epoch: Epoch(1),
@@ -156,6 +157,7 @@ mod test {
             is_expired: false,
             created_at,
             expires_at: None,
+            aggregation_summary: None,
             single_signatures: vec![fake_data::single_signature(vec![1, 4, 5])],
         };
         let expected = OpenMessage {
@@ -1,4 +1,5 @@
 pub mod cardano_database;
+pub mod cardano_protocol_parameters;
 pub mod cardano_stake_distribution;
 pub mod cardano_transaction;
 pub mod mithril_stake_distribution;
@@ -11,7 +11,10 @@ use crate::dependency_injection::EpochServiceWrapper;
 use crate::event_store::{EventMessage, TransmitterService};
 use crate::http_server::routes::http_server_child_logger;
 use crate::http_server::routes::router::{RouterConfig, RouterState};
-use crate::services::{CertifierService, MessageService, ProverService, SignedEntityService};
+use crate::services::{
+    CardanoBlocksProverService, CertifierService, MessageService, ProverService,
+    SignedEntityService,
+};
 use crate::{
     MetricsService, SignerRegisterer, SingleSignatureAuthenticator, VerificationKeyStorer,
 };
@@ -131,6 +134,17 @@ pub fn with_prover_service(
     warp::any().map(move || prover_service.clone())
 }
 
+/// With Cardano blocks prover service
+pub fn with_cardano_blocks_prover_service(
+    router_state: &RouterState,
+) -> impl Filter<Extract = (Arc<dyn CardanoBlocksProverService>,), Error = Infallible> + Clone {
+    let prover_service = router_state
+        .dependencies
+        .cardano_blocks_prover_service
+        .clone();
+    warp::any().map(move || prover_service.clone())
+}
+
 /// With Single Signature Authenticator
 pub fn with_single_signature_authenticator(
     router_state: &RouterState,
@@ -163,7 +177,10 @@ pub fn with_origin_tag(
 }
 
 pub mod validators {
-    use crate::http_server::validators::ProverTransactionsHashValidator;
+    use crate::http_server::validators::{
+        ProverBlocksHashValidator, ProverTransactionsHashValidator,
+        ProverTransactionsSearchValidator,
+    };
 
     use super::*;
 
@@ -177,6 +194,29 @@ pub mod validators {
 
         warp::any().map(move || ProverTransactionsHashValidator::new(max_hashes))
     }
+
+    /// With Prover Blocks Hash Validator
+    pub fn with_prover_blocks_hash_validator(
+        router_state: &RouterState,
+    ) -> impl Filter<Extract = (ProverBlocksHashValidator,), Error = Infallible> + Clone {
+        let max_hashes = router_state
+            .configuration
+            .cardano_transactions_prover_max_hashes_allowed_by_request;
+
+        warp::any().map(move || ProverBlocksHashValidator::new(max_hashes))
+    }
+
+    /// With Prover Transactions Search Validator
+    pub fn with_prover_transactions_search_validator(
+        router_state: &RouterState,
+    ) -> impl Filter<Extract = (ProverTransactionsSearchValidator,), Error = Infallible> + Clone
+    {
+        let max_block_ranges = router_state
+            .configuration
+            .cardano_transactions_prover_max_block_ranges_allowed_by_request;
+
+        warp::any().map(move || ProverTransactionsSearchValidator::new(max_block_ranges))
+    }
 }
 
 #[cfg(test)]
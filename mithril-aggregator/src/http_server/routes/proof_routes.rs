@@ -1,6 +1,10 @@
 use serde::{Deserialize, Serialize};
 use warp::Filter;
 
+use mithril_common::entities::{
+    BlockNumber, BlockRange, BlockRangesSequence, CardanoTransactionFilter, MetadataLabel,
+};
+
 use crate::http_server::routes::middlewares;
 use crate::http_server::routes::router::RouterState;
 
@@ -25,10 +29,85 @@ impl CardanoTransactionProofQueryParams {
     }
 }
 
+#[derive(Deserialize, Serialize, Debug)]
+struct CardanoTransactionSearchProofQueryParams {
+    address: Option<String>,
+    metadata_label: Option<MetadataLabel>,
+    from_block_number: u64,
+    to_block_number: u64,
+}
+
+impl CardanoTransactionSearchProofQueryParams {
+    /// The filter of the search, if exactly one filter was given
+    pub fn filter(&self) -> Option<CardanoTransactionFilter> {
+        match (&self.address, self.metadata_label) {
+            (Some(address), None) => Some(CardanoTransactionFilter::Address(address.clone())),
+            (None, Some(label)) => Some(CardanoTransactionFilter::MetadataLabel(label)),
+            _ => None,
+        }
+    }
+
+    /// The block ranges that overlap the `[from_block_number, to_block_number)` interval
+    pub fn block_ranges(&self) -> Vec<BlockRange> {
+        if self.to_block_number <= self.from_block_number {
+            return vec![];
+        }
+
+        let last_block_number = BlockNumber(self.to_block_number - 1);
+        BlockRangesSequence::new(
+            BlockRange::start(BlockNumber(self.from_block_number))
+                ..=BlockRange::start(last_block_number) + BlockRange::LENGTH - 1,
+        )
+        .into_vec()
+    }
+}
+
+#[derive(Deserialize, Serialize, Debug)]
+struct CardanoBlockProofQueryParams {
+    block_hashes: String,
+}
+
+impl CardanoBlockProofQueryParams {
+    pub fn split_blocks_hashes(&self) -> Vec<String> {
+        self.block_hashes
+            .split(',')
+            .map(|s| s.to_string())
+            .collect()
+    }
+
+    pub fn sanitize(&self) -> Vec<String> {
+        let mut block_hashes = self.split_blocks_hashes();
+        block_hashes.sort();
+        block_hashes.dedup();
+        block_hashes
+    }
+}
+
 pub fn routes(
     router_state: &RouterState,
 ) -> impl Filter<Extract = (impl warp::Reply,), Error = warp::Rejection> + Clone {
-    proof_cardano_transaction(router_state)
+    proof_cardano_transaction_search(router_state)
+        .or(proof_cardano_transaction(router_state))
+        .or(proof_cardano_block(router_state))
+}
+
+/// GET /proof/cardano-transaction/search
+fn proof_cardano_transaction_search(
+    router_state: &RouterState,
+) -> impl Filter<Extract = (impl warp::Reply,), Error = warp::Rejection> + Clone {
+    warp::path!("proof" / "cardano-transaction" / "search")
+        .and(warp::get())
+        .and(middlewares::with_origin_tag(router_state))
+        .and(warp::query::<CardanoTransactionSearchProofQueryParams>())
+        .and(middlewares::with_logger(router_state))
+        .and(middlewares::extract_config(router_state, |config| {
+            config.allow_cardano_transactions_search
+        }))
+        .and(middlewares::with_signed_entity_service(router_state))
+        .and(middlewares::validators::with_prover_transactions_search_validator(router_state))
+        .and(middlewares::with_prover_service(router_state))
+        .and(middlewares::with_metrics_service(router_state))
+        .and_then(handlers::proof_cardano_transaction_search)
 }
 
 /// GET /proof/cardano-transaction
@@ -47,23 +126,61 @@ fn proof_cardano_transaction(
         .and_then(handlers::proof_cardano_transaction)
 }
 
+/// GET /proof/cardano-block
+fn proof_cardano_block(
+    router_state: &RouterState,
+) -> impl Filter<Extract = (impl warp::Reply,), Error = warp::Rejection> + Clone {
+    warp::path!("proof" / "cardano-block")
+        .and(warp::get())
+        .and(middlewares::with_origin_tag(router_state))
+        .and(warp::query::<CardanoBlockProofQueryParams>())
+        .and(middlewares::with_logger(router_state))
+        .and(middlewares::with_signed_entity_service(router_state))
+        .and(middlewares::validators::with_prover_blocks_hash_validator(
+            router_state,
+        ))
+        .and(middlewares::with_cardano_blocks_prover_service(
+            router_state,
+        ))
+        .and(middlewares::with_metrics_service(router_state))
+        .and_then(handlers::proof_cardano_block)
+}
+
 mod handlers {
     use mithril_common::{
-        entities::CardanoTransactionsSnapshot, messages::CardanoTransactionsProofsMessage,
-        signable_builder::SignedEntity, StdResult,
+        entities::{BlockRange, CardanoTransactionFilter},
+        entities::{CardanoBlocksSnapshot, CardanoTransactionsSnapshot},
+        messages::{
+            CardanoBlocksProofsMessage, CardanoTransactionsProofsMessage,
+            CardanoTransactionsSearchProofsMessage,
+        },
+        signable_builder::SignedEntity,
+        StdResult,
     };
     use slog::{debug, warn, Logger};
     use std::{convert::Infallible, sync::Arc};
     use warp::http::StatusCode;
 
     use crate::{
-        http_server::{routes::reply, validators::ProverTransactionsHashValidator},
-        message_adapters::ToCardanoTransactionsProofsMessageAdapter,
-        services::{ProverService, SignedEntityService},
+        http_server::{
+            routes::reply,
+            validators::{
+                ProverBlocksHashValidator, ProverTransactionsHashValidator,
+                ProverTransactionsSearchValidator,
+            },
+        },
+        message_adapters::{
+            ToCardanoBlocksProofsMessageAdapter, ToCardanoTransactionsProofsMessageAdapter,
+            ToCardanoTransactionsSearchProofsMessageAdapter,
+        },
+        services::{CardanoBlocksProverService, ProverService, SignedEntityService},
         unwrap_to_internal_server_error, MetricsService,
     };
 
-    use super::CardanoTransactionProofQueryParams;
+    use super::{
+        CardanoBlockProofQueryParams, CardanoTransactionProofQueryParams,
+        CardanoTransactionSearchProofQueryParams,
+    };
 
     pub async fn proof_cardano_transaction(
         origin_tag: Option<String>,
@@ -138,6 +255,152 @@ mod handlers {
 
         Ok(message)
     }
+
+    #[allow(clippy::too_many_arguments)]
+    pub async fn proof_cardano_transaction_search(
+        origin_tag: Option<String>,
+        search_parameters: CardanoTransactionSearchProofQueryParams,
+        logger: Logger,
+        allow_cardano_transactions_search: bool,
+        signed_entity_service: Arc<dyn SignedEntityService>,
+        validator: ProverTransactionsSearchValidator,
+        prover_service: Arc<dyn ProverService>,
+        metrics_service: Arc<MetricsService>,
+    ) -> Result<impl warp::Reply, Infallible> {
+        metrics_service
+            .get_proof_cardano_transaction_search_total_proofs_served_since_startup()
+            .increment(&[origin_tag.as_deref().unwrap_or_default()]);
+
+        debug!(
+            logger, ">> proof_cardano_transaction_search";
+            "search_parameters" => ?search_parameters
+        );
+
+        if !allow_cardano_transactions_search {
+            warn!(
+                logger,
+                "proof_cardano_transaction_search::transactions_filters_extraction_disabled"
+            );
+            return Ok(reply::empty(StatusCode::FORBIDDEN));
+        }
+
+        let filter = search_parameters.filter();
+        let block_ranges = search_parameters.block_ranges();
+        if let Err(error) = validator.validate(filter.as_ref(), &block_ranges) {
+            warn!(logger, "proof_cardano_transaction_search::bad_request");
+            return Ok(reply::bad_request(error.label, error.message));
+        }
+        // The validator ensures that the filter is set
+        let filter = filter.unwrap();
+
+        match unwrap_to_internal_server_error!(
+            signed_entity_service
+                .get_last_cardano_transaction_snapshot()
+                .await,
+            logger => "proof_cardano_transaction_search::error"
+        ) {
+            Some(signed_entity) => {
+                let message = unwrap_to_internal_server_error!(
+                    build_search_response_message(prover_service, signed_entity, filter, block_ranges).await,
+                    logger => "proof_cardano_transaction_search"
+                );
+                Ok(reply::json(&message, StatusCode::OK))
+            }
+            None => {
+                warn!(logger, "proof_cardano_transaction_search::not_found");
+                Ok(reply::empty(StatusCode::NOT_FOUND))
+            }
+        }
+    }
+
+    pub async fn build_search_response_message(
+        prover_service: Arc<dyn ProverService>,
+        signed_entity: SignedEntity<CardanoTransactionsSnapshot>,
+        filter: CardanoTransactionFilter,
+        block_ranges: Vec<BlockRange>,
+    ) -> StdResult<CardanoTransactionsSearchProofsMessage> {
+        let transactions_set_proof = prover_service
+            .compute_transactions_proofs_by_filter(&filter, &block_ranges)
+            .await?;
+        let message = ToCardanoTransactionsSearchProofsMessageAdapter::try_adapt(
+            signed_entity,
+            filter,
+            transactions_set_proof,
+        )?;
+
+        Ok(message)
+    }
+
+    pub async fn proof_cardano_block(
+        origin_tag: Option<String>,
+        block_parameters: CardanoBlockProofQueryParams,
+        logger: Logger,
+        signed_entity_service: Arc<dyn SignedEntityService>,
+        validator: ProverBlocksHashValidator,
+        prover_service: Arc<dyn CardanoBlocksProverService>,
+        metrics_service: Arc<MetricsService>,
+    ) -> Result<impl warp::Reply, Infallible> {
+        metrics_service
+            .get_proof_cardano_block_total_proofs_served_since_startup()
+            .increment(&[origin_tag.as_deref().unwrap_or_default()]);
+
+        let block_hashes = block_parameters.split_blocks_hashes();
+        debug!(
+            logger, ">> proof_cardano_block";
+            "block_hashes" => &block_parameters.block_hashes
+        );
+
+        if let Err(error) = validator.validate(&block_hashes) {
+            warn!(logger, "proof_cardano_block::bad_request");
+            return Ok(reply::bad_request(error.label, error.message));
+        }
+
+        let sanitized_hashes = block_parameters.sanitize();
+
+        // Fallback to 0, it should be impossible to have more than u32::MAX blocks.
+        metrics_service
+            .get_proof_cardano_block_total_blocks_served_since_startup()
+            .increment_by(
+                &[origin_tag.as_deref().unwrap_or_default()],
+                sanitized_hashes.len().try_into().unwrap_or(0),
+            );
+
+        match unwrap_to_internal_server_error!(
+            signed_entity_service
+                .get_last_cardano_blocks_snapshot()
+                .await,
+            logger => "proof_cardano_block::error"
+        ) {
+            Some(signed_entity) => {
+                let message = unwrap_to_internal_server_error!(
+                    build_blocks_response_message(prover_service, signed_entity, sanitized_hashes).await,
+                    logger => "proof_cardano_block"
+                );
+                Ok(reply::json(&message, StatusCode::OK))
+            }
+            None => {
+                warn!(logger, "proof_cardano_block::not_found");
+                Ok(reply::empty(StatusCode::NOT_FOUND))
+            }
+        }
+    }
+
+    pub async fn build_blocks_response_message(
+        prover_service: Arc<dyn CardanoBlocksProverService>,
+        signed_entity: SignedEntity<CardanoBlocksSnapshot>,
+        block_hashes: Vec<String>,
+    ) -> StdResult<CardanoBlocksProofsMessage> {
+        let blocks_set_proofs = prover_service
+            .compute_blocks_proofs(signed_entity.artifact.block_number, block_hashes.as_slice())
+            .await?;
+        let message = ToCardanoBlocksProofsMessageAdapter::try_adapt(
+            signed_entity,
+            blocks_set_proofs,
+            block_hashes,
+        )?;
+
+        Ok(message)
+    }
 }
 
 #[cfg(test)]
@@ -152,13 +415,16 @@ mod tests {
     };
 
     use mithril_common::{
-        entities::{BlockNumber, CardanoTransactionsSetProof, CardanoTransactionsSnapshot},
+        entities::{
+            BlockNumber, CardanoBlocksSetProof, CardanoBlocksSnapshot, CardanoTransactionsSetProof,
+            CardanoTransactionsSnapshot,
+        },
         signable_builder::SignedEntity,
         test_utils::{apispec::APISpec, assert_equivalent, fake_data},
         MITHRIL_ORIGIN_TAG_HEADER,
     };
 
-    use crate::services::MockProverService;
+    use crate::services::{MockCardanoBlocksProverService, MockProverService};
     use crate::{initialize_dependencies, services::MockSignedEntityService};
 
     use super::*;
@@ -432,4 +698,517 @@ mod tests {
 
         assert_equivalent(params.sanitize(), vec![tx1, tx2]);
     }
+
+    mod proof_cardano_transaction_search {
+        use crate::http_server::routes::router::RouterConfig;
+        use crate::ServeCommandDependenciesContainer;
+
+        use super::*;
+
+        fn search_router_state(
+            dependency_manager: ServeCommandDependenciesContainer,
+        ) -> RouterState {
+            RouterState::new(
+                Arc::new(dependency_manager),
+                RouterConfig {
+                    allow_cardano_transactions_search: true,
+                    ..RouterConfig::dummy_with_origin_tag_white_list(&["TEST"])
+                },
+            )
+        }
+
+        fn search_params(
+            from_block_number: u64,
+            to_block_number: u64,
+        ) -> CardanoTransactionSearchProofQueryParams {
+            CardanoTransactionSearchProofQueryParams {
+                address: None,
+                metadata_label: Some(674),
+                from_block_number,
+                to_block_number,
+            }
+        }
+
+        #[test]
+        fn search_params_filter_is_set_only_with_exactly_one_filter() {
+            let params = CardanoTransactionSearchProofQueryParams {
+                address: Some("addr_test1xyz".to_string()),
+                ..search_params(0, 15)
+            };
+            assert_eq!(None, params.filter());
+
+            let params = CardanoTransactionSearchProofQueryParams {
+                address: Some("addr_test1xyz".to_string()),
+                metadata_label: None,
+                ..search_params(0, 15)
+            };
+            assert_eq!(
+                Some(CardanoTransactionFilter::Address(
+                    "addr_test1xyz".to_string()
+                )),
+                params.filter()
+            );
+
+            assert_eq!(
+                Some(CardanoTransactionFilter::MetadataLabel(674)),
+                search_params(0, 15).filter()
+            );
+
+            let params = CardanoTransactionSearchProofQueryParams {
+                metadata_label: None,
+                ..search_params(0, 15)
+            };
+            assert_eq!(None, params.filter());
+        }
+
+        #[test]
+        fn search_params_block_ranges_overlap_the_block_numbers_interval() {
+            assert_eq!(
+                Vec::<BlockRange>::new(),
+                search_params(15, 15).block_ranges()
+            );
+            assert_eq!(
+                Vec::<BlockRange>::new(),
+                search_params(16, 10).block_ranges()
+            );
+            assert_eq!(
+                vec![BlockRange::from_block_number(BlockNumber(0))],
+                search_params(0, 15).block_ranges()
+            );
+            assert_eq!(
+                vec![
+                    BlockRange::from_block_number(BlockNumber(0)),
+                    BlockRange::from_block_number(BlockNumber(15)),
+                ],
+                search_params(3, 16).block_ranges()
+            );
+            assert_eq!(
+                vec![
+                    BlockRange::from_block_number(BlockNumber(15)),
+                    BlockRange::from_block_number(BlockNumber(30)),
+                    BlockRange::from_block_number(BlockNumber(45)),
+                ],
+                search_params(29, 50).block_ranges()
+            );
+        }
+
+        #[tokio::test]
+        async fn build_search_response_message_return_latest_block_number_from_artifact_beacon() {
+            let mut mock_prover_service = MockProverService::new();
+            mock_prover_service
+                .expect_compute_transactions_proofs_by_filter()
+                .returning(|_, _| Ok(Some(CardanoTransactionsSetProof::dummy())));
+
+            let signed_entity = SignedEntity::<CardanoTransactionsSnapshot> {
+                artifact: CardanoTransactionsSnapshot::new(String::new(), BlockNumber(2309)),
+                ..SignedEntity::<CardanoTransactionsSnapshot>::dummy()
+            };
+
+            let message = handlers::build_search_response_message(
+                Arc::new(mock_prover_service),
+                signed_entity,
+                CardanoTransactionFilter::MetadataLabel(674),
+                vec![BlockRange::from_block_number(BlockNumber(0))],
+            )
+            .await
+            .unwrap();
+
+            assert_eq!(message.latest_block_number, 2309)
+        }
+
+        #[tokio::test]
+        async fn test_proof_cardano_transaction_search_increments_proofs_metrics() {
+            let dependency_manager = initialize_dependencies!().await;
+            let metrics_service = dependency_manager.metrics_service.clone();
+            let initial_proofs_counter_value = metrics_service
+                .get_proof_cardano_transaction_search_total_proofs_served_since_startup()
+                .get(&["TEST"]);
+
+            request()
+                .method(Method::GET.as_str())
+                .path("/proof/cardano-transaction/search?metadata_label=674&from_block_number=0&to_block_number=30")
+                .header(MITHRIL_ORIGIN_TAG_HEADER, "TEST")
+                .reply(&setup_router(search_router_state(dependency_manager)))
+                .await;
+
+            assert_eq!(
+                initial_proofs_counter_value + 1,
+                metrics_service
+                    .get_proof_cardano_transaction_search_total_proofs_served_since_startup()
+                    .get(&["TEST"])
+            );
+        }
+
+        #[tokio::test]
+        async fn proof_cardano_transaction_search_is_forbidden_if_the_filters_extraction_is_disabled(
+        ) {
+            let mut dependency_manager = initialize_dependencies!().await;
+            let mut mock_prover_service = MockProverService::new();
+            mock_prover_service
+                .expect_compute_transactions_proofs_by_filter()
+                .never();
+            dependency_manager.prover_service = Arc::new(mock_prover_service);
+
+            let method = Method::GET.as_str();
+            let path = "/proof/cardano-transaction/search";
+
+            let response = request()
+                .method(method)
+                .path(&format!(
+                    "{path}?metadata_label=674&from_block_number=0&to_block_number=30"
+                ))
+                .reply(&setup_router(RouterState::new_with_dummy_config(Arc::new(
+                    dependency_manager,
+                ))))
+                .await;
+
+            APISpec::verify_conformity(
+                APISpec::get_all_spec_files(),
+                method,
+                path,
+                "application/json",
+                &Null,
+                &response,
+                &StatusCode::FORBIDDEN,
+            )
+            .unwrap();
+        }
+
+        #[tokio::test]
+        async fn proof_cardano_transaction_search_ok() {
+            let mut dependency_manager = initialize_dependencies!().await;
+            let mut mock_signed_entity_service = MockSignedEntityService::new();
+            mock_signed_entity_service
+                .expect_get_last_cardano_transaction_snapshot()
+                .returning(|| Ok(Some(SignedEntity::<CardanoTransactionsSnapshot>::dummy())));
+            dependency_manager.signed_entity_service = Arc::new(mock_signed_entity_service);
+
+            let mut mock_prover_service = MockProverService::new();
+            mock_prover_service
+                .expect_compute_transactions_proofs_by_filter()
+                .returning(|_, _| Ok(Some(CardanoTransactionsSetProof::dummy())));
+            dependency_manager.prover_service = Arc::new(mock_prover_service);
+
+            let method = Method::GET.as_str();
+            let path = "/proof/cardano-transaction/search";
+
+            let response = request()
+                .method(method)
+                .path(&format!(
+                    "{path}?metadata_label=674&from_block_number=0&to_block_number=30"
+                ))
+                .reply(&setup_router(search_router_state(dependency_manager)))
+                .await;
+
+            APISpec::verify_conformity(
+                APISpec::get_all_spec_files(),
+                method,
+                path,
+                "application/json",
+                &Null,
+                &response,
+                &StatusCode::OK,
+            )
+            .unwrap();
+        }
+
+        #[tokio::test]
+        async fn proof_cardano_transaction_search_not_found() {
+            let dependency_manager = initialize_dependencies!().await;
+
+            let method = Method::GET.as_str();
+            let path = "/proof/cardano-transaction/search";
+
+            let response = request()
+                .method(method)
+                .path(&format!(
+                    "{path}?address=addr_test1xyz&from_block_number=0&to_block_number=30"
+                ))
+                .reply(&setup_router(search_router_state(dependency_manager)))
+                .await;
+
+            APISpec::verify_conformity(
+                APISpec::get_all_spec_files(),
+                method,
+                path,
+                "application/json",
+                &Null,
+                &response,
+                &StatusCode::NOT_FOUND,
+            )
+            .unwrap();
+        }
+
+        #[tokio::test]
+        async fn proof_cardano_transaction_search_ko() {
+            let mut dependency_manager = initialize_dependencies!().await;
+            let mut mock_signed_entity_service = MockSignedEntityService::new();
+            mock_signed_entity_service
+                .expect_get_last_cardano_transaction_snapshot()
+                .returning(|| Err(anyhow!("Error")));
+            dependency_manager.signed_entity_service = Arc::new(mock_signed_entity_service);
+
+            let method = Method::GET.as_str();
+            let path = "/proof/cardano-transaction/search";
+
+            let response = request()
+                .method(method)
+                .path(&format!(
+                    "{path}?metadata_label=674&from_block_number=0&to_block_number=30"
+                ))
+                .reply(&setup_router(search_router_state(dependency_manager)))
+                .await;
+
+            APISpec::verify_conformity(
+                APISpec::get_all_spec_files(),
+                method,
+                path,
+                "application/json",
+                &Null,
+                &response,
+                &StatusCode::INTERNAL_SERVER_ERROR,
+            )
+            .unwrap();
+        }
+
+        #[tokio::test]
+        async fn proof_cardano_transaction_search_return_bad_request_with_invalid_search() {
+            let dependency_manager = initialize_dependencies!().await;
+
+            let method = Method::GET.as_str();
+            let path = "/proof/cardano-transaction/search";
+
+            let response = request()
+                .method(method)
+                .path(&format!(
+                    "{path}?address=addr_test1xyz&metadata_label=674&from_block_number=0&to_block_number=30"
+                ))
+                .reply(&setup_router(search_router_state(dependency_manager)))
+                .await;
+
+            APISpec::verify_conformity(
+                APISpec::get_all_spec_files(),
+                method,
+                path,
+                "application/json",
+                &Null,
+                &response,
+                &StatusCode::BAD_REQUEST,
+            )
+            .unwrap();
+        }
+    }
+
+    mod proof_cardano_block {
+        use super::*;
+
+        fn block_hashes() -> Vec<String> {
+            vec!["a".repeat(64), "b".repeat(64)]
+        }
+
+        #[tokio::test]
+        async fn build_blocks_response_message_return_latest_block_number_from_artifact_beacon() {
+            let mut mock_prover_service = MockCardanoBlocksProverService::new();
+            mock_prover_service
+                .expect_compute_blocks_proofs()
+                .returning(|_, _| Ok(vec![CardanoBlocksSetProof::dummy()]));
+
+            let signed_entity = SignedEntity::<CardanoBlocksSnapshot> {
+                artifact: CardanoBlocksSnapshot::new(String::new(), BlockNumber(2309)),
+                ..SignedEntity::<CardanoBlocksSnapshot>::dummy()
+            };
+
+            let message = handlers::build_blocks_response_message(
+                Arc::new(mock_prover_service),
+                signed_entity,
+                vec![],
+            )
+            .await
+            .unwrap();
+
+            assert_eq!(message.latest_block_number, 2309)
+        }
+
+        #[tokio::test]
+        async fn test_proof_cardano_block_increments_proofs_metrics() {
+            let method = Method::GET.as_str();
+            let path = "/proof/cardano-block";
+            let dependency_manager = Arc::new(initialize_dependencies!().await);
+            let initial_proofs_counter_value = dependency_manager
+                .metrics_service
+                .get_proof_cardano_block_total_proofs_served_since_startup()
+                .get(&["TEST"]);
+            let initial_blocks_counter_value = dependency_manager
+                .metrics_service
+                .get_proof_cardano_block_total_blocks_served_since_startup()
+                .get(&["TEST"]);
+
+            request()
+                .method(method)
+                .path(&format!("{path}?block_hashes={}", block_hashes().join(",")))
+                .header(MITHRIL_ORIGIN_TAG_HEADER, "TEST")
+                .reply(&setup_router(RouterState::new_with_origin_tag_white_list(
+                    dependency_manager.clone(),
+                    &["TEST"],
+                )))
+                .await;
+
+            assert_eq!(
+                initial_proofs_counter_value + 1,
+                dependency_manager
+                    .metrics_service
+                    .get_proof_cardano_block_total_proofs_served_since_startup()
+                    .get(&["TEST"])
+            );
+            assert_eq!(
+                initial_blocks_counter_value + 2,
+                dependency_manager
+                    .metrics_service
+                    .get_proof_cardano_block_total_blocks_served_since_startup()
+                    .get(&["TEST"])
+            );
+        }
+
+        #[tokio::test]
+        async fn proof_cardano_block_ok() {
+            let mut dependency_manager = initialize_dependencies!().await;
+            let mut mock_signed_entity_service = MockSignedEntityService::new();
+            mock_signed_entity_service
+                .expect_get_last_cardano_blocks_snapshot()
+                .returning(|| Ok(Some(SignedEntity::<CardanoBlocksSnapshot>::dummy())));
+            dependency_manager.signed_entity_service = Arc::new(mock_signed_entity_service);
+
+            let mut mock_prover_service = MockCardanoBlocksProverService::new();
+            mock_prover_service
+                .expect_compute_blocks_proofs()
+                .returning(|_, _| Ok(vec![CardanoBlocksSetProof::dummy()]));
+            dependency_manager.cardano_blocks_prover_service = Arc::new(mock_prover_service);
+
+            let method = Method::GET.as_str();
+            let path = "/proof/cardano-block";
+
+            let response = request()
+                .method(method)
+                .path(&format!("{path}?block_hashes={}", block_hashes().join(",")))
+                .reply(&setup_router(RouterState::new_with_dummy_config(Arc::new(
+                    dependency_manager,
+                ))))
+                .await;
+
+            APISpec::verify_conformity(
+                APISpec::get_all_spec_files(),
+                method,
+                path,
+                "application/json",
+                &Null,
+                &response,
+                &StatusCode::OK,
+            )
+            .unwrap();
+        }
+
+        #[tokio::test]
+        async fn proof_cardano_block_not_found() {
+            let mut dependency_manager = initialize_dependencies!().await;
+            let mut mock_signed_entity_service = MockSignedEntityService::new();
+            mock_signed_entity_service
+                .expect_get_last_cardano_blocks_snapshot()
+                .returning(|| Ok(None));
+            dependency_manager.signed_entity_service = Arc::new(mock_signed_entity_service);
+
+            let method = Method::GET.as_str();
+            let path = "/proof/cardano-block";
+
+            let response = request()
+                .method(method)
+                .path(&format!("{path}?block_hashes={}", block_hashes().join(",")))
+                .reply(&setup_router(RouterState::new_with_dummy_config(Arc::new(
+                    dependency_manager,
+                ))))
+                .await;
+
+            APISpec::verify_conformity(
+                APISpec::get_all_spec_files(),
+                method,
+                path,
+                "application/json",
+                &Null,
+                &response,
+                &StatusCode::NOT_FOUND,
+            )
+            .unwrap();
+        }
+
+        #[tokio::test]
+        async fn proof_cardano_block_ko() {
+            let mut dependency_manager = initialize_dependencies!().await;
+            let mut mock_signed_entity_service = MockSignedEntityService::new();
+            mock_signed_entity_service
+                .expect_get_last_cardano_blocks_snapshot()
+                .returning(|| Err(anyhow!("Error")));
+            dependency_manager.signed_entity_service = Arc::new(mock_signed_entity_service);
+
+            let method = Method::GET.as_str();
+            let path = "/proof/cardano-block";
+
+            let response = request()
+                .method(method)
+                .path(&format!("{path}?block_hashes={}", block_hashes().join(",")))
+                .reply(&setup_router(RouterState::new_with_dummy_config(Arc::new(
+                    dependency_manager,
+                ))))
+                .await;
+
+            APISpec::verify_conformity(
+                APISpec::get_all_spec_files(),
+                method,
+                path,
+                "application/json",
+                &Null,
+                &response,
+                &StatusCode::INTERNAL_SERVER_ERROR,
+            )
+            .unwrap();
+        }
+
+        #[tokio::test]
+        async fn proof_cardano_block_return_bad_request_with_invalid_hashes() {
+            let dependency_manager = initialize_dependencies!().await;
+
+            let method = Method::GET.as_str();
+            let path = "/proof/cardano-block";
+
+            let response = request()
+                .method(method)
+                .path(&format!(
+                    "{path}?block_hashes=invalid%3A%2F%2Fid,,block-456"
+                ))
+                .reply(&setup_router(RouterState::new_with_dummy_config(Arc::new(
+                    dependency_manager,
+                ))))
+                .await;
+
+            APISpec::verify_conformity(
+                APISpec::get_all_spec_files(),
+                method,
+                path,
+                "application/json",
+                &Null,
+                &response,
+                &StatusCode::BAD_REQUEST,
+            )
+            .unwrap();
+        }
+
+        #[test]
+        fn sanitize_cardano_block_proof_query_params_remove_duplicate() {
+            let [block1, block2]: [String; 2] = block_hashes().try_into().unwrap();
+
+            let params = CardanoBlockProofQueryParams {
+                block_hashes: format!("{block1},{block2},{block2},{block1},{block2}",),
+            };
+
+            assert_equivalent(params.sanitize(), vec![block1, block2]);
+        }
+    }
 }
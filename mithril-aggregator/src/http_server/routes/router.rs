@@ -37,10 +37,12 @@ pub struct RouterConfig {
     pub server_url: SanitizedUrlWithTrailingSlash,
     pub allowed_discriminants: BTreeSet<SignedEntityTypeDiscriminants>,
     pub cardano_transactions_prover_max_hashes_allowed_by_request: usize,
+    pub cardano_transactions_prover_max_block_ranges_allowed_by_request: usize,
     pub cardano_db_artifacts_directory: PathBuf,
     pub snapshot_directory: PathBuf,
     pub cardano_node_version: String,
     pub allow_http_serve_directory: bool,
+    pub allow_cardano_transactions_search: bool,
     pub origin_tag_white_list: HashSet<String>,
 }
 
@@ -55,10 +57,12 @@ impl RouterConfig {
                 SignedEntityTypeDiscriminants::CardanoStakeDistribution,
             ]),
             cardano_transactions_prover_max_hashes_allowed_by_request: 1_000,
+            cardano_transactions_prover_max_block_ranges_allowed_by_request: 100,
             cardano_db_artifacts_directory: PathBuf::from("/dummy/cardano-db/directory"),
             snapshot_directory: PathBuf::from("/dummy/snapshot/directory"),
             cardano_node_version: "1.2.3".to_string(),
             allow_http_serve_directory: false,
+            allow_cardano_transactions_search: false,
             origin_tag_white_list: HashSet::from(["DUMMY_TAG".to_string()]),
         }
     }
@@ -138,6 +142,7 @@ pub fn routes(
                 .or(artifact_routes::cardano_database::routes(&state))
                 .or(artifact_routes::mithril_stake_distribution::routes(&state))
                 .or(artifact_routes::cardano_stake_distribution::routes(&state))
+                .or(artifact_routes::cardano_protocol_parameters::routes(&state))
                 .or(artifact_routes::cardano_transaction::routes(&state))
                 .or(proof_routes::routes(&state))
                 .or(signer_routes::routes(&state))
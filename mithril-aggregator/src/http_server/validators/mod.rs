@@ -1,3 +1,7 @@
+mod prover_blocks_hash_validator;
 mod prover_transactions_hash_validator;
+mod prover_transactions_search_validator;
 
+pub use prover_blocks_hash_validator::*;
 pub use prover_transactions_hash_validator::*;
+pub use prover_transactions_search_validator::*;
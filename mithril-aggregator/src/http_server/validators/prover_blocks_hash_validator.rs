@@ -0,0 +1,105 @@
+use mithril_common::entities::ClientError;
+
+pub struct ProverBlocksHashValidator {
+    max_hashes: usize,
+}
+
+impl ProverBlocksHashValidator {
+    const LABEL: &'static str = "invalid_block_hashes";
+
+    pub fn new(max_hashes: usize) -> Self {
+        Self { max_hashes }
+    }
+
+    pub fn validate(&self, hashes: &[String]) -> Result<(), ClientError> {
+        if hashes.len() > self.max_hashes {
+            return Err(ClientError::new(
+                Self::LABEL,
+                format!(
+                    "Block hashes list contains more than maximum allowed number of hashes: '{}'",
+                    self.max_hashes
+                ),
+            ));
+        }
+
+        for hash in hashes {
+            if hash.is_empty() {
+                return Err(ClientError::new(Self::LABEL, "Block hash cannot be empty"));
+            }
+
+            if hash.chars().count() != 64 {
+                return Err(ClientError::new(
+                    Self::LABEL,
+                    "Block hash must have 64 characters",
+                ));
+            }
+
+            if !hash.chars().all(|c| c.is_ascii_hexdigit()) {
+                return Err(ClientError::new(
+                    Self::LABEL,
+                    "Block hash must contain only hexadecimal characters",
+                ));
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+impl Default for ProverBlocksHashValidator {
+    fn default() -> Self {
+        Self::new(usize::MAX)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn prover_blocks_hash_validator_return_error_when_hash_is_invalid() {
+        for (hash, expected_message) in [
+            ("".to_string(), "Block hash cannot be empty"),
+            ("abc".to_string(), "Block hash must have 64 characters"),
+            (
+                format!("{}g", "a".repeat(63)),
+                "Block hash must contain only hexadecimal characters",
+            ),
+        ] {
+            let error = ProverBlocksHashValidator::default()
+                .validate(&[hash])
+                .expect_err("Should return an error");
+
+            assert_eq!(
+                error,
+                ClientError::new("invalid_block_hashes", expected_message)
+            );
+        }
+    }
+
+    #[test]
+    fn prover_blocks_hash_validator_when_hash_contains_only_hexadecimal_characters() {
+        ProverBlocksHashValidator::default()
+            .validate(&[format!("bcd9{}", "a".repeat(60))])
+            .expect("Should succeed");
+    }
+
+    #[test]
+    fn prover_blocks_hash_validator_return_error_when_more_hashes_than_max_allowed() {
+        let blocks_hashes = vec!["a".repeat(64), "b".repeat(64), "c".repeat(64)];
+        let validator = ProverBlocksHashValidator::new(2);
+
+        let error = validator
+            .validate(&blocks_hashes)
+            .expect_err("Should return an error");
+
+        assert_eq!(
+            error,
+            ClientError::new(
+                "invalid_block_hashes",
+                "Block hashes list contains more than maximum allowed number of hashes: '2'"
+            )
+        );
+    }
+}
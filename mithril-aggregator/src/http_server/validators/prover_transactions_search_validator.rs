@@ -0,0 +1,181 @@
+use mithril_common::entities::{BlockRange, CardanoTransactionFilter, ClientError};
+
+pub struct ProverTransactionsSearchValidator {
+    max_block_ranges: usize,
+}
+
+impl ProverTransactionsSearchValidator {
+    const LABEL: &'static str = "invalid_transactions_search";
+
+    pub fn new(max_block_ranges: usize) -> Self {
+        Self { max_block_ranges }
+    }
+
+    pub fn validate(
+        &self,
+        filter: Option<&CardanoTransactionFilter>,
+        block_ranges: &[BlockRange],
+    ) -> Result<(), ClientError> {
+        match filter {
+            None => {
+                return Err(ClientError::new(
+                    Self::LABEL,
+                    "Exactly one filter must be given: either 'address' or 'metadata_label'",
+                ));
+            }
+            Some(CardanoTransactionFilter::Address(address)) => {
+                if address.is_empty() {
+                    return Err(ClientError::new(Self::LABEL, "Address cannot be empty"));
+                }
+
+                if !address
+                    .chars()
+                    .all(|c| c.is_ascii_alphanumeric() || c == '_')
+                {
+                    return Err(ClientError::new(
+                        Self::LABEL,
+                        "Address must contain only alphanumeric characters or '_'",
+                    ));
+                }
+            }
+            Some(CardanoTransactionFilter::MetadataLabel(_)) => {}
+        }
+
+        if block_ranges.is_empty() {
+            return Err(ClientError::new(
+                Self::LABEL,
+                "Block numbers interval must contain at least one block",
+            ));
+        }
+
+        if block_ranges.len() > self.max_block_ranges {
+            return Err(ClientError::new(
+                Self::LABEL,
+                format!(
+                    "Block numbers interval spans more than maximum allowed number of block ranges: '{}'",
+                    self.max_block_ranges
+                ),
+            ));
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+impl Default for ProverTransactionsSearchValidator {
+    fn default() -> Self {
+        Self::new(usize::MAX)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use mithril_common::entities::BlockNumber;
+
+    use super::*;
+
+    fn block_ranges(count: u64) -> Vec<BlockRange> {
+        (0..count)
+            .map(|i| BlockRange::from_block_number(BlockNumber(i) * BlockRange::LENGTH))
+            .collect()
+    }
+
+    #[test]
+    fn prover_transactions_search_validator_return_error_when_no_filter() {
+        let error = ProverTransactionsSearchValidator::default()
+            .validate(None, &block_ranges(1))
+            .expect_err("Should return an error");
+
+        assert_eq!(
+            error,
+            ClientError::new(
+                "invalid_transactions_search",
+                "Exactly one filter must be given: either 'address' or 'metadata_label'"
+            )
+        );
+    }
+
+    #[test]
+    fn prover_transactions_search_validator_return_error_when_address_is_invalid() {
+        for (address, expected_message) in [
+            ("", "Address cannot be empty"),
+            (
+                "addr_test1;drop",
+                "Address must contain only alphanumeric characters or '_'",
+            ),
+        ] {
+            let error = ProverTransactionsSearchValidator::default()
+                .validate(
+                    Some(&CardanoTransactionFilter::Address(address.to_string())),
+                    &block_ranges(1),
+                )
+                .expect_err("Should return an error");
+
+            assert_eq!(
+                error,
+                ClientError::new("invalid_transactions_search", expected_message),
+                "Invalid address: {}",
+                address
+            );
+        }
+    }
+
+    #[test]
+    fn prover_transactions_search_validator_return_error_when_no_block_range() {
+        let error = ProverTransactionsSearchValidator::default()
+            .validate(Some(&CardanoTransactionFilter::MetadataLabel(674)), &[])
+            .expect_err("Should return an error");
+
+        assert_eq!(
+            error,
+            ClientError::new(
+                "invalid_transactions_search",
+                "Block numbers interval must contain at least one block"
+            )
+        );
+    }
+
+    #[test]
+    fn prover_transactions_search_validator_return_error_when_more_block_ranges_than_max_allowed() {
+        let validator = ProverTransactionsSearchValidator::new(2);
+
+        let error = validator
+            .validate(
+                Some(&CardanoTransactionFilter::MetadataLabel(674)),
+                &block_ranges(3),
+            )
+            .expect_err("Should return an error");
+
+        assert_eq!(
+            error,
+            ClientError::new(
+                "invalid_transactions_search",
+                format!(
+                    "Block numbers interval spans more than maximum allowed number of block ranges: '{}'",
+                    validator.max_block_ranges
+                )
+            )
+        );
+    }
+
+    #[test]
+    fn prover_transactions_search_validator_when_filter_and_block_ranges_are_valid() {
+        let validator = ProverTransactionsSearchValidator::new(2);
+
+        validator
+            .validate(
+                Some(&CardanoTransactionFilter::Address(
+                    "addr_test1vr80076l3x5uw6n94nwhgmv7ssgy6muzf47ugn6z0l92rhg2mgtu0".to_string(),
+                )),
+                &block_ranges(2),
+            )
+            .expect("Should succeed");
+        validator
+            .validate(
+                Some(&CardanoTransactionFilter::MetadataLabel(674)),
+                &block_ranges(1),
+            )
+            .expect("Should succeed");
+    }
+}
@@ -6,6 +6,7 @@ use mithril_common::{
     entities::{HexEncodedDigest, ImmutableFileName},
     StdResult,
 };
+use mithril_persistence::database::repository::SqliteImmutableFileDigestCacheProvider;
 
 /// A trait for mapping [ImmutableFileName]s to their digests.
 #[cfg_attr(test, mockall::automock)]
@@ -16,3 +17,12 @@ pub trait ImmutableFileDigestMapper: Sync + Send {
         &self,
     ) -> StdResult<BTreeMap<ImmutableFileName, HexEncodedDigest>>;
 }
+
+#[async_trait]
+impl ImmutableFileDigestMapper for SqliteImmutableFileDigestCacheProvider {
+    async fn get_immutable_file_digest_map(
+        &self,
+    ) -> StdResult<BTreeMap<ImmutableFileName, HexEncodedDigest>> {
+        self.get_all_digests().await
+    }
+}
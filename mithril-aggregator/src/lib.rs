@@ -31,10 +31,11 @@ mod tools;
 
 pub use crate::artifact_builder::ArtifactBuilder;
 pub use crate::configuration::{
-    ConfigurationSource, DefaultConfiguration, ExecutionEnvironment, ServeCommandConfiguration,
-    SnapshotUploaderType, ZstandardCompressionParameters,
+    ConfigurationSource, DefaultConfiguration, ExecutionEnvironment,
+    ProtocolParametersSecurityRequirement, ServeCommandConfiguration, SnapshotUploaderType,
+    ZstandardCompressionParameters,
 };
-pub use crate::multi_signer::{MultiSigner, MultiSignerImpl};
+pub use crate::multi_signer::{MultiSignatureAggregation, MultiSigner, MultiSignerImpl};
 pub use commands::{CommandType, MainOpts};
 pub use dependency_injection::ServeCommandDependenciesContainer;
 pub use file_uploaders::{DumbUploader, FileUploader};
@@ -0,0 +1,89 @@
+use mithril_common::{
+    entities::{BlockHash, CardanoBlocksSetProof, CardanoBlocksSnapshot},
+    messages::{CardanoBlocksProofsMessage, CardanoBlocksSetProofMessagePart},
+    signable_builder::SignedEntity,
+    StdResult,
+};
+
+/// Adapter to spawn [CardanoBlocksProofsMessage] from [CardanoBlocksSetProof] instances.
+pub struct ToCardanoBlocksProofsMessageAdapter;
+
+impl ToCardanoBlocksProofsMessageAdapter {
+    /// Turn an entity instance into message.
+    pub fn try_adapt(
+        signed_entity: SignedEntity<CardanoBlocksSnapshot>,
+        blocks_set_proofs: Vec<CardanoBlocksSetProof>,
+        block_hashes_to_certify: Vec<BlockHash>,
+    ) -> StdResult<CardanoBlocksProofsMessage> {
+        let blocks_hashes_not_certified =
+            compute_not_certified_blocks(&blocks_set_proofs, &block_hashes_to_certify);
+
+        Ok(CardanoBlocksProofsMessage::new(
+            &signed_entity.certificate_id,
+            try_adapt_set_proof_message(blocks_set_proofs)?,
+            blocks_hashes_not_certified,
+            signed_entity.artifact.block_number,
+        ))
+    }
+}
+
+fn compute_not_certified_blocks(
+    blocks_set_proofs: &[CardanoBlocksSetProof],
+    block_hashes_to_certify: &[BlockHash],
+) -> Vec<BlockHash> {
+    let blocks_hashes_certified = blocks_set_proofs
+        .iter()
+        .flat_map(|proof| proof.blocks_hashes())
+        .collect::<Vec<_>>();
+
+    block_hashes_to_certify
+        .iter()
+        .filter(|hash| !blocks_hashes_certified.contains(hash))
+        .cloned()
+        .collect()
+}
+
+fn try_adapt_set_proof_message(
+    blocks_set_proofs: Vec<CardanoBlocksSetProof>,
+) -> StdResult<Vec<CardanoBlocksSetProofMessagePart>> {
+    let mut messages = vec![];
+
+    for set_proof in blocks_set_proofs {
+        messages.push(set_proof.try_into()?);
+    }
+
+    Ok(messages)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn try_adapt_cardano_blocks_proof_to_message() {
+        let blocks_set_proof = CardanoBlocksSetProof::dummy();
+        let blocks_hashes_non_certified = vec!["block-hash-6".to_string()];
+        let block_hashes = [
+            blocks_set_proof.blocks_hashes(),
+            blocks_hashes_non_certified.clone(),
+        ]
+        .concat();
+
+        let signed_entity = SignedEntity::<CardanoBlocksSnapshot>::dummy();
+
+        let message = ToCardanoBlocksProofsMessageAdapter::try_adapt(
+            signed_entity.clone(),
+            vec![blocks_set_proof.clone()],
+            block_hashes,
+        )
+        .unwrap();
+
+        let expected_message = CardanoBlocksProofsMessage::new(
+            &signed_entity.certificate_id,
+            vec![blocks_set_proof.try_into().unwrap()],
+            blocks_hashes_non_certified,
+            signed_entity.artifact.block_number,
+        );
+        assert_eq!(expected_message, message);
+    }
+}
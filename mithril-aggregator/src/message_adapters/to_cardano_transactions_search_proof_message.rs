@@ -0,0 +1,103 @@
+use mithril_common::{
+    entities::{
+        CardanoTransactionFilter, CardanoTransactionsSetProof, CardanoTransactionsSnapshot,
+    },
+    messages::CardanoTransactionsSearchProofsMessage,
+    signable_builder::SignedEntity,
+    StdResult,
+};
+
+/// Adapter to spawn [CardanoTransactionsSearchProofsMessage] from [CardanoTransactionsSetProof] instances.
+pub struct ToCardanoTransactionsSearchProofsMessageAdapter;
+
+impl ToCardanoTransactionsSearchProofsMessageAdapter {
+    /// Turn an entity instance into message.
+    pub fn try_adapt(
+        signed_entity: SignedEntity<CardanoTransactionsSnapshot>,
+        filter: CardanoTransactionFilter,
+        transactions_set_proof: Option<CardanoTransactionsSetProof>,
+    ) -> StdResult<CardanoTransactionsSearchProofsMessage> {
+        let certified_block_ranges = transactions_set_proof
+            .as_ref()
+            .map(|set_proof| set_proof.block_ranges())
+            .unwrap_or_default();
+
+        Ok(CardanoTransactionsSearchProofsMessage::new(
+            &signed_entity.certificate_id,
+            filter,
+            certified_block_ranges,
+            transactions_set_proof
+                .map(|set_proof| set_proof.try_into())
+                .transpose()?,
+            signed_entity.artifact.block_number,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use mithril_common::crypto_helper::MKTreeStoreInMemory;
+    use mithril_common::entities::{BlockNumber, BlockRange};
+
+    use super::*;
+
+    #[test]
+    fn try_adapt_cardano_transactions_search_proof_to_message() {
+        let set_proof = CardanoTransactionsSetProof::from_subset_of_leaves::<MKTreeStoreInMemory>(
+            &[
+                (BlockNumber(1), "tx-1".to_string()),
+                (BlockNumber(16), "tx-2".to_string()),
+                (BlockNumber(17), "tx-3".to_string()),
+            ],
+            &["tx-1".to_string(), "tx-2".to_string(), "tx-3".to_string()],
+        )
+        .unwrap();
+        let signed_entity = SignedEntity::<CardanoTransactionsSnapshot>::dummy();
+        let filter = CardanoTransactionFilter::MetadataLabel(674);
+
+        let message = ToCardanoTransactionsSearchProofsMessageAdapter::try_adapt(
+            signed_entity.clone(),
+            filter.clone(),
+            Some(set_proof.clone()),
+        )
+        .unwrap();
+
+        assert_eq!(
+            CardanoTransactionsSearchProofsMessage::new(
+                &signed_entity.certificate_id,
+                filter,
+                vec![
+                    BlockRange::from_block_number(BlockNumber(0)),
+                    BlockRange::from_block_number(BlockNumber(15)),
+                ],
+                Some(set_proof.try_into().unwrap()),
+                signed_entity.artifact.block_number,
+            ),
+            message
+        );
+    }
+
+    #[test]
+    fn try_adapt_cardano_transactions_search_without_proof_to_message() {
+        let signed_entity = SignedEntity::<CardanoTransactionsSnapshot>::dummy();
+        let filter = CardanoTransactionFilter::MetadataLabel(674);
+
+        let message = ToCardanoTransactionsSearchProofsMessageAdapter::try_adapt(
+            signed_entity.clone(),
+            filter.clone(),
+            None,
+        )
+        .unwrap();
+
+        assert_eq!(
+            CardanoTransactionsSearchProofsMessage::new(
+                &signed_entity.certificate_id,
+                filter,
+                vec![],
+                None,
+                signed_entity.artifact.block_number,
+            ),
+            message
+        );
+    }
+}
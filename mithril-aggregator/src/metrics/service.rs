@@ -60,6 +60,11 @@ build_metrics_service!(
         "Number of Cardano stake distribution artifact details served since startup on a Mithril aggregator node",
         &[ORIGIN_TAG_LABEL]
     ),
+    artifact_detail_cardano_protocol_parameters_total_served_since_startup:MetricCounterWithLabels(
+        "mithril_aggregator_artifact_detail_cardano_protocol_parameters_total_served_since_startup",
+        "Number of Cardano protocol parameters artifact details served since startup on a Mithril aggregator node",
+        &[ORIGIN_TAG_LABEL]
+    ),
     artifact_detail_cardano_transaction_total_served_since_startup:MetricCounterWithLabels(
         "mithril_aggregator_artifact_detail_cardano_transaction_total_served_since_startup",
         "Number of Cardano transaction artifact details served since startup on a Mithril aggregator node",
@@ -75,6 +80,21 @@ build_metrics_service!(
         "Number of Cardano transaction hashes requested for proof since startup on a Mithril aggregator node",
         &[ORIGIN_TAG_LABEL]
     ),
+    proof_cardano_transaction_search_total_proofs_served_since_startup:MetricCounterWithLabels(
+        "mithril_aggregator_proof_cardano_transaction_search_total_proofs_served_since_startup",
+        "Number of Cardano transaction search proofs served since startup on a Mithril aggregator node",
+        &[ORIGIN_TAG_LABEL]
+    ),
+    proof_cardano_block_total_proofs_served_since_startup:MetricCounterWithLabels(
+        "mithril_aggregator_proof_cardano_block_total_proofs_served_since_startup",
+        "Number of Cardano block proofs served since startup on a Mithril aggregator node",
+        &[ORIGIN_TAG_LABEL]
+    ),
+    proof_cardano_block_total_blocks_served_since_startup:MetricCounterWithLabels(
+        "mithril_aggregator_proof_cardano_block_total_blocks_served_since_startup",
+        "Number of Cardano block hashes requested for proof since startup on a Mithril aggregator node",
+        &[ORIGIN_TAG_LABEL]
+    ),
     signer_registration_total_received_since_startup:MetricCounterWithLabels(
         "mithril_aggregator_signer_registration_total_received_since_startup",
         "Number of signer registrations received since startup on a Mithril aggregator node",
@@ -109,6 +129,14 @@ build_metrics_service!(
         "mithril_aggregator_artifact_cardano_transaction_total_produced_since_startup",
         "Number of Cardano transaction artifacts produced since startup on a Mithril aggregator node"
     ),
+    artifact_cardano_blocks_total_produced_since_startup:MetricCounter(
+        "mithril_aggregator_artifact_cardano_blocks_total_produced_since_startup",
+        "Number of Cardano blocks artifacts produced since startup on a Mithril aggregator node"
+    ),
+    artifact_cardano_protocol_parameters_total_produced_since_startup:MetricCounter(
+        "mithril_aggregator_artifact_cardano_protocol_parameters_total_produced_since_startup",
+        "Number of Cardano protocol parameters artifacts produced since startup on a Mithril aggregator node"
+    ),
     runtime_cycle_success_since_startup:MetricCounter(
         "mithril_aggregator_runtime_cycle_success_since_startup",
         "Number of successful runtime cycles since startup on a Mithril aggregator"
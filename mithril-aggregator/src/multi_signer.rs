@@ -1,17 +1,21 @@
 use anyhow::{anyhow, Context};
 use async_trait::async_trait;
 use slog::{debug, warn, Logger};
+use std::collections::BTreeMap;
+use tokio::sync::Mutex;
 
 use mithril_common::{
-    crypto_helper::{ProtocolAggregationError, ProtocolMultiSignature},
-    entities::{self},
+    crypto_helper::{
+        ProtocolAggregationError, ProtocolMultiSignature, ProtocolVerifiedSingleSignature,
+    },
+    entities::{self, SignedEntityTypeDiscriminants},
     logging::LoggerExtensions,
-    protocol::MultiSigner as ProtocolMultiSigner,
+    protocol::{MultiSigner as ProtocolMultiSigner, SingleSignaturesAggregator, ToMessage},
     StdResult,
 };
 
 use crate::dependency_injection::EpochServiceWrapper;
-use crate::entities::OpenMessage;
+use crate::entities::{AggregationSummary, OpenMessage};
 
 /// MultiSigner is the cryptographic engine in charge of producing multi signatures from individual signatures
 #[cfg_attr(test, mockall::automock)]
@@ -31,16 +35,66 @@ pub trait MultiSigner: Sync + Send {
         signature: &entities::SingleSignature,
     ) -> StdResult<()>;
 
+    /// Verify a single signature for the aggregation of the signatures of the open message,
+    /// without adding it to the aggregation
+    ///
+    /// Fails if the single signature is invalid.
+    async fn verify_single_signature_for_aggregation(
+        &self,
+        open_message: &OpenMessage,
+        signature: &entities::SingleSignature,
+    ) -> StdResult<ProtocolVerifiedSingleSignature>;
+
+    /// Add a single signature verified with
+    /// [verify_single_signature_for_aggregation][MultiSigner::verify_single_signature_for_aggregation]
+    /// to the aggregation of the signatures of the open message, returns true if the quorum is
+    /// reached
+    async fn add_verified_single_signature_to_aggregation(
+        &self,
+        open_message: &OpenMessage,
+        verified_signature: ProtocolVerifiedSingleSignature,
+    ) -> StdResult<bool>;
+
     /// Creates a multi signature from single signatures
+    ///
+    /// If the quorum is not reached, the summary of the aggregation explaining why is returned
+    /// instead.
     async fn create_multi_signature(
         &self,
         open_message: &OpenMessage,
-    ) -> StdResult<Option<ProtocolMultiSignature>>;
+    ) -> StdResult<MultiSignatureAggregation>;
+}
+
+/// Outcome of the aggregation of the single signatures of an open message
+#[derive(Debug, Clone)]
+pub enum MultiSignatureAggregation {
+    /// The quorum is reached and the multi-signature is created
+    Created(ProtocolMultiSignature),
+
+    /// The quorum is not reached, the summary of the aggregation explains why
+    QuorumNotReached(AggregationSummary),
+}
+
+/// Aggregator of the single signatures of an open message
+struct OpenMessageAggregator {
+    signed_entity_type: entities::SignedEntityType,
+    message: String,
+    aggregator: SingleSignaturesAggregator,
+}
+
+impl OpenMessageAggregator {
+    fn is_aggregating(&self, open_message: &OpenMessage, message: &str) -> bool {
+        self.signed_entity_type == open_message.signed_entity_type && self.message == message
+    }
 }
 
 /// MultiSignerImpl is an implementation of the MultiSigner
+///
+/// The single signatures of the open messages are aggregated incrementally, so each of them is
+/// verified only once even if the multi-signature creation is attempted several times.
 pub struct MultiSignerImpl {
     epoch_service: EpochServiceWrapper,
+    aggregators: Mutex<BTreeMap<SignedEntityTypeDiscriminants, OpenMessageAggregator>>,
     logger: Logger,
 }
 
@@ -51,10 +105,77 @@ impl MultiSignerImpl {
         debug!(logger, "New MultiSignerImpl created");
         Self {
             epoch_service,
+            aggregators: Mutex::new(BTreeMap::new()),
             logger,
         }
     }
 
+    /// Apply the given function to the aggregator of the open message.
+    ///
+    /// The aggregator is created the first time the open message is aggregated, replacing the
+    /// aggregator of the previous open message of the same type. The single signatures of the
+    /// open message that were never added to the aggregator are added first, invalid ones are
+    /// ignored.
+    async fn with_open_message_aggregator<R>(
+        &self,
+        open_message: &OpenMessage,
+        f: impl FnOnce(&mut SingleSignaturesAggregator) -> R,
+    ) -> StdResult<R> {
+        let message = open_message.protocol_message.to_message();
+        let discriminant: SignedEntityTypeDiscriminants = (&open_message.signed_entity_type).into();
+        let is_aggregating =
+            |aggregators: &BTreeMap<SignedEntityTypeDiscriminants, OpenMessageAggregator>| {
+                aggregators
+                    .get(&discriminant)
+                    .is_some_and(|aggregator| aggregator.is_aggregating(open_message, &message))
+            };
+
+        let mut aggregators = self.aggregators.lock().await;
+        if !is_aggregating(&aggregators) {
+            // The epoch service lock is not taken while holding the aggregators lock
+            drop(aggregators);
+            let aggregator = {
+                let epoch_service = self.epoch_service.read().await;
+                let protocol_multi_signer =
+                    epoch_service.protocol_multi_signer().with_context(|| {
+                        "Multi Signer could not get protocol multi-signer from epoch service"
+                    })?;
+                protocol_multi_signer.incremental_aggregator(&message)
+            };
+
+            aggregators = self.aggregators.lock().await;
+            // Another call may have created the aggregator in the meantime
+            if !is_aggregating(&aggregators) {
+                aggregators.insert(
+                    discriminant,
+                    OpenMessageAggregator {
+                        signed_entity_type: open_message.signed_entity_type.clone(),
+                        message: message.clone(),
+                        aggregator,
+                    },
+                );
+            }
+        }
+
+        let aggregator = &mut aggregators
+            .get_mut(&discriminant)
+            .with_context(|| "Multi Signer could not get the open message aggregator")?
+            .aggregator;
+        for single_signature in &open_message.single_signatures {
+            if aggregator.has_signature_of_signer(single_signature) {
+                continue;
+            }
+            if let Err(error) = aggregator.add_single_signature(single_signature) {
+                warn!(
+                    self.logger, "Ignoring invalid single signature";
+                    "party_id" => &single_signature.party_id, "error" => ?error
+                );
+            }
+        }
+
+        Ok(f(aggregator))
+    }
+
     fn run_verify_single_signature(
         &self,
         message: &str,
@@ -109,31 +230,67 @@ impl MultiSigner for MultiSignerImpl {
         self.run_verify_single_signature(message, single_signature, next_protocol_multi_signer)
     }
 
+    async fn verify_single_signature_for_aggregation(
+        &self,
+        open_message: &OpenMessage,
+        single_signature: &entities::SingleSignature,
+    ) -> StdResult<ProtocolVerifiedSingleSignature> {
+        self.with_open_message_aggregator(open_message, |aggregator| {
+            aggregator.verify_single_signature(single_signature)
+        })
+        .await?
+    }
+
+    async fn add_verified_single_signature_to_aggregation(
+        &self,
+        open_message: &OpenMessage,
+        verified_signature: ProtocolVerifiedSingleSignature,
+    ) -> StdResult<bool> {
+        self.with_open_message_aggregator(open_message, |aggregator| {
+            aggregator
+                .add_verified_single_signature(verified_signature)
+                .map(|_| aggregator.is_quorum_reached())
+        })
+        .await?
+    }
+
     /// Creates a multi signature from single signatures
     async fn create_multi_signature(
         &self,
         open_message: &OpenMessage,
-    ) -> StdResult<Option<ProtocolMultiSignature>> {
+    ) -> StdResult<MultiSignatureAggregation> {
         debug!(self.logger, ">> create_multi_signature"; "open_message" => ?open_message);
 
-        let epoch_service = self.epoch_service.read().await;
-        let protocol_multi_signer = epoch_service.protocol_multi_signer().with_context(|| {
-            "Multi Signer could not get protocol multi-signer from epoch service"
-        })?;
+        let aggregation = self
+            .with_open_message_aggregator(open_message, |aggregator| {
+                aggregator
+                    .aggregate()
+                    .map_err(|error| (error, AggregationSummary::from_aggregator(aggregator)))
+            })
+            .await?;
 
-        match protocol_multi_signer.aggregate_single_signatures(
-            &open_message.single_signatures,
-            &open_message.protocol_message,
-        ) {
-            Ok(multi_signature) => Ok(Some(multi_signature)),
-            Err(ProtocolAggregationError::NotEnoughSignatures(actual, expected)) => {
+        match aggregation {
+            Ok(multi_signature) => Ok(MultiSignatureAggregation::Created(multi_signature)),
+            Err((ProtocolAggregationError::NotEnoughSignatures(actual, expected), summary)) => {
                 warn!(
                     self.logger,
-                    "Could not compute multi-signature: Not enough signatures. Got only {actual} out of {expected}."
+                    "Could not compute multi-signature: Not enough signatures. Got only {actual} out of {expected}.";
+                    "valid_signatures" => summary.valid_signatures,
+                    "valid_signers_stake" => summary.valid_signers_stake,
+                    "total_stake" => summary.total_stake,
+                    "rejected_signatures" => summary.rejected_signatures.len()
                 );
-                Ok(None)
+                for rejected_signature in &summary.rejected_signatures {
+                    debug!(
+                        self.logger, "Single signature rejected from the aggregation";
+                        "party_id" => ?rejected_signature.party_id,
+                        "signer_index" => rejected_signature.signer_index,
+                        "reason" => &rejected_signature.reason
+                    );
+                }
+                Ok(MultiSignatureAggregation::QuorumNotReached(summary))
             }
-            Err(err) => Err(anyhow!(err).context(format!(
+            Err((err, _)) => Err(anyhow!(err).context(format!(
                 "Multi Signer can not create multi-signature for entity type '{:?}'",
                 open_message.signed_entity_type
             ))),
@@ -147,7 +304,9 @@ mod tests {
     use tokio::sync::RwLock;
 
     use mithril_common::crypto_helper::tests_setup::*;
-    use mithril_common::entities::{CardanoDbBeacon, Epoch, SignedEntityType, SignerWithStake};
+    use mithril_common::entities::{
+        CardanoDbBeacon, Epoch, ProtocolMessage, SignedEntityType, SignerWithStake,
+    };
     use mithril_common::protocol::ToMessage;
     use mithril_common::test_utils::{fake_data, MithrilFixtureBuilder};
 
@@ -157,6 +316,20 @@ mod tests {
 
     use super::*;
 
+    async fn add_single_signature_to_aggregation(
+        multi_signer: &MultiSignerImpl,
+        open_message: &OpenMessage,
+        signature: &entities::SingleSignature,
+    ) -> StdResult<bool> {
+        let verified_signature = multi_signer
+            .verify_single_signature_for_aggregation(open_message, signature)
+            .await?;
+
+        multi_signer
+            .add_verified_single_signature_to_aggregation(open_message, verified_signature)
+            .await
+    }
+
     fn take_signatures_until_quorum_is_almost_reached(
         signatures: &mut Vec<entities::SingleSignature>,
         quorum: usize,
@@ -288,31 +461,170 @@ mod tests {
         };
 
         // No signatures registered: multi-signer can't create the multi-signature
-        assert!(multi_signer
-            .create_multi_signature(&open_message)
-            .await
-            .expect("create multi signature should not fail")
-            .is_none());
+        assert!(matches!(
+            multi_signer
+                .create_multi_signature(&open_message)
+                .await
+                .expect("create multi signature should not fail"),
+            MultiSignatureAggregation::QuorumNotReached(_)
+        ));
 
         // Add some signatures but not enough to reach the quorum: multi-signer should not create the multi-signature
         open_message.single_signatures = signatures_to_almost_reach_quorum;
 
-        assert!(multi_signer
+        let aggregation_summary = match multi_signer
             .create_multi_signature(&open_message)
             .await
             .expect("create multi signature should not fail")
-            .is_none());
+        {
+            MultiSignatureAggregation::QuorumNotReached(summary) => summary,
+            MultiSignatureAggregation::Created(_) => {
+                panic!("no multi-signature should be computed without the quorum")
+            }
+        };
+        assert!(!aggregation_summary.is_quorum_reached());
+        assert_eq!(
+            open_message.single_signatures.len() as u64,
+            aggregation_summary.valid_signatures
+        );
 
         // Add the remaining signatures to reach the quorum: multi-signer should create a multi-signature
         open_message.single_signatures.append(&mut signatures);
 
         assert!(
+            matches!(
+                multi_signer
+                    .create_multi_signature(&open_message)
+                    .await
+                    .expect("create multi signature should not fail"),
+                MultiSignatureAggregation::Created(_)
+            ),
+            "no multi-signature were computed"
+        );
+    }
+
+    #[tokio::test]
+    async fn add_single_signature_to_aggregation_returns_true_once_the_quorum_is_reached() {
+        let epoch = Epoch(5);
+        let fixture = MithrilFixtureBuilder::default().with_signers(5).build();
+        let multi_signer = MultiSignerImpl::new(
+            Arc::new(RwLock::new(FakeEpochService::from_fixture(epoch, &fixture))),
+            TestLogger::stdout(),
+        );
+        let message = setup_message();
+        let mut signatures = fixture.sign_all(&message);
+        let signatures_to_almost_reach_quorum = take_signatures_until_quorum_is_almost_reached(
+            &mut signatures,
+            fixture.protocol_parameters().k as usize,
+        );
+        let mut open_message = OpenMessage {
+            epoch,
+            signed_entity_type: SignedEntityType::CardanoImmutableFilesFull(CardanoDbBeacon {
+                epoch,
+                ..fake_data::beacon()
+            }),
+            protocol_message: message.clone(),
+            is_certified: false,
+            single_signatures: Vec::new(),
+            ..OpenMessage::dummy()
+        };
+
+        for signature in signatures_to_almost_reach_quorum {
+            let is_quorum_reached =
+                add_single_signature_to_aggregation(&multi_signer, &open_message, &signature)
+                    .await
+                    .unwrap();
+            assert!(!is_quorum_reached);
+            open_message.single_signatures.push(signature);
+        }
+
+        let last_signature = signatures.pop().unwrap();
+        open_message.single_signatures.append(&mut signatures);
+        let is_quorum_reached =
+            add_single_signature_to_aggregation(&multi_signer, &open_message, &last_signature)
+                .await
+                .unwrap();
+        assert!(is_quorum_reached);
+    }
+
+    #[tokio::test]
+    async fn verified_single_signature_is_not_aggregated_until_added() {
+        let epoch = Epoch(5);
+        let fixture = MithrilFixtureBuilder::default().with_signers(5).build();
+        let multi_signer = MultiSignerImpl::new(
+            Arc::new(RwLock::new(FakeEpochService::from_fixture(epoch, &fixture))),
+            TestLogger::stdout(),
+        );
+        let open_message = OpenMessage {
+            epoch,
+            signed_entity_type: SignedEntityType::CardanoImmutableFilesFull(CardanoDbBeacon {
+                epoch,
+                ..fake_data::beacon()
+            }),
+            protocol_message: setup_message(),
+            is_certified: false,
+            single_signatures: Vec::new(),
+            ..OpenMessage::dummy()
+        };
+
+        for signature in fixture.sign_all(&open_message.protocol_message) {
             multi_signer
-                .create_multi_signature(&open_message)
+                .verify_single_signature_for_aggregation(&open_message, &signature)
                 .await
-                .expect("create multi signature should not fail")
-                .is_some(),
-            "no multi-signature were computed"
+                .unwrap();
+        }
+
+        match multi_signer
+            .create_multi_signature(&open_message)
+            .await
+            .unwrap()
+        {
+            MultiSignatureAggregation::QuorumNotReached(summary) => {
+                assert_eq!(0, summary.valid_signatures);
+            }
+            MultiSignatureAggregation::Created(_) => {
+                panic!("Verified single signatures should not be aggregated until added")
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn verify_single_signature_for_aggregation_fails_if_the_signature_is_invalid() {
+        let epoch = Epoch(5);
+        let fixture = MithrilFixtureBuilder::default().with_signers(5).build();
+        let multi_signer = MultiSignerImpl::new(
+            Arc::new(RwLock::new(FakeEpochService::from_fixture(epoch, &fixture))),
+            TestLogger::stdout(),
         );
+        let open_message = OpenMessage {
+            epoch,
+            signed_entity_type: SignedEntityType::CardanoImmutableFilesFull(CardanoDbBeacon {
+                epoch,
+                ..fake_data::beacon()
+            }),
+            protocol_message: setup_message(),
+            is_certified: false,
+            single_signatures: Vec::new(),
+            ..OpenMessage::dummy()
+        };
+        let signature_of_another_message = fixture.sign_all(&ProtocolMessage::new()).pop().unwrap();
+
+        multi_signer
+            .verify_single_signature_for_aggregation(&open_message, &signature_of_another_message)
+            .await
+            .expect_err("Verifying a signature of another message should fail");
+
+        let aggregation_summary = match multi_signer
+            .create_multi_signature(&open_message)
+            .await
+            .unwrap()
+        {
+            MultiSignatureAggregation::QuorumNotReached(summary) => summary,
+            MultiSignatureAggregation::Created(_) => {
+                panic!("no multi-signature should be computed from an invalid signature")
+            }
+        };
+        assert_eq!(0, aggregation_summary.valid_signatures);
+        assert_eq!(1, aggregation_summary.rejected_signatures.len());
     }
 }
@@ -3,6 +3,7 @@ use chrono::Local;
 use slog::{info, trace, Logger};
 use std::fmt::Display;
 use std::sync::Arc;
+use tokio::sync::Notify;
 
 use mithril_common::entities::TimePoint;
 use mithril_common::logging::LoggerExtensions;
@@ -60,6 +61,7 @@ pub struct AggregatorRuntime {
     config: AggregatorConfig,
     state: AggregatorState,
     runner: Arc<dyn AggregatorRunnerTrait>,
+    quorum_notifier: Arc<Notify>,
     logger: Logger,
 }
 
@@ -88,10 +90,18 @@ impl AggregatorRuntime {
             config: aggregator_config,
             state,
             runner,
+            quorum_notifier: Arc::new(Notify::new()),
             logger,
         })
     }
 
+    /// Set the notifier of the open messages reaching the quorum, a cycle is run as soon as it's
+    /// notified instead of waiting for the next interval tick.
+    pub fn with_quorum_notifier(mut self, quorum_notifier: Arc<Notify>) -> Self {
+        self.quorum_notifier = quorum_notifier;
+        self
+    }
+
     /// Return the actual state of the state machine.
     pub fn get_state(&self) -> String {
         match self.state {
@@ -107,7 +117,13 @@ impl AggregatorRuntime {
         let mut interval = tokio::time::interval(self.config.interval);
 
         loop {
-            interval.tick().await;
+            tokio::select! {
+                _ = interval.tick() => {}
+                _ = self.quorum_notifier.notified() => {
+                    info!(self.logger, "Quorum reached, running a cycle without waiting for the next interval tick");
+                }
+            }
+
             // Note: the "time" property in logs produced by our formatter (slog_bunyan) uses local
             // time, so we must use it as well to avoid confusion.
             let approximate_next_cycle_time = Local::now() + self.config.interval;
@@ -818,6 +834,77 @@ mod tests {
             assert_eq!("ready".to_string(), runtime.get_state());
         }
 
+        #[tokio::test]
+        async fn signing_certificate_is_created_on_quorum_without_waiting_for_the_next_interval_tick(
+        ) {
+            let quorum_notifier = Arc::new(Notify::new());
+            let artifact_created_notifier = Arc::new(Notify::new());
+            let mut runner = MockAggregatorRunner::new();
+            runner
+                .expect_get_time_point_from_chain()
+                .times(2)
+                .returning(|| Ok(TimePoint::dummy()));
+            runner
+                .expect_is_open_message_outdated()
+                .times(2)
+                .returning(|_, _| Ok(false));
+            let mut is_quorum_reached = false;
+            let notifier = quorum_notifier.clone();
+            runner
+                .expect_create_certificate()
+                .times(2)
+                .returning(move |_| {
+                    if is_quorum_reached {
+                        Ok(Some(fake_data::certificate("whatever".to_string())))
+                    } else {
+                        // The quorum is reached right after the first cycle
+                        is_quorum_reached = true;
+                        notifier.notify_one();
+                        Ok(None)
+                    }
+                });
+            let notifier = artifact_created_notifier.clone();
+            runner
+                .expect_create_artifact()
+                .once()
+                .returning(move |_, _| {
+                    notifier.notify_one();
+                    Ok(())
+                });
+            runner
+                .expect_increment_runtime_cycle_total_since_startup_counter()
+                .times(2)
+                .returning(|| ());
+            runner
+                .expect_increment_runtime_cycle_success_since_startup_counter()
+                .once()
+                .returning(|| ());
+
+            let state = SigningState {
+                current_time_point: TimePoint::dummy(),
+                open_message: OpenMessage::dummy(),
+            };
+            let mut runtime = AggregatorRuntime::new(
+                AggregatorConfig::new(Duration::from_secs(3600), false),
+                Some(AggregatorState::Signing(state)),
+                Arc::new(runner),
+                TestLogger::stdout(),
+            )
+            .await
+            .unwrap()
+            .with_quorum_notifier(quorum_notifier);
+
+            tokio::select! {
+                result = runtime.run() => panic!("The runtime should not stop, got: {result:?}"),
+                _ = artifact_created_notifier.notified() => {}
+                _ = tokio::time::sleep(Duration::from_secs(5)) => {
+                    panic!("The certificate should be created without waiting for the next interval tick")
+                }
+            }
+
+            assert_eq!("ready".to_string(), runtime.get_state());
+        }
+
         #[tokio::test]
         pub async fn critical_error() {
             let mut runner = MockAggregatorRunner::new();
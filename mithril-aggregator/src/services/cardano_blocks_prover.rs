@@ -0,0 +1,341 @@
+use async_trait::async_trait;
+use slog::Logger;
+use std::{collections::BTreeSet, sync::Arc};
+
+use mithril_common::{
+    crypto_helper::{MKTreeNode, MKTreeStorer},
+    entities::{BlockHash, BlockNumber, BlockRange, CardanoBlock, CardanoBlocksSetProof},
+    logging::LoggerExtensions,
+    signable_builder::BlockRangeRootRetriever,
+    StdResult,
+};
+
+use crate::services::BlockRangeRootsMerkleMapPool;
+
+/// Cardano blocks prover service is the cryptographic engine in charge of producing cryptographic
+/// proofs of inclusion for Cardano blocks
+#[cfg_attr(test, mockall::automock)]
+#[async_trait]
+pub trait CardanoBlocksProverService: Sync + Send {
+    /// Compute the cryptographic proofs for the given blocks
+    async fn compute_blocks_proofs(
+        &self,
+        up_to: BlockNumber,
+        block_hashes: &[BlockHash],
+    ) -> StdResult<Vec<CardanoBlocksSetProof>>;
+
+    /// Compute the cache
+    async fn compute_cache(&self, up_to: BlockNumber) -> StdResult<()>;
+}
+
+/// Cardano blocks retriever
+#[cfg_attr(test, mockall::automock)]
+#[async_trait]
+pub trait CardanoBlocksRetriever: Sync + Send {
+    /// Get a list of blocks by hashes using chronological order
+    async fn get_by_hashes(
+        &self,
+        hashes: Vec<BlockHash>,
+        up_to: BlockNumber,
+    ) -> StdResult<Vec<CardanoBlock>>;
+
+    /// Get by block ranges
+    async fn get_by_block_ranges(
+        &self,
+        block_ranges: Vec<BlockRange>,
+    ) -> StdResult<Vec<CardanoBlock>>;
+}
+
+/// Mithril Cardano blocks prover
+pub struct MithrilCardanoBlocksProverService<S: MKTreeStorer> {
+    blocks_retriever: Arc<dyn CardanoBlocksRetriever>,
+    mk_map_pool: BlockRangeRootsMerkleMapPool<S>,
+}
+
+impl<S: MKTreeStorer> MithrilCardanoBlocksProverService<S> {
+    /// Create a new Mithril Cardano blocks prover
+    pub fn new(
+        blocks_retriever: Arc<dyn CardanoBlocksRetriever>,
+        block_range_root_retriever: Arc<dyn BlockRangeRootRetriever<S>>,
+        mk_map_pool_size: usize,
+        logger: Logger,
+    ) -> Self {
+        Self {
+            blocks_retriever,
+            mk_map_pool: BlockRangeRootsMerkleMapPool::new(
+                block_range_root_retriever,
+                mk_map_pool_size,
+                logger.new_with_component_name::<Self>(),
+            ),
+        }
+    }
+}
+
+#[async_trait]
+impl<S: MKTreeStorer> CardanoBlocksProverService for MithrilCardanoBlocksProverService<S> {
+    async fn compute_blocks_proofs(
+        &self,
+        up_to: BlockNumber,
+        block_hashes: &[BlockHash],
+    ) -> StdResult<Vec<CardanoBlocksSetProof>> {
+        // 1 - Retrieve the blocks to prove and compute the set of block ranges that contain them
+        let blocks_to_prove = self
+            .blocks_retriever
+            .get_by_hashes(block_hashes.to_vec(), up_to)
+            .await?;
+        if blocks_to_prove.is_empty() {
+            return Ok(vec![]);
+        }
+        let block_ranges = blocks_to_prove
+            .iter()
+            .map(|b| BlockRange::from_block_number(b.block_number))
+            .collect::<BTreeSet<_>>()
+            .into_iter()
+            .collect::<Vec<_>>();
+        let blocks = self
+            .blocks_retriever
+            .get_by_block_ranges(block_ranges)
+            .await?;
+        let block_ranges_blocks =
+            BlockRangeRootsMerkleMapPool::<S>::group_by_block_range(blocks, |b| b.block_number);
+
+        // 2 - Compute the proof for all blocks
+        let mk_proof = self
+            .mk_map_pool
+            .compute_proof(&block_ranges_blocks, &blocks_to_prove)?;
+        let mk_proof_leaves = mk_proof.leaves();
+        let blocks_certified: Vec<CardanoBlock> = blocks_to_prove
+            .into_iter()
+            .filter(|block| mk_proof_leaves.contains(&MKTreeNode::from(block)))
+            .collect();
+
+        Ok(vec![CardanoBlocksSetProof::new(blocks_certified, mk_proof)])
+    }
+
+    async fn compute_cache(&self, up_to: BlockNumber) -> StdResult<()> {
+        self.mk_map_pool.compute_cache(up_to).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use anyhow::anyhow;
+    use mithril_common::crypto_helper::{MKMap, MKMapNode, MKTree, MKTreeStoreInMemory};
+    use mithril_common::entities::SlotNumber;
+    use mockall::mock;
+    use mockall::predicate::eq;
+
+    use crate::test_tools::TestLogger;
+
+    use super::*;
+
+    mock! {
+        pub BlockRangeRootRetrieverImpl<S: MKTreeStorer> { }
+
+        #[async_trait]
+        impl<S: MKTreeStorer> BlockRangeRootRetriever<S> for BlockRangeRootRetrieverImpl<S> {
+            async fn retrieve_block_range_roots<'a>(
+                &'a self,
+                up_to_beacon: BlockNumber,
+            ) -> StdResult<Box<dyn Iterator<Item = (BlockRange, MKTreeNode)> + 'a>>;
+
+            async fn compute_merkle_map_from_block_range_roots(
+                &self,
+                up_to_beacon: BlockNumber,
+            ) -> StdResult<MKMap<BlockRange, MKMapNode<BlockRange, S>, S>>;
+        }
+    }
+
+    /// Build three blocks per block range for the given number of block ranges
+    fn build_blocks(total_block_ranges: u64) -> Vec<CardanoBlock> {
+        (0..total_block_ranges)
+            .flat_map(|i| {
+                let block_range_start = BlockRange::LENGTH * i;
+                (0..3u64).map(move |j| {
+                    let block_number = block_range_start + j;
+                    CardanoBlock::new(
+                        format!("block-hash-{block_number}"),
+                        block_number,
+                        SlotNumber(*block_number * 10),
+                    )
+                })
+            })
+            .collect()
+    }
+
+    fn compute_mk_map_from_blocks(
+        blocks: &[CardanoBlock],
+    ) -> MKMap<BlockRange, MKMapNode<BlockRange, MKTreeStoreInMemory>, MKTreeStoreInMemory> {
+        let mut block_ranges_map: BTreeMap<BlockRange, Vec<CardanoBlock>> = BTreeMap::new();
+        for block in blocks {
+            block_ranges_map
+                .entry(BlockRange::from_block_number(block.block_number))
+                .or_default()
+                .push(block.clone());
+        }
+
+        MKMap::new_from_iter(block_ranges_map.into_iter().map(|(block_range, blocks)| {
+            (
+                block_range,
+                MKMapNode::TreeNode(
+                    MKTree::<MKTreeStoreInMemory>::new(&blocks)
+                        .unwrap()
+                        .compute_root()
+                        .unwrap(),
+                ),
+            )
+        }))
+        .unwrap()
+    }
+
+    fn build_prover<F, G, S: MKTreeStorer + 'static>(
+        blocks_retriever_mock_config: F,
+        block_range_root_retriever_mock_config: G,
+    ) -> MithrilCardanoBlocksProverService<S>
+    where
+        F: FnOnce(&mut MockCardanoBlocksRetriever),
+        G: FnOnce(&mut MockBlockRangeRootRetrieverImpl<S>),
+    {
+        let mut blocks_retriever = MockCardanoBlocksRetriever::new();
+        blocks_retriever_mock_config(&mut blocks_retriever);
+        let mut block_range_root_retriever = MockBlockRangeRootRetrieverImpl::new();
+        block_range_root_retriever_mock_config(&mut block_range_root_retriever);
+        let mk_map_pool_size = 1;
+
+        MithrilCardanoBlocksProverService::new(
+            Arc::new(blocks_retriever),
+            Arc::new(block_range_root_retriever),
+            mk_map_pool_size,
+            TestLogger::stdout(),
+        )
+    }
+
+    #[tokio::test]
+    async fn compute_proof_for_one_set_of_certified_blocks() {
+        let blocks = build_blocks(5);
+        let beacon = blocks.last().unwrap().block_number;
+        let blocks_to_prove = vec![blocks[1].clone(), blocks[2].clone(), blocks[7].clone()];
+        let block_hashes_to_prove: Vec<BlockHash> = blocks_to_prove
+            .iter()
+            .map(|b| b.block_hash.clone())
+            .collect();
+        let prover = build_prover(
+            |blocks_retriever_mock| {
+                let blocks_to_prove = blocks_to_prove.clone();
+                blocks_retriever_mock
+                    .expect_get_by_hashes()
+                    .with(eq(block_hashes_to_prove.clone()), eq(beacon))
+                    .return_once(move |_, _| Ok(blocks_to_prove));
+
+                let all_blocks_in_block_ranges_to_prove = [&blocks[0..3], &blocks[6..9]].concat();
+                blocks_retriever_mock
+                    .expect_get_by_block_ranges()
+                    .with(eq(vec![
+                        BlockRange::from_block_number(BlockNumber(0)),
+                        BlockRange::from_block_number(BlockRange::LENGTH * 2),
+                    ]))
+                    .return_once(move |_| Ok(all_blocks_in_block_ranges_to_prove));
+            },
+            |block_range_root_retriever_mock| {
+                let mk_map = compute_mk_map_from_blocks(&blocks);
+                block_range_root_retriever_mock
+                    .expect_compute_merkle_map_from_block_range_roots()
+                    .return_once(|_| Ok(mk_map));
+            },
+        );
+        prover.compute_cache(beacon).await.unwrap();
+
+        let blocks_set_proof = prover
+            .compute_blocks_proofs(beacon, &block_hashes_to_prove)
+            .await
+            .unwrap();
+
+        assert_eq!(blocks_set_proof.len(), 1);
+        assert_eq!(blocks_set_proof[0].blocks(), &blocks_to_prove);
+        blocks_set_proof[0].verify().unwrap();
+    }
+
+    #[tokio::test]
+    async fn cant_compute_proof_for_unknown_block() {
+        let blocks = build_blocks(5);
+        let beacon = blocks.last().unwrap().block_number;
+        let prover = build_prover(
+            |blocks_retriever_mock| {
+                blocks_retriever_mock
+                    .expect_get_by_hashes()
+                    .return_once(|_, _| Ok(vec![]));
+                blocks_retriever_mock.expect_get_by_block_ranges().never();
+            },
+            |block_range_root_retriever_mock| {
+                let mk_map = compute_mk_map_from_blocks(&blocks);
+                block_range_root_retriever_mock
+                    .expect_compute_merkle_map_from_block_range_roots()
+                    .return_once(|_| Ok(mk_map));
+            },
+        );
+        prover.compute_cache(beacon).await.unwrap();
+
+        let blocks_set_proof = prover
+            .compute_blocks_proofs(beacon, &["block-unknown-123".to_string()])
+            .await
+            .unwrap();
+
+        assert_eq!(blocks_set_proof.len(), 0);
+    }
+
+    #[tokio::test]
+    async fn cant_compute_proof_for_block_of_a_block_range_without_root() {
+        let blocks = build_blocks(5);
+        let beacon = blocks.last().unwrap().block_number;
+        let block_to_prove = blocks[7].clone();
+        let prover = build_prover(
+            |blocks_retriever_mock| {
+                let blocks_to_prove = vec![block_to_prove.clone()];
+                blocks_retriever_mock
+                    .expect_get_by_hashes()
+                    .return_once(move |_, _| Ok(blocks_to_prove));
+                let all_blocks_in_block_range_to_prove = blocks[6..9].to_vec();
+                blocks_retriever_mock
+                    .expect_get_by_block_ranges()
+                    .return_once(move |_| Ok(all_blocks_in_block_range_to_prove));
+            },
+            |block_range_root_retriever_mock| {
+                // The Merkle map only knows the root of the first block range
+                let mk_map = compute_mk_map_from_blocks(&blocks[0..3]);
+                block_range_root_retriever_mock
+                    .expect_compute_merkle_map_from_block_range_roots()
+                    .return_once(|_| Ok(mk_map));
+            },
+        );
+        prover.compute_cache(beacon).await.unwrap();
+
+        prover
+            .compute_blocks_proofs(beacon, &[block_to_prove.block_hash])
+            .await
+            .expect_err("Should have failed because the block range has no root");
+    }
+
+    #[tokio::test]
+    async fn cant_compute_proof_if_blocks_retriever_fails() {
+        let prover = build_prover::<_, _, MKTreeStoreInMemory>(
+            |blocks_retriever_mock| {
+                blocks_retriever_mock
+                    .expect_get_by_hashes()
+                    .returning(|_, _| Err(anyhow!("Error")));
+            },
+            |block_range_root_retriever_mock| {
+                block_range_root_retriever_mock
+                    .expect_compute_merkle_map_from_block_range_roots()
+                    .return_once(|_| MKMap::new(&[]));
+            },
+        );
+        prover.compute_cache(BlockNumber(100)).await.unwrap();
+
+        prover
+            .compute_blocks_proofs(BlockNumber(100), &["block-hash-1".to_string()])
+            .await
+            .expect_err("Should have failed because of blocks retriever failure");
+    }
+}
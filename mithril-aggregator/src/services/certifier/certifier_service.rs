@@ -3,6 +3,7 @@ use async_trait::async_trait;
 use chrono::Utc;
 use slog::{debug, info, trace, warn, Logger};
 use std::sync::Arc;
+use tokio::sync::Notify;
 
 use mithril_common::certificate_chain::CertificateVerifier;
 use mithril_common::crypto_helper::{ProtocolGenesisVerifier, PROTOCOL_VERSION};
@@ -11,7 +12,6 @@ use mithril_common::entities::{
     SignedEntityType, SingleSignature, StakeDistributionParty,
 };
 use mithril_common::logging::LoggerExtensions;
-use mithril_common::protocol::ToMessage;
 use mithril_common::{CardanoNetwork, StdResult};
 
 use crate::database::record::{OpenMessageRecord, OpenMessageWithSingleSignaturesRecord};
@@ -21,7 +21,7 @@ use crate::database::repository::{
 use crate::dependency_injection::EpochServiceWrapper;
 use crate::entities::OpenMessage;
 use crate::services::{CertifierService, CertifierServiceError, SignatureRegistrationStatus};
-use crate::MultiSigner;
+use crate::{MultiSignatureAggregation, MultiSigner};
 
 /// Mithril CertifierService implementation
 pub struct MithrilCertifierService {
@@ -33,6 +33,7 @@ pub struct MithrilCertifierService {
     genesis_verifier: Arc<ProtocolGenesisVerifier>,
     multi_signer: Arc<dyn MultiSigner>,
     epoch_service: EpochServiceWrapper,
+    quorum_notifier: Arc<Notify>,
     logger: Logger,
 }
 
@@ -48,6 +49,7 @@ impl MithrilCertifierService {
         genesis_verifier: Arc<ProtocolGenesisVerifier>,
         multi_signer: Arc<dyn MultiSigner>,
         epoch_service: EpochServiceWrapper,
+        quorum_notifier: Arc<Notify>,
         logger: Logger,
     ) -> Self {
         Self {
@@ -59,6 +61,7 @@ impl MithrilCertifierService {
             certificate_verifier,
             genesis_verifier,
             epoch_service,
+            quorum_notifier,
             logger: logger.new_with_component_name::<Self>(),
         }
     }
@@ -126,8 +129,11 @@ impl CertifierService for MithrilCertifierService {
             return Err(CertifierServiceError::Expired(signed_entity_type.clone()).into());
         }
 
-        self.multi_signer
-            .verify_single_signature(&open_message.protocol_message.to_message(), signature)
+        // The single signature is verified once, before it's stored, and it's only added to the
+        // aggregation once stored so the aggregation never holds a signature missing in database
+        let verified_signature = self
+            .multi_signer
+            .verify_single_signature_for_aggregation(&open_message.clone().into(), signature)
             .await
             .map_err(|err| {
                 CertifierServiceError::InvalidSingleSignature(signed_entity_type.clone(), err)
@@ -144,6 +150,17 @@ impl CertifierService for MithrilCertifierService {
             single_signature.open_message_id
         );
 
+        let is_quorum_reached = self
+            .multi_signer
+            .add_verified_single_signature_to_aggregation(&open_message.clone().into(), verified_signature)
+            .await
+            .with_context(|| format!("Certifier can not add the single signature of party '{}' to the aggregation of open message: '{open_message:?}'", signature.party_id))?;
+
+        if is_quorum_reached {
+            info!(self.logger, "register_single_signature: quorum reached for {signed_entity_type:?}, notifying the runtime.");
+            self.quorum_notifier.notify_one();
+        }
+
         Ok(SignatureRegistrationStatus::Registered)
     }
 
@@ -261,11 +278,21 @@ impl CertifierService for MithrilCertifierService {
             .create_multi_signature(&open_message)
             .await?
         {
-            None => {
+            MultiSignatureAggregation::QuorumNotReached(aggregation_summary) => {
                 debug!(self.logger, "create_certificate: No multi-signature could be created for open message {signed_entity_type:?}");
+                self.open_message_repository
+                    .update_open_message_aggregation_summary(
+                        &open_message_record.open_message_id,
+                        &aggregation_summary,
+                    )
+                    .await
+                    .with_context(|| {
+                        "Certifier can not persist the aggregation summary of the open message"
+                    })?;
+
                 return Ok(None);
             }
-            Some(signature) => {
+            MultiSignatureAggregation::Created(signature) => {
                 info!(self.logger, "create_certificate: multi-signature created for open message {signed_entity_type:?}");
                 signature
             }
@@ -388,8 +415,9 @@ mod tests {
     use std::path::PathBuf;
 
     use crate::{
-        dependency_injection::DependenciesBuilder, multi_signer::MockMultiSigner,
-        services::FakeEpochService, test_tools::TestLogger, ServeCommandConfiguration,
+        dependency_injection::DependenciesBuilder, entities::AggregationSummary,
+        multi_signer::MockMultiSigner, services::FakeEpochService, test_tools::TestLogger,
+        ServeCommandConfiguration,
     };
     use chrono::{DateTime, Days};
     use mithril_common::{
@@ -397,6 +425,7 @@ mod tests {
         temp_dir,
         test_utils::{fake_data, MithrilFixture, MithrilFixtureBuilder},
     };
+    use mithril_persistence::sqlite::ConnectionBuilder;
     use tokio::sync::RwLock;
 
     use super::*;
@@ -425,6 +454,7 @@ mod tests {
                 genesis_verifier,
                 multi_signer,
                 epoch_service,
+                Arc::new(Notify::new()),
                 TestLogger::stdout(),
             )
         }
@@ -629,6 +659,81 @@ mod tests {
         assert!(!open_message.single_signatures.is_empty());
     }
 
+    #[tokio::test]
+    async fn should_notify_when_registered_single_signatures_reach_the_quorum() {
+        let beacon = CardanoDbBeacon::new(3, 1);
+        let signed_entity_type = SignedEntityType::CardanoImmutableFilesFull(beacon.clone());
+        let protocol_message = ProtocolMessage::new();
+        let epochs_with_signers = (1..=3).map(Epoch).collect::<Vec<_>>();
+        let fixture = MithrilFixtureBuilder::default().with_signers(5).build();
+        let mut certifier_service = setup_certifier_service(
+            temp_dir!(),
+            &fixture,
+            &epochs_with_signers,
+            Some(beacon.epoch),
+        )
+        .await;
+        let quorum_notifier = Arc::new(Notify::new());
+        certifier_service.quorum_notifier = quorum_notifier.clone();
+
+        certifier_service
+            .create_open_message(&signed_entity_type, &protocol_message)
+            .await
+            .unwrap();
+
+        for signature in fixture.sign_all(&protocol_message) {
+            certifier_service
+                .register_single_signature(&signed_entity_type, &signature)
+                .await
+                .unwrap();
+        }
+
+        tokio::time::timeout(
+            std::time::Duration::from_secs(1),
+            quorum_notifier.notified(),
+        )
+        .await
+        .expect("The quorum should have been notified");
+    }
+
+    #[tokio::test]
+    async fn should_not_aggregate_single_signatures_that_could_not_be_stored() {
+        let beacon = CardanoDbBeacon::new(3, 1);
+        let signed_entity_type = SignedEntityType::CardanoImmutableFilesFull(beacon.clone());
+        let protocol_message = ProtocolMessage::new();
+        let epochs_with_signers = (1..=3).map(Epoch).collect::<Vec<_>>();
+        let fixture = MithrilFixtureBuilder::default().with_signers(5).build();
+        let mut certifier_service = setup_certifier_service(
+            temp_dir!(),
+            &fixture,
+            &epochs_with_signers,
+            Some(beacon.epoch),
+        )
+        .await;
+        // A database without any table, so storing a single signature always fails
+        certifier_service.single_signature_repository = Arc::new(SingleSignatureRepository::new(
+            Arc::new(ConnectionBuilder::open_memory().build().unwrap()),
+        ));
+
+        certifier_service
+            .create_open_message(&signed_entity_type, &protocol_message)
+            .await
+            .unwrap();
+
+        for signature in fixture.sign_all(&protocol_message) {
+            certifier_service
+                .register_single_signature(&signed_entity_type, &signature)
+                .await
+                .expect_err("Registering a single signature that can not be stored should fail");
+        }
+
+        let create_certificate_result = certifier_service
+            .create_certificate(&signed_entity_type)
+            .await
+            .unwrap();
+        assert!(create_certificate_result.is_none());
+    }
+
     #[tokio::test]
     async fn should_not_register_invalid_single_signature() {
         let beacon = CardanoDbBeacon::new(3, 1);
@@ -854,10 +959,24 @@ mod tests {
 
     #[tokio::test]
     async fn should_not_create_certificate_when_no_multi_signature_produced() {
+        let aggregation_summary = AggregationSummary {
+            quorum: 10,
+            unique_indexes: 4,
+            valid_signatures: 1,
+            valid_signers_stake: 100,
+            total_stake: 1000,
+            rejected_signatures: vec![],
+            computed_at: Utc::now(),
+        };
+        let expected_aggregation_summary = aggregation_summary.clone();
         let mut mock_multi_signer = MockMultiSigner::new();
         mock_multi_signer
             .expect_create_multi_signature()
-            .return_once(move |_| Ok(None));
+            .return_once(move |_| {
+                Ok(MultiSignatureAggregation::QuorumNotReached(
+                    aggregation_summary,
+                ))
+            });
         let beacon = CardanoDbBeacon::new(1, 1);
         let signed_entity_type = SignedEntityType::CardanoImmutableFilesFull(beacon.clone());
         let protocol_message = ProtocolMessage::new();
@@ -875,6 +994,16 @@ mod tests {
             .await
             .unwrap();
         assert!(create_certificate_result.is_none());
+
+        let open_message_record = certifier_service
+            .get_open_message_record(&signed_entity_type)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(
+            Some(expected_aggregation_summary),
+            open_message_record.aggregation_summary
+        );
     }
 
     #[tokio::test]
@@ -10,6 +10,7 @@ use mithril_common::{
     messages::{
         CardanoDatabaseDigestListItemMessage, CardanoDatabaseDigestListMessage,
         CardanoDatabaseSnapshotListMessage, CardanoDatabaseSnapshotMessage,
+        CardanoProtocolParametersListMessage, CardanoProtocolParametersMessage,
         CardanoStakeDistributionListMessage, CardanoStakeDistributionMessage,
         CardanoTransactionSnapshotListMessage, CardanoTransactionSnapshotMessage,
         CertificateListMessage, CertificateMessage, EpochSettingsMessage,
@@ -113,6 +114,24 @@ pub trait MessageService: Sync + Send {
         &self,
         limit: usize,
     ) -> StdResult<CardanoStakeDistributionListMessage>;
+
+    /// Return the information regarding the Cardano protocol parameters for the given identifier.
+    async fn get_cardano_protocol_parameters_message(
+        &self,
+        signed_entity_id: &str,
+    ) -> StdResult<Option<CardanoProtocolParametersMessage>>;
+
+    /// Return the information regarding the Cardano protocol parameters for the given epoch.
+    async fn get_cardano_protocol_parameters_message_by_epoch(
+        &self,
+        epoch: Epoch,
+    ) -> StdResult<Option<CardanoProtocolParametersMessage>>;
+
+    /// Return the list of the last Cardano protocol parameters message.
+    async fn get_cardano_protocol_parameters_list_message(
+        &self,
+        limit: usize,
+    ) -> StdResult<CardanoProtocolParametersListMessage>;
 }
 
 /// Implementation of the [MessageService]
@@ -155,8 +174,10 @@ impl MessageService for MithrilMessageService {
         let current_signers = epoch_service.current_signers()?;
         let next_signers = epoch_service.next_signers()?;
 
-        let cardano_transactions_discriminant =
-            allowed_discriminants.get(&SignedEntityTypeDiscriminants::CardanoTransactions);
+        // Cardano blocks are signed with the Cardano transactions signing configuration
+        let cardano_transactions_discriminant = allowed_discriminants
+            .get(&SignedEntityTypeDiscriminants::CardanoTransactions)
+            .or(allowed_discriminants.get(&SignedEntityTypeDiscriminants::CardanoBlocks));
 
         let cardano_transactions_signing_config = cardano_transactions_discriminant
             .map(|_| epoch_service.current_cardano_transactions_signing_config())
@@ -347,6 +368,43 @@ impl MessageService for MithrilMessageService {
 
         entities.into_iter().map(|i| i.try_into()).collect()
     }
+
+    async fn get_cardano_protocol_parameters_message(
+        &self,
+        signed_entity_id: &str,
+    ) -> StdResult<Option<CardanoProtocolParametersMessage>> {
+        let signed_entity = self
+            .signed_entity_storer
+            .get_signed_entity(signed_entity_id)
+            .await?;
+
+        signed_entity.map(|v| v.try_into()).transpose()
+    }
+
+    async fn get_cardano_protocol_parameters_message_by_epoch(
+        &self,
+        epoch: Epoch,
+    ) -> StdResult<Option<CardanoProtocolParametersMessage>> {
+        let signed_entity = self
+            .signed_entity_storer
+            .get_cardano_protocol_parameters_signed_entity_by_epoch(epoch)
+            .await?;
+
+        signed_entity.map(|v| v.try_into()).transpose()
+    }
+
+    async fn get_cardano_protocol_parameters_list_message(
+        &self,
+        limit: usize,
+    ) -> StdResult<CardanoProtocolParametersListMessage> {
+        let signed_entity_type_id = SignedEntityTypeDiscriminants::CardanoProtocolParameters;
+        let entities = self
+            .signed_entity_storer
+            .get_last_signed_entities_by_type(&signed_entity_type_id, limit)
+            .await?;
+
+        entities.into_iter().map(|i| i.try_into()).collect()
+    }
 }
 
 #[cfg(test)]
@@ -518,6 +576,26 @@ mod tests {
             assert!(message.next_cardano_transactions_signing_config.is_some(),);
         }
 
+        #[tokio::test]
+        async fn get_epoch_settings_message_with_cardano_blocks_enabled() {
+            let fixture = MithrilFixtureBuilder::default().with_signers(3).build();
+            let epoch_service = FakeEpochService::from_fixture(Epoch(4), &fixture);
+            let message_service = MessageServiceBuilder::new()
+                .with_epoch_service(epoch_service)
+                .build()
+                .await;
+
+            let message = message_service
+                .get_epoch_settings_message(BTreeSet::from([
+                    SignedEntityTypeDiscriminants::CardanoBlocks,
+                ]))
+                .await
+                .unwrap();
+
+            assert!(message.cardano_transactions_signing_config.is_some());
+            assert!(message.next_cardano_transactions_signing_config.is_some(),);
+        }
+
         #[tokio::test]
         async fn get_epoch_settings_message_with_cardano_transactions_not_enabled() {
             let fixture = MithrilFixtureBuilder::default().with_signers(3).build();
@@ -1144,4 +1222,138 @@ mod tests {
             assert_eq!(message, response);
         }
     }
+
+    mod cardano_protocol_parameters {
+        use mithril_common::entities::{CardanoProtocolParameters, LedgerProtocolParameters};
+
+        use super::*;
+
+        #[tokio::test]
+        async fn get_cardano_protocol_parameters() {
+            let record = SignedEntityRecord {
+                signed_entity_id: "signed_entity_id".to_string(),
+                signed_entity_type: SignedEntityType::CardanoProtocolParameters(Epoch(18)),
+                certificate_id: "cert_id".to_string(),
+                artifact: serde_json::to_string(&CardanoProtocolParameters::new(
+                    Epoch(18),
+                    LedgerProtocolParameters::dummy(),
+                ))
+                .unwrap(),
+                created_at: Default::default(),
+            };
+            let message: CardanoProtocolParametersMessage = record.clone().try_into().unwrap();
+
+            let service = MessageServiceBuilder::new()
+                .with_signed_entity_records(&[record.clone()])
+                .build()
+                .await;
+
+            let response = service
+                .get_cardano_protocol_parameters_message(&record.signed_entity_id)
+                .await
+                .unwrap()
+                .expect("A CardanoProtocolParametersMessage was expected.");
+
+            assert_eq!(message, response);
+        }
+
+        #[tokio::test]
+        async fn get_cardano_protocol_parameters_not_exist() {
+            let service = MessageServiceBuilder::new().build().await;
+
+            let response = service
+                .get_cardano_protocol_parameters_message("whatever")
+                .await
+                .unwrap();
+
+            assert!(response.is_none());
+        }
+
+        #[tokio::test]
+        async fn get_cardano_protocol_parameters_by_epoch() {
+            let record = SignedEntityRecord {
+                signed_entity_id: "signed_entity_id".to_string(),
+                signed_entity_type: SignedEntityType::CardanoProtocolParameters(Epoch(18)),
+                certificate_id: "cert_id".to_string(),
+                artifact: serde_json::to_string(&CardanoProtocolParameters::new(
+                    Epoch(18),
+                    LedgerProtocolParameters::dummy(),
+                ))
+                .unwrap(),
+                created_at: Default::default(),
+            };
+            let message: CardanoProtocolParametersMessage = record.clone().try_into().unwrap();
+
+            let service = MessageServiceBuilder::new()
+                .with_signed_entity_records(&[record.clone()])
+                .build()
+                .await;
+
+            let response = service
+                .get_cardano_protocol_parameters_message_by_epoch(
+                    record.signed_entity_type.get_epoch(),
+                )
+                .await
+                .unwrap()
+                .expect("A CardanoProtocolParametersMessage was expected.");
+
+            assert_eq!(message, response);
+        }
+
+        #[tokio::test]
+        async fn get_cardano_protocol_parameters_by_epoch_not_exist() {
+            let service = MessageServiceBuilder::new().build().await;
+
+            let response = service
+                .get_cardano_protocol_parameters_message_by_epoch(Epoch(999))
+                .await
+                .unwrap();
+
+            assert!(response.is_none());
+        }
+
+        #[tokio::test]
+        async fn get_cardano_protocol_parameters_list_message() {
+            let records = vec![
+                SignedEntityRecord {
+                    signed_entity_id: "signed_entity_id-1".to_string(),
+                    signed_entity_type: SignedEntityType::CardanoProtocolParameters(Epoch(18)),
+                    certificate_id: "cert_id-1".to_string(),
+                    artifact: serde_json::to_string(&CardanoProtocolParameters::new(
+                        Epoch(18),
+                        LedgerProtocolParameters::dummy(),
+                    ))
+                    .unwrap(),
+                    created_at: Default::default(),
+                },
+                SignedEntityRecord {
+                    signed_entity_id: "signed_entity_id-2".to_string(),
+                    signed_entity_type: SignedEntityType::CardanoDatabase(fake_data::beacon()),
+                    certificate_id: "cert_id-2".to_string(),
+                    artifact: serde_json::to_string(&fake_data::cardano_database_snapshots(1)[0])
+                        .unwrap(),
+                    created_at: Default::default(),
+                },
+            ];
+            let message: CardanoProtocolParametersListMessage =
+                vec![records[0].clone().try_into().unwrap()];
+
+            let service = MessageServiceBuilder::new()
+                .with_signed_entity_records(&records)
+                .build()
+                .await;
+
+            let response = service
+                .get_cardano_protocol_parameters_list_message(0)
+                .await
+                .unwrap();
+            assert!(response.is_empty());
+
+            let response = service
+                .get_cardano_protocol_parameters_list_message(3)
+                .await
+                .unwrap();
+            assert_eq!(message, response);
+        }
+    }
 }
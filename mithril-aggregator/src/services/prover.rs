@@ -2,15 +2,16 @@ use async_trait::async_trait;
 use rayon::prelude::*;
 use slog::{debug, info, Logger};
 use std::{
-    collections::{BTreeMap, BTreeSet, HashMap},
+    collections::{BTreeMap, BTreeSet},
     sync::Arc,
     time::Duration,
 };
 
 use mithril_common::{
-    crypto_helper::{MKMap, MKMapNode, MKTree, MKTreeStorer},
+    crypto_helper::{MKMap, MKMapNode, MKMapProof, MKTree, MKTreeNode, MKTreeStorer},
     entities::{
-        BlockNumber, BlockRange, CardanoTransaction, CardanoTransactionsSetProof, TransactionHash,
+        BlockNumber, BlockRange, CardanoTransaction, CardanoTransactionFilter,
+        CardanoTransactionsSetProof, TransactionHash,
     },
     logging::LoggerExtensions,
     signable_builder::BlockRangeRootRetriever,
@@ -29,6 +30,16 @@ pub trait ProverService: Sync + Send {
         transaction_hashes: &[TransactionHash],
     ) -> StdResult<Vec<CardanoTransactionsSetProof>>;
 
+    /// Compute the cryptographic proof of all the transactions of the given block ranges, the
+    /// proven transactions hashes being the ones matching the given filter
+    ///
+    /// Only the certified block ranges are proven, `None` is returned if none of them are.
+    async fn compute_transactions_proofs_by_filter(
+        &self,
+        filter: &CardanoTransactionFilter,
+        block_ranges: &[BlockRange],
+    ) -> StdResult<Option<CardanoTransactionsSetProof>>;
+
     /// Compute the cache
     async fn compute_cache(&self, up_to: BlockNumber) -> StdResult<()>;
 }
@@ -49,122 +60,98 @@ pub trait TransactionsRetriever: Sync + Send {
         &self,
         block_ranges: Vec<BlockRange>,
     ) -> StdResult<Vec<CardanoTransaction>>;
+
+    /// Get the hashes of the transactions matching a filter in the given block ranges using
+    /// chronological order
+    async fn get_hashes_by_filter(
+        &self,
+        filter: &CardanoTransactionFilter,
+        block_ranges: Vec<BlockRange>,
+    ) -> StdResult<Vec<TransactionHash>>;
 }
 
-/// Mithril prover
-pub struct MithrilProverService<S: MKTreeStorer> {
-    transaction_retriever: Arc<dyn TransactionsRetriever>,
+/// Pool of Merkle maps of the certified block range roots, shared by the provers of the leaves
+/// of the block ranges (Cardano transactions, Cardano blocks)
+pub struct BlockRangeRootsMerkleMapPool<S: MKTreeStorer> {
     block_range_root_retriever: Arc<dyn BlockRangeRootRetriever<S>>,
     mk_map_pool: ResourcePool<MKMap<BlockRange, MKMapNode<BlockRange, S>, S>>,
     logger: Logger,
 }
 
-impl<S: MKTreeStorer> MithrilProverService<S> {
-    /// Create a new Mithril prover
+impl<S: MKTreeStorer> BlockRangeRootsMerkleMapPool<S> {
+    /// Create a new Merkle maps pool
     pub fn new(
-        transaction_retriever: Arc<dyn TransactionsRetriever>,
         block_range_root_retriever: Arc<dyn BlockRangeRootRetriever<S>>,
         mk_map_pool_size: usize,
         logger: Logger,
     ) -> Self {
         Self {
-            transaction_retriever,
             block_range_root_retriever,
             mk_map_pool: ResourcePool::new(mk_map_pool_size, vec![]),
-            logger: logger.new_with_component_name::<Self>(),
+            logger,
         }
     }
 
-    async fn get_block_ranges(
-        &self,
-        transaction_hashes: &[TransactionHash],
-        up_to: BlockNumber,
-    ) -> StdResult<Vec<BlockRange>> {
-        let transactions = self
-            .transaction_retriever
-            .get_by_hashes(transaction_hashes.to_vec(), up_to)
-            .await?;
-        let block_ranges = transactions
-            .iter()
-            .map(|t| BlockRange::from_block_number(t.block_number))
-            .collect::<BTreeSet<_>>();
+    /// Group the given leaves by the block range of their block number
+    pub fn group_by_block_range<L>(
+        leaves: Vec<L>,
+        block_number: impl Fn(&L) -> BlockNumber,
+    ) -> BTreeMap<BlockRange, Vec<L>> {
+        let mut block_ranges_map: BTreeMap<BlockRange, Vec<L>> = BTreeMap::new();
+        for leaf in leaves {
+            block_ranges_map
+                .entry(BlockRange::from_block_number(block_number(&leaf)))
+                .or_default()
+                .push(leaf);
+        }
 
-        Ok(block_ranges.into_iter().collect::<Vec<_>>())
+        block_ranges_map
     }
 
-    /// Get all the transactions of the block ranges
-    async fn get_all_transactions_for_block_ranges(
+    /// Run a computation on a Merkle map acquired from the pool, the Merkle map being given back
+    /// to the pool whatever the outcome of the computation
+    pub fn with_merkle_map<R>(
         &self,
-        block_ranges: &[BlockRange],
-    ) -> StdResult<HashMap<BlockRange, Vec<CardanoTransaction>>> {
-        let mut block_ranges_map = HashMap::new();
-        let transactions = self
-            .transaction_retriever
-            .get_by_block_ranges(block_ranges.to_vec())
-            .await?;
-        for transaction in transactions {
-            let block_range = BlockRange::from_block_number(transaction.block_number);
-            let block_range_transactions: &mut Vec<_> =
-                block_ranges_map.entry(block_range).or_insert(vec![]);
-            block_range_transactions.push(transaction)
-        }
+        computation: impl FnOnce(&mut MKMap<BlockRange, MKMapNode<BlockRange, S>, S>) -> StdResult<R>,
+    ) -> StdResult<R> {
+        let acquire_timeout = Duration::from_millis(1000);
+        let mut mk_map = self.mk_map_pool.acquire_resource(acquire_timeout)?;
+        let result = computation(&mut *mk_map);
+        self.mk_map_pool.give_back_resource_pool_item(mk_map)?;
 
-        Ok(block_ranges_map)
+        result
     }
-}
 
-#[async_trait]
-impl<S: MKTreeStorer> ProverService for MithrilProverService<S> {
-    async fn compute_transactions_proofs(
+    /// Compute the proof of the given leaves, the block ranges that contain them being enriched
+    /// with all their leaves
+    pub fn compute_proof<L, T>(
         &self,
-        up_to: BlockNumber,
-        transaction_hashes: &[TransactionHash],
-    ) -> StdResult<Vec<CardanoTransactionsSetProof>> {
-        // 1 - Compute the set of block ranges with transactions to prove
-        let block_ranges_transactions = self.get_block_ranges(transaction_hashes, up_to).await?;
-        let block_range_transactions = self
-            .get_all_transactions_for_block_ranges(&block_ranges_transactions)
-            .await?;
-
-        // 2 - Compute block ranges sub Merkle trees
-        let mk_trees: StdResult<Vec<(BlockRange, MKTree<S>)>> = block_range_transactions
-            .into_iter()
-            .map(|(block_range, transactions)| {
-                let mk_tree = MKTree::new(&transactions)?;
-                Ok((block_range, mk_tree))
-            })
-            .collect();
-        let mk_trees = BTreeMap::from_iter(mk_trees?);
-
-        // 3 - Compute block range roots Merkle map
-        let acquire_timeout = Duration::from_millis(1000);
-        let mut mk_map = self.mk_map_pool.acquire_resource(acquire_timeout)?;
+        block_ranges_leaves: &BTreeMap<BlockRange, Vec<L>>,
+        leaves_to_prove: &[T],
+    ) -> StdResult<MKMapProof<BlockRange>>
+    where
+        L: Into<MKTreeNode> + Clone,
+        T: Into<MKTreeNode> + Clone,
+    {
+        // 1 - Compute block ranges sub Merkle trees
+        let mk_trees = block_ranges_leaves
+            .iter()
+            .map(|(block_range, leaves)| Ok((block_range.to_owned(), MKTree::<S>::new(leaves)?)))
+            .collect::<StdResult<Vec<_>>>()?;
 
-        // 4 - Enrich the Merkle map with the block ranges Merkle trees
-        for (block_range, mk_tree) in mk_trees {
-            mk_map.replace(block_range, mk_tree.into())?;
-        }
+        self.with_merkle_map(|mk_map| {
+            // 2 - Enrich the Merkle map with the block ranges Merkle trees
+            for (block_range, mk_tree) in mk_trees {
+                mk_map.replace(block_range, mk_tree.into())?;
+            }
 
-        // 5 - Compute the proof for all transactions
-        if let Ok(mk_proof) = mk_map.compute_proof(transaction_hashes) {
-            self.mk_map_pool.give_back_resource_pool_item(mk_map)?;
-            let mk_proof_leaves = mk_proof.leaves();
-            let transaction_hashes_certified: Vec<TransactionHash> = transaction_hashes
-                .iter()
-                .filter(|hash| mk_proof_leaves.contains(&hash.as_str().into()))
-                .cloned()
-                .collect();
-
-            Ok(vec![CardanoTransactionsSetProof::new(
-                transaction_hashes_certified,
-                mk_proof,
-            )])
-        } else {
-            Ok(vec![])
-        }
+            // 3 - Compute the proof for all the leaves
+            mk_map.compute_proof(leaves_to_prove)
+        })
     }
 
-    async fn compute_cache(&self, up_to: BlockNumber) -> StdResult<()> {
+    /// Compute the cache
+    pub async fn compute_cache(&self, up_to: BlockNumber) -> StdResult<()> {
         let pool_size = self.mk_map_pool.size();
         info!(
             self.logger, "Starts computing the Merkle map pool resource of size {pool_size}";
@@ -208,6 +195,154 @@ impl<S: MKTreeStorer> ProverService for MithrilProverService<S> {
     }
 }
 
+/// Mithril prover
+pub struct MithrilProverService<S: MKTreeStorer> {
+    transaction_retriever: Arc<dyn TransactionsRetriever>,
+    mk_map_pool: BlockRangeRootsMerkleMapPool<S>,
+}
+
+impl<S: MKTreeStorer> MithrilProverService<S> {
+    /// Create a new Mithril prover
+    pub fn new(
+        transaction_retriever: Arc<dyn TransactionsRetriever>,
+        block_range_root_retriever: Arc<dyn BlockRangeRootRetriever<S>>,
+        mk_map_pool_size: usize,
+        logger: Logger,
+    ) -> Self {
+        Self {
+            transaction_retriever,
+            mk_map_pool: BlockRangeRootsMerkleMapPool::new(
+                block_range_root_retriever,
+                mk_map_pool_size,
+                logger.new_with_component_name::<Self>(),
+            ),
+        }
+    }
+
+    /// Get all the transactions of the block ranges
+    async fn get_all_transactions_for_block_ranges(
+        &self,
+        block_ranges: &[BlockRange],
+    ) -> StdResult<BTreeMap<BlockRange, Vec<CardanoTransaction>>> {
+        let transactions = self
+            .transaction_retriever
+            .get_by_block_ranges(block_ranges.to_vec())
+            .await?;
+
+        Ok(BlockRangeRootsMerkleMapPool::<S>::group_by_block_range(
+            transactions,
+            |transaction| transaction.block_number,
+        ))
+    }
+}
+
+#[async_trait]
+impl<S: MKTreeStorer> ProverService for MithrilProverService<S> {
+    async fn compute_transactions_proofs(
+        &self,
+        up_to: BlockNumber,
+        transaction_hashes: &[TransactionHash],
+    ) -> StdResult<Vec<CardanoTransactionsSetProof>> {
+        // 1 - Compute the set of block ranges with transactions to prove
+        let transactions_to_prove = self
+            .transaction_retriever
+            .get_by_hashes(transaction_hashes.to_vec(), up_to)
+            .await?;
+        if transactions_to_prove.is_empty() {
+            return Ok(vec![]);
+        }
+        let block_ranges = transactions_to_prove
+            .iter()
+            .map(|t| BlockRange::from_block_number(t.block_number))
+            .collect::<BTreeSet<_>>()
+            .into_iter()
+            .collect::<Vec<_>>();
+        let block_range_transactions = self
+            .get_all_transactions_for_block_ranges(&block_ranges)
+            .await?;
+
+        // 2 - Compute the proof for all transactions
+        let mk_proof = self
+            .mk_map_pool
+            .compute_proof(&block_range_transactions, transaction_hashes)?;
+        let mk_proof_leaves = mk_proof.leaves();
+        let transaction_hashes_certified: Vec<TransactionHash> = transaction_hashes
+            .iter()
+            .filter(|hash| mk_proof_leaves.contains(&hash.as_str().into()))
+            .cloned()
+            .collect();
+
+        Ok(vec![CardanoTransactionsSetProof::new(
+            transaction_hashes_certified,
+            mk_proof,
+        )])
+    }
+
+    async fn compute_transactions_proofs_by_filter(
+        &self,
+        filter: &CardanoTransactionFilter,
+        block_ranges: &[BlockRange],
+    ) -> StdResult<Option<CardanoTransactionsSetProof>> {
+        // 1 - Compute block ranges sub Merkle trees with all their transactions
+        let block_range_transactions = self
+            .get_all_transactions_for_block_ranges(block_ranges)
+            .await?;
+        let mk_trees: StdResult<Vec<(BlockRange, MKTree<S>)>> = block_range_transactions
+            .iter()
+            .map(|(block_range, transactions)| {
+                let mk_tree = MKTree::new(transactions)?;
+                Ok((block_range.to_owned(), mk_tree))
+            })
+            .collect();
+        let mk_trees = mk_trees?;
+
+        // 2 - Enrich the Merkle map with the certified block ranges Merkle trees and compute the
+        // proof for all transactions of the certified block ranges
+        let mk_proof = self.mk_map_pool.with_merkle_map(|mk_map| {
+            let mut certified_transaction_hashes = vec![];
+            for (block_range, mk_tree) in mk_trees {
+                if mk_map.get(&block_range).is_some() {
+                    certified_transaction_hashes.extend(
+                        block_range_transactions[&block_range]
+                            .iter()
+                            .map(|t| t.transaction_hash.clone()),
+                    );
+                    mk_map.replace(block_range, mk_tree.into())?;
+                }
+            }
+
+            if certified_transaction_hashes.is_empty() {
+                Ok(None)
+            } else {
+                Ok(mk_map.compute_proof(&certified_transaction_hashes).ok())
+            }
+        })?;
+        let Some(mk_proof) = mk_proof else {
+            return Ok(None);
+        };
+
+        // 3 - Keep the transactions matching the filter in the proven block ranges
+        let matching_transaction_hashes = self
+            .transaction_retriever
+            .get_hashes_by_filter(filter, mk_proof.proven_keys())
+            .await?;
+        let mk_proof_leaves = mk_proof.leaves();
+        let transaction_hashes_certified: Vec<TransactionHash> = matching_transaction_hashes
+            .into_iter()
+            .filter(|hash| mk_proof_leaves.contains(&hash.as_str().into()))
+            .collect();
+
+        Ok(Some(CardanoTransactionsSetProof::new(
+            transaction_hashes_certified,
+            mk_proof,
+        )))
+    }
+
+    async fn compute_cache(&self, up_to: BlockNumber) -> StdResult<()> {
+        self.mk_map_pool.compute_cache(up_to).await
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use anyhow::anyhow;
@@ -578,6 +713,125 @@ mod tests {
         transactions_set_proof[0].verify().unwrap();
     }
 
+    #[tokio::test]
+    async fn compute_proof_by_filter_for_all_transactions_of_the_certified_block_ranges() {
+        let transactions = CardanoTransactionsBuilder::new()
+            .max_transactions_per_block(1)
+            .blocks_per_block_range(3)
+            .build_block_ranges(5);
+        let test_data = test_data::build_test_data(&[], &transactions);
+        let certified_block_ranges = test_data
+            .block_ranges_map
+            .keys()
+            .take(2)
+            .cloned()
+            .collect::<Vec<_>>();
+        let uncertified_block_range =
+            BlockRange::from_block_number(test_data.beacon + BlockRange::LENGTH);
+        let block_ranges_to_prove = [
+            certified_block_ranges.clone(),
+            vec![uncertified_block_range],
+        ]
+        .concat();
+        let all_transactions_in_certified_block_ranges =
+            test_data::filter_transactions_for_block_ranges(&certified_block_ranges, &transactions);
+        let matching_transaction_hashes =
+            test_data::map_to_transaction_hashes(&test_data::filter_transactions_for_indices(
+                &[0, 2],
+                &all_transactions_in_certified_block_ranges,
+            ));
+        let filter = CardanoTransactionFilter::MetadataLabel(674);
+        let prover = build_prover(
+            |transaction_retriever_mock| {
+                let all_transactions_in_certified_block_ranges =
+                    all_transactions_in_certified_block_ranges.clone();
+                transaction_retriever_mock
+                    .expect_get_by_block_ranges()
+                    .with(eq(block_ranges_to_prove.clone()))
+                    .return_once(move |_| Ok(all_transactions_in_certified_block_ranges));
+
+                let matching_transaction_hashes = matching_transaction_hashes.clone();
+                transaction_retriever_mock
+                    .expect_get_hashes_by_filter()
+                    .with(eq(filter.clone()), eq(certified_block_ranges.clone()))
+                    .return_once(move |_, _| Ok(matching_transaction_hashes));
+            },
+            |block_range_root_retriever_mock| {
+                let block_ranges_map = test_data.block_ranges_map.clone();
+                block_range_root_retriever_mock
+                    .expect_compute_merkle_map_from_block_range_roots()
+                    .return_once(|_| {
+                        Ok(test_data::compute_mk_map_from_block_ranges_map(
+                            block_ranges_map,
+                        ))
+                    });
+            },
+        );
+        prover.compute_cache(test_data.beacon).await.unwrap();
+
+        let transactions_set_proof = prover
+            .compute_transactions_proofs_by_filter(&filter, &block_ranges_to_prove)
+            .await
+            .unwrap()
+            .expect("A proof should have been computed for the certified block ranges");
+
+        assert_eq!(
+            transactions_set_proof.transactions_hashes(),
+            matching_transaction_hashes
+        );
+        assert_eq!(
+            transactions_set_proof.block_ranges(),
+            certified_block_ranges
+        );
+        transactions_set_proof.verify().unwrap();
+        transactions_set_proof
+            .verify_block_ranges_completeness()
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn cant_compute_proof_by_filter_without_certified_block_range() {
+        let transactions = CardanoTransactionsBuilder::new()
+            .max_transactions_per_block(1)
+            .blocks_per_block_range(3)
+            .build_block_ranges(5);
+        let test_data = test_data::build_test_data(&[], &transactions);
+        let uncertified_block_range =
+            BlockRange::from_block_number(test_data.beacon + BlockRange::LENGTH);
+        let prover = build_prover(
+            |transaction_retriever_mock| {
+                transaction_retriever_mock
+                    .expect_get_by_block_ranges()
+                    .with(eq(vec![uncertified_block_range.clone()]))
+                    .return_once(move |_| Ok(vec![]));
+                transaction_retriever_mock
+                    .expect_get_hashes_by_filter()
+                    .never();
+            },
+            |block_range_root_retriever_mock| {
+                let block_ranges_map = test_data.block_ranges_map.clone();
+                block_range_root_retriever_mock
+                    .expect_compute_merkle_map_from_block_range_roots()
+                    .return_once(|_| {
+                        Ok(test_data::compute_mk_map_from_block_ranges_map(
+                            block_ranges_map,
+                        ))
+                    });
+            },
+        );
+        prover.compute_cache(test_data.beacon).await.unwrap();
+
+        let transactions_set_proof = prover
+            .compute_transactions_proofs_by_filter(
+                &CardanoTransactionFilter::MetadataLabel(674),
+                &[uncertified_block_range],
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(None, transactions_set_proof);
+    }
+
     #[tokio::test]
     async fn cant_compute_proof_if_transaction_retriever_fails() {
         let transactions = CardanoTransactionsBuilder::new()
@@ -11,9 +11,10 @@ use tokio::task::JoinHandle;
 
 use mithril_common::{
     entities::{
-        BlockNumber, CardanoDatabaseSnapshot, CardanoDbBeacon, CardanoStakeDistribution,
-        CardanoTransactionsSnapshot, Certificate, Epoch, MithrilStakeDistribution,
-        SignedEntityType, SignedEntityTypeDiscriminants, Snapshot,
+        BlockNumber, CardanoBlocksSnapshot, CardanoDatabaseSnapshot, CardanoDbBeacon,
+        CardanoProtocolParameters, CardanoStakeDistribution, CardanoTransactionsSnapshot,
+        Certificate, Epoch, MithrilStakeDistribution, SignedEntityType,
+        SignedEntityTypeDiscriminants, Snapshot,
     },
     logging::LoggerExtensions,
     signable_builder::{Artifact, SignedEntity},
@@ -86,6 +87,18 @@ pub trait SignedEntityService: Send + Sync {
         &self,
         total: usize,
     ) -> StdResult<Vec<SignedEntity<CardanoStakeDistribution>>>;
+
+    /// Return the last signed Cardano Blocks Snapshot.
+    async fn get_last_cardano_blocks_snapshot(
+        &self,
+    ) -> StdResult<Option<SignedEntity<CardanoBlocksSnapshot>>>;
+
+    /// Return a list of signed Cardano protocol parameters ordered by creation
+    /// date descending.
+    async fn get_last_signed_cardano_protocol_parameters(
+        &self,
+        total: usize,
+    ) -> StdResult<Vec<SignedEntity<CardanoProtocolParameters>>>;
 }
 
 /// Mithril ArtifactBuilder Service
@@ -103,6 +116,9 @@ pub struct MithrilSignedEntityService {
         Arc<dyn ArtifactBuilder<Epoch, CardanoStakeDistribution>>,
     cardano_database_artifact_builder:
         Arc<dyn ArtifactBuilder<CardanoDbBeacon, CardanoDatabaseSnapshot>>,
+    cardano_blocks_artifact_builder: Arc<dyn ArtifactBuilder<BlockNumber, CardanoBlocksSnapshot>>,
+    cardano_protocol_parameters_artifact_builder:
+        Arc<dyn ArtifactBuilder<Epoch, CardanoProtocolParameters>>,
     metrics_service: Arc<MetricsService>,
     logger: Logger,
 }
@@ -119,6 +135,9 @@ pub struct SignedEntityServiceArtifactsDependencies {
         Arc<dyn ArtifactBuilder<Epoch, CardanoStakeDistribution>>,
     cardano_database_artifact_builder:
         Arc<dyn ArtifactBuilder<CardanoDbBeacon, CardanoDatabaseSnapshot>>,
+    cardano_blocks_artifact_builder: Arc<dyn ArtifactBuilder<BlockNumber, CardanoBlocksSnapshot>>,
+    cardano_protocol_parameters_artifact_builder:
+        Arc<dyn ArtifactBuilder<Epoch, CardanoProtocolParameters>>,
 }
 
 impl SignedEntityServiceArtifactsDependencies {
@@ -139,6 +158,12 @@ impl SignedEntityServiceArtifactsDependencies {
         cardano_database_artifact_builder: Arc<
             dyn ArtifactBuilder<CardanoDbBeacon, CardanoDatabaseSnapshot>,
         >,
+        cardano_blocks_artifact_builder: Arc<
+            dyn ArtifactBuilder<BlockNumber, CardanoBlocksSnapshot>,
+        >,
+        cardano_protocol_parameters_artifact_builder: Arc<
+            dyn ArtifactBuilder<Epoch, CardanoProtocolParameters>,
+        >,
     ) -> Self {
         Self {
             mithril_stake_distribution_artifact_builder,
@@ -146,6 +171,8 @@ impl SignedEntityServiceArtifactsDependencies {
             cardano_transactions_artifact_builder,
             cardano_stake_distribution_artifact_builder,
             cardano_database_artifact_builder,
+            cardano_blocks_artifact_builder,
+            cardano_protocol_parameters_artifact_builder,
         }
     }
 }
@@ -170,6 +197,9 @@ impl MithrilSignedEntityService {
             cardano_stake_distribution_artifact_builder: dependencies
                 .cardano_stake_distribution_artifact_builder,
             cardano_database_artifact_builder: dependencies.cardano_database_artifact_builder,
+            cardano_blocks_artifact_builder: dependencies.cardano_blocks_artifact_builder,
+            cardano_protocol_parameters_artifact_builder: dependencies
+                .cardano_protocol_parameters_artifact_builder,
             signed_entity_type_lock,
             metrics_service,
             logger: logger.new_with_component_name::<Self>(),
@@ -278,6 +308,26 @@ impl MithrilSignedEntityService {
                         )
                     })?
             )),
+            SignedEntityType::CardanoBlocks(_epoch, block_number) => Ok(Arc::new(
+                self.cardano_blocks_artifact_builder
+                    .compute_artifact(block_number, certificate)
+                    .await
+                    .with_context(|| {
+                        format!(
+                            "Signed Entity Service can not compute artifact for entity type: '{signed_entity_type}'"
+                        )
+                    })?,
+            )),
+            SignedEntityType::CardanoProtocolParameters(epoch) => Ok(Arc::new(
+                self.cardano_protocol_parameters_artifact_builder
+                    .compute_artifact(epoch, certificate)
+                    .await
+                    .with_context(|| {
+                        format!(
+                            "Signed Entity Service can not compute artifact for entity type: '{signed_entity_type}'"
+                        )
+                    })?,
+            )),
         }
     }
 
@@ -318,6 +368,12 @@ impl MithrilSignedEntityService {
             SignedEntityType::CardanoDatabase(_) => {
                 metrics.get_artifact_cardano_database_total_produced_since_startup()
             }
+            SignedEntityType::CardanoBlocks(_, _) => {
+                metrics.get_artifact_cardano_blocks_total_produced_since_startup()
+            }
+            SignedEntityType::CardanoProtocolParameters(_) => {
+                metrics.get_artifact_cardano_protocol_parameters_total_produced_since_startup()
+            }
         };
 
         metric_counter.increment();
@@ -490,6 +546,19 @@ impl SignedEntityService for MithrilSignedEntityService {
         }
     }
 
+    async fn get_last_cardano_blocks_snapshot(
+        &self,
+    ) -> StdResult<Option<SignedEntity<CardanoBlocksSnapshot>>> {
+        let mut signed_entities_records = self
+            .get_last_signed_entities(1, &SignedEntityTypeDiscriminants::CardanoBlocks)
+            .await?;
+
+        match signed_entities_records.pop() {
+            Some(record) => Ok(Some(record.try_into()?)),
+            None => Ok(None),
+        }
+    }
+
     async fn get_last_signed_cardano_stake_distributions(
         &self,
         total: usize,
@@ -506,6 +575,23 @@ impl SignedEntityService for MithrilSignedEntityService {
 
         Ok(signed_entities)
     }
+
+    async fn get_last_signed_cardano_protocol_parameters(
+        &self,
+        total: usize,
+    ) -> StdResult<Vec<SignedEntity<CardanoProtocolParameters>>> {
+        let signed_entities = self
+            .get_last_signed_entities(
+                total,
+                &SignedEntityTypeDiscriminants::CardanoProtocolParameters,
+            )
+            .await?
+            .into_iter()
+            .map(|record| record.try_into())
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(signed_entities)
+    }
 }
 
 #[cfg(test)]
@@ -513,7 +599,9 @@ mod tests {
     use std::{sync::atomic::Ordering, time::Duration};
 
     use mithril_common::{
-        entities::{CardanoTransactionsSnapshot, Epoch, StakeDistribution},
+        entities::{
+            CardanoTransactionsSnapshot, Epoch, LedgerProtocolParameters, StakeDistribution,
+        },
         signable_builder,
         test_utils::fake_data,
     };
@@ -566,6 +654,10 @@ mod tests {
             MockArtifactBuilder<Epoch, CardanoStakeDistribution>,
         mock_cardano_database_artifact_builder:
             MockArtifactBuilder<CardanoDbBeacon, CardanoDatabaseSnapshot>,
+        mock_cardano_blocks_artifact_builder:
+            MockArtifactBuilder<BlockNumber, CardanoBlocksSnapshot>,
+        mock_cardano_protocol_parameters_artifact_builder:
+            MockArtifactBuilder<Epoch, CardanoProtocolParameters>,
     }
 
     impl MockDependencyInjector {
@@ -592,6 +684,14 @@ mod tests {
                     CardanoDbBeacon,
                     CardanoDatabaseSnapshot,
                 >::new(),
+                mock_cardano_blocks_artifact_builder: MockArtifactBuilder::<
+                    BlockNumber,
+                    CardanoBlocksSnapshot,
+                >::new(),
+                mock_cardano_protocol_parameters_artifact_builder: MockArtifactBuilder::<
+                    Epoch,
+                    CardanoProtocolParameters,
+                >::new(),
             }
         }
 
@@ -602,6 +702,8 @@ mod tests {
                 Arc::new(self.mock_cardano_transactions_artifact_builder),
                 Arc::new(self.mock_cardano_stake_distribution_artifact_builder),
                 Arc::new(self.mock_cardano_database_artifact_builder),
+                Arc::new(self.mock_cardano_blocks_artifact_builder),
+                Arc::new(self.mock_cardano_protocol_parameters_artifact_builder),
             );
             MithrilSignedEntityService::new(
                 Arc::new(self.mock_signed_entity_storer),
@@ -659,6 +761,8 @@ mod tests {
                 Arc::new(self.mock_cardano_transactions_artifact_builder),
                 Arc::new(self.mock_cardano_stake_distribution_artifact_builder),
                 Arc::new(self.mock_cardano_database_artifact_builder),
+                Arc::new(self.mock_cardano_blocks_artifact_builder),
+                Arc::new(self.mock_cardano_protocol_parameters_artifact_builder),
             );
             MithrilSignedEntityService::new(
                 Arc::new(self.mock_signed_entity_storer),
@@ -723,6 +827,12 @@ mod tests {
             SignedEntityType::CardanoDatabase(_) => metrics_service
                 .get_artifact_cardano_database_total_produced_since_startup()
                 .get(),
+            SignedEntityType::CardanoBlocks(_, _) => metrics_service
+                .get_artifact_cardano_blocks_total_produced_since_startup()
+                .get(),
+            SignedEntityType::CardanoProtocolParameters(_) => metrics_service
+                .get_artifact_cardano_protocol_parameters_total_produced_since_startup()
+                .get(),
         }
     }
 
@@ -883,6 +993,27 @@ mod tests {
         .await;
     }
 
+    #[tokio::test]
+    async fn should_store_the_artifact_when_creating_artifact_for_cardano_blocks() {
+        let block_number = BlockNumber(149);
+        generic_test_that_the_artifact_is_stored(
+            SignedEntityType::CardanoBlocks(Epoch(1), block_number),
+            CardanoBlocksSnapshot::new("merkle_root".to_string(), block_number),
+            &|mock_injector| &mut mock_injector.mock_cardano_blocks_artifact_builder,
+        )
+        .await;
+    }
+
+    #[tokio::test]
+    async fn should_store_the_artifact_when_creating_artifact_for_cardano_protocol_parameters() {
+        generic_test_that_the_artifact_is_stored(
+            SignedEntityType::CardanoProtocolParameters(Epoch(1)),
+            CardanoProtocolParameters::new(Epoch(1), LedgerProtocolParameters::dummy()),
+            &|mock_injector| &mut mock_injector.mock_cardano_protocol_parameters_artifact_builder,
+        )
+        .await;
+    }
+
     #[tokio::test]
     async fn build_cardano_database_artifact_when_given_cardano_database_entity_type() {
         let mut mock_container = MockDependencyInjector::new();
@@ -263,7 +263,13 @@ impl CompressedArchiveSnapshotter {
             "No ledger file found in directory: `{}`",
             db_ledger_dir.display()
         ))?;
-        files_to_snapshot.push(PathBuf::from(LEDGER_DIR).join(&last_ledger.filename));
+        // UTxO-HD ledger snapshots are directories, all their files are included
+        files_to_snapshot.extend(
+            last_ledger
+                .list_files_relative_paths()
+                .into_iter()
+                .map(|path| PathBuf::from(LEDGER_DIR).join(path)),
+        );
 
         fs::create_dir(target_folder.join(IMMUTABLE_DIR))
             .with_context(|| format!("Can not create folder: `{}`", target_folder.display()))?;
@@ -273,6 +279,11 @@ impl CompressedArchiveSnapshotter {
         for file in &files_to_snapshot {
             let source = self.db_directory.join(file);
             let target = target_folder.join(file);
+            if let Some(target_parent) = target.parent().filter(|parent| !parent.exists()) {
+                fs::create_dir_all(target_parent).with_context(|| {
+                    format!("Can not create folder: `{}`", target_parent.display())
+                })?;
+            }
             tokio::fs::copy(&source, &target).await.with_context(|| {
                 format!(
                     "Failed to copy file `{}` to `{}`",
@@ -311,7 +322,7 @@ mod tests {
     use std::path::Path;
     use std::sync::Arc;
 
-    use mithril_common::digesters::DummyCardanoDbBuilder;
+    use mithril_common::digesters::{DummyCardanoDbBuilder, LedgerStateSnapshotLayout};
     use mithril_common::test_utils::assert_equivalent;
     use mithril_common::{assert_dir_eq, current_function, temp_dir_create};
 
@@ -715,6 +726,54 @@ mod tests {
                 manifest.signature
             )
         }
+
+        #[tokio::test]
+        async fn create_archive_should_embed_all_files_of_the_last_utxo_hd_ledger_snapshot() {
+            let test_dir = temp_dir_create!();
+            let cardano_db = DummyCardanoDbBuilder::new(current_function!())
+                .with_immutables(&[1, 2, 3])
+                .with_ledger_files(&["437"])
+                .with_utxo_hd_ledger_snapshots(&["537", "637"], LedgerStateSnapshotLayout::Lmdb)
+                .build();
+
+            let snapshotter = CompressedArchiveSnapshotter {
+                ancillary_signer: Arc::new(MockAncillarySigner::that_succeeds_with_signature(
+                    fake_keys::signable_manifest_signature()[0],
+                )),
+                ..snapshotter_for_test(&test_dir, cardano_db.get_dir(), CompressionAlgorithm::Gzip)
+            };
+
+            let archive = snapshotter
+                .snapshot_ancillary(2, "ancillary")
+                .await
+                .unwrap();
+            let unpacked = archive.unpack_gzip(test_dir);
+            let manifest = serde_json::from_reader::<_, AncillaryFilesManifest>(
+                File::open(unpacked.join(AncillaryFilesManifest::ANCILLARY_MANIFEST_FILE_NAME))
+                    .unwrap(),
+            )
+            .unwrap();
+
+            assert_eq!(
+                vec![
+                    &PathBuf::from(IMMUTABLE_DIR).join("00003.chunk"),
+                    &PathBuf::from(IMMUTABLE_DIR).join("00003.primary"),
+                    &PathBuf::from(IMMUTABLE_DIR).join("00003.secondary"),
+                    &PathBuf::from(LEDGER_DIR).join("637/meta"),
+                    &PathBuf::from(LEDGER_DIR).join("637/state"),
+                    &PathBuf::from(LEDGER_DIR).join("637/tables/data.mdb"),
+                    &PathBuf::from(LEDGER_DIR).join("637/tables/lock.mdb"),
+                ],
+                manifest.data.keys().collect::<Vec<_>>()
+            );
+            for file in manifest.data.keys() {
+                assert!(
+                    unpacked.join(file).is_file(),
+                    "Expected file to be included in the archive: {}",
+                    file.display()
+                );
+            }
+        }
     }
 
     mod compute_immutable_total_and_average_uncompressed_size {
@@ -302,6 +302,12 @@ mod test {
                             beacon.epoch, beacon.immutable_file_number
                         )
                     }
+                    SignedEntityType::CardanoBlocks(epoch, block_number) => {
+                        format!("cardano-blocks-{epoch}-{block_number}",)
+                    }
+                    SignedEntityType::CardanoProtocolParameters(epoch) => {
+                        format!("cardano-protocol-parameters-{epoch}")
+                    }
                 };
 
                 let signed_entity_record = SignedEntityRecord {
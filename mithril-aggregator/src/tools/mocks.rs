@@ -2,7 +2,7 @@ use async_trait::async_trait;
 
 use mithril_common::chain_observer::{ChainAddress, ChainObserver, ChainObserverError, TxDatum};
 use mithril_common::crypto_helper::{KESPeriod, OpCert};
-use mithril_common::entities::{ChainPoint, Epoch, StakeDistribution};
+use mithril_common::entities::{ChainPoint, Epoch, LedgerProtocolParameters, StakeDistribution};
 use mithril_persistence::store::StakeStorer;
 
 use mithril_common::StdResult;
@@ -28,6 +28,10 @@ mock! {
             &self,
         ) -> Result<Option<StakeDistribution>, ChainObserverError>;
 
+        async fn get_current_protocol_parameters(
+            &self,
+        ) -> Result<Option<LedgerProtocolParameters>, ChainObserverError>;
+
         async fn get_current_kes_period(
             &self,
             opcert: &OpCert,
@@ -162,6 +162,20 @@ impl AggregatorObserver {
                     .await?
                     .first()
                     .map(|s| &s.signed_entity_type)),
+            SignedEntityType::CardanoBlocks(_, _) => Ok(Some(signed_entity_type_expected)
+                == self
+                    .signed_entity_service
+                    .get_last_cardano_blocks_snapshot()
+                    .await?
+                    .map(|s| s.signed_entity_type)
+                    .as_ref()),
+            SignedEntityType::CardanoProtocolParameters(_) => Ok(Some(signed_entity_type_expected)
+                == self
+                    .signed_entity_service
+                    .get_last_signed_cardano_protocol_parameters(1)
+                    .await?
+                    .first()
+                    .map(|s| &s.signed_entity_type)),
         }
     }
 }
@@ -6,11 +6,13 @@ use std::collections::HashMap;
 use mithril_client::MithrilResult;
 
 use crate::configuration::ConfigParameters;
+use crate::network_profile::NetworkProfile;
 
 /// Context for the command execution
 pub struct CommandContext {
     config_builder: ConfigBuilder<DefaultState>,
     unstable_enabled: bool,
+    network_profile: Option<NetworkProfile>,
     logger: Logger,
 }
 
@@ -24,10 +26,17 @@ impl CommandContext {
         Self {
             config_builder,
             unstable_enabled,
+            network_profile: None,
             logger,
         }
     }
 
+    /// Set the network profile providing the verification keys
+    pub fn with_network_profile(mut self, network_profile: Option<NetworkProfile>) -> Self {
+        self.network_profile = network_profile;
+        self
+    }
+
     /// Check if unstable commands are enabled
     pub fn is_unstable_enabled(&self) -> bool {
         self.unstable_enabled
@@ -40,6 +49,18 @@ impl CommandContext {
         Ok(ConfigParameters::new(config_hash_map))
     }
 
+    /// Fill the verification keys missing from the given parameters with the ones of the
+    /// network profile, if any.
+    ///
+    /// The keys are fetched only when needed, so commands that do not verify anything or that
+    /// are given their keys never fetch them.
+    pub async fn fill_verification_keys(&self, parameters: ConfigParameters) -> ConfigParameters {
+        match &self.network_profile {
+            Some(profile) => profile.fill_missing_keys(parameters, &self.logger).await,
+            None => parameters,
+        }
+    }
+
     /// Get the shared logger
     pub fn logger(&self) -> &Logger {
         &self.logger
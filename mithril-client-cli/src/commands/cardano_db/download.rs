@@ -10,7 +10,10 @@ use std::{
 };
 
 use crate::{
-    commands::{client_builder, SharedArgs},
+    commands::{
+        ancillary_verification_key, client_builder, DownloadLimitsArgs, LedgerStateConversionArgs,
+        SharedArgs,
+    },
     configuration::{ConfigError, ConfigSource},
     utils::{
         self, CardanoDbDownloadChecker, CardanoDbUtils, ExpanderUtils, IndicatifFeedbackReceiver,
@@ -46,17 +49,23 @@ pub struct CardanoDbDownloadCommand {
     genesis_verification_key: Option<String>,
 
     /// Include ancillary files in the download, if set the `ancillary_verification_key` is required
-    /// in order to verify the ancillary files.
+    /// in order to verify the ancillary files, it can also be provided by a network profile.
     ///
     /// By default, only finalized immutable files are downloaded.
     /// The last ledger state snapshot and the last immutable file (the ancillary files) can be
     /// downloaded with this option.
-    #[clap(long, requires = "ancillary_verification_key")]
+    #[clap(long)]
     include_ancillary: bool,
 
     /// Ancillary verification key to verify the ancillary files.
     #[clap(long, env = "ANCILLARY_VERIFICATION_KEY")]
     ancillary_verification_key: Option<String>,
+
+    #[clap(flatten)]
+    download_limits: DownloadLimitsArgs,
+
+    #[clap(flatten)]
+    ledger_state_conversion: LedgerStateConversionArgs,
 }
 
 impl CardanoDbDownloadCommand {
@@ -68,6 +77,7 @@ impl CardanoDbDownloadCommand {
     /// Command execution
     pub async fn execute(&self, context: CommandContext) -> MithrilResult<()> {
         let params = context.config_parameters()?.add_source(self)?;
+        let params = context.fill_verification_keys(params).await;
         let download_dir: &String = &params.require("download_dir")?;
         let db_dir = Path::new(download_dir).join("db");
         let logger = context.logger();
@@ -78,12 +88,17 @@ impl CardanoDbDownloadCommand {
             ProgressOutputType::Tty
         };
         let progress_printer = ProgressPrinter::new(progress_output_type, 5);
-        let client = client_builder(&params)?
+        let client = self
+            .download_limits
+            .apply_to(client_builder(&params)?)
             .add_feedback_receiver(Arc::new(IndicatifFeedbackReceiver::new(
                 progress_output_type,
                 logger.clone(),
             )))
-            .set_ancillary_verification_key(self.ancillary_verification_key.clone())
+            .set_ancillary_verification_key(ancillary_verification_key(
+                &params,
+                self.include_ancillary,
+            )?)
             .with_logger(logger.clone())
             .build()?;
 
@@ -134,6 +149,9 @@ impl CardanoDbDownloadCommand {
             )
         })?;
 
+        self.ledger_state_conversion
+            .convert_last_snapshot(&db_dir, logger)?;
+
         let message =
             Self::compute_cardano_db_message(4, &progress_printer, &certificate, &db_dir).await?;
 
@@ -356,6 +374,13 @@ impl ConfigSource for CardanoDbDownloadCommand {
             );
         }
 
+        if let Some(ancillary_verification_key) = self.ancillary_verification_key.clone() {
+            map.insert(
+                "ancillary_verification_key".to_string(),
+                ancillary_verification_key,
+            );
+        }
+
         Ok(map)
     }
 }
@@ -396,16 +421,6 @@ mod tests {
         }
     }
 
-    #[test]
-    fn ancillary_verification_key_is_mandatory_when_include_ancillary_is_true() {
-        CardanoDbDownloadCommand::try_parse_from([
-            "cdbv1-command",
-            "--include-ancillary",
-            "whatever_digest",
-        ])
-        .expect_err("The command should fail because ancillary_verification_key is not set");
-    }
-
     #[tokio::test]
     async fn verify_cardano_db_signature_should_remove_db_dir_if_messages_mismatch() {
         let progress_printer = ProgressPrinter::new(ProgressOutputType::Tty, 1);
@@ -17,7 +17,10 @@ use mithril_client::{
 };
 
 use crate::{
-    commands::{client_builder, SharedArgs},
+    commands::{
+        ancillary_verification_key, client_builder, DownloadLimitsArgs, LedgerStateConversionArgs,
+        SharedArgs,
+    },
     configuration::{ConfigError, ConfigSource},
     utils::{
         self, CardanoDbDownloadChecker, CardanoDbUtils, ExpanderUtils, IndicatifFeedbackReceiver,
@@ -70,12 +73,12 @@ pub struct CardanoDbV2DownloadCommand {
     end: Option<ImmutableFileNumber>,
 
     /// Include ancillary files in the download, if set the `ancillary_verification_key` is required
-    /// in order to verify the ancillary files.
+    /// in order to verify the ancillary files, it can also be provided by a network profile.
     ///
     /// By default, only finalized immutable files are downloaded.
     /// The last ledger state snapshot and the last immutable file (the ancillary files) can be
     /// downloaded with this option.
-    #[clap(long, requires = "ancillary_verification_key")]
+    #[clap(long)]
     include_ancillary: bool,
 
     /// Ancillary verification key to verify the ancillary files.
@@ -85,6 +88,23 @@ pub struct CardanoDbV2DownloadCommand {
     /// Allow existing files in the download directory to be overridden.
     #[clap(long)]
     allow_override: bool,
+
+    #[clap(flatten)]
+    download_limits: DownloadLimitsArgs,
+
+    /// Maximum number of files downloaded in parallel.
+    #[clap(long, default_value_t = DownloadUnpackOptions::default().max_parallel_downloads)]
+    max_parallel_downloads: usize,
+
+    /// Adapt the number of parallel downloads to the network conditions.
+    ///
+    /// The number of parallel downloads is halved each time a download fails or times out,
+    /// then slowly increased back up to `max_parallel_downloads`.
+    #[clap(long)]
+    adaptive_concurrency: bool,
+
+    #[clap(flatten)]
+    ledger_state_conversion: LedgerStateConversionArgs,
 }
 
 impl CardanoDbV2DownloadCommand {
@@ -108,6 +128,7 @@ impl CardanoDbV2DownloadCommand {
     /// Command execution
     pub async fn execute(&self, context: CommandContext) -> MithrilResult<()> {
         let params = context.config_parameters()?.add_source(self)?;
+        let params = context.fill_verification_keys(params).await;
         let download_dir: &String = &params.require("download_dir")?;
         let restoration_options = RestorationOptions {
             db_dir: Path::new(download_dir).join("db_v2"),
@@ -115,7 +136,8 @@ impl CardanoDbV2DownloadCommand {
             download_unpack_options: DownloadUnpackOptions {
                 allow_override: self.allow_override,
                 include_ancillary: self.include_ancillary,
-                ..DownloadUnpackOptions::default()
+                max_parallel_downloads: self.max_parallel_downloads,
+                adaptive_concurrency: self.adaptive_concurrency,
             },
             disk_space_safety_margin_ratio: DISK_SPACE_SAFETY_MARGIN_RATIO,
         };
@@ -127,12 +149,17 @@ impl CardanoDbV2DownloadCommand {
             ProgressOutputType::Tty
         };
         let progress_printer = ProgressPrinter::new(progress_output_type, 6);
-        let client = client_builder(&params)?
+        let client = self
+            .download_limits
+            .apply_to(client_builder(&params)?)
             .add_feedback_receiver(Arc::new(IndicatifFeedbackReceiver::new(
                 progress_output_type,
                 logger.clone(),
             )))
-            .set_ancillary_verification_key(self.ancillary_verification_key.clone())
+            .set_ancillary_verification_key(ancillary_verification_key(
+                &params,
+                self.include_ancillary,
+            )?)
             .with_logger(logger.clone())
             .build()?;
 
@@ -189,6 +216,9 @@ impl CardanoDbV2DownloadCommand {
             )
         })?;
 
+        self.ledger_state_conversion
+            .convert_last_snapshot(&restoration_options.db_dir, logger)?;
+
         let merkle_proof = Self::compute_verify_merkle_proof(
             4,
             &progress_printer,
@@ -507,6 +537,13 @@ impl ConfigSource for CardanoDbV2DownloadCommand {
             );
         }
 
+        if let Some(ancillary_verification_key) = self.ancillary_verification_key.clone() {
+            map.insert(
+                "ancillary_verification_key".to_string(),
+                ancillary_verification_key,
+            );
+        }
+
         Ok(map)
     }
 }
@@ -522,6 +559,8 @@ mod tests {
     };
     use mithril_common::test_utils::TempDir;
 
+    use crate::commands::LedgerStateSnapshotTargetLayout;
+
     use super::*;
 
     fn dummy_certificate() -> MithrilCertificate {
@@ -551,13 +590,77 @@ mod tests {
     }
 
     #[test]
-    fn ancillary_verification_key_is_mandatory_when_include_ancillary_is_true() {
-        CardanoDbV2DownloadCommand::try_parse_from([
+    fn parse_download_limits_and_concurrency_arguments() {
+        let command = CardanoDbV2DownloadCommand::try_parse_from([
+            "cdbv2-command",
+            "--max-download-bandwidth",
+            "1048576",
+            "--download-timeout",
+            "60",
+            "--max-parallel-downloads",
+            "8",
+            "--adaptive-concurrency",
+            "whatever_hash",
+        ])
+        .unwrap();
+
+        assert_eq!(
+            Some(1048576),
+            command.download_limits.max_download_bandwidth
+        );
+        assert_eq!(Some(60), command.download_limits.download_timeout);
+        assert_eq!(8, command.max_parallel_downloads);
+        assert!(command.adaptive_concurrency);
+    }
+
+    #[test]
+    fn parse_ledger_state_conversion_arguments() {
+        let command = CardanoDbV2DownloadCommand::try_parse_from([
             "cdbv2-command",
             "--include-ancillary",
+            "--convert-ledger-state-snapshot-to",
+            "lmdb",
+            "--snapshot-converter-bin",
+            "/path/to/snapshot-converter",
+            "--cardano-node-config",
+            "/path/to/config.json",
+            "whatever_hash",
+        ])
+        .unwrap();
+
+        assert_eq!(
+            Some(LedgerStateSnapshotTargetLayout::Lmdb),
+            command
+                .ledger_state_conversion
+                .convert_ledger_state_snapshot_to
+        );
+    }
+
+    #[test]
+    fn ledger_state_conversion_requires_the_ancillary_files() {
+        CardanoDbV2DownloadCommand::try_parse_from([
+            "cdbv2-command",
+            "--convert-ledger-state-snapshot-to",
+            "lmdb",
+            "--snapshot-converter-bin",
+            "/path/to/snapshot-converter",
+            "--cardano-node-config",
+            "/path/to/config.json",
             "whatever_hash",
         ])
-        .expect_err("The command should fail because ancillary_verification_key is not set");
+        .expect_err("The conversion should require the ancillary files to be included");
+    }
+
+    #[test]
+    fn max_parallel_downloads_defaults_to_download_unpack_options_default() {
+        let command =
+            CardanoDbV2DownloadCommand::try_parse_from(["cdbv2-command", "whatever_hash"]).unwrap();
+
+        assert_eq!(
+            DownloadUnpackOptions::default().max_parallel_downloads,
+            command.max_parallel_downloads
+        );
+        assert!(!command.adaptive_concurrency);
     }
 
     #[tokio::test]
@@ -47,6 +47,7 @@ impl CardanoStakeDistributionDownloadCommand {
     /// Main command execution
     pub async fn execute(&self, context: CommandContext) -> MithrilResult<()> {
         let params = context.config_parameters()?.add_source(self)?;
+        let params = context.fill_verification_keys(params).await;
         let download_dir = params.get_or("download_dir", ".");
         let download_dir = Path::new(&download_dir);
         let logger = context.logger();
@@ -40,6 +40,7 @@ impl CardanoTransactionsCertifyCommand {
     /// Cardano transaction certify command
     pub async fn execute(&self, context: CommandContext) -> MithrilResult<()> {
         let params = context.config_parameters()?.add_source(self)?;
+        let params = context.fill_verification_keys(params).await;
         let logger = context.logger();
 
         let progress_output_type = if self.is_json_output_enabled() {
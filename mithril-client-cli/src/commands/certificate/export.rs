@@ -0,0 +1,193 @@
+use anyhow::{anyhow, Context};
+use clap::Parser;
+use std::path::PathBuf;
+
+use mithril_client::common::{ProtocolMessage, SignedEntityType};
+use mithril_client::{Client, MessageBuilder, MithrilCertificate, MithrilResult};
+
+use crate::{
+    commands::{client_builder_with_fallback_genesis_key, SharedArgs},
+    utils::{ExpanderUtils, ProgressOutputType, ProgressPrinter},
+    CommandContext,
+};
+
+/// Clap command to export the certificate chain of a given certificate to a bundle file.
+///
+/// The bundle holds all the certificates from the given certificate back to the genesis
+/// certificate and the message computed from the certified artifact, it can be verified offline
+/// with the `certificate verify-chain --from-file` command and the genesis verification key.
+///
+/// Only the certificates of Mithril stake distributions, Cardano stake distributions and Cardano
+/// protocol parameters can be exported since their artifact message can be computed without
+/// downloading the artifact data.
+#[derive(Parser, Debug, Clone)]
+pub struct CertificateExportCommand {
+    #[clap(flatten)]
+    shared_args: SharedArgs,
+
+    /// Hash of the certificate to export the chain of, or `latest` for the latest certificate.
+    certificate_hash: String,
+
+    /// Path of the file where the certificate chain bundle will be written.
+    ///
+    /// By default, the chain is written to `certificate-chain-<CERTIFICATE_HASH>.json` in the
+    /// current directory.
+    #[clap(long)]
+    output: Option<PathBuf>,
+}
+
+impl CertificateExportCommand {
+    /// Is JSON output enabled
+    pub fn is_json_output_enabled(&self) -> bool {
+        self.shared_args.json
+    }
+
+    /// Main command execution
+    pub async fn execute(&self, context: CommandContext) -> MithrilResult<()> {
+        let params = context.config_parameters()?;
+        let progress_output_type = if self.is_json_output_enabled() {
+            ProgressOutputType::JsonReporter
+        } else {
+            ProgressOutputType::Tty
+        };
+        let progress_printer = ProgressPrinter::new(progress_output_type, 3);
+        let client = client_builder_with_fallback_genesis_key(&params)?
+            .with_logger(context.logger().clone())
+            .build()?;
+
+        let get_list_of_certificate_hashes = || async {
+            let certificates = client.certificate().list().await.with_context(|| {
+                "Can not get the list of certificates while retrieving the latest certificate hash"
+            })?;
+
+            Ok(certificates
+                .iter()
+                .map(|certificate| certificate.hash.to_owned())
+                .collect::<Vec<String>>())
+        };
+        let certificate_hash = ExpanderUtils::expand_eventual_id_alias(
+            &self.certificate_hash,
+            get_list_of_certificate_hashes(),
+        )
+        .await?;
+
+        progress_printer.report_step(
+            1,
+            "Computing the message of the artifact certified by the certificate…",
+        )?;
+        let certificate = client
+            .certificate()
+            .get(&certificate_hash)
+            .await?
+            .ok_or_else(|| anyhow!("No certificate exists for hash '{certificate_hash}'"))?;
+        let artifact_message = Self::compute_artifact_message(&client, &certificate)
+            .await
+            .with_context(|| {
+                format!("Can not compute the artifact message of certificate '{certificate_hash}'")
+            })?;
+
+        progress_printer.report_step(
+            2,
+            "Fetching the certificate chain back to the genesis certificate…",
+        )?;
+        let certificate_chain = client
+            .certificate()
+            .fetch_chain_bundle(&certificate_hash, artifact_message, None)
+            .await
+            .with_context(|| {
+                format!("Can not fetch the certificate chain of certificate '{certificate_hash}'")
+            })?;
+
+        progress_printer.report_step(3, "Writing the certificate chain bundle to a file…")?;
+        let filepath = self
+            .output
+            .clone()
+            .unwrap_or_else(|| PathBuf::from(format!("certificate-chain-{certificate_hash}.json")));
+        certificate_chain.write_to_file(&filepath)?;
+
+        if self.is_json_output_enabled() {
+            println!(
+                r#"{{"certificate_hash": "{}", "total_certificates": {}, "filepath": "{}"}}"#,
+                certificate_hash,
+                certificate_chain.certificates.len(),
+                filepath.display()
+            );
+        } else {
+            println!(
+                "Certificate chain of certificate '{}' ({} certificates) has been exported to '{}'.",
+                certificate_hash,
+                certificate_chain.certificates.len(),
+                filepath.display()
+            );
+        }
+
+        Ok(())
+    }
+
+    async fn compute_artifact_message(
+        client: &Client,
+        certificate: &MithrilCertificate,
+    ) -> MithrilResult<ProtocolMessage> {
+        let message_builder = MessageBuilder::new();
+
+        match &certificate.signed_entity_type {
+            SignedEntityType::MithrilStakeDistribution(_) => {
+                let list_item = client
+                    .mithril_stake_distribution()
+                    .list()
+                    .await?
+                    .into_iter()
+                    .find(|msd| msd.certificate_hash == certificate.hash)
+                    .ok_or_else(|| {
+                        anyhow!("No Mithril stake distribution is certified by the certificate")
+                    })?;
+                let mithril_stake_distribution = client
+                    .mithril_stake_distribution()
+                    .get(&list_item.hash)
+                    .await?
+                    .ok_or_else(|| {
+                        anyhow!(
+                            "No Mithril stake distribution exists for hash '{}'",
+                            list_item.hash
+                        )
+                    })?;
+
+                message_builder.compute_mithril_stake_distribution_message(
+                    certificate,
+                    &mithril_stake_distribution,
+                )
+            }
+            SignedEntityType::CardanoStakeDistribution(epoch) => {
+                let cardano_stake_distribution = client
+                    .cardano_stake_distribution()
+                    .get_by_epoch(*epoch)
+                    .await?
+                    .ok_or_else(|| {
+                        anyhow!("No Cardano stake distribution exists for epoch '{epoch}'")
+                    })?;
+
+                message_builder.compute_cardano_stake_distribution_message(
+                    certificate,
+                    &cardano_stake_distribution,
+                )
+            }
+            SignedEntityType::CardanoProtocolParameters(epoch) => {
+                let cardano_protocol_parameters = client
+                    .cardano_protocol_parameters()
+                    .get_by_epoch(*epoch)
+                    .await?
+                    .ok_or_else(|| {
+                        anyhow!("No Cardano protocol parameters exist for epoch '{epoch}'")
+                    })?;
+
+                Ok(message_builder.compute_cardano_protocol_parameters_message(
+                    certificate,
+                    &cardano_protocol_parameters,
+                ))
+            }
+            signed_entity_type => Err(anyhow!(
+                "Exporting the certificate chain of a '{signed_entity_type}' is not supported"
+            )),
+        }
+    }
+}
@@ -0,0 +1,47 @@
+//! Commands for the Mithril certificates
+mod export;
+mod list;
+mod show;
+mod verify_chain;
+
+pub use export::*;
+pub use list::*;
+pub use show::*;
+pub use verify_chain::*;
+
+use crate::CommandContext;
+use clap::Subcommand;
+use mithril_client::MithrilResult;
+
+/// Certificate management (alias: cert)
+#[derive(Subcommand, Debug, Clone)]
+#[command(about = "Certificate management (alias: cert)")]
+pub enum CertificateCommands {
+    /// List the latest certificates
+    #[clap(arg_required_else_help = false)]
+    List(CertificateListCommand),
+
+    /// Show the given certificate
+    #[clap(arg_required_else_help = false)]
+    Show(CertificateShowCommand),
+
+    /// Verify the certificate chain of the given certificate, from an aggregator or from a file
+    #[clap(arg_required_else_help = false)]
+    VerifyChain(CertificateVerifyChainCommand),
+
+    /// Export the certificate chain of the given certificate to a file that can be verified offline
+    #[clap(arg_required_else_help = false)]
+    Export(CertificateExportCommand),
+}
+
+impl CertificateCommands {
+    /// Execute certificate command
+    pub async fn execute(&self, config_builder: CommandContext) -> MithrilResult<()> {
+        match self {
+            Self::List(cmd) => cmd.execute(config_builder).await,
+            Self::Show(cmd) => cmd.execute(config_builder).await,
+            Self::VerifyChain(cmd) => cmd.execute(config_builder).await,
+            Self::Export(cmd) => cmd.execute(config_builder).await,
+        }
+    }
+}
@@ -0,0 +1,122 @@
+use anyhow::{anyhow, Context};
+use clap::Parser;
+use cli_table::{print_stdout, Cell, Table};
+
+use crate::{
+    commands::{client_builder_with_fallback_genesis_key, SharedArgs},
+    utils::ExpanderUtils,
+    CommandContext,
+};
+use mithril_client::MithrilResult;
+
+/// Clap command to show a given certificate
+#[derive(Parser, Debug, Clone)]
+pub struct CertificateShowCommand {
+    #[clap(flatten)]
+    shared_args: SharedArgs,
+
+    /// Hash of the certificate to show or `latest` for the latest certificate
+    certificate_hash: String,
+}
+
+impl CertificateShowCommand {
+    /// Is JSON output enabled
+    pub fn is_json_output_enabled(&self) -> bool {
+        self.shared_args.json
+    }
+
+    /// Certificate Show command
+    pub async fn execute(&self, context: CommandContext) -> MithrilResult<()> {
+        let params = context.config_parameters()?;
+        let client = client_builder_with_fallback_genesis_key(&params)?
+            .with_logger(context.logger().clone())
+            .build()?;
+
+        let get_list_of_certificate_hashes = || async {
+            let certificates = client.certificate().list().await.with_context(|| {
+                "Can not get the list of certificates while retrieving the latest certificate hash"
+            })?;
+
+            Ok(certificates
+                .iter()
+                .map(|certificate| certificate.hash.to_owned())
+                .collect::<Vec<String>>())
+        };
+
+        let certificate = client
+            .certificate()
+            .get(
+                &ExpanderUtils::expand_eventual_id_alias(
+                    &self.certificate_hash,
+                    get_list_of_certificate_hashes(),
+                )
+                .await?,
+            )
+            .await?
+            .ok_or_else(|| {
+                anyhow!(
+                    "Certificate not found for hash: '{}'",
+                    &self.certificate_hash
+                )
+            })?;
+
+        if self.is_json_output_enabled() {
+            println!("{}", serde_json::to_string(&certificate)?);
+        } else {
+            let certificate_table = vec![
+                vec!["Hash".cell(), certificate.hash.clone().cell()],
+                vec![
+                    "Previous Hash".cell(),
+                    certificate.previous_hash.clone().cell(),
+                ],
+                vec!["Epoch".cell(), format!("{}", certificate.epoch).cell()],
+                vec![
+                    "Signed Entity Type".cell(),
+                    format!("{:?}", certificate.signed_entity_type).cell(),
+                ],
+                vec![
+                    "Network".cell(),
+                    certificate.metadata.network.clone().cell(),
+                ],
+                vec![
+                    "Protocol Version".cell(),
+                    certificate.metadata.protocol_version.clone().cell(),
+                ],
+                vec![
+                    "Protocol Parameters".cell(),
+                    format!("{:?}", certificate.metadata.protocol_parameters).cell(),
+                ],
+                vec![
+                    "Total Signers".cell(),
+                    certificate.metadata.signers.len().cell(),
+                ],
+                vec![
+                    "Initiated".cell(),
+                    certificate.metadata.initiated_at.to_string().cell(),
+                ],
+                vec![
+                    "Sealed".cell(),
+                    certificate.metadata.sealed_at.to_string().cell(),
+                ],
+                vec![
+                    "Signed Message".cell(),
+                    certificate.signed_message.clone().cell(),
+                ],
+                vec![
+                    "Kind".cell(),
+                    if certificate.genesis_signature.is_empty() {
+                        "Standard"
+                    } else {
+                        "Genesis"
+                    }
+                    .cell(),
+                ],
+            ]
+            .table();
+
+            print_stdout(certificate_table)?
+        }
+
+        Ok(())
+    }
+}
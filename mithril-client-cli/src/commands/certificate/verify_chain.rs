@@ -0,0 +1,216 @@
+use anyhow::{anyhow, Context};
+use clap::Parser;
+use std::{collections::HashMap, path::PathBuf, sync::Arc};
+
+use mithril_client::{
+    CertificateChainBundle, Client, ClientBuilder, MithrilResult,
+    CERTIFICATE_CHAIN_BUNDLE_FORMAT_VERSION,
+};
+
+use crate::{
+    commands::{client_builder, trusted_checkpoints, SharedArgs},
+    configuration::{ConfigError, ConfigParameters, ConfigSource},
+    utils::{
+        CertificateChainBundleAggregatorClient, ExpanderUtils, IndicatifFeedbackReceiver,
+        ProgressOutputType, ProgressPrinter,
+    },
+    CommandContext,
+};
+
+/// Clap command to verify the certificate chain of a given certificate
+#[derive(Parser, Debug, Clone)]
+pub struct CertificateVerifyChainCommand {
+    #[clap(flatten)]
+    shared_args: SharedArgs,
+
+    /// Hash of the certificate to verify the chain of, or `latest` for the latest certificate.
+    ///
+    /// When verifying from a file, defaults to the certificate the bundle chain starts from.
+    #[clap(required_unless_present = "from_file")]
+    certificate_hash: Option<String>,
+
+    /// Verify the certificate chain offline, from a certificate chain bundle file created with
+    /// the `certificate export` command, instead of fetching it from an aggregator.
+    #[clap(long)]
+    from_file: Option<PathBuf>,
+
+    /// Genesis verification key to check the certificate chain.
+    #[clap(long, env = "GENESIS_VERIFICATION_KEY")]
+    genesis_verification_key: Option<String>,
+}
+
+impl CertificateVerifyChainCommand {
+    /// Is JSON output enabled
+    pub fn is_json_output_enabled(&self) -> bool {
+        self.shared_args.json
+    }
+
+    /// Main command execution
+    pub async fn execute(&self, context: CommandContext) -> MithrilResult<()> {
+        let params = context.config_parameters()?.add_source(self)?;
+        let params = context.fill_verification_keys(params).await;
+        let logger = context.logger();
+
+        let progress_output_type = if self.is_json_output_enabled() {
+            ProgressOutputType::JsonReporter
+        } else {
+            ProgressOutputType::Tty
+        };
+        let progress_printer = ProgressPrinter::new(progress_output_type, 2);
+
+        let certificate_chain_bundle = match &self.from_file {
+            Some(path) => {
+                progress_printer.report_step(
+                    1,
+                    &format!(
+                        "Reading the certificate chain bundle file '{}'…",
+                        path.display()
+                    ),
+                )?;
+                Some(CertificateChainBundle::read_from_file(path)?)
+            }
+            None => {
+                progress_printer.report_step(1, "Fetching the certificate from the aggregator…")?;
+                None
+            }
+        };
+        let builder = match &certificate_chain_bundle {
+            Some(bundle) => self.offline_client_builder(&params, bundle)?,
+            None => client_builder(&params)?,
+        };
+        let client = builder
+            .add_feedback_receiver(Arc::new(IndicatifFeedbackReceiver::new(
+                progress_output_type,
+                logger.clone(),
+            )))
+            .with_logger(logger.clone())
+            .build()?;
+        let certificate_hash = match (&self.certificate_hash, &certificate_chain_bundle) {
+            (Some(certificate_hash), Some(_)) => certificate_hash.clone(),
+            (None, Some(bundle)) => bundle.certificate_hash.clone(),
+            (Some(certificate_hash), None) => {
+                Self::expand_certificate_hash(&client, certificate_hash).await?
+            }
+            (None, None) => return Err(anyhow!("A certificate hash is required")),
+        };
+
+        progress_printer.report_step(2, "Verifying the certificate chain…")?;
+        let certificate = client
+            .certificate()
+            .verify_chain(&certificate_hash)
+            .await
+            .with_context(|| {
+                format!("Can not verify the certificate chain from certificate_hash: '{certificate_hash}'")
+            })?;
+        if let Some(bundle) =
+            certificate_chain_bundle.filter(|bundle| bundle.certificate_hash == certificate.hash)
+        {
+            if bundle.artifact_message.compute_hash() != certificate.signed_message {
+                return Err(anyhow!(
+                    "The artifact message of the bundle is not signed by the certificate '{}'",
+                    certificate.hash
+                ));
+            }
+        }
+
+        if self.is_json_output_enabled() {
+            println!(
+                r#"{{"certificate_hash": "{}", "verified": true}}"#,
+                certificate.hash
+            );
+        } else {
+            println!(
+                "Certificate chain of certificate '{}' has been verified.",
+                certificate.hash
+            );
+        }
+
+        Ok(())
+    }
+
+    fn offline_client_builder(
+        &self,
+        params: &ConfigParameters,
+        certificate_chain_bundle: &CertificateChainBundle,
+    ) -> MithrilResult<ClientBuilder> {
+        if certificate_chain_bundle.format_version != CERTIFICATE_CHAIN_BUNDLE_FORMAT_VERSION {
+            return Err(anyhow!(
+                "Unsupported certificate chain bundle format version: {}",
+                certificate_chain_bundle.format_version
+            ));
+        }
+        // Only a checkpoint signed with the genesis signer can be trusted from the bundle itself
+        let mut trusted_checkpoints = trusted_checkpoints(params)?;
+        trusted_checkpoints.extend(
+            certificate_chain_bundle
+                .checkpoint
+                .clone()
+                .filter(|checkpoint| checkpoint.is_signed()),
+        );
+        let client_builder = ClientBuilder::new(&params.require("genesis_verification_key")?)
+            .with_aggregator_client(Arc::new(CertificateChainBundleAggregatorClient::new(
+                certificate_chain_bundle.clone(),
+            )))
+            .with_trusted_checkpoints(trusted_checkpoints);
+
+        Ok(client_builder)
+    }
+
+    async fn expand_certificate_hash(
+        client: &Client,
+        certificate_hash: &str,
+    ) -> MithrilResult<String> {
+        let get_list_of_certificate_hashes = || async {
+            let certificates = client.certificate().list().await.with_context(|| {
+                "Can not get the list of certificates while retrieving the latest certificate hash"
+            })?;
+
+            Ok(certificates
+                .iter()
+                .map(|certificate| certificate.hash.to_owned())
+                .collect::<Vec<String>>())
+        };
+
+        ExpanderUtils::expand_eventual_id_alias(certificate_hash, get_list_of_certificate_hashes())
+            .await
+    }
+}
+
+impl ConfigSource for CertificateVerifyChainCommand {
+    fn collect(&self) -> Result<HashMap<String, String>, ConfigError> {
+        let mut map = HashMap::new();
+
+        if let Some(genesis_verification_key) = self.genesis_verification_key.clone() {
+            map.insert(
+                "genesis_verification_key".to_string(),
+                genesis_verification_key,
+            );
+        }
+
+        Ok(map)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn certificate_hash_is_mandatory_when_not_verifying_from_a_file() {
+        CertificateVerifyChainCommand::try_parse_from(["verify-chain-command"])
+            .expect_err("The command should fail because certificate_hash is not set");
+    }
+
+    #[test]
+    fn certificate_hash_is_optional_when_verifying_from_a_file() {
+        let command = CertificateVerifyChainCommand::try_parse_from([
+            "verify-chain-command",
+            "--from-file",
+            "chain.json",
+        ])
+        .unwrap();
+
+        assert_eq!(None, command.certificate_hash);
+        assert_eq!(Some(PathBuf::from("chain.json")), command.from_file);
+    }
+}
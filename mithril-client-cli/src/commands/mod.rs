@@ -7,13 +7,23 @@ pub mod cardano_db;
 pub mod cardano_db_v2;
 pub mod cardano_stake_distribution;
 pub mod cardano_transaction;
+pub mod certificate;
 mod deprecation;
 pub mod mithril_stake_distribution;
 
 pub use deprecation::{DeprecatedCommand, Deprecation};
 
-use clap::Args;
-use mithril_client::{ClientBuilder, MithrilResult};
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use anyhow::{anyhow, Context};
+use clap::{Args, ValueEnum};
+use slog::Logger;
+
+use mithril_client::{
+    common::LedgerStateSnapshotLayout, CertificateChainCheckpoint, ClientBuilder, ClientOptions,
+    LedgerStateSnapshotConverter, MithrilResult,
+};
 
 use crate::configuration::ConfigParameters;
 
@@ -25,12 +35,110 @@ pub struct SharedArgs {
     json: bool,
 }
 
+/// Arguments limiting the network usage of the file downloads
+#[derive(Debug, Clone, Default, Args)]
+pub struct DownloadLimitsArgs {
+    /// Maximum bandwidth, in bytes per second, shared by all the parallel file downloads.
+    ///
+    /// By default, the downloads are not throttled.
+    #[clap(long)]
+    max_download_bandwidth: Option<u64>,
+
+    /// Maximum duration, in seconds, of the download of a single file.
+    ///
+    /// A download lasting longer fails and is retried. By default, the downloads never time out.
+    #[clap(long)]
+    download_timeout: Option<u64>,
+}
+
+impl DownloadLimitsArgs {
+    /// Apply these limits to the given client builder, leaving its other options untouched
+    pub(crate) fn apply_to(&self, client_builder: ClientBuilder) -> ClientBuilder {
+        client_builder
+            .with_max_download_bandwidth(self.max_download_bandwidth)
+            .with_download_timeout(self.download_timeout.map(Duration::from_secs))
+    }
+}
+
+/// UTxO-HD layouts a downloaded ledger state snapshot can be converted to
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum LedgerStateSnapshotTargetLayout {
+    /// Ledger tables serialized from the in-memory backend
+    InMemory,
+    /// Ledger tables stored in a LMDB database
+    Lmdb,
+}
+
+impl From<LedgerStateSnapshotTargetLayout> for LedgerStateSnapshotLayout {
+    fn from(layout: LedgerStateSnapshotTargetLayout) -> Self {
+        match layout {
+            LedgerStateSnapshotTargetLayout::InMemory => LedgerStateSnapshotLayout::InMemory,
+            LedgerStateSnapshotTargetLayout::Lmdb => LedgerStateSnapshotLayout::Lmdb,
+        }
+    }
+}
+
+/// Arguments to convert the downloaded ledger state snapshot to a UTxO-HD layout
+#[derive(Debug, Clone, Default, Args)]
+pub struct LedgerStateConversionArgs {
+    /// Convert the downloaded ledger state snapshot to the given UTxO-HD layout.
+    ///
+    /// Requires the ancillary files to be included in the download.
+    #[clap(
+        long,
+        value_enum,
+        requires_all = ["include_ancillary", "snapshot_converter_bin", "cardano_node_config"]
+    )]
+    convert_ledger_state_snapshot_to: Option<LedgerStateSnapshotTargetLayout>,
+
+    /// Path to the `snapshot-converter` binary distributed with the Cardano node.
+    #[clap(long)]
+    snapshot_converter_bin: Option<PathBuf>,
+
+    /// Path to the configuration file of the Cardano node, used by the `snapshot-converter`.
+    #[clap(long)]
+    cardano_node_config: Option<PathBuf>,
+}
+
+impl LedgerStateConversionArgs {
+    /// Convert the last ledger state snapshot of the given Cardano db if a target layout is set
+    pub(crate) fn convert_last_snapshot(
+        &self,
+        db_dir: &Path,
+        logger: &Logger,
+    ) -> MithrilResult<()> {
+        let Some(target_layout) = self.convert_ledger_state_snapshot_to else {
+            return Ok(());
+        };
+        let (Some(snapshot_converter_bin), Some(cardano_node_config)) =
+            (&self.snapshot_converter_bin, &self.cardano_node_config)
+        else {
+            return Err(anyhow!(
+                "The snapshot converter binary and the Cardano node configuration are required to convert the ledger state snapshot"
+            ));
+        };
+
+        LedgerStateSnapshotConverter::new(
+            snapshot_converter_bin,
+            cardano_node_config,
+            logger.clone(),
+        )
+        .convert_last_snapshot(db_dir, target_layout.into())
+        .with_context(|| {
+            format!("Can not convert the ledger state snapshot to the '{target_layout:?}' layout")
+        })?;
+
+        Ok(())
+    }
+}
+
 pub(crate) fn client_builder(params: &ConfigParameters) -> MithrilResult<ClientBuilder> {
     let builder = ClientBuilder::aggregator(
         &params.require("aggregator_endpoint")?,
         &params.require("genesis_verification_key")?,
     )
-    .with_origin_tag(params.get("origin_tag"));
+    .with_origin_tag(params.get("origin_tag"))
+    .with_trusted_checkpoints(trusted_checkpoints(params)?);
 
     Ok(builder)
 }
@@ -51,7 +159,142 @@ pub(crate) fn client_builder_with_fallback_genesis_key(
             fallback_genesis_verification_key,
         ),
     )
-    .with_origin_tag(params.get("origin_tag"));
+    .with_origin_tag(params.get("origin_tag"))
+    .with_trusted_checkpoints(trusted_checkpoints(params)?);
 
     Ok(builder)
 }
+
+/// Get the trusted checkpoints of the certificate chain, read from the JSON file set with the
+/// `trusted_checkpoints_file` parameter if any.
+pub(crate) fn trusted_checkpoints(
+    params: &ConfigParameters,
+) -> MithrilResult<Vec<CertificateChainCheckpoint>> {
+    match params.get("trusted_checkpoints_file") {
+        Some(path) => {
+            let file = std::fs::File::open(&path)
+                .with_context(|| format!("Could not open trusted checkpoints file '{path}'"))?;
+
+            serde_json::from_reader(file)
+                .with_context(|| format!("Could not parse trusted checkpoints file '{path}'"))
+        }
+        None => Ok(vec![]),
+    }
+}
+
+/// Get the ancillary verification key, which is required when the ancillary files are included
+/// in the download.
+pub(crate) fn ancillary_verification_key(
+    params: &ConfigParameters,
+    include_ancillary: bool,
+) -> MithrilResult<Option<String>> {
+    if include_ancillary {
+        let ancillary_verification_key = params
+            .require("ancillary_verification_key")
+            .with_context(|| {
+                "An ancillary verification key is required to include the ancillary files"
+            })?;
+
+        Ok(Some(ancillary_verification_key))
+    } else {
+        Ok(params.get("ancillary_verification_key"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use mithril_common::test_utils::{CertificateChainBuilder, TempDir};
+
+    use super::*;
+
+    #[test]
+    fn ancillary_verification_key_is_mandatory_when_include_ancillary_is_true() {
+        let params = ConfigParameters::build(&[]);
+
+        ancillary_verification_key(&params, true)
+            .expect_err("Should fail because ancillary_verification_key is not set");
+    }
+
+    #[test]
+    fn ancillary_verification_key_is_optional_when_include_ancillary_is_false() {
+        let params = ConfigParameters::build(&[]);
+
+        assert_eq!(None, ancillary_verification_key(&params, false).unwrap());
+    }
+
+    #[test]
+    fn ancillary_verification_key_is_read_from_parameters() {
+        let params = ConfigParameters::build(&[("ancillary_verification_key", "key")]);
+
+        assert_eq!(
+            Some("key".to_string()),
+            ancillary_verification_key(&params, true).unwrap()
+        );
+    }
+
+    #[test]
+    fn trusted_checkpoints_are_empty_when_no_file_is_set() {
+        let params = ConfigParameters::build(&[]);
+
+        assert_eq!(
+            Vec::<CertificateChainCheckpoint>::new(),
+            trusted_checkpoints(&params).unwrap()
+        );
+    }
+
+    #[test]
+    fn trusted_checkpoints_are_read_from_file() {
+        let (certificates, _) = CertificateChainBuilder::new()
+            .with_total_certificates(3)
+            .build();
+        let checkpoints = vec![CertificateChainCheckpoint::from_certificate(
+            &certificates[1],
+        )];
+        let file = TempDir::create(
+            "client-cli-commands",
+            "trusted_checkpoints_are_read_from_file",
+        )
+        .join("trusted_checkpoints.json");
+        std::fs::write(&file, serde_json::to_string(&checkpoints).unwrap()).unwrap();
+        let params =
+            ConfigParameters::build(&[("trusted_checkpoints_file", file.to_str().unwrap())]);
+
+        assert_eq!(checkpoints, trusted_checkpoints(&params).unwrap());
+    }
+
+    #[test]
+    fn trusted_checkpoints_fails_if_file_is_invalid() {
+        let file = TempDir::create(
+            "client-cli-commands",
+            "trusted_checkpoints_fails_if_file_is_invalid",
+        )
+        .join("trusted_checkpoints.json");
+        std::fs::write(&file, "not a json").unwrap();
+        let params =
+            ConfigParameters::build(&[("trusted_checkpoints_file", file.to_str().unwrap())]);
+
+        trusted_checkpoints(&params).expect_err("Should fail with an invalid file");
+    }
+
+    #[test]
+    fn ledger_state_conversion_does_nothing_without_target_layout() {
+        let logger = Logger::root(slog::Discard, slog::o!());
+
+        LedgerStateConversionArgs::default()
+            .convert_last_snapshot(Path::new("/not/existing/db"), &logger)
+            .unwrap();
+    }
+
+    #[test]
+    fn ledger_state_conversion_fails_without_snapshot_converter_binary() {
+        let logger = Logger::root(slog::Discard, slog::o!());
+
+        LedgerStateConversionArgs {
+            convert_ledger_state_snapshot_to: Some(LedgerStateSnapshotTargetLayout::Lmdb),
+            snapshot_converter_bin: None,
+            cardano_node_config: Some(PathBuf::from("config.json")),
+        }
+        .convert_last_snapshot(Path::new("/not/existing/db"), &logger)
+        .expect_err("Conversion should fail without the snapshot converter binary");
+    }
+}
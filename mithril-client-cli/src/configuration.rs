@@ -41,7 +41,6 @@ impl ConfigParameters {
     }
 
     /// Add or replace a parameter in the holder
-    #[cfg(test)]
     pub fn add_parameter(&mut self, name: &str, value: &str) -> &mut Self {
         let _ = self.parameters.insert(name.to_string(), value.to_string());
 
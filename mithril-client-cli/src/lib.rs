@@ -13,6 +13,7 @@
 mod command_context;
 pub mod commands;
 mod configuration;
+pub mod network_profile;
 mod utils;
 
 pub use command_context::*;
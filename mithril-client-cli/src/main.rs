@@ -16,9 +16,10 @@ use mithril_doc::{Documenter, GenerateDocCommands, StructDoc};
 use mithril_client_cli::commands::{
     cardano_db::CardanoDbCommands, cardano_db_v2::CardanoDbV2Commands,
     cardano_stake_distribution::CardanoStakeDistributionCommands,
-    cardano_transaction::CardanoTransactionCommands,
+    cardano_transaction::CardanoTransactionCommands, certificate::CertificateCommands,
     mithril_stake_distribution::MithrilStakeDistributionCommands, DeprecatedCommand, Deprecation,
 };
+use mithril_client_cli::network_profile::{load_network_profile, NetworkProfile};
 use mithril_client_cli::{ClapError, CommandContext};
 
 enum LogOutputType {
@@ -70,6 +71,24 @@ pub struct Args {
     #[example = "`https://aggregator.pre-release-preview.api.mithril.network/aggregator`"]
     aggregator_endpoint: Option<String>,
 
+    /// Network profile providing the Aggregator endpoint and the verification keys.
+    ///
+    /// Either a Cardano network (`mainnet`, `preprod` or `preview`) or a Mithril network
+    /// identifier (i.e. `testing-preview`). The values set on the command line, with environment
+    /// variables or in the configuration file take precedence over the ones of the profile.
+    #[clap(long, env = "MITHRIL_NETWORK")]
+    #[example = "`mainnet`, `preprod` or `preview`"]
+    network: Option<String>,
+
+    /// Networks file describing custom network profiles, using the format of the `networks.json`
+    /// file of the Mithril repository.
+    ///
+    /// By default, `$XDG_CONFIG_HOME/mithril/networks.json` (or `~/.config/mithril/networks.json`)
+    /// is used if it exists.
+    #[clap(long, env = "MITHRIL_NETWORKS_FILE")]
+    #[example = "`./networks.json`"]
+    networks_file: Option<PathBuf>,
+
     /// Enable JSON output for logs displayed according to verbosity level
     #[clap(long)]
     log_format_json: bool,
@@ -86,6 +105,15 @@ pub struct Args {
     /// Request origin tag
     #[clap(long)]
     origin_tag: Option<String>,
+
+    /// JSON file listing trusted checkpoints of the certificate chain.
+    ///
+    /// The certificate chain verification stops at a trusted checkpoint instead of walking the
+    /// chain back to the genesis certificate. Signed checkpoints are verified with the genesis
+    /// verification key, unsigned checkpoints are trusted as is.
+    #[clap(long, env = "TRUSTED_CHECKPOINTS_FILE")]
+    #[example = "`./trusted-checkpoints.json`"]
+    trusted_checkpoints_file: Option<String>,
 }
 
 impl Args {
@@ -95,16 +123,46 @@ impl Args {
             "Mithril client CLI version: {}",
             env!("CARGO_PKG_VERSION")
         );
-        debug!(root_logger, "Run Mode: {}", self.run_mode);
+        let network_profile = self.network_profile(&root_logger)?;
+        let config = self.config_builder(network_profile.as_ref(), &root_logger)?;
+        let context = CommandContext::new(config, self.unstable, root_logger)
+            .with_network_profile(network_profile);
+
+        self.command.execute(context).await
+    }
+
+    fn network_profile(&self, logger: &Logger) -> MithrilResult<Option<NetworkProfile>> {
+        self.network
+            .as_ref()
+            .map(|network| {
+                debug!(logger, "Network profile: {network}");
+                load_network_profile(network, self.networks_file.as_deref(), logger)
+            })
+            .transpose()
+    }
+
+    fn config_builder(
+        &self,
+        network_profile: Option<&NetworkProfile>,
+        logger: &Logger,
+    ) -> MithrilResult<ConfigBuilder<DefaultState>> {
+        debug!(logger, "Run Mode: {}", self.run_mode);
         let filename = format!("{}/{}.json", self.config_directory.display(), self.run_mode);
-        debug!(root_logger, "Reading configuration file '{filename}'.");
-        let config: ConfigBuilder<DefaultState> = config::Config::builder()
+        debug!(logger, "Reading configuration file '{filename}'.");
+        let mut config: ConfigBuilder<DefaultState> = config::Config::builder();
+        if let Some(profile) = network_profile {
+            // Defaults have the lowest priority: any other source overrides the profile.
+            // Verification keys are fetched later, only by the commands that need them.
+            for (name, value) in profile.parameters() {
+                config = config.set_default(name, value)?;
+            }
+        }
+        let config = config
             .add_source(config::File::with_name(&filename).required(false))
             .add_source(self.clone())
             .set_default("download_dir", "")?;
-        let context = CommandContext::new(config, self.unstable, root_logger);
 
-        self.command.execute(context).await
+        Ok(config)
     }
 
     fn log_level(&self) -> Level {
@@ -185,6 +243,7 @@ impl Source for Args {
         let myself = self.clone();
         register_config_value_option!(map, &namespace, myself.aggregator_endpoint);
         register_config_value_option!(map, &namespace, myself.origin_tag);
+        register_config_value_option!(map, &namespace, myself.trusted_checkpoints_file);
 
         Ok(map)
     }
@@ -207,6 +266,9 @@ enum ArtifactCommands {
     #[clap(subcommand, alias("cdbv2"))]
     CardanoDbV2(CardanoDbV2Commands),
 
+    #[clap(subcommand, alias("cert"))]
+    Certificate(CertificateCommands),
+
     #[clap(alias("doc"), hide(true))]
     GenerateDoc(GenerateDocCommands),
 }
@@ -218,6 +280,7 @@ impl ArtifactCommands {
             Self::MithrilStakeDistribution(cmd) => cmd.execute(context).await,
             Self::CardanoTransaction(cmd) => cmd.execute(context).await,
             Self::CardanoStakeDistribution(cmd) => cmd.execute(context).await,
+            Self::Certificate(cmd) => cmd.execute(context).await,
             Self::CardanoDbV2(cmd) => {
                 if !context.is_unstable_enabled() {
                     Err(anyhow!(Self::unstable_flag_missing_message(
@@ -259,6 +322,8 @@ async fn main() -> MithrilResult<()> {
 
 #[cfg(test)]
 mod tests {
+    use mithril_common::test_utils::TempDir;
+
     use super::*;
 
     #[tokio::test]
@@ -275,4 +340,92 @@ mod tests {
             .to_string()
             .contains("subcommand is only accepted using the --unstable flag."));
     }
+
+    #[tokio::test]
+    async fn fail_if_network_profile_is_unknown() {
+        let args = Args::try_parse_from([
+            "mithril-client",
+            "--network",
+            "unknown",
+            "cardano-db",
+            "snapshot",
+            "list",
+        ])
+        .unwrap();
+
+        args.execute(Logger::root(slog::Discard, slog::o!()))
+            .await
+            .expect_err("Should fail if the network profile is unknown");
+    }
+
+    #[tokio::test]
+    async fn network_profile_values_are_overridden_by_configuration_file_and_arguments() {
+        let dir = TempDir::create(
+            "client-cli",
+            "network_profile_values_are_overridden_by_configuration_file_and_arguments",
+        );
+        let networks_file = dir.join("networks.json");
+        std::fs::write(
+            &networks_file,
+            r#"{
+                "devnet": {
+                    "mithril-networks": [{
+                        "my-devnet": {
+                            "aggregators": [{ "url": "http://profile-aggregator" }],
+                            "genesis": { "verification-key": { "value": "profile-genesis-key" } },
+                            "ancillary": { "verification-key": { "value": "profile-ancillary-key" } }
+                        }
+                    }]
+                }
+            }"#,
+        )
+        .unwrap();
+        std::fs::write(
+            dir.join("dev.json"),
+            r#"{ "genesis_verification_key": "file-genesis-key" }"#,
+        )
+        .unwrap();
+        let args = Args::try_parse_from([
+            "mithril-client",
+            "--run-mode",
+            "dev",
+            "--config-directory",
+            dir.to_str().unwrap(),
+            "--network",
+            "devnet",
+            "--networks-file",
+            networks_file.to_str().unwrap(),
+            "--aggregator-endpoint",
+            "http://cli-aggregator",
+            "cardano-db",
+            "snapshot",
+            "list",
+        ])
+        .unwrap();
+
+        let logger = Logger::root(slog::Discard, slog::o!());
+        let network_profile = args.network_profile(&logger).unwrap();
+        let config = args
+            .config_builder(network_profile.as_ref(), &logger)
+            .unwrap();
+        let context =
+            CommandContext::new(config, false, logger).with_network_profile(network_profile);
+
+        let params = context
+            .fill_verification_keys(context.config_parameters().unwrap())
+            .await;
+
+        assert_eq!(
+            "http://cli-aggregator",
+            params.require("aggregator_endpoint").unwrap()
+        );
+        assert_eq!(
+            "file-genesis-key",
+            params.require("genesis_verification_key").unwrap()
+        );
+        assert_eq!(
+            "profile-ancillary-key",
+            params.require("ancillary_verification_key").unwrap()
+        );
+    }
 }
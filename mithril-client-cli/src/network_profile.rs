@@ -0,0 +1,514 @@
+//! Network profiles
+//!
+//! Resolve the aggregator endpoint and the verification keys of a Mithril network from its
+//! name, using the networks shipped with the client or a user networks file.
+
+use anyhow::{anyhow, Context};
+use serde::Deserialize;
+use slog::{debug, warn, Logger};
+use std::collections::{BTreeMap, HashMap};
+use std::path::{Path, PathBuf};
+
+use mithril_client::MithrilResult;
+
+use crate::configuration::ConfigParameters;
+
+/// Description of the known Mithril networks, shipped with the client.
+const EMBEDDED_NETWORKS: &str = include_str!("../networks.json");
+
+/// Location of the networks file of the user, relative to its configuration directory.
+const USER_NETWORKS_FILE: &str = "mithril/networks.json";
+
+/// Where to find a verification key of a network.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum KeyLocation {
+    /// Key to download from a URL
+    Url(String),
+
+    /// Key to read from a local file
+    Path(PathBuf),
+
+    /// Key given inline
+    Value(String),
+}
+
+impl KeyLocation {
+    /// Fetch the key from its location.
+    pub async fn fetch(&self) -> MithrilResult<String> {
+        let key = match self {
+            KeyLocation::Url(url) => reqwest::get(url)
+                .await
+                .and_then(|response| response.error_for_status())
+                .with_context(|| format!("Could not download key from '{url}'"))?
+                .text()
+                .await
+                .with_context(|| format!("Could not read key downloaded from '{url}'"))?,
+            KeyLocation::Path(path) => std::fs::read_to_string(path)
+                .with_context(|| format!("Could not read key file '{}'", path.display()))?,
+            KeyLocation::Value(value) => value.clone(),
+        };
+
+        Ok(key.trim().to_string())
+    }
+
+    fn relative_to(self, base_dir: &Path) -> Self {
+        match self {
+            KeyLocation::Path(path) if path.is_relative() => KeyLocation::Path(base_dir.join(path)),
+            location => location,
+        }
+    }
+}
+
+/// Endpoint and verification keys of a Mithril network.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NetworkProfile {
+    /// Name of the profile
+    pub name: String,
+
+    /// Aggregator endpoint of the network
+    pub aggregator_endpoint: String,
+
+    /// Location of the genesis verification key
+    pub genesis_verification_key: Option<KeyLocation>,
+
+    /// Location of the ancillary verification key
+    pub ancillary_verification_key: Option<KeyLocation>,
+}
+
+impl NetworkProfile {
+    /// Configuration parameters of this profile that do not require any fetching.
+    pub fn parameters(&self) -> HashMap<String, String> {
+        HashMap::from([(
+            "aggregator_endpoint".to_string(),
+            self.aggregator_endpoint.clone(),
+        )])
+    }
+
+    /// Fill the verification keys missing from the given parameters with the keys of this
+    /// profile.
+    ///
+    /// Only the keys that are not already set are fetched. A key that can not be fetched is
+    /// left unset, so the command requiring it fails with a missing parameter error.
+    pub async fn fill_missing_keys(
+        &self,
+        mut parameters: ConfigParameters,
+        logger: &Logger,
+    ) -> ConfigParameters {
+        for (parameter, location) in [
+            ("genesis_verification_key", &self.genesis_verification_key),
+            (
+                "ancillary_verification_key",
+                &self.ancillary_verification_key,
+            ),
+        ] {
+            let Some(location) = location else {
+                continue;
+            };
+            if parameters.get(parameter).is_some() {
+                continue;
+            }
+
+            match location.fetch().await {
+                Ok(key) => {
+                    parameters.add_parameter(parameter, &key);
+                }
+                Err(error) => {
+                    warn!(
+                        logger, "Could not fetch '{parameter}' of network '{}'", self.name;
+                        "error" => ?error
+                    );
+                }
+            }
+        }
+
+        parameters
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct CardanoNetwork {
+    #[serde(rename = "mithril-networks", default)]
+    mithril_networks: Vec<BTreeMap<String, MithrilNetwork>>,
+}
+
+#[derive(Debug, Deserialize)]
+struct MithrilNetwork {
+    aggregators: Vec<AggregatorLocation>,
+    genesis: Option<VerificationKeyEntry>,
+    ancillary: Option<VerificationKeyEntry>,
+}
+
+#[derive(Debug, Deserialize)]
+struct AggregatorLocation {
+    url: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct VerificationKeyEntry {
+    #[serde(rename = "verification-key")]
+    verification_key: KeyLocation,
+}
+
+/// Collection of the network profiles, by name.
+///
+/// Profiles are described using the format of the `networks.json` file of the Mithril
+/// repository: each Mithril network is available by its identifier (i.e. `release-mainnet`),
+/// and each Cardano network by its name (i.e. `mainnet`) which selects its first Mithril network.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct NetworkProfiles {
+    profiles: BTreeMap<String, NetworkProfile>,
+}
+
+impl NetworkProfiles {
+    /// Load the profiles of the networks shipped with the client, overridden by the profiles
+    /// of the given networks file.
+    ///
+    /// If no file is given, the user networks file is used if it exists.
+    pub fn load(networks_file: Option<&Path>) -> MithrilResult<Self> {
+        let mut profiles = Self::embedded()?;
+
+        match networks_file {
+            Some(path) => profiles.extend(Self::from_file(path)?),
+            None => {
+                if let Some(path) = default_user_networks_file().filter(|path| path.exists()) {
+                    profiles.extend(Self::from_file(&path)?);
+                }
+            }
+        }
+
+        Ok(profiles)
+    }
+
+    /// Profiles of the networks shipped with the client.
+    pub fn embedded() -> MithrilResult<Self> {
+        Self::from_json(EMBEDDED_NETWORKS, Path::new("."))
+            .with_context(|| "Could not parse embedded networks description")
+    }
+
+    /// Read the profiles from a networks file.
+    ///
+    /// Relative key paths are resolved from the directory of the file.
+    pub fn from_file(path: &Path) -> MithrilResult<Self> {
+        let content = std::fs::read_to_string(path)
+            .with_context(|| format!("Could not read networks file '{}'", path.display()))?;
+        let base_dir = path.parent().unwrap_or(Path::new("."));
+
+        Self::from_json(&content, base_dir)
+            .with_context(|| format!("Could not parse networks file '{}'", path.display()))
+    }
+
+    fn from_json(content: &str, base_dir: &Path) -> MithrilResult<Self> {
+        let cardano_networks: BTreeMap<String, CardanoNetwork> = serde_json::from_str(content)?;
+        let mut profiles = BTreeMap::new();
+
+        for (cardano_network_name, cardano_network) in cardano_networks {
+            for (mithril_network_id, mithril_network) in
+                cardano_network.mithril_networks.into_iter().flatten()
+            {
+                let aggregator = mithril_network.aggregators.first().ok_or_else(|| {
+                    anyhow!("Network '{mithril_network_id}' does not define any aggregator")
+                })?;
+                let key_location = |entry: Option<VerificationKeyEntry>| {
+                    entry.map(|entry| entry.verification_key.relative_to(base_dir))
+                };
+                let profile = NetworkProfile {
+                    name: mithril_network_id.clone(),
+                    aggregator_endpoint: aggregator.url.clone(),
+                    genesis_verification_key: key_location(mithril_network.genesis),
+                    ancillary_verification_key: key_location(mithril_network.ancillary),
+                };
+
+                profiles
+                    .entry(cardano_network_name.clone())
+                    .or_insert_with(|| NetworkProfile {
+                        name: cardano_network_name.clone(),
+                        ..profile.clone()
+                    });
+                profiles.insert(mithril_network_id, profile);
+            }
+        }
+
+        Ok(Self { profiles })
+    }
+
+    fn extend(&mut self, other: Self) {
+        self.profiles.extend(other.profiles);
+    }
+
+    /// Get the profile with the given name.
+    pub fn get(&self, name: &str) -> MithrilResult<&NetworkProfile> {
+        self.profiles.get(name).ok_or_else(|| {
+            anyhow!(
+                "Unknown network '{name}', available networks: {}",
+                self.names().join(", ")
+            )
+        })
+    }
+
+    /// Names of the available profiles.
+    pub fn names(&self) -> Vec<&str> {
+        self.profiles.keys().map(|name| name.as_str()).collect()
+    }
+}
+
+/// Location of the networks file of the user: `$XDG_CONFIG_HOME/mithril/networks.json`,
+/// or `$HOME/.config/mithril/networks.json` when `XDG_CONFIG_HOME` is not set.
+pub fn default_user_networks_file() -> Option<PathBuf> {
+    std::env::var_os("XDG_CONFIG_HOME")
+        .filter(|dir| !dir.is_empty())
+        .map(PathBuf::from)
+        .or_else(|| std::env::var_os("HOME").map(|home| PathBuf::from(home).join(".config")))
+        .map(|config_dir| config_dir.join(USER_NETWORKS_FILE))
+}
+
+/// Load the named network profile.
+pub fn load_network_profile(
+    name: &str,
+    networks_file: Option<&Path>,
+    logger: &Logger,
+) -> MithrilResult<NetworkProfile> {
+    let profiles = NetworkProfiles::load(networks_file)?;
+    let profile = profiles.get(name)?;
+    debug!(
+        logger,
+        "Using network profile '{name}' with aggregator '{}'", profile.aggregator_endpoint
+    );
+
+    Ok(profile.clone())
+}
+
+#[cfg(test)]
+mod tests {
+    use mithril_common::test_utils::TempDir;
+
+    use super::*;
+
+    fn discard_logger() -> Logger {
+        Logger::root(slog::Discard, slog::o!())
+    }
+
+    fn write_networks_file(dir: &Path, content: &str) -> PathBuf {
+        let path = dir.join("networks.json");
+        std::fs::write(&path, content).unwrap();
+        path
+    }
+
+    #[test]
+    fn embedded_profiles_contains_all_cardano_networks() {
+        let profiles = NetworkProfiles::embedded().unwrap();
+
+        for (name, aggregator_endpoint) in [
+            (
+                "mainnet",
+                "https://aggregator.release-mainnet.api.mithril.network/aggregator",
+            ),
+            (
+                "preprod",
+                "https://aggregator.release-preprod.api.mithril.network/aggregator",
+            ),
+            (
+                "preview",
+                "https://aggregator.pre-release-preview.api.mithril.network/aggregator",
+            ),
+        ] {
+            let profile = profiles.get(name).unwrap();
+            assert_eq!(aggregator_endpoint, profile.aggregator_endpoint);
+            assert!(profile.genesis_verification_key.is_some());
+            assert!(profile.ancillary_verification_key.is_some());
+        }
+    }
+
+    #[test]
+    fn embedded_profiles_contains_mithril_networks_by_identifier() {
+        let profiles = NetworkProfiles::embedded().unwrap();
+
+        let profile = profiles.get("testing-preview").unwrap();
+
+        assert_eq!(
+            "https://aggregator.testing-preview.api.mithril.network/aggregator",
+            profile.aggregator_endpoint
+        );
+    }
+
+    #[test]
+    fn get_unknown_profile_fails() {
+        let profiles = NetworkProfiles::embedded().unwrap();
+
+        profiles
+            .get("unknown")
+            .expect_err("getting an unknown profile should fail");
+    }
+
+    #[test]
+    fn networks_file_profiles_override_embedded_profiles() {
+        let dir = TempDir::create(
+            "client-cli",
+            "networks_file_profiles_override_embedded_profiles",
+        );
+        let path = write_networks_file(
+            &dir,
+            r#"{
+                "mainnet": {
+                    "mithril-networks": [
+                        { "my-mainnet": { "aggregators": [{ "url": "http://my-mainnet" }] } }
+                    ]
+                },
+                "devnet": {
+                    "mithril-networks": [
+                        { "my-devnet": { "aggregators": [{ "url": "http://my-devnet" }] } }
+                    ]
+                }
+            }"#,
+        );
+
+        let profiles = NetworkProfiles::load(Some(&path)).unwrap();
+
+        assert_eq!(
+            "http://my-mainnet",
+            profiles.get("mainnet").unwrap().aggregator_endpoint
+        );
+        assert_eq!(
+            "http://my-devnet",
+            profiles.get("devnet").unwrap().aggregator_endpoint
+        );
+        assert_eq!(
+            "https://aggregator.release-preprod.api.mithril.network/aggregator",
+            profiles.get("preprod").unwrap().aggregator_endpoint
+        );
+    }
+
+    #[test]
+    fn load_fails_if_networks_file_does_not_exist() {
+        NetworkProfiles::load(Some(Path::new("/does/not/exist/networks.json")))
+            .expect_err("loading a missing networks file should fail");
+    }
+
+    #[test]
+    fn relative_key_paths_are_resolved_from_networks_file_directory() {
+        let dir = TempDir::create(
+            "client-cli",
+            "relative_key_paths_are_resolved_from_networks_file_directory",
+        );
+        let path = write_networks_file(
+            &dir,
+            r#"{
+                "devnet": {
+                    "mithril-networks": [{
+                        "my-devnet": {
+                            "aggregators": [{ "url": "http://my-devnet" }],
+                            "genesis": { "verification-key": { "path": "genesis.vkey" } },
+                            "ancillary": { "verification-key": { "path": "/keys/ancillary.vkey" } }
+                        }
+                    }]
+                }
+            }"#,
+        );
+
+        let profiles = NetworkProfiles::from_file(&path).unwrap();
+        let profile = profiles.get("my-devnet").unwrap();
+
+        assert_eq!(
+            Some(KeyLocation::Path(dir.join("genesis.vkey"))),
+            profile.genesis_verification_key
+        );
+        assert_eq!(
+            Some(KeyLocation::Path(PathBuf::from("/keys/ancillary.vkey"))),
+            profile.ancillary_verification_key
+        );
+    }
+
+    #[test]
+    fn profile_parameters_only_contains_aggregator_endpoint() {
+        let profile = NetworkProfile {
+            name: "devnet".to_string(),
+            aggregator_endpoint: "http://my-devnet".to_string(),
+            genesis_verification_key: Some(KeyLocation::Value("genesis-key".to_string())),
+            ancillary_verification_key: None,
+        };
+
+        assert_eq!(
+            HashMap::from([(
+                "aggregator_endpoint".to_string(),
+                "http://my-devnet".to_string()
+            )]),
+            profile.parameters()
+        );
+    }
+
+    #[tokio::test]
+    async fn fill_missing_keys_fetch_keys_from_path_and_value() {
+        let dir = TempDir::create(
+            "client-cli",
+            "fill_missing_keys_fetch_keys_from_path_and_value",
+        );
+        std::fs::write(dir.join("genesis.vkey"), "genesis-key\n").unwrap();
+        let profile = NetworkProfile {
+            name: "devnet".to_string(),
+            aggregator_endpoint: "http://my-devnet".to_string(),
+            genesis_verification_key: Some(KeyLocation::Path(dir.join("genesis.vkey"))),
+            ancillary_verification_key: Some(KeyLocation::Value("ancillary-key".to_string())),
+        };
+
+        let parameters = profile
+            .fill_missing_keys(ConfigParameters::default(), &discard_logger())
+            .await;
+
+        assert_eq!(
+            ConfigParameters::build(&[
+                ("genesis_verification_key", "genesis-key"),
+                ("ancillary_verification_key", "ancillary-key"),
+            ]),
+            parameters
+        );
+    }
+
+    #[tokio::test]
+    async fn fill_missing_keys_does_not_fetch_keys_already_set() {
+        let profile = NetworkProfile {
+            name: "devnet".to_string(),
+            aggregator_endpoint: "http://my-devnet".to_string(),
+            // Fetching this key would fail and leave the parameter unset
+            genesis_verification_key: Some(KeyLocation::Path(PathBuf::from(
+                "/does/not/exist/genesis.vkey",
+            ))),
+            ancillary_verification_key: Some(KeyLocation::Value("ancillary-key".to_string())),
+        };
+
+        let parameters = profile
+            .fill_missing_keys(
+                ConfigParameters::build(&[
+                    ("genesis_verification_key", "configured-genesis-key"),
+                    ("ancillary_verification_key", "configured-ancillary-key"),
+                ]),
+                &discard_logger(),
+            )
+            .await;
+
+        assert_eq!(
+            ConfigParameters::build(&[
+                ("genesis_verification_key", "configured-genesis-key"),
+                ("ancillary_verification_key", "configured-ancillary-key"),
+            ]),
+            parameters
+        );
+    }
+
+    #[tokio::test]
+    async fn fill_missing_keys_skips_keys_that_can_not_be_fetched() {
+        let profile = NetworkProfile {
+            name: "devnet".to_string(),
+            aggregator_endpoint: "http://my-devnet".to_string(),
+            genesis_verification_key: Some(KeyLocation::Path(PathBuf::from(
+                "/does/not/exist/genesis.vkey",
+            ))),
+            ancillary_verification_key: None,
+        };
+
+        let parameters = profile
+            .fill_missing_keys(ConfigParameters::default(), &discard_logger())
+            .await;
+
+        assert_eq!(ConfigParameters::default(), parameters);
+    }
+}
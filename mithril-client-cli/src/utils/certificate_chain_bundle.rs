@@ -0,0 +1,181 @@
+use anyhow::anyhow;
+use async_trait::async_trait;
+use std::collections::HashMap;
+
+use mithril_client::aggregator_client::{
+    AggregatorClient, AggregatorClientError, AggregatorRequest,
+};
+use mithril_client::{CertificateChainBundle, MithrilCertificate};
+
+/// An [AggregatorClient] answering certificate requests from a [CertificateChainBundle].
+///
+/// It allows to verify a certificate chain without any access to an aggregator, any other
+/// request fails.
+pub struct CertificateChainBundleAggregatorClient {
+    certificates: HashMap<String, MithrilCertificate>,
+}
+
+impl CertificateChainBundleAggregatorClient {
+    /// Constructs a new `CertificateChainBundleAggregatorClient`.
+    pub fn new(certificate_chain_bundle: CertificateChainBundle) -> Self {
+        Self {
+            certificates: certificate_chain_bundle
+                .certificates
+                .into_iter()
+                .map(|certificate| (certificate.hash.clone(), certificate))
+                .collect(),
+        }
+    }
+}
+
+#[async_trait]
+impl AggregatorClient for CertificateChainBundleAggregatorClient {
+    async fn get_content(
+        &self,
+        request: AggregatorRequest,
+    ) -> Result<String, AggregatorClientError> {
+        match request {
+            AggregatorRequest::GetCertificate { hash } => match self.certificates.get(&hash) {
+                Some(certificate) => serde_json::to_string(certificate)
+                    .map_err(|e| AggregatorClientError::SubsystemError(e.into())),
+                None => Err(AggregatorClientError::RemoteServerLogical(anyhow!(
+                    "Certificate '{hash}' is not in the certificate chain bundle"
+                ))),
+            },
+            _ => Err(AggregatorClientError::SubsystemError(anyhow!(
+                "Only certificates can be read from a certificate chain bundle"
+            ))),
+        }
+    }
+
+    async fn post_content(
+        &self,
+        _request: AggregatorRequest,
+    ) -> Result<String, AggregatorClientError> {
+        Err(AggregatorClientError::SubsystemError(anyhow!(
+            "Nothing can be posted to a certificate chain bundle"
+        )))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use mithril_client::common::ProtocolMessage;
+    use mithril_client::{ClientBuilder, CERTIFICATE_CHAIN_BUNDLE_FORMAT_VERSION};
+    use mithril_common::test_utils::CertificateChainBuilder;
+
+    use super::*;
+
+    fn build_certificate_chain(total_certificates: u64) -> (Vec<MithrilCertificate>, String) {
+        let (certificates, genesis_verifier) = CertificateChainBuilder::new()
+            .with_total_certificates(total_certificates)
+            .with_certificates_per_epoch(2)
+            .build();
+        let certificates = certificates
+            .into_iter()
+            .map(|certificate| certificate.try_into().unwrap())
+            .collect();
+        let genesis_verification_key = genesis_verifier
+            .to_verification_key()
+            .to_json_hex()
+            .unwrap();
+
+        (certificates, genesis_verification_key)
+    }
+
+    /// The certificates of the test chains sign the artifact message as their protocol message
+    fn compute_artifact_message(certificate: &MithrilCertificate) -> ProtocolMessage {
+        certificate.protocol_message.clone()
+    }
+
+    fn build_bundle(certificates: Vec<MithrilCertificate>) -> CertificateChainBundle {
+        CertificateChainBundle {
+            format_version: CERTIFICATE_CHAIN_BUNDLE_FORMAT_VERSION,
+            certificate_hash: certificates[0].hash.clone(),
+            artifact_message: compute_artifact_message(&certificates[0]),
+            checkpoint: None,
+            certificates,
+        }
+    }
+
+    fn build_offline_client(
+        certificate_chain_bundle: CertificateChainBundle,
+        genesis_verification_key: &str,
+    ) -> mithril_client::Client {
+        ClientBuilder::new(genesis_verification_key)
+            .with_aggregator_client(Arc::new(CertificateChainBundleAggregatorClient::new(
+                certificate_chain_bundle,
+            )))
+            .build()
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn fetch_certificate_chain_bundle_up_to_genesis() {
+        // The builder returns the chain from the latest certificate to the genesis certificate
+        let (certificates, genesis_verification_key) = build_certificate_chain(5);
+        let last_certificate_hash = certificates[0].hash.clone();
+        let artifact_message = compute_artifact_message(&certificates[0]);
+        let client = build_offline_client(
+            build_bundle(certificates.clone()),
+            &genesis_verification_key,
+        );
+
+        let bundle = client
+            .certificate()
+            .fetch_chain_bundle(&last_certificate_hash, artifact_message.clone(), None)
+            .await
+            .unwrap();
+
+        assert_eq!(last_certificate_hash, bundle.certificate_hash);
+        assert_eq!(certificates, bundle.certificates);
+        assert_eq!(artifact_message, bundle.artifact_message);
+        assert_eq!(
+            certificates[0].signed_message,
+            bundle.artifact_message.compute_hash()
+        );
+    }
+
+    #[tokio::test]
+    async fn verify_certificate_chain_from_bundle_without_aggregator() {
+        let (certificates, genesis_verification_key) = build_certificate_chain(5);
+        let certificate_hash = certificates[0].hash.clone();
+        let client = build_offline_client(build_bundle(certificates), &genesis_verification_key);
+
+        let certificate = client
+            .certificate()
+            .verify_chain(&certificate_hash)
+            .await
+            .unwrap();
+
+        assert_eq!(certificate_hash, certificate.hash);
+    }
+
+    #[tokio::test]
+    async fn verify_certificate_chain_from_bundle_fails_if_a_certificate_is_missing() {
+        let (mut certificates, genesis_verification_key) = build_certificate_chain(5);
+        let certificate_hash = certificates[0].hash.clone();
+        // Remove the genesis certificate
+        certificates.pop();
+        let client = build_offline_client(build_bundle(certificates), &genesis_verification_key);
+
+        client
+            .certificate()
+            .verify_chain(&certificate_hash)
+            .await
+            .expect_err("verify_chain should fail when a certificate is missing");
+    }
+
+    #[tokio::test]
+    async fn get_content_fails_for_requests_other_than_get_certificate() {
+        let (certificates, _) = build_certificate_chain(2);
+        let client = CertificateChainBundleAggregatorClient::new(build_bundle(certificates));
+
+        client
+            .get_content(AggregatorRequest::ListCertificates)
+            .await
+            .expect_err("listing certificates should fail");
+    }
+}
@@ -3,6 +3,7 @@
 
 mod cardano_db;
 mod cardano_db_download_checker;
+mod certificate_chain_bundle;
 mod expander;
 mod feedback_receiver;
 mod multi_download_progress_reporter;
@@ -10,6 +11,7 @@ mod progress_reporter;
 
 pub use cardano_db::*;
 pub use cardano_db_download_checker::*;
+pub use certificate_chain_bundle::*;
 pub use expander::*;
 pub use feedback_receiver::*;
 pub use multi_download_progress_reporter::*;
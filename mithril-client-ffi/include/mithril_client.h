@@ -0,0 +1,182 @@
+#ifndef MITHRIL_CLIENT_FFI_H
+#define MITHRIL_CLIENT_FFI_H
+
+/* Warning: this file is generated by cbindgen with `make header`, do not modify it manually. */
+
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stddef.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+// Types of artifacts that can be listed and retrieved with [mithril_client_list_artifacts] and
+// [mithril_client_get_artifact].
+typedef enum MithrilArtifactType {
+  // Cardano database snapshots, identified by their digest.
+  MITHRIL_ARTIFACT_TYPE_CARDANO_DATABASE = 0,
+  // Mithril stake distributions, identified by their hash.
+  MITHRIL_ARTIFACT_TYPE_MITHRIL_STAKE_DISTRIBUTION = 1,
+  // Cardano transactions snapshots, identified by their hash.
+  MITHRIL_ARTIFACT_TYPE_CARDANO_TRANSACTIONS = 2,
+  // Cardano stake distributions, identified by their hash.
+  MITHRIL_ARTIFACT_TYPE_CARDANO_STAKE_DISTRIBUTION = 3,
+  // Mithril certificates, identified by their hash.
+  MITHRIL_ARTIFACT_TYPE_CERTIFICATE = 4,
+} MithrilArtifactType;
+
+// Status returned by every function of the library.
+//
+// When a function returns anything else than [MithrilStatus::Ok], the details of the failure
+// can be read with [mithril_last_error_message].
+typedef enum MithrilStatus {
+  // The call succeeded.
+  MITHRIL_STATUS_OK = 0,
+  // An argument is invalid (null pointer, invalid UTF-8, malformed value...).
+  MITHRIL_STATUS_INVALID_ARGUMENT = 1,
+  // The requested item does not exist on the aggregator.
+  MITHRIL_STATUS_NOT_FOUND = 2,
+  // The Mithril client failed to fulfill the request.
+  MITHRIL_STATUS_CLIENT_ERROR = 3,
+  // The retrieved data could not be verified against its certificate.
+  MITHRIL_STATUS_VERIFICATION_FAILED = 4,
+  // An unexpected internal error occurred.
+  MITHRIL_STATUS_INTERNAL_ERROR = 5,
+} MithrilStatus;
+
+// Opaque handle on a Mithril client and the runtime driving its requests.
+typedef struct MithrilClient MithrilClient;
+
+// Callback receiving the progress events of long-running tasks (downloads, certificate chain
+// validations).
+//
+// `event_json` is a JSON object with a `type` field naming the event and a `payload` field
+// holding its data. It is only valid for the duration of the call.
+//
+// The callback can be invoked from any thread of the library runtime.
+typedef void (*MithrilFeedbackCallback)(const char *event_json, void *user_data);
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+// Create a new Mithril client targeting the given aggregator.
+//
+// - `aggregator_endpoint` and `genesis_verification_key` are mandatory.
+// - `ancillary_verification_key` is optional (can be null), it is only needed to download the
+//   ancillary files of a Cardano database.
+// - `feedback_callback` is optional (can be null), when set it receives the progress events of
+//   long-running tasks along with the given `user_data`.
+//
+// On success the new client is written to `out_client` and must be released with
+// [mithril_client_free].
+//
+// # Safety
+// - String arguments must be null or valid nul-terminated strings.
+// - `user_data` must stay valid, and be usable from any thread, as long as the client lives.
+// - `out_client` must be a valid pointer to write a `MithrilClient*` to.
+enum MithrilStatus mithril_client_new(const char *aggregator_endpoint,
+                                      const char *genesis_verification_key,
+                                      const char *ancillary_verification_key,
+                                      MithrilFeedbackCallback feedback_callback,
+                                      void *user_data,
+                                      struct MithrilClient **out_client);
+
+// Release a client created with [mithril_client_new].
+//
+// # Safety
+// `client` must be null or a handle returned by [mithril_client_new] that has not already been
+// freed.
+void mithril_client_free(struct MithrilClient *client);
+
+// List the latest artifacts of the given type, written as a JSON array to `out_json`.
+//
+// The returned string must be released with [mithril_string_free][crate::mithril_string_free].
+//
+// # Safety
+// - `client` must be a valid handle returned by [mithril_client_new].
+// - `artifact_type` must be one of the [MithrilArtifactType] values.
+// - `out_json` must be a valid pointer to write a `char*` to.
+enum MithrilStatus mithril_client_list_artifacts(const struct MithrilClient *client,
+                                                 uint32_t artifact_type,
+                                                 char **out_json);
+
+// Get the artifact of the given type and identifier, written as a JSON object to `out_json`.
+//
+// Returns [MithrilStatus::NotFound] if the aggregator does not know the artifact.
+// The returned string must be released with [mithril_string_free][crate::mithril_string_free].
+//
+// # Safety
+// - `client` must be a valid handle returned by [mithril_client_new].
+// - `artifact_type` must be one of the [MithrilArtifactType] values.
+// - `id` must be a valid nul-terminated string.
+// - `out_json` must be a valid pointer to write a `char*` to.
+enum MithrilStatus mithril_client_get_artifact(const struct MithrilClient *client,
+                                               uint32_t artifact_type,
+                                               const char *id,
+                                               char **out_json);
+
+// Verify the certificate chain from the given certificate back to the genesis certificate.
+//
+// On success, the verified certificate is written as a JSON object to `out_json`, it must be
+// released with [mithril_string_free][crate::mithril_string_free].
+//
+// # Safety
+// - `client` must be a valid handle returned by [mithril_client_new].
+// - `certificate_hash` must be a valid nul-terminated string.
+// - `out_json` must be a valid pointer to write a `char*` to.
+enum MithrilStatus mithril_client_verify_certificate_chain(const struct MithrilClient *client,
+                                                           const char *certificate_hash,
+                                                           char **out_json);
+
+// Download, unpack and verify the Cardano database with the given digest to `target_directory`.
+//
+// The target directory must already exist. Ancillary files are downloaded only if
+// `include_ancillary` is true, in which case the client must have been created with an
+// ancillary verification key.
+//
+// Progress is reported through the feedback callback given to [mithril_client_new].
+//
+// # Safety
+// - `client` must be a valid handle returned by [mithril_client_new].
+// - `digest` and `target_directory` must be valid nul-terminated strings.
+enum MithrilStatus mithril_client_download_cardano_database(const struct MithrilClient *client,
+                                                            const char *digest,
+                                                            const char *target_directory,
+                                                            bool include_ancillary);
+
+// Get and verify the proofs of inclusion of the given Cardano transactions in the certified
+// Cardano transactions set.
+//
+// On success, a JSON object with the `certificate_hash`, the `certified_transactions` and the
+// `non_certified_transactions` is written to `out_json`, it must be released with
+// [mithril_string_free][crate::mithril_string_free].
+//
+// # Safety
+// - `client` must be a valid handle returned by [mithril_client_new].
+// - `transactions_hashes` must point to an array of `transactions_hashes_len` valid
+//   nul-terminated strings.
+// - `out_json` must be a valid pointer to write a `char*` to.
+enum MithrilStatus mithril_client_verify_cardano_transactions(const struct MithrilClient *client,
+                                                              const char *const *transactions_hashes,
+                                                              size_t transactions_hashes_len,
+                                                              char **out_json);
+
+// Get the message of the last error that occurred on the calling thread.
+//
+// Returns a null pointer if the last call succeeded. The returned string is owned by the
+// library and is valid until the next call to a function of the library on the same thread,
+// it must **not** be freed by the caller.
+const char *mithril_last_error_message(void);
+
+// Release a string allocated by the library.
+//
+// # Safety
+// `value` must be null or a string returned by a function of this library that has not already
+// been freed.
+void mithril_string_free(char *value);
+
+#ifdef __cplusplus
+}  // extern "C"
+#endif  // __cplusplus
+
+#endif  /* MITHRIL_CLIENT_FFI_H */
@@ -0,0 +1,536 @@
+use anyhow::{anyhow, Context};
+use serde::Serialize;
+use std::ffi::{c_char, c_void};
+use std::path::Path;
+use std::sync::Arc;
+use tokio::runtime::Runtime;
+
+use mithril_client::{Client, ClientBuilder, MessageBuilder, MithrilResult};
+
+use crate::error::{run_ffi, FfiError, FfiResult, MithrilStatus};
+use crate::feedback::{CallbackFeedbackReceiver, MithrilFeedbackCallback};
+use crate::strings::{optional_str_from_ptr, str_from_ptr, write_output_string};
+
+/// Types of artifacts that can be listed and retrieved with [mithril_client_list_artifacts] and
+/// [mithril_client_get_artifact].
+///
+/// The functions take the artifact type as an integer, an unknown value is rejected with
+/// [MithrilStatus::InvalidArgument].
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MithrilArtifactType {
+    /// Cardano database snapshots, identified by their digest.
+    CardanoDatabase = 0,
+    /// Mithril stake distributions, identified by their hash.
+    MithrilStakeDistribution = 1,
+    /// Cardano transactions snapshots, identified by their hash.
+    CardanoTransactions = 2,
+    /// Cardano stake distributions, identified by their hash.
+    CardanoStakeDistribution = 3,
+    /// Mithril certificates, identified by their hash.
+    Certificate = 4,
+}
+
+impl TryFrom<u32> for MithrilArtifactType {
+    type Error = FfiError;
+
+    fn try_from(value: u32) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(Self::CardanoDatabase),
+            1 => Ok(Self::MithrilStakeDistribution),
+            2 => Ok(Self::CardanoTransactions),
+            3 => Ok(Self::CardanoStakeDistribution),
+            4 => Ok(Self::Certificate),
+            _ => Err(FfiError::invalid_argument(anyhow!(
+                "Unknown artifact type: {value}"
+            ))),
+        }
+    }
+}
+
+/// Opaque handle on a Mithril client and the runtime driving its requests.
+pub struct MithrilClient {
+    client: Client,
+    runtime: Runtime,
+}
+
+impl MithrilClient {
+    fn new(
+        aggregator_endpoint: &str,
+        genesis_verification_key: &str,
+        ancillary_verification_key: Option<&str>,
+        feedback_receiver: Option<CallbackFeedbackReceiver>,
+    ) -> MithrilResult<Self> {
+        let runtime = tokio::runtime::Builder::new_multi_thread()
+            .enable_all()
+            .build()
+            .with_context(|| "Building the client runtime failed")?;
+        let mut builder = ClientBuilder::aggregator(aggregator_endpoint, genesis_verification_key)
+            .set_ancillary_verification_key(ancillary_verification_key.map(|k| k.to_string()))
+            .with_origin_tag(Some("FFI".to_string()));
+        if let Some(receiver) = feedback_receiver {
+            builder = builder.add_feedback_receiver(Arc::new(receiver));
+        }
+        let client = builder.build()?;
+
+        Ok(Self { client, runtime })
+    }
+
+    fn list_artifacts(&self, artifact_type: MithrilArtifactType) -> MithrilResult<String> {
+        self.runtime.block_on(async {
+            match artifact_type {
+                MithrilArtifactType::CardanoDatabase => {
+                    to_json(&self.client.cardano_database().list().await?)
+                }
+                MithrilArtifactType::MithrilStakeDistribution => {
+                    to_json(&self.client.mithril_stake_distribution().list().await?)
+                }
+                MithrilArtifactType::CardanoTransactions => {
+                    to_json(&self.client.cardano_transaction().list_snapshots().await?)
+                }
+                MithrilArtifactType::CardanoStakeDistribution => {
+                    to_json(&self.client.cardano_stake_distribution().list().await?)
+                }
+                MithrilArtifactType::Certificate => {
+                    to_json(&self.client.certificate().list().await?)
+                }
+            }
+        })
+    }
+
+    fn get_artifact(
+        &self,
+        artifact_type: MithrilArtifactType,
+        id: &str,
+    ) -> MithrilResult<Option<String>> {
+        self.runtime.block_on(async {
+            match artifact_type {
+                MithrilArtifactType::CardanoDatabase => {
+                    optional_to_json(self.client.cardano_database().get(id).await?)
+                }
+                MithrilArtifactType::MithrilStakeDistribution => {
+                    optional_to_json(self.client.mithril_stake_distribution().get(id).await?)
+                }
+                MithrilArtifactType::CardanoTransactions => {
+                    optional_to_json(self.client.cardano_transaction().get_snapshot(id).await?)
+                }
+                MithrilArtifactType::CardanoStakeDistribution => {
+                    optional_to_json(self.client.cardano_stake_distribution().get(id).await?)
+                }
+                MithrilArtifactType::Certificate => {
+                    optional_to_json(self.client.certificate().get(id).await?)
+                }
+            }
+        })
+    }
+
+    fn verify_certificate_chain(&self, certificate_hash: &str) -> MithrilResult<String> {
+        self.runtime.block_on(async {
+            let certificate = self
+                .client
+                .certificate()
+                .verify_chain(certificate_hash)
+                .await?;
+
+            to_json(&certificate)
+        })
+    }
+
+    fn download_cardano_database(
+        &self,
+        digest: &str,
+        target_directory: &Path,
+        include_ancillary: bool,
+    ) -> FfiResult<()> {
+        self.runtime.block_on(async {
+            let snapshot = self
+                .client
+                .cardano_database()
+                .get(digest)
+                .await?
+                .ok_or_else(|| {
+                    FfiError::not_found(anyhow!("No Cardano database found for digest '{digest}'"))
+                })?;
+            let certificate = self
+                .client
+                .certificate()
+                .verify_chain(&snapshot.certificate_hash)
+                .await
+                .map_err(FfiError::verification_failed)?;
+
+            if include_ancillary {
+                self.client
+                    .cardano_database()
+                    .download_unpack_full(&snapshot, target_directory)
+                    .await?;
+            } else {
+                self.client
+                    .cardano_database()
+                    .download_unpack(&snapshot, target_directory)
+                    .await?;
+            }
+            // Statistics are a best effort, a failure must not fail the download
+            let _ = self.client.cardano_database().add_statistics(&snapshot).await;
+
+            let message = MessageBuilder::new()
+                .compute_snapshot_message(&certificate, target_directory)
+                .await?;
+            if !certificate.match_message(&message) {
+                return Err(FfiError::verification_failed(anyhow!(
+                    "Certificate and computed message did not match for Cardano database '{digest}': certificate signed message: '{}', computed message: '{}'",
+                    certificate.signed_message,
+                    message.compute_hash()
+                )));
+            }
+
+            Ok::<(), FfiError>(())
+        })
+    }
+
+    fn verify_cardano_transactions(&self, transactions_hashes: &[&str]) -> FfiResult<String> {
+        self.runtime.block_on(async {
+            let proofs = self
+                .client
+                .cardano_transaction()
+                .get_proofs(transactions_hashes)
+                .await?;
+            let verified_transactions = proofs
+                .verify()
+                .map_err(|e| FfiError::verification_failed(anyhow!(e)))?;
+            let certificate = self
+                .client
+                .certificate()
+                .verify_chain(&proofs.certificate_hash)
+                .await
+                .map_err(FfiError::verification_failed)?;
+
+            let message = MessageBuilder::new()
+                .compute_cardano_transactions_proofs_message(&certificate, &verified_transactions);
+            if !certificate.match_message(&message) {
+                return Err(FfiError::verification_failed(anyhow!(
+                    "Proof and certificate don't match (certificate hash = '{}')",
+                    certificate.hash
+                )));
+            }
+
+            Ok::<String, FfiError>(to_json(&serde_json::json!({
+                "certificate_hash": certificate.hash,
+                "certified_transactions": verified_transactions.certified_transactions(),
+                "non_certified_transactions": proofs.non_certified_transactions,
+            }))?)
+        })
+    }
+}
+
+fn to_json<T: Serialize>(value: &T) -> MithrilResult<String> {
+    serde_json::to_string(value).with_context(|| "Serializing the result to JSON failed")
+}
+
+fn optional_to_json<T: Serialize>(value: Option<T>) -> MithrilResult<Option<String>> {
+    value.map(|v| to_json(&v)).transpose()
+}
+
+/// Borrow the client behind the given handle.
+///
+/// # Safety
+/// `client` must be null or a handle returned by [mithril_client_new] that has not been freed.
+unsafe fn client_from_ptr<'a>(client: *const MithrilClient) -> FfiResult<&'a MithrilClient> {
+    client
+        .as_ref()
+        .ok_or_else(|| FfiError::invalid_argument(anyhow!("'client' must not be null")))
+}
+
+/// Create a new Mithril client targeting the given aggregator.
+///
+/// - `aggregator_endpoint` and `genesis_verification_key` are mandatory.
+/// - `ancillary_verification_key` is optional (can be null), it is only needed to download the
+///   ancillary files of a Cardano database.
+/// - `feedback_callback` is optional (can be null), when set it receives the progress events of
+///   long-running tasks along with the given `user_data`.
+///
+/// On success the new client is written to `out_client` and must be released with
+/// [mithril_client_free].
+///
+/// # Safety
+/// - String arguments must be null or valid nul-terminated strings.
+/// - `user_data` must stay valid, and be usable from any thread, as long as the client lives.
+/// - `out_client` must be a valid pointer to write a `MithrilClient*` to.
+#[no_mangle]
+pub unsafe extern "C" fn mithril_client_new(
+    aggregator_endpoint: *const c_char,
+    genesis_verification_key: *const c_char,
+    ancillary_verification_key: *const c_char,
+    feedback_callback: MithrilFeedbackCallback,
+    user_data: *mut c_void,
+    out_client: *mut *mut MithrilClient,
+) -> MithrilStatus {
+    run_ffi(|| {
+        let aggregator_endpoint = str_from_ptr(aggregator_endpoint, "aggregator_endpoint")?;
+        let genesis_verification_key =
+            str_from_ptr(genesis_verification_key, "genesis_verification_key")?;
+        let ancillary_verification_key =
+            optional_str_from_ptr(ancillary_verification_key, "ancillary_verification_key")?;
+        if out_client.is_null() {
+            return Err(FfiError::invalid_argument(anyhow!(
+                "'out_client' must not be null"
+            )));
+        }
+
+        let client = MithrilClient::new(
+            aggregator_endpoint,
+            genesis_verification_key,
+            ancillary_verification_key,
+            feedback_callback.map(|callback| CallbackFeedbackReceiver::new(callback, user_data)),
+        )
+        .map_err(FfiError::invalid_argument)?;
+        *out_client = Box::into_raw(Box::new(client));
+
+        Ok(())
+    })
+}
+
+/// Release a client created with [mithril_client_new].
+///
+/// # Safety
+/// `client` must be null or a handle returned by [mithril_client_new] that has not already been
+/// freed.
+#[no_mangle]
+pub unsafe extern "C" fn mithril_client_free(client: *mut MithrilClient) {
+    if !client.is_null() {
+        drop(Box::from_raw(client));
+    }
+}
+
+/// List the latest artifacts of the given type, written as a JSON array to `out_json`.
+///
+/// The returned string must be released with [mithril_string_free][crate::mithril_string_free].
+///
+/// # Safety
+/// - `client` must be a valid handle returned by [mithril_client_new].
+/// - `artifact_type` must be one of the [MithrilArtifactType] values.
+/// - `out_json` must be a valid pointer to write a `char*` to.
+#[no_mangle]
+pub unsafe extern "C" fn mithril_client_list_artifacts(
+    client: *const MithrilClient,
+    artifact_type: u32,
+    out_json: *mut *mut c_char,
+) -> MithrilStatus {
+    run_ffi(|| {
+        let client = client_from_ptr(client)?;
+        let artifact_type = MithrilArtifactType::try_from(artifact_type)?;
+        let json = client.list_artifacts(artifact_type)?;
+
+        write_output_string(out_json, json)
+    })
+}
+
+/// Get the artifact of the given type and identifier, written as a JSON object to `out_json`.
+///
+/// Returns [MithrilStatus::NotFound] if the aggregator does not know the artifact.
+/// The returned string must be released with [mithril_string_free][crate::mithril_string_free].
+///
+/// # Safety
+/// - `client` must be a valid handle returned by [mithril_client_new].
+/// - `artifact_type` must be one of the [MithrilArtifactType] values.
+/// - `id` must be a valid nul-terminated string.
+/// - `out_json` must be a valid pointer to write a `char*` to.
+#[no_mangle]
+pub unsafe extern "C" fn mithril_client_get_artifact(
+    client: *const MithrilClient,
+    artifact_type: u32,
+    id: *const c_char,
+    out_json: *mut *mut c_char,
+) -> MithrilStatus {
+    run_ffi(|| {
+        let client = client_from_ptr(client)?;
+        let artifact_type = MithrilArtifactType::try_from(artifact_type)?;
+        let id = str_from_ptr(id, "id")?;
+        let json = client.get_artifact(artifact_type, id)?.ok_or_else(|| {
+            FfiError::not_found(anyhow!("No {artifact_type:?} artifact found for '{id}'"))
+        })?;
+
+        write_output_string(out_json, json)
+    })
+}
+
+/// Verify the certificate chain from the given certificate back to the genesis certificate.
+///
+/// On success, the verified certificate is written as a JSON object to `out_json`, it must be
+/// released with [mithril_string_free][crate::mithril_string_free].
+///
+/// # Safety
+/// - `client` must be a valid handle returned by [mithril_client_new].
+/// - `certificate_hash` must be a valid nul-terminated string.
+/// - `out_json` must be a valid pointer to write a `char*` to.
+#[no_mangle]
+pub unsafe extern "C" fn mithril_client_verify_certificate_chain(
+    client: *const MithrilClient,
+    certificate_hash: *const c_char,
+    out_json: *mut *mut c_char,
+) -> MithrilStatus {
+    run_ffi(|| {
+        let client = client_from_ptr(client)?;
+        let certificate_hash = str_from_ptr(certificate_hash, "certificate_hash")?;
+        let json = client
+            .verify_certificate_chain(certificate_hash)
+            .map_err(FfiError::verification_failed)?;
+
+        write_output_string(out_json, json)
+    })
+}
+
+/// Download, unpack and verify the Cardano database with the given digest to `target_directory`.
+///
+/// The target directory must already exist. Ancillary files are downloaded only if
+/// `include_ancillary` is true, in which case the client must have been created with an
+/// ancillary verification key.
+///
+/// Progress is reported through the feedback callback given to [mithril_client_new].
+///
+/// # Safety
+/// - `client` must be a valid handle returned by [mithril_client_new].
+/// - `digest` and `target_directory` must be valid nul-terminated strings.
+#[no_mangle]
+pub unsafe extern "C" fn mithril_client_download_cardano_database(
+    client: *const MithrilClient,
+    digest: *const c_char,
+    target_directory: *const c_char,
+    include_ancillary: bool,
+) -> MithrilStatus {
+    run_ffi(|| {
+        let client = client_from_ptr(client)?;
+        let digest = str_from_ptr(digest, "digest")?;
+        let target_directory = str_from_ptr(target_directory, "target_directory")?;
+
+        client.download_cardano_database(digest, Path::new(target_directory), include_ancillary)
+    })
+}
+
+/// Get and verify the proofs of inclusion of the given Cardano transactions in the certified
+/// Cardano transactions set.
+///
+/// On success, a JSON object with the `certificate_hash`, the `certified_transactions` and the
+/// `non_certified_transactions` is written to `out_json`, it must be released with
+/// [mithril_string_free][crate::mithril_string_free].
+///
+/// # Safety
+/// - `client` must be a valid handle returned by [mithril_client_new].
+/// - `transactions_hashes` must point to an array of `transactions_hashes_len` valid
+///   nul-terminated strings.
+/// - `out_json` must be a valid pointer to write a `char*` to.
+#[no_mangle]
+pub unsafe extern "C" fn mithril_client_verify_cardano_transactions(
+    client: *const MithrilClient,
+    transactions_hashes: *const *const c_char,
+    transactions_hashes_len: usize,
+    out_json: *mut *mut c_char,
+) -> MithrilStatus {
+    run_ffi(|| {
+        let client = client_from_ptr(client)?;
+        if transactions_hashes.is_null() || transactions_hashes_len == 0 {
+            return Err(FfiError::invalid_argument(anyhow!(
+                "'transactions_hashes' must contain at least one transaction hash"
+            )));
+        }
+        let hashes = std::slice::from_raw_parts(transactions_hashes, transactions_hashes_len)
+            .iter()
+            .map(|hash| str_from_ptr(*hash, "transactions_hashes"))
+            .collect::<FfiResult<Vec<&str>>>()?;
+        let json = client.verify_cardano_transactions(&hashes)?;
+
+        write_output_string(out_json, json)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use std::ffi::CString;
+    use std::ptr;
+
+    use super::*;
+
+    #[test]
+    fn new_client_fails_with_null_mandatory_arguments() {
+        let endpoint = CString::new("http://localhost:8000/aggregator").unwrap();
+        let mut out_client: *mut MithrilClient = ptr::null_mut();
+
+        let status = unsafe {
+            mithril_client_new(
+                endpoint.as_ptr(),
+                ptr::null(),
+                ptr::null(),
+                None,
+                ptr::null_mut(),
+                &mut out_client,
+            )
+        };
+
+        assert_eq!(MithrilStatus::InvalidArgument, status);
+        assert!(out_client.is_null());
+    }
+
+    #[test]
+    fn new_client_fails_with_invalid_genesis_verification_key() {
+        let endpoint = CString::new("http://localhost:8000/aggregator").unwrap();
+        let genesis_verification_key = CString::new("invalid").unwrap();
+        let mut out_client: *mut MithrilClient = ptr::null_mut();
+
+        let status = unsafe {
+            mithril_client_new(
+                endpoint.as_ptr(),
+                genesis_verification_key.as_ptr(),
+                ptr::null(),
+                None,
+                ptr::null_mut(),
+                &mut out_client,
+            )
+        };
+
+        assert_eq!(MithrilStatus::InvalidArgument, status);
+        assert!(out_client.is_null());
+    }
+
+    #[test]
+    fn functions_fail_with_null_client() {
+        let mut out_json: *mut c_char = ptr::null_mut();
+
+        let status = unsafe {
+            mithril_client_list_artifacts(
+                ptr::null(),
+                MithrilArtifactType::Certificate as u32,
+                &mut out_json,
+            )
+        };
+
+        assert_eq!(MithrilStatus::InvalidArgument, status);
+        assert!(out_json.is_null());
+    }
+
+    #[test]
+    fn artifact_type_from_known_values() {
+        for artifact_type in [
+            MithrilArtifactType::CardanoDatabase,
+            MithrilArtifactType::MithrilStakeDistribution,
+            MithrilArtifactType::CardanoTransactions,
+            MithrilArtifactType::CardanoStakeDistribution,
+            MithrilArtifactType::Certificate,
+        ] {
+            assert_eq!(
+                artifact_type,
+                MithrilArtifactType::try_from(artifact_type as u32).unwrap()
+            );
+        }
+    }
+
+    #[test]
+    fn artifact_type_from_unknown_value_is_an_invalid_argument() {
+        let status = run_ffi(|| MithrilArtifactType::try_from(42).map(|_| ()));
+
+        assert_eq!(MithrilStatus::InvalidArgument, status);
+    }
+
+    #[test]
+    fn free_null_client_does_nothing() {
+        unsafe { mithril_client_free(ptr::null_mut()) };
+    }
+}
@@ -0,0 +1,187 @@
+use std::cell::RefCell;
+use std::ffi::{c_char, CString};
+use std::panic::{catch_unwind, AssertUnwindSafe};
+
+use anyhow::anyhow;
+
+/// Status returned by every function of the library.
+///
+/// When a function returns anything else than [MithrilStatus::Ok], the details of the failure
+/// can be read with [mithril_last_error_message].
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MithrilStatus {
+    /// The call succeeded.
+    Ok = 0,
+    /// An argument is invalid (null pointer, invalid UTF-8, malformed value...).
+    InvalidArgument = 1,
+    /// The requested item does not exist on the aggregator.
+    NotFound = 2,
+    /// The Mithril client failed to fulfill the request.
+    ClientError = 3,
+    /// The retrieved data could not be verified against its certificate.
+    VerificationFailed = 4,
+    /// An unexpected internal error occurred.
+    InternalError = 5,
+}
+
+/// Error raised by the functions of the library, mapped to a [MithrilStatus] at the boundary.
+#[derive(Debug)]
+pub(crate) struct FfiError {
+    status: MithrilStatus,
+    error: anyhow::Error,
+}
+
+impl FfiError {
+    pub(crate) fn invalid_argument(error: anyhow::Error) -> Self {
+        Self {
+            status: MithrilStatus::InvalidArgument,
+            error,
+        }
+    }
+
+    pub(crate) fn not_found(error: anyhow::Error) -> Self {
+        Self {
+            status: MithrilStatus::NotFound,
+            error,
+        }
+    }
+
+    pub(crate) fn verification_failed(error: anyhow::Error) -> Self {
+        Self {
+            status: MithrilStatus::VerificationFailed,
+            error,
+        }
+    }
+}
+
+impl From<anyhow::Error> for FfiError {
+    fn from(error: anyhow::Error) -> Self {
+        Self {
+            status: MithrilStatus::ClientError,
+            error,
+        }
+    }
+}
+
+pub(crate) type FfiResult<T> = Result<T, FfiError>;
+
+thread_local! {
+    static LAST_ERROR: RefCell<Option<CString>> = const { RefCell::new(None) };
+}
+
+fn set_last_error(message: String) {
+    let message =
+        CString::new(message.replace('\0', "\\0")).expect("interior nul bytes have been escaped");
+    LAST_ERROR.with(|last_error| *last_error.borrow_mut() = Some(message));
+}
+
+fn clear_last_error() {
+    LAST_ERROR.with(|last_error| *last_error.borrow_mut() = None);
+}
+
+/// Run the given function, converting its result to a [MithrilStatus] and recording the error
+/// message, if any, so it can be read with [mithril_last_error_message].
+///
+/// Panics are caught so they never unwind across the FFI boundary, the library state touched by
+/// a panicking call must not be relied upon afterward.
+pub(crate) fn run_ffi<F>(f: F) -> MithrilStatus
+where
+    F: FnOnce() -> FfiResult<()>,
+{
+    clear_last_error();
+
+    match catch_unwind(AssertUnwindSafe(f)) {
+        Ok(Ok(())) => MithrilStatus::Ok,
+        Ok(Err(FfiError { status, error })) => {
+            set_last_error(format!("{error:?}"));
+            status
+        }
+        Err(panic) => {
+            let reason = panic
+                .downcast_ref::<&str>()
+                .map(|s| s.to_string())
+                .or_else(|| panic.downcast_ref::<String>().cloned())
+                .unwrap_or_else(|| "unknown panic".to_string());
+            set_last_error(format!("{:?}", anyhow!("Internal panic: {reason}")));
+            MithrilStatus::InternalError
+        }
+    }
+}
+
+/// Get the message of the last error that occurred on the calling thread.
+///
+/// Returns a null pointer if the last call succeeded. The returned string is owned by the
+/// library and is valid until the next call to a function of the library on the same thread,
+/// it must **not** be freed by the caller.
+#[no_mangle]
+pub extern "C" fn mithril_last_error_message() -> *const c_char {
+    LAST_ERROR.with(|last_error| {
+        last_error
+            .borrow()
+            .as_ref()
+            .map(|message| message.as_ptr())
+            .unwrap_or(std::ptr::null())
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use std::ffi::CStr;
+
+    use super::*;
+
+    fn last_error_message() -> Option<String> {
+        let message = mithril_last_error_message();
+        if message.is_null() {
+            None
+        } else {
+            Some(
+                unsafe { CStr::from_ptr(message) }
+                    .to_string_lossy()
+                    .to_string(),
+            )
+        }
+    }
+
+    #[test]
+    fn run_ffi_returns_ok_status_and_clears_last_error() {
+        set_last_error("previous error".to_string());
+
+        let status = run_ffi(|| Ok(()));
+
+        assert_eq!(MithrilStatus::Ok, status);
+        assert_eq!(None, last_error_message());
+    }
+
+    #[test]
+    fn run_ffi_returns_error_status_and_records_last_error() {
+        let status = run_ffi(|| Err(FfiError::not_found(anyhow!("item not found"))));
+
+        assert_eq!(MithrilStatus::NotFound, status);
+        assert!(last_error_message().unwrap().contains("item not found"));
+    }
+
+    #[test]
+    fn run_ffi_maps_anyhow_errors_to_client_error_status() {
+        let status = run_ffi(|| Err(anyhow!("client failure").into()));
+
+        assert_eq!(MithrilStatus::ClientError, status);
+    }
+
+    #[test]
+    fn run_ffi_catches_panics() {
+        let status = run_ffi(|| panic!("boom"));
+
+        assert_eq!(MithrilStatus::InternalError, status);
+        assert!(last_error_message().unwrap().contains("boom"));
+    }
+
+    #[test]
+    fn last_error_message_escapes_nul_bytes() {
+        let status = run_ffi(|| Err(FfiError::invalid_argument(anyhow!("nul\0byte"))));
+
+        assert_eq!(MithrilStatus::InvalidArgument, status);
+        assert!(last_error_message().unwrap().contains("nul\\0byte"));
+    }
+}
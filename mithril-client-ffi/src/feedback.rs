@@ -0,0 +1,96 @@
+use async_trait::async_trait;
+use std::ffi::{c_char, c_void, CString};
+
+use mithril_client::feedback::{FeedbackReceiver, MithrilEvent};
+
+/// Callback receiving the progress events of long-running tasks (downloads, certificate chain
+/// validations).
+///
+/// `event_json` is a JSON object with a `type` field naming the event and a `payload` field
+/// holding its data. It is only valid for the duration of the call.
+///
+/// The callback can be invoked from any thread of the library runtime.
+pub type MithrilFeedbackCallback =
+    Option<unsafe extern "C" fn(event_json: *const c_char, user_data: *mut c_void)>;
+
+/// Opaque pointer given back to the caller on each callback invocation.
+struct UserData(*mut c_void);
+
+// SAFETY: the caller is responsible for providing a user data that can be used from any thread,
+// this requirement is documented on `mithril_client_new`.
+unsafe impl Send for UserData {}
+unsafe impl Sync for UserData {}
+
+/// A [FeedbackReceiver] that forwards the events, serialized to JSON, to a C callback.
+pub(crate) struct CallbackFeedbackReceiver {
+    callback: unsafe extern "C" fn(*const c_char, *mut c_void),
+    user_data: UserData,
+}
+
+impl CallbackFeedbackReceiver {
+    pub(crate) fn new(
+        callback: unsafe extern "C" fn(*const c_char, *mut c_void),
+        user_data: *mut c_void,
+    ) -> Self {
+        Self {
+            callback,
+            user_data: UserData(user_data),
+        }
+    }
+
+    fn serialize_event(event: MithrilEvent) -> Option<CString> {
+        let json = serde_json::json!({
+            "type": event.to_string(),
+            "payload": event,
+        });
+
+        CString::new(json.to_string()).ok()
+    }
+}
+
+#[async_trait]
+impl FeedbackReceiver for CallbackFeedbackReceiver {
+    async fn handle_event(&self, event: MithrilEvent) {
+        if let Some(event_json) = Self::serialize_event(event) {
+            // SAFETY: the callback validity is guaranteed by the caller of `mithril_client_new`.
+            unsafe { (self.callback)(event_json.as_ptr(), self.user_data.0) };
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::ffi::CStr;
+    use std::sync::Mutex;
+
+    use super::*;
+
+    unsafe extern "C" fn record_event(event_json: *const c_char, user_data: *mut c_void) {
+        let events = &*(user_data as *const Mutex<Vec<String>>);
+        events
+            .lock()
+            .unwrap()
+            .push(CStr::from_ptr(event_json).to_string_lossy().to_string());
+    }
+
+    #[tokio::test]
+    async fn forward_events_as_json_to_the_callback() {
+        let events: Mutex<Vec<String>> = Mutex::new(vec![]);
+        let receiver = CallbackFeedbackReceiver::new(
+            record_event,
+            &events as *const Mutex<Vec<String>> as *mut c_void,
+        );
+
+        receiver
+            .handle_event(MithrilEvent::SnapshotDownloadCompleted {
+                download_id: "download-id".to_string(),
+            })
+            .await;
+
+        let events = events.into_inner().unwrap();
+        assert_eq!(1, events.len());
+        let event: serde_json::Value = serde_json::from_str(&events[0]).unwrap();
+        assert_eq!("SnapshotDownloadCompleted", event["type"]);
+        assert_eq!("download-id", event["payload"]["download_id"]);
+    }
+}
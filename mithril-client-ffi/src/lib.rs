@@ -0,0 +1,30 @@
+#![warn(missing_docs)]
+//! C-compatible FFI for the `mithril-client` library.
+//!
+//! This crate exposes a stable C ABI on top of the [mithril_client] library so it can be used
+//! from any language able to call C functions (Go, C#, Python...). The matching C header is
+//! available in the `include` folder of the crate.
+//!
+//! # Conventions
+//!
+//! - Every function returns a [MithrilStatus], the details of a failure can be read with
+//!   [mithril_last_error_message].
+//! - Strings given to the library are nul-terminated UTF-8 strings owned by the caller.
+//! - Strings returned by the library (JSON documents) are owned by the caller and must be
+//!   released with [mithril_string_free].
+//! - A client handle is created with [mithril_client_new] and released with
+//!   [mithril_client_free]. All its functions are blocking.
+
+mod client;
+mod error;
+mod feedback;
+mod strings;
+
+pub use client::{
+    mithril_client_download_cardano_database, mithril_client_free, mithril_client_get_artifact,
+    mithril_client_list_artifacts, mithril_client_new, mithril_client_verify_cardano_transactions,
+    mithril_client_verify_certificate_chain, MithrilArtifactType, MithrilClient,
+};
+pub use error::{mithril_last_error_message, MithrilStatus};
+pub use feedback::MithrilFeedbackCallback;
+pub use strings::mithril_string_free;
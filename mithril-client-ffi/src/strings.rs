@@ -0,0 +1,105 @@
+use std::ffi::{c_char, CStr, CString};
+
+use anyhow::{anyhow, Context};
+
+use crate::error::{FfiError, FfiResult};
+
+/// Read a mandatory UTF-8 string argument given by the caller.
+///
+/// # Safety
+/// `ptr` must be null or point to a valid nul-terminated string that outlives the returned value.
+pub(crate) unsafe fn str_from_ptr<'a>(ptr: *const c_char, name: &str) -> FfiResult<&'a str> {
+    optional_str_from_ptr(ptr, name)?
+        .ok_or_else(|| FfiError::invalid_argument(anyhow!("'{name}' must not be null")))
+}
+
+/// Read an optional UTF-8 string argument given by the caller, a null pointer meaning `None`.
+///
+/// # Safety
+/// `ptr` must be null or point to a valid nul-terminated string that outlives the returned value.
+pub(crate) unsafe fn optional_str_from_ptr<'a>(
+    ptr: *const c_char,
+    name: &str,
+) -> FfiResult<Option<&'a str>> {
+    if ptr.is_null() {
+        return Ok(None);
+    }
+
+    CStr::from_ptr(ptr)
+        .to_str()
+        .map(Some)
+        .with_context(|| format!("'{name}' is not a valid UTF-8 string"))
+        .map_err(FfiError::invalid_argument)
+}
+
+/// Hand over the ownership of the given string to the caller through the `out` pointer.
+///
+/// The caller must release it with [mithril_string_free].
+///
+/// # Safety
+/// `out` must be null or a valid pointer to write a `*mut c_char` to.
+pub(crate) unsafe fn write_output_string(out: *mut *mut c_char, value: String) -> FfiResult<()> {
+    if out.is_null() {
+        return Err(FfiError::invalid_argument(anyhow!(
+            "the output pointer must not be null"
+        )));
+    }
+    let value = CString::new(value).with_context(|| "output string contains a nul byte")?;
+    *out = value.into_raw();
+
+    Ok(())
+}
+
+/// Release a string allocated by the library.
+///
+/// # Safety
+/// `value` must be null or a string returned by a function of this library that has not already
+/// been freed.
+#[no_mangle]
+pub unsafe extern "C" fn mithril_string_free(value: *mut c_char) {
+    if !value.is_null() {
+        drop(CString::from_raw(value));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::ptr;
+
+    use super::*;
+
+    #[test]
+    fn str_from_ptr_fails_with_null_pointer() {
+        unsafe { str_from_ptr(ptr::null(), "arg") }.expect_err("Null pointer should fail");
+    }
+
+    #[test]
+    fn str_from_ptr_fails_with_invalid_utf8() {
+        let invalid = CString::new(vec![0xff, 0xfe]).unwrap();
+
+        unsafe { str_from_ptr(invalid.as_ptr(), "arg") }.expect_err("Invalid UTF-8 should fail");
+    }
+
+    #[test]
+    fn optional_str_from_ptr_returns_none_with_null_pointer() {
+        let value = unsafe { optional_str_from_ptr(ptr::null(), "arg") }.unwrap();
+
+        assert_eq!(None, value);
+    }
+
+    #[test]
+    fn write_output_string_then_free_it() {
+        let mut out: *mut c_char = ptr::null_mut();
+
+        unsafe { write_output_string(&mut out, "value".to_string()) }.unwrap();
+
+        assert_eq!("value", unsafe { CStr::from_ptr(out) }.to_str().unwrap());
+        unsafe { mithril_string_free(out) };
+    }
+
+    #[test]
+    fn write_output_string_fails_with_null_output_pointer() {
+        unsafe { write_output_string(ptr::null_mut(), "value".to_string()) }
+            .expect_err("Null output pointer should fail");
+    }
+}
@@ -0,0 +1,262 @@
+//! Drive the C ABI of the library against a `mithril-aggregator-fake` listening on port 8000.
+//!
+//! Run them with `make test`, which takes care of starting the fake aggregator.
+
+#[allow(dead_code)]
+mod test_data {
+    include!(concat!(env!("OUT_DIR"), "/imported_data.rs"));
+}
+
+use std::ffi::{c_char, c_void, CStr, CString};
+use std::ptr;
+use std::sync::Mutex;
+
+use mithril_client::{
+    CardanoStakeDistribution, MithrilCertificate, MithrilCertificateListItem,
+    MithrilStakeDistribution,
+};
+use mithril_client_ffi::*;
+
+const GENESIS_VERIFICATION_KEY: &str = "5b33322c3235332c3138362c3230312c3137372c31312c3131372c3133352c3138372c3136372c3138312c3138382c32322c35392c3230362c3130352c3233312c3135302c3231352c33302c37382c3231322c37362c31362c3235322c3138302c37322c3133342c3133372c3234372c3136312c36385d";
+const FAKE_AGGREGATOR_ENDPOINT: &str = "http://127.0.0.1:8000/aggregator";
+
+struct TestClient {
+    handle: *mut MithrilClient,
+    // Kept alive as long as the client since it's given as the callback user data
+    events: Box<Mutex<Vec<String>>>,
+}
+
+impl TestClient {
+    fn new() -> Self {
+        let endpoint = CString::new(FAKE_AGGREGATOR_ENDPOINT).unwrap();
+        let genesis_verification_key = CString::new(GENESIS_VERIFICATION_KEY).unwrap();
+        let events = Box::new(Mutex::new(vec![]));
+        let mut handle: *mut MithrilClient = ptr::null_mut();
+
+        let status = unsafe {
+            mithril_client_new(
+                endpoint.as_ptr(),
+                genesis_verification_key.as_ptr(),
+                ptr::null(),
+                Some(record_event),
+                events.as_ref() as *const Mutex<Vec<String>> as *mut c_void,
+                &mut handle,
+            )
+        };
+        assert_eq!(MithrilStatus::Ok, status, "{}", last_error());
+
+        Self { handle, events }
+    }
+
+    fn list(&self, artifact_type: MithrilArtifactType) -> String {
+        let mut out_json: *mut c_char = ptr::null_mut();
+        let status = unsafe {
+            mithril_client_list_artifacts(self.handle, artifact_type as u32, &mut out_json)
+        };
+        assert_eq!(MithrilStatus::Ok, status, "{}", last_error());
+
+        take_string(out_json)
+    }
+
+    fn get(&self, artifact_type: MithrilArtifactType, id: &str) -> (MithrilStatus, String) {
+        let id = CString::new(id).unwrap();
+        let mut out_json: *mut c_char = ptr::null_mut();
+        let status = unsafe {
+            mithril_client_get_artifact(
+                self.handle,
+                artifact_type as u32,
+                id.as_ptr(),
+                &mut out_json,
+            )
+        };
+
+        (status, take_string(out_json))
+    }
+
+    fn verify_certificate_chain(&self, certificate_hash: &str) -> (MithrilStatus, String) {
+        let certificate_hash = CString::new(certificate_hash).unwrap();
+        let mut out_json: *mut c_char = ptr::null_mut();
+        let status = unsafe {
+            mithril_client_verify_certificate_chain(
+                self.handle,
+                certificate_hash.as_ptr(),
+                &mut out_json,
+            )
+        };
+
+        (status, take_string(out_json))
+    }
+
+    fn received_events(&self) -> Vec<String> {
+        self.events.lock().unwrap().clone()
+    }
+}
+
+impl Drop for TestClient {
+    fn drop(&mut self) {
+        unsafe { mithril_client_free(self.handle) };
+    }
+}
+
+unsafe extern "C" fn record_event(event_json: *const c_char, user_data: *mut c_void) {
+    let events = &*(user_data as *const Mutex<Vec<String>>);
+    events
+        .lock()
+        .unwrap()
+        .push(CStr::from_ptr(event_json).to_string_lossy().to_string());
+}
+
+fn take_string(value: *mut c_char) -> String {
+    if value.is_null() {
+        return String::new();
+    }
+    let result = unsafe { CStr::from_ptr(value) }
+        .to_string_lossy()
+        .to_string();
+    unsafe { mithril_string_free(value) };
+
+    result
+}
+
+fn last_error() -> String {
+    let message = mithril_last_error_message();
+    if message.is_null() {
+        String::new()
+    } else {
+        unsafe { CStr::from_ptr(message) }
+            .to_string_lossy()
+            .to_string()
+    }
+}
+
+#[test]
+fn list_certificates() {
+    let client = TestClient::new();
+
+    let json = client.list(MithrilArtifactType::Certificate);
+    let certificates: Vec<MithrilCertificateListItem> = serde_json::from_str(&json).unwrap();
+
+    assert_eq!(
+        certificates.len(),
+        // Aggregator return up to 20 items for a list route
+        test_data::certificate_hashes().len().min(20)
+    );
+}
+
+#[test]
+fn list_every_artifact_type() {
+    let client = TestClient::new();
+
+    for artifact_type in [
+        MithrilArtifactType::CardanoDatabase,
+        MithrilArtifactType::MithrilStakeDistribution,
+        MithrilArtifactType::CardanoTransactions,
+        MithrilArtifactType::CardanoStakeDistribution,
+        MithrilArtifactType::Certificate,
+    ] {
+        let json = client.list(artifact_type);
+        let list: Vec<serde_json::Value> = serde_json::from_str(&json).unwrap();
+
+        assert!(
+            !list.is_empty(),
+            "{artifact_type:?} list should not be empty"
+        );
+    }
+}
+
+#[test]
+fn get_mithril_stake_distribution() {
+    let client = TestClient::new();
+    let hash = test_data::mithril_stake_distribution_hashes()[0];
+
+    let (status, json) = client.get(MithrilArtifactType::MithrilStakeDistribution, hash);
+
+    assert_eq!(MithrilStatus::Ok, status, "{}", last_error());
+    let msd: MithrilStakeDistribution = serde_json::from_str(&json).unwrap();
+    assert_eq!(hash, msd.hash);
+}
+
+#[test]
+fn get_cardano_stake_distribution() {
+    let client = TestClient::new();
+    let hash = test_data::cardano_stake_distribution_hashes()[0];
+
+    let (status, json) = client.get(MithrilArtifactType::CardanoStakeDistribution, hash);
+
+    assert_eq!(MithrilStatus::Ok, status, "{}", last_error());
+    let csd: CardanoStakeDistribution = serde_json::from_str(&json).unwrap();
+    assert_eq!(hash, csd.hash);
+}
+
+#[test]
+fn get_unknown_artifact_returns_not_found_status() {
+    let client = TestClient::new();
+
+    let (status, json) = client.get(MithrilArtifactType::Certificate, "whatever");
+
+    assert_eq!(MithrilStatus::NotFound, status);
+    assert!(json.is_empty());
+    assert!(!last_error().is_empty());
+}
+
+#[test]
+fn verify_certificate_chain_and_receive_feedback_events() {
+    let client = TestClient::new();
+    let (_, msd_json) = client.get(
+        MithrilArtifactType::MithrilStakeDistribution,
+        test_data::mithril_stake_distribution_hashes()[0],
+    );
+    let msd: MithrilStakeDistribution = serde_json::from_str(&msd_json).unwrap();
+
+    let (status, json) = client.verify_certificate_chain(&msd.certificate_hash);
+
+    assert_eq!(MithrilStatus::Ok, status, "{}", last_error());
+    let certificate: MithrilCertificate = serde_json::from_str(&json).unwrap();
+    assert_eq!(msd.certificate_hash, certificate.hash);
+    assert!(client
+        .received_events()
+        .iter()
+        .any(|event| event.contains("CertificateChainValidationStarted")));
+}
+
+#[test]
+fn verify_cardano_transactions() {
+    let client = TestClient::new();
+    let transaction_hash = CString::new(test_data::proof_transaction_hashes()[0]).unwrap();
+    let transactions_hashes = [transaction_hash.as_ptr()];
+    let mut out_json: *mut c_char = ptr::null_mut();
+
+    let status = unsafe {
+        mithril_client_verify_cardano_transactions(
+            client.handle,
+            transactions_hashes.as_ptr(),
+            transactions_hashes.len(),
+            &mut out_json,
+        )
+    };
+
+    assert_eq!(MithrilStatus::Ok, status, "{}", last_error());
+    let result: serde_json::Value = serde_json::from_str(&take_string(out_json)).unwrap();
+    assert_eq!(
+        serde_json::json!([test_data::proof_transaction_hashes()[0]]),
+        result["certified_transactions"]
+    );
+}
+
+#[test]
+fn download_unknown_cardano_database_returns_not_found_status() {
+    let client = TestClient::new();
+    let digest = CString::new("whatever").unwrap();
+    let target_directory = CString::new(std::env::temp_dir().to_string_lossy().as_ref()).unwrap();
+
+    let status = unsafe {
+        mithril_client_download_cardano_database(
+            client.handle,
+            digest.as_ptr(),
+            target_directory.as_ptr(),
+            false,
+        )
+    };
+
+    assert_eq!(MithrilStatus::NotFound, status);
+}
@@ -6,6 +6,9 @@
 //! [AggregatorRequest] enum.
 //!
 //! An implementation using HTTP is available: [AggregatorHTTPClient].
+//!
+//! To spread requests over several aggregators, see
+//! [MultiAggregatorClient][crate::multi_aggregator_client::MultiAggregatorClient].
 
 use anyhow::{anyhow, Context};
 use async_recursion::async_recursion;
@@ -25,7 +28,7 @@ use mithril_common::logging::LoggerExtensions;
 use mithril_common::messages::CardanoDatabaseImmutableFilesRestoredMessage;
 use mithril_common::MITHRIL_API_VERSION_HEADER;
 
-use crate::common::Epoch;
+use crate::common::{BlockNumber, Epoch, MetadataLabel};
 use crate::{MithrilError, MithrilResult};
 
 /// Error tied with the Aggregator client
@@ -46,6 +49,10 @@ pub enum AggregatorClientError {
     /// HTTP subsystem error
     #[error("HTTP subsystem error")]
     SubsystemError(#[source] MithrilError),
+
+    /// Error raised when several aggregators did not agree on a certificate.
+    #[error("Certificate cross-check between aggregators failed")]
+    CertificateCrossCheckFailed(#[source] MithrilError),
 }
 
 /// What can be read from an [AggregatorClient].
@@ -121,6 +128,25 @@ pub enum AggregatorRequest {
         transactions_hashes: Vec<String>,
     },
 
+    /// Search the Cardano transactions matching an address or a metadata label in a block numbers
+    /// interval, with a proof of all the transactions of the certified block ranges
+    SearchTransactionsProofs {
+        /// Address of an output of the transactions to search
+        address: Option<String>,
+        /// Label of a metadata entry of the transactions to search
+        metadata_label: Option<MetadataLabel>,
+        /// Start of the searched block numbers interval (inclusive)
+        from_block_number: BlockNumber,
+        /// End of the searched block numbers interval (exclusive)
+        to_block_number: BlockNumber,
+    },
+
+    /// Get proofs that the given set of Cardano blocks is included in the global Cardano blocks set
+    GetBlocksProofs {
+        /// Hashes of the blocks to get proofs for.
+        blocks_hashes: Vec<String>,
+    },
+
     /// Get a specific [Cardano transaction snapshot][crate::CardanoTransactionSnapshot]
     GetCardanoTransactionSnapshot {
         /// Hash of the Cardano transaction snapshot to retrieve
@@ -144,6 +170,21 @@ pub enum AggregatorRequest {
 
     /// Lists the aggregator [Cardano stake distribution][crate::CardanoStakeDistribution]
     ListCardanoStakeDistributions,
+
+    /// Get specific [Cardano protocol parameters][crate::CardanoProtocolParameters] from the aggregator by hash
+    GetCardanoProtocolParameters {
+        /// Hash of the Cardano protocol parameters to retrieve
+        hash: String,
+    },
+
+    /// Get specific [Cardano protocol parameters][crate::CardanoProtocolParameters] from the aggregator by epoch
+    GetCardanoProtocolParametersByEpoch {
+        /// Epoch during which the Cardano protocol parameters are in effect
+        epoch: Epoch,
+    },
+
+    /// Lists the aggregator [Cardano protocol parameters][crate::CardanoProtocolParameters]
+    ListCardanoProtocolParameters,
 }
 
 impl AggregatorRequest {
@@ -197,6 +238,25 @@ impl AggregatorRequest {
                 "proof/cardano-transaction?transaction_hashes={}",
                 transactions_hashes.join(",")
             ),
+            AggregatorRequest::SearchTransactionsProofs {
+                address,
+                metadata_label,
+                from_block_number,
+                to_block_number,
+            } => {
+                let filter = match (address, metadata_label) {
+                    (Some(address), _) => format!("address={address}&"),
+                    (None, Some(metadata_label)) => format!("metadata_label={metadata_label}&"),
+                    (None, None) => String::new(),
+                };
+                format!(
+                    "proof/cardano-transaction/search?{filter}from_block_number={from_block_number}&to_block_number={to_block_number}"
+                )
+            }
+            AggregatorRequest::GetBlocksProofs { blocks_hashes } => format!(
+                "proof/cardano-block?block_hashes={}",
+                blocks_hashes.join(",")
+            ),
             AggregatorRequest::GetCardanoTransactionSnapshot { hash } => {
                 format!("artifact/cardano-transaction/{hash}")
             }
@@ -212,6 +272,15 @@ impl AggregatorRequest {
             AggregatorRequest::ListCardanoStakeDistributions => {
                 "artifact/cardano-stake-distributions".to_string()
             }
+            AggregatorRequest::GetCardanoProtocolParameters { hash } => {
+                format!("artifact/cardano-protocol-parameters/{hash}")
+            }
+            AggregatorRequest::GetCardanoProtocolParametersByEpoch { epoch } => {
+                format!("artifact/cardano-protocol-parameters/epoch/{epoch}")
+            }
+            AggregatorRequest::ListCardanoProtocolParameters => {
+                "artifact/cardano-protocol-parameters".to_string()
+            }
         }
     }
 
@@ -697,6 +766,36 @@ mod tests {
             .route()
         );
 
+        assert_eq!(
+            "proof/cardano-transaction/search?address=addr_test1xyz&from_block_number=15&to_block_number=45".to_string(),
+            AggregatorRequest::SearchTransactionsProofs {
+                address: Some("addr_test1xyz".to_string()),
+                metadata_label: None,
+                from_block_number: BlockNumber(15),
+                to_block_number: BlockNumber(45),
+            }
+            .route()
+        );
+
+        assert_eq!(
+            "proof/cardano-transaction/search?metadata_label=674&from_block_number=15&to_block_number=45".to_string(),
+            AggregatorRequest::SearchTransactionsProofs {
+                address: None,
+                metadata_label: Some(674),
+                from_block_number: BlockNumber(15),
+                to_block_number: BlockNumber(45),
+            }
+            .route()
+        );
+
+        assert_eq!(
+            "proof/cardano-block?block_hashes=abc,def".to_string(),
+            AggregatorRequest::GetBlocksProofs {
+                blocks_hashes: vec!["abc".to_string(), "def".to_string()]
+            }
+            .route()
+        );
+
         assert_eq!(
             "artifact/cardano-transaction/abc".to_string(),
             AggregatorRequest::GetCardanoTransactionSnapshot {
@@ -727,6 +826,24 @@ mod tests {
             "artifact/cardano-stake-distributions".to_string(),
             AggregatorRequest::ListCardanoStakeDistributions.route()
         );
+
+        assert_eq!(
+            "artifact/cardano-protocol-parameters/abc".to_string(),
+            AggregatorRequest::GetCardanoProtocolParameters {
+                hash: "abc".to_string()
+            }
+            .route()
+        );
+
+        assert_eq!(
+            "artifact/cardano-protocol-parameters/epoch/123".to_string(),
+            AggregatorRequest::GetCardanoProtocolParametersByEpoch { epoch: Epoch(123) }.route()
+        );
+
+        assert_eq!(
+            "artifact/cardano-protocol-parameters".to_string(),
+            AggregatorRequest::ListCardanoProtocolParameters.route()
+        );
     }
 
     #[test]
@@ -0,0 +1,105 @@
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+/// Control the number of files downloaded in parallel.
+///
+/// When adaptive, the limit follows an additive increase / multiplicative decrease strategy:
+/// it is halved each time a download from a location fails (including on timeout) and
+/// increased by one each time a download succeeds, never exceeding the maximum number of
+/// parallel downloads.
+pub struct ConcurrencyController {
+    max_parallel_downloads: usize,
+    adaptive: bool,
+    current_limit: AtomicUsize,
+}
+
+impl ConcurrencyController {
+    /// Constructs a new `ConcurrencyController`, starting at `max_parallel_downloads`.
+    pub fn new(max_parallel_downloads: usize, adaptive: bool) -> Self {
+        Self {
+            max_parallel_downloads,
+            adaptive,
+            current_limit: AtomicUsize::new(max_parallel_downloads),
+        }
+    }
+
+    /// Current number of downloads allowed to run in parallel.
+    pub fn limit(&self) -> usize {
+        self.current_limit.load(Ordering::Relaxed)
+    }
+
+    /// Record that a file was successfully downloaded.
+    pub fn on_download_success(&self) {
+        if self.adaptive {
+            let max_parallel_downloads = self.max_parallel_downloads;
+            self.update_limit(|limit| (limit + 1).min(max_parallel_downloads));
+        }
+    }
+
+    /// Record that a file could not be downloaded from a location.
+    pub fn on_download_failure(&self) {
+        if self.adaptive {
+            self.update_limit(|limit| (limit / 2).max(1));
+        }
+    }
+
+    fn update_limit<F: Fn(usize) -> usize>(&self, update: F) {
+        // The closure always returns `Some`, so the update can not fail
+        let _ = self
+            .current_limit
+            .fetch_update(Ordering::Relaxed, Ordering::Relaxed, |limit| {
+                Some(update(limit))
+            });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn limit_starts_at_max_parallel_downloads() {
+        let controller = ConcurrencyController::new(10, true);
+
+        assert_eq!(10, controller.limit());
+    }
+
+    #[test]
+    fn limit_never_changes_when_not_adaptive() {
+        let controller = ConcurrencyController::new(10, false);
+
+        controller.on_download_failure();
+        assert_eq!(10, controller.limit());
+
+        controller.on_download_success();
+        assert_eq!(10, controller.limit());
+    }
+
+    #[test]
+    fn limit_is_halved_on_failure_down_to_one() {
+        let controller = ConcurrencyController::new(10, true);
+
+        controller.on_download_failure();
+        assert_eq!(5, controller.limit());
+
+        controller.on_download_failure();
+        assert_eq!(2, controller.limit());
+
+        controller.on_download_failure();
+        controller.on_download_failure();
+        assert_eq!(1, controller.limit());
+    }
+
+    #[test]
+    fn limit_is_increased_by_one_on_success_up_to_max_parallel_downloads() {
+        let controller = ConcurrencyController::new(4, true);
+        controller.on_download_failure();
+        assert_eq!(2, controller.limit());
+
+        controller.on_download_success();
+        assert_eq!(3, controller.limit());
+
+        controller.on_download_success();
+        controller.on_download_success();
+        assert_eq!(4, controller.limit());
+    }
+}
@@ -14,8 +14,10 @@ use crate::{
     MithrilResult,
 };
 
+use super::concurrency_controller::ConcurrencyController;
+
 /// The future type for downloading a file
-type DownloadFuture = dyn Future<Output = MithrilResult<()>> + Send;
+pub type DownloadFuture = dyn Future<Output = MithrilResult<()>> + Send;
 
 /// A task to download and unpack a file
 pub struct DownloadTask {
@@ -44,7 +46,12 @@ impl DownloadTask {
         }
     }
 
-    async fn download_unpack_file(&self, target_dir: &Path, logger: &Logger) -> MithrilResult<()> {
+    async fn download_unpack_file(
+        &self,
+        target_dir: &Path,
+        concurrency_controller: Option<&ConcurrencyController>,
+        logger: &Logger,
+    ) -> MithrilResult<()> {
         let mut download_succeeded = false;
 
         for location_to_try in &self.locations_to_try {
@@ -66,7 +73,13 @@ impl DownloadTask {
                     self.name(), location_to_try.file_downloader_uri;
                     "error" => ?e
                 );
+                if let Some(concurrency_controller) = concurrency_controller {
+                    concurrency_controller.on_download_failure();
+                }
             } else {
+                if let Some(concurrency_controller) = concurrency_controller {
+                    concurrency_controller.on_download_success();
+                }
                 download_succeeded = true;
                 break;
             }
@@ -88,9 +101,10 @@ impl DownloadTask {
         ancillary_files_temp_dir: &Path,
         target_dir: &Path,
         ancillary_verifier: &Arc<AncillaryVerifier>,
+        concurrency_controller: Option<&ConcurrencyController>,
         logger: &Logger,
     ) -> MithrilResult<()> {
-        self.download_unpack_file(ancillary_files_temp_dir, logger)
+        self.download_unpack_file(ancillary_files_temp_dir, concurrency_controller, logger)
             .await?;
         let validated_manifest = ancillary_verifier.verify(ancillary_files_temp_dir).await?;
         validated_manifest.move_to_final_location(target_dir).await
@@ -101,10 +115,30 @@ impl DownloadTask {
     /// The download is attempted for each location until the file is downloaded.
     /// If all locations fail, an error is returned.
     pub fn build_download_future(self, logger: Logger) -> Pin<Box<DownloadFuture>> {
+        self.build_future(None, logger)
+    }
+
+    /// Build a future that will download and unpack the file when awaited, reporting the
+    /// failure or success of each location to the given [ConcurrencyController].
+    pub fn build_download_future_with_concurrency_controller(
+        self,
+        concurrency_controller: Arc<ConcurrencyController>,
+        logger: Logger,
+    ) -> Pin<Box<DownloadFuture>> {
+        self.build_future(Some(concurrency_controller), logger)
+    }
+
+    fn build_future(
+        self,
+        concurrency_controller: Option<Arc<ConcurrencyController>>,
+        logger: Logger,
+    ) -> Pin<Box<DownloadFuture>> {
         let download_future = async move {
+            let concurrency_controller = concurrency_controller.as_deref();
             match &self.kind {
                 DownloadKind::Immutable(..) => {
-                    self.download_unpack_file(&self.target_dir, &logger).await
+                    self.download_unpack_file(&self.target_dir, concurrency_controller, &logger)
+                        .await
                 }
                 DownloadKind::Ancillary { verifier } => {
                     let ancillary_files_temp_dir = temp_ancillary_target_dir(
@@ -125,6 +159,7 @@ impl DownloadTask {
                             &ancillary_files_temp_dir,
                             &self.target_dir,
                             verifier,
+                            concurrency_controller,
                             &logger,
                         )
                         .await;
@@ -259,6 +294,56 @@ mod tests {
 
             download_task.build_download_future(logger).await.unwrap();
         }
+
+        #[tokio::test]
+        async fn report_location_failures_and_success_to_the_concurrency_controller() {
+            let target_dir = temp_dir_create!();
+            let file_downloader = Arc::new(
+                MockFileDownloaderBuilder::default()
+                    .with_file_uri("http://whatever-1/00001.tar.gz")
+                    .with_target_dir(target_dir.clone())
+                    .with_failure()
+                    .next_call()
+                    .with_file_uri("http://whatever-2/00001.tar.gz")
+                    .with_target_dir(target_dir.clone())
+                    .with_failure()
+                    .next_call()
+                    .with_file_uri("http://whatever-3/00001.tar.gz")
+                    .with_target_dir(target_dir.clone())
+                    .with_success()
+                    .build(),
+            );
+            let concurrency_controller = Arc::new(ConcurrencyController::new(8, true));
+
+            let download_task = DownloadTask {
+                kind: DownloadKind::Immutable(1),
+                locations_to_try: create_locations_to_download(
+                    file_downloader,
+                    [
+                        "http://whatever-1/00001.tar.gz",
+                        "http://whatever-2/00001.tar.gz",
+                        "http://whatever-3/00001.tar.gz",
+                    ],
+                ),
+                size_uncompressed: 0,
+                target_dir: target_dir.clone(),
+                download_event: DownloadEvent::Immutable {
+                    download_id: "download_id".to_string(),
+                    immutable_file_number: 1,
+                },
+            };
+
+            download_task
+                .build_download_future_with_concurrency_controller(
+                    concurrency_controller.clone(),
+                    TestLogger::stdout(),
+                )
+                .await
+                .unwrap();
+
+            // 8 halved twice on failures, then increased by one on success
+            assert_eq!(3, concurrency_controller.limit());
+        }
     }
 
     mod download_unpack_ancillary_file {
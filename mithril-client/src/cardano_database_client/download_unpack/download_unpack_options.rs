@@ -19,6 +19,10 @@ pub struct DownloadUnpackOptions {
 
     /// Maximum number of parallel downloads
     pub max_parallel_downloads: usize,
+
+    /// Adapt the number of parallel downloads to the download failures: the limit is halved
+    /// on each failure and slowly increased back to `max_parallel_downloads` on success
+    pub adaptive_concurrency: bool,
 }
 
 impl Default for DownloadUnpackOptions {
@@ -27,6 +31,7 @@ impl Default for DownloadUnpackOptions {
             allow_override: false,
             include_ancillary: false,
             max_parallel_downloads: 100,
+            adaptive_concurrency: false,
         }
     }
 }
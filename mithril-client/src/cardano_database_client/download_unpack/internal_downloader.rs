@@ -1,6 +1,7 @@
 use std::collections::{BTreeSet, VecDeque};
 use std::ops::RangeInclusive;
 use std::path::Path;
+use std::pin::Pin;
 use std::sync::Arc;
 
 use anyhow::anyhow;
@@ -17,7 +18,8 @@ use crate::file_downloader::{DownloadEvent, FileDownloader, FileDownloaderUri};
 use crate::utils::{create_bootstrap_node_files, AncillaryVerifier, VecDequeExtensions};
 use crate::MithrilResult;
 
-use super::download_task::{DownloadKind, DownloadTask, LocationToDownload};
+use super::concurrency_controller::ConcurrencyController;
+use super::download_task::{DownloadFuture, DownloadKind, DownloadTask, LocationToDownload};
 use super::DownloadUnpackOptions;
 
 pub struct InternalArtifactDownloader {
@@ -83,7 +85,11 @@ impl InternalArtifactDownloader {
                 &download_id,
             )?);
         }
-        self.batch_download_unpack(tasks, download_unpack_options.max_parallel_downloads)
+        let concurrency_controller = Arc::new(ConcurrencyController::new(
+            download_unpack_options.max_parallel_downloads,
+            download_unpack_options.adaptive_concurrency,
+        ));
+        self.batch_download_unpack(tasks, concurrency_controller)
             .await?;
 
         create_bootstrap_node_files(&self.logger, target_dir, &cardano_database_snapshot.network)?;
@@ -217,16 +223,19 @@ impl InternalArtifactDownloader {
     }
 
     /// Download and unpack the files in parallel.
+    ///
+    /// The number of parallel downloads is bounded by the given [ConcurrencyController], tasks
+    /// are spawned as long as the controller limit allows it.
     async fn batch_download_unpack(
         &self,
         mut tasks: VecDeque<DownloadTask>,
-        max_parallel_downloads: usize,
+        concurrency_controller: Arc<ConcurrencyController>,
     ) -> MithrilResult<()> {
         let mut join_set: JoinSet<MithrilResult<()>> = JoinSet::new();
 
-        let initial_tasks_chunk = tasks.pop_up_to_n(max_parallel_downloads);
+        let initial_tasks_chunk = tasks.pop_up_to_n(concurrency_controller.limit());
         for task in initial_tasks_chunk {
-            join_set.spawn(task.build_download_future(self.logger.clone()));
+            join_set.spawn(self.build_task_future(task, &concurrency_controller));
         }
 
         while let Some(result) = join_set.join_next().await {
@@ -235,13 +244,30 @@ impl InternalArtifactDownloader {
                 anyhow::bail!(error);
             }
 
-            if let Some(task) = tasks.pop_front() {
-                join_set.spawn(task.build_download_future(self.logger.clone()));
+            // Always keep at least one download running so the batch can not stall
+            while join_set.is_empty() || join_set.len() < concurrency_controller.limit() {
+                match tasks.pop_front() {
+                    Some(task) => {
+                        join_set.spawn(self.build_task_future(task, &concurrency_controller));
+                    }
+                    None => break,
+                }
             }
         }
 
         Ok(())
     }
+
+    fn build_task_future(
+        &self,
+        task: DownloadTask,
+        concurrency_controller: &Arc<ConcurrencyController>,
+    ) -> Pin<Box<DownloadFuture>> {
+        task.build_download_future_with_concurrency_controller(
+            concurrency_controller.clone(),
+            self.logger.clone(),
+        )
+    }
 }
 
 #[cfg(test)]
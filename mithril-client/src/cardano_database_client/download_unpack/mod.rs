@@ -1,3 +1,4 @@
+mod concurrency_controller;
 mod download_task;
 mod download_unpack_options;
 mod internal_downloader;
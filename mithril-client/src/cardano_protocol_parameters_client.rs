@@ -0,0 +1,310 @@
+//! A client to retrieve Cardano protocol parameters data from an Aggregator.
+//!
+//! In order to do so it defines a [CardanoProtocolParametersClient] which exposes the following features:
+//!  - [get][CardanoProtocolParametersClient::get]: get a Cardano protocol parameters data from its hash
+//!  - [get_by_epoch][CardanoProtocolParametersClient::get_by_epoch]: get a Cardano protocol parameters data from its epoch
+//!  - [list][CardanoProtocolParametersClient::list]: get the list of available Cardano protocol parameters
+//!
+//! # Get Cardano protocol parameters
+//!
+//! To get Cardano protocol parameters using the [ClientBuilder][crate::client::ClientBuilder].
+//!
+//! ```no_run
+//! # async fn run() -> mithril_client::MithrilResult<()> {
+//! use mithril_client::ClientBuilder;
+//!
+//! let client = ClientBuilder::aggregator("YOUR_AGGREGATOR_ENDPOINT", "YOUR_GENESIS_VERIFICATION_KEY").build()?;
+//! let cardano_protocol_parameters = client.cardano_protocol_parameters().get("CARDANO_PROTOCOL_PARAMETERS_HASH").await?.unwrap();
+//!
+//! println!(
+//!     "Cardano protocol parameters hash={}, epoch={}, protocol_parameters={:?}",
+//!     cardano_protocol_parameters.hash,
+//!     cardano_protocol_parameters.epoch,
+//!     cardano_protocol_parameters.protocol_parameters
+//! );
+//! #    Ok(())
+//! # }
+//! ```
+//!
+//! # List available Cardano protocol parameters
+//!
+//! To list available Cardano protocol parameters using the [ClientBuilder][crate::client::ClientBuilder].
+//!
+//! ```no_run
+//! # async fn run() -> mithril_client::MithrilResult<()> {
+//! use mithril_client::ClientBuilder;
+//!
+//! let client = ClientBuilder::aggregator("YOUR_AGGREGATOR_ENDPOINT", "YOUR_GENESIS_VERIFICATION_KEY").build()?;
+//! let cardano_protocol_parameters_list = client.cardano_protocol_parameters().list().await?;
+//!
+//! for cardano_protocol_parameters in cardano_protocol_parameters_list {
+//!     println!("Cardano protocol parameters hash={}, epoch={}", cardano_protocol_parameters.hash, cardano_protocol_parameters.epoch);
+//! }
+//! #    Ok(())
+//! # }
+//! ```
+//!
+//! # Get Cardano protocol parameters by epoch
+//!
+//! To get Cardano protocol parameters by epoch using the [ClientBuilder][crate::client::ClientBuilder].
+//! The epoch represents the epoch during which the protocol parameters are in effect on the Cardano ledger.
+//!
+//! ```no_run
+//! # async fn run() -> mithril_client::MithrilResult<()> {
+//! use mithril_client::ClientBuilder;
+//! use mithril_client::common::Epoch;
+//!
+//! let client = ClientBuilder::aggregator("YOUR_AGGREGATOR_ENDPOINT", "YOUR_GENESIS_VERIFICATION_KEY").build()?;
+//! let cardano_protocol_parameters = client.cardano_protocol_parameters().get_by_epoch(Epoch(500)).await?.unwrap();
+//!
+//! println!(
+//!     "Cardano protocol parameters hash={}, epoch={}, protocol_parameters={:?}",
+//!     cardano_protocol_parameters.hash,
+//!     cardano_protocol_parameters.epoch,
+//!     cardano_protocol_parameters.protocol_parameters
+//! );
+//! #    Ok(())
+//! # }
+//! ```
+
+use anyhow::Context;
+use std::sync::Arc;
+
+use crate::aggregator_client::{AggregatorClient, AggregatorClientError, AggregatorRequest};
+use crate::common::Epoch;
+use crate::{CardanoProtocolParameters, CardanoProtocolParametersListItem, MithrilResult};
+
+/// HTTP client for CardanoProtocolParameters API from the Aggregator
+pub struct CardanoProtocolParametersClient {
+    aggregator_client: Arc<dyn AggregatorClient>,
+}
+
+impl CardanoProtocolParametersClient {
+    /// Constructs a new `CardanoProtocolParametersClient`.
+    pub fn new(aggregator_client: Arc<dyn AggregatorClient>) -> Self {
+        Self { aggregator_client }
+    }
+
+    /// Fetch a list of signed CardanoProtocolParameters
+    pub async fn list(&self) -> MithrilResult<Vec<CardanoProtocolParametersListItem>> {
+        let response = self
+            .aggregator_client
+            .get_content(AggregatorRequest::ListCardanoProtocolParameters)
+            .await
+            .with_context(|| "CardanoProtocolParameters client can not get the artifact list")?;
+        let items = serde_json::from_str::<Vec<CardanoProtocolParametersListItem>>(&response)
+            .with_context(|| {
+                "CardanoProtocolParameters client can not deserialize artifact list"
+            })?;
+
+        Ok(items)
+    }
+
+    /// Get the given Cardano protocol parameters data by hash.
+    pub async fn get(&self, hash: &str) -> MithrilResult<Option<CardanoProtocolParameters>> {
+        self.fetch_with_aggregator_request(AggregatorRequest::GetCardanoProtocolParameters {
+            hash: hash.to_string(),
+        })
+        .await
+    }
+
+    /// Get the given Cardano protocol parameters data by epoch.
+    pub async fn get_by_epoch(
+        &self,
+        epoch: Epoch,
+    ) -> MithrilResult<Option<CardanoProtocolParameters>> {
+        self.fetch_with_aggregator_request(AggregatorRequest::GetCardanoProtocolParametersByEpoch {
+            epoch,
+        })
+        .await
+    }
+
+    /// Fetch the given Cardano protocol parameters data with an aggregator request.
+    /// If it cannot be found, a None is returned.
+    async fn fetch_with_aggregator_request(
+        &self,
+        request: AggregatorRequest,
+    ) -> MithrilResult<Option<CardanoProtocolParameters>> {
+        match self.aggregator_client.get_content(request).await {
+            Ok(content) => {
+                let cardano_protocol_parameters: CardanoProtocolParameters =
+                    serde_json::from_str(&content).with_context(|| {
+                        "CardanoProtocolParameters client can not deserialize artifact"
+                    })?;
+
+                Ok(Some(cardano_protocol_parameters))
+            }
+            Err(AggregatorClientError::RemoteServerLogical(_)) => Ok(None),
+            Err(e) => Err(e.into()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use anyhow::anyhow;
+    use chrono::{DateTime, Utc};
+    use mockall::predicate::eq;
+
+    use crate::aggregator_client::MockAggregatorClient;
+    use crate::common::LedgerProtocolParameters;
+
+    use super::*;
+
+    fn fake_messages() -> Vec<CardanoProtocolParametersListItem> {
+        vec![
+            CardanoProtocolParametersListItem {
+                epoch: Epoch(1),
+                hash: "hash-123".to_string(),
+                certificate_hash: "cert-hash-123".to_string(),
+                created_at: DateTime::parse_from_rfc3339("2024-08-06T12:13:05.618857482Z")
+                    .unwrap()
+                    .with_timezone(&Utc),
+            },
+            CardanoProtocolParametersListItem {
+                epoch: Epoch(2),
+                hash: "hash-456".to_string(),
+                certificate_hash: "cert-hash-456".to_string(),
+                created_at: DateTime::parse_from_rfc3339("2024-08-06T12:13:05.618857482Z")
+                    .unwrap()
+                    .with_timezone(&Utc),
+            },
+        ]
+    }
+
+    fn fake_message(epoch: Epoch) -> CardanoProtocolParameters {
+        CardanoProtocolParameters {
+            epoch,
+            hash: "hash-123".to_string(),
+            certificate_hash: "certificate-hash-123".to_string(),
+            protocol_parameters: LedgerProtocolParameters::dummy(),
+            created_at: DateTime::<Utc>::default(),
+        }
+    }
+
+    #[tokio::test]
+    async fn list_cardano_protocol_parameters_returns_messages() {
+        let message = fake_messages();
+        let mut http_client = MockAggregatorClient::new();
+        http_client
+            .expect_get_content()
+            .with(eq(AggregatorRequest::ListCardanoProtocolParameters))
+            .return_once(move |_| Ok(serde_json::to_string(&message).unwrap()));
+        let client = CardanoProtocolParametersClient::new(Arc::new(http_client));
+
+        let messages = client.list().await.unwrap();
+
+        assert_eq!(2, messages.len());
+        assert_eq!("hash-123".to_string(), messages[0].hash);
+        assert_eq!("hash-456".to_string(), messages[1].hash);
+    }
+
+    #[tokio::test]
+    async fn list_cardano_protocol_parameters_returns_error_when_invalid_json_structure_in_response(
+    ) {
+        let mut http_client = MockAggregatorClient::new();
+        http_client
+            .expect_get_content()
+            .return_once(move |_| Ok("invalid json structure".to_string()));
+        let client = CardanoProtocolParametersClient::new(Arc::new(http_client));
+
+        client
+            .list()
+            .await
+            .expect_err("List Cardano protocol parameters should return an error");
+    }
+
+    #[tokio::test]
+    async fn get_cardano_protocol_parameters_returns_message() {
+        let message = fake_message(Epoch(3));
+        let mut http_client = MockAggregatorClient::new();
+        http_client
+            .expect_get_content()
+            .with(eq(AggregatorRequest::GetCardanoProtocolParameters {
+                hash: "hash-123".to_string(),
+            }))
+            .return_once(move |_| Ok(serde_json::to_string(&message).unwrap()));
+        let client = CardanoProtocolParametersClient::new(Arc::new(http_client));
+
+        let cardano_protocol_parameters = client
+            .get("hash-123")
+            .await
+            .unwrap()
+            .expect("This test returns Cardano protocol parameters");
+
+        assert_eq!("hash-123".to_string(), cardano_protocol_parameters.hash);
+        assert_eq!(Epoch(3), cardano_protocol_parameters.epoch);
+        assert_eq!(
+            LedgerProtocolParameters::dummy(),
+            cardano_protocol_parameters.protocol_parameters
+        );
+    }
+
+    #[tokio::test]
+    async fn get_cardano_protocol_parameters_returns_none_when_not_found_or_remote_server_logical_error(
+    ) {
+        let mut http_client = MockAggregatorClient::new();
+        http_client.expect_get_content().return_once(move |_| {
+            Err(AggregatorClientError::RemoteServerLogical(anyhow!(
+                "not found"
+            )))
+        });
+        let client = CardanoProtocolParametersClient::new(Arc::new(http_client));
+
+        let result = client.get("hash-123").await.unwrap();
+
+        assert!(result.is_none());
+    }
+
+    #[tokio::test]
+    async fn get_cardano_protocol_parameters_returns_error() {
+        let mut http_client = MockAggregatorClient::new();
+        http_client
+            .expect_get_content()
+            .return_once(move |_| Err(AggregatorClientError::SubsystemError(anyhow!("error"))));
+        let client = CardanoProtocolParametersClient::new(Arc::new(http_client));
+
+        client
+            .get("hash-123")
+            .await
+            .expect_err("Get Cardano protocol parameters should return an error");
+    }
+
+    #[tokio::test]
+    async fn get_cardano_protocol_parameters_by_epoch_returns_message() {
+        let message = fake_message(Epoch(3));
+        let mut http_client = MockAggregatorClient::new();
+        http_client
+            .expect_get_content()
+            .with(eq(AggregatorRequest::GetCardanoProtocolParametersByEpoch {
+                epoch: Epoch(3),
+            }))
+            .return_once(move |_| Ok(serde_json::to_string(&message).unwrap()));
+        let client = CardanoProtocolParametersClient::new(Arc::new(http_client));
+
+        let cardano_protocol_parameters = client
+            .get_by_epoch(Epoch(3))
+            .await
+            .unwrap()
+            .expect("This test returns Cardano protocol parameters");
+
+        assert_eq!("hash-123".to_string(), cardano_protocol_parameters.hash);
+        assert_eq!(Epoch(3), cardano_protocol_parameters.epoch);
+    }
+
+    #[tokio::test]
+    async fn get_cardano_protocol_parameters_by_epoch_returns_none_when_not_found_or_remote_server_logical_error(
+    ) {
+        let mut http_client = MockAggregatorClient::new();
+        http_client.expect_get_content().return_once(move |_| {
+            Err(AggregatorClientError::RemoteServerLogical(anyhow!(
+                "not found"
+            )))
+        });
+        let client = CardanoProtocolParametersClient::new(Arc::new(http_client));
+
+        let result = client.get_by_epoch(Epoch(3)).await.unwrap();
+
+        assert!(result.is_none());
+    }
+}
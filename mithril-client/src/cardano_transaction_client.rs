@@ -3,6 +3,9 @@
 //! In order to do so it defines a [CardanoTransactionClient] which exposes the following features:
 //!  - [get_proofs][CardanoTransactionClient::get_proofs]: get a [cryptographic proof][CardanoTransactionsProofs]
 //!    that the transactions with given hash are included in the global Cardano transactions set.
+//!  - [search_proofs][CardanoTransactionClient::search_proofs]: get a [cryptographic proof][CardanoTransactionsSearchProofs]
+//!    of all the transactions of the block ranges of a block numbers interval, along with the transactions
+//!    matching an address or a metadata label.
 //!  - [get][CardanoTransactionClient::get_snapshot]: get a [Cardano transaction snapshot][CardanoTransactionSnapshot]
 //!    data from its hash.
 //!  - [list][CardanoTransactionClient::list_snapshots]: get the list of the latest available Cardano transaction
@@ -40,6 +43,45 @@
 //! # }
 //! ```
 //!
+//! # Search and verify the Cardano transactions of an address
+//!
+//! To search the Cardano transactions with an output at a given address using the [ClientBuilder][crate::client::ClientBuilder].
+//!
+//! The proof certifies all the transactions of the certified block ranges, but not their content: which of those
+//! transactions have an output at the address is claimed by the aggregator and is **not** verified.
+//!
+//! ```no_run
+//! # async fn run() -> mithril_client::MithrilResult<()> {
+//! use mithril_client::{ClientBuilder, MessageBuilder};
+//! use mithril_client::common::{BlockNumber, CardanoTransactionFilter};
+//!
+//! let client = ClientBuilder::aggregator("YOUR_AGGREGATOR_ENDPOINT", "YOUR_GENESIS_VERIFICATION_KEY").build()?;
+//!
+//! // 1 - Search the transactions of an address and verify the proof
+//! let search_proof = client
+//!     .cardano_transaction()
+//!     .search_proofs(
+//!         CardanoTransactionFilter::Address("YOUR_ADDRESS".to_string()),
+//!         BlockNumber(0)..BlockNumber(150),
+//!     )
+//!     .await?;
+//! println!("Mithril certified all the transactions of the block ranges : {:?}", &search_proof.certified_block_ranges);
+//!
+//! let search_result = search_proof.verify()?;
+//!
+//! // 2 - Verify its associated certificate chain
+//! let certificate = client.certificate().verify_chain(&search_proof.certificate_hash).await?;
+//!
+//! // 3 - Ensure that the proof is indeed signed in the associated certificate
+//! let message = MessageBuilder::new().compute_cardano_transactions_search_proofs_message(&certificate, &search_result);
+//! if certificate.match_message(&message) {
+//!     println!("Certified block ranges : {:?}", search_result.certified_block_ranges());
+//!     println!("Transactions of the address, not verified : {:?}", search_result.claimed_matching_transactions());
+//! }
+//! #    Ok(())
+//! # }
+//! ```
+//!
 //! # Get a Cardano transaction snapshot
 //!
 //! To get a Cardano transaction snapshot using the [ClientBuilder][crate::client::ClientBuilder].
@@ -75,11 +117,13 @@
 //! ```
 
 use crate::aggregator_client::{AggregatorClient, AggregatorClientError, AggregatorRequest};
+use crate::common::{BlockNumber, CardanoTransactionFilter};
 use crate::{
     CardanoTransactionSnapshot, CardanoTransactionSnapshotListItem, CardanoTransactionsProofs,
-    MithrilResult,
+    CardanoTransactionsSearchProofs, MithrilResult,
 };
 use anyhow::Context;
+use std::ops::Range;
 use std::sync::Arc;
 
 /// HTTP client for CardanoTransactionsAPI from the Aggregator
@@ -117,6 +161,37 @@ impl CardanoTransactionClient {
         }
     }
 
+    /// Search the transactions matching the given filter in the block ranges overlapping the given
+    /// block numbers interval, and get a proof of all the transactions of the certified block ranges.
+    ///
+    /// The match of the transactions with the filter is not certified, it's claimed by the
+    /// aggregator.
+    pub async fn search_proofs(
+        &self,
+        filter: CardanoTransactionFilter,
+        block_numbers: Range<BlockNumber>,
+    ) -> MithrilResult<CardanoTransactionsSearchProofs> {
+        let (address, metadata_label) = match filter {
+            CardanoTransactionFilter::Address(address) => (Some(address), None),
+            CardanoTransactionFilter::MetadataLabel(label) => (None, Some(label)),
+        };
+        let content = self
+            .aggregator_client
+            .get_content(AggregatorRequest::SearchTransactionsProofs {
+                address,
+                metadata_label,
+                from_block_number: block_numbers.start,
+                to_block_number: block_numbers.end,
+            })
+            .await?;
+        let search_proofs: CardanoTransactionsSearchProofs = serde_json::from_str(&content)
+            .with_context(|| {
+                "CardanoTransactionProof Client can not deserialize transactions search proofs"
+            })?;
+
+        Ok(search_proofs)
+    }
+
     /// Fetch a list of signed Cardano transaction snapshots.
     pub async fn list_snapshots(&self) -> MithrilResult<Vec<CardanoTransactionSnapshotListItem>> {
         let response = self
@@ -168,7 +243,7 @@ mod tests {
     use crate::common::{BlockNumber, Epoch};
     use crate::{
         CardanoTransactionSnapshot, CardanoTransactionSnapshotListItem, CardanoTransactionsProofs,
-        CardanoTransactionsSetProof,
+        CardanoTransactionsSearchProofs, CardanoTransactionsSetProof,
     };
 
     use super::*;
@@ -275,6 +350,62 @@ mod tests {
         assert_eq!(expected_transactions_proofs, transactions_proofs);
     }
 
+    #[tokio::test]
+    async fn test_search_proofs_ok() {
+        let mut aggregator_client = MockAggregatorClient::new();
+        let search_proofs = CardanoTransactionsSearchProofs::new(
+            "cert-hash-123",
+            CardanoTransactionFilter::MetadataLabel(674),
+            vec![],
+            None,
+            BlockNumber(99999),
+        );
+        let expected_search_proofs = search_proofs.clone();
+        aggregator_client
+            .expect_get_content()
+            .with(eq(AggregatorRequest::SearchTransactionsProofs {
+                address: None,
+                metadata_label: Some(674),
+                from_block_number: BlockNumber(15),
+                to_block_number: BlockNumber(45),
+            }))
+            .return_once(move |_| Ok(serde_json::to_string(&search_proofs).unwrap()))
+            .times(1);
+
+        let cardano_tx_client = CardanoTransactionClient::new(Arc::new(aggregator_client));
+        let search_proofs = cardano_tx_client
+            .search_proofs(
+                CardanoTransactionFilter::MetadataLabel(674),
+                BlockNumber(15)..BlockNumber(45),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(expected_search_proofs, search_proofs);
+    }
+
+    #[tokio::test]
+    async fn test_search_proofs_ko() {
+        let mut aggregator_client = MockAggregatorClient::new();
+        aggregator_client
+            .expect_get_content()
+            .return_once(move |_| {
+                Err(AggregatorClientError::RemoteServerTechnical(anyhow!(
+                    "an error"
+                )))
+            })
+            .times(1);
+
+        let cardano_tx_client = CardanoTransactionClient::new(Arc::new(aggregator_client));
+        cardano_tx_client
+            .search_proofs(
+                CardanoTransactionFilter::Address("addr_test1xyz".to_string()),
+                BlockNumber(15)..BlockNumber(45),
+            )
+            .await
+            .expect_err("The search should fail here.");
+    }
+
     #[tokio::test]
     async fn test_get_proof_ko() {
         let mut aggregator_client = MockAggregatorClient::new();
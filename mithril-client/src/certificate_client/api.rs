@@ -5,7 +5,11 @@ use std::sync::Arc;
 use crate::aggregator_client::AggregatorClient;
 use crate::certificate_client::fetch::InternalCertificateRetriever;
 use crate::certificate_client::{fetch, verify};
-use crate::{MithrilCertificate, MithrilCertificateListItem, MithrilResult};
+use crate::common::ProtocolMessage;
+use crate::{
+    CertificateChainBundle, CertificateChainCheckpoint, MithrilCertificate,
+    MithrilCertificateListItem, MithrilResult,
+};
 
 /// Aggregator client for the Certificate
 pub struct CertificateClient {
@@ -51,6 +55,22 @@ impl CertificateClient {
     pub async fn verify_chain(&self, certificate_hash: &str) -> MithrilResult<MithrilCertificate> {
         verify::verify_chain(self, certificate_hash).await
     }
+
+    /// Fetch the certificate chain starting with the certificate with given `certificate_hash` as
+    /// a portable bundle, back to the genesis certificate or to the given `checkpoint`.
+    ///
+    /// The `artifact_message` is embedded in the bundle, it must be computed from the certified
+    /// artifact with the [MessageBuilder][crate::MessageBuilder].
+    ///
+    /// The bundle is not verified, use [CertificateChainBundle::verify] to do so.
+    pub async fn fetch_chain_bundle(
+        &self,
+        certificate_hash: &str,
+        artifact_message: ProtocolMessage,
+        checkpoint: Option<CertificateChainCheckpoint>,
+    ) -> MithrilResult<CertificateChainBundle> {
+        fetch::chain_bundle(self, certificate_hash, artifact_message, checkpoint).await
+    }
 }
 
 /// API that defines how to validate certificates.
@@ -3,8 +3,11 @@ use async_trait::async_trait;
 use slog::{crit, Logger};
 use std::sync::Arc;
 
-use mithril_common::certificate_chain::{CertificateRetriever, CertificateRetrieverError};
-use mithril_common::entities::Certificate;
+use mithril_common::certificate_chain::{
+    CertificateChainBundle, CertificateChainCheckpoint, CertificateRetriever,
+    CertificateRetrieverError,
+};
+use mithril_common::entities::{Certificate, ProtocolMessage};
 use mithril_common::messages::CertificateMessage;
 
 use crate::aggregator_client::{AggregatorClient, AggregatorClientError, AggregatorRequest};
@@ -34,6 +37,27 @@ pub(super) async fn get(
     client.retriever.get(certificate_hash).await
 }
 
+#[inline]
+pub(super) async fn chain_bundle(
+    client: &CertificateClient,
+    certificate_hash: &str,
+    artifact_message: ProtocolMessage,
+    checkpoint: Option<CertificateChainCheckpoint>,
+) -> MithrilResult<CertificateChainBundle> {
+    CertificateChainBundle::build(
+        client.retriever.as_ref(),
+        certificate_hash,
+        artifact_message,
+        checkpoint,
+    )
+    .await
+    .with_context(|| {
+        format!(
+            "CertificateClient can not fetch the chain bundle of certificate '{certificate_hash}'"
+        )
+    })
+}
+
 /// Internal type to implement the [InternalCertificateRetriever] trait and avoid a circular
 /// dependency between the [CertificateClient] and the [CommonMithrilCertificateVerifier] that need
 /// a [CertificateRetriever] as a dependency.
@@ -105,9 +129,10 @@ impl CertificateRetriever for InternalCertificateRetriever {
 
 #[cfg(test)]
 mod tests {
-    use mithril_common::test_utils::fake_data;
+    use mithril_common::test_utils::{fake_data, CertificateChainBuilder};
 
     use crate::certificate_client::tests_utils::CertificateClientTestBuilder;
+    use crate::test_utils::TestLogger;
 
     use super::*;
 
@@ -217,4 +242,40 @@ mod tests {
             .await
             .expect_err("The certificate client should fail here.");
     }
+
+    #[tokio::test]
+    async fn fetch_chain_bundle_back_to_a_checkpoint() {
+        let (chain, verifier) = CertificateChainBuilder::new()
+            .with_total_certificates(5)
+            .with_certificates_per_epoch(1)
+            .build();
+        let checkpoint = CertificateChainCheckpoint::from_certificate(&chain[2]);
+
+        // Only the certificates down to the checkpoint are expected to be fetched
+        let certificate_client = CertificateClientTestBuilder::default()
+            .config_aggregator_client_mock(|mock| {
+                mock.expect_certificate_chain(chain[..=2].to_vec())
+            })
+            .build();
+
+        let bundle = certificate_client
+            .fetch_chain_bundle(
+                &chain[0].hash,
+                chain[0].protocol_message.clone(),
+                Some(checkpoint.clone()),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(3, bundle.certificates.len());
+        assert_eq!(Some(checkpoint.clone()), bundle.checkpoint);
+        bundle
+            .verify(
+                &verifier.to_verification_key(),
+                &[checkpoint],
+                TestLogger::stdout(),
+            )
+            .await
+            .expect("The fetched bundle should be valid");
+    }
 }
@@ -4,6 +4,7 @@
 //!  - [get][CertificateClient::get]: get a certificate data from its hash
 //!  - [list][CertificateClient::list]: get the list of available certificates
 //!  - [verify_chain][CertificateClient::verify_chain]: verify a certificate chain
+//!  - [fetch_chain_bundle][CertificateClient::fetch_chain_bundle]: fetch a certificate chain as a portable bundle
 //!
 //! # Get a certificate
 //!
@@ -54,6 +55,29 @@
 //! #    Ok(())
 //! # }
 //! ```
+//!
+//! # Fetch a certificate chain bundle
+//!
+//! To fetch a certificate chain as a portable bundle, that can be archived and verified later
+//! without access to an aggregator, using the [ClientBuilder][crate::client::ClientBuilder].
+//!
+//! ```no_run
+//! # async fn run() -> mithril_client::MithrilResult<()> {
+//! use mithril_client::{ClientBuilder, MessageBuilder};
+//!
+//! let client = ClientBuilder::aggregator("YOUR_AGGREGATOR_ENDPOINT", "YOUR_GENESIS_VERIFICATION_KEY").build()?;
+//! let certificate = client.certificate().get("CERTIFICATE_HASH").await?.unwrap();
+//! let stake_distribution = client.mithril_stake_distribution().get("STAKE_DISTRIBUTION_HASH").await?.unwrap();
+//!
+//! // The artifact message is computed from the certified artifact
+//! let artifact_message = MessageBuilder::new()
+//!     .compute_mithril_stake_distribution_message(&certificate, &stake_distribution)?;
+//! let bundle = client.certificate().fetch_chain_bundle(&certificate.hash, artifact_message, None).await?;
+//!
+//! println!("Bundle of certificate (hash: {}) contains {} certificates", bundle.certificate_hash, bundle.certificates.len());
+//! #    Ok(())
+//! # }
+//! ```
 
 mod api;
 mod fetch;
@@ -68,6 +92,7 @@ pub use verify_cache::MemoryCertificateVerifierCache;
 
 #[cfg(test)]
 pub(crate) mod tests_utils {
+    use mithril_common::certificate_chain::CertificateChainCheckpoint;
     use mithril_common::crypto_helper::ProtocolGenesisVerificationKey;
     use mithril_common::entities::Certificate;
     use mithril_common::messages::CertificateMessage;
@@ -84,6 +109,7 @@ pub(crate) mod tests_utils {
     pub(crate) struct CertificateClientTestBuilder {
         aggregator_client: MockAggregatorClient,
         genesis_verification_key: Option<String>,
+        trusted_checkpoints: Vec<CertificateChainCheckpoint>,
         feedback_receivers: Vec<Arc<dyn FeedbackReceiver>>,
         #[cfg(feature = "unstable")]
         verifier_cache: Option<Arc<dyn CertificateVerifierCache>>,
@@ -106,6 +132,14 @@ pub(crate) mod tests_utils {
             self
         }
 
+        pub fn with_trusted_checkpoints(
+            mut self,
+            trusted_checkpoints: Vec<CertificateChainCheckpoint>,
+        ) -> Self {
+            self.trusted_checkpoints = trusted_checkpoints;
+            self
+        }
+
         pub fn add_feedback_receiver(
             mut self,
             feedback_receiver: Arc<dyn FeedbackReceiver>,
@@ -143,7 +177,8 @@ pub(crate) mod tests_utils {
                             self.verifier_cache,
                             logger.clone(),
                         )
-                        .unwrap(),
+                        .unwrap()
+                        .with_trusted_checkpoints(self.trusted_checkpoints),
                     ),
                 };
 
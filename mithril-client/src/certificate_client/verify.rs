@@ -1,11 +1,14 @@
 use anyhow::{anyhow, Context};
 use async_trait::async_trait;
 use slog::{trace, Logger};
+#[cfg(feature = "unstable")]
+use std::collections::HashSet;
 use std::sync::Arc;
 
 use mithril_common::{
     certificate_chain::{
-        CertificateRetriever, CertificateVerifier as CommonCertificateVerifier,
+        CertificateChainCheckpoint, CertificateRetriever,
+        CertificateVerifier as CommonCertificateVerifier,
         MithrilCertificateVerifier as CommonMithrilCertificateVerifier,
     },
     crypto_helper::ProtocolGenesisVerificationKey,
@@ -51,6 +54,8 @@ pub struct MithrilCertificateVerifier {
     retriever: Arc<InternalCertificateRetriever>,
     internal_verifier: Arc<dyn CommonCertificateVerifier>,
     genesis_verification_key: ProtocolGenesisVerificationKey,
+    #[cfg(feature = "unstable")]
+    trusted_checkpoint_hashes: HashSet<String>,
     feedback_sender: FeedbackSender,
     #[cfg(feature = "unstable")]
     verifier_cache: Option<Arc<dyn CertificateVerifierCache>>,
@@ -83,6 +88,8 @@ impl MithrilCertificateVerifier {
             retriever,
             internal_verifier,
             genesis_verification_key,
+            #[cfg(feature = "unstable")]
+            trusted_checkpoint_hashes: HashSet::new(),
             feedback_sender,
             #[cfg(feature = "unstable")]
             verifier_cache,
@@ -90,8 +97,32 @@ impl MithrilCertificateVerifier {
         })
     }
 
+    /// Set the trusted checkpoints at which the verification of a certificate chain stops
+    /// instead of walking it back to the genesis certificate.
+    pub fn with_trusted_checkpoints(
+        mut self,
+        checkpoints: Vec<CertificateChainCheckpoint>,
+    ) -> Self {
+        #[cfg(feature = "unstable")]
+        {
+            self.trusted_checkpoint_hashes = checkpoints
+                .iter()
+                .map(|checkpoint| checkpoint.certificate_hash.clone())
+                .collect();
+        }
+        self.internal_verifier = Arc::new(
+            CommonMithrilCertificateVerifier::new(self.logger.clone(), self.retriever.clone())
+                .with_trusted_checkpoints(checkpoints),
+        );
+        self
+    }
+
     #[cfg(feature = "unstable")]
     async fn fetch_cached_previous_hash(&self, hash: &str) -> MithrilResult<Option<String>> {
+        // Trusted checkpoints are always verified since they end the chain verification
+        if self.trusted_checkpoint_hashes.contains(hash) {
+            return Ok(None);
+        }
         if let Some(cache) = self.verifier_cache.as_ref() {
             Ok(cache.get_previous_hash(hash).await?)
         } else {
@@ -323,6 +354,32 @@ mod tests {
         assert_eq!(certificate.hash, last_certificate_hash);
     }
 
+    #[tokio::test]
+    async fn verify_chain_stops_at_a_trusted_checkpoint() {
+        let (chain, verifier) = CertificateChainBuilder::new()
+            .with_total_certificates(5)
+            .with_certificates_per_epoch(1)
+            .build();
+        let last_certificate_hash = chain.first().unwrap().hash.clone();
+        let checkpoint = CertificateChainCheckpoint::from_certificate(&chain[2]);
+
+        // Only the certificates down to the checkpoint are expected to be fetched
+        let certificate_client = CertificateClientTestBuilder::default()
+            .config_aggregator_client_mock(|mock| {
+                mock.expect_certificate_chain(chain[..=2].to_vec())
+            })
+            .with_genesis_verification_key(verifier.to_verification_key())
+            .with_trusted_checkpoints(vec![checkpoint])
+            .build();
+
+        let certificate = certificate_client
+            .verify_chain(&last_certificate_hash)
+            .await
+            .expect("Chain validation should succeed");
+
+        assert_eq!(certificate.hash, last_certificate_hash);
+    }
+
     #[cfg(feature = "unstable")]
     mod cache {
         use chrono::TimeDelta;
@@ -4,13 +4,18 @@ use serde::{Deserialize, Serialize};
 use slog::{o, Logger};
 use std::collections::HashMap;
 use std::sync::Arc;
+#[cfg(feature = "fs")]
+use std::time::Duration;
 
 use mithril_common::api_version::APIVersionProvider;
+use mithril_common::certificate_chain::CertificateChainCheckpoint;
 use mithril_common::MITHRIL_ORIGIN_TAG_HEADER;
 
 use crate::aggregator_client::{AggregatorClient, AggregatorHTTPClient};
+use crate::cardano_block_client::CardanoBlockClient;
 #[cfg(feature = "unstable")]
 use crate::cardano_database_client::CardanoDatabaseClient;
+use crate::cardano_protocol_parameters_client::CardanoProtocolParametersClient;
 use crate::cardano_stake_distribution_client::CardanoStakeDistributionClient;
 use crate::cardano_transaction_client::CardanoTransactionClient;
 #[cfg(feature = "unstable")]
@@ -21,9 +26,10 @@ use crate::certificate_client::{
 use crate::feedback::{FeedbackReceiver, FeedbackSender};
 #[cfg(feature = "fs")]
 use crate::file_downloader::{
-    FileDownloadRetryPolicy, FileDownloader, HttpFileDownloader, RetryDownloader,
+    BandwidthLimiter, FileDownloadRetryPolicy, FileDownloader, HttpFileDownloader, RetryDownloader,
 };
 use crate::mithril_stake_distribution_client::MithrilStakeDistributionClient;
+use crate::multi_aggregator_client::MultiAggregatorClient;
 use crate::snapshot_client::SnapshotClient;
 #[cfg(feature = "fs")]
 use crate::utils::AncillaryVerifier;
@@ -104,7 +110,9 @@ pub struct Client {
     #[cfg(feature = "unstable")]
     cardano_database_client: Arc<CardanoDatabaseClient>,
     cardano_transaction_client: Arc<CardanoTransactionClient>,
+    cardano_block_client: Arc<CardanoBlockClient>,
     cardano_stake_distribution_client: Arc<CardanoStakeDistributionClient>,
+    cardano_protocol_parameters_client: Arc<CardanoProtocolParametersClient>,
 }
 
 impl Client {
@@ -140,23 +148,39 @@ impl Client {
         self.cardano_transaction_client.clone()
     }
 
+    /// Get the client that fetches and verifies Mithril Cardano block proof.
+    pub fn cardano_block(&self) -> Arc<CardanoBlockClient> {
+        self.cardano_block_client.clone()
+    }
+
     /// Get the client that fetches Cardano stake distributions.
     pub fn cardano_stake_distribution(&self) -> Arc<CardanoStakeDistributionClient> {
         self.cardano_stake_distribution_client.clone()
     }
+
+    /// Get the client that fetches Cardano protocol parameters.
+    pub fn cardano_protocol_parameters(&self) -> Arc<CardanoProtocolParametersClient> {
+        self.cardano_protocol_parameters_client.clone()
+    }
 }
 
 /// Builder than can be used to create a [Client] easily or with custom dependencies.
 pub struct ClientBuilder {
-    aggregator_endpoint: Option<String>,
+    aggregator_endpoints: Vec<String>,
+    certificate_cross_check_quorum: usize,
     genesis_verification_key: String,
     origin_tag: Option<String>,
     #[cfg(feature = "fs")]
     ancillary_verification_key: Option<String>,
     aggregator_client: Option<Arc<dyn AggregatorClient>>,
     certificate_verifier: Option<Arc<dyn CertificateVerifier>>,
+    trusted_checkpoints: Vec<CertificateChainCheckpoint>,
     #[cfg(feature = "fs")]
     http_file_downloader: Option<Arc<dyn FileDownloader>>,
+    #[cfg(feature = "fs")]
+    max_download_bandwidth_in_bytes_per_second: Option<u64>,
+    #[cfg(feature = "fs")]
+    download_timeout: Option<Duration>,
     #[cfg(feature = "unstable")]
     certificate_verifier_cache: Option<Arc<dyn CertificateVerifierCache>>,
     logger: Option<Logger>,
@@ -168,16 +192,32 @@ impl ClientBuilder {
     /// Constructs a new `ClientBuilder` that fetches data from the aggregator at the given
     /// endpoint and with the given genesis verification key.
     pub fn aggregator(endpoint: &str, genesis_verification_key: &str) -> ClientBuilder {
+        Self::aggregators(&[endpoint], genesis_verification_key)
+    }
+
+    /// Constructs a new `ClientBuilder` that fetches data from several aggregators of the same
+    /// network and with the given genesis verification key.
+    ///
+    /// The aggregators are queried in the given order, failing over to the next one on errors.
+    /// Use [ClientBuilder::with_certificate_cross_check_quorum] to also require that several of
+    /// them return the same certificates.
+    pub fn aggregators(endpoints: &[&str], genesis_verification_key: &str) -> ClientBuilder {
         Self {
-            aggregator_endpoint: Some(endpoint.to_string()),
+            aggregator_endpoints: endpoints.iter().map(|e| e.to_string()).collect(),
+            certificate_cross_check_quorum: 1,
             genesis_verification_key: genesis_verification_key.to_string(),
             origin_tag: None,
             #[cfg(feature = "fs")]
             ancillary_verification_key: None,
             aggregator_client: None,
             certificate_verifier: None,
+            trusted_checkpoints: vec![],
             #[cfg(feature = "fs")]
             http_file_downloader: None,
+            #[cfg(feature = "fs")]
+            max_download_bandwidth_in_bytes_per_second: None,
+            #[cfg(feature = "fs")]
+            download_timeout: None,
             #[cfg(feature = "unstable")]
             certificate_verifier_cache: None,
             logger: None,
@@ -192,15 +232,21 @@ impl ClientBuilder {
     /// to request data from the aggregator.
     pub fn new(genesis_verification_key: &str) -> ClientBuilder {
         Self {
-            aggregator_endpoint: None,
+            aggregator_endpoints: vec![],
+            certificate_cross_check_quorum: 1,
             genesis_verification_key: genesis_verification_key.to_string(),
             origin_tag: None,
             #[cfg(feature = "fs")]
             ancillary_verification_key: None,
             aggregator_client: None,
             certificate_verifier: None,
+            trusted_checkpoints: vec![],
             #[cfg(feature = "fs")]
             http_file_downloader: None,
+            #[cfg(feature = "fs")]
+            max_download_bandwidth_in_bytes_per_second: None,
+            #[cfg(feature = "fs")]
+            download_timeout: None,
             #[cfg(feature = "unstable")]
             certificate_verifier_cache: None,
             logger: None,
@@ -222,7 +268,7 @@ impl ClientBuilder {
         let feedback_sender = FeedbackSender::new(&self.feedback_receivers);
 
         let aggregator_client = match self.aggregator_client {
-            None => Arc::new(self.build_aggregator_client(logger.clone())?),
+            None => self.build_aggregator_client(logger.clone())?,
             Some(client) => client,
         };
 
@@ -236,7 +282,8 @@ impl ClientBuilder {
                     self.certificate_verifier_cache,
                     logger.clone(),
                 )
-                .with_context(|| "Building certificate verifier failed")?,
+                .with_context(|| "Building certificate verifier failed")?
+                .with_trusted_checkpoints(self.trusted_checkpoints.clone()),
             ),
             Some(verifier) => verifier,
         };
@@ -253,10 +300,7 @@ impl ClientBuilder {
         #[cfg(feature = "fs")]
         let http_file_downloader = match self.http_file_downloader {
             None => Arc::new(RetryDownloader::new(
-                Arc::new(
-                    HttpFileDownloader::new(feedback_sender.clone(), logger.clone())
-                        .with_context(|| "Building http file downloader failed")?,
-                ),
+                Arc::new(self.build_http_file_downloader(feedback_sender.clone(), logger.clone())?),
                 FileDownloadRetryPolicy::default(),
             )),
             Some(http_file_downloader) => http_file_downloader,
@@ -300,8 +344,14 @@ impl ClientBuilder {
         let cardano_transaction_client =
             Arc::new(CardanoTransactionClient::new(aggregator_client.clone()));
 
-        let cardano_stake_distribution_client =
-            Arc::new(CardanoStakeDistributionClient::new(aggregator_client));
+        let cardano_block_client = Arc::new(CardanoBlockClient::new(aggregator_client.clone()));
+
+        let cardano_stake_distribution_client = Arc::new(CardanoStakeDistributionClient::new(
+            aggregator_client.clone(),
+        ));
+
+        let cardano_protocol_parameters_client =
+            Arc::new(CardanoProtocolParametersClient::new(aggregator_client));
 
         Ok(Client {
             certificate_client,
@@ -310,18 +360,48 @@ impl ClientBuilder {
             #[cfg(feature = "unstable")]
             cardano_database_client,
             cardano_transaction_client,
+            cardano_block_client,
             cardano_stake_distribution_client,
+            cardano_protocol_parameters_client,
         })
     }
 
     fn build_aggregator_client(
         &self,
         logger: Logger,
+    ) -> Result<Arc<dyn AggregatorClient>, anyhow::Error> {
+        match self.aggregator_endpoints.as_slice() {
+            [] => Err(anyhow!("No aggregator endpoint set: \
+                    You must either provide an aggregator endpoint or your own AggregatorClient implementation")),
+            [endpoint] if self.certificate_cross_check_quorum <= 1 => Ok(Arc::new(
+                self.build_aggregator_http_client(endpoint, logger)?,
+            )),
+            endpoints => {
+                let aggregator_clients = endpoints
+                    .iter()
+                    .map(|endpoint| {
+                        self.build_aggregator_http_client(endpoint, logger.clone())
+                            .map(|client| Arc::new(client) as Arc<dyn AggregatorClient>)
+                    })
+                    .collect::<Result<Vec<_>, _>>()?;
+
+                Ok(Arc::new(
+                    MultiAggregatorClient::new(
+                        aggregator_clients,
+                        self.certificate_cross_check_quorum,
+                        logger,
+                    )
+                    .with_context(|| "Building multi aggregator client failed")?,
+                ))
+            }
+        }
+    }
+
+    fn build_aggregator_http_client(
+        &self,
+        endpoint: &str,
+        logger: Logger,
     ) -> Result<AggregatorHTTPClient, anyhow::Error> {
-        let endpoint = self
-            .aggregator_endpoint.as_ref()
-            .ok_or(anyhow!("No aggregator endpoint set: \
-                    You must either provide an aggregator endpoint or your own AggregatorClient implementation"))?;
         let endpoint_url = Url::parse(endpoint).with_context(|| {
             format!("Invalid aggregator endpoint, it must be a correctly formed url: '{endpoint}'")
         })?;
@@ -338,6 +418,26 @@ impl ClientBuilder {
         .with_context(|| "Building aggregator client failed")
     }
 
+    #[cfg(feature = "fs")]
+    fn build_http_file_downloader(
+        &self,
+        feedback_sender: FeedbackSender,
+        logger: Logger,
+    ) -> MithrilResult<HttpFileDownloader> {
+        let mut http_file_downloader = HttpFileDownloader::new(feedback_sender, logger)
+            .with_context(|| "Building http file downloader failed")?;
+
+        if let Some(bytes_per_second) = self.max_download_bandwidth_in_bytes_per_second {
+            http_file_downloader = http_file_downloader
+                .with_bandwidth_limiter(Arc::new(BandwidthLimiter::new(bytes_per_second)));
+        }
+        if let Some(timeout) = self.download_timeout {
+            http_file_downloader = http_file_downloader.with_download_timeout(timeout);
+        }
+
+        Ok(http_file_downloader)
+    }
+
     fn compute_http_headers(&self) -> HashMap<String, String> {
         let mut headers = self.options.http_headers.clone().unwrap_or_default();
         if let Some(origin_tag) = self.origin_tag.clone() {
@@ -356,6 +456,16 @@ impl ClientBuilder {
         self
     }
 
+    /// Set the number of aggregators that must return the same certificate, or the same
+    /// certificate hash for an artifact, before it is trusted.
+    ///
+    /// Only used when the builder was created with several aggregator endpoints using
+    /// [ClientBuilder::aggregators], defaults to `1` (no cross-check, only failover).
+    pub fn with_certificate_cross_check_quorum(mut self, quorum: usize) -> ClientBuilder {
+        self.certificate_cross_check_quorum = quorum;
+        self
+    }
+
     /// Set the [CertificateVerifier] that will be used to validate certificates.
     pub fn with_certificate_verifier(
         mut self,
@@ -365,6 +475,20 @@ impl ClientBuilder {
         self
     }
 
+    /// Set the trusted checkpoints at which the certificate chain verification stops instead of
+    /// walking the chain back to the genesis certificate.
+    ///
+    /// Signed checkpoints are verified with the genesis verification key, unsigned checkpoints
+    /// are trusted as is. Only used by the default [CertificateVerifier], ignored if one is set
+    /// with [ClientBuilder::with_certificate_verifier].
+    pub fn with_trusted_checkpoints(
+        mut self,
+        trusted_checkpoints: Vec<CertificateChainCheckpoint>,
+    ) -> ClientBuilder {
+        self.trusted_checkpoints = trusted_checkpoints;
+        self
+    }
+
     cfg_unstable! {
         /// Set the [CertificateVerifierCache] that will be used to cache certificate validation results.
         ///
@@ -423,6 +547,25 @@ impl ClientBuilder {
         self.options = options;
         self
     }
+
+    /// Set the maximum bandwidth, in bytes per second, shared by all the file downloads of the
+    /// client.
+    ///
+    /// The downloads are not throttled if not set.
+    #[cfg(feature = "fs")]
+    pub fn with_max_download_bandwidth(mut self, bytes_per_second: Option<u64>) -> Self {
+        self.max_download_bandwidth_in_bytes_per_second = bytes_per_second;
+        self
+    }
+
+    /// Set the maximum duration of the download of a single file.
+    ///
+    /// A download lasting longer fails and is retried, the downloads never time out if not set.
+    #[cfg(feature = "fs")]
+    pub fn with_download_timeout(mut self, timeout: Option<Duration>) -> Self {
+        self.download_timeout = timeout;
+        self
+    }
 }
 
 #[cfg(test)]
@@ -495,4 +638,66 @@ mod tests {
         assert_eq!(Some(http_headers), builder.options.http_headers);
         assert_eq!(None, builder.origin_tag);
     }
+
+    #[test]
+    fn build_aggregator_client_fails_without_endpoint() {
+        let builder = ClientBuilder::new("");
+
+        builder
+            .build_aggregator_client(Logger::root(slog::Discard, o!()))
+            .expect_err("Building an aggregator client without endpoint should fail");
+    }
+
+    #[test]
+    fn build_aggregator_client_with_several_endpoints() {
+        let builder = ClientBuilder::aggregators(
+            &["http://aggregator-1.test", "http://aggregator-2.test"],
+            "",
+        )
+        .with_certificate_cross_check_quorum(2);
+
+        builder
+            .build_aggregator_client(Logger::root(slog::Discard, o!()))
+            .expect("Building an aggregator client with several endpoints should not fail");
+    }
+
+    #[test]
+    fn build_aggregator_client_fails_if_cross_check_quorum_exceeds_number_of_endpoints() {
+        let builder = ClientBuilder::aggregators(
+            &["http://aggregator-1.test", "http://aggregator-2.test"],
+            "",
+        )
+        .with_certificate_cross_check_quorum(3);
+
+        builder
+            .build_aggregator_client(Logger::root(slog::Discard, o!()))
+            .expect_err("Building an aggregator client with a quorum too high should fail");
+    }
+
+    #[cfg(feature = "fs")]
+    #[test]
+    fn build_http_file_downloader_with_download_limits() {
+        let builder = ClientBuilder::new("")
+            .with_max_download_bandwidth(Some(1024))
+            .with_download_timeout(Some(Duration::from_secs(30)));
+
+        builder
+            .build_http_file_downloader(FeedbackSender::new(&[]), Logger::root(slog::Discard, o!()))
+            .expect("Building an http file downloader with download limits should not fail");
+    }
+
+    #[cfg(feature = "fs")]
+    #[test]
+    fn with_options_does_not_overwrite_download_limits() {
+        let builder = ClientBuilder::new("")
+            .with_max_download_bandwidth(Some(1024))
+            .with_download_timeout(Some(Duration::from_secs(30)))
+            .with_options(ClientOptions::default());
+
+        assert_eq!(
+            Some(1024),
+            builder.max_download_bandwidth_in_bytes_per_second
+        );
+        assert_eq!(Some(Duration::from_secs(30)), builder.download_timeout);
+    }
 }
@@ -0,0 +1,123 @@
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+/// A token bucket limiting the throughput of the downloads sharing it.
+///
+/// The bucket holds up to one second worth of bandwidth, consuming more bytes than available
+/// puts the bucket in debt and makes the caller wait until the debt is paid back.
+pub struct BandwidthLimiter {
+    bytes_per_second: u64,
+    state: Mutex<BucketState>,
+}
+
+struct BucketState {
+    available_bytes: f64,
+    last_refill: Instant,
+}
+
+impl BandwidthLimiter {
+    /// Constructs a new `BandwidthLimiter` capping the throughput to `bytes_per_second`.
+    ///
+    /// A limit of `0` is treated as `1` byte per second.
+    pub fn new(bytes_per_second: u64) -> Self {
+        let bytes_per_second = bytes_per_second.max(1);
+
+        Self {
+            bytes_per_second,
+            state: Mutex::new(BucketState {
+                available_bytes: bytes_per_second as f64,
+                last_refill: Instant::now(),
+            }),
+        }
+    }
+
+    /// Maximum throughput allowed by this limiter, in bytes per second.
+    pub fn bytes_per_second(&self) -> u64 {
+        self.bytes_per_second
+    }
+
+    /// Consume `bytes` from the bucket, waiting if the bucket can not afford them.
+    pub async fn consume(&self, bytes: u64) {
+        let wait_duration = self.reserve(bytes, Instant::now());
+
+        if !wait_duration.is_zero() {
+            tokio::time::sleep(wait_duration).await;
+        }
+    }
+
+    /// Withdraw `bytes` from the bucket and compute how long the caller must wait for the
+    /// bucket to get back out of debt.
+    fn reserve(&self, bytes: u64, now: Instant) -> Duration {
+        let rate = self.bytes_per_second as f64;
+        let mut state = self.state.lock().unwrap();
+
+        let elapsed = now.saturating_duration_since(state.last_refill);
+        state.available_bytes = (state.available_bytes + elapsed.as_secs_f64() * rate).min(rate);
+        state.last_refill = now;
+        state.available_bytes -= bytes as f64;
+
+        if state.available_bytes >= 0.0 {
+            Duration::ZERO
+        } else {
+            Duration::from_secs_f64(-state.available_bytes / rate)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn consuming_less_than_the_burst_does_not_wait() {
+        let limiter = BandwidthLimiter::new(1000);
+        let now = Instant::now();
+
+        assert_eq!(Duration::ZERO, limiter.reserve(400, now));
+        assert_eq!(Duration::ZERO, limiter.reserve(600, now));
+    }
+
+    #[test]
+    fn consuming_more_than_available_waits_for_the_debt_to_be_paid() {
+        let limiter = BandwidthLimiter::new(1000);
+        let now = Instant::now();
+
+        assert_eq!(Duration::ZERO, limiter.reserve(1000, now));
+        assert_eq!(Duration::from_millis(500), limiter.reserve(500, now));
+        assert_eq!(Duration::from_millis(1500), limiter.reserve(1000, now));
+    }
+
+    #[test]
+    fn bucket_refills_over_time_up_to_one_second_of_bandwidth() {
+        let limiter = BandwidthLimiter::new(1000);
+        let now = Instant::now();
+        limiter.reserve(1000, now);
+
+        assert_eq!(
+            Duration::ZERO,
+            limiter.reserve(250, now + Duration::from_millis(250))
+        );
+        assert_eq!(
+            Duration::from_millis(500),
+            limiter.reserve(1500, now + Duration::from_secs(10))
+        );
+    }
+
+    #[test]
+    fn limit_of_zero_is_treated_as_one_byte_per_second() {
+        let limiter = BandwidthLimiter::new(0);
+
+        assert_eq!(1, limiter.bytes_per_second());
+    }
+
+    #[tokio::test]
+    async fn consume_sleeps_when_bucket_is_in_debt() {
+        let limiter = BandwidthLimiter::new(1000);
+        limiter.consume(1000).await;
+
+        let start = Instant::now();
+        limiter.consume(100).await;
+
+        assert!(start.elapsed() >= Duration::from_millis(90));
+    }
+}
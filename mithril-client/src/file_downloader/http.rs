@@ -1,6 +1,8 @@
 use std::{
     io::{BufReader, Read, Write},
     path::Path,
+    sync::Arc,
+    time::Duration,
 };
 
 use anyhow::{anyhow, Context};
@@ -20,12 +22,14 @@ use crate::common::CompressionAlgorithm;
 use crate::feedback::FeedbackSender;
 use crate::utils::StreamReader;
 
-use super::{interface::DownloadEvent, FileDownloader, FileDownloaderUri};
+use super::{interface::DownloadEvent, BandwidthLimiter, FileDownloader, FileDownloaderUri};
 
 /// A file downloader that only handles download through HTTP.
 pub struct HttpFileDownloader {
     http_client: reqwest::Client,
     feedback_sender: FeedbackSender,
+    bandwidth_limiter: Option<Arc<BandwidthLimiter>>,
+    download_timeout: Option<Duration>,
     logger: Logger,
 }
 
@@ -39,10 +43,49 @@ impl HttpFileDownloader {
         Ok(Self {
             http_client,
             feedback_sender,
+            bandwidth_limiter: None,
+            download_timeout: None,
             logger: logger.new_with_component_name::<Self>(),
         })
     }
 
+    /// Cap the throughput of the downloads with the given limiter.
+    ///
+    /// The limiter is shared by all the downloads done by this downloader, including the
+    /// parallel ones.
+    pub fn with_bandwidth_limiter(mut self, bandwidth_limiter: Arc<BandwidthLimiter>) -> Self {
+        self.bandwidth_limiter = Some(bandwidth_limiter);
+        self
+    }
+
+    /// Abort any download of a single file that lasts longer than `download_timeout`.
+    pub fn with_download_timeout(mut self, download_timeout: Duration) -> Self {
+        self.download_timeout = Some(download_timeout);
+        self
+    }
+
+    async fn throttle(&self, bytes: usize) {
+        if let Some(bandwidth_limiter) = &self.bandwidth_limiter {
+            bandwidth_limiter.consume(bytes as u64).await;
+        }
+    }
+
+    async fn download_file(
+        &self,
+        location: &FileDownloaderUri,
+        sender: &Sender<Vec<u8>>,
+        download_event_type: DownloadEvent,
+        file_size: u64,
+    ) -> StdResult<()> {
+        if let Some(local_path) = Self::file_scheme_to_local_path(location.as_str()) {
+            self.download_local_file(&local_path, sender, download_event_type, file_size)
+                .await
+        } else {
+            self.download_remote_file(location.as_str(), sender, download_event_type, file_size)
+                .await
+        }
+    }
+
     async fn get(&self, location: &str) -> StdResult<Response> {
         debug!(self.logger, "GET Snapshot location='{location}'.");
         let request_builder = self.http_client.get(location);
@@ -93,6 +136,7 @@ impl HttpFileDownloader {
                 break;
             }
             buffer.truncate(bytes_read);
+            self.throttle(bytes_read).await;
             sender.send_async(buffer).await.with_context(|| {
                 format!(
                     "Local file read: could not write {} bytes to stream.",
@@ -130,6 +174,7 @@ impl HttpFileDownloader {
 
         while let Some(item) = remote_stream.next().await {
             let chunk = item.with_context(|| "Download: Could not read from byte stream")?;
+            self.throttle(chunk.len()).await;
             sender.send_async(chunk.to_vec()).await.with_context(|| {
                 format!("Download: could not write {} bytes to stream.", chunk.len())
             })?;
@@ -215,12 +260,18 @@ impl FileDownloader for HttpFileDownloader {
         let unpack_thread = tokio::task::spawn_blocking(move || -> StdResult<()> {
             Self::unpack_file(receiver, compression_algorithm, &dest_dir, download_id)
         });
-        if let Some(local_path) = Self::file_scheme_to_local_path(location.as_str()) {
-            self.download_local_file(&local_path, &sender, download_event_type, file_size)
-                .await?;
-        } else {
-            self.download_remote_file(location.as_str(), &sender, download_event_type, file_size)
-                .await?;
+        let download = self.download_file(location, &sender, download_event_type, file_size);
+        match self.download_timeout {
+            Some(download_timeout) => tokio::time::timeout(download_timeout, download)
+                .await
+                .map_err(|_| {
+                    anyhow!(
+                        "Download: timed out after {}s (location='{}')",
+                        download_timeout.as_secs_f64(),
+                        location.as_str()
+                    )
+                })??,
+            None => download.await?,
         }
         drop(sender);
         unpack_thread
@@ -378,4 +429,78 @@ mod tests {
         ];
         assert_eq!(expected_events, feedback_receiver.stacked_events());
     }
+
+    #[tokio::test]
+    async fn download_fails_when_exceeding_download_timeout() {
+        let target_dir = TempDir::create(
+            "client-http-downloader",
+            "download_fails_when_exceeding_download_timeout",
+        );
+        let server = MockServer::start();
+        server.mock(|when, then| {
+            when.method(httpmock::Method::GET).path("/snapshot.tar");
+            then.status(200)
+                .body("Hello, world!")
+                .delay(Duration::from_secs(2));
+        });
+        let http_file_downloader =
+            HttpFileDownloader::new(FeedbackSender::new(&[]), TestLogger::stdout())
+                .unwrap()
+                .with_download_timeout(Duration::from_millis(100));
+
+        let error = http_file_downloader
+            .download_unpack(
+                &FileDownloaderUri::FileUri(FileUri(server.url("/snapshot.tar"))),
+                0,
+                &target_dir,
+                None,
+                DownloadEvent::Digest {
+                    download_id: "id".to_string(),
+                },
+            )
+            .await
+            .expect_err("download_unpack should fail after the timeout");
+
+        assert!(
+            error.to_string().contains("timed out"),
+            "unexpected error: {error:?}"
+        );
+    }
+
+    #[tokio::test]
+    async fn download_is_throttled_by_the_bandwidth_limiter() {
+        let target_dir = TempDir::create(
+            "client-http-downloader",
+            "download_is_throttled_by_the_bandwidth_limiter",
+        );
+        let content = "a".repeat(300);
+        let source_file_path = target_dir.join("snapshot.txt");
+        std::fs::write(&source_file_path, &content).unwrap();
+        let bandwidth_limiter = Arc::new(BandwidthLimiter::new(1000));
+        // Empty the bucket so the download must wait for it to refill
+        bandwidth_limiter.consume(1000).await;
+        let http_file_downloader =
+            HttpFileDownloader::new(FeedbackSender::new(&[]), TestLogger::stdout())
+                .unwrap()
+                .with_bandwidth_limiter(bandwidth_limiter);
+
+        let unpack_dir = target_dir.join("unpacked");
+        std::fs::create_dir(&unpack_dir).unwrap();
+
+        let start = std::time::Instant::now();
+        http_file_downloader
+            .download_unpack(
+                &local_file_uri(&source_file_path),
+                0,
+                &unpack_dir,
+                None,
+                DownloadEvent::Digest {
+                    download_id: "id".to_string(),
+                },
+            )
+            .await
+            .unwrap();
+
+        assert!(start.elapsed() >= Duration::from_millis(250));
+    }
 }
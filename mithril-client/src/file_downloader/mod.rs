@@ -2,12 +2,14 @@
 //!
 //! This module provides the necessary abstractions to download files from different sources.
 
+mod bandwidth_limiter;
 mod http;
 mod interface;
 #[cfg(test)]
 mod mock_builder;
 mod retry;
 
+pub use bandwidth_limiter::BandwidthLimiter;
 pub use http::HttpFileDownloader;
 #[cfg(test)]
 pub use interface::MockFileDownloader;
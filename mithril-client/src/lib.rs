@@ -9,7 +9,9 @@
 //! - [Cardano Database v1 (aka Snapshot)][snapshot_client]: list, get, download archive and record statistics.
 //! - [Cardano Database v2][cardano_database_client] list, get, download archive and record statistics.
 //! - [Cardano transactions][cardano_transaction_client] list & get snapshot, get proofs.
+//! - [Cardano blocks][cardano_block_client] get proofs.
 //! - [Cardano stake distribution][cardano_stake_distribution_client] list, get and get by epoch.
+//! - [Cardano protocol parameters][cardano_protocol_parameters_client] list, get and get by epoch.
 //! - [Mithril stake distribution][mithril_stake_distribution_client] list and get.
 //! - [Certificates][certificate_client] list, get, and chain validation.
 //!
@@ -120,9 +122,11 @@ macro_rules! cfg_fs_unstable {
 }
 
 pub mod aggregator_client;
+pub mod cardano_block_client;
 cfg_unstable! {
     pub mod cardano_database_client;
 }
+pub mod cardano_protocol_parameters_client;
 pub mod cardano_stake_distribution_client;
 pub mod cardano_transaction_client;
 pub mod certificate_client;
@@ -130,9 +134,12 @@ mod client;
 pub mod feedback;
 mod message;
 pub mod mithril_stake_distribution_client;
+pub mod multi_aggregator_client;
 pub mod snapshot_client;
 cfg_fs! {
     pub mod file_downloader;
+
+    pub use utils::LedgerStateSnapshotConverter;
 }
 
 mod type_alias;
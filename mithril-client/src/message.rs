@@ -5,6 +5,7 @@ use std::path::Path;
 #[cfg(feature = "fs")]
 use std::sync::Arc;
 
+use mithril_common::entities::CardanoProtocolParameters as CardanoProtocolParametersEntity;
 use mithril_common::logging::LoggerExtensions;
 use mithril_common::protocol::SignerBuilder;
 use mithril_common::signable_builder::CardanoStakeDistributionSignableBuilder;
@@ -17,8 +18,9 @@ use mithril_common::{
 
 use crate::{
     common::{ProtocolMessage, ProtocolMessagePartKey},
-    CardanoStakeDistribution, MithrilCertificate, MithrilResult, MithrilSigner,
-    MithrilStakeDistribution, VerifiedCardanoTransactions,
+    CardanoProtocolParameters, CardanoStakeDistribution, CardanoTransactionsSearchResult,
+    MithrilCertificate, MithrilResult, MithrilSigner, MithrilStakeDistribution,
+    VerifiedCardanoBlocks, VerifiedCardanoTransactions,
 };
 
 /// A [MessageBuilder] can be used to compute the message of Mithril artifacts.
@@ -157,6 +159,31 @@ impl MessageBuilder {
         message
     }
 
+    /// Compute message for a Cardano Transactions Search Proofs.
+    ///
+    /// A match of the message only certifies the block ranges of the search, not which of
+    /// their transactions match the filter.
+    pub fn compute_cardano_transactions_search_proofs_message(
+        &self,
+        transactions_proofs_certificate: &MithrilCertificate,
+        search_result: &CardanoTransactionsSearchResult,
+    ) -> ProtocolMessage {
+        let mut message = transactions_proofs_certificate.protocol_message.clone();
+        search_result.fill_protocol_message(&mut message);
+        message
+    }
+
+    /// Compute message for a Cardano Blocks Proofs.
+    pub fn compute_cardano_blocks_proofs_message(
+        &self,
+        blocks_proofs_certificate: &MithrilCertificate,
+        verified_blocks: &VerifiedCardanoBlocks,
+    ) -> ProtocolMessage {
+        let mut message = blocks_proofs_certificate.protocol_message.clone();
+        verified_blocks.fill_protocol_message(&mut message);
+        message
+    }
+
     /// Compute message for a Cardano stake distribution.
     pub fn compute_cardano_stake_distribution_message(
         &self,
@@ -180,6 +207,30 @@ impl MessageBuilder {
 
         Ok(message)
     }
+
+    /// Compute message for Cardano protocol parameters.
+    pub fn compute_cardano_protocol_parameters_message(
+        &self,
+        certificate: &MithrilCertificate,
+        cardano_protocol_parameters: &CardanoProtocolParameters,
+    ) -> ProtocolMessage {
+        let cardano_protocol_parameters_entity = CardanoProtocolParametersEntity::new(
+            cardano_protocol_parameters.epoch,
+            cardano_protocol_parameters.protocol_parameters.clone(),
+        );
+
+        let mut message = certificate.protocol_message.clone();
+        message.set_message_part(
+            ProtocolMessagePartKey::CardanoProtocolParametersEpoch,
+            cardano_protocol_parameters.epoch.to_string(),
+        );
+        message.set_message_part(
+            ProtocolMessagePartKey::CardanoProtocolParametersHash,
+            cardano_protocol_parameters_entity.hash,
+        );
+
+        message
+    }
 }
 
 impl Default for MessageBuilder {
@@ -0,0 +1,657 @@
+//! An [AggregatorClient] that spreads its requests over several aggregators of the same network.
+//!
+//! The [MultiAggregatorClient] tries each aggregator in order and fails over to the next one
+//! when a request fails.
+//!
+//! When a cross-check quorum greater than one is configured, certificates and artifacts are
+//! fetched from all the aggregators and are only trusted if at least `quorum` of them agree:
+//! - on the signed message and aggregate verification key of a certificate,
+//! - on the hash of the certificate that certifies an artifact, since that is the link between
+//!   the artifact and the certificate chain that is verified afterward.
+//!
+//! Any disagreement between two aggregators on those values is reported as an equivocation.
+
+use anyhow::anyhow;
+use async_trait::async_trait;
+use serde::Deserialize;
+use slog::{debug, warn, Logger};
+use std::future::Future;
+use std::sync::Arc;
+
+use mithril_common::logging::LoggerExtensions;
+use mithril_common::messages::CertificateMessage;
+
+use crate::aggregator_client::{AggregatorClient, AggregatorClientError, AggregatorRequest};
+use crate::MithrilResult;
+
+/// Fields of a response that must be identical across aggregators for it to be trusted.
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum ResponseFingerprint {
+    /// A certificate, whose hash is the one requested by the caller.
+    Certificate {
+        hash: String,
+        signed_message: String,
+        aggregate_verification_key: String,
+    },
+    /// An artifact, identified by the certificate that certifies it.
+    Artifact { certificate_hash: String },
+}
+
+/// Part of any artifact message that links it to its certificate.
+#[derive(Deserialize)]
+struct CertifiedArtifactMessage {
+    certificate_hash: String,
+}
+
+impl ResponseFingerprint {
+    fn is_cross_checked(request: &AggregatorRequest) -> bool {
+        match request {
+            AggregatorRequest::GetCertificate { .. }
+            | AggregatorRequest::GetMithrilStakeDistribution { .. }
+            | AggregatorRequest::GetSnapshot { .. }
+            | AggregatorRequest::GetTransactionsProofs { .. }
+            | AggregatorRequest::SearchTransactionsProofs { .. }
+            | AggregatorRequest::GetBlocksProofs { .. }
+            | AggregatorRequest::GetCardanoTransactionSnapshot { .. }
+            | AggregatorRequest::GetCardanoStakeDistribution { .. }
+            | AggregatorRequest::GetCardanoStakeDistributionByEpoch { .. }
+            | AggregatorRequest::GetCardanoProtocolParameters { .. }
+            | AggregatorRequest::GetCardanoProtocolParametersByEpoch { .. } => true,
+            #[cfg(feature = "unstable")]
+            AggregatorRequest::GetCardanoDatabaseSnapshot { .. } => true,
+            _ => false,
+        }
+    }
+
+    fn from_response(request: &AggregatorRequest, content: &str) -> MithrilResult<Self> {
+        match request {
+            AggregatorRequest::GetCertificate { .. } => {
+                let message: CertificateMessage = serde_json::from_str(content)?;
+
+                Ok(Self::Certificate {
+                    hash: message.hash,
+                    signed_message: message.signed_message,
+                    aggregate_verification_key: message.aggregate_verification_key,
+                })
+            }
+            _ => {
+                let message: CertifiedArtifactMessage = serde_json::from_str(content)?;
+
+                Ok(Self::Artifact {
+                    certificate_hash: message.certificate_hash,
+                })
+            }
+        }
+    }
+
+    fn describe(&self) -> String {
+        match self {
+            Self::Certificate { hash, .. } => format!("certificate '{hash}'"),
+            Self::Artifact { certificate_hash } => {
+                format!("artifact certified by certificate '{certificate_hash}'")
+            }
+        }
+    }
+}
+
+/// An [AggregatorClient] backed by several aggregators, with failover and optional
+/// cross-checking of certificates and artifacts.
+pub struct MultiAggregatorClient {
+    aggregator_clients: Vec<Arc<dyn AggregatorClient>>,
+    certificate_cross_check_quorum: usize,
+    logger: Logger,
+}
+
+impl MultiAggregatorClient {
+    /// Constructs a new `MultiAggregatorClient`.
+    ///
+    /// The `certificate_cross_check_quorum` is the number of aggregators that must agree on a
+    /// certificate, or on the certificate of an artifact, before it is returned, a value of `1`
+    /// disables the cross-check.
+    pub fn new(
+        aggregator_clients: Vec<Arc<dyn AggregatorClient>>,
+        certificate_cross_check_quorum: usize,
+        logger: Logger,
+    ) -> MithrilResult<Self> {
+        if aggregator_clients.is_empty() {
+            return Err(anyhow!(
+                "At least one aggregator client must be provided to a MultiAggregatorClient"
+            ));
+        }
+        if certificate_cross_check_quorum == 0
+            || certificate_cross_check_quorum > aggregator_clients.len()
+        {
+            return Err(anyhow!(
+                "Invalid certificate cross-check quorum '{certificate_cross_check_quorum}': it must be between 1 and the number of aggregators ({})",
+                aggregator_clients.len()
+            ));
+        }
+
+        Ok(Self {
+            aggregator_clients,
+            certificate_cross_check_quorum,
+            logger: logger.new_with_component_name::<Self>(),
+        })
+    }
+
+    /// Send the request to each aggregator in order until one of them answers.
+    async fn send_with_failover<'a, F, Fut>(
+        &'a self,
+        request: AggregatorRequest,
+        send: F,
+    ) -> Result<String, AggregatorClientError>
+    where
+        F: Fn(&'a Arc<dyn AggregatorClient>, AggregatorRequest) -> Fut,
+        Fut: Future<Output = Result<String, AggregatorClientError>> + 'a,
+    {
+        let mut last_error = None;
+
+        for (index, client) in self.aggregator_clients.iter().enumerate() {
+            match send(client, request.clone()).await {
+                Ok(content) => return Ok(content),
+                Err(error) => {
+                    warn!(
+                        self.logger, "Aggregator #{index} failed to answer, trying next one";
+                        "route" => request.route(), "error" => ?error
+                    );
+                    last_error = Some(error);
+                }
+            }
+        }
+
+        Err(last_error.expect("there is at least one aggregator client"))
+    }
+
+    async fn get_cross_checked_content(
+        &self,
+        request: AggregatorRequest,
+    ) -> Result<String, AggregatorClientError> {
+        let responses = futures::future::join_all(
+            self.aggregator_clients
+                .iter()
+                .map(|client| client.get_content(request.clone())),
+        )
+        .await;
+
+        let mut agreeing_responses: Vec<(ResponseFingerprint, String, usize)> = vec![];
+        let mut last_error = None;
+        for (index, response) in responses.into_iter().enumerate() {
+            let content = match response {
+                Ok(content) => content,
+                Err(error) => {
+                    warn!(
+                        self.logger, "Aggregator #{index} failed to answer during cross-check";
+                        "route" => request.route(), "error" => ?error
+                    );
+                    last_error = Some(error);
+                    continue;
+                }
+            };
+            let fingerprint = match ResponseFingerprint::from_response(&request, &content) {
+                Ok(fingerprint) => fingerprint,
+                Err(error) => {
+                    warn!(
+                        self.logger, "Aggregator #{index} returned an invalid response during cross-check";
+                        "route" => request.route(), "error" => ?error
+                    );
+                    last_error = Some(AggregatorClientError::SubsystemError(error));
+                    continue;
+                }
+            };
+
+            match agreeing_responses
+                .iter_mut()
+                .find(|(known_fingerprint, _, _)| *known_fingerprint == fingerprint)
+            {
+                Some((_, _, count)) => *count += 1,
+                None => agreeing_responses.push((fingerprint, content, 1)),
+            }
+        }
+
+        if agreeing_responses.len() > 1 {
+            return Err(AggregatorClientError::CertificateCrossCheckFailed(anyhow!(
+                "Aggregators returned {} different responses for route '{}': {:?}",
+                agreeing_responses.len(),
+                request.route(),
+                agreeing_responses
+                    .iter()
+                    .map(|(fingerprint, _, count)| format!(
+                        "{} returned by {count} aggregator(s)",
+                        fingerprint.describe()
+                    ))
+                    .collect::<Vec<_>>()
+            )));
+        }
+
+        match agreeing_responses.pop() {
+            Some((_, content, count)) if count >= self.certificate_cross_check_quorum => {
+                debug!(
+                    self.logger, "Response cross-checked by {count} aggregator(s)";
+                    "route" => request.route()
+                );
+                Ok(content)
+            }
+            Some((fingerprint, _, count)) => {
+                Err(AggregatorClientError::CertificateCrossCheckFailed(anyhow!(
+                    "The {} was only confirmed by {count} aggregator(s), {} expected",
+                    fingerprint.describe(),
+                    self.certificate_cross_check_quorum
+                )))
+            }
+            None => Err(last_error.expect("there is at least one aggregator client")),
+        }
+    }
+}
+
+#[cfg_attr(target_family = "wasm", async_trait(?Send))]
+#[cfg_attr(not(target_family = "wasm"), async_trait)]
+impl AggregatorClient for MultiAggregatorClient {
+    async fn get_content(
+        &self,
+        request: AggregatorRequest,
+    ) -> Result<String, AggregatorClientError> {
+        if self.certificate_cross_check_quorum > 1
+            && ResponseFingerprint::is_cross_checked(&request)
+        {
+            self.get_cross_checked_content(request).await
+        } else {
+            self.send_with_failover(request, |client, request| client.get_content(request))
+                .await
+        }
+    }
+
+    async fn post_content(
+        &self,
+        request: AggregatorRequest,
+    ) -> Result<String, AggregatorClientError> {
+        self.send_with_failover(request, |client, request| client.post_content(request))
+            .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use mockall::predicate::eq;
+
+    use mithril_common::messages::MithrilStakeDistributionMessage;
+
+    use crate::aggregator_client::MockAggregatorClient;
+    use crate::test_utils::TestLogger;
+
+    use super::*;
+
+    fn certificate_json(hash: &str, signed_message: &str, avk: &str) -> String {
+        let message = CertificateMessage {
+            hash: hash.to_string(),
+            signed_message: signed_message.to_string(),
+            aggregate_verification_key: avk.to_string(),
+            ..CertificateMessage::dummy()
+        };
+
+        serde_json::to_string(&message).unwrap()
+    }
+
+    fn artifact_json(certificate_hash: &str) -> String {
+        let message = MithrilStakeDistributionMessage {
+            certificate_hash: certificate_hash.to_string(),
+            ..MithrilStakeDistributionMessage::dummy()
+        };
+
+        serde_json::to_string(&message).unwrap()
+    }
+
+    fn get_artifact_request() -> AggregatorRequest {
+        AggregatorRequest::GetMithrilStakeDistribution {
+            hash: "msd-hash".to_string(),
+        }
+    }
+
+    fn get_certificate_request() -> AggregatorRequest {
+        AggregatorRequest::GetCertificate {
+            hash: "cert-hash".to_string(),
+        }
+    }
+
+    fn client_returning(response: Result<String, AggregatorClientError>) -> MockAggregatorClient {
+        let mut client = MockAggregatorClient::new();
+        client
+            .expect_get_content()
+            .return_once(move |_| response)
+            .times(1);
+        client
+    }
+
+    fn client_failing_with_technical_error() -> MockAggregatorClient {
+        client_returning(Err(AggregatorClientError::RemoteServerTechnical(anyhow!(
+            "an error"
+        ))))
+    }
+
+    fn build_client(clients: Vec<MockAggregatorClient>, quorum: usize) -> MultiAggregatorClient {
+        MultiAggregatorClient::new(
+            clients
+                .into_iter()
+                .map(|c| Arc::new(c) as Arc<dyn AggregatorClient>)
+                .collect(),
+            quorum,
+            TestLogger::stdout(),
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn creation_fails_without_aggregator_client() {
+        MultiAggregatorClient::new(vec![], 1, TestLogger::stdout())
+            .expect_err("Creation without any aggregator client should fail");
+    }
+
+    #[test]
+    fn creation_fails_with_quorum_out_of_bounds() {
+        for quorum in [0, 3] {
+            MultiAggregatorClient::new(
+                vec![
+                    Arc::new(MockAggregatorClient::new()),
+                    Arc::new(MockAggregatorClient::new()),
+                ],
+                quorum,
+                TestLogger::stdout(),
+            )
+            .expect_err("Creation with a quorum out of bounds should fail");
+        }
+    }
+
+    mod failover {
+        use super::*;
+
+        #[tokio::test]
+        async fn get_content_returns_first_aggregator_response() {
+            let mut first_client = MockAggregatorClient::new();
+            first_client
+                .expect_get_content()
+                .with(eq(AggregatorRequest::ListCertificates))
+                .return_once(|_| Ok("first".to_string()))
+                .times(1);
+            let mut second_client = MockAggregatorClient::new();
+            second_client.expect_get_content().never();
+            let client = build_client(vec![first_client, second_client], 1);
+
+            let content = client
+                .get_content(AggregatorRequest::ListCertificates)
+                .await
+                .unwrap();
+
+            assert_eq!("first", content);
+        }
+
+        #[tokio::test]
+        async fn get_content_fails_over_to_next_aggregator_on_error() {
+            let client = build_client(
+                vec![
+                    client_failing_with_technical_error(),
+                    client_returning(Ok("second".to_string())),
+                ],
+                1,
+            );
+
+            let content = client
+                .get_content(AggregatorRequest::ListCertificates)
+                .await
+                .unwrap();
+
+            assert_eq!("second", content);
+        }
+
+        #[tokio::test]
+        async fn get_content_returns_last_error_if_all_aggregators_fail() {
+            let client = build_client(
+                vec![
+                    client_failing_with_technical_error(),
+                    client_returning(Err(AggregatorClientError::RemoteServerLogical(anyhow!(
+                        "not found"
+                    )))),
+                ],
+                1,
+            );
+
+            let error = client
+                .get_content(AggregatorRequest::ListCertificates)
+                .await
+                .expect_err("get_content should fail when all aggregators fail");
+
+            assert!(
+                matches!(error, AggregatorClientError::RemoteServerLogical(_)),
+                "Expected RemoteServerLogical error, got: {error:?}"
+            );
+        }
+
+        #[tokio::test]
+        async fn post_content_fails_over_to_next_aggregator_on_error() {
+            let mut first_client = MockAggregatorClient::new();
+            first_client
+                .expect_post_content()
+                .return_once(|_| Err(AggregatorClientError::SubsystemError(anyhow!("an error"))))
+                .times(1);
+            let mut second_client = MockAggregatorClient::new();
+            second_client
+                .expect_post_content()
+                .return_once(|_| Ok("second".to_string()))
+                .times(1);
+            let client = build_client(vec![first_client, second_client], 1);
+
+            let content = client
+                .post_content(AggregatorRequest::IncrementSnapshotStatistic {
+                    snapshot: "{}".to_string(),
+                })
+                .await
+                .unwrap();
+
+            assert_eq!("second", content);
+        }
+
+        #[tokio::test]
+        async fn certificate_is_not_cross_checked_with_a_quorum_of_one() {
+            let mut second_client = MockAggregatorClient::new();
+            second_client.expect_get_content().never();
+            let client = build_client(
+                vec![
+                    client_returning(Ok(certificate_json("cert-hash", "msg", "avk"))),
+                    second_client,
+                ],
+                1,
+            );
+
+            client.get_content(get_certificate_request()).await.unwrap();
+        }
+    }
+
+    mod cross_check {
+        use super::*;
+
+        #[tokio::test]
+        async fn returns_certificate_when_quorum_agrees() {
+            let certificate = certificate_json("cert-hash", "msg", "avk");
+            let client = build_client(
+                vec![
+                    client_returning(Ok(certificate.clone())),
+                    client_returning(Ok(certificate.clone())),
+                    client_failing_with_technical_error(),
+                ],
+                2,
+            );
+
+            let content = client.get_content(get_certificate_request()).await.unwrap();
+
+            assert_eq!(certificate, content);
+        }
+
+        #[tokio::test]
+        async fn fails_when_quorum_is_not_reached() {
+            let client = build_client(
+                vec![
+                    client_returning(Ok(certificate_json("cert-hash", "msg", "avk"))),
+                    client_failing_with_technical_error(),
+                    client_failing_with_technical_error(),
+                ],
+                2,
+            );
+
+            let error = client
+                .get_content(get_certificate_request())
+                .await
+                .expect_err("get_content should fail when quorum is not reached");
+
+            assert!(
+                matches!(error, AggregatorClientError::CertificateCrossCheckFailed(_)),
+                "Expected CertificateCrossCheckFailed error, got: {error:?}"
+            );
+        }
+
+        #[tokio::test]
+        async fn returns_last_error_when_all_aggregators_fail() {
+            let client = build_client(
+                vec![
+                    client_failing_with_technical_error(),
+                    client_failing_with_technical_error(),
+                ],
+                2,
+            );
+
+            let error = client
+                .get_content(get_certificate_request())
+                .await
+                .expect_err("get_content should fail when all aggregators fail");
+
+            assert!(
+                matches!(error, AggregatorClientError::RemoteServerTechnical(_)),
+                "Expected RemoteServerTechnical error, got: {error:?}"
+            );
+        }
+
+        #[tokio::test]
+        async fn detects_equivocation_even_if_quorum_is_reached() {
+            for (hash, signed_message, avk) in [
+                ("another-hash", "msg", "avk"),
+                ("cert-hash", "another-msg", "avk"),
+                ("cert-hash", "msg", "another-avk"),
+            ] {
+                let client = build_client(
+                    vec![
+                        client_returning(Ok(certificate_json("cert-hash", "msg", "avk"))),
+                        client_returning(Ok(certificate_json("cert-hash", "msg", "avk"))),
+                        client_returning(Ok(certificate_json(hash, signed_message, avk))),
+                    ],
+                    2,
+                );
+
+                let error = client
+                    .get_content(get_certificate_request())
+                    .await
+                    .expect_err("get_content should fail when aggregators disagree");
+
+                assert!(
+                    matches!(error, AggregatorClientError::CertificateCrossCheckFailed(_)),
+                    "Expected CertificateCrossCheckFailed error, got: {error:?}"
+                );
+            }
+        }
+
+        #[tokio::test]
+        async fn returns_artifact_when_quorum_agrees_on_its_certificate() {
+            let artifact = artifact_json("cert-hash");
+            let client = build_client(
+                vec![
+                    client_returning(Ok(artifact.clone())),
+                    client_returning(Ok(artifact.clone())),
+                    client_failing_with_technical_error(),
+                ],
+                2,
+            );
+
+            let content = client.get_content(get_artifact_request()).await.unwrap();
+
+            assert_eq!(artifact, content);
+        }
+
+        #[tokio::test]
+        async fn fails_when_quorum_is_not_reached_on_artifact_certificate() {
+            let client = build_client(
+                vec![
+                    client_returning(Ok(artifact_json("cert-hash"))),
+                    client_failing_with_technical_error(),
+                    client_failing_with_technical_error(),
+                ],
+                2,
+            );
+
+            let error = client
+                .get_content(get_artifact_request())
+                .await
+                .expect_err("get_content should fail when quorum is not reached");
+
+            assert!(
+                matches!(error, AggregatorClientError::CertificateCrossCheckFailed(_)),
+                "Expected CertificateCrossCheckFailed error, got: {error:?}"
+            );
+        }
+
+        #[tokio::test]
+        async fn detects_equivocation_on_artifact_certificate_even_if_quorum_is_reached() {
+            let client = build_client(
+                vec![
+                    client_returning(Ok(artifact_json("cert-hash"))),
+                    client_returning(Ok(artifact_json("cert-hash"))),
+                    client_returning(Ok(artifact_json("another-cert-hash"))),
+                ],
+                2,
+            );
+
+            let error = client
+                .get_content(get_artifact_request())
+                .await
+                .expect_err("get_content should fail when aggregators disagree");
+
+            assert!(
+                matches!(error, AggregatorClientError::CertificateCrossCheckFailed(_)),
+                "Expected CertificateCrossCheckFailed error, got: {error:?}"
+            );
+        }
+
+        #[tokio::test]
+        async fn artifact_responses_without_certificate_hash_are_rejected() {
+            let client = build_client(
+                vec![
+                    client_returning(Ok("{}".to_string())),
+                    client_returning(Ok("{}".to_string())),
+                ],
+                2,
+            );
+
+            let error = client
+                .get_content(get_artifact_request())
+                .await
+                .expect_err("get_content should fail when no response is a valid artifact");
+
+            assert!(
+                matches!(error, AggregatorClientError::SubsystemError(_)),
+                "Expected SubsystemError error, got: {error:?}"
+            );
+        }
+
+        #[tokio::test]
+        async fn other_requests_are_not_cross_checked() {
+            let mut second_client = MockAggregatorClient::new();
+            second_client.expect_get_content().never();
+            let client = build_client(
+                vec![client_returning(Ok("list".to_string())), second_client],
+                2,
+            );
+
+            let content = client
+                .get_content(AggregatorRequest::ListCertificates)
+                .await
+                .unwrap();
+
+            assert_eq!("list", content);
+        }
+    }
+}
@@ -35,6 +35,19 @@ pub use mithril_common::messages::CertificateMessage as MithrilCertificate;
 
 pub use mithril_common::messages::CertificateMetadataMessagePart as MithrilCertificateMetadata;
 
+/// A trusted checkpoint of a [Mithril certificate][MithrilCertificate] chain.
+///
+pub use mithril_common::certificate_chain::CertificateChainCheckpoint;
+
+/// A portable bundle of a [Mithril certificate][MithrilCertificate] chain, that can be verified
+/// without access to an aggregator.
+///
+pub use mithril_common::certificate_chain::CertificateChainBundle;
+
+/// Version of the [CertificateChainBundle] format
+///
+pub use mithril_common::certificate_chain::CERTIFICATE_CHAIN_BUNDLE_FORMAT_VERSION;
+
 /// List item of Mithril certificates
 ///
 pub use mithril_common::messages::CertificateListItemMessage as MithrilCertificateListItem;
@@ -53,6 +66,20 @@ pub use mithril_common::messages::VerifiedCardanoTransactions;
 
 pub use mithril_common::messages::VerifyCardanoTransactionsProofsError;
 
+pub use mithril_common::messages::CardanoTransactionsSearchProofsMessage as CardanoTransactionsSearchProofs;
+
+pub use mithril_common::messages::CardanoTransactionsSearchResult;
+
+pub use mithril_common::messages::VerifyCardanoTransactionsSearchProofsError;
+
+pub use mithril_common::messages::CardanoBlocksProofsMessage as CardanoBlocksProofs;
+
+pub use mithril_common::messages::CardanoBlocksSetProofMessagePart as CardanoBlocksSetProof;
+
+pub use mithril_common::messages::VerifiedCardanoBlocks;
+
+pub use mithril_common::messages::VerifyCardanoBlocksProofsError;
+
 /// A snapshot that allow to know up to which [point of time][common::CardanoDbBeacon] Mithril have certified Cardano transactions.
 pub use mithril_common::messages::CardanoTransactionSnapshotMessage as CardanoTransactionSnapshot;
 
@@ -65,13 +92,24 @@ pub use mithril_common::messages::CardanoStakeDistributionMessage as CardanoStak
 /// List item of Cardano stake distributions.
 pub use mithril_common::messages::CardanoStakeDistributionListItemMessage as CardanoStakeDistributionListItem;
 
+/// Cardano protocol parameters.
+pub use mithril_common::messages::CardanoProtocolParametersMessage as CardanoProtocolParameters;
+
+/// List item of Cardano protocol parameters.
+pub use mithril_common::messages::CardanoProtocolParametersListItemMessage as CardanoProtocolParametersListItem;
+
 /// `mithril-common` re-exports
 pub mod common {
     pub use mithril_common::entities::{
-        BlockHash, BlockNumber, CardanoDbBeacon, ChainPoint, CompressionAlgorithm, Epoch,
-        ImmutableFileNumber, ProtocolMessage, ProtocolMessagePartKey, ProtocolParameters,
-        SignedEntityType, SlotNumber, StakeDistribution, TransactionHash,
+        BlockHash, BlockNumber, CardanoDbBeacon, CardanoTransactionFilter, ChainPoint,
+        CompressionAlgorithm, Epoch, ExecutionUnitPrices, ExecutionUnits, ImmutableFileNumber,
+        LedgerGovernanceParameters, LedgerProtocolParameters, MetadataLabel, ProtocolMessage,
+        ProtocolMessagePartKey, ProtocolParameters, RationalNumber, SignedEntityType, SlotNumber,
+        StakeDistribution, TransactionHash,
     };
+    cfg_fs! {
+        pub use mithril_common::digesters::{LedgerFile, LedgerStateSnapshotLayout};
+    }
     cfg_unstable! {
         pub use mithril_common::entities::{
             AncillaryLocation, DigestLocation, ImmutablesLocation, MultiFilesUri, TemplateUri,
@@ -0,0 +1,300 @@
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+use anyhow::{anyhow, Context};
+use slog::{debug, warn, Logger};
+
+use mithril_common::digesters::{LedgerFile, LedgerStateSnapshotLayout, LEDGER_DIR};
+use mithril_common::logging::LoggerExtensions;
+
+use crate::MithrilResult;
+
+/// Converts the ledger state snapshot of a Cardano database between the UTxO-HD in-memory and
+/// LMDB layouts.
+///
+/// The conversion is delegated to the `snapshot-converter` tool distributed with the Cardano node.
+pub struct LedgerStateSnapshotConverter {
+    snapshot_converter_bin: PathBuf,
+    cardano_node_config: PathBuf,
+    logger: Logger,
+}
+
+impl LedgerStateSnapshotConverter {
+    /// Constructs a new `LedgerStateSnapshotConverter`.
+    pub fn new(snapshot_converter_bin: &Path, cardano_node_config: &Path, logger: Logger) -> Self {
+        Self {
+            snapshot_converter_bin: snapshot_converter_bin.to_path_buf(),
+            cardano_node_config: cardano_node_config.to_path_buf(),
+            logger: logger.new_with_component_name::<Self>(),
+        }
+    }
+
+    /// Converts the last ledger state snapshot of the given Cardano database to the target layout.
+    ///
+    /// The converted snapshot replaces the original one, nothing is done if the snapshot already
+    /// uses the target layout. If the conversion fails, the original snapshot is kept and the
+    /// partially converted one is removed.
+    pub fn convert_last_snapshot(
+        &self,
+        db_dir: &Path,
+        target_layout: LedgerStateSnapshotLayout,
+    ) -> MithrilResult<LedgerFile> {
+        if !target_layout.is_utxo_hd() {
+            return Err(anyhow!(
+                "Ledger state snapshots can only be converted to a UTxO-HD layout, got: '{target_layout}'"
+            ));
+        }
+
+        let ledger_files = LedgerFile::list_all_in_dir(&db_dir.join(LEDGER_DIR))?;
+        let last_ledger = ledger_files.last().ok_or_else(|| {
+            anyhow!(
+                "No ledger state snapshot found in directory: `{}`",
+                db_dir.display()
+            )
+        })?;
+        if last_ledger.layout() == target_layout {
+            debug!(
+                self.logger, "Ledger state snapshot already uses the target layout";
+                "snapshot" => ?last_ledger.path, "layout" => %target_layout
+            );
+            return Ok(last_ledger.clone());
+        }
+
+        let converted_path = last_ledger
+            .path
+            .with_file_name(format!("{}_{target_layout}", last_ledger.filename));
+        // Leftover of a previous failed conversion
+        Self::remove_if_exists(&converted_path)?;
+        if let Err(error) = self.run_snapshot_converter(last_ledger, &converted_path, target_layout)
+        {
+            if let Err(cleanup_error) = Self::remove_if_exists(&converted_path) {
+                warn!(
+                    self.logger, "Failed to remove the partially converted ledger state snapshot";
+                    "snapshot" => ?converted_path, "error" => ?cleanup_error
+                );
+            }
+            return Err(error);
+        }
+        self.replace_snapshot(&last_ledger.path, &converted_path)?;
+
+        let converted_ledger = LedgerFile::from_path(&last_ledger.path)
+            .filter(|ledger| ledger.layout() == target_layout)
+            .ok_or_else(|| {
+                anyhow!(
+                    "Converted ledger state snapshot `{}` does not use the '{target_layout}' layout",
+                    last_ledger.path.display()
+                )
+            })?;
+
+        Ok(converted_ledger)
+    }
+
+    fn run_snapshot_converter(
+        &self,
+        ledger: &LedgerFile,
+        output_path: &Path,
+        target_layout: LedgerStateSnapshotLayout,
+    ) -> MithrilResult<()> {
+        debug!(
+            self.logger, "Converting ledger state snapshot";
+            "snapshot" => ?ledger.path, "from" => %ledger.layout(), "to" => %target_layout
+        );
+        let output = Command::new(&self.snapshot_converter_bin)
+            .arg(Self::snapshot_converter_format(ledger.layout()))
+            .arg(&ledger.path)
+            .arg(Self::snapshot_converter_format(target_layout))
+            .arg(output_path)
+            .arg("cardano")
+            .arg("--config")
+            .arg(&self.cardano_node_config)
+            .output()
+            .with_context(|| {
+                format!(
+                    "Failed to run the snapshot converter: `{}`",
+                    self.snapshot_converter_bin.display()
+                )
+            })?;
+
+        if !output.status.success() {
+            return Err(anyhow!(
+                "Snapshot converter failed with status '{}': {}",
+                output.status,
+                String::from_utf8_lossy(&output.stderr)
+            ));
+        }
+
+        Ok(())
+    }
+
+    /// Swap the original snapshot with the converted one.
+    ///
+    /// The original snapshot is moved aside and only removed once the converted snapshot took its
+    /// place, so it's restored if the swap fails.
+    fn replace_snapshot(&self, original_path: &Path, converted_path: &Path) -> MithrilResult<()> {
+        let mut aside_path = original_path.as_os_str().to_owned();
+        aside_path.push("_original");
+        let aside_path = PathBuf::from(aside_path);
+
+        Self::remove_if_exists(&aside_path)?;
+        std::fs::rename(original_path, &aside_path).with_context(|| {
+            format!(
+                "Failed to move aside the original ledger state snapshot: `{}`",
+                original_path.display()
+            )
+        })?;
+
+        if let Err(error) = std::fs::rename(converted_path, original_path) {
+            let restore_result = std::fs::rename(&aside_path, original_path);
+            return Err(error).with_context(|| {
+                format!(
+                    "Failed to move the converted ledger state snapshot to: `{}`, original snapshot restored: {}",
+                    original_path.display(),
+                    restore_result.is_ok()
+                )
+            });
+        }
+
+        if let Err(error) = Self::remove_if_exists(&aside_path) {
+            warn!(
+                self.logger, "Failed to remove the original ledger state snapshot";
+                "snapshot" => ?aside_path, "error" => ?error
+            );
+        }
+
+        Ok(())
+    }
+
+    fn remove_if_exists(path: &Path) -> MithrilResult<()> {
+        if !path.exists() {
+            return Ok(());
+        }
+
+        if path.is_dir() {
+            std::fs::remove_dir_all(path)
+        } else {
+            std::fs::remove_file(path)
+        }
+        .with_context(|| format!("Failed to remove: `{}`", path.display()))
+    }
+
+    fn snapshot_converter_format(layout: LedgerStateSnapshotLayout) -> &'static str {
+        match layout {
+            LedgerStateSnapshotLayout::Legacy => "Legacy",
+            LedgerStateSnapshotLayout::InMemory => "Mem",
+            LedgerStateSnapshotLayout::Lmdb => "LMDB",
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use mithril_common::digesters::DummyCardanoDbBuilder;
+    use mithril_common::{current_function, temp_dir_create};
+
+    use crate::test_utils::TestLogger;
+
+    use super::*;
+
+    fn converter(snapshot_converter_bin: &Path) -> LedgerStateSnapshotConverter {
+        LedgerStateSnapshotConverter::new(
+            snapshot_converter_bin,
+            Path::new("config.json"),
+            TestLogger::stdout(),
+        )
+    }
+
+    #[test]
+    fn does_nothing_if_the_last_snapshot_already_uses_the_target_layout() {
+        let cardano_db = DummyCardanoDbBuilder::new(current_function!())
+            .with_ledger_files(&["437"])
+            .with_utxo_hd_ledger_snapshots(&["537"], LedgerStateSnapshotLayout::Lmdb)
+            .build();
+
+        let ledger = converter(Path::new("/not/existing/snapshot-converter"))
+            .convert_last_snapshot(cardano_db.get_dir(), LedgerStateSnapshotLayout::Lmdb)
+            .unwrap();
+
+        assert_eq!("537", ledger.filename);
+        assert_eq!(LedgerStateSnapshotLayout::Lmdb, ledger.layout());
+    }
+
+    #[test]
+    fn fails_if_the_target_layout_is_legacy() {
+        let cardano_db = DummyCardanoDbBuilder::new(current_function!())
+            .with_utxo_hd_ledger_snapshots(&["537"], LedgerStateSnapshotLayout::InMemory)
+            .build();
+
+        converter(Path::new("/not/existing/snapshot-converter"))
+            .convert_last_snapshot(cardano_db.get_dir(), LedgerStateSnapshotLayout::Legacy)
+            .expect_err("Conversion to the legacy layout should fail");
+    }
+
+    #[test]
+    fn fails_and_keep_the_original_snapshot_if_the_snapshot_converter_can_not_run() {
+        let cardano_db = DummyCardanoDbBuilder::new(current_function!())
+            .with_utxo_hd_ledger_snapshots(&["537"], LedgerStateSnapshotLayout::InMemory)
+            .build();
+
+        converter(Path::new("/not/existing/snapshot-converter"))
+            .convert_last_snapshot(cardano_db.get_dir(), LedgerStateSnapshotLayout::Lmdb)
+            .expect_err("Conversion should fail if the snapshot converter can not run");
+
+        let ledger = LedgerFile::from_path(&cardano_db.get_ledger_dir().join("537")).unwrap();
+        assert_eq!(LedgerStateSnapshotLayout::InMemory, ledger.layout());
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn replace_the_last_snapshot_with_the_converted_one() {
+        use std::os::unix::fs::PermissionsExt;
+
+        let test_dir = temp_dir_create!();
+        let cardano_db = DummyCardanoDbBuilder::new(current_function!())
+            .with_utxo_hd_ledger_snapshots(&["537"], LedgerStateSnapshotLayout::InMemory)
+            .build();
+        // Fake converter creating an LMDB snapshot in its output directory (fourth argument)
+        let fake_converter = test_dir.join("snapshot-converter");
+        std::fs::write(
+            &fake_converter,
+            "#!/bin/sh\nmkdir -p \"$4/tables\" && touch \"$4/state\" \"$4/tables/data.mdb\"\n",
+        )
+        .unwrap();
+        std::fs::set_permissions(&fake_converter, std::fs::Permissions::from_mode(0o755)).unwrap();
+
+        let ledger = converter(&fake_converter)
+            .convert_last_snapshot(cardano_db.get_dir(), LedgerStateSnapshotLayout::Lmdb)
+            .unwrap();
+
+        assert_eq!(cardano_db.get_ledger_dir().join("537"), ledger.path);
+        assert_eq!(LedgerStateSnapshotLayout::Lmdb, ledger.layout());
+        assert!(!cardano_db.get_ledger_dir().join("537_lmdb").exists());
+        assert!(!cardano_db.get_ledger_dir().join("537_original").exists());
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn fails_keep_the_original_snapshot_and_remove_the_converter_output_if_the_converter_fails() {
+        use std::os::unix::fs::PermissionsExt;
+
+        let test_dir = temp_dir_create!();
+        let cardano_db = DummyCardanoDbBuilder::new(current_function!())
+            .with_utxo_hd_ledger_snapshots(&["537"], LedgerStateSnapshotLayout::InMemory)
+            .build();
+        // Fake converter failing after a partial write of its output directory (fourth argument)
+        let fake_converter = test_dir.join("snapshot-converter");
+        std::fs::write(
+            &fake_converter,
+            "#!/bin/sh\nmkdir -p \"$4/tables\" && touch \"$4/state\"\nexit 1\n",
+        )
+        .unwrap();
+        std::fs::set_permissions(&fake_converter, std::fs::Permissions::from_mode(0o755)).unwrap();
+
+        converter(&fake_converter)
+            .convert_last_snapshot(cardano_db.get_dir(), LedgerStateSnapshotLayout::Lmdb)
+            .expect_err("Conversion should fail if the snapshot converter fails");
+
+        let ledger = LedgerFile::from_path(&cardano_db.get_ledger_dir().join("537")).unwrap();
+        assert_eq!(LedgerStateSnapshotLayout::InMemory, ledger.layout());
+        assert!(!cardano_db.get_ledger_dir().join("537_lmdb").exists());
+    }
+}
@@ -5,8 +5,10 @@ cfg_fs! {
     mod ancillary_verifier;
     mod stream_reader;
     mod bootstrap_files;
+    mod ledger_state_snapshot_converter;
 
     pub use ancillary_verifier::AncillaryVerifier;
+    pub use ledger_state_snapshot_converter::LedgerStateSnapshotConverter;
     pub use stream_reader::*;
     pub use bootstrap_files::*;
 }
@@ -96,6 +96,7 @@ fn generate_merkle_map(
         };
         let leaves_to_prove = mktree_to_prove
             .leaves()
+            .unwrap()
             .into_iter()
             .take(1)
             .collect::<Vec<_>>();
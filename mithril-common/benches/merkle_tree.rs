@@ -42,7 +42,7 @@ fn append_merkle_tree_benches(c: &mut Criterion) {
         ));
         for total_leaves in TOTAL_LEAVES_BENCHES.iter() {
             let mut mk_tree = generate_merkle_tree(*total_leaves);
-            let leaves_to_append = &mk_tree.leaves()[..*total_leaves_to_append];
+            let leaves_to_append = &mk_tree.leaves().unwrap()[..*total_leaves_to_append];
             group.bench_with_input(
                 BenchmarkId::from_parameter(total_leaves),
                 total_leaves,
@@ -64,7 +64,7 @@ fn create_merkle_tree_proof_benches(c: &mut Criterion) {
         ));
         for total_leaves in TOTAL_LEAVES_BENCHES.iter() {
             let mk_tree = generate_merkle_tree(*total_leaves);
-            let leaves_to_prove = &mk_tree.leaves()[..*total_leaves_to_prove];
+            let leaves_to_prove = &mk_tree.leaves().unwrap()[..*total_leaves_to_prove];
             group.bench_with_input(
                 BenchmarkId::from_parameter(total_leaves),
                 total_leaves,
@@ -86,7 +86,7 @@ fn verify_merkle_tree_proof_benches(c: &mut Criterion) {
         ));
         for total_leaves in TOTAL_LEAVES_BENCHES.iter() {
             let mk_tree = generate_merkle_tree(*total_leaves);
-            let leaves_to_prove = &mk_tree.leaves()[..*total_leaves_to_prove];
+            let leaves_to_prove = &mk_tree.leaves().unwrap()[..*total_leaves_to_prove];
             let mk_proof = mk_tree.compute_proof(leaves_to_prove).unwrap();
             group.bench_with_input(
                 BenchmarkId::from_parameter(total_leaves),
@@ -1,7 +1,11 @@
-use pallas_traverse::MultiEraBlock;
+use pallas_traverse::{MultiEraBlock, MultiEraTx};
+use std::collections::BTreeSet;
 use std::fmt::{Debug, Formatter};
 
-use crate::entities::{BlockNumber, CardanoTransaction, ChainPoint, SlotNumber, TransactionHash};
+use crate::entities::{
+    BlockNumber, CardanoBlock, CardanoTransaction, CardanoTransactionFilter,
+    CardanoTransactionIndexEntry, ChainPoint, SlotNumber, TransactionHash,
+};
 
 /// A block scanned from a Cardano database
 #[derive(Clone, PartialEq)]
@@ -14,6 +18,10 @@ pub struct ScannedBlock {
     pub slot_number: SlotNumber,
     /// Hashes of the transactions in the block
     pub transactions_hashes: Vec<TransactionHash>,
+    /// Filters matched by the transactions of the block (output addresses and metadata labels)
+    ///
+    /// Only filled when the filters extraction is enabled in the chain reader.
+    pub transactions_filters: Vec<(TransactionHash, Vec<CardanoTransactionFilter>)>,
 }
 
 impl ScannedBlock {
@@ -29,9 +37,22 @@ impl ScannedBlock {
             block_number,
             slot_number,
             transactions_hashes: transaction_hashes.into_iter().map(|h| h.into()).collect(),
+            transactions_filters: vec![],
         }
     }
 
+    /// Set the filters matched by the transactions of the block
+    pub fn with_transactions_filters<T: Into<TransactionHash>>(
+        mut self,
+        transactions_filters: Vec<(T, Vec<CardanoTransactionFilter>)>,
+    ) -> Self {
+        self.transactions_filters = transactions_filters
+            .into_iter()
+            .map(|(hash, filters)| (hash.into(), filters))
+            .collect();
+        self
+    }
+
     pub(crate) fn convert(multi_era_block: MultiEraBlock) -> Self {
         let mut transactions = Vec::new();
         for tx in &multi_era_block.txs() {
@@ -46,11 +67,57 @@ impl ScannedBlock {
         )
     }
 
+    /// Same as [Self::convert] but also extract the output addresses and metadata labels of
+    /// the transactions.
+    pub(crate) fn convert_with_transactions_filters(multi_era_block: MultiEraBlock) -> Self {
+        let transactions_filters = multi_era_block
+            .txs()
+            .iter()
+            .map(|tx| (tx.hash().to_string(), Self::extract_transaction_filters(tx)))
+            .collect::<Vec<_>>();
+
+        Self::convert(multi_era_block).with_transactions_filters(transactions_filters)
+    }
+
+    fn extract_transaction_filters(tx: &MultiEraTx) -> Vec<CardanoTransactionFilter> {
+        let mut filters = BTreeSet::new();
+        for output in tx.outputs() {
+            // Outputs with an address that can't be decoded are not indexed
+            if let Ok(address) = output.address() {
+                filters.insert(CardanoTransactionFilter::Address(address.to_string()));
+            }
+        }
+        if let Some(metadata) = tx.metadata().as_alonzo() {
+            for (label, _) in metadata.iter() {
+                filters.insert(CardanoTransactionFilter::MetadataLabel(*label));
+            }
+        }
+
+        filters.into_iter().collect()
+    }
+
     /// Number of transactions in the block
     pub fn transactions_len(&self) -> usize {
         self.transactions_hashes.len()
     }
 
+    /// Compute the index entries of the filters matched by the transactions of the block.
+    pub fn index_entries(&self) -> Vec<CardanoTransactionIndexEntry> {
+        self.transactions_filters
+            .iter()
+            .flat_map(|(transaction_hash, filters)| {
+                filters.iter().map(|filter| {
+                    CardanoTransactionIndexEntry::new(
+                        filter.clone(),
+                        transaction_hash,
+                        self.block_number,
+                        self.slot_number,
+                    )
+                })
+            })
+            .collect()
+    }
+
     /// Convert the scanned block into a list of Cardano transactions.
     ///
     /// Consume the block.
@@ -78,10 +145,21 @@ impl Debug for ScannedBlock {
             .field("block_number", &self.block_number)
             .field("slot_number", &self.slot_number)
             .field("transactions_hashes", &self.transactions_hashes)
+            .field("transactions_filters", &self.transactions_filters)
             .finish()
     }
 }
 
+impl From<&ScannedBlock> for CardanoBlock {
+    fn from(scanned_block: &ScannedBlock) -> Self {
+        CardanoBlock::new(
+            hex::encode(&scanned_block.block_hash),
+            scanned_block.block_number,
+            scanned_block.slot_number,
+        )
+    }
+}
+
 impl From<&ScannedBlock> for ChainPoint {
     fn from(scanned_block: &ScannedBlock) -> Self {
         ChainPoint::new(
@@ -91,3 +169,70 @@ impl From<&ScannedBlock> for ChainPoint {
         )
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn index_entries_list_all_filters_of_all_transactions() {
+        let block = ScannedBlock::new(
+            "block-hash",
+            BlockNumber(10),
+            SlotNumber(50),
+            vec!["tx-hash-1", "tx-hash-2"],
+        )
+        .with_transactions_filters(vec![
+            (
+                "tx-hash-1",
+                vec![
+                    CardanoTransactionFilter::Address("addr-1".to_string()),
+                    CardanoTransactionFilter::MetadataLabel(674),
+                ],
+            ),
+            (
+                "tx-hash-2",
+                vec![CardanoTransactionFilter::Address("addr-1".to_string())],
+            ),
+        ]);
+
+        assert_eq!(
+            vec![
+                CardanoTransactionIndexEntry::new(
+                    CardanoTransactionFilter::Address("addr-1".to_string()),
+                    "tx-hash-1",
+                    BlockNumber(10),
+                    SlotNumber(50)
+                ),
+                CardanoTransactionIndexEntry::new(
+                    CardanoTransactionFilter::MetadataLabel(674),
+                    "tx-hash-1",
+                    BlockNumber(10),
+                    SlotNumber(50)
+                ),
+                CardanoTransactionIndexEntry::new(
+                    CardanoTransactionFilter::Address("addr-1".to_string()),
+                    "tx-hash-2",
+                    BlockNumber(10),
+                    SlotNumber(50)
+                ),
+            ],
+            block.index_entries()
+        );
+    }
+
+    #[test]
+    fn index_entries_are_empty_when_filters_were_not_extracted() {
+        let block = ScannedBlock::new(
+            "block-hash",
+            BlockNumber(10),
+            SlotNumber(50),
+            vec!["tx-hash-1", "tx-hash-2"],
+        );
+
+        assert_eq!(
+            Vec::<CardanoTransactionIndexEntry>::new(),
+            block.index_entries()
+        );
+    }
+}
@@ -0,0 +1,417 @@
+//! A module used to define a portable bundle of a Certificate Chain
+//!
+use anyhow::{anyhow, Context};
+use serde::{Deserialize, Serialize};
+use slog::Logger;
+use std::collections::HashSet;
+use std::sync::Arc;
+
+use super::{
+    CertificateChainCheckpoint, CertificateRetriever, CertificateVerifier,
+    FileCertificateRetriever, MithrilCertificateVerifier,
+};
+use crate::crypto_helper::ProtocolGenesisVerificationKey;
+use crate::entities::{Certificate, ProtocolMessage};
+use crate::messages::CertificateMessage;
+use crate::StdResult;
+
+/// Version of the [CertificateChainBundle] format produced by this crate
+pub const CERTIFICATE_CHAIN_BUNDLE_FORMAT_VERSION: u32 = 1;
+
+/// A portable bundle of a certificate chain, that can be archived and verified later without
+/// any access to an aggregator.
+///
+/// The bundle is serialized in JSON with the following fields:
+/// * `format_version`: version of the bundle format, currently `1`,
+/// * `certificate_hash`: hash of the target certificate, the first one of the chain,
+/// * `artifact_message`: the protocol message computed from the certified artifact, which hash
+///   must be the message signed by the target certificate,
+/// * `checkpoint` (optional): the checkpoint at which the chain ends, if it doesn't end with a
+///   genesis certificate,
+/// * `certificates`: the certificates of the chain, in the format of the aggregator API, ordered
+///   from the target certificate back to the genesis certificate or the checkpoint certificate.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CertificateChainBundle {
+    /// Version of the bundle format
+    pub format_version: u32,
+
+    /// Hash of the target certificate
+    pub certificate_hash: String,
+
+    /// Protocol message computed from the certified artifact
+    pub artifact_message: ProtocolMessage,
+
+    /// Checkpoint at which the chain ends, if it doesn't end with a genesis certificate
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub checkpoint: Option<CertificateChainCheckpoint>,
+
+    /// Certificates of the chain, from the target certificate back to the genesis certificate or
+    /// the checkpoint certificate
+    pub certificates: Vec<CertificateMessage>,
+}
+
+impl CertificateChainBundle {
+    /// Build the bundle of the certificate chain starting at the given certificate.
+    ///
+    /// The `artifact_message` must be computed from the certified artifact (i.e. with the
+    /// `MessageBuilder` of the Mithril client library), it's not taken from the certificate so
+    /// that the bundle proves that the artifact is certified.
+    ///
+    /// The chain is followed back to the genesis certificate, or to the certificate of the given
+    /// checkpoint if it's found first.
+    pub async fn build(
+        certificate_retriever: &dyn CertificateRetriever,
+        certificate_hash: &str,
+        artifact_message: ProtocolMessage,
+        checkpoint: Option<CertificateChainCheckpoint>,
+    ) -> StdResult<Self> {
+        let mut certificates: Vec<Certificate> = vec![];
+        let mut visited_hashes = HashSet::new();
+        let mut next_hash = certificate_hash.to_string();
+        let mut reached_checkpoint = false;
+
+        loop {
+            if !visited_hashes.insert(next_hash.clone()) {
+                return Err(anyhow!(
+                    "Certificate chain contains a loop at certificate '{next_hash}'"
+                ));
+            }
+
+            let certificate = certificate_retriever
+                .get_certificate_details(&next_hash)
+                .await
+                .with_context(|| format!("Can not retrieve certificate '{next_hash}'"))?;
+            let is_genesis = certificate.is_genesis();
+            reached_checkpoint = checkpoint
+                .as_ref()
+                .is_some_and(|checkpoint| checkpoint.certificate_hash == certificate.hash);
+            next_hash = certificate.previous_hash.clone();
+            certificates.push(certificate);
+
+            if is_genesis || reached_checkpoint {
+                break;
+            }
+        }
+
+        let certificates = certificates
+            .into_iter()
+            .map(TryInto::try_into)
+            .collect::<StdResult<Vec<CertificateMessage>>>()?;
+
+        Ok(Self {
+            format_version: CERTIFICATE_CHAIN_BUNDLE_FORMAT_VERSION,
+            certificate_hash: certificate_hash.to_string(),
+            artifact_message,
+            checkpoint: checkpoint.filter(|_| reached_checkpoint),
+            certificates,
+        })
+    }
+
+    /// Verify the certificate chain of the bundle and that the target certificate signs the hash
+    /// of the artifact message, returns the target certificate if valid.
+    ///
+    /// The checkpoint embedded in the bundle is only trusted if it's signed with the genesis
+    /// signer or if it's part of the given `trusted_checkpoints`.
+    pub async fn verify(
+        &self,
+        genesis_verification_key: &ProtocolGenesisVerificationKey,
+        trusted_checkpoints: &[CertificateChainCheckpoint],
+        logger: Logger,
+    ) -> StdResult<Certificate> {
+        if self.format_version != CERTIFICATE_CHAIN_BUNDLE_FORMAT_VERSION {
+            return Err(anyhow!(
+                "Unsupported certificate chain bundle format version: {}, expected: {}",
+                self.format_version,
+                CERTIFICATE_CHAIN_BUNDLE_FORMAT_VERSION
+            ));
+        }
+
+        let certificate_retriever = FileCertificateRetriever::from_bundle(self)?;
+        let certificate = certificate_retriever
+            .get_certificate_details(&self.certificate_hash)
+            .await
+            .with_context(|| "The bundle does not contain its target certificate")?;
+
+        let mut checkpoints = trusted_checkpoints.to_vec();
+        if let Some(checkpoint) = self.checkpoint.as_ref().filter(|c| c.is_signed()) {
+            checkpoints.push(checkpoint.clone());
+        }
+        let verifier = MithrilCertificateVerifier::new(logger, Arc::new(certificate_retriever))
+            .with_trusted_checkpoints(checkpoints);
+        verifier
+            .verify_certificate_chain(certificate.clone(), genesis_verification_key)
+            .await
+            .with_context(|| {
+                format!(
+                    "Certificate chain of certificate '{}' is invalid",
+                    self.certificate_hash
+                )
+            })?;
+
+        if self.artifact_message.compute_hash() != certificate.signed_message {
+            return Err(anyhow!(
+                "The artifact message is not signed by the certificate '{}'",
+                self.certificate_hash
+            ));
+        }
+
+        Ok(certificate)
+    }
+}
+
+cfg_fs! {
+    use std::path::Path;
+
+    impl CertificateChainBundle {
+        /// Read a certificate chain bundle from a JSON file.
+        pub fn read_from_file(path: &Path) -> StdResult<Self> {
+            let file = std::fs::File::open(path).with_context(|| {
+                format!(
+                    "Could not open certificate chain bundle file '{}'",
+                    path.display()
+                )
+            })?;
+
+            serde_json::from_reader(file).with_context(|| {
+                format!(
+                    "Could not parse certificate chain bundle file '{}'",
+                    path.display()
+                )
+            })
+        }
+
+        /// Write the certificate chain bundle to a JSON file.
+        pub fn write_to_file(&self, path: &Path) -> StdResult<()> {
+            let file = std::fs::File::create(path).with_context(|| {
+                format!(
+                    "Could not create certificate chain bundle file '{}'",
+                    path.display()
+                )
+            })?;
+
+            serde_json::to_writer_pretty(file, self).with_context(|| {
+                format!(
+                    "Could not write certificate chain bundle file '{}'",
+                    path.display()
+                )
+            })
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::certificate_chain::FakeCertificaterRetriever;
+    use crate::crypto_helper::ProtocolGenesisSigner;
+    use crate::entities::ProtocolMessagePartKey;
+    use crate::test_utils::{CertificateChainBuilder, TestLogger};
+
+    use super::*;
+
+    fn build_certificate_chain(
+        total_certificates: u64,
+    ) -> (Vec<Certificate>, ProtocolGenesisVerificationKey) {
+        let (certificates, genesis_verifier) = CertificateChainBuilder::new()
+            .with_total_certificates(total_certificates)
+            .with_certificates_per_epoch(2)
+            .build();
+
+        (certificates, genesis_verifier.to_verification_key())
+    }
+
+    /// The certificates of the test chains sign the artifact message as their protocol message
+    fn compute_artifact_message(certificate: &Certificate) -> ProtocolMessage {
+        certificate.protocol_message.clone()
+    }
+
+    async fn build_bundle(
+        certificates: &[Certificate],
+        checkpoint: Option<CertificateChainCheckpoint>,
+    ) -> CertificateChainBundle {
+        let retriever = FakeCertificaterRetriever::from_certificates(certificates);
+
+        CertificateChainBundle::build(
+            &retriever,
+            &certificates[0].hash,
+            compute_artifact_message(&certificates[0]),
+            checkpoint,
+        )
+        .await
+        .unwrap()
+    }
+
+    #[tokio::test]
+    async fn build_bundle_back_to_genesis_certificate() {
+        let (certificates, _) = build_certificate_chain(5);
+
+        let bundle = build_bundle(&certificates, None).await;
+
+        assert_eq!(
+            CERTIFICATE_CHAIN_BUNDLE_FORMAT_VERSION,
+            bundle.format_version
+        );
+        assert_eq!(certificates[0].hash, bundle.certificate_hash);
+        assert_eq!(None, bundle.checkpoint);
+        assert_eq!(
+            certificates
+                .iter()
+                .map(|c| c.hash.clone())
+                .collect::<Vec<_>>(),
+            bundle
+                .certificates
+                .iter()
+                .map(|c| c.hash.clone())
+                .collect::<Vec<_>>()
+        );
+    }
+
+    #[tokio::test]
+    async fn build_bundle_back_to_checkpoint_certificate() {
+        let (certificates, _) = build_certificate_chain(5);
+        let checkpoint = CertificateChainCheckpoint::from_certificate(&certificates[2]);
+
+        let bundle = build_bundle(&certificates, Some(checkpoint.clone())).await;
+
+        assert_eq!(Some(checkpoint), bundle.checkpoint);
+        assert_eq!(3, bundle.certificates.len());
+    }
+
+    #[tokio::test]
+    async fn build_bundle_without_checkpoint_if_not_found_in_chain() {
+        let (certificates, _) = build_certificate_chain(5);
+        let (other_certificates, _) = build_certificate_chain(3);
+        let checkpoint = CertificateChainCheckpoint::from_certificate(&other_certificates[0]);
+
+        let bundle = build_bundle(&certificates, Some(checkpoint)).await;
+
+        assert_eq!(None, bundle.checkpoint);
+        assert_eq!(5, bundle.certificates.len());
+    }
+
+    #[tokio::test]
+    async fn verify_bundle_back_to_genesis_certificate() {
+        let (certificates, genesis_verification_key) = build_certificate_chain(5);
+        let bundle = build_bundle(&certificates, None).await;
+
+        let certificate = bundle
+            .verify(&genesis_verification_key, &[], TestLogger::stdout())
+            .await
+            .unwrap();
+
+        assert_eq!(certificates[0], certificate);
+    }
+
+    #[tokio::test]
+    async fn verify_bundle_back_to_signed_checkpoint() {
+        let (certificates, genesis_verification_key) = build_certificate_chain(5);
+        let checkpoint = CertificateChainCheckpoint::from_certificate(&certificates[2])
+            .sign(&ProtocolGenesisSigner::create_deterministic_signer())
+            .unwrap();
+        let bundle = build_bundle(&certificates, Some(checkpoint)).await;
+
+        bundle
+            .verify(&genesis_verification_key, &[], TestLogger::stdout())
+            .await
+            .expect("Bundle ending with a signed checkpoint should be valid");
+    }
+
+    #[tokio::test]
+    async fn verify_bundle_back_to_user_pinned_checkpoint_only_if_trusted() {
+        let (certificates, genesis_verification_key) = build_certificate_chain(5);
+        let checkpoint = CertificateChainCheckpoint::from_certificate(&certificates[2]);
+        let bundle = build_bundle(&certificates, Some(checkpoint.clone())).await;
+
+        bundle
+            .verify(&genesis_verification_key, &[], TestLogger::stdout())
+            .await
+            .expect_err("An unsigned checkpoint of the bundle should not be trusted");
+        bundle
+            .verify(
+                &genesis_verification_key,
+                &[checkpoint],
+                TestLogger::stdout(),
+            )
+            .await
+            .expect("A trusted checkpoint should end the chain verification");
+    }
+
+    #[tokio::test]
+    async fn verify_bundle_fails_if_artifact_message_is_tampered() {
+        let (certificates, genesis_verification_key) = build_certificate_chain(5);
+        let mut bundle = build_bundle(&certificates, None).await;
+        bundle.artifact_message.set_message_part(
+            ProtocolMessagePartKey::SnapshotDigest,
+            "tampered-digest".to_string(),
+        );
+
+        bundle
+            .verify(&genesis_verification_key, &[], TestLogger::stdout())
+            .await
+            .expect_err("A tampered artifact message should be invalid");
+    }
+
+    #[tokio::test]
+    async fn build_bundle_embeds_the_given_artifact_message() {
+        let (certificates, _) = build_certificate_chain(5);
+        let retriever = FakeCertificaterRetriever::from_certificates(&certificates);
+        let mut artifact_message = compute_artifact_message(&certificates[0]);
+        artifact_message.set_message_part(
+            ProtocolMessagePartKey::SnapshotDigest,
+            "artifact-digest".to_string(),
+        );
+
+        let bundle = CertificateChainBundle::build(
+            &retriever,
+            &certificates[0].hash,
+            artifact_message.clone(),
+            None,
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(artifact_message, bundle.artifact_message);
+    }
+
+    #[tokio::test]
+    async fn verify_bundle_fails_if_artifact_message_is_not_signed_by_the_certificate() {
+        let (certificates, genesis_verification_key) = build_certificate_chain(5);
+        let retriever = FakeCertificaterRetriever::from_certificates(&certificates);
+        // Message computed from an artifact certified by another certificate of the chain
+        let bundle = CertificateChainBundle::build(
+            &retriever,
+            &certificates[0].hash,
+            compute_artifact_message(&certificates[1]),
+            None,
+        )
+        .await
+        .unwrap();
+
+        bundle
+            .verify(&genesis_verification_key, &[], TestLogger::stdout())
+            .await
+            .expect_err("An artifact message not signed by the certificate should be invalid");
+    }
+
+    #[tokio::test]
+    async fn verify_bundle_fails_if_format_version_is_unsupported() {
+        let (certificates, genesis_verification_key) = build_certificate_chain(5);
+        let mut bundle = build_bundle(&certificates, None).await;
+        bundle.format_version = CERTIFICATE_CHAIN_BUNDLE_FORMAT_VERSION + 1;
+
+        bundle
+            .verify(&genesis_verification_key, &[], TestLogger::stdout())
+            .await
+            .expect_err("An unsupported format version should be rejected");
+    }
+
+    #[tokio::test]
+    async fn bundle_serialization_roundtrip() {
+        let (certificates, _) = build_certificate_chain(5);
+        let bundle = build_bundle(&certificates, None).await;
+
+        let json = serde_json::to_string(&bundle).unwrap();
+        let deserialized: CertificateChainBundle = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(bundle, deserialized);
+    }
+}
@@ -0,0 +1,191 @@
+//! A module used to define trusted checkpoints of a Certificate Chain
+//!
+use anyhow::Context;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+use crate::crypto_helper::{
+    ProtocolAggregateVerificationKey, ProtocolGenesisSignature, ProtocolGenesisSigner,
+    ProtocolGenesisVerificationKey,
+};
+use crate::entities::{Certificate, Epoch};
+use crate::StdResult;
+
+/// A trusted checkpoint of a certificate chain.
+///
+/// A [certificate verifier][crate::certificate_chain::CertificateVerifier] can stop the
+/// verification of a certificate chain at a checkpoint instead of walking it back to the genesis
+/// certificate: the checkpoint certificate is then used as an alternative trust anchor.
+///
+/// A checkpoint is either:
+/// * **signed**: its `genesis_signature` is verified with the genesis verification key, so it can be
+///   distributed by untrusted parties,
+/// * **user-pinned**: without signature, it is trusted as is by the user who configured it.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CertificateChainCheckpoint {
+    /// Hash of the checkpoint certificate
+    pub certificate_hash: String,
+
+    /// Epoch of the checkpoint certificate
+    pub epoch: Epoch,
+
+    /// Aggregate verification key of the checkpoint certificate
+    pub aggregate_verification_key: ProtocolAggregateVerificationKey,
+
+    /// Signature of the checkpoint with the genesis signer, if any
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub genesis_signature: Option<ProtocolGenesisSignature>,
+}
+
+impl CertificateChainCheckpoint {
+    /// Create a user-pinned checkpoint from a certificate
+    pub fn from_certificate(certificate: &Certificate) -> Self {
+        Self {
+            certificate_hash: certificate.hash.clone(),
+            epoch: certificate.epoch,
+            aggregate_verification_key: certificate.aggregate_verification_key.clone(),
+            genesis_signature: None,
+        }
+    }
+
+    /// Sign the checkpoint with the genesis signer
+    pub fn sign(mut self, genesis_signer: &ProtocolGenesisSigner) -> StdResult<Self> {
+        let message = self.compute_signable_message()?;
+        self.genesis_signature = Some(genesis_signer.sign(&message));
+
+        Ok(self)
+    }
+
+    /// Check if the checkpoint is signed with the genesis signer
+    pub fn is_signed(&self) -> bool {
+        self.genesis_signature.is_some()
+    }
+
+    /// Verify the genesis signature of the checkpoint, a user-pinned checkpoint is always valid
+    pub fn verify_signature(
+        &self,
+        genesis_verification_key: &ProtocolGenesisVerificationKey,
+    ) -> StdResult<()> {
+        match &self.genesis_signature {
+            Some(signature) => {
+                let message = self.compute_signable_message()?;
+                genesis_verification_key
+                    .verify(&message, signature)
+                    .with_context(|| {
+                        format!(
+                            "Invalid genesis signature for the checkpoint of certificate '{}'",
+                            self.certificate_hash
+                        )
+                    })
+            }
+            None => Ok(()),
+        }
+    }
+
+    /// Check if the checkpoint matches the given certificate
+    pub fn matches_certificate(&self, certificate: &Certificate) -> bool {
+        self.certificate_hash == certificate.hash
+            && self.epoch == certificate.epoch
+            && self.aggregate_verification_key == certificate.aggregate_verification_key
+    }
+
+    /// Compute the message signed by the genesis signer for this checkpoint
+    pub fn compute_signable_message(&self) -> StdResult<Vec<u8>> {
+        let mut hasher = Sha256::new();
+        hasher.update(self.certificate_hash.as_bytes());
+        hasher.update(self.epoch.to_be_bytes());
+        hasher.update(
+            self.aggregate_verification_key
+                .to_json_hex()
+                .with_context(|| "Can not encode the checkpoint aggregate verification key")?
+                .as_bytes(),
+        );
+
+        Ok(hex::encode(hasher.finalize()).into_bytes())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::crypto_helper::tests_setup::setup_certificate_chain;
+
+    use super::*;
+
+    #[test]
+    fn user_pinned_checkpoint_signature_is_always_valid() {
+        let (certificates, genesis_verifier) = setup_certificate_chain(5, 2);
+        let checkpoint = CertificateChainCheckpoint::from_certificate(&certificates[1]);
+
+        assert!(!checkpoint.is_signed());
+        checkpoint
+            .verify_signature(&genesis_verifier.to_verification_key())
+            .expect("A user-pinned checkpoint should be valid");
+    }
+
+    #[test]
+    fn signed_checkpoint_is_valid_with_the_genesis_verification_key() {
+        let (certificates, genesis_verifier) = setup_certificate_chain(5, 2);
+        let checkpoint = CertificateChainCheckpoint::from_certificate(&certificates[1])
+            .sign(&ProtocolGenesisSigner::create_deterministic_signer())
+            .unwrap();
+
+        assert!(checkpoint.is_signed());
+        checkpoint
+            .verify_signature(&genesis_verifier.to_verification_key())
+            .expect("The signed checkpoint should be valid");
+    }
+
+    #[test]
+    fn signed_checkpoint_is_invalid_if_tampered() {
+        let (certificates, genesis_verifier) = setup_certificate_chain(5, 2);
+        let mut checkpoint = CertificateChainCheckpoint::from_certificate(&certificates[1])
+            .sign(&ProtocolGenesisSigner::create_deterministic_signer())
+            .unwrap();
+        checkpoint.epoch += 1;
+
+        checkpoint
+            .verify_signature(&genesis_verifier.to_verification_key())
+            .expect_err("A tampered checkpoint should be invalid");
+    }
+
+    #[test]
+    fn signed_checkpoint_is_invalid_with_another_genesis_verification_key() {
+        let (certificates, _) = setup_certificate_chain(5, 2);
+        let checkpoint = CertificateChainCheckpoint::from_certificate(&certificates[1])
+            .sign(&ProtocolGenesisSigner::create_non_deterministic_signer())
+            .unwrap();
+
+        checkpoint
+            .verify_signature(
+                &ProtocolGenesisSigner::create_deterministic_signer()
+                    .create_verifier()
+                    .to_verification_key(),
+            )
+            .expect_err("The checkpoint should be invalid with another genesis verification key");
+    }
+
+    #[test]
+    fn checkpoint_matches_its_certificate_only() {
+        let (certificates, _) = setup_certificate_chain(5, 2);
+        let checkpoint = CertificateChainCheckpoint::from_certificate(&certificates[1]);
+
+        assert!(checkpoint.matches_certificate(&certificates[1]));
+        assert!(!checkpoint.matches_certificate(&certificates[2]));
+    }
+
+    #[test]
+    fn checkpoint_serialization_roundtrip() {
+        let (certificates, _) = setup_certificate_chain(5, 2);
+        for checkpoint in [
+            CertificateChainCheckpoint::from_certificate(&certificates[1]),
+            CertificateChainCheckpoint::from_certificate(&certificates[1])
+                .sign(&ProtocolGenesisSigner::create_deterministic_signer())
+                .unwrap(),
+        ] {
+            let json = serde_json::to_string(&checkpoint).unwrap();
+            let deserialized: CertificateChainCheckpoint = serde_json::from_str(&json).unwrap();
+
+            assert_eq!(checkpoint, deserialized);
+        }
+    }
+}
@@ -7,7 +7,7 @@ use slog::{debug, Logger};
 use std::sync::Arc;
 use thiserror::Error;
 
-use super::CertificateRetriever;
+use super::{CertificateChainCheckpoint, CertificateRetriever};
 use crate::crypto_helper::{
     ProtocolAggregateVerificationKey, ProtocolGenesisError, ProtocolGenesisVerificationKey,
     ProtocolMultiSignature,
@@ -74,6 +74,11 @@ pub enum CertificateVerifierError {
     #[error("certificate chain infinite loop error")]
     CertificateChainInfiniteLoop,
 
+    /// Error raised when a [Certificate] matching the hash of a trusted checkpoint doesn't match
+    /// its `epoch` or `aggregate_verification_key`.
+    #[error("certificate chain checkpoint unmatch error")]
+    CertificateChainCheckpointUnmatch,
+
     /// Error raised when [CertificateVerifier::verify_genesis_certificate] was called with a
     /// certificate that's not a genesis certificate.
     #[error("can't validate genesis certificate: given certificate isn't a genesis certificate")]
@@ -106,6 +111,8 @@ pub trait CertificateVerifier: Send + Sync {
     ) -> StdResult<()>;
 
     /// Verify if a Certificate is valid and returns the previous Certificate in the chain if exists
+    ///
+    /// No previous Certificate is returned for a genesis certificate or a trusted checkpoint.
     async fn verify_certificate(
         &self,
         certificate: &Certificate,
@@ -134,6 +141,7 @@ pub trait CertificateVerifier: Send + Sync {
 pub struct MithrilCertificateVerifier {
     logger: Logger,
     certificate_retriever: Arc<dyn CertificateRetriever>,
+    trusted_checkpoints: Vec<CertificateChainCheckpoint>,
 }
 
 impl MithrilCertificateVerifier {
@@ -143,9 +151,60 @@ impl MithrilCertificateVerifier {
         Self {
             logger: logger.new_with_component_name::<Self>(),
             certificate_retriever,
+            trusted_checkpoints: vec![],
         }
     }
 
+    /// Set the trusted checkpoints at which the verification of a certificate chain can stop
+    /// instead of walking it back to the genesis certificate.
+    pub fn with_trusted_checkpoints(
+        mut self,
+        checkpoints: Vec<CertificateChainCheckpoint>,
+    ) -> Self {
+        self.trusted_checkpoints = checkpoints;
+        self
+    }
+
+    fn find_trusted_checkpoint(
+        &self,
+        certificate_hash: &str,
+    ) -> Option<&CertificateChainCheckpoint> {
+        self.trusted_checkpoints
+            .iter()
+            .find(|checkpoint| checkpoint.certificate_hash == certificate_hash)
+    }
+
+    /// Verify a standard certificate that is a trusted checkpoint, without its previous certificate
+    fn verify_checkpoint_certificate(
+        &self,
+        certificate: &Certificate,
+        checkpoint: &CertificateChainCheckpoint,
+        genesis_verification_key: &ProtocolGenesisVerificationKey,
+    ) -> StdResult<()> {
+        let multi_signature = match &certificate.signature {
+            CertificateSignature::MultiSignature(_, signature) => Ok(signature),
+            _ => Err(CertificateVerifierError::InvalidStandardCertificateProvided),
+        }?;
+        checkpoint.verify_signature(genesis_verification_key)?;
+        if !checkpoint.matches_certificate(certificate) {
+            return Err(anyhow!(
+                CertificateVerifierError::CertificateChainCheckpointUnmatch
+            ));
+        }
+        self.verify_is_not_in_infinite_loop(certificate)?;
+        self.verify_hash_matches_content(certificate)?;
+        self.verify_signed_message_matches_hashed_protocol_message(certificate)?;
+        self.verify_multi_signature(
+            certificate.signed_message.as_bytes(),
+            multi_signature,
+            &certificate.aggregate_verification_key,
+            &certificate.metadata.protocol_parameters,
+        )?;
+        self.verify_epoch_matches_protocol_message(certificate)?;
+
+        Ok(())
+    }
+
     async fn fetch_previous_certificate(
         &self,
         certificate: &Certificate,
@@ -406,6 +465,21 @@ impl CertificateVerifier for MithrilCertificateVerifier {
                 Ok(None)
             }
             CertificateSignature::MultiSignature(_, _) => {
+                if let Some(checkpoint) = self.find_trusted_checkpoint(&certificate.hash) {
+                    debug!(
+                        self.logger, "Certificate is a trusted checkpoint, stopping the chain verification";
+                        "certificate_hash" => &certificate.hash,
+                        "checkpoint_is_signed" => checkpoint.is_signed(),
+                    );
+                    self.verify_checkpoint_certificate(
+                        certificate,
+                        checkpoint,
+                        genesis_verification_key,
+                    )?;
+
+                    return Ok(None);
+                }
+
                 let previous_certificate = self.fetch_previous_certificate(certificate).await?;
                 self.verify_standard_certificate(certificate, &previous_certificate)
                     .await?;
@@ -428,7 +502,7 @@ mod tests {
     use super::*;
 
     use crate::certificate_chain::{CertificateRetrieverError, FakeCertificaterRetriever};
-    use crate::crypto_helper::{tests_setup::*, ProtocolClerk};
+    use crate::crypto_helper::{tests_setup::*, ProtocolClerk, ProtocolGenesisSigner};
     use crate::test_utils::{
         CertificateChainBuilder, CertificateChainBuilderContext, MithrilFixtureBuilder, TestLogger,
     };
@@ -1074,6 +1148,93 @@ mod tests {
         assert_error_matches!(CertificateVerifierError::CertificateHashUnmatch, error)
     }
 
+    #[tokio::test]
+    async fn verify_certificate_chain_stops_at_a_user_pinned_trusted_checkpoint() {
+        let (fake_certificates, genesis_verifier) = setup_certificate_chain(7, 2);
+        let checkpoint = CertificateChainCheckpoint::from_certificate(&fake_certificates[3]);
+        // The certificates older than the checkpoint are not available
+        let certificate_retriever =
+            FakeCertificaterRetriever::from_certificates(&fake_certificates[..=3]);
+        let verifier =
+            MithrilCertificateVerifier::new(TestLogger::stdout(), Arc::new(certificate_retriever))
+                .with_trusted_checkpoints(vec![checkpoint]);
+
+        verifier
+            .verify_certificate_chain(
+                fake_certificates[0].clone(),
+                &genesis_verifier.to_verification_key(),
+            )
+            .await
+            .expect("verify_certificate_chain should stop at the trusted checkpoint");
+    }
+
+    #[tokio::test]
+    async fn verify_certificate_chain_stops_at_a_signed_trusted_checkpoint() {
+        let (fake_certificates, genesis_verifier) = setup_certificate_chain(7, 2);
+        let checkpoint = CertificateChainCheckpoint::from_certificate(&fake_certificates[3])
+            .sign(&ProtocolGenesisSigner::create_deterministic_signer())
+            .unwrap();
+        let certificate_retriever =
+            FakeCertificaterRetriever::from_certificates(&fake_certificates[..=3]);
+        let verifier =
+            MithrilCertificateVerifier::new(TestLogger::stdout(), Arc::new(certificate_retriever))
+                .with_trusted_checkpoints(vec![checkpoint]);
+
+        verifier
+            .verify_certificate_chain(
+                fake_certificates[0].clone(),
+                &genesis_verifier.to_verification_key(),
+            )
+            .await
+            .expect("verify_certificate_chain should stop at the trusted checkpoint");
+    }
+
+    #[tokio::test]
+    async fn verify_certificate_chain_fails_when_signed_trusted_checkpoint_is_invalid() {
+        let (fake_certificates, genesis_verifier) = setup_certificate_chain(7, 2);
+        let checkpoint = CertificateChainCheckpoint::from_certificate(&fake_certificates[3])
+            .sign(&ProtocolGenesisSigner::create_non_deterministic_signer())
+            .unwrap();
+        let certificate_retriever =
+            FakeCertificaterRetriever::from_certificates(&fake_certificates);
+        let verifier =
+            MithrilCertificateVerifier::new(TestLogger::stdout(), Arc::new(certificate_retriever))
+                .with_trusted_checkpoints(vec![checkpoint]);
+
+        verifier
+            .verify_certificate_chain(
+                fake_certificates[0].clone(),
+                &genesis_verifier.to_verification_key(),
+            )
+            .await
+            .expect_err("verify_certificate_chain should fail with an invalid checkpoint");
+    }
+
+    #[tokio::test]
+    async fn verify_certificate_chain_fails_when_trusted_checkpoint_does_not_match_certificate() {
+        let (fake_certificates, genesis_verifier) = setup_certificate_chain(7, 2);
+        let mut checkpoint = CertificateChainCheckpoint::from_certificate(&fake_certificates[3]);
+        checkpoint.epoch += 10;
+        let certificate_retriever =
+            FakeCertificaterRetriever::from_certificates(&fake_certificates);
+        let verifier =
+            MithrilCertificateVerifier::new(TestLogger::stdout(), Arc::new(certificate_retriever))
+                .with_trusted_checkpoints(vec![checkpoint]);
+
+        let error = verifier
+            .verify_certificate_chain(
+                fake_certificates[0].clone(),
+                &genesis_verifier.to_verification_key(),
+            )
+            .await
+            .expect_err("verify_certificate_chain should fail");
+
+        assert_error_matches!(
+            CertificateVerifierError::CertificateChainCheckpointUnmatch,
+            error
+        )
+    }
+
     #[tokio::test]
     async fn verify_certificate_chain_fails_when_adversarial_with_registered_signer_forgery_through_protocol_parameters(
     ) {
@@ -0,0 +1,141 @@
+//! A module used to retrieve the Certificate Chain from a certificate chain bundle
+//!
+use anyhow::anyhow;
+use async_trait::async_trait;
+use std::collections::HashMap;
+
+use super::{CertificateChainBundle, CertificateRetriever, CertificateRetrieverError};
+use crate::entities::Certificate;
+use crate::StdResult;
+
+/// A [CertificateRetriever] that retrieves the certificates from a [CertificateChainBundle],
+/// without any access to an aggregator.
+pub struct FileCertificateRetriever {
+    certificates: HashMap<String, Certificate>,
+}
+
+impl FileCertificateRetriever {
+    /// Create a new [FileCertificateRetriever] from the certificates of a bundle
+    pub fn from_bundle(bundle: &CertificateChainBundle) -> StdResult<Self> {
+        let certificates = bundle
+            .certificates
+            .iter()
+            .map(|message| {
+                let certificate: Certificate = message.clone().try_into()?;
+                Ok((certificate.hash.clone(), certificate))
+            })
+            .collect::<StdResult<HashMap<_, _>>>()?;
+
+        Ok(Self { certificates })
+    }
+}
+
+cfg_fs! {
+    use std::path::Path;
+
+    impl FileCertificateRetriever {
+        /// Create a new [FileCertificateRetriever] from a certificate chain bundle file
+        pub fn from_file(path: &Path) -> StdResult<Self> {
+            Self::from_bundle(&CertificateChainBundle::read_from_file(path)?)
+        }
+    }
+}
+
+#[cfg_attr(target_family = "wasm", async_trait(?Send))]
+#[cfg_attr(not(target_family = "wasm"), async_trait)]
+impl CertificateRetriever for FileCertificateRetriever {
+    async fn get_certificate_details(
+        &self,
+        certificate_hash: &str,
+    ) -> Result<Certificate, CertificateRetrieverError> {
+        self.certificates
+            .get(certificate_hash)
+            .cloned()
+            .ok_or_else(|| {
+                CertificateRetrieverError(anyhow!(
+                    "Certificate '{certificate_hash}' not found in the certificate chain bundle"
+                ))
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::entities::ProtocolMessage;
+    use crate::messages::CertificateMessage;
+    use crate::test_utils::CertificateChainBuilder;
+
+    use super::*;
+
+    fn bundle_from_certificates(certificates: &[Certificate]) -> CertificateChainBundle {
+        CertificateChainBundle {
+            format_version: 1,
+            certificate_hash: certificates[0].hash.clone(),
+            artifact_message: ProtocolMessage::new(),
+            checkpoint: None,
+            certificates: certificates
+                .iter()
+                .map(|c| CertificateMessage::try_from(c.clone()).unwrap())
+                .collect(),
+        }
+    }
+
+    #[tokio::test]
+    async fn retrieve_certificates_of_the_bundle() {
+        let (certificates, _) = CertificateChainBuilder::new()
+            .with_total_certificates(3)
+            .build();
+        let retriever =
+            FileCertificateRetriever::from_bundle(&bundle_from_certificates(&certificates))
+                .unwrap();
+
+        for certificate in &certificates {
+            assert_eq!(
+                *certificate,
+                retriever
+                    .get_certificate_details(&certificate.hash)
+                    .await
+                    .unwrap()
+            );
+        }
+    }
+
+    #[tokio::test]
+    async fn fails_to_retrieve_certificate_missing_from_the_bundle() {
+        let (certificates, _) = CertificateChainBuilder::new()
+            .with_total_certificates(3)
+            .build();
+        let retriever =
+            FileCertificateRetriever::from_bundle(&bundle_from_certificates(&certificates[..1]))
+                .unwrap();
+
+        retriever
+            .get_certificate_details(&certificates[1].hash)
+            .await
+            .expect_err("Certificate missing from the bundle should not be retrieved");
+    }
+
+    #[cfg(feature = "fs")]
+    #[tokio::test]
+    async fn read_certificates_from_a_bundle_file() {
+        use crate::test_utils::temp_dir_create;
+
+        let (certificates, _) = CertificateChainBuilder::new()
+            .with_total_certificates(3)
+            .build();
+        let path = temp_dir_create!().join("bundle.json");
+        bundle_from_certificates(&certificates)
+            .write_to_file(&path)
+            .unwrap();
+
+        let retriever = FileCertificateRetriever::from_file(&path).unwrap();
+
+        assert_eq!(
+            certificates[2],
+            retriever
+                .get_certificate_details(&certificates[2].hash)
+                .await
+                .unwrap()
+        );
+    }
+}
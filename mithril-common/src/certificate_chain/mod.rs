@@ -1,17 +1,25 @@
 //! Tools to retrieve, validate the Certificate Chain created by an aggregator
 
+mod certificate_chain_bundle;
+mod certificate_checkpoint;
 mod certificate_genesis;
 mod certificate_retriever;
 mod certificate_verifier;
+mod file_certificate_retriever;
 cfg_test_tools! {
     mod fake_certificate_retriever;
 }
 
+pub use certificate_chain_bundle::{
+    CertificateChainBundle, CERTIFICATE_CHAIN_BUNDLE_FORMAT_VERSION,
+};
+pub use certificate_checkpoint::CertificateChainCheckpoint;
 pub use certificate_genesis::CertificateGenesisProducer;
 pub use certificate_retriever::{CertificateRetriever, CertificateRetrieverError};
 pub use certificate_verifier::{
     CertificateVerifier, CertificateVerifierError, MithrilCertificateVerifier,
 };
+pub use file_certificate_retriever::FileCertificateRetriever;
 
 cfg_test_tools! {
     pub use fake_certificate_retriever::FakeCertificaterRetriever;
@@ -1,12 +1,18 @@
 use serde::{Deserialize, Serialize};
-use std::{fmt::Display, path::PathBuf, sync::Arc};
+use std::{
+    fmt::Display,
+    path::{Path, PathBuf},
+    sync::Arc,
+};
 use thiserror::Error;
 
 use crate::{chain_observer::ChainObserver, CardanoNetwork, StdResult};
 
 #[cfg(any(test, feature = "test_tools"))]
 use super::FakeObserver;
-use super::{CardanoCliChainObserver, CardanoCliRunner, PallasChainObserver};
+use super::{
+    CardanoCliChainObserver, CardanoCliRunner, LedgerStateChainObserver, PallasChainObserver,
+};
 
 /// Type of chain observers available
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
@@ -17,6 +23,10 @@ pub enum ChainObserverType {
     CardanoCli,
     /// Pallas chain observer.
     Pallas,
+    /// Ledger state chain observer, reading the ledger state snapshots of the Cardano node
+    /// database.
+    #[serde(rename = "ledger-state")]
+    LedgerState,
     /// Fake chain observer.
     #[cfg(any(test, feature = "test_tools"))]
     Fake,
@@ -27,6 +37,7 @@ impl Display for ChainObserverType {
         match self {
             Self::CardanoCli => write!(f, "cardano-cli"),
             Self::Pallas => write!(f, "pallas"),
+            Self::LedgerState => write!(f, "ledger-state"),
             #[cfg(any(test, feature = "test_tools"))]
             Self::Fake => write!(f, "fake"),
         }
@@ -39,6 +50,10 @@ pub enum ChainObserverBuilderError {
     /// Missing cardano cli runner error.
     #[error("cardano cli runner is missing")]
     MissingCardanoCliRunner,
+
+    /// Missing cardano node database path error.
+    #[error("cardano node database path is missing")]
+    MissingCardanoDbPath,
 }
 
 /// Chain observer builder
@@ -47,6 +62,7 @@ pub struct ChainObserverBuilder {
     cardano_node_socket_path: PathBuf,
     cardano_network: CardanoNetwork,
     cardano_cli_runner: Option<Box<CardanoCliRunner>>,
+    cardano_db_path: Option<PathBuf>,
 }
 
 impl ChainObserverBuilder {
@@ -62,9 +78,16 @@ impl ChainObserverBuilder {
             cardano_node_socket_path: cardano_node_socket_path.to_owned(),
             cardano_network: cardano_node_network.to_owned(),
             cardano_cli_runner: cardano_cli_runner.map(|c| c.to_owned().into()),
+            cardano_db_path: None,
         }
     }
 
+    /// Set the Cardano node database path, needed by the ledger state chain observer
+    pub fn with_cardano_db_path(mut self, cardano_db_path: &Path) -> Self {
+        self.cardano_db_path = Some(cardano_db_path.to_path_buf());
+        self
+    }
+
     /// Create chain observer
     pub fn build(&self) -> StdResult<Arc<dyn ChainObserver>> {
         match self.chain_observer_type {
@@ -79,6 +102,11 @@ impl ChainObserverBuilder {
                     PallasChainObserver::new(&self.cardano_node_socket_path, self.cardano_network);
                 Ok(Arc::new(observer))
             }
+            ChainObserverType::LedgerState => Ok(Arc::new(LedgerStateChainObserver::new(
+                self.cardano_db_path
+                    .as_ref()
+                    .ok_or(ChainObserverBuilderError::MissingCardanoDbPath)?,
+            ))),
             #[cfg(any(test, feature = "test_tools"))]
             ChainObserverType::Fake => Ok(Arc::new(FakeObserver::default())),
         }
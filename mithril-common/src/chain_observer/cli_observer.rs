@@ -3,7 +3,8 @@ use async_trait::async_trait;
 use hex::FromHex;
 use nom::IResult;
 use rand_core::RngCore;
-use serde_json::Value;
+use serde::Deserialize;
+use serde_json::{Number, Value};
 use std::collections::HashMap;
 use std::fs;
 use std::path::PathBuf;
@@ -12,7 +13,11 @@ use tokio::process::Command;
 use crate::chain_observer::interface::{ChainObserver, ChainObserverError};
 use crate::chain_observer::{ChainAddress, TxDatum};
 use crate::crypto_helper::{encode_bech32, KESPeriod, OpCert, SerDeShelleyFileFormat};
-use crate::entities::{BlockNumber, ChainPoint, Epoch, SlotNumber, StakeDistribution};
+use crate::entities::{
+    BlockNumber, ChainPoint, Epoch, ExecutionUnitPrices, ExecutionUnits,
+    LedgerGovernanceParameters, LedgerProtocolParameters, RationalNumber, SlotNumber,
+    StakeDistribution,
+};
 use crate::{CardanoNetwork, StdResult};
 
 const CARDANO_ERA: &str = "latest";
@@ -37,6 +42,8 @@ pub trait CliRunner {
     async fn launch_chain_point(&self) -> StdResult<String>;
     /// Launches the kes period.
     async fn launch_kes_period(&self, opcert_file: &str) -> StdResult<String>;
+    /// Launches the protocol parameters.
+    async fn launch_protocol_parameters(&self) -> StdResult<String>;
 }
 
 /// A runner able to request data from a Cardano node using the
@@ -155,6 +162,17 @@ impl CardanoCliRunner {
         command
     }
 
+    fn command_for_protocol_parameters(&self) -> Command {
+        let mut command = self.get_command();
+        command
+            .arg(CARDANO_ERA)
+            .arg("query")
+            .arg("protocol-parameters");
+        self.post_config_command(&mut command);
+
+        command
+    }
+
     fn get_command(&self) -> Command {
         let mut command = Command::new(&self.cli_path);
         command.env(
@@ -316,6 +334,171 @@ impl CliRunner for CardanoCliRunner {
             ))
         }
     }
+
+    async fn launch_protocol_parameters(&self) -> StdResult<String> {
+        let output = self.command_for_protocol_parameters().output().await?;
+
+        if output.status.success() {
+            Ok(std::str::from_utf8(&output.stdout)?.trim().to_string())
+        } else {
+            let message = String::from_utf8_lossy(&output.stderr);
+
+            Err(anyhow!(
+                "Error launching command {:?}, error = '{}'",
+                self.command_for_protocol_parameters(),
+                message
+            ))
+        }
+    }
+}
+
+/// Protocol parameters as returned by the `query protocol-parameters` command of the Cardano CLI
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct CliProtocolParameters {
+    protocol_version: CliProtocolVersion,
+    tx_fee_per_byte: u64,
+    tx_fee_fixed: u64,
+    max_block_body_size: u64,
+    max_block_header_size: u64,
+    max_tx_size: u64,
+    stake_address_deposit: u64,
+    stake_pool_deposit: u64,
+    pool_retire_max_epoch: u64,
+    stake_pool_target_num: u64,
+    min_pool_cost: u64,
+    utxo_cost_per_byte: Option<u64>,
+    max_value_size: Option<u64>,
+    collateral_percentage: Option<u64>,
+    max_collateral_inputs: Option<u64>,
+    execution_unit_prices: Option<CliExecutionUnitPrices>,
+    max_tx_execution_units: Option<CliExecutionUnits>,
+    max_block_execution_units: Option<CliExecutionUnits>,
+    gov_action_deposit: Option<u64>,
+    d_rep_deposit: Option<u64>,
+    d_rep_activity: Option<u64>,
+    gov_action_lifetime: Option<u64>,
+    committee_min_size: Option<u64>,
+    committee_max_term_length: Option<u64>,
+}
+
+#[derive(Deserialize)]
+struct CliProtocolVersion {
+    major: u64,
+    minor: u64,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct CliExecutionUnitPrices {
+    price_memory: Number,
+    price_steps: Number,
+}
+
+/// Convert a decimal number printed by the Cardano CLI (ie: `5.77e-2`) to the exact rational
+/// number it represents
+fn decimal_to_rational_number(decimal: &Number) -> StdResult<RationalNumber> {
+    let text = decimal.to_string();
+    let (mantissa, exponent) = match text.split_once(['e', 'E']) {
+        Some((mantissa, exponent)) => (mantissa, exponent.parse::<i64>()?),
+        None => (text.as_str(), 0),
+    };
+    let (integer_part, fractional_part) = mantissa.split_once('.').unwrap_or((mantissa, ""));
+    let digits = format!("{integer_part}{fractional_part}");
+    if digits.is_empty() || !digits.chars().all(|c| c.is_ascii_digit()) {
+        return Err(anyhow!("Invalid non negative decimal number: '{text}'"));
+    }
+
+    let overflow = || anyhow!("Decimal number '{text}' can not be represented as a rational");
+    let numerator = digits.parse::<u64>().map_err(|_| overflow())?;
+    let scale = fractional_part.len() as i64 - exponent;
+    let power_of_ten = 10u64
+        .checked_pow(scale.unsigned_abs().try_into().map_err(|_| overflow())?)
+        .ok_or_else(overflow)?;
+    if scale >= 0 {
+        RationalNumber::new(numerator, power_of_ten)
+    } else {
+        RationalNumber::new(numerator.checked_mul(power_of_ten).ok_or_else(overflow)?, 1)
+    }
+}
+
+#[derive(Deserialize)]
+struct CliExecutionUnits {
+    memory: u64,
+    steps: u64,
+}
+
+impl TryFrom<CliProtocolParameters> for LedgerProtocolParameters {
+    type Error = anyhow::Error;
+
+    fn try_from(other: CliProtocolParameters) -> StdResult<Self> {
+        let governance = match (
+            other.gov_action_deposit,
+            other.d_rep_deposit,
+            other.d_rep_activity,
+            other.gov_action_lifetime,
+            other.committee_min_size,
+            other.committee_max_term_length,
+        ) {
+            (
+                Some(gov_action_deposit),
+                Some(drep_deposit),
+                Some(drep_activity),
+                Some(gov_action_lifetime),
+                Some(committee_min_size),
+                Some(committee_max_term_length),
+            ) => Some(LedgerGovernanceParameters {
+                gov_action_deposit,
+                drep_deposit,
+                drep_activity,
+                gov_action_lifetime,
+                committee_min_size,
+                committee_max_term_length,
+            }),
+            _ => None,
+        };
+
+        let execution_unit_prices = match other.execution_unit_prices {
+            Some(prices) => Some(ExecutionUnitPrices {
+                price_memory: decimal_to_rational_number(&prices.price_memory)
+                    .with_context(|| "Invalid protocol parameter: 'priceMemory'")?,
+                price_steps: decimal_to_rational_number(&prices.price_steps)
+                    .with_context(|| "Invalid protocol parameter: 'priceSteps'")?,
+            }),
+            None => None,
+        };
+
+        Ok(Self {
+            protocol_version_major: other.protocol_version.major,
+            protocol_version_minor: other.protocol_version.minor,
+            tx_fee_per_byte: other.tx_fee_per_byte,
+            tx_fee_fixed: other.tx_fee_fixed,
+            max_block_body_size: other.max_block_body_size,
+            max_block_header_size: other.max_block_header_size,
+            max_tx_size: other.max_tx_size,
+            stake_address_deposit: other.stake_address_deposit,
+            stake_pool_deposit: other.stake_pool_deposit,
+            pool_retire_max_epoch: other.pool_retire_max_epoch,
+            stake_pool_target_num: other.stake_pool_target_num,
+            min_pool_cost: other.min_pool_cost,
+            utxo_cost_per_byte: other.utxo_cost_per_byte,
+            max_value_size: other.max_value_size,
+            collateral_percentage: other.collateral_percentage,
+            max_collateral_inputs: other.max_collateral_inputs,
+            execution_unit_prices,
+            max_tx_execution_units: other.max_tx_execution_units.map(|units| ExecutionUnits {
+                memory: units.memory,
+                steps: units.steps,
+            }),
+            max_block_execution_units: other.max_block_execution_units.map(|units| {
+                ExecutionUnits {
+                    memory: units.memory,
+                    steps: units.steps,
+                }
+            }),
+            governance,
+        })
+    }
 }
 
 /// A [ChainObserver] pulling it's data using a [CardanoCliRunner].
@@ -538,6 +721,25 @@ impl ChainObserver for CardanoCliChainObserver {
         }
     }
 
+    async fn get_current_protocol_parameters(
+        &self,
+    ) -> Result<Option<LedgerProtocolParameters>, ChainObserverError> {
+        let output = self
+            .cli_runner
+            .launch_protocol_parameters()
+            .await
+            .map_err(ChainObserverError::General)?;
+        let protocol_parameters: CliProtocolParameters = serde_json::from_str(&output)
+            .with_context(|| format!("output was = '{output}'"))
+            .map_err(ChainObserverError::InvalidContent)?;
+
+        let protocol_parameters = protocol_parameters
+            .try_into()
+            .map_err(ChainObserverError::InvalidContent)?;
+
+        Ok(Some(protocol_parameters))
+    }
+
     async fn get_current_kes_period(
         &self,
         opcert: &OpCert,
@@ -579,6 +781,32 @@ mod tests {
 
     use kes_summed_ed25519::{kes::Sum6Kes, traits::KesSk};
 
+    #[test]
+    fn convert_decimal_to_rational_number() {
+        fn decimal(text: &str) -> Number {
+            serde_json::from_str(text).unwrap()
+        }
+
+        assert_eq!(
+            RationalNumber::new(577, 10000).unwrap(),
+            decimal_to_rational_number(&decimal("5.77e-2")).unwrap()
+        );
+        assert_eq!(
+            RationalNumber::new(721, 10000000).unwrap(),
+            decimal_to_rational_number(&decimal("0.0000721")).unwrap()
+        );
+        assert_eq!(
+            RationalNumber::new(1200, 1).unwrap(),
+            decimal_to_rational_number(&decimal("1.2e3")).unwrap()
+        );
+        assert_eq!(
+            RationalNumber::new(3, 1).unwrap(),
+            decimal_to_rational_number(&decimal("3")).unwrap()
+        );
+        decimal_to_rational_number(&decimal("-0.5"))
+            .expect_err("A negative decimal should be rejected");
+    }
+
     #[tokio::test]
     async fn test_get_current_era() {
         let observer = CardanoCliChainObserver::new(Box::<TestCliRunner>::default());
@@ -610,6 +838,21 @@ mod tests {
         );
     }
 
+    #[tokio::test]
+    async fn test_get_current_protocol_parameters() {
+        let observer = CardanoCliChainObserver::new(Box::<TestCliRunner>::default());
+        let protocol_parameters = observer
+            .get_current_protocol_parameters()
+            .await
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(
+            test_expected::launch_protocol_parameters::protocol_parameters(),
+            protocol_parameters
+        );
+    }
+
     #[tokio::test]
     async fn test_cli_testnet_runner() {
         let runner = CardanoCliRunner::new(
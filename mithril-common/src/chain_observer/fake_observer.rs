@@ -27,6 +27,11 @@ pub struct FakeObserver {
     ///
     /// [get_current_era]: ChainObserver::get_current_era
     pub current_era: RwLock<String>,
+
+    /// The ledger protocol parameters, used by [get_current_protocol_parameters]
+    ///
+    /// [get_current_protocol_parameters]: ChainObserver::get_current_protocol_parameters
+    pub protocol_parameters: RwLock<Option<LedgerProtocolParameters>>,
 }
 
 impl FakeObserver {
@@ -37,6 +42,7 @@ impl FakeObserver {
             current_time_point: RwLock::new(current_time_point.clone()),
             datums: RwLock::new(vec![]),
             current_era: RwLock::new(String::new()),
+            protocol_parameters: RwLock::new(Some(LedgerProtocolParameters::dummy())),
         }
     }
 
@@ -143,6 +149,16 @@ impl FakeObserver {
         let mut current_era = self.current_era.write().await;
         *current_era = new_current_era;
     }
+
+    /// Set the protocol parameters that will be returned by
+    /// [get_current_protocol_parameters][ChainObserver::get_current_protocol_parameters].
+    pub async fn set_protocol_parameters(
+        &self,
+        new_protocol_parameters: Option<LedgerProtocolParameters>,
+    ) {
+        let mut protocol_parameters = self.protocol_parameters.write().await;
+        *protocol_parameters = new_protocol_parameters;
+    }
 }
 
 impl Default for FakeObserver {
@@ -199,6 +215,12 @@ impl ChainObserver for FakeObserver {
         ))
     }
 
+    async fn get_current_protocol_parameters(
+        &self,
+    ) -> Result<Option<LedgerProtocolParameters>, ChainObserverError> {
+        Ok(self.protocol_parameters.read().await.clone())
+    }
+
     async fn get_current_kes_period(
         &self,
         _opcert: &OpCert,
@@ -374,4 +396,22 @@ mod tests {
             .expect("get_current_era should not fail");
         assert_eq!(Some("Conway".to_string()), current_era);
     }
+
+    #[tokio::test]
+    async fn test_get_current_protocol_parameters() {
+        let fake_observer = FakeObserver::new(None);
+        let protocol_parameters = LedgerProtocolParameters {
+            tx_fee_per_byte: 50,
+            ..LedgerProtocolParameters::dummy()
+        };
+        fake_observer
+            .set_protocol_parameters(Some(protocol_parameters.clone()))
+            .await;
+
+        let current_protocol_parameters = fake_observer
+            .get_current_protocol_parameters()
+            .await
+            .expect("get_current_protocol_parameters should not fail");
+        assert_eq!(Some(protocol_parameters), current_protocol_parameters);
+    }
 }
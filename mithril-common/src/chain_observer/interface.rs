@@ -47,6 +47,11 @@ pub trait ChainObserver: Sync + Send {
         &self,
     ) -> Result<Option<StakeDistribution>, ChainObserverError>;
 
+    /// Retrieve the current protocol parameters of the Cardano ledger
+    async fn get_current_protocol_parameters(
+        &self,
+    ) -> Result<Option<LedgerProtocolParameters>, ChainObserverError>;
+
     /// Retrieve the KES period of an operational certificate
     async fn get_current_kes_period(
         &self,
@@ -0,0 +1,963 @@
+use std::collections::{BTreeMap, BTreeSet};
+use std::fs::File;
+use std::io::{self, BufReader, Read};
+use std::path::{Path, PathBuf};
+use std::time::SystemTime;
+
+use anyhow::{anyhow, Context};
+use async_trait::async_trait;
+use pallas_traverse::Era;
+use tokio::sync::Mutex;
+
+use crate::{
+    chain_observer::{interface::*, pallas_observer::ERA_OFFSET, ChainAddress, TxDatum},
+    crypto_helper::encode_bech32,
+    digesters::{LedgerFile, UTXO_HD_STATE_FILE},
+    entities::{
+        BlockNumber, ChainPoint, Epoch, LedgerProtocolParameters, SlotNumber, StakeDistribution,
+    },
+    StdResult,
+};
+
+/// Content of a ledger state snapshot of a Cardano node
+#[derive(Debug, Clone, PartialEq)]
+pub struct LedgerStateSnapshot {
+    /// Era of the ledger state
+    pub era: String,
+
+    /// Epoch of the ledger state, not available in the Byron era
+    pub epoch: Option<Epoch>,
+
+    /// Tip of the ledger state, not available in the Byron era or at origin
+    pub chain_point: Option<ChainPoint>,
+
+    /// Stake distribution of the 'mark' snapshot, not available in the Byron era
+    pub stake_distribution: Option<StakeDistribution>,
+}
+
+impl LedgerStateSnapshot {
+    /// Decode a ledger state snapshot file content.
+    ///
+    /// See [Self::decode_from_reader].
+    pub fn decode(bytes: &[u8]) -> StdResult<Self> {
+        Self::decode_from_reader(bytes)
+    }
+
+    /// Decode a ledger state snapshot while reading it, without loading it in memory.
+    ///
+    /// The snapshot is a versioned extended ledger state: `[version, [ledger_state, header_state]]`
+    /// where the ledger state is the hard fork combinator telescope of the eras, the current era
+    /// being the last one.
+    pub fn decode_from_reader<R: Read>(reader: R) -> StdResult<Self> {
+        let mut decoder = CborStreamDecoder::new(reader);
+        decoder.expect_array_of_len(2, "versioned extended ledger state")?;
+        decoder.u8("extended ledger state version")?;
+        decoder.expect_array_of_len(2, "extended ledger state")?;
+
+        let eras_count = decoder.array("hard fork telescope")?;
+        if eras_count == 0 {
+            return Err(anyhow!("Hard fork telescope can not be empty"));
+        }
+        for _past_era in 1..eras_count {
+            decoder.skip()?;
+        }
+        let era_index = eras_count - 1;
+        let era = Era::try_from(era_index as u16 + ERA_OFFSET)
+            .with_context(|| format!("Unknown era at hard fork telescope index '{era_index}'"))?;
+
+        // Current era: [start_bound, ledger_state]
+        decoder.expect_array_of_len(2, "current era")?;
+        decoder.skip()?;
+        if era == Era::Byron {
+            return Ok(Self {
+                era: era.to_string(),
+                epoch: None,
+                chain_point: None,
+                stake_distribution: None,
+            });
+        }
+
+        let (chain_point, epoch, stake_distribution) =
+            Self::decode_shelley_ledger_state(&mut decoder)
+                .with_context(|| format!("Invalid {era} ledger state"))?;
+
+        Ok(Self {
+            era: era.to_string(),
+            epoch: Some(epoch),
+            chain_point,
+            stake_distribution: Some(stake_distribution),
+        })
+    }
+
+    /// Decode a Shelley based ledger state:
+    /// `[version, [tip, new_epoch_state, transition]]`
+    fn decode_shelley_ledger_state<R: Read>(
+        decoder: &mut CborStreamDecoder<R>,
+    ) -> StdResult<(Option<ChainPoint>, Epoch, StakeDistribution)> {
+        decoder.expect_array_of_len(2, "versioned ledger state")?;
+        decoder.u8("ledger state version")?;
+        decoder.expect_array_of_len(3, "ledger state")?;
+        let chain_point = Self::decode_tip(decoder)?;
+
+        // New epoch state: [epoch, blocks_made_previous, blocks_made_current, epoch_state, ...]
+        decoder.array("new epoch state")?;
+        let epoch = Epoch(decoder.u64("epoch")?);
+        decoder.skip()?;
+        decoder.skip()?;
+
+        // Epoch state: [account_state, ledger_state, snapshots, non_myopic]
+        decoder.array("epoch state")?;
+        decoder.skip()?;
+        decoder.skip()?;
+
+        // Snapshots: [mark, set, go, fees]
+        decoder.array("stake snapshots")?;
+        let stake_distribution = Self::decode_stake_snapshot(decoder)
+            .with_context(|| "Invalid 'mark' stake snapshot")?;
+
+        Ok((chain_point, epoch, stake_distribution))
+    }
+
+    /// Decode the tip of a ledger state: `[]` at origin, else `[[slot, block_number, hash]]`
+    fn decode_tip<R: Read>(decoder: &mut CborStreamDecoder<R>) -> StdResult<Option<ChainPoint>> {
+        match decoder.array("ledger tip")? {
+            0 => Ok(None),
+            1 => {
+                decoder.expect_array_of_len(3, "ledger tip")?;
+                let slot_number = SlotNumber(decoder.u64("tip slot number")?);
+                let block_number = BlockNumber(decoder.u64("tip block number")?);
+                let block_hash = hex::encode(decoder.bytes("tip block hash")?);
+
+                Ok(Some(ChainPoint::new(slot_number, block_number, block_hash)))
+            }
+            len => Err(anyhow!("Invalid ledger tip array length '{len}'")),
+        }
+    }
+
+    /// Decode a stake snapshot: `[stakes, delegations, pools_parameters]` and sum the stake
+    /// delegated to each registered pool.
+    fn decode_stake_snapshot<R: Read>(
+        decoder: &mut CborStreamDecoder<R>,
+    ) -> StdResult<StakeDistribution> {
+        decoder.expect_array_of_len(3, "stake snapshot")?;
+
+        let mut credentials_stake = BTreeMap::new();
+        for _ in 0..decoder.map("stakes")? {
+            let credential = decoder.raw_item()?;
+            let stake = decoder.u64("stake")?;
+            credentials_stake.insert(credential, stake);
+        }
+
+        let mut pools_stake: BTreeMap<Vec<u8>, u64> = BTreeMap::new();
+        for _ in 0..decoder.map("delegations")? {
+            let credential = decoder.raw_item()?;
+            let pool_key_hash = decoder.bytes("delegation pool key hash")?;
+            let stake = credentials_stake
+                .get(&credential)
+                .copied()
+                .unwrap_or_default();
+            *pools_stake.entry(pool_key_hash).or_default() += stake;
+        }
+
+        let mut registered_pools = BTreeSet::new();
+        for _ in 0..decoder.map("pools parameters")? {
+            registered_pools.insert(decoder.bytes("pool key hash")?);
+            decoder.skip()?;
+        }
+
+        let mut stake_distribution = StakeDistribution::new();
+        for (pool_key_hash, stake) in pools_stake
+            .into_iter()
+            .filter(|(pool_key_hash, stake)| *stake > 0 && registered_pools.contains(pool_key_hash))
+        {
+            let pool_id = encode_bech32("pool", &pool_key_hash)
+                .with_context(|| "Failed to encode stake pool hash")?;
+            stake_distribution.insert(pool_id, stake);
+        }
+
+        Ok(stake_distribution)
+    }
+}
+
+const MAJOR_TYPE_UNSIGNED: u8 = 0;
+const MAJOR_TYPE_NEGATIVE: u8 = 1;
+const MAJOR_TYPE_BYTES: u8 = 2;
+const MAJOR_TYPE_TEXT: u8 = 3;
+const MAJOR_TYPE_ARRAY: u8 = 4;
+const MAJOR_TYPE_MAP: u8 = 5;
+const MAJOR_TYPE_TAG: u8 = 6;
+const MAJOR_TYPE_SIMPLE: u8 = 7;
+
+/// Header of a CBOR item: its major type and its argument, `None` for an indefinite length item
+/// or for the break stop code of the indefinite length items.
+type CborHeader = (u8, Option<u64>);
+
+/// Decoder of the CBOR items of a reader.
+///
+/// A ledger state snapshot can weigh several gigabytes, mainly because of the UTxO set that is
+/// not needed here, so the items are decoded while being read and the unneeded ones are skipped
+/// without being kept in memory.
+struct CborStreamDecoder<R> {
+    reader: R,
+    /// Raw bytes read since the start of the recording, see [Self::raw_item]
+    recording: Option<Vec<u8>>,
+}
+
+impl<R: Read> CborStreamDecoder<R> {
+    fn new(reader: R) -> Self {
+        Self {
+            reader,
+            recording: None,
+        }
+    }
+
+    fn read_bytes(&mut self, len: u64) -> StdResult<Vec<u8>> {
+        let mut bytes = Vec::new();
+        (&mut self.reader)
+            .take(len)
+            .read_to_end(&mut bytes)
+            .with_context(|| "Failed to read the CBOR input")?;
+        if bytes.len() as u64 != len {
+            return Err(anyhow!("Unexpected end of the CBOR input"));
+        }
+        if let Some(recording) = &mut self.recording {
+            recording.extend_from_slice(&bytes);
+        }
+
+        Ok(bytes)
+    }
+
+    fn discard_bytes(&mut self, len: u64) -> StdResult<()> {
+        if self.recording.is_some() {
+            return self.read_bytes(len).map(|_| ());
+        }
+
+        let discarded = io::copy(&mut (&mut self.reader).take(len), &mut io::sink())
+            .with_context(|| "Failed to read the CBOR input")?;
+        if discarded != len {
+            return Err(anyhow!("Unexpected end of the CBOR input"));
+        }
+
+        Ok(())
+    }
+
+    fn read_argument(&mut self, size: u64) -> StdResult<u64> {
+        Ok(self
+            .read_bytes(size)?
+            .into_iter()
+            .fold(0, |argument, byte| (argument << 8) | byte as u64))
+    }
+
+    fn header(&mut self) -> StdResult<CborHeader> {
+        let initial_byte = self.read_bytes(1)?[0];
+        let major_type = initial_byte >> 5;
+        let argument = match initial_byte & 0x1f {
+            info @ 0..=23 => Some(info as u64),
+            24 => Some(self.read_argument(1)?),
+            25 => Some(self.read_argument(2)?),
+            26 => Some(self.read_argument(4)?),
+            27 => Some(self.read_argument(8)?),
+            31 if ![MAJOR_TYPE_UNSIGNED, MAJOR_TYPE_NEGATIVE, MAJOR_TYPE_TAG]
+                .contains(&major_type) =>
+            {
+                None
+            }
+            info => {
+                return Err(anyhow!(
+                    "Invalid CBOR additional information '{info}' for major type '{major_type}'"
+                ))
+            }
+        };
+
+        Ok((major_type, argument))
+    }
+
+    fn is_break((major_type, argument): CborHeader) -> bool {
+        major_type == MAJOR_TYPE_SIMPLE && argument.is_none()
+    }
+
+    fn expect_definite_header(&mut self, expected_major_type: u8, name: &str) -> StdResult<u64> {
+        match self.header()? {
+            (major_type, Some(argument)) if major_type == expected_major_type => Ok(argument),
+            (major_type, None) if major_type == expected_major_type => Err(anyhow!(
+                "Indefinite length item is not supported for the {name}"
+            )),
+            (major_type, _) => Err(anyhow!(
+                "Invalid {name}: expected CBOR major type '{expected_major_type}' but got '{major_type}'"
+            )),
+        }
+    }
+
+    fn array(&mut self, name: &str) -> StdResult<u64> {
+        self.expect_definite_header(MAJOR_TYPE_ARRAY, name)
+    }
+
+    fn expect_array_of_len(&mut self, expected_len: u64, name: &str) -> StdResult<()> {
+        let len = self.array(name)?;
+        if len != expected_len {
+            return Err(anyhow!(
+                "Invalid {name} array length: expected '{expected_len}' but got '{len}'"
+            ));
+        }
+
+        Ok(())
+    }
+
+    fn map(&mut self, name: &str) -> StdResult<u64> {
+        self.expect_definite_header(MAJOR_TYPE_MAP, name)
+    }
+
+    fn u64(&mut self, name: &str) -> StdResult<u64> {
+        self.expect_definite_header(MAJOR_TYPE_UNSIGNED, name)
+    }
+
+    fn u8(&mut self, name: &str) -> StdResult<u8> {
+        let value = self.u64(name)?;
+        value
+            .try_into()
+            .map_err(|_| anyhow!("Invalid {name}: '{value}' is out of the u8 bounds"))
+    }
+
+    fn bytes(&mut self, name: &str) -> StdResult<Vec<u8>> {
+        let len = self.expect_definite_header(MAJOR_TYPE_BYTES, name)?;
+        self.read_bytes(len)
+    }
+
+    /// Skip the next item
+    fn skip(&mut self) -> StdResult<()> {
+        let header = self.header()?;
+        if Self::is_break(header) {
+            return Err(anyhow!("Unexpected CBOR break stop code"));
+        }
+
+        self.skip_content(header)
+    }
+
+    fn skip_content(&mut self, header: CborHeader) -> StdResult<()> {
+        match header {
+            (MAJOR_TYPE_UNSIGNED | MAJOR_TYPE_NEGATIVE | MAJOR_TYPE_SIMPLE, _) => Ok(()),
+            (MAJOR_TYPE_BYTES | MAJOR_TYPE_TEXT, Some(len)) => self.discard_bytes(len),
+            (MAJOR_TYPE_ARRAY, Some(len)) => (0..len).try_for_each(|_| self.skip()),
+            (MAJOR_TYPE_MAP, Some(len)) => (0..len).try_for_each(|_| {
+                self.skip()?;
+                self.skip()
+            }),
+            (MAJOR_TYPE_TAG, _) => self.skip(),
+            // Indefinite length items end with a break stop code
+            (_, None) => loop {
+                let header = self.header()?;
+                if Self::is_break(header) {
+                    return Ok(());
+                }
+                self.skip_content(header)?;
+            },
+            (major_type, _) => Err(anyhow!("Invalid CBOR major type '{major_type}'")),
+        }
+    }
+
+    /// Returns the raw CBOR bytes of the next item
+    fn raw_item(&mut self) -> StdResult<Vec<u8>> {
+        self.recording = Some(Vec::new());
+        let result = self.skip();
+        let raw_item = self.recording.take().unwrap_or_default();
+
+        result.map(|_| raw_item)
+    }
+}
+
+/// A [ChainObserver] that reads the latest ledger state snapshot of a Cardano node database.
+///
+/// It does not need a running Cardano node, which makes it usable offline (e.g. for genesis
+/// bootstrapping or to audit past stake distributions). Only the era, epoch, chain point and stake
+/// distribution can be read from a ledger state snapshot.
+///
+/// The latest decoded snapshot is cached until its file or its modification time changes.
+pub struct LedgerStateChainObserver {
+    db_path: PathBuf,
+    cached_snapshot: Mutex<Option<CachedLedgerStateSnapshot>>,
+}
+
+/// A decoded ledger state snapshot, with the path and the modification time of its file
+struct CachedLedgerStateSnapshot {
+    state_path: PathBuf,
+    modified: SystemTime,
+    snapshot: LedgerStateSnapshot,
+}
+
+impl LedgerStateChainObserver {
+    /// Creates a new `LedgerStateChainObserver` reading the ledger state snapshots of the given
+    /// Cardano node database directory.
+    pub fn new(db_path: &Path) -> Self {
+        Self {
+            db_path: db_path.to_owned(),
+            cached_snapshot: Mutex::new(None),
+        }
+    }
+
+    /// Read and decode the latest ledger state snapshot, `None` if there's no snapshot
+    ///
+    /// The snapshot is decoded again only if the latest snapshot file or its modification time
+    /// changed since the previous read.
+    async fn read_latest_snapshot(&self) -> StdResult<Option<LedgerStateSnapshot>> {
+        let ledger_files = LedgerFile::list_all_in_dir(&self.db_path)
+            .with_context(|| "LedgerStateChainObserver failed to list the ledger files")?;
+        let Some(latest_ledger_file) = ledger_files.last() else {
+            return Ok(None);
+        };
+
+        // UTxO-HD snapshots are directories where the ledger state is stored apart from the tables
+        let state_path = if latest_ledger_file.layout().is_utxo_hd() {
+            latest_ledger_file.path.join(UTXO_HD_STATE_FILE)
+        } else {
+            latest_ledger_file.path.clone()
+        };
+        let modified = std::fs::metadata(&state_path)
+            .and_then(|metadata| metadata.modified())
+            .with_context(|| {
+                format!(
+                    "LedgerStateChainObserver failed to read the modification time of ledger file '{state_path:?}'"
+                )
+            })?;
+
+        let mut cached_snapshot = self.cached_snapshot.lock().await;
+        if let Some(cached) = cached_snapshot.as_ref() {
+            if cached.state_path == state_path && cached.modified == modified {
+                return Ok(Some(cached.snapshot.clone()));
+            }
+        }
+
+        let snapshot = tokio::task::spawn_blocking({
+            let state_path = state_path.clone();
+            move || {
+                let file = File::open(&state_path).with_context(|| {
+                    format!("LedgerStateChainObserver failed to open ledger file '{state_path:?}'")
+                })?;
+
+                LedgerStateSnapshot::decode_from_reader(BufReader::new(file)).with_context(|| {
+                    format!(
+                        "LedgerStateChainObserver failed to decode ledger file '{state_path:?}'"
+                    )
+                })
+            }
+        })
+        .await
+        .with_context(|| "LedgerStateChainObserver failed to join the decoding task")??;
+
+        *cached_snapshot = Some(CachedLedgerStateSnapshot {
+            state_path,
+            modified,
+            snapshot: snapshot.clone(),
+        });
+
+        Ok(Some(snapshot))
+    }
+
+    async fn read_latest_snapshot_content<T, F>(
+        &self,
+        extract: F,
+    ) -> Result<Option<T>, ChainObserverError>
+    where
+        F: FnOnce(LedgerStateSnapshot) -> Option<T>,
+    {
+        let snapshot = self
+            .read_latest_snapshot()
+            .await
+            .map_err(ChainObserverError::InvalidContent)?;
+
+        Ok(snapshot.and_then(extract))
+    }
+}
+
+#[async_trait]
+impl ChainObserver for LedgerStateChainObserver {
+    async fn get_current_datums(
+        &self,
+        _address: &ChainAddress,
+    ) -> Result<Vec<TxDatum>, ChainObserverError> {
+        Err(ChainObserverError::General(anyhow!(
+            "LedgerStateChainObserver can not retrieve the datums of an address"
+        )))
+    }
+
+    async fn get_current_era(&self) -> Result<Option<String>, ChainObserverError> {
+        self.read_latest_snapshot_content(|snapshot| Some(snapshot.era))
+            .await
+    }
+
+    async fn get_current_epoch(&self) -> Result<Option<Epoch>, ChainObserverError> {
+        self.read_latest_snapshot_content(|snapshot| snapshot.epoch)
+            .await
+    }
+
+    async fn get_current_chain_point(&self) -> Result<Option<ChainPoint>, ChainObserverError> {
+        self.read_latest_snapshot_content(|snapshot| snapshot.chain_point)
+            .await
+    }
+
+    async fn get_current_stake_distribution(
+        &self,
+    ) -> Result<Option<StakeDistribution>, ChainObserverError> {
+        self.read_latest_snapshot_content(|snapshot| snapshot.stake_distribution)
+            .await
+    }
+
+    async fn get_current_protocol_parameters(
+        &self,
+    ) -> Result<Option<LedgerProtocolParameters>, ChainObserverError> {
+        Err(ChainObserverError::General(anyhow!(
+            "LedgerStateChainObserver can not retrieve the protocol parameters"
+        )))
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod test_helper {
+    use pallas_codec::minicbor::Encoder;
+
+    use super::*;
+
+    /// Stake of a credential delegated to a pool in a stake snapshot
+    pub struct DelegatedStake {
+        pub credential_key_hash: [u8; 28],
+        pub pool_key_hash: [u8; 28],
+        pub stake: u64,
+    }
+
+    /// Encode a minimal Shelley based ledger state, `era_index` being the index of the era in the
+    /// hard fork telescope
+    pub fn encode_ledger_state(
+        era_index: u64,
+        tip: Option<&ChainPoint>,
+        epoch: Epoch,
+        delegated_stakes: &[DelegatedStake],
+        registered_pools: &[[u8; 28]],
+    ) -> Vec<u8> {
+        let mut encoder = Encoder::new(Vec::new());
+        encode_telescope_prefix(&mut encoder, era_index);
+
+        // Shelley based ledger state
+        encoder.array(2).unwrap().u8(2).unwrap().array(3).unwrap();
+        match tip {
+            None => {
+                encoder.array(0).unwrap();
+            }
+            Some(point) => {
+                encoder
+                    .array(1)
+                    .unwrap()
+                    .array(3)
+                    .unwrap()
+                    .u64(*point.slot_number)
+                    .unwrap()
+                    .u64(*point.block_number)
+                    .unwrap()
+                    .bytes(&hex::decode(&point.block_hash).unwrap())
+                    .unwrap();
+            }
+        }
+
+        // New epoch state
+        encoder.array(7).unwrap().u64(*epoch).unwrap();
+        encoder.map(0).unwrap().map(0).unwrap();
+        // Epoch state
+        encoder.array(4).unwrap();
+        encoder.array(2).unwrap().u64(0).unwrap().u64(0).unwrap();
+        encoder.array(0).unwrap();
+        // Snapshots: mark, set, go, fees
+        encoder.array(4).unwrap();
+        encode_stake_snapshot(&mut encoder, delegated_stakes, registered_pools);
+        encode_stake_snapshot(&mut encoder, &[], &[]);
+        encode_stake_snapshot(&mut encoder, &[], &[]);
+        encoder.u64(0).unwrap();
+        encoder.array(0).unwrap();
+        // Remaining new epoch state fields
+        for _ in 0..3 {
+            encoder.null().unwrap();
+        }
+        // Transition
+        encoder.u64(0).unwrap();
+        // Header state
+        encoder.array(0).unwrap();
+
+        encoder.into_writer()
+    }
+
+    /// Encode a ledger state in the Byron era
+    pub fn encode_byron_ledger_state() -> Vec<u8> {
+        let mut encoder = Encoder::new(Vec::new());
+        encode_telescope_prefix(&mut encoder, 0);
+        encoder.array(0).unwrap();
+        encoder.array(0).unwrap();
+
+        encoder.into_writer()
+    }
+
+    fn encode_telescope_prefix(encoder: &mut Encoder<Vec<u8>>, era_index: u64) {
+        encoder.array(2).unwrap().u8(1).unwrap().array(2).unwrap();
+        encoder.array(era_index + 1).unwrap();
+        for past_era in 0..era_index {
+            encoder.array(2).unwrap();
+            encode_bound(encoder, past_era);
+            encode_bound(encoder, past_era + 1);
+        }
+        encoder.array(2).unwrap();
+        encode_bound(encoder, era_index);
+    }
+
+    fn encode_bound(encoder: &mut Encoder<Vec<u8>>, epoch: u64) {
+        encoder
+            .array(3)
+            .unwrap()
+            .u64(epoch * 432_000)
+            .unwrap()
+            .u64(epoch * 432_000)
+            .unwrap()
+            .u64(epoch)
+            .unwrap();
+    }
+
+    fn encode_stake_snapshot(
+        encoder: &mut Encoder<Vec<u8>>,
+        delegated_stakes: &[DelegatedStake],
+        registered_pools: &[[u8; 28]],
+    ) {
+        encoder.array(3).unwrap();
+        encoder.map(delegated_stakes.len() as u64).unwrap();
+        for delegated_stake in delegated_stakes {
+            encode_credential(encoder, &delegated_stake.credential_key_hash);
+            encoder.u64(delegated_stake.stake).unwrap();
+        }
+        encoder.map(delegated_stakes.len() as u64).unwrap();
+        for delegated_stake in delegated_stakes {
+            encode_credential(encoder, &delegated_stake.credential_key_hash);
+            encoder.bytes(&delegated_stake.pool_key_hash).unwrap();
+        }
+        encoder.map(registered_pools.len() as u64).unwrap();
+        for pool_key_hash in registered_pools {
+            encoder.bytes(pool_key_hash).unwrap();
+            encoder.array(0).unwrap();
+        }
+    }
+
+    fn encode_credential(encoder: &mut Encoder<Vec<u8>>, key_hash: &[u8; 28]) {
+        encoder
+            .array(2)
+            .unwrap()
+            .u8(0)
+            .unwrap()
+            .bytes(key_hash)
+            .unwrap();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use crate::test_utils::TempDir;
+
+    use super::test_helper::*;
+    use super::*;
+
+    const CONWAY_ERA_INDEX: u64 = 6;
+
+    fn pool_id(key_hash: &[u8; 28]) -> String {
+        encode_bech32("pool", key_hash).unwrap()
+    }
+
+    fn chain_point() -> ChainPoint {
+        ChainPoint::new(SlotNumber(83), BlockNumber(35), "ab".repeat(32))
+    }
+
+    #[test]
+    fn decode_conway_ledger_state_snapshot() {
+        let bytes = encode_ledger_state(
+            CONWAY_ERA_INDEX,
+            Some(&chain_point()),
+            Epoch(521),
+            &[
+                DelegatedStake {
+                    credential_key_hash: [1; 28],
+                    pool_key_hash: [10; 28],
+                    stake: 1_000,
+                },
+                DelegatedStake {
+                    credential_key_hash: [2; 28],
+                    pool_key_hash: [10; 28],
+                    stake: 500,
+                },
+                DelegatedStake {
+                    credential_key_hash: [3; 28],
+                    pool_key_hash: [11; 28],
+                    stake: 2_000,
+                },
+            ],
+            &[[10; 28], [11; 28]],
+        );
+
+        let snapshot = LedgerStateSnapshot::decode(&bytes).unwrap();
+
+        assert_eq!(
+            LedgerStateSnapshot {
+                era: Era::Conway.to_string(),
+                epoch: Some(Epoch(521)),
+                chain_point: Some(chain_point()),
+                stake_distribution: Some(StakeDistribution::from([
+                    (pool_id(&[10; 28]), 1_500),
+                    (pool_id(&[11; 28]), 2_000),
+                ])),
+            },
+            snapshot
+        );
+    }
+
+    #[test]
+    fn decode_stake_distribution_of_registered_pools_with_stake_only() {
+        let bytes = encode_ledger_state(
+            CONWAY_ERA_INDEX,
+            None,
+            Epoch(521),
+            &[
+                DelegatedStake {
+                    credential_key_hash: [1; 28],
+                    pool_key_hash: [10; 28],
+                    stake: 1_000,
+                },
+                DelegatedStake {
+                    credential_key_hash: [2; 28],
+                    pool_key_hash: [11; 28],
+                    stake: 0,
+                },
+                DelegatedStake {
+                    credential_key_hash: [3; 28],
+                    pool_key_hash: [12; 28],
+                    stake: 2_000,
+                },
+            ],
+            &[[10; 28], [11; 28]],
+        );
+
+        let snapshot = LedgerStateSnapshot::decode(&bytes).unwrap();
+
+        assert_eq!(None, snapshot.chain_point);
+        assert_eq!(
+            Some(StakeDistribution::from([(pool_id(&[10; 28]), 1_000)])),
+            snapshot.stake_distribution
+        );
+    }
+
+    #[test]
+    fn decode_era_from_the_hard_fork_telescope() {
+        let babbage_bytes = encode_ledger_state(5, None, Epoch(400), &[], &[]);
+        let babbage_snapshot = LedgerStateSnapshot::decode(&babbage_bytes).unwrap();
+        assert_eq!(Era::Babbage.to_string(), babbage_snapshot.era);
+
+        let byron_snapshot = LedgerStateSnapshot::decode(&encode_byron_ledger_state()).unwrap();
+        assert_eq!(
+            LedgerStateSnapshot {
+                era: Era::Byron.to_string(),
+                epoch: None,
+                chain_point: None,
+                stake_distribution: None,
+            },
+            byron_snapshot
+        );
+    }
+
+    #[test]
+    fn decode_invalid_ledger_state_snapshot_fails() {
+        LedgerStateSnapshot::decode(&[0x82, 0x01])
+            .expect_err("Decoding a truncated ledger state should fail");
+
+        let mut bytes = encode_ledger_state(CONWAY_ERA_INDEX, None, Epoch(521), &[], &[]);
+        bytes.truncate(bytes.len() / 2);
+        LedgerStateSnapshot::decode(&bytes)
+            .expect_err("Decoding a truncated ledger state should fail");
+    }
+
+    #[tokio::test]
+    async fn observer_reads_the_latest_ledger_state_snapshot() {
+        let db_path = TempDir::create("ledger_state_observer", "reads_latest_snapshot");
+        let ledger_dir = db_path.join("ledger");
+        fs::create_dir_all(&ledger_dir).unwrap();
+        fs::write(
+            ledger_dir.join("432000"),
+            encode_ledger_state(CONWAY_ERA_INDEX, None, Epoch(520), &[], &[]),
+        )
+        .unwrap();
+        fs::write(
+            ledger_dir.join("864000"),
+            encode_ledger_state(
+                CONWAY_ERA_INDEX,
+                Some(&chain_point()),
+                Epoch(521),
+                &[DelegatedStake {
+                    credential_key_hash: [1; 28],
+                    pool_key_hash: [10; 28],
+                    stake: 1_000,
+                }],
+                &[[10; 28]],
+            ),
+        )
+        .unwrap();
+        let observer = LedgerStateChainObserver::new(&db_path);
+
+        assert_eq!(
+            Some(Era::Conway.to_string()),
+            observer.get_current_era().await.unwrap()
+        );
+        assert_eq!(
+            Some(Epoch(521)),
+            observer.get_current_epoch().await.unwrap()
+        );
+        assert_eq!(
+            Some(chain_point()),
+            observer.get_current_chain_point().await.unwrap()
+        );
+        assert_eq!(
+            Some(StakeDistribution::from([(pool_id(&[10; 28]), 1_000)])),
+            observer.get_current_stake_distribution().await.unwrap()
+        );
+    }
+
+    #[tokio::test]
+    async fn observer_reads_a_trimmed_conway_ledger_state_snapshot_fixture() {
+        let db_path = TempDir::create("ledger_state_observer", "reads_snapshot_fixture");
+        let ledger_dir = db_path.join("ledger");
+        fs::create_dir_all(&ledger_dir).unwrap();
+        fs::copy(
+            Path::new(env!("CARGO_MANIFEST_DIR"))
+                .join("../mithril-test-lab/test_data/ledger/225403200"),
+            ledger_dir.join("225403200"),
+        )
+        .unwrap();
+        let observer = LedgerStateChainObserver::new(&db_path);
+
+        assert_eq!(
+            Some(Era::Conway.to_string()),
+            observer.get_current_era().await.unwrap()
+        );
+        assert_eq!(
+            Some(Epoch(521)),
+            observer.get_current_epoch().await.unwrap()
+        );
+        assert_eq!(
+            Some(ChainPoint::new(
+                SlotNumber(225_403_200),
+                BlockNumber(3_060_000),
+                "ab".repeat(32)
+            )),
+            observer.get_current_chain_point().await.unwrap()
+        );
+        // The third pool of the fixture is delegated to but not registered
+        assert_eq!(
+            Some(StakeDistribution::from([
+                (pool_id(&[0x10; 28]), 3_500_000),
+                (pool_id(&[0x11; 28]), 4_000_000),
+            ])),
+            observer.get_current_stake_distribution().await.unwrap()
+        );
+    }
+
+    #[tokio::test]
+    async fn observer_decodes_the_snapshot_again_only_if_its_file_changed() {
+        fn write_snapshot(path: &Path, epoch: Epoch, modified: SystemTime) {
+            fs::write(
+                path,
+                encode_ledger_state(CONWAY_ERA_INDEX, None, epoch, &[], &[]),
+            )
+            .unwrap();
+            File::options()
+                .write(true)
+                .open(path)
+                .unwrap()
+                .set_modified(modified)
+                .unwrap();
+        }
+
+        let db_path = TempDir::create("ledger_state_observer", "cache_decoded_snapshot");
+        let ledger_dir = db_path.join("ledger");
+        fs::create_dir_all(&ledger_dir).unwrap();
+        let modified = SystemTime::UNIX_EPOCH + std::time::Duration::from_secs(1_000);
+        write_snapshot(&ledger_dir.join("432000"), Epoch(520), modified);
+        let observer = LedgerStateChainObserver::new(&db_path);
+
+        assert_eq!(
+            Some(Epoch(520)),
+            observer.get_current_epoch().await.unwrap()
+        );
+
+        // Same file and modification time: the cached snapshot is used
+        write_snapshot(&ledger_dir.join("432000"), Epoch(521), modified);
+        assert_eq!(
+            Some(Epoch(520)),
+            observer.get_current_epoch().await.unwrap()
+        );
+
+        // Modification time changed: the snapshot is decoded again
+        write_snapshot(
+            &ledger_dir.join("432000"),
+            Epoch(521),
+            modified + std::time::Duration::from_secs(1),
+        );
+        assert_eq!(
+            Some(Epoch(521)),
+            observer.get_current_epoch().await.unwrap()
+        );
+
+        // New snapshot file: it is decoded
+        write_snapshot(&ledger_dir.join("864000"), Epoch(522), modified);
+        assert_eq!(
+            Some(Epoch(522)),
+            observer.get_current_epoch().await.unwrap()
+        );
+    }
+
+    #[tokio::test]
+    async fn observer_can_not_retrieve_the_protocol_parameters() {
+        let db_path = TempDir::create("ledger_state_observer", "protocol_parameters");
+        let observer = LedgerStateChainObserver::new(&db_path);
+
+        let error = observer
+            .get_current_protocol_parameters()
+            .await
+            .expect_err("Retrieving the protocol parameters should fail");
+        assert!(
+            matches!(error, ChainObserverError::General(_)),
+            "Expected 'General' error but got '{:?}'",
+            error
+        );
+    }
+
+    #[tokio::test]
+    async fn observer_returns_none_without_ledger_state_snapshot() {
+        let db_path = TempDir::create("ledger_state_observer", "without_snapshot");
+        fs::create_dir_all(db_path.join("ledger")).unwrap();
+        let observer = LedgerStateChainObserver::new(&db_path);
+
+        assert_eq!(None, observer.get_current_epoch().await.unwrap());
+        assert_eq!(
+            None,
+            observer.get_current_stake_distribution().await.unwrap()
+        );
+    }
+
+    #[tokio::test]
+    async fn observer_fails_with_an_invalid_ledger_state_snapshot() {
+        let db_path = TempDir::create("ledger_state_observer", "invalid_snapshot");
+        let ledger_dir = db_path.join("ledger");
+        fs::create_dir_all(&ledger_dir).unwrap();
+        fs::write(ledger_dir.join("432000"), b"not a ledger state").unwrap();
+        let observer = LedgerStateChainObserver::new(&db_path);
+
+        let error = observer
+            .get_current_epoch()
+            .await
+            .expect_err("Reading an invalid ledger state snapshot should fail");
+        assert!(
+            matches!(error, ChainObserverError::InvalidContent(_)),
+            "Expected 'InvalidContent' error but got '{:?}'",
+            error
+        );
+    }
+}
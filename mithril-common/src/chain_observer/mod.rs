@@ -13,6 +13,7 @@ pub use model::{
 cfg_fs! {
     mod builder;
     mod cli_observer;
+    mod ledger_state_observer;
     mod pallas_observer;
 
     #[cfg(test)]
@@ -21,6 +22,7 @@ cfg_fs! {
     pub use builder::{ChainObserverBuilder, ChainObserverType};
     pub use cli_observer::CliRunner;
     pub use cli_observer::{CardanoCliChainObserver, CardanoCliRunner};
+    pub use ledger_state_observer::{LedgerStateChainObserver, LedgerStateSnapshot};
     pub use pallas_observer::PallasChainObserver;
 }
 
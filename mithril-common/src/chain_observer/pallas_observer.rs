@@ -11,7 +11,7 @@ use pallas_network::{
         localstate::{
             queries_v16::{
                 self, Addr, Addrs, ChainBlockNumber, GenesisConfig, PostAlonsoTransactionOutput,
-                StakeSnapshot, Stakes, TransactionOutput, UTxOByAddress,
+                ProtocolParam, StakeSnapshot, Stakes, TransactionOutput, UTxOByAddress,
             },
             Client,
         },
@@ -24,7 +24,11 @@ use pallas_traverse::Era;
 use crate::{
     chain_observer::{interface::*, ChainAddress, TxDatum},
     crypto_helper::{encode_bech32, KESPeriod, OpCert},
-    entities::{BlockNumber, ChainPoint, Epoch, SlotNumber, StakeDistribution},
+    entities::{
+        BlockNumber, ChainPoint, Epoch, ExecutionUnitPrices, ExecutionUnits,
+        LedgerGovernanceParameters, LedgerProtocolParameters, RationalNumber, SlotNumber,
+        StakeDistribution,
+    },
     CardanoNetwork, StdResult,
 };
 
@@ -32,7 +36,7 @@ use super::model::{try_inspect, Datum, Datums};
 
 // The era value returned from the queries_v16::get_current_era has an offset of -1 with the era value of the pallas_traverse::Era due to Cardano node implementation.
 // It needs to be compensated to get the correct era display name.
-const ERA_OFFSET: u16 = 1;
+pub(super) const ERA_OFFSET: u16 = 1;
 
 /// A runner that uses Pallas library to interact with a Cardano node using N2C Ouroboros mini-protocols
 pub struct PallasChainObserver {
@@ -385,6 +389,155 @@ impl PallasChainObserver {
         Ok(Some(current_kes_period))
     }
 
+    /// Fetches the current protocol parameters using the provided `statequery` client.
+    async fn do_get_current_protocol_parameters_state_query(
+        &self,
+        statequery: &mut Client,
+    ) -> StdResult<Vec<ProtocolParam>> {
+        let era = self.do_get_current_era_state_query(statequery).await?;
+        let protocol_parameters = queries_v16::get_current_pparams(statequery, era)
+            .await
+            .map_err(|err| anyhow!(err))
+            .with_context(|| "PallasChainObserver failed to get current protocol parameters")?;
+
+        Ok(protocol_parameters)
+    }
+
+    /// Fetches the current protocol parameters through the local statequery and converts them
+    /// to [LedgerProtocolParameters].
+    async fn get_protocol_parameters(
+        &self,
+        client: &mut NodeClient,
+    ) -> Result<Option<LedgerProtocolParameters>, ChainObserverError> {
+        let statequery = client.statequery();
+
+        statequery
+            .acquire(None)
+            .await
+            .map_err(|err| anyhow!(err))
+            .with_context(|| "PallasChainObserver failed to acquire statequery")?;
+
+        let protocol_parameters = self
+            .do_get_current_protocol_parameters_state_query(statequery)
+            .await?;
+
+        match protocol_parameters.first() {
+            Some(protocol_parameters) => Ok(Some(
+                Self::map_protocol_parameters(protocol_parameters)
+                    .map_err(ChainObserverError::InvalidContent)?,
+            )),
+            None => Ok(None),
+        }
+    }
+
+    /// Converts the protocol parameters returned by the node to [LedgerProtocolParameters].
+    fn map_protocol_parameters(
+        protocol_parameters: &ProtocolParam,
+    ) -> StdResult<LedgerProtocolParameters> {
+        fn required<T: Clone + Into<u64>>(value: &Option<T>, name: &str) -> StdResult<u64> {
+            value
+                .clone()
+                .map(Into::into)
+                .with_context(|| format!("Missing protocol parameter: '{name}'"))
+        }
+        fn optional<T: Clone + Into<u64>>(value: &Option<T>) -> Option<u64> {
+            value.clone().map(Into::into)
+        }
+
+        let execution_unit_prices = match &protocol_parameters.execution_costs {
+            Some(prices) => Some(ExecutionUnitPrices {
+                price_memory: RationalNumber::new(
+                    prices.mem_price.numerator,
+                    prices.mem_price.denominator,
+                )
+                .with_context(|| "Invalid protocol parameter: 'mem_price'")?,
+                price_steps: RationalNumber::new(
+                    prices.step_price.numerator,
+                    prices.step_price.denominator,
+                )
+                .with_context(|| "Invalid protocol parameter: 'step_price'")?,
+            }),
+            None => None,
+        };
+
+        let governance = match (
+            optional(&protocol_parameters.governance_action_deposit),
+            optional(&protocol_parameters.drep_deposit),
+            optional(&protocol_parameters.drep_inactivity_period),
+            optional(&protocol_parameters.governance_action_validity_period),
+            optional(&protocol_parameters.min_committee_size),
+            optional(&protocol_parameters.committee_term_limit),
+        ) {
+            (
+                Some(gov_action_deposit),
+                Some(drep_deposit),
+                Some(drep_activity),
+                Some(gov_action_lifetime),
+                Some(committee_min_size),
+                Some(committee_max_term_length),
+            ) => Some(LedgerGovernanceParameters {
+                gov_action_deposit,
+                drep_deposit,
+                drep_activity,
+                gov_action_lifetime,
+                committee_min_size,
+                committee_max_term_length,
+            }),
+            _ => None,
+        };
+
+        Ok(LedgerProtocolParameters {
+            protocol_version_major: required(
+                &protocol_parameters.protocol_version_major,
+                "protocol_version_major",
+            )?,
+            protocol_version_minor: required(
+                &protocol_parameters.protocol_version_minor,
+                "protocol_version_minor",
+            )?,
+            tx_fee_per_byte: required(&protocol_parameters.minfee_a, "minfee_a")?,
+            tx_fee_fixed: required(&protocol_parameters.minfee_b, "minfee_b")?,
+            max_block_body_size: required(
+                &protocol_parameters.max_block_body_size,
+                "max_block_body_size",
+            )?,
+            max_block_header_size: required(
+                &protocol_parameters.max_block_header_size,
+                "max_block_header_size",
+            )?,
+            max_tx_size: required(
+                &protocol_parameters.max_transaction_size,
+                "max_transaction_size",
+            )?,
+            stake_address_deposit: required(&protocol_parameters.key_deposit, "key_deposit")?,
+            stake_pool_deposit: required(&protocol_parameters.pool_deposit, "pool_deposit")?,
+            pool_retire_max_epoch: required(&protocol_parameters.maximum_epoch, "maximum_epoch")?,
+            stake_pool_target_num: required(
+                &protocol_parameters.desired_number_of_stake_pools,
+                "desired_number_of_stake_pools",
+            )?,
+            min_pool_cost: required(&protocol_parameters.min_pool_cost, "min_pool_cost")?,
+            utxo_cost_per_byte: optional(&protocol_parameters.ada_per_utxo_byte),
+            max_value_size: optional(&protocol_parameters.max_value_size),
+            collateral_percentage: optional(&protocol_parameters.collateral_percentage),
+            max_collateral_inputs: optional(&protocol_parameters.max_collateral_inputs),
+            execution_unit_prices,
+            max_tx_execution_units: protocol_parameters.max_tx_ex_units.as_ref().map(|units| {
+                ExecutionUnits {
+                    memory: units.mem as u64,
+                    steps: units.steps,
+                }
+            }),
+            max_block_execution_units: protocol_parameters.max_block_ex_units.as_ref().map(
+                |units| ExecutionUnits {
+                    memory: units.mem as u64,
+                    steps: units.steps,
+                },
+            ),
+            governance,
+        })
+    }
+
     /// Processes a state query with the `NodeClient`, releasing the state query.
     async fn process_statequery(&self, client: &mut NodeClient) -> StdResult<()> {
         let statequery = client.statequery();
@@ -493,6 +646,20 @@ impl ChainObserver for PallasChainObserver {
         Ok(stake_distribution)
     }
 
+    async fn get_current_protocol_parameters(
+        &self,
+    ) -> Result<Option<LedgerProtocolParameters>, ChainObserverError> {
+        let mut client = self.get_client().await?;
+
+        let protocol_parameters = self.get_protocol_parameters(&mut client).await?;
+
+        self.post_process_statequery(&mut client).await?;
+
+        client.abort().await;
+
+        Ok(protocol_parameters)
+    }
+
     async fn get_current_kes_period(
         &self,
         _opcert: &OpCert,
@@ -512,6 +679,7 @@ impl ChainObserver for PallasChainObserver {
 #[cfg(test)]
 mod tests {
     use std::fs;
+    use std::sync::Arc;
 
     use kes_summed_ed25519::{kes::Sum6Kes, traits::KesSk};
     use pallas_codec::utils::{AnyCbor, AnyUInt, KeyValuePairs, TagWrap};
@@ -528,6 +696,9 @@ mod tests {
     };
     use tokio::net::UnixListener;
 
+    use crate::signable_builder::{
+        CardanoProtocolParametersRetriever, ChainObserverProtocolParametersRetriever,
+    };
     use crate::test_utils::TempDir;
     use crate::{crypto_helper::ColdKeyGenerator, CardanoNetwork};
 
@@ -645,6 +816,59 @@ mod tests {
         vec![genesis]
     }
 
+    fn get_fake_protocol_parameters() -> ProtocolParam {
+        ProtocolParam {
+            minfee_a: Some(44),
+            minfee_b: Some(155381),
+            max_block_body_size: Some(90112),
+            max_transaction_size: Some(16384),
+            max_block_header_size: Some(1100),
+            key_deposit: Some(AnyUInt::U64(2_000_000)),
+            pool_deposit: Some(AnyUInt::U64(500_000_000)),
+            maximum_epoch: Some(18),
+            desired_number_of_stake_pools: Some(500),
+            pool_pledge_influence: None,
+            expansion_rate: None,
+            treasury_growth_rate: None,
+            protocol_version_major: Some(10),
+            protocol_version_minor: Some(0),
+            min_pool_cost: Some(AnyUInt::U64(170_000_000)),
+            ada_per_utxo_byte: Some(AnyUInt::U64(4310)),
+            cost_models_for_script_languages: None,
+            // Not in their irreducible form to check that the prices are kept exact
+            execution_costs: Some(queries_v16::ExUnitPrices {
+                mem_price: queries_v16::RationalNumber {
+                    numerator: 5770,
+                    denominator: 100000,
+                },
+                step_price: queries_v16::RationalNumber {
+                    numerator: 721,
+                    denominator: 10000000,
+                },
+            }),
+            max_tx_ex_units: Some(queries_v16::ExUnits {
+                mem: 14_000_000,
+                steps: 10_000_000_000,
+            }),
+            max_block_ex_units: Some(queries_v16::ExUnits {
+                mem: 62_000_000,
+                steps: 20_000_000_000,
+            }),
+            max_value_size: Some(5000),
+            collateral_percentage: Some(150),
+            max_collateral_inputs: Some(3),
+            pool_voting_thresholds: None,
+            drep_voting_thresholds: None,
+            min_committee_size: Some(7),
+            committee_term_limit: Some(146),
+            governance_action_validity_period: Some(6),
+            governance_action_deposit: Some(AnyUInt::U64(100_000_000_000)),
+            drep_deposit: Some(AnyUInt::U64(500_000_000)),
+            drep_inactivity_period: Some(20),
+            minfee_refscript_cost_per_byte: None,
+        }
+    }
+
     /// pallas responses mock server.
     async fn mock_server(server: &mut pallas_network::facades::NodeServer) -> AnyCbor {
         let query: queries_v16::Request =
@@ -676,6 +900,9 @@ mod tests {
             Request::LedgerQuery(LedgerQuery::BlockQuery(_, BlockQuery::GetStakeSnapshots(_))) => {
                 AnyCbor::from_encode(get_fake_stake_snapshot())
             }
+            Request::LedgerQuery(LedgerQuery::BlockQuery(_, BlockQuery::GetCurrentPParams)) => {
+                AnyCbor::from_encode(vec![get_fake_protocol_parameters()])
+            }
             _ => panic!("unexpected query from client: {query:?}"),
         }
     }
@@ -775,6 +1002,134 @@ mod tests {
         assert_eq!(expected_stake_distribution, computed_stake_distribution);
     }
 
+    #[tokio::test]
+    async fn get_current_protocol_parameters() {
+        let socket_path = create_temp_dir("get_current_protocol_parameters").join("node.socket");
+        let server = setup_server(socket_path.clone(), 2).await;
+        let client = tokio::spawn(async move {
+            let observer =
+                PallasChainObserver::new(socket_path.as_path(), CardanoNetwork::TestNet(10));
+            observer.get_current_protocol_parameters().await.unwrap()
+        });
+
+        let (_, client_res) = tokio::join!(server, client);
+        let protocol_parameters = client_res.expect("Client failed");
+        assert_eq!(
+            Some(LedgerProtocolParameters {
+                protocol_version_major: 10,
+                protocol_version_minor: 0,
+                tx_fee_per_byte: 44,
+                tx_fee_fixed: 155381,
+                max_block_body_size: 90112,
+                max_block_header_size: 1100,
+                max_tx_size: 16384,
+                stake_address_deposit: 2_000_000,
+                stake_pool_deposit: 500_000_000,
+                pool_retire_max_epoch: 18,
+                stake_pool_target_num: 500,
+                min_pool_cost: 170_000_000,
+                utxo_cost_per_byte: Some(4310),
+                max_value_size: Some(5000),
+                collateral_percentage: Some(150),
+                max_collateral_inputs: Some(3),
+                execution_unit_prices: Some(ExecutionUnitPrices {
+                    price_memory: RationalNumber {
+                        numerator: 577,
+                        denominator: 10000,
+                    },
+                    price_steps: RationalNumber {
+                        numerator: 721,
+                        denominator: 10000000,
+                    },
+                }),
+                max_tx_execution_units: Some(ExecutionUnits {
+                    memory: 14_000_000,
+                    steps: 10_000_000_000,
+                }),
+                max_block_execution_units: Some(ExecutionUnits {
+                    memory: 62_000_000,
+                    steps: 20_000_000_000,
+                }),
+                governance: Some(LedgerGovernanceParameters {
+                    gov_action_deposit: 100_000_000_000,
+                    drep_deposit: 500_000_000,
+                    drep_activity: 20,
+                    gov_action_lifetime: 6,
+                    committee_min_size: 7,
+                    committee_max_term_length: 146,
+                }),
+            }),
+            protocol_parameters
+        );
+    }
+
+    #[test]
+    fn map_protocol_parameters_without_governance_parameters() {
+        let protocol_parameters = ProtocolParam {
+            drep_deposit: None,
+            ..get_fake_protocol_parameters()
+        };
+
+        let mapped_protocol_parameters =
+            PallasChainObserver::map_protocol_parameters(&protocol_parameters).unwrap();
+
+        assert_eq!(None, mapped_protocol_parameters.governance);
+    }
+
+    #[test]
+    fn map_protocol_parameters_fails_if_a_required_parameter_is_missing() {
+        let protocol_parameters = ProtocolParam {
+            minfee_a: None,
+            ..get_fake_protocol_parameters()
+        };
+
+        let error = PallasChainObserver::map_protocol_parameters(&protocol_parameters)
+            .expect_err("Mapping should fail if a required protocol parameter is missing");
+
+        assert!(
+            error.to_string().contains("minfee_a"),
+            "unexpected error: {error:?}"
+        );
+    }
+
+    #[test]
+    fn map_protocol_parameters_fails_if_an_execution_unit_price_has_a_zero_denominator() {
+        let protocol_parameters = ProtocolParam {
+            execution_costs: Some(queries_v16::ExUnitPrices {
+                mem_price: queries_v16::RationalNumber {
+                    numerator: 577,
+                    denominator: 0,
+                },
+                step_price: queries_v16::RationalNumber {
+                    numerator: 721,
+                    denominator: 10000000,
+                },
+            }),
+            ..get_fake_protocol_parameters()
+        };
+
+        PallasChainObserver::map_protocol_parameters(&protocol_parameters)
+            .expect_err("Mapping should fail if an execution unit price is invalid");
+    }
+
+    #[tokio::test]
+    async fn protocol_parameters_retriever_fails_for_another_epoch_than_the_current_one() {
+        let socket_path =
+            create_temp_dir("protocol_parameters_retriever_other_epoch").join("node.socket");
+        let server = setup_server(socket_path.clone(), 2).await;
+        let client = tokio::spawn(async move {
+            let observer =
+                PallasChainObserver::new(socket_path.as_path(), CardanoNetwork::TestNet(10));
+            let retriever = ChainObserverProtocolParametersRetriever::new(Arc::new(observer));
+            retriever.retrieve(Epoch(9)).await
+        });
+
+        let (_, client_res) = tokio::join!(server, client);
+        client_res
+            .expect("Client failed")
+            .expect_err("Retrieving the protocol parameters of another epoch should fail");
+    }
+
     #[tokio::test]
     async fn get_current_kes_period() {
         let socket_path = create_temp_dir("get_current_kes_period").join("node.socket");
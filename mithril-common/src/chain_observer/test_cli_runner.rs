@@ -35,6 +35,13 @@ pub(crate) mod test_expected {
             "pool1qpqvz90w7qsex2al2ejjej0rfgrwsguch307w8fraw7a7adf6g8";
         pub(crate) const POOL_STAKE_MARK_FOR_POOL_ID_SPECIFIC: u64 = 0;
     }
+    pub(crate) mod launch_protocol_parameters {
+        use crate::entities::LedgerProtocolParameters;
+
+        pub(crate) fn protocol_parameters() -> LedgerProtocolParameters {
+            LedgerProtocolParameters::dummy()
+        }
+    }
     pub(crate) mod launch_stake_snapshot_all_pools {
         pub(crate) const STAKE_MARK_POOL_1: u64 = 300000000001;
         pub(crate) const STAKE_MARK_POOL_2: u64 = 600000000001;
@@ -275,4 +282,54 @@ pool1qz2vzszautc2c8mljnqre2857dpmheq7kgt6vav0s38tvvhxm6w   1.051e-6
 
         Ok(output)
     }
+
+    /// launches the protocol parameters.
+    async fn launch_protocol_parameters(&self) -> StdResult<String> {
+        let output = r#"
+{
+    "collateralPercentage": 150,
+    "committeeMaxTermLength": 146,
+    "committeeMinSize": 7,
+    "costModels": {},
+    "dRepActivity": 20,
+    "dRepDeposit": 500000000,
+    "executionUnitPrices": {
+        "priceMemory": 5.77e-2,
+        "priceSteps": 7.21e-5
+    },
+    "govActionDeposit": 100000000000,
+    "govActionLifetime": 6,
+    "maxBlockBodySize": 90112,
+    "maxBlockExecutionUnits": {
+        "memory": 62000000,
+        "steps": 20000000000
+    },
+    "maxBlockHeaderSize": 1100,
+    "maxCollateralInputs": 3,
+    "maxTxExecutionUnits": {
+        "memory": 14000000,
+        "steps": 10000000000
+    },
+    "maxTxSize": 16384,
+    "maxValueSize": 5000,
+    "minFeeRefScriptCostPerByte": 15,
+    "minPoolCost": 170000000,
+    "monetaryExpansion": 3.0e-3,
+    "poolPledgeInfluence": 0.3,
+    "poolRetireMaxEpoch": 18,
+    "protocolVersion": {
+        "major": 10,
+        "minor": 0
+    },
+    "stakeAddressDeposit": 2000000,
+    "stakePoolDeposit": 500000000,
+    "stakePoolTargetNum": 500,
+    "treasuryCut": 0.2,
+    "txFeeFixed": 155381,
+    "txFeePerByte": 44,
+    "utxoCostPerByte": 4310
+}"#;
+
+        Ok(output.to_string())
+    }
 }
@@ -0,0 +1,570 @@
+use std::collections::VecDeque;
+use std::path::{Path, PathBuf};
+
+use anyhow::{anyhow, Context};
+use async_trait::async_trait;
+use pallas_traverse::MultiEraBlock;
+use slog::{debug, Logger};
+
+use crate::cardano_block_scanner::{RawCardanoPoint, ScannedBlock};
+use crate::digesters::ImmutableFile;
+use crate::entities::{ImmutableFileNumber, SlotNumber};
+use crate::logging::LoggerExtensions;
+use crate::StdResult;
+
+use super::{ChainBlockNextAction, ChainBlockReader};
+
+/// Size of an entry of a secondary index file of the immutable database
+const SECONDARY_INDEX_ENTRY_SIZE: usize = 56;
+
+/// Version of the supported primary index files of the immutable database
+const PRIMARY_INDEX_VERSION: u8 = 1;
+
+/// Size of an offset of a primary index file of the immutable database
+const PRIMARY_INDEX_OFFSET_SIZE: usize = 4;
+
+/// Entry of a secondary index file, locating a block in its chunk file
+#[derive(Debug, Clone, PartialEq)]
+struct SecondaryIndexEntry {
+    block_offset: u64,
+    header_hash: Vec<u8>,
+    /// Slot number of a regular block, or epoch number of an epoch boundary block (EBB)
+    block_or_ebb: u64,
+    is_ebb: bool,
+}
+
+impl SecondaryIndexEntry {
+    /// Slot number of the block, `None` for an epoch boundary block
+    fn slot_number(&self) -> Option<SlotNumber> {
+        (!self.is_ebb).then_some(SlotNumber(self.block_or_ebb))
+    }
+
+    /// Parse an entry: block offset (u64), header offset (u16), header size (u16),
+    /// checksum (u32), header hash (32 bytes) and slot number or epoch for EBBs (u64),
+    /// all big endian.
+    fn parse(bytes: &[u8]) -> Self {
+        Self {
+            block_offset: u64::from_be_bytes(bytes[0..8].try_into().unwrap()),
+            header_hash: bytes[16..48].to_vec(),
+            block_or_ebb: u64::from_be_bytes(bytes[48..56].try_into().unwrap()),
+            is_ebb: false,
+        }
+    }
+
+    fn parse_all(bytes: &[u8]) -> StdResult<Vec<Self>> {
+        if bytes.len() % SECONDARY_INDEX_ENTRY_SIZE != 0 {
+            return Err(anyhow!(
+                "Secondary index size '{}' is not a multiple of its entries size '{SECONDARY_INDEX_ENTRY_SIZE}'",
+                bytes.len()
+            ));
+        }
+
+        Ok(bytes
+            .chunks_exact(SECONDARY_INDEX_ENTRY_SIZE)
+            .map(Self::parse)
+            .collect())
+    }
+
+    /// Parse the entries of a secondary index that are referenced by a primary index.
+    ///
+    /// A primary index starts with its version byte followed by one big endian `u32` offset in
+    /// the secondary index per relative slot of the chunk, and a final offset for the end of the
+    /// secondary index. A relative slot is filled if its offset differs from the next one, the
+    /// relative slot `0` being reserved to the epoch boundary block (EBB) of the chunk.
+    fn parse_all_with_primary_index(primary_bytes: &[u8], bytes: &[u8]) -> StdResult<Vec<Self>> {
+        match primary_bytes.first() {
+            Some(&PRIMARY_INDEX_VERSION) => {}
+            version => {
+                return Err(anyhow!(
+                    "Unsupported primary index version '{version:?}', expected '{PRIMARY_INDEX_VERSION}'"
+                ))
+            }
+        }
+        let offsets_bytes = &primary_bytes[1..];
+        if offsets_bytes.len() % PRIMARY_INDEX_OFFSET_SIZE != 0 {
+            return Err(anyhow!(
+                "Primary index offsets size '{}' is not a multiple of an offset size '{PRIMARY_INDEX_OFFSET_SIZE}'",
+                offsets_bytes.len()
+            ));
+        }
+        let offsets: Vec<usize> = offsets_bytes
+            .chunks_exact(PRIMARY_INDEX_OFFSET_SIZE)
+            .map(|offset| u32::from_be_bytes(offset.try_into().unwrap()) as usize)
+            .collect();
+
+        let all_entries = Self::parse_all(bytes)?;
+        let mut entries = Vec::with_capacity(all_entries.len());
+        for (relative_slot, pair) in offsets.windows(2).enumerate() {
+            let (offset, next_offset) = (pair[0], pair[1]);
+            if next_offset == offset {
+                continue;
+            }
+            if next_offset < offset || offset % SECONDARY_INDEX_ENTRY_SIZE != 0 {
+                return Err(anyhow!(
+                    "Invalid secondary index offset '{offset}' for relative slot '{relative_slot}'"
+                ));
+            }
+
+            let mut entry = all_entries
+                .get(offset / SECONDARY_INDEX_ENTRY_SIZE)
+                .cloned()
+                .ok_or(anyhow!(
+                    "Secondary index offset '{offset}' of relative slot '{relative_slot}' is out of bounds"
+                ))?;
+            entry.is_ebb = relative_slot == 0;
+            entries.push(entry);
+        }
+
+        if entries.len() != all_entries.len() {
+            return Err(anyhow!(
+                "Primary index references '{}' secondary index entries, but the secondary index has '{}' entries",
+                entries.len(),
+                all_entries.len()
+            ));
+        }
+
+        Ok(entries)
+    }
+}
+
+/// [ImmutableFilesChainReader] reads blocks directly from the chunk files of the immutable
+/// database of a Cardano node, without the need of a running node.
+///
+/// The blocks are located in the chunk files with their primary and secondary index files. The
+/// epoch boundary blocks (EBB) of the Byron era are skipped as they don't carry transactions.
+/// Only the completed immutable files are read: once their last block is read, the reader
+/// behaves as if the tip of the chain is reached.
+pub struct ImmutableFilesChainReader {
+    db_path: PathBuf,
+    chain_point: Option<RawCardanoPoint>,
+    next_immutable_file_number: Option<ImmutableFileNumber>,
+    pending_blocks: VecDeque<ScannedBlock>,
+    extract_transactions_filters: bool,
+    logger: Logger,
+}
+
+impl ImmutableFilesChainReader {
+    /// Creates a new `ImmutableFilesChainReader` reading the immutable files of the given
+    /// Cardano node database directory.
+    pub fn new(db_path: &Path, logger: Logger) -> Self {
+        Self {
+            db_path: db_path.to_owned(),
+            chain_point: None,
+            next_immutable_file_number: None,
+            pending_blocks: VecDeque::new(),
+            extract_transactions_filters: false,
+            logger: logger.new_with_component_name::<Self>(),
+        }
+    }
+
+    /// Enable or disable the extraction of the output addresses and metadata labels of the
+    /// transactions of the read blocks.
+    pub fn with_transactions_filters_extraction(mut self, enabled: bool) -> Self {
+        self.extract_transactions_filters = enabled;
+        self
+    }
+
+    /// Returns the next completed immutable file to read, skipping the ones which blocks are
+    /// all before the chain point.
+    // `Option::is_none_or` is not used, it would require Rust 1.82
+    #[allow(clippy::unnecessary_map_or)]
+    async fn find_next_immutable_file(
+        &self,
+    ) -> StdResult<Option<(ImmutableFile, Vec<SecondaryIndexEntry>)>> {
+        let immutable_files = ImmutableFile::list_completed_in_dir(&self.db_path)
+            .with_context(|| "ImmutableFilesChainReader failed to list the immutable files")?;
+
+        for chunk_file in immutable_files
+            .into_iter()
+            .filter(|f| f.path.extension().is_some_and(|e| e == "chunk"))
+            .filter(|f| {
+                self.next_immutable_file_number
+                    .map_or(true, |number| f.number >= number)
+            })
+        {
+            let secondary_index = Self::read_secondary_index(&chunk_file.path).await?;
+            let last_slot_number = secondary_index
+                .iter()
+                .rev()
+                .find_map(SecondaryIndexEntry::slot_number);
+            match (&self.chain_point, last_slot_number) {
+                (_, None) => continue,
+                (Some(point), Some(last_slot_number))
+                    if !point.is_origin() && last_slot_number <= point.slot_number =>
+                {
+                    continue
+                }
+                _ => return Ok(Some((chunk_file, secondary_index))),
+            }
+        }
+
+        Ok(None)
+    }
+
+    async fn read_secondary_index(chunk_path: &Path) -> StdResult<Vec<SecondaryIndexEntry>> {
+        let primary_path = chunk_path.with_extension("primary");
+        let primary_bytes = tokio::fs::read(&primary_path).await.with_context(|| {
+            format!("ImmutableFilesChainReader failed to read primary index '{primary_path:?}'")
+        })?;
+        let secondary_path = chunk_path.with_extension("secondary");
+        let bytes = tokio::fs::read(&secondary_path).await.with_context(|| {
+            format!("ImmutableFilesChainReader failed to read secondary index '{secondary_path:?}'")
+        })?;
+
+        SecondaryIndexEntry::parse_all_with_primary_index(&primary_bytes, &bytes).with_context(
+            || format!("Invalid primary index '{primary_path:?}' or secondary index '{secondary_path:?}'"),
+        )
+    }
+
+    /// Parse the blocks of a chunk file that are after the chain point
+    fn parse_chunk_blocks(
+        &self,
+        chunk_bytes: &[u8],
+        secondary_index: &[SecondaryIndexEntry],
+    ) -> StdResult<Vec<ScannedBlock>> {
+        let mut blocks = Vec::with_capacity(secondary_index.len());
+        for (index, entry) in secondary_index.iter().enumerate() {
+            if entry.is_ebb {
+                debug!(
+                    self.logger, "Skipping epoch boundary block";
+                    "epoch" => entry.block_or_ebb, "block_offset" => entry.block_offset
+                );
+                continue;
+            }
+
+            let start = entry.block_offset as usize;
+            let end = secondary_index
+                .get(index + 1)
+                .map(|next_entry| next_entry.block_offset as usize)
+                .unwrap_or(chunk_bytes.len());
+            let raw_block = chunk_bytes.get(start..end).ok_or(anyhow!(
+                "Block at offset '{start}' is out of the chunk bounds"
+            ))?;
+
+            let multi_era_block = MultiEraBlock::decode(raw_block)
+                .with_context(|| format!("Failed to decode the block at offset '{start}'"))?;
+            if multi_era_block.hash().as_slice() != entry.header_hash.as_slice() {
+                return Err(anyhow!(
+                    "Hash of the block at offset '{start}' does not match its secondary index entry"
+                ));
+            }
+
+            let scanned_block = if self.extract_transactions_filters {
+                ScannedBlock::convert_with_transactions_filters(multi_era_block)
+            } else {
+                ScannedBlock::convert(multi_era_block)
+            };
+            if self.is_after_chain_point(scanned_block.slot_number) {
+                blocks.push(scanned_block);
+            }
+        }
+
+        Ok(blocks)
+    }
+
+    fn is_after_chain_point(&self, slot_number: SlotNumber) -> bool {
+        match &self.chain_point {
+            Some(point) if !point.is_origin() => slot_number > point.slot_number,
+            _ => true,
+        }
+    }
+
+    /// Load the blocks of the next immutable file, returns `false` if there's none left.
+    async fn load_next_immutable_file(&mut self) -> StdResult<bool> {
+        let Some((chunk_file, secondary_index)) = self.find_next_immutable_file().await? else {
+            return Ok(false);
+        };
+        debug!(
+            self.logger, "Reading blocks from chunk file";
+            "immutable_file_number" => chunk_file.number, "chain_point" => ?self.chain_point
+        );
+
+        let chunk_bytes = tokio::fs::read(&chunk_file.path).await.with_context(|| {
+            format!(
+                "ImmutableFilesChainReader failed to read chunk file '{:?}'",
+                chunk_file.path
+            )
+        })?;
+        let blocks = self
+            .parse_chunk_blocks(&chunk_bytes, &secondary_index)
+            .with_context(|| {
+                format!(
+                    "ImmutableFilesChainReader failed to parse chunk file '{:?}'",
+                    chunk_file.path
+                )
+            })?;
+
+        self.pending_blocks.extend(blocks);
+        self.next_immutable_file_number = Some(chunk_file.number + 1);
+
+        Ok(true)
+    }
+}
+
+#[async_trait]
+impl ChainBlockReader for ImmutableFilesChainReader {
+    async fn set_chain_point(&mut self, point: &RawCardanoPoint) -> StdResult<()> {
+        self.chain_point = Some(point.clone());
+        self.next_immutable_file_number = None;
+        self.pending_blocks.clear();
+
+        Ok(())
+    }
+
+    async fn get_next_chain_block(&mut self) -> StdResult<Option<ChainBlockNextAction>> {
+        while self.pending_blocks.is_empty() {
+            if !self.load_next_immutable_file().await? {
+                return Ok(None);
+            }
+        }
+
+        Ok(self.pending_blocks.pop_front().map(|parsed_block| {
+            self.chain_point = Some(RawCardanoPoint::from(&parsed_block));
+            ChainBlockNextAction::RollForward { parsed_block }
+        }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use crate::entities::SlotNumber;
+    use crate::test_utils::{TempDir, TestLogger};
+
+    use super::*;
+
+    /// Copy the immutable files of the test data to a new Cardano node database directory.
+    ///
+    /// The test data has four immutable files of a hundred blocks each, the last one being
+    /// considered not completed, so blocks of slots 0 to 299 can be read.
+    fn create_db_with_immutable_files(test_name: &str) -> PathBuf {
+        let db_path = TempDir::create("immutable_files_chain_reader", test_name);
+        let immutable_dir = db_path.join("immutable");
+        fs::create_dir_all(&immutable_dir).unwrap();
+        let test_data_dir =
+            Path::new(env!("CARGO_MANIFEST_DIR")).join("../mithril-test-lab/test_data/immutable");
+        for entry in fs::read_dir(test_data_dir).unwrap() {
+            let path = entry.unwrap().path();
+            fs::copy(&path, immutable_dir.join(path.file_name().unwrap())).unwrap();
+        }
+
+        db_path
+    }
+
+    async fn read_all_blocks(chain_reader: &mut ImmutableFilesChainReader) -> Vec<ScannedBlock> {
+        let mut blocks = vec![];
+        while let Some(next_action) = chain_reader.get_next_chain_block().await.unwrap() {
+            match next_action {
+                ChainBlockNextAction::RollForward { parsed_block } => blocks.push(parsed_block),
+                ChainBlockNextAction::RollBackward { .. } => {
+                    panic!("Immutable files chain reader should never roll backward")
+                }
+            }
+        }
+
+        blocks
+    }
+
+    #[test]
+    fn parse_secondary_index_entries() {
+        let mut bytes = vec![];
+        for (offset, slot) in [(0_u64, 10_u64), (850, 12)] {
+            bytes.extend(offset.to_be_bytes());
+            bytes.extend([0; 8]);
+            bytes.extend([slot as u8; 32]);
+            bytes.extend(slot.to_be_bytes());
+        }
+
+        let entries = SecondaryIndexEntry::parse_all(&bytes).unwrap();
+
+        assert_eq!(
+            vec![
+                SecondaryIndexEntry {
+                    block_offset: 0,
+                    header_hash: vec![10; 32],
+                    block_or_ebb: 10,
+                    is_ebb: false,
+                },
+                SecondaryIndexEntry {
+                    block_offset: 850,
+                    header_hash: vec![12; 32],
+                    block_or_ebb: 12,
+                    is_ebb: false,
+                },
+            ],
+            entries
+        );
+    }
+
+    fn secondary_index_bytes(entries: &[(u64, u64)]) -> Vec<u8> {
+        let mut bytes = vec![];
+        for (offset, block_or_ebb) in entries {
+            bytes.extend(offset.to_be_bytes());
+            bytes.extend([0; 8]);
+            bytes.extend([*block_or_ebb as u8; 32]);
+            bytes.extend(block_or_ebb.to_be_bytes());
+        }
+        bytes
+    }
+
+    fn primary_index_bytes(offsets: &[u32]) -> Vec<u8> {
+        let mut bytes = vec![PRIMARY_INDEX_VERSION];
+        for offset in offsets {
+            bytes.extend(offset.to_be_bytes());
+        }
+        bytes
+    }
+
+    #[test]
+    fn parse_secondary_index_entries_with_primary_index_flags_the_epoch_boundary_block() {
+        let secondary_bytes = secondary_index_bytes(&[(0, 3), (650, 64_800), (1500, 64_802)]);
+        // EBB at relative slot 0, blocks at relative slots 1 and 3
+        let primary_bytes = primary_index_bytes(&[0, 56, 112, 112, 168, 168]);
+
+        let entries =
+            SecondaryIndexEntry::parse_all_with_primary_index(&primary_bytes, &secondary_bytes)
+                .unwrap();
+
+        assert_eq!(
+            vec![
+                (None, true),
+                (Some(SlotNumber(64_800)), false),
+                (Some(SlotNumber(64_802)), false)
+            ],
+            entries
+                .iter()
+                .map(|e| (e.slot_number(), e.is_ebb))
+                .collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn parse_secondary_index_entries_with_primary_index_of_the_test_data() {
+        let test_data_dir =
+            Path::new(env!("CARGO_MANIFEST_DIR")).join("../mithril-test-lab/test_data/immutable");
+        let primary_bytes = fs::read(test_data_dir.join("00001.primary")).unwrap();
+        let secondary_bytes = fs::read(test_data_dir.join("00001.secondary")).unwrap();
+
+        let entries =
+            SecondaryIndexEntry::parse_all_with_primary_index(&primary_bytes, &secondary_bytes)
+                .unwrap();
+
+        assert_eq!(
+            (100..200)
+                .map(|slot| Some(SlotNumber(slot)))
+                .collect::<Vec<_>>(),
+            entries.iter().map(|e| e.slot_number()).collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn parse_secondary_index_entries_with_primary_index_fails_if_an_entry_is_not_referenced() {
+        let secondary_bytes = secondary_index_bytes(&[(0, 1), (650, 2)]);
+        let primary_bytes = primary_index_bytes(&[0, 0, 56, 56]);
+
+        SecondaryIndexEntry::parse_all_with_primary_index(&primary_bytes, &secondary_bytes)
+            .expect_err("Should fail when a secondary index entry is not referenced");
+    }
+
+    #[test]
+    fn parse_secondary_index_entries_with_primary_index_fails_with_an_unsupported_version() {
+        let secondary_bytes = secondary_index_bytes(&[(0, 1)]);
+        let mut primary_bytes = primary_index_bytes(&[0, 0, 56]);
+        primary_bytes[0] = 2;
+
+        SecondaryIndexEntry::parse_all_with_primary_index(&primary_bytes, &secondary_bytes)
+            .expect_err("Should fail with an unsupported primary index version");
+    }
+
+    #[test]
+    fn parse_secondary_index_with_truncated_entry_fails() {
+        SecondaryIndexEntry::parse_all(&[0; SECONDARY_INDEX_ENTRY_SIZE + 1])
+            .expect_err("Should fail with a truncated entry");
+    }
+
+    #[tokio::test]
+    async fn read_all_blocks_of_the_completed_immutable_files_from_origin() {
+        let db_path = create_db_with_immutable_files("read_from_origin");
+        let mut chain_reader = ImmutableFilesChainReader::new(&db_path, TestLogger::stdout());
+
+        let blocks = read_all_blocks(&mut chain_reader).await;
+
+        assert_eq!(300, blocks.len());
+        assert_eq!(
+            (0..300).map(SlotNumber).collect::<Vec<_>>(),
+            blocks.iter().map(|b| b.slot_number).collect::<Vec<_>>()
+        );
+        assert!(blocks
+            .windows(2)
+            .all(|pair| pair[0].block_number < pair[1].block_number));
+    }
+
+    #[tokio::test]
+    async fn read_blocks_after_the_chain_point() {
+        let db_path = create_db_with_immutable_files("read_after_chain_point");
+        let mut chain_reader = ImmutableFilesChainReader::new(&db_path, TestLogger::stdout());
+        chain_reader
+            .set_chain_point(&RawCardanoPoint::new(
+                SlotNumber(149),
+                hex::decode("f7d3c32a7db7b81b295e54df71232d40a39580f203817fabbc70ca6395009d99")
+                    .unwrap(),
+            ))
+            .await
+            .unwrap();
+
+        let blocks = read_all_blocks(&mut chain_reader).await;
+
+        assert_eq!(150, blocks.len());
+        assert_eq!(SlotNumber(150), blocks[0].slot_number);
+        assert_eq!(
+            "fca851d4694b4be621786b1a6a0ea0933b2d09475c9177902c55735db5da2e24",
+            hex::encode(&blocks[0].block_hash)
+        );
+    }
+
+    #[tokio::test]
+    async fn set_chain_point_restarts_the_reading_from_the_new_point() {
+        let db_path = create_db_with_immutable_files("restart_from_new_point");
+        let mut chain_reader = ImmutableFilesChainReader::new(&db_path, TestLogger::stdout());
+        read_all_blocks(&mut chain_reader).await;
+
+        chain_reader
+            .set_chain_point(&RawCardanoPoint::new(SlotNumber(289), vec![]))
+            .await
+            .unwrap();
+        let blocks = read_all_blocks(&mut chain_reader).await;
+
+        assert_eq!(
+            (290..300).map(SlotNumber).collect::<Vec<_>>(),
+            blocks.iter().map(|b| b.slot_number).collect::<Vec<_>>()
+        );
+    }
+
+    #[tokio::test]
+    async fn return_none_when_the_chain_point_is_after_the_last_completed_immutable_file() {
+        let db_path = create_db_with_immutable_files("chain_point_after_last_immutable");
+        let mut chain_reader = ImmutableFilesChainReader::new(&db_path, TestLogger::stdout());
+        chain_reader
+            .set_chain_point(&RawCardanoPoint::new(SlotNumber(299), vec![]))
+            .await
+            .unwrap();
+
+        let next_action = chain_reader.get_next_chain_block().await.unwrap();
+
+        assert_eq!(None, next_action);
+    }
+
+    #[tokio::test]
+    async fn read_blocks_with_transactions_filters_extraction() {
+        let db_path = create_db_with_immutable_files("read_with_filters_extraction");
+        let mut chain_reader = ImmutableFilesChainReader::new(&db_path, TestLogger::stdout())
+            .with_transactions_filters_extraction(true);
+
+        let blocks = read_all_blocks(&mut chain_reader).await;
+
+        assert!(blocks
+            .iter()
+            .all(|b| b.transactions_filters.len() == b.transactions_hashes.len()));
+    }
+}
@@ -2,10 +2,12 @@
 
 mod entity;
 mod fake_chain_reader;
+mod immutable_files_chain_reader;
 mod interface;
 mod pallas_chain_reader;
 
 pub use entity::*;
 pub use fake_chain_reader::*;
+pub use immutable_files_chain_reader::*;
 pub use interface::*;
 pub use pallas_chain_reader::*;
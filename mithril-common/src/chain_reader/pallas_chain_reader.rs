@@ -22,6 +22,7 @@ pub struct PallasChainReader {
     socket: PathBuf,
     network: CardanoNetwork,
     client: Option<NodeClient>,
+    extract_transactions_filters: bool,
     logger: Logger,
 }
 
@@ -32,10 +33,18 @@ impl PallasChainReader {
             socket: socket.to_owned(),
             network,
             client: None,
+            extract_transactions_filters: false,
             logger: logger.new_with_component_name::<Self>(),
         }
     }
 
+    /// Enable or disable the extraction of the output addresses and metadata labels of the
+    /// transactions of the read blocks.
+    pub fn with_transactions_filters_extraction(mut self, enabled: bool) -> Self {
+        self.extract_transactions_filters = enabled;
+        self
+    }
+
     /// Creates and returns a new `NodeClient` connected to the specified socket.
     async fn new_client(&self) -> StdResult<NodeClient> {
         let magic = self.network.code();
@@ -84,7 +93,11 @@ impl PallasChainReader {
             NextResponse::RollForward(raw_block, _forward_tip) => {
                 let multi_era_block = MultiEraBlock::decode(&raw_block)
                     .with_context(|| "PallasChainReader failed to decode raw block")?;
-                let parsed_block = ScannedBlock::convert(multi_era_block);
+                let parsed_block = if self.extract_transactions_filters {
+                    ScannedBlock::convert_with_transactions_filters(multi_era_block)
+                } else {
+                    ScannedBlock::convert(multi_era_block)
+                };
                 Ok(Some(ChainBlockNextAction::RollForward { parsed_block }))
             }
             NextResponse::RollBackward(rollback_point, _) => {
@@ -142,7 +155,10 @@ mod tests {
     use super::*;
 
     use crate::test_utils::TestLogger;
-    use crate::{entities::BlockNumber, test_utils::TempDir};
+    use crate::{
+        entities::{BlockNumber, CardanoTransactionFilter},
+        test_utils::TempDir,
+    };
 
     /// Enum representing the action to be performed by the server.
     enum ServerAction {
@@ -324,6 +340,63 @@ mod tests {
         }
     }
 
+    #[tokio::test]
+    async fn get_next_chain_block_rolls_forward_with_transactions_filters() {
+        let socket_path =
+            create_temp_dir("get_next_chain_block_rolls_forward_with_transactions_filters")
+                .join("node.socket");
+        let known_point = get_fake_specific_point();
+        let server = setup_server(
+            socket_path.clone(),
+            ServerAction::RollForward,
+            HasAgency::Yes,
+        )
+        .await;
+        let client = tokio::spawn(async move {
+            let mut chain_reader = PallasChainReader::new(
+                socket_path.as_path(),
+                CardanoNetwork::TestNet(10),
+                TestLogger::stdout(),
+            )
+            .with_transactions_filters_extraction(true);
+
+            chain_reader
+                .set_chain_point(&RawCardanoPoint::from(known_point.clone()))
+                .await
+                .unwrap();
+
+            chain_reader.get_next_chain_block().await.unwrap().unwrap()
+        });
+
+        let (_, client_res) = tokio::join!(server, client);
+        let chain_block = client_res.expect("Client failed to get next chain block");
+        match chain_block {
+            ChainBlockNextAction::RollForward { parsed_block } => {
+                assert_eq!(
+                    get_fake_scanned_block().transactions_hashes,
+                    parsed_block.transactions_hashes
+                );
+                assert_eq!(
+                    parsed_block.transactions_hashes,
+                    parsed_block
+                        .transactions_filters
+                        .iter()
+                        .map(|(hash, _)| hash.clone())
+                        .collect::<Vec<_>>()
+                );
+                assert!(parsed_block
+                    .transactions_filters
+                    .iter()
+                    .all(|(_, filters)| {
+                        filters
+                            .iter()
+                            .any(|f| matches!(f, CardanoTransactionFilter::Address(_)))
+                    }));
+            }
+            _ => panic!("Unexpected chain block action"),
+        }
+    }
+
     #[tokio::test]
     async fn get_next_chain_block_has_no_agency() {
         let socket_path = create_temp_dir("get_next_chain_block_has_no_agency").join("node.socket");
@@ -7,8 +7,9 @@ use crate::{
     crypto_helper::{
         cardano::SerDeShelleyFileFormat,
         types::{
-            ProtocolParameters, ProtocolPartyId, ProtocolSignerVerificationKey,
-            ProtocolSignerVerificationKeySignature, ProtocolStakeDistribution,
+            ProtocolInitializerEncryptionKey, ProtocolParameters, ProtocolPartyId,
+            ProtocolSignerVerificationKey, ProtocolSignerVerificationKeySignature,
+            ProtocolStakeDistribution,
         },
         ProtocolOpCert,
     },
@@ -245,6 +246,68 @@ impl StmInitializerWrapper {
         })
     }
 
+    /// Convert to bytes, with the `StmInitializer` encrypted with the given key.
+    /// # Layout
+    /// * Length of the encrypted StmInitializer (u64)
+    /// * Encrypted StmInitializer
+    /// * KesSignature
+    pub fn to_encrypted_bytes<R: RngCore + CryptoRng>(
+        &self,
+        key: &ProtocolInitializerEncryptionKey,
+        rng: &mut R,
+    ) -> StdResult<Vec<u8>> {
+        let encrypted_stm_initializer = self
+            .stm_initializer
+            .to_encrypted_bytes(key, rng)
+            .with_context(|| "Could not encrypt the protocol initializer")?;
+
+        let mut out = Vec::new();
+        out.extend_from_slice(&(encrypted_stm_initializer.len() as u64).to_be_bytes());
+        out.extend_from_slice(&encrypted_stm_initializer);
+        if let Some(kes_signature) = &self.kes_signature {
+            out.extend_from_slice(&kes_signature.to_bytes());
+        }
+
+        Ok(out)
+    }
+
+    /// Convert a slice of bytes produced by [to_encrypted_bytes](Self::to_encrypted_bytes) to an
+    /// `StmInitializerWrapper`, decrypting its `StmInitializer` with the given key.
+    /// # Error
+    /// The function fails if the bytes are truncated, or if the `StmInitializer` can't be
+    /// decrypted with the given key.
+    pub fn from_encrypted_bytes(
+        bytes: &[u8],
+        key: &ProtocolInitializerEncryptionKey,
+    ) -> StdResult<Self> {
+        let mut u64_bytes = [0u8; 8];
+        u64_bytes.copy_from_slice(
+            bytes
+                .get(..8)
+                .ok_or(anyhow!("Encrypted protocol initializer is truncated"))?,
+        );
+        let encrypted_length = u64::from_be_bytes(u64_bytes) as usize;
+        let (encrypted_stm_initializer, kes_signature_bytes) = bytes[8..]
+            .split_at_checked(encrypted_length)
+            .ok_or(anyhow!("Encrypted protocol initializer is truncated"))?;
+
+        let stm_initializer = StmInitializer::from_encrypted_bytes(encrypted_stm_initializer, key)
+            .with_context(|| "Could not decrypt the protocol initializer")?;
+        let kes_signature = if kes_signature_bytes.is_empty() {
+            None
+        } else {
+            Some(
+                Sum6KesSig::from_bytes(kes_signature_bytes)
+                    .map_err(|_| anyhow!("Could not decode the KES signature"))?,
+            )
+        };
+
+        Ok(Self {
+            stm_initializer,
+            kes_signature,
+        })
+    }
+
     cfg_test_tools! {
         /// Override the protocol parameters of the `StmInitializer` for testing purposes only.
         pub fn override_protocol_parameters(&mut self, protocol_parameters: &ProtocolParameters) {
@@ -273,6 +336,27 @@ impl KeyRegWrapper {
         kes_sig: Option<ProtocolSignerVerificationKeySignature>, // Used for only for testing when SPO pool id is not certified
         kes_period: Option<KESPeriod>,
         pk: ProtocolSignerVerificationKey,
+    ) -> Result<ProtocolPartyId, ProtocolRegistrationErrorWrapper> {
+        let pool_id_bech32 = self.certify(party_id, opcert, kes_sig, kes_period, &pk)?;
+        let stake = self.stake_distribution[&pool_id_bech32];
+        self.stm_key_reg
+            .register(stake, pk.into())
+            .map_err(ProtocolRegistrationErrorWrapper::CoreRegister)?;
+
+        Ok(pool_id_bech32)
+    }
+
+    /// Certify a party without registering it: the OpCert and the KES signature of the
+    /// Mithril key are verified, and the party must be part of the stake distribution.
+    ///
+    /// The certified parties can then be registered at once with [Self::register_all].
+    pub fn certify(
+        &self,
+        party_id: Option<ProtocolPartyId>, // Used for only for testing when SPO pool id is not certified
+        opcert: Option<ProtocolOpCert>, // Used for only for testing when SPO pool id is not certified
+        kes_sig: Option<ProtocolSignerVerificationKeySignature>, // Used for only for testing when SPO pool id is not certified
+        kes_period: Option<KESPeriod>,
+        pk: &ProtocolSignerVerificationKey,
     ) -> Result<ProtocolPartyId, ProtocolRegistrationErrorWrapper> {
         let pool_id_bech32: ProtocolPartyId = if let Some(opcert) = opcert {
             opcert
@@ -308,15 +392,36 @@ impl KeyRegWrapper {
             party_id.ok_or(ProtocolRegistrationErrorWrapper::PartyIdMissing)?
         };
 
-        if let Some(&stake) = self.stake_distribution.get(&pool_id_bech32) {
-            self.stm_key_reg
-                .register(stake, pk.into())
-                .map_err(ProtocolRegistrationErrorWrapper::CoreRegister)?;
+        if self.stake_distribution.contains_key(&pool_id_bech32) {
             return Ok(pool_id_bech32);
         }
         Err(ProtocolRegistrationErrorWrapper::PartyIdNonExisting)
     }
 
+    /// Register several certified parties at once.
+    ///
+    /// The proofs of possession of the Mithril keys are verified in a single batch, which is
+    /// much faster than registering the parties one by one. No party is registered if one of
+    /// them can not be registered.
+    pub fn register_all(
+        &mut self,
+        parties: &[(ProtocolPartyId, ProtocolSignerVerificationKey)],
+    ) -> Result<(), ProtocolRegistrationErrorWrapper> {
+        let parties = parties
+            .iter()
+            .map(|(party_id, pk)| {
+                self.stake_distribution
+                    .get(party_id)
+                    .map(|&stake| (stake, **pk))
+                    .ok_or(ProtocolRegistrationErrorWrapper::PartyIdNonExisting)
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        self.stm_key_reg
+            .register_all(&parties)
+            .map_err(ProtocolRegistrationErrorWrapper::CoreRegister)
+    }
+
     /// Finalize the key registration.
     /// This function disables `KeyReg::register`, consumes the instance of `self`, and returns a `ClosedKeyReg`.
     pub fn close<D: Digest + FixedOutput>(self) -> ClosedKeyReg<D> {
@@ -423,6 +528,50 @@ mod test {
         assert!(key_registration_2.is_ok())
     }
 
+    #[test]
+    fn test_certify_and_register_all() {
+        let params = StmParameters {
+            m: 5,
+            k: 5,
+            phi_f: 1.0,
+        };
+        let mut rng = ChaCha20Rng::from_seed([0u8; 32]);
+        let (party_id, operational_certificate_file, kes_secret_key_file) =
+            create_cryptographic_material(3);
+        let mut key_reg = KeyRegWrapper::init(&vec![(party_id.clone(), 10)]);
+        let initializer =
+            StmInitializerWrapper::setup(params, Some(kes_secret_key_file), Some(0), 10, &mut rng)
+                .unwrap();
+        let opcert = OpCert::from_file(operational_certificate_file)
+            .expect("opcert deserialization should not fail")
+            .into();
+        let verification_key: ProtocolSignerVerificationKey =
+            initializer.stm_initializer.verification_key().into();
+
+        let certified_party_id = key_reg
+            .certify(
+                None,
+                Some(opcert),
+                initializer.verification_key_signature(),
+                Some(0),
+                &verification_key,
+            )
+            .expect("certify should not fail");
+        assert_eq!(party_id, certified_party_id);
+
+        key_reg
+            .register_all(&[(certified_party_id.clone(), verification_key.clone())])
+            .expect("register_all should not fail");
+
+        let error = key_reg
+            .register_all(&[(certified_party_id, verification_key)])
+            .expect_err("registering the same key twice should fail");
+        assert!(matches!(
+            error,
+            ProtocolRegistrationErrorWrapper::CoreRegister(RegisterError::KeyRegistered(_))
+        ));
+    }
+
     const GOLDEN_STM_INITIALIZER_WRAPPER_JSON: &str = r#"
     {
         "stm_initializer": {
@@ -504,4 +653,44 @@ mod test {
                 .expect("Deserializing a StmInitializerWrapper from bytes should not fail");
         assert_eq!(None, stm_initializer_wrapper_from_bytes.kes_signature);
     }
+
+    #[test]
+    fn test_initializer_wrapper_encrypted_conversions() {
+        let stm_initializer_wrapper: StmInitializerWrapper =
+            serde_json::from_str(GOLDEN_STM_INITIALIZER_WRAPPER_JSON)
+                .expect("Deserializing a StmInitializerWrapper should not fail");
+        let mut rng = ChaCha20Rng::from_seed([0u8; 32]);
+        let key = ProtocolInitializerEncryptionKey::generate(&mut rng);
+
+        let encrypted_bytes = stm_initializer_wrapper
+            .to_encrypted_bytes(&key, &mut rng)
+            .unwrap();
+        let stm_initializer_wrapper_from_encrypted_bytes =
+            StmInitializerWrapper::from_encrypted_bytes(&encrypted_bytes, &key)
+                .expect("Decrypting a StmInitializerWrapper with its key should not fail");
+
+        assert_eq!(
+            stm_initializer_wrapper.to_bytes(),
+            stm_initializer_wrapper_from_encrypted_bytes.to_bytes()
+        );
+        StmInitializerWrapper::from_encrypted_bytes(
+            &encrypted_bytes,
+            &ProtocolInitializerEncryptionKey::generate(&mut rng),
+        )
+        .expect_err("Decrypting a StmInitializerWrapper with another key should fail");
+        StmInitializerWrapper::from_encrypted_bytes(&encrypted_bytes[..100], &key)
+            .expect_err("Decrypting a truncated StmInitializerWrapper should fail");
+    }
+
+    #[test]
+    fn initializer_wrapper_debug_does_not_contain_the_secret_key() {
+        let stm_initializer_wrapper: StmInitializerWrapper =
+            serde_json::from_str(GOLDEN_STM_INITIALIZER_WRAPPER_JSON)
+                .expect("Deserializing a StmInitializerWrapper should not fail");
+
+        let debug = format!("{stm_initializer_wrapper:?}");
+
+        assert!(debug.contains("<redacted>"));
+        assert!(!debug.contains("[49, 181, 118, 110"));
+    }
 }
@@ -21,7 +21,7 @@ pub trait MKMapValue<K: MKMapKey>: Clone + TryInto<MKTreeNode> + TryFrom<MKTreeN
     fn compute_root(&self) -> StdResult<MKTreeNode>;
 
     /// Check if the merkelized map value contains a leaf
-    fn contains<T: Into<MKTreeNode> + Clone>(&self, leaf: &T) -> bool;
+    fn contains<T: Into<MKTreeNode> + Clone>(&self, leaf: &T) -> StdResult<bool>;
 
     /// Can the merkelized map value compute a proof
     fn can_compute_proof(&self) -> bool;
@@ -137,8 +137,14 @@ impl<K: MKMapKey, V: MKMapValue<K>, S: MKTreeStorer> MKMap<K, V, S> {
     }
 
     /// Check if the merkelized map contains a leaf (and returns the corresponding key and value if exists)
-    pub fn contains(&self, leaf: &MKTreeNode) -> Option<(&K, &V)> {
-        self.iter().find(|(_, v)| v.contains(leaf))
+    pub fn contains(&self, leaf: &MKTreeNode) -> StdResult<Option<(&K, &V)>> {
+        for (key, value) in self.iter() {
+            if value.contains(leaf)? {
+                return Ok(Some((key, value)));
+            }
+        }
+
+        Ok(None)
     }
 
     /// Get the value of the merkelized map for a given key
@@ -191,7 +197,7 @@ impl<K: MKMapKey, V: MKMapValue<K>, S: MKTreeStorer> MKMap<K, V, S> {
             return Err(anyhow!("MKMap could not compute proof for empty leaves"));
         }
 
-        let leaves_by_keys = self.group_leaves_by_keys(leaves);
+        let leaves_by_keys = self.group_leaves_by_keys(leaves)?;
         let mut sub_proofs = BTreeMap::<K, MKMapProof<K>>::default();
         for (key, sub_leaves) in leaves_by_keys {
             if let Some(value) = self.get(&key) {
@@ -218,31 +224,25 @@ impl<K: MKMapKey, V: MKMapValue<K>, S: MKTreeStorer> MKMap<K, V, S> {
     fn group_leaves_by_keys<T: Into<MKTreeNode> + Clone>(
         &self,
         leaves: &[T],
-    ) -> HashMap<K, Vec<MKTreeNode>> {
+    ) -> StdResult<HashMap<K, Vec<MKTreeNode>>> {
         let can_compute_proof_map: HashMap<K, V> = self
             .provable_keys
             .iter()
             .filter_map(|k| self.get(k).map(|v| (k.to_owned(), v.to_owned())))
             .collect();
-        let leaves_by_keys: HashMap<K, Vec<MKTreeNode>> = can_compute_proof_map
-            .iter()
-            .map(|(key, value)| {
-                let leaves_found = leaves
-                    .iter()
-                    .filter_map(|leaf| value.contains(leaf).then_some(leaf.to_owned().into()))
-                    .collect::<Vec<_>>();
-
-                (key.to_owned(), leaves_found)
-            })
-            .fold(HashMap::default(), |mut acc, (key, leaves)| {
-                leaves.into_iter().for_each(|leaf| {
-                    acc.entry(key.to_owned()).or_default().push(leaf);
-                });
-
-                acc
-            });
+        let mut leaves_by_keys: HashMap<K, Vec<MKTreeNode>> = HashMap::default();
+        for (key, value) in can_compute_proof_map.iter() {
+            for leaf in leaves {
+                if value.contains(leaf)? {
+                    leaves_by_keys
+                        .entry(key.to_owned())
+                        .or_default()
+                        .push(leaf.to_owned().into());
+                }
+            }
+        }
 
-        leaves_by_keys
+        Ok(leaves_by_keys)
     }
 }
 
@@ -336,6 +336,33 @@ impl<K: MKMapKey> MKMapProof<K> {
             .ok_or(anyhow!("MKMapProof does not contain leaf {:?}", leaf))
     }
 
+    /// List the keys of the merkelized map which values are proven by a sub proof
+    pub fn proven_keys(&self) -> Vec<K> {
+        self.sub_proofs.iter().map(|(k, _p)| k.to_owned()).collect()
+    }
+
+    /// Check that the sub proof of the given key contains all the leaves of the merkelized value
+    /// of this key
+    pub fn contains_all_leaves_of(&self, key: &K) -> StdResult<()> {
+        let (_key, sub_proof) = self
+            .sub_proofs
+            .iter()
+            .find(|(k, _p)| k == key)
+            .ok_or(anyhow!(
+                "MKMapProof does not contain a sub proof for the key"
+            ))?;
+        if !sub_proof.sub_proofs.is_empty() {
+            return Err(anyhow!(
+                "MKMapProof can not check the completeness of a recursive sub proof"
+            ));
+        }
+
+        sub_proof
+            .master_proof
+            .contains_all_leaves()
+            .with_context(|| "MKMapProof sub proof does not contain all the leaves of the key")
+    }
+
     /// List the leaves of the merkelized map proof
     pub fn leaves(&self) -> Vec<MKTreeNode> {
         if self.sub_proofs.is_empty() {
@@ -381,12 +408,12 @@ impl<K: MKMapKey, S: MKTreeStorer> MKMapValue<K> for MKMapNode<K, S> {
         }
     }
 
-    fn contains<T: Into<MKTreeNode> + Clone>(&self, leaf: &T) -> bool {
+    fn contains<T: Into<MKTreeNode> + Clone>(&self, leaf: &T) -> StdResult<bool> {
         let leaf = leaf.to_owned().into();
         match self {
-            MKMapNode::Map(mk_map) => mk_map.contains(&leaf).is_some(),
+            MKMapNode::Map(mk_map) => Ok(mk_map.contains(&leaf)?.is_some()),
             MKMapNode::Tree(merkle_tree) => merkle_tree.contains(&leaf),
-            MKMapNode::TreeNode(merkle_tree_node) => *merkle_tree_node == leaf,
+            MKMapNode::TreeNode(merkle_tree_node) => Ok(*merkle_tree_node == leaf),
         }
     }
 
@@ -761,11 +788,14 @@ mod tests {
             BlockRange::new(4, 6),
             BlockRange::new(7, 9),
         ]);
-        let mktree_node_to_certify = entries[2].1.leaves()[1].clone();
+        let mktree_node_to_certify = entries[2].1.leaves().unwrap()[1].clone();
         let mk_map_full =
             MKMap::<_, _, MKTreeStoreInMemory>::new(&into_mkmap_tree_entries(entries)).unwrap();
 
-        mk_map_full.contains(&mktree_node_to_certify).unwrap();
+        mk_map_full
+            .contains(&mktree_node_to_certify)
+            .unwrap()
+            .unwrap();
     }
 
     #[test]
@@ -802,10 +832,10 @@ mod tests {
     fn test_mk_map_should_compute_and_verify_valid_proof() {
         let entries = generate_merkle_trees(10, 3);
         let mktree_nodes_to_certify = [
-            entries[0].1.leaves()[0].clone(),
-            entries[1].1.leaves()[0].clone(),
-            entries[1].1.leaves()[1].clone(),
-            entries[2].1.leaves()[1].clone(),
+            entries[0].1.leaves().unwrap()[0].clone(),
+            entries[1].1.leaves().unwrap()[0].clone(),
+            entries[1].1.leaves().unwrap()[1].clone(),
+            entries[2].1.leaves().unwrap()[1].clone(),
         ];
         let mk_map_full =
             MKMap::<_, _, MKTreeStoreInMemory>::new(&into_mkmap_tree_entries(entries)).unwrap();
@@ -821,15 +851,47 @@ mod tests {
         assert_eq!(mktree_nodes_to_certify.to_vec(), mk_proof_leaves);
     }
 
+    #[test]
+    fn test_mk_map_proof_should_contain_all_leaves_only_of_fully_proven_keys() {
+        let entries = generate_merkle_trees(10, 3);
+        let mut mktree_nodes_to_certify = entries[0].1.leaves().unwrap();
+        mktree_nodes_to_certify.push(entries[1].1.leaves().unwrap()[0].clone());
+        let (block_range_fully_proven, block_range_partially_proven, block_range_not_proven) = (
+            entries[0].0.clone(),
+            entries[1].0.clone(),
+            entries[2].0.clone(),
+        );
+        let mk_map_full =
+            MKMap::<_, _, MKTreeStoreInMemory>::new(&into_mkmap_tree_entries(entries)).unwrap();
+        let mk_map_proof = mk_map_full.compute_proof(&mktree_nodes_to_certify).unwrap();
+
+        assert_eq!(
+            vec![
+                block_range_fully_proven.clone(),
+                block_range_partially_proven.clone()
+            ],
+            mk_map_proof.proven_keys()
+        );
+        mk_map_proof
+            .contains_all_leaves_of(&block_range_fully_proven)
+            .expect("All the leaves of the block range should be proven");
+        mk_map_proof
+            .contains_all_leaves_of(&block_range_partially_proven)
+            .expect_err("Only some leaves of the block range are proven");
+        mk_map_proof
+            .contains_all_leaves_of(&block_range_not_proven)
+            .expect_err("The block range is not proven");
+    }
+
     #[test]
     fn test_mk_map_should_compute_and_verify_valid_proof_recursively() {
         let entries = generate_merkle_trees(100, 3);
         let mktree_nodes_to_certify = [
-            entries[0].1.leaves()[0].clone(),
-            entries[2].1.leaves()[1].clone(),
-            entries[3].1.leaves()[2].clone(),
-            entries[20].1.leaves()[0].clone(),
-            entries[30].1.leaves()[0].clone(),
+            entries[0].1.leaves().unwrap()[0].clone(),
+            entries[2].1.leaves().unwrap()[1].clone(),
+            entries[3].1.leaves().unwrap()[2].clone(),
+            entries[20].1.leaves().unwrap()[0].clone(),
+            entries[30].1.leaves().unwrap()[0].clone(),
         ];
         let merkle_tree_node_entries = &into_mkmap_tree_entries(entries)
             .chunks(10)
@@ -1,12 +1,12 @@
 use anyhow::{anyhow, Context};
 use blake2::{Blake2s256, Digest};
 use ckb_merkle_mountain_range::{
-    Error as MMRError, MMRStoreReadOps, MMRStoreWriteOps, Merge, MerkleProof, Result as MMRResult,
-    MMR,
+    leaf_index_to_mmr_size, leaf_index_to_pos, Error as MMRError, MMRStoreReadOps,
+    MMRStoreWriteOps, Merge, MerkleProof, Result as MMRResult, MMR,
 };
 use serde::{Deserialize, Serialize};
 use std::{
-    collections::{BTreeMap, HashMap},
+    collections::{BTreeMap, BTreeSet, HashMap},
     fmt::Display,
     ops::{Add, Deref},
     sync::{Arc, RwLock},
@@ -169,6 +169,30 @@ impl MKProof {
             .collect::<Vec<_>>()
     }
 
+    /// Check that the proof contains all the leaves of the Merkle tree it was computed from
+    ///
+    /// The proven positions must be exactly the positions of the leaves of a Merkle mountain
+    /// range which size is the size of the proof.
+    pub fn contains_all_leaves(&self) -> StdResult<()> {
+        let proven_positions = self
+            .inner_leaves
+            .iter()
+            .map(|(position, _)| *position)
+            .collect::<BTreeSet<_>>();
+        let total_leaves = proven_positions.len() as u64;
+        let expected_positions = (0..total_leaves)
+            .map(leaf_index_to_pos)
+            .collect::<BTreeSet<_>>();
+
+        (total_leaves > 0
+            && proven_positions == expected_positions
+            && leaf_index_to_mmr_size(total_leaves - 1) == self.inner_proof_size)
+            .then_some(())
+            .ok_or(anyhow!(
+                "MKProof does not contain all the leaves of its Merkle tree"
+            ))
+    }
+
     cfg_test_tools! {
         /// Build a [MKProof] based on the given leaves (*Test only*).
         pub fn from_leaves<T: Into<MKTreeNode> + Clone>(
@@ -227,25 +251,25 @@ impl MKTreeLeafIndexer for MKTreeStoreInMemory {
         Ok(())
     }
 
-    fn get_leaf_position(&self, node: &MKTreeNode) -> Option<MKTreeLeafPosition> {
+    fn get_leaf_position(&self, node: &MKTreeNode) -> StdResult<Option<MKTreeLeafPosition>> {
         let inner_leaves = self.inner_leaves.read().unwrap();
-        (*inner_leaves).get(node).cloned()
+        Ok((*inner_leaves).get(node).cloned())
     }
 
-    fn total_leaves(&self) -> usize {
+    fn total_leaves(&self) -> StdResult<usize> {
         let inner_leaves = self.inner_leaves.read().unwrap();
-        (*inner_leaves).len()
+        Ok((*inner_leaves).len())
     }
 
-    fn leaves(&self) -> Vec<MKTreeNode> {
+    fn leaves(&self) -> StdResult<Vec<MKTreeNode>> {
         let inner_leaves = self.inner_leaves.read().unwrap();
-        (*inner_leaves)
+        Ok((*inner_leaves)
             .iter()
             .map(|(leaf, position)| (position, leaf))
             .collect::<BTreeMap<_, _>>()
             .into_values()
             .map(|leaf| (**leaf).clone())
-            .collect()
+            .collect())
     }
 }
 
@@ -324,15 +348,15 @@ impl<S: MKTreeStorer> MKTreeLeafIndexer for MKTreeStore<S> {
         self.storer.set_leaf_position(pos, leaf)
     }
 
-    fn get_leaf_position(&self, leaf: &MKTreeNode) -> Option<MKTreeLeafPosition> {
+    fn get_leaf_position(&self, leaf: &MKTreeNode) -> StdResult<Option<MKTreeLeafPosition>> {
         self.storer.get_leaf_position(leaf)
     }
 
-    fn total_leaves(&self) -> usize {
+    fn total_leaves(&self) -> StdResult<usize> {
         self.storer.total_leaves()
     }
 
-    fn leaves(&self) -> Vec<MKTreeNode> {
+    fn leaves(&self) -> StdResult<Vec<MKTreeNode>> {
         self.storer.leaves()
     }
 }
@@ -343,17 +367,17 @@ pub trait MKTreeLeafIndexer {
     fn set_leaf_position(&self, pos: MKTreeLeafPosition, leaf: Arc<MKTreeNode>) -> StdResult<()>;
 
     /// Get the position of the leaf in the Merkle tree
-    fn get_leaf_position(&self, leaf: &MKTreeNode) -> Option<MKTreeLeafPosition>;
+    fn get_leaf_position(&self, leaf: &MKTreeNode) -> StdResult<Option<MKTreeLeafPosition>>;
 
     /// Number of leaves in the Merkle tree
-    fn total_leaves(&self) -> usize;
+    fn total_leaves(&self) -> StdResult<usize>;
 
     /// List of leaves with their positions in the Merkle tree
-    fn leaves(&self) -> Vec<MKTreeNode>;
+    fn leaves(&self) -> StdResult<Vec<MKTreeNode>>;
 
     /// Check if the Merkle tree contains the given leaf
-    fn contains_leaf(&self, leaf: &MKTreeNode) -> bool {
-        self.get_leaf_position(leaf).is_some()
+    fn contains_leaf(&self, leaf: &MKTreeNode) -> StdResult<bool> {
+        Ok(self.get_leaf_position(leaf)?.is_some())
     }
 }
 
@@ -393,17 +417,17 @@ impl<S: MKTreeStorer> MKTree<S> {
     }
 
     /// Number of leaves in the Merkle tree
-    pub fn total_leaves(&self) -> usize {
+    pub fn total_leaves(&self) -> StdResult<usize> {
         self.inner_tree.store().total_leaves()
     }
 
     /// List of leaves with their positions in the Merkle tree
-    pub fn leaves(&self) -> Vec<MKTreeNode> {
+    pub fn leaves(&self) -> StdResult<Vec<MKTreeNode>> {
         self.inner_tree.store().leaves()
     }
 
     /// Check if the Merkle tree contains the given leaf
-    pub fn contains(&self, leaf: &MKTreeNode) -> bool {
+    pub fn contains(&self, leaf: &MKTreeNode) -> StdResult<bool> {
         self.inner_tree.store().contains_leaf(leaf)
     }
 
@@ -421,7 +445,7 @@ impl<S: MKTreeStorer> MKTree<S> {
         let inner_leaves = leaves
             .iter()
             .map(|leaf| {
-                if let Some(leaf_position) = self.inner_tree.store().get_leaf_position(leaf) {
+                if let Some(leaf_position) = self.inner_tree.store().get_leaf_position(leaf)? {
                     Ok((leaf_position, Arc::new(leaf.to_owned())))
                 } else {
                     Err(anyhow!("Leaf not found in the Merkle tree"))
@@ -445,8 +469,8 @@ impl<S: MKTreeStorer> MKTree<S> {
 
 impl<S: MKTreeStorer> Clone for MKTree<S> {
     fn clone(&self) -> Self {
-        // Cloning should never fail so unwrap is safe
-        Self::new(&self.leaves()).unwrap()
+        // Cloning can't fail with the in memory store, it panics if a disk backed store fails
+        Self::new(&self.leaves().unwrap()).unwrap()
     }
 }
 
@@ -494,12 +518,42 @@ mod tests {
         proof.verify().expect_err("The MKProof should be invalid");
     }
 
+    #[test]
+    fn test_should_contain_all_leaves_when_proof_is_computed_for_all_leaves() {
+        for total_leaves in [1, 2, 3, 7, 10, 16] {
+            let leaves = generate_leaves(total_leaves);
+            let proof = MKProof::from_leaves(&leaves).expect("MKProof generation should not fail");
+
+            proof.contains_all_leaves().unwrap_or_else(|_| {
+                panic!("The MKProof of {total_leaves} leaves should be complete")
+            });
+        }
+    }
+
+    #[test]
+    fn test_should_not_contain_all_leaves_when_proof_is_computed_for_a_subset_of_leaves() {
+        let leaves = generate_leaves(10);
+        let proof = MKProof::from_subset_of_leaves(&leaves, &leaves[..9])
+            .expect("MKProof generation should not fail");
+
+        proof
+            .contains_all_leaves()
+            .expect_err("The MKProof should not be complete");
+
+        let proof = MKProof::from_subset_of_leaves(&leaves, &leaves[1..])
+            .expect("MKProof generation should not fail");
+
+        proof
+            .contains_all_leaves()
+            .expect_err("The MKProof should not be complete");
+    }
+
     #[test]
     fn test_should_list_leaves() {
         let leaves: Vec<MKTreeNode> = vec!["test-0".into(), "test-1".into(), "test-2".into()];
         let mktree =
             MKTree::<MKTreeStoreInMemory>::new(&leaves).expect("MKTree creation should not fail");
-        let leaves_retrieved = mktree.leaves();
+        let leaves_retrieved = mktree.leaves().unwrap();
 
         assert_eq!(
             leaves.iter().collect::<Vec<_>>(),
@@ -531,7 +585,7 @@ mod tests {
             .append(leaves_to_append)
             .expect("MKTree append leaves should not fail");
 
-        assert_eq!(10, mktree.total_leaves());
+        assert_eq!(10, mktree.total_leaves().unwrap());
     }
 
     #[test]
@@ -0,0 +1,321 @@
+use std::{iter::repeat_n, sync::Arc};
+
+use anyhow::Context;
+
+use crate::crypto_helper::{
+    Bytes, MKTreeLeafIndexer, MKTreeLeafPosition, MKTreeNode, MKTreeStorer,
+};
+use crate::StdResult;
+
+/// A Merkle tree store with a disk backed Sqlite backend
+///
+/// * The nodes and the leaves positions of the Merkle tree are stored in a private temporary
+///   database on disk, which is deleted when the last clone of the store is dropped.
+/// * Only a bounded page cache is kept in memory, which keeps the memory footprint stable
+///   regardless of the number of leaves of the Merkle tree.
+/// * This store is slower than the [in memory store][crate::crypto_helper::MKTreeStoreInMemory],
+///   clones of the store share the same underlying database.
+#[derive(Clone)]
+pub struct MKTreeStoreSqlite {
+    inner_store: Arc<sqlite::ConnectionThreadSafe>,
+}
+
+impl MKTreeStoreSqlite {
+    /// Maximum size of the page cache of the underlying database, in KiB
+    pub const PAGE_CACHE_SIZE_KIB: u32 = 2048;
+
+    /// Maximum number of elements inserted with a single statement
+    const INSERT_BATCH_SIZE: usize = 500;
+
+    fn build() -> StdResult<Self> {
+        Ok(Self {
+            inner_store: Arc::new(Self::create_connection()?),
+        })
+    }
+
+    fn create_connection() -> StdResult<sqlite::ConnectionThreadSafe> {
+        // An empty path creates a private temporary database on disk that is removed
+        // automatically when the connection is closed
+        let connection = sqlite::Connection::open_thread_safe("")?;
+        connection.execute(format!(
+            "pragma journal_mode = OFF;
+            pragma synchronous = OFF;
+            pragma temp_store = FILE;
+            pragma cache_size = -{};",
+            Self::PAGE_CACHE_SIZE_KIB
+        ))?;
+        connection.execute(
+            "create table merkle_tree (
+                position integer,
+                element blob,
+                primary key (position)
+            );
+            create table merkle_tree_leaf (
+                leaf blob,
+                position integer not null,
+                primary key (leaf)
+            );
+            create index merkle_tree_leaf_position on merkle_tree_leaf(position);",
+        )?;
+
+        Ok(connection)
+    }
+
+    fn get_element_at_position(&self, position: u64) -> StdResult<Option<Arc<MKTreeNode>>> {
+        let query = "select element from merkle_tree where position = ?";
+        let mut statement = self.inner_store.prepare(query)?;
+        statement.bind((1, position as i64))?;
+        let result = if let sqlite::State::Row = statement.next()? {
+            Some(Arc::new(MKTreeNode::new(
+                statement.read::<Bytes, _>("element")?,
+            )))
+        } else {
+            None
+        };
+
+        Ok(result)
+    }
+
+    fn insert_elements_from_position(
+        &self,
+        position: u64,
+        elements: Vec<Arc<MKTreeNode>>,
+    ) -> StdResult<()> {
+        for (chunk_index, chunk) in elements.chunks(Self::INSERT_BATCH_SIZE).enumerate() {
+            let chunk_position = position + (chunk_index * Self::INSERT_BATCH_SIZE) as u64;
+            let values_columns: Vec<&str> = repeat_n("(?, ?)", chunk.len()).collect();
+            let values: Vec<sqlite::Value> = chunk
+                .iter()
+                .enumerate()
+                .flat_map(|(i, elem)| {
+                    vec![
+                        sqlite::Value::Integer((chunk_position + i as u64) as i64),
+                        sqlite::Value::Binary((***elem).to_vec()),
+                    ]
+                })
+                .collect();
+            let query = format!(
+                "insert into merkle_tree(position, element) values {}",
+                values_columns.join(", ")
+            );
+            let mut statement = self.inner_store.prepare(query)?;
+            statement.bind::<&[(_, sqlite::Value)]>(
+                values
+                    .into_iter()
+                    .enumerate()
+                    .map(|(i, v)| (i + 1, v))
+                    .collect::<Vec<_>>()
+                    .as_slice(),
+            )?;
+            statement.next()?;
+        }
+
+        Ok(())
+    }
+
+    fn insert_leaf_position(
+        &self,
+        position: MKTreeLeafPosition,
+        leaf: &MKTreeNode,
+    ) -> StdResult<()> {
+        let query = "insert or replace into merkle_tree_leaf(leaf, position) values (?, ?)";
+        let mut statement = self.inner_store.prepare(query)?;
+        statement.bind::<&[(_, sqlite::Value)]>(&[
+            (1, sqlite::Value::Binary(leaf.to_vec())),
+            (2, sqlite::Value::Integer(position as i64)),
+        ])?;
+        statement.next()?;
+
+        Ok(())
+    }
+
+    fn get_leaf_position_by_leaf(
+        &self,
+        leaf: &MKTreeNode,
+    ) -> StdResult<Option<MKTreeLeafPosition>> {
+        let query = "select position from merkle_tree_leaf where leaf = ?";
+        let mut statement = self.inner_store.prepare(query)?;
+        statement.bind((1, leaf.to_vec().as_slice()))?;
+        let result = if let sqlite::State::Row = statement.next()? {
+            Some(statement.read::<i64, _>("position")? as MKTreeLeafPosition)
+        } else {
+            None
+        };
+
+        Ok(result)
+    }
+
+    fn count_leaves(&self) -> StdResult<usize> {
+        let mut statement = self
+            .inner_store
+            .prepare("select count(*) as total from merkle_tree_leaf")?;
+        statement.next()?;
+
+        Ok(statement.read::<i64, _>("total")? as usize)
+    }
+
+    fn get_all_leaves(&self) -> StdResult<Vec<MKTreeNode>> {
+        let mut statement = self
+            .inner_store
+            .prepare("select leaf from merkle_tree_leaf order by position")?;
+        let mut leaves = vec![];
+        while let sqlite::State::Row = statement.next()? {
+            leaves.push(MKTreeNode::new(statement.read::<Bytes, _>("leaf")?));
+        }
+
+        Ok(leaves)
+    }
+}
+
+impl MKTreeLeafIndexer for MKTreeStoreSqlite {
+    fn set_leaf_position(&self, pos: MKTreeLeafPosition, node: Arc<MKTreeNode>) -> StdResult<()> {
+        self.insert_leaf_position(pos, &node).with_context(|| {
+            format!("MKTreeStoreSqlite failed to set the position of the leaf at position {pos}")
+        })
+    }
+
+    fn get_leaf_position(&self, node: &MKTreeNode) -> StdResult<Option<MKTreeLeafPosition>> {
+        self.get_leaf_position_by_leaf(node)
+            .with_context(|| "MKTreeStoreSqlite failed to retrieve the position of a leaf")
+    }
+
+    fn total_leaves(&self) -> StdResult<usize> {
+        self.count_leaves()
+            .with_context(|| "MKTreeStoreSqlite failed to count the leaves")
+    }
+
+    fn leaves(&self) -> StdResult<Vec<MKTreeNode>> {
+        self.get_all_leaves()
+            .with_context(|| "MKTreeStoreSqlite failed to retrieve the leaves")
+    }
+}
+
+impl MKTreeStorer for MKTreeStoreSqlite {
+    fn build() -> StdResult<Self> {
+        Self::build()
+    }
+
+    fn get_elem(&self, pos: u64) -> StdResult<Option<Arc<MKTreeNode>>> {
+        self.get_element_at_position(pos).with_context(|| {
+            format!("MKTreeStoreSqlite failed to retrieve element at position {pos}")
+        })
+    }
+
+    fn append(&self, pos: u64, elems: Vec<Arc<MKTreeNode>>) -> StdResult<()> {
+        self.insert_elements_from_position(pos, elems)
+            .with_context(|| {
+                format!("MKTreeStoreSqlite failed to insert elements from position {pos}")
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::crypto_helper::{MKMap, MKMapNode, MKTree, MKTreeStoreInMemory};
+    use crate::entities::BlockRange;
+
+    use super::*;
+
+    fn generate_leaves(total_leaves: usize) -> Vec<MKTreeNode> {
+        (0..total_leaves)
+            .map(|i| format!("test-{i}").into())
+            .collect()
+    }
+
+    #[test]
+    fn test_golden_merkle_root() {
+        let leaves = vec!["golden-1", "golden-2", "golden-3", "golden-4", "golden-5"];
+        let mktree =
+            MKTree::<MKTreeStoreSqlite>::new(&leaves).expect("MKTree creation should not fail");
+        let mkroot = mktree
+            .compute_root()
+            .expect("MKRoot generation should not fail");
+
+        assert_eq!(
+            "3bbced153528697ecde7345a22e50115306478353619411523e804f2323fd921",
+            mkroot.to_hex()
+        );
+    }
+
+    #[test]
+    fn test_should_compute_same_root_as_in_memory_store() {
+        let leaves = generate_leaves(1_234);
+        let mktree_sqlite = MKTree::<MKTreeStoreSqlite>::new(&leaves).unwrap();
+        let mktree_in_memory = MKTree::<MKTreeStoreInMemory>::new(&leaves).unwrap();
+
+        assert_eq!(
+            mktree_in_memory.compute_root().unwrap(),
+            mktree_sqlite.compute_root().unwrap()
+        );
+    }
+
+    #[test]
+    fn test_should_index_leaves() {
+        let leaves = generate_leaves(10);
+        let mut mktree = MKTree::<MKTreeStoreSqlite>::new(&leaves[0..5]).unwrap();
+        mktree.append(&leaves[5..]).unwrap();
+
+        assert_eq!(10, mktree.total_leaves().unwrap());
+        assert_eq!(leaves, mktree.leaves().unwrap());
+        assert!(mktree.contains(&leaves[7]).unwrap());
+        assert!(!mktree.contains(&"not-a-leaf".into()).unwrap());
+    }
+
+    #[test]
+    fn test_should_insert_more_elements_than_a_single_batch() {
+        let store = MKTreeStoreSqlite::build().unwrap();
+        let elements: Vec<Arc<MKTreeNode>> =
+            generate_leaves(MKTreeStoreSqlite::INSERT_BATCH_SIZE * 2 + 1)
+                .into_iter()
+                .map(Arc::new)
+                .collect();
+
+        store.append(10, elements.clone()).unwrap();
+
+        assert_eq!(None, store.get_elem(9).unwrap());
+        assert_eq!(Some(elements[0].clone()), store.get_elem(10).unwrap());
+        assert_eq!(
+            Some(elements[MKTreeStoreSqlite::INSERT_BATCH_SIZE].clone()),
+            store
+                .get_elem(10 + MKTreeStoreSqlite::INSERT_BATCH_SIZE as u64)
+                .unwrap()
+        );
+        assert_eq!(
+            elements.last().cloned(),
+            store.get_elem(10 + elements.len() as u64 - 1).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_should_compute_and_verify_proof() {
+        let leaves = generate_leaves(100);
+        let mktree = MKTree::<MKTreeStoreSqlite>::new(&leaves).unwrap();
+        let leaves_to_verify = &[leaves[1].to_owned(), leaves[42].to_owned()];
+
+        let proof = mktree.compute_proof(leaves_to_verify).unwrap();
+
+        proof.verify().expect("The MKProof should be valid");
+        assert_eq!(mktree.compute_root().unwrap(), *proof.root());
+    }
+
+    #[test]
+    fn test_should_compute_and_verify_merkle_map_proof() {
+        let entries: Vec<(BlockRange, MKMapNode<BlockRange, MKTreeStoreSqlite>)> = (0..5)
+            .map(|i| {
+                let leaves: Vec<MKTreeNode> = (0..10)
+                    .map(|j| format!("block-range-{i}-test-{j}").into())
+                    .collect();
+                let mktree = MKTree::<MKTreeStoreSqlite>::new(&leaves).unwrap();
+                (
+                    BlockRange::new(i as u64 * 15, (i as u64 + 1) * 15),
+                    mktree.into(),
+                )
+            })
+            .collect();
+        let mkmap = MKMap::<_, _, MKTreeStoreSqlite>::new(&entries).unwrap();
+
+        let proof = mkmap.compute_proof(&["block-range-3-test-7"]).unwrap();
+
+        proof.verify().expect("The MKMapProof should be valid");
+    }
+}
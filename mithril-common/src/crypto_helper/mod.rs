@@ -9,6 +9,12 @@ mod merkle_map;
 mod merkle_tree;
 mod types;
 
+cfg_sqlite! {
+    mod merkle_tree_store_sqlite;
+
+    pub use merkle_tree_store_sqlite::MKTreeStoreSqlite;
+}
+
 cfg_test_tools! {
     pub mod tests_setup;
 }
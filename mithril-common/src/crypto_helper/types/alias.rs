@@ -4,8 +4,14 @@ use crate::crypto_helper::cardano::{
 };
 
 use mithril_stm::{
+    aggregation_report::{StmAggregationReport, StmSignatureStatus},
+    analysis::{ParametersAnalysis, SecurityTarget},
+    encryption::StmEncryptionKey,
     key_reg::ClosedKeyReg,
-    stm::{Index, Stake, StmClerk, StmParameters, StmSigner},
+    simulation::{QuorumSimulationReport, QuorumSimulator, SimulatedParty},
+    stm::{
+        Index, Stake, StmClerk, StmIncrementalAggregator, StmParameters, StmSigner, StmVerifiedSig,
+    },
     AggregationError,
 };
 
@@ -29,6 +35,21 @@ pub type ProtocolStakeDistribution = Vec<(ProtocolPartyId, ProtocolStake)>;
 /// Alias of [MithrilStm::StmParameters](struct@mithril_stm::stm::StmParameters).
 pub type ProtocolParameters = StmParameters;
 
+/// Alias of [MithrilStm::ParametersAnalysis](struct@mithril_stm::analysis::ParametersAnalysis).
+pub type ProtocolParametersAnalysis = ParametersAnalysis;
+
+/// Alias of [MithrilStm::SecurityTarget](struct@mithril_stm::analysis::SecurityTarget).
+pub type ProtocolSecurityTarget = SecurityTarget;
+
+/// Alias of [MithrilStm::QuorumSimulator](struct@mithril_stm::simulation::QuorumSimulator).
+pub type ProtocolQuorumSimulator = QuorumSimulator<D>;
+
+/// Alias of [MithrilStm::SimulatedParty](struct@mithril_stm::simulation::SimulatedParty).
+pub type ProtocolSimulatedParty = SimulatedParty;
+
+/// Alias of [MithrilStm::QuorumSimulationReport](struct@mithril_stm::simulation::QuorumSimulationReport).
+pub type ProtocolQuorumSimulationReport = QuorumSimulationReport;
+
 /// Alias of [MithrilStm::Index](type@mithril_stm::stm::Index).
 pub type ProtocolLotteryIndex = Index;
 
@@ -38,9 +59,24 @@ pub type ProtocolSigner = StmSigner<D>;
 /// Alias of a wrapper of [MithrilStm:StmInitializer](struct@mithril_stm::stm::StmInitializer).
 pub type ProtocolInitializer = StmInitializerWrapper;
 
+/// Alias of [MithrilStm:StmEncryptionKey](enum@mithril_stm::encryption::StmEncryptionKey).
+pub type ProtocolInitializerEncryptionKey = StmEncryptionKey;
+
 /// Alias of [MithrilStm:StmClerk](struct@mithril_stm::stm::StmClerk).
 pub type ProtocolClerk = StmClerk<D>;
 
+/// Alias of [MithrilStm:StmIncrementalAggregator](struct@mithril_stm::stm::StmIncrementalAggregator).
+pub type ProtocolIncrementalAggregator = StmIncrementalAggregator<D>;
+
+/// Alias of [MithrilStm:StmVerifiedSig](struct@mithril_stm::stm::StmVerifiedSig).
+pub type ProtocolVerifiedSingleSignature = StmVerifiedSig;
+
+/// Alias of [MithrilStm:StmAggregationReport](struct@mithril_stm::aggregation_report::StmAggregationReport).
+pub type ProtocolAggregationReport = StmAggregationReport;
+
+/// Alias of [MithrilStm:StmSignatureStatus](enum@mithril_stm::aggregation_report::StmSignatureStatus).
+pub type ProtocolSignatureStatus = StmSignatureStatus;
+
 /// Alias of a wrapper of [MithrilStm:KeyReg](struct@mithril_stm::key_reg::KeyReg).
 pub type ProtocolKeyRegistration = KeyRegWrapper;
 
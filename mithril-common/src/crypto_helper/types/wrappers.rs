@@ -1,7 +1,8 @@
 use anyhow::Context;
 use hex::{FromHex, ToHex};
 use kes_summed_ed25519::kes::Sum6KesSig;
-use mithril_stm::stm::{StmAggrSig, StmAggrVerificationKey, StmSig, StmVerificationKeyPoP};
+use mithril_stm::aggregate_proof::AggregateSignature;
+use mithril_stm::stm::{StmAggrVerificationKey, StmSig, StmVerificationKeyPoP};
 
 use crate::crypto_helper::{MKMapProof, MKProof, OpCert, ProtocolKey, ProtocolKeyCodec, D};
 use crate::entities::BlockRange;
@@ -18,8 +19,9 @@ pub type ProtocolSignerVerificationKeySignature = ProtocolKey<Sum6KesSig>;
 /// Wrapper of [MithrilStm:StmSig](type@StmSig) to add serialization utilities.
 pub type ProtocolSingleSignature = ProtocolKey<StmSig>;
 
-/// Wrapper of [MithrilStm:StmAggrSig](struct@StmAggrSig) to add serialization utilities.
-pub type ProtocolMultiSignature = ProtocolKey<StmAggrSig<D>>;
+/// Wrapper of [MithrilStm:AggregateSignature](enum@AggregateSignature) to add serialization
+/// utilities, it can hold an aggregate signature of any of the supported proof systems.
+pub type ProtocolMultiSignature = ProtocolKey<AggregateSignature<D>>;
 
 /// Wrapper of [OpCert] to add serialization utilities.
 pub type ProtocolOpCert = ProtocolKey<OpCert>;
@@ -76,7 +78,7 @@ impl ProtocolKeyCodec<ed25519_dalek::Signature> for ed25519_dalek::Signature {
 }
 
 impl_codec_and_type_conversions_for_protocol_key!(
-    json_hex_codec => StmVerificationKeyPoP, Sum6KesSig, StmSig, StmAggrSig<D>, OpCert,
+    json_hex_codec => StmVerificationKeyPoP, Sum6KesSig, StmSig, AggregateSignature<D>, OpCert,
         ed25519_dalek::VerifyingKey, ed25519_dalek::SigningKey, StmAggrVerificationKey<D>,
         MKProof
 );
@@ -1,6 +1,10 @@
 use crate::test_utils::TempDir;
 use crate::{
-    digesters::{ImmutableFile, IMMUTABLE_DIR, LEDGER_DIR, VOLATILE_DIR},
+    digesters::{
+        ImmutableFile, LedgerStateSnapshotLayout, IMMUTABLE_DIR, LEDGER_DIR,
+        UTXO_HD_IN_MEMORY_TABLES_FILE, UTXO_HD_LMDB_TABLES_FILE, UTXO_HD_STATE_FILE,
+        UTXO_HD_TABLES_DIR, VOLATILE_DIR,
+    },
     entities::ImmutableFileNumber,
 };
 use std::{
@@ -104,6 +108,7 @@ pub struct DummyCardanoDbBuilder {
     immutable_file_size: Option<u64>,
     ledger_files_to_write: Vec<String>,
     ledger_file_size: Option<u64>,
+    utxo_hd_ledger_snapshots_to_write: Vec<(String, LedgerStateSnapshotLayout)>,
     volatile_files_to_write: Vec<String>,
     volatile_file_size: Option<u64>,
 }
@@ -120,6 +125,7 @@ impl DummyCardanoDbBuilder {
             immutable_file_size: None,
             ledger_files_to_write: vec![],
             ledger_file_size: None,
+            utxo_hd_ledger_snapshots_to_write: vec![],
             volatile_files_to_write: vec![],
             volatile_file_size: None,
         }
@@ -144,6 +150,25 @@ impl DummyCardanoDbBuilder {
         self
     }
 
+    /// Set UTxO-HD ledger snapshot directories, using the given layout, to write to the db in the
+    /// 'ledger' subdirectory.
+    ///
+    /// The size of the files of the snapshots follows the size set with
+    /// [set_ledger_file_size][Self::set_ledger_file_size].
+    pub fn with_utxo_hd_ledger_snapshots(
+        &mut self,
+        snapshots: &[&str],
+        layout: LedgerStateSnapshotLayout,
+    ) -> &mut Self {
+        assert!(
+            layout.is_utxo_hd(),
+            "'layout' must be a UTxO-HD ledger snapshot layout"
+        );
+        self.utxo_hd_ledger_snapshots_to_write
+            .extend(snapshots.iter().map(|name| (name.to_string(), layout)));
+        self
+    }
+
     /// Set the size of all ledger files written by [build][Self::build] to the given `file_size` in bytes.
     pub fn set_ledger_file_size(&mut self, file_size: u64) -> &mut Self {
         self.ledger_file_size = Some(file_size);
@@ -217,6 +242,22 @@ impl DummyCardanoDbBuilder {
             ledger_files.push(ledger_file_path);
         }
 
+        for (snapshot_name, layout) in &self.utxo_hd_ledger_snapshots_to_write {
+            let snapshot_dir = dir.join(LEDGER_DIR).join(snapshot_name);
+            let tables_dir = snapshot_dir.join(UTXO_HD_TABLES_DIR);
+            std::fs::create_dir_all(&tables_dir).unwrap();
+            write_dummy_file(self.ledger_file_size, &snapshot_dir, UTXO_HD_STATE_FILE);
+            write_dummy_file(self.ledger_file_size, &snapshot_dir, "meta");
+            let tables_files = match layout {
+                LedgerStateSnapshotLayout::Lmdb => vec![UTXO_HD_LMDB_TABLES_FILE, "lock.mdb"],
+                _ => vec![UTXO_HD_IN_MEMORY_TABLES_FILE],
+            };
+            for tables_file in tables_files {
+                write_dummy_file(self.ledger_file_size, &tables_dir, tables_file);
+            }
+            ledger_files.push(snapshot_dir);
+        }
+
         for filename in &self.volatile_files_to_write {
             write_dummy_file(self.volatile_file_size, &dir.join(VOLATILE_DIR), filename);
         }
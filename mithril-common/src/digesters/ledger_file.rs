@@ -1,5 +1,6 @@
 use std::{
     cmp::Ordering,
+    fmt::{Display, Formatter},
     path::{Path, PathBuf},
 };
 use thiserror::Error;
@@ -18,7 +19,54 @@ fn find_ledger_dir(path_to_walk: &Path) -> Option<PathBuf> {
         .map(|e| e.into_path())
 }
 
+/// Name of the file holding the serialized ledger state in a UTxO-HD ledger snapshot directory
+pub const UTXO_HD_STATE_FILE: &str = "state";
+
+/// Name of the directory holding the ledger tables in a UTxO-HD ledger snapshot directory
+pub const UTXO_HD_TABLES_DIR: &str = "tables";
+
+/// Name of the ledger tables file of a UTxO-HD ledger snapshot using the in-memory backend
+pub const UTXO_HD_IN_MEMORY_TABLES_FILE: &str = "tvar";
+
+/// Name of the ledger tables database file of a UTxO-HD ledger snapshot using the LMDB backend
+pub const UTXO_HD_LMDB_TABLES_FILE: &str = "data.mdb";
+
+/// Layout of a ledger state snapshot stored in the 'ledger' directory of a Cardano node database
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum LedgerStateSnapshotLayout {
+    /// Single file named after the slot number of the snapshot (before UTxO-HD)
+    Legacy,
+
+    /// UTxO-HD directory named after the slot number of the snapshot, with its ledger tables
+    /// serialized from the in-memory backend
+    InMemory,
+
+    /// UTxO-HD directory named after the slot number of the snapshot, with its ledger tables
+    /// stored in a LMDB database
+    Lmdb,
+}
+
+impl LedgerStateSnapshotLayout {
+    /// Returns true if the layout is one of the UTxO-HD directory layouts
+    pub fn is_utxo_hd(&self) -> bool {
+        matches!(self, Self::InMemory | Self::Lmdb)
+    }
+}
+
+impl Display for LedgerStateSnapshotLayout {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Legacy => write!(f, "legacy"),
+            Self::InMemory => write!(f, "in-memory"),
+            Self::Lmdb => write!(f, "lmdb"),
+        }
+    }
+}
+
 /// Represent an ledger file in a Cardano node database directory
+///
+/// Depending on its [layout][LedgerStateSnapshotLayout], a ledger file is either a single file or
+/// a UTxO-HD snapshot directory.
 #[derive(Debug, PartialEq, Eq, Clone)]
 pub struct LedgerFile {
     /// The path to the ledger file
@@ -29,6 +77,8 @@ pub struct LedgerFile {
 
     /// The filename
     pub filename: String,
+
+    layout: LedgerStateSnapshotLayout,
 }
 
 /// [LedgerFile::list_all_in_dir] related errors.
@@ -46,22 +96,77 @@ impl LedgerFile {
             path,
             slot_number,
             filename: filename.into(),
+            layout: LedgerStateSnapshotLayout::Legacy,
         }
     }
 
+    /// Set the layout of the ledger state snapshot
+    pub fn with_layout(mut self, layout: LedgerStateSnapshotLayout) -> Self {
+        self.layout = layout;
+        self
+    }
+
+    /// The layout of the ledger state snapshot
+    pub fn layout(&self) -> LedgerStateSnapshotLayout {
+        self.layout
+    }
+
     /// Convert a path to a LedgerFile if it satisfies the LedgerFile constraints.
     ///
-    /// The constraints are: the path must be a file, the filename should only contain a number (no
-    /// extension).
+    /// The constraints are: the filename should only contain a number (no extension), and if the
+    /// path is a directory it must contain a UTxO-HD ledger snapshot (a `state` file and a `tables`
+    /// directory with the tables of either the in-memory or the LMDB backend).
     pub fn from_path(path: &Path) -> Option<LedgerFile> {
-        path.file_name()
-            .map(|name| name.to_string_lossy())
-            .and_then(|filename| {
-                filename
-                    .parse::<u64>()
-                    .map(|number| Self::new(path.to_path_buf(), SlotNumber(number), filename))
-                    .ok()
-            })
+        let filename = path.file_name()?.to_string_lossy();
+        let slot_number = filename.parse::<u64>().ok()?;
+        let layout = if path.is_dir() {
+            Self::detect_utxo_hd_layout(path)?
+        } else {
+            LedgerStateSnapshotLayout::Legacy
+        };
+
+        Some(Self::new(path.to_path_buf(), SlotNumber(slot_number), filename).with_layout(layout))
+    }
+
+    fn detect_utxo_hd_layout(snapshot_dir: &Path) -> Option<LedgerStateSnapshotLayout> {
+        if !snapshot_dir.join(UTXO_HD_STATE_FILE).is_file() {
+            return None;
+        }
+
+        let tables_dir = snapshot_dir.join(UTXO_HD_TABLES_DIR);
+        if tables_dir.join(UTXO_HD_LMDB_TABLES_FILE).is_file() {
+            Some(LedgerStateSnapshotLayout::Lmdb)
+        } else if tables_dir.join(UTXO_HD_IN_MEMORY_TABLES_FILE).is_file() {
+            Some(LedgerStateSnapshotLayout::InMemory)
+        } else {
+            None
+        }
+    }
+
+    /// List the paths of all the files of the ledger state snapshot, relative to the 'ledger'
+    /// directory.
+    ///
+    /// For a legacy snapshot this is only its filename, for a UTxO-HD snapshot this is every file
+    /// of its directory.
+    pub fn list_files_relative_paths(&self) -> Vec<PathBuf> {
+        match self.layout {
+            LedgerStateSnapshotLayout::Legacy => vec![PathBuf::from(&self.filename)],
+            LedgerStateSnapshotLayout::InMemory | LedgerStateSnapshotLayout::Lmdb => {
+                WalkDir::new(&self.path)
+                    .sort_by_file_name()
+                    .into_iter()
+                    .filter_map(|entry| entry.ok())
+                    .filter(|entry| entry.file_type().is_file())
+                    .filter_map(|entry| {
+                        entry
+                            .path()
+                            .strip_prefix(&self.path)
+                            .ok()
+                            .map(|path| PathBuf::from(&self.filename).join(path))
+                    })
+                    .collect()
+            }
+        }
     }
 
     /// List all [`LedgerFile`] in a given directory.
@@ -75,7 +180,6 @@ impl LedgerFile {
             .min_depth(1)
             .max_depth(1)
             .into_iter()
-            .filter_entry(|e| e.file_type().is_file())
             .filter_map(|file| file.ok())
         {
             if let Some(ledger_file) = LedgerFile::from_path(path.path()) {
@@ -110,7 +214,7 @@ mod tests {
 
     use crate::test_utils::TempDir;
 
-    use super::LedgerFile;
+    use super::*;
 
     fn get_test_dir(subdir_name: &str) -> PathBuf {
         TempDir::create("ledger_file", subdir_name)
@@ -124,6 +228,27 @@ mod tests {
         }
     }
 
+    fn create_fake_utxo_hd_snapshot(
+        parent_dir: &Path,
+        snapshot_name: &str,
+        layout: LedgerStateSnapshotLayout,
+    ) {
+        let snapshot_dir = parent_dir.join(snapshot_name);
+        std::fs::create_dir_all(snapshot_dir.join(UTXO_HD_TABLES_DIR)).unwrap();
+        create_fake_files(&snapshot_dir, &[UTXO_HD_STATE_FILE, "meta"]);
+        match layout {
+            LedgerStateSnapshotLayout::InMemory => create_fake_files(
+                &snapshot_dir.join(UTXO_HD_TABLES_DIR),
+                &[UTXO_HD_IN_MEMORY_TABLES_FILE],
+            ),
+            LedgerStateSnapshotLayout::Lmdb => create_fake_files(
+                &snapshot_dir.join(UTXO_HD_TABLES_DIR),
+                &[UTXO_HD_LMDB_TABLES_FILE, "lock.mdb"],
+            ),
+            LedgerStateSnapshotLayout::Legacy => {}
+        }
+    }
+
     fn extract_filenames(ledger_files: &[LedgerFile]) -> Vec<String> {
         ledger_files
             .iter()
@@ -175,4 +300,60 @@ mod tests {
 
         assert_eq!(vec!["123", "124"], extract_filenames(&ledger_files));
     }
+
+    #[test]
+    fn list_all_ledger_file_should_detect_utxo_hd_snapshot_directories() {
+        let target_dir =
+            get_test_dir("list_all_ledger_file_should_detect_utxo_hd_snapshot_directories/ledger");
+        create_fake_files(&target_dir, &["123"]);
+        create_fake_utxo_hd_snapshot(&target_dir, "124", LedgerStateSnapshotLayout::InMemory);
+        create_fake_utxo_hd_snapshot(&target_dir, "125", LedgerStateSnapshotLayout::Lmdb);
+        // A directory without tables is not a ledger snapshot
+        create_fake_utxo_hd_snapshot(&target_dir, "126", LedgerStateSnapshotLayout::Legacy);
+        std::fs::create_dir(target_dir.join("127")).unwrap();
+
+        let ledger_files = LedgerFile::list_all_in_dir(target_dir.parent().unwrap())
+            .expect("LedgerFile::list_all_in_dir Failed");
+
+        assert_eq!(
+            vec![
+                ("123".to_string(), LedgerStateSnapshotLayout::Legacy),
+                ("124".to_string(), LedgerStateSnapshotLayout::InMemory),
+                ("125".to_string(), LedgerStateSnapshotLayout::Lmdb),
+            ],
+            ledger_files
+                .into_iter()
+                .map(|f| (f.filename, f.layout))
+                .collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn list_files_relative_paths_of_a_legacy_snapshot() {
+        let target_dir = get_test_dir("list_files_relative_paths_of_a_legacy_snapshot/ledger");
+        create_fake_files(&target_dir, &["123"]);
+        let ledger_file = LedgerFile::from_path(&target_dir.join("123")).unwrap();
+
+        assert_eq!(
+            vec![PathBuf::from("123")],
+            ledger_file.list_files_relative_paths()
+        );
+    }
+
+    #[test]
+    fn list_files_relative_paths_of_a_utxo_hd_snapshot() {
+        let target_dir = get_test_dir("list_files_relative_paths_of_a_utxo_hd_snapshot/ledger");
+        create_fake_utxo_hd_snapshot(&target_dir, "124", LedgerStateSnapshotLayout::Lmdb);
+        let ledger_file = LedgerFile::from_path(&target_dir.join("124")).unwrap();
+
+        assert_eq!(
+            vec![
+                PathBuf::from("124/meta"),
+                PathBuf::from("124/state"),
+                PathBuf::from("124/tables/data.mdb"),
+                PathBuf::from("124/tables/lock.mdb"),
+            ],
+            ledger_file.list_files_relative_paths()
+        );
+    }
 }
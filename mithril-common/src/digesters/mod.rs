@@ -17,7 +17,10 @@ pub use immutable_file_observer::{
     DumbImmutableFileObserver, ImmutableFileObserver, ImmutableFileObserverError,
     ImmutableFileSystemObserver,
 };
-pub use ledger_file::{LedgerFile, LedgerFileListingError};
+pub use ledger_file::{
+    LedgerFile, LedgerFileListingError, LedgerStateSnapshotLayout, UTXO_HD_IN_MEMORY_TABLES_FILE,
+    UTXO_HD_LMDB_TABLES_FILE, UTXO_HD_STATE_FILE, UTXO_HD_TABLES_DIR,
+};
 
 pub use dumb_immutable_digester::DumbImmutableDigester;
 
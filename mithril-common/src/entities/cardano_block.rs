@@ -0,0 +1,100 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    crypto_helper::MKTreeNode,
+    entities::{BlockHash, BlockNumber, SlotNumber},
+};
+
+#[derive(Debug, PartialEq, Eq, Clone, Serialize, Deserialize)]
+/// Cardano block representation
+pub struct CardanoBlock {
+    /// Hash of the block
+    pub block_hash: BlockHash,
+
+    /// Number of the block
+    pub block_number: BlockNumber,
+
+    /// Slot number of the block
+    pub slot_number: SlotNumber,
+}
+
+impl CardanoBlock {
+    /// CardanoBlock factory
+    pub fn new<T: Into<BlockHash>>(
+        block_hash: T,
+        block_number: BlockNumber,
+        slot_number: SlotNumber,
+    ) -> Self {
+        Self {
+            block_hash: block_hash.into(),
+            block_number,
+            slot_number,
+        }
+    }
+}
+
+impl From<CardanoBlock> for MKTreeNode {
+    fn from(other: CardanoBlock) -> Self {
+        (&other).into()
+    }
+}
+
+impl From<&CardanoBlock> for MKTreeNode {
+    fn from(other: &CardanoBlock) -> Self {
+        // The block number and slot number are part of the leaf so that a proof binds the block
+        // hash to its position in the chain.
+        let mut leaf = other.block_hash.as_bytes().to_vec();
+        leaf.extend_from_slice(&other.block_number.to_be_bytes());
+        leaf.extend_from_slice(&other.slot_number.to_be_bytes());
+
+        MKTreeNode::new(leaf)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_convert_cardano_block_to_merkle_tree_node() {
+        let block = CardanoBlock::new("block-hash-123", BlockNumber(10), SlotNumber(4));
+
+        let computed_mktree_node: MKTreeNode = block.clone().into();
+
+        assert_eq!(
+            MKTreeNode::new(
+                [
+                    "block-hash-123".as_bytes(),
+                    &10_u64.to_be_bytes(),
+                    &4_u64.to_be_bytes()
+                ]
+                .concat()
+            ),
+            computed_mktree_node
+        );
+        assert_ne!(
+            MKTreeNode::from(CardanoBlock::new(
+                "block-hash-456",
+                BlockNumber(10),
+                SlotNumber(4)
+            )),
+            computed_mktree_node
+        );
+        assert_ne!(
+            MKTreeNode::from(CardanoBlock::new(
+                "block-hash-123",
+                BlockNumber(11),
+                SlotNumber(4)
+            )),
+            computed_mktree_node
+        );
+        assert_ne!(
+            MKTreeNode::from(CardanoBlock::new(
+                "block-hash-123",
+                BlockNumber(10),
+                SlotNumber(5)
+            )),
+            computed_mktree_node
+        );
+    }
+}
@@ -0,0 +1,156 @@
+use crate::crypto_helper::{MKMapProof, ProtocolMkProof};
+use crate::entities::{BlockHash, CardanoBlock};
+use crate::StdResult;
+
+use super::BlockRange;
+
+cfg_test_tools! {
+    use crate::crypto_helper::{MKMap, MKTree, MKTreeNode, MKMapNode, MKTreeStorer, MKTreeStoreInMemory};
+    use crate::entities::{BlockNumber, SlotNumber};
+    use std::collections::HashMap;
+}
+
+/// A cryptographic proof that a set of Cardano blocks is included in the global Cardano blocks set
+#[derive(Clone, Debug, PartialEq)]
+pub struct CardanoBlocksSetProof {
+    /// Certified blocks
+    pub(crate) blocks: Vec<CardanoBlock>,
+
+    /// Proof of the blocks
+    pub(crate) blocks_proof: ProtocolMkProof,
+}
+
+impl CardanoBlocksSetProof {
+    /// CardanoBlocksSetProof factory
+    pub fn new<T: Into<MKMapProof<BlockRange>>>(
+        blocks: Vec<CardanoBlock>,
+        blocks_proof: T,
+    ) -> Self {
+        Self {
+            blocks,
+            blocks_proof: ProtocolMkProof::new(blocks_proof.into()),
+        }
+    }
+
+    /// Return the hex encoded merkle root of this proof
+    pub fn merkle_root(&self) -> String {
+        self.blocks_proof.compute_root().to_hex()
+    }
+
+    /// Get the blocks certified by this proof
+    pub fn blocks(&self) -> &[CardanoBlock] {
+        &self.blocks
+    }
+
+    /// Get the hashes of the blocks certified by this proof
+    pub fn blocks_hashes(&self) -> Vec<BlockHash> {
+        self.blocks.iter().map(|b| b.block_hash.clone()).collect()
+    }
+
+    /// Verify that blocks set proof is valid
+    pub fn verify(&self) -> StdResult<()> {
+        self.blocks_proof.verify()?;
+        for block in &self.blocks {
+            self.blocks_proof.contains(&block.into())?;
+        }
+
+        Ok(())
+    }
+
+    cfg_test_tools! {
+        /// Retrieve a dummy proof (for test only)
+        pub fn dummy() -> Self {
+            let blocks = vec![
+                CardanoBlock::new("block-hash-1", BlockNumber(0), SlotNumber(10)),
+                CardanoBlock::new("block-hash-2", BlockNumber(1), SlotNumber(11)),
+                CardanoBlock::new("block-hash-3", BlockNumber(10), SlotNumber(20)),
+                CardanoBlock::new("block-hash-4", BlockNumber(20), SlotNumber(30)),
+                CardanoBlock::new("block-hash-5", BlockNumber(22), SlotNumber(32)),
+            ];
+
+            Self::from_blocks::<MKTreeStoreInMemory>(&blocks).unwrap()
+        }
+
+        /// Helper to create a proof from a list of blocks
+        pub fn from_blocks<S: MKTreeStorer>(blocks: &[CardanoBlock]) -> StdResult<Self> {
+            let mut blocks_by_block_ranges: HashMap<BlockRange, Vec<CardanoBlock>> =
+                HashMap::new();
+            for block in blocks {
+                let block_range = BlockRange::from_block_number(block.block_number);
+                blocks_by_block_ranges
+                    .entry(block_range)
+                    .or_default()
+                    .push(block.to_owned());
+            }
+            let mk_map = MKMap::<_, _, MKTreeStoreInMemory>::new(
+                blocks_by_block_ranges
+                    .into_iter()
+                    .try_fold(
+                        vec![],
+                        |mut acc, (block_range, blocks)| -> StdResult<Vec<(_, MKMapNode<_,S>)>> {
+                            acc.push((block_range, MKTree::<S>::new(&blocks)?.into()));
+                            Ok(acc)
+                        },
+                    )?
+                    .as_slice(),
+            )?;
+            let mk_leaves: Vec<MKTreeNode> = blocks.iter().map(|b| b.into()).collect();
+            let mk_proof = mk_map.compute_proof(&mk_leaves)?;
+            Ok(Self::new(blocks.to_vec(), mk_proof))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn blocks_dataset() -> Vec<CardanoBlock> {
+        vec![
+            CardanoBlock::new("block-hash-1", BlockNumber(0), SlotNumber(10)),
+            CardanoBlock::new("block-hash-2", BlockNumber(1), SlotNumber(11)),
+            CardanoBlock::new("block-hash-3", BlockNumber(10), SlotNumber(20)),
+            CardanoBlock::new("block-hash-4", BlockNumber(20), SlotNumber(30)),
+        ]
+    }
+
+    #[test]
+    fn should_verify_where_all_blocks_are_contained_in_the_proof() {
+        let proof =
+            CardanoBlocksSetProof::from_blocks::<MKTreeStoreInMemory>(&blocks_dataset()).unwrap();
+
+        proof.verify().expect("The proof should be valid");
+    }
+
+    #[test]
+    fn shouldnt_verify_where_at_least_one_block_is_not_contained_in_the_proof() {
+        let proof =
+            CardanoBlocksSetProof::from_blocks::<MKTreeStoreInMemory>(&blocks_dataset()).unwrap();
+        let mut blocks_tampered = proof.blocks().to_vec();
+        blocks_tampered.push(CardanoBlock::new(
+            "block-hash-123",
+            BlockNumber(1),
+            SlotNumber(11),
+        ));
+        let proof = CardanoBlocksSetProof {
+            blocks: blocks_tampered,
+            ..proof
+        };
+
+        proof.verify().expect_err("The proof should be invalid");
+    }
+
+    #[test]
+    fn shouldnt_verify_a_known_block_hash_with_a_different_position_in_the_chain() {
+        let proof =
+            CardanoBlocksSetProof::from_blocks::<MKTreeStoreInMemory>(&blocks_dataset()).unwrap();
+        let mut blocks_tampered = proof.blocks().to_vec();
+        blocks_tampered[1].block_number = BlockNumber(2);
+        let proof = CardanoBlocksSetProof {
+            blocks: blocks_tampered,
+            ..proof
+        };
+
+        proof.verify().expect_err("The proof should be invalid");
+    }
+}
@@ -0,0 +1,76 @@
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+use super::{Epoch, LedgerProtocolParameters};
+
+/// Cardano Protocol Parameters
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct CardanoProtocolParameters {
+    /// Unique hash of the Cardano Protocol Parameters
+    pub hash: String,
+
+    /// Epoch at which the Cardano Protocol Parameters are in effect
+    pub epoch: Epoch,
+
+    /// Protocol parameters of the Cardano ledger
+    pub protocol_parameters: LedgerProtocolParameters,
+}
+
+impl CardanoProtocolParameters {
+    /// Constructor
+    pub fn new(epoch: Epoch, protocol_parameters: LedgerProtocolParameters) -> Self {
+        let mut cardano_protocol_parameters = Self {
+            hash: "".to_string(),
+            epoch,
+            protocol_parameters,
+        };
+        cardano_protocol_parameters.hash = cardano_protocol_parameters.compute_hash();
+
+        cardano_protocol_parameters
+    }
+
+    /// Cardano protocol parameters hash computation
+    fn compute_hash(&self) -> String {
+        let mut hasher = Sha256::new();
+        hasher.update(self.epoch.to_be_bytes());
+        hasher.update(self.protocol_parameters.compute_hash().as_bytes());
+
+        hex::encode(hasher.finalize())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn compute_hash_returns_same_hash_with_same_cardano_protocol_parameters() {
+        assert_eq!(
+            CardanoProtocolParameters::new(Epoch(1), LedgerProtocolParameters::dummy()).hash,
+            CardanoProtocolParameters::new(Epoch(1), LedgerProtocolParameters::dummy()).hash
+        );
+    }
+
+    #[test]
+    fn compute_hash_returns_different_hash_with_different_epoch() {
+        assert_ne!(
+            CardanoProtocolParameters::new(Epoch(1), LedgerProtocolParameters::dummy()).hash,
+            CardanoProtocolParameters::new(Epoch(2), LedgerProtocolParameters::dummy()).hash
+        );
+    }
+
+    #[test]
+    fn compute_hash_returns_different_hash_with_different_protocol_parameters() {
+        assert_ne!(
+            CardanoProtocolParameters::new(Epoch(1), LedgerProtocolParameters::dummy()).hash,
+            CardanoProtocolParameters::new(
+                Epoch(1),
+                LedgerProtocolParameters {
+                    min_pool_cost: 340000000,
+                    ..LedgerProtocolParameters::dummy()
+                }
+            )
+            .hash
+        );
+    }
+}
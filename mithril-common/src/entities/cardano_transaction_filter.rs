@@ -0,0 +1,108 @@
+use std::fmt::{Display, Formatter};
+
+use serde::{Deserialize, Serialize};
+
+use crate::entities::{BlockNumber, SlotNumber, TransactionHash};
+
+/// Label of a transaction metadata entry.
+pub type MetadataLabel = u64;
+
+/// Criterion used to search the Cardano transactions by their content.
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case", tag = "type", content = "value")]
+pub enum CardanoTransactionFilter {
+    /// Transactions that have at least one output at the given address (bech32 for Shelley
+    /// addresses, base58 for Byron addresses)
+    Address(String),
+
+    /// Transactions that have a metadata entry under the given label
+    MetadataLabel(MetadataLabel),
+}
+
+impl CardanoTransactionFilter {
+    /// Name of the kind of the filter
+    pub fn kind(&self) -> &'static str {
+        match self {
+            CardanoTransactionFilter::Address(_) => "address",
+            CardanoTransactionFilter::MetadataLabel(_) => "metadata_label",
+        }
+    }
+
+    /// Value of the filter as a string
+    pub fn value(&self) -> String {
+        match self {
+            CardanoTransactionFilter::Address(address) => address.clone(),
+            CardanoTransactionFilter::MetadataLabel(label) => label.to_string(),
+        }
+    }
+}
+
+impl Display for CardanoTransactionFilter {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}={}", self.kind(), self.value())
+    }
+}
+
+/// Entry of the index of the Cardano transactions by [CardanoTransactionFilter].
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct CardanoTransactionIndexEntry {
+    /// Filter matched by the transaction
+    pub filter: CardanoTransactionFilter,
+
+    /// Hash of the matching transaction
+    pub transaction_hash: TransactionHash,
+
+    /// Block number of the matching transaction
+    pub block_number: BlockNumber,
+
+    /// Slot number of the matching transaction
+    pub slot_number: SlotNumber,
+}
+
+impl CardanoTransactionIndexEntry {
+    /// CardanoTransactionIndexEntry factory
+    pub fn new<T: Into<TransactionHash>>(
+        filter: CardanoTransactionFilter,
+        transaction_hash: T,
+        block_number: BlockNumber,
+        slot_number: SlotNumber,
+    ) -> Self {
+        Self {
+            filter,
+            transaction_hash: transaction_hash.into(),
+            block_number,
+            slot_number,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn display_filter_as_kind_and_value() {
+        assert_eq!(
+            "address=addr_test1vr80076l3x5uw6n94nwhgmv7ssgy6muzf47ugn6z0l92rhg2mgtu0",
+            CardanoTransactionFilter::Address(
+                "addr_test1vr80076l3x5uw6n94nwhgmv7ssgy6muzf47ugn6z0l92rhg2mgtu0".to_string()
+            )
+            .to_string()
+        );
+        assert_eq!(
+            "metadata_label=674",
+            CardanoTransactionFilter::MetadataLabel(674).to_string()
+        );
+    }
+
+    #[test]
+    fn serialize_filter_with_its_kind() {
+        let json = serde_json::to_string(&CardanoTransactionFilter::MetadataLabel(721)).unwrap();
+
+        assert_eq!(r#"{"type":"metadata_label","value":721}"#, json);
+        assert_eq!(
+            CardanoTransactionFilter::MetadataLabel(721),
+            serde_json::from_str(&json).unwrap()
+        );
+    }
+}
@@ -1,3 +1,5 @@
+use anyhow::Context;
+
 use crate::crypto_helper::{MKMapProof, ProtocolMkProof};
 use crate::entities::TransactionHash;
 use crate::StdResult;
@@ -42,6 +44,11 @@ impl CardanoTransactionsSetProof {
         &self.transactions_hashes
     }
 
+    /// Get the block ranges which transactions are proven by this proof
+    pub fn block_ranges(&self) -> Vec<BlockRange> {
+        self.transactions_proof.proven_keys()
+    }
+
     /// Verify that transactions set proof is valid
     pub fn verify(&self) -> StdResult<()> {
         self.transactions_proof.verify()?;
@@ -52,6 +59,19 @@ impl CardanoTransactionsSetProof {
         Ok(())
     }
 
+    /// Verify that the proof contains all the transactions of each of its block ranges
+    pub fn verify_block_ranges_completeness(&self) -> StdResult<()> {
+        for block_range in self.block_ranges() {
+            self.transactions_proof
+                .contains_all_leaves_of(&block_range)
+                .with_context(|| {
+                    format!("Transactions of block range {block_range:?} are not all proven")
+                })?;
+        }
+
+        Ok(())
+    }
+
     cfg_test_tools! {
         /// Retrieve a dummy proof (for test only)
         pub fn dummy() -> Self {
@@ -71,6 +91,16 @@ impl CardanoTransactionsSetProof {
         pub fn from_leaves<S: MKTreeStorer>(leaves: &[(BlockNumber, TransactionHash)]) -> StdResult<Self> {
             let transactions_hashes: Vec<TransactionHash> =
                 leaves.iter().map(|(_, t)| t.into()).collect();
+
+            Self::from_subset_of_leaves::<S>(leaves, &transactions_hashes)
+        }
+
+        /// Helper to create a proof of a subset of a list of leaves
+        pub fn from_subset_of_leaves<S: MKTreeStorer>(
+            leaves: &[(BlockNumber, TransactionHash)],
+            transactions_hashes_to_prove: &[TransactionHash],
+        ) -> StdResult<Self> {
+            let transactions_hashes = transactions_hashes_to_prove.to_vec();
             let mut transactions_by_block_ranges: HashMap<BlockRange, Vec<TransactionHash>> =
                 HashMap::new();
             for (block_number, transaction_hash) in leaves {
@@ -123,6 +153,49 @@ mod tests {
         proof.verify().expect("The proof should be valid");
     }
 
+    #[test]
+    fn should_verify_block_ranges_completeness_when_all_their_transactions_are_proven() {
+        let leaves = vec![
+            (BlockNumber(0), "tx-1".to_string()),
+            (BlockNumber(1), "tx-2".to_string()),
+            (BlockNumber(20), "tx-3".to_string()),
+            (BlockNumber(22), "tx-4".to_string()),
+        ];
+        let proof =
+            CardanoTransactionsSetProof::from_leaves::<MKTreeStoreInMemory>(&leaves).unwrap();
+
+        assert_eq!(
+            vec![
+                BlockRange::from_block_number(BlockNumber(0)),
+                BlockRange::from_block_number(BlockNumber(20)),
+            ],
+            proof.block_ranges()
+        );
+        proof
+            .verify_block_ranges_completeness()
+            .expect("All the transactions of the block ranges are proven");
+    }
+
+    #[test]
+    fn shouldnt_verify_block_ranges_completeness_when_a_transaction_is_missing() {
+        let leaves = vec![
+            (BlockNumber(0), "tx-1".to_string()),
+            (BlockNumber(1), "tx-2".to_string()),
+            (BlockNumber(20), "tx-3".to_string()),
+            (BlockNumber(22), "tx-4".to_string()),
+        ];
+        let proof = CardanoTransactionsSetProof::from_subset_of_leaves::<MKTreeStoreInMemory>(
+            &leaves,
+            &["tx-1".to_string(), "tx-2".to_string(), "tx-3".to_string()],
+        )
+        .unwrap();
+
+        proof.verify().expect("The proof should be valid");
+        proof
+            .verify_block_ranges_completeness()
+            .expect_err("A transaction of the second block range is not proven");
+    }
+
     #[test]
     fn shouldnt_verify_where_at_least_one_hash_is_not_contained_in_the_proof() {
         let leaves = vec![
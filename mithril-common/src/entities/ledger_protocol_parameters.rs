@@ -0,0 +1,378 @@
+use anyhow::anyhow;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+use crate::StdResult;
+
+/// Execution units of a Plutus script
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct ExecutionUnits {
+    /// Memory units
+    pub memory: u64,
+
+    /// CPU steps
+    pub steps: u64,
+}
+
+/// Exact rational number, as the ledger stores the unit interval protocol parameters
+///
+/// It is always kept in its irreducible form so that two equal rationals share the same
+/// encoding.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct RationalNumber {
+    /// Numerator
+    pub numerator: u64,
+
+    /// Denominator
+    pub denominator: u64,
+}
+
+impl RationalNumber {
+    /// Create a new rational number reduced to its irreducible form
+    ///
+    /// Fails if the denominator is zero.
+    pub fn new(numerator: u64, denominator: u64) -> StdResult<Self> {
+        if denominator == 0 {
+            return Err(anyhow!(
+                "Rational number '{numerator}/{denominator}' has a zero denominator"
+            ));
+        }
+        let gcd = Self::gcd(numerator, denominator);
+
+        Ok(Self {
+            numerator: numerator / gcd,
+            denominator: denominator / gcd,
+        })
+    }
+
+    fn gcd(a: u64, b: u64) -> u64 {
+        if b == 0 {
+            a
+        } else {
+            Self::gcd(b, a % b)
+        }
+    }
+}
+
+/// Prices of the execution units of a Plutus script, in Lovelace per unit
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct ExecutionUnitPrices {
+    /// Price of a memory unit
+    pub price_memory: RationalNumber,
+
+    /// Price of a CPU step
+    pub price_steps: RationalNumber,
+}
+
+/// Governance parameters of the Cardano ledger (available from the Conway era)
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct LedgerGovernanceParameters {
+    /// Deposit required to submit a governance action, in Lovelace
+    pub gov_action_deposit: u64,
+
+    /// Deposit required to register a DRep, in Lovelace
+    pub drep_deposit: u64,
+
+    /// Number of epochs after which an inactive DRep is considered inactive
+    pub drep_activity: u64,
+
+    /// Number of epochs a governance action remains open for voting
+    pub gov_action_lifetime: u64,
+
+    /// Minimal size of the constitutional committee
+    pub committee_min_size: u64,
+
+    /// Maximal term length of a constitutional committee member, in epochs
+    pub committee_max_term_length: u64,
+}
+
+/// Protocol parameters of the Cardano ledger
+///
+/// Only the parameters needed by off-chain tooling (fees, deposits, limits and governance) are
+/// retained, the fields that don't exist in the current era are left empty.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct LedgerProtocolParameters {
+    /// Major version of the protocol
+    pub protocol_version_major: u64,
+
+    /// Minor version of the protocol
+    pub protocol_version_minor: u64,
+
+    /// Fee per byte of a transaction (`minFeeA`), in Lovelace
+    pub tx_fee_per_byte: u64,
+
+    /// Fixed fee of a transaction (`minFeeB`), in Lovelace
+    pub tx_fee_fixed: u64,
+
+    /// Maximal size of a block body, in bytes
+    pub max_block_body_size: u64,
+
+    /// Maximal size of a block header, in bytes
+    pub max_block_header_size: u64,
+
+    /// Maximal size of a transaction, in bytes
+    pub max_tx_size: u64,
+
+    /// Deposit required to register a stake address, in Lovelace
+    pub stake_address_deposit: u64,
+
+    /// Deposit required to register a stake pool, in Lovelace
+    pub stake_pool_deposit: u64,
+
+    /// Maximal number of epochs in the future a pool retirement can be announced
+    pub pool_retire_max_epoch: u64,
+
+    /// Targeted number of stake pools
+    pub stake_pool_target_num: u64,
+
+    /// Minimal fixed cost of a stake pool, in Lovelace
+    pub min_pool_cost: u64,
+
+    /// Cost per byte of an UTxO entry, in Lovelace
+    pub utxo_cost_per_byte: Option<u64>,
+
+    /// Maximal size of a multi-asset value, in bytes
+    pub max_value_size: Option<u64>,
+
+    /// Percentage of the transaction fee required as collateral
+    pub collateral_percentage: Option<u64>,
+
+    /// Maximal number of collateral inputs of a transaction
+    pub max_collateral_inputs: Option<u64>,
+
+    /// Prices of the execution units
+    pub execution_unit_prices: Option<ExecutionUnitPrices>,
+
+    /// Maximal execution units of a transaction
+    pub max_tx_execution_units: Option<ExecutionUnits>,
+
+    /// Maximal execution units of a block
+    pub max_block_execution_units: Option<ExecutionUnits>,
+
+    /// Governance parameters
+    pub governance: Option<LedgerGovernanceParameters>,
+}
+
+impl LedgerProtocolParameters {
+    /// Compute the hash of the canonical encoding of the protocol parameters
+    ///
+    /// Every field is encoded in declaration order as big-endian bytes, with a presence byte
+    /// before each optional field so that two different sets of parameters can't share an
+    /// encoding.
+    pub fn compute_hash(&self) -> String {
+        fn update_optional<T>(hasher: &mut Sha256, value: &Option<T>, update: fn(&mut Sha256, &T)) {
+            match value {
+                Some(value) => {
+                    hasher.update([1u8]);
+                    update(hasher, value);
+                }
+                None => hasher.update([0u8]),
+            }
+        }
+        fn update_u64(hasher: &mut Sha256, value: &u64) {
+            hasher.update(value.to_be_bytes());
+        }
+        fn update_execution_units(hasher: &mut Sha256, value: &ExecutionUnits) {
+            hasher.update(value.memory.to_be_bytes());
+            hasher.update(value.steps.to_be_bytes());
+        }
+        fn update_rational_number(hasher: &mut Sha256, value: &RationalNumber) {
+            hasher.update(value.numerator.to_be_bytes());
+            hasher.update(value.denominator.to_be_bytes());
+        }
+
+        let mut hasher = Sha256::new();
+        for value in [
+            self.protocol_version_major,
+            self.protocol_version_minor,
+            self.tx_fee_per_byte,
+            self.tx_fee_fixed,
+            self.max_block_body_size,
+            self.max_block_header_size,
+            self.max_tx_size,
+            self.stake_address_deposit,
+            self.stake_pool_deposit,
+            self.pool_retire_max_epoch,
+            self.stake_pool_target_num,
+            self.min_pool_cost,
+        ] {
+            update_u64(&mut hasher, &value);
+        }
+        update_optional(&mut hasher, &self.utxo_cost_per_byte, update_u64);
+        update_optional(&mut hasher, &self.max_value_size, update_u64);
+        update_optional(&mut hasher, &self.collateral_percentage, update_u64);
+        update_optional(&mut hasher, &self.max_collateral_inputs, update_u64);
+        update_optional(
+            &mut hasher,
+            &self.execution_unit_prices,
+            |hasher, prices| {
+                update_rational_number(hasher, &prices.price_memory);
+                update_rational_number(hasher, &prices.price_steps);
+            },
+        );
+        update_optional(
+            &mut hasher,
+            &self.max_tx_execution_units,
+            update_execution_units,
+        );
+        update_optional(
+            &mut hasher,
+            &self.max_block_execution_units,
+            update_execution_units,
+        );
+        update_optional(&mut hasher, &self.governance, |hasher, governance| {
+            hasher.update(governance.gov_action_deposit.to_be_bytes());
+            hasher.update(governance.drep_deposit.to_be_bytes());
+            hasher.update(governance.drep_activity.to_be_bytes());
+            hasher.update(governance.gov_action_lifetime.to_be_bytes());
+            hasher.update(governance.committee_min_size.to_be_bytes());
+            hasher.update(governance.committee_max_term_length.to_be_bytes());
+        });
+
+        hex::encode(hasher.finalize())
+    }
+
+    cfg_test_tools! {
+        /// Return a dummy [LedgerProtocolParameters] (test-only), with the values of the Cardano
+        /// mainnet at the beginning of the Conway era.
+        pub fn dummy() -> Self {
+            Self {
+                protocol_version_major: 10,
+                protocol_version_minor: 0,
+                tx_fee_per_byte: 44,
+                tx_fee_fixed: 155381,
+                max_block_body_size: 90112,
+                max_block_header_size: 1100,
+                max_tx_size: 16384,
+                stake_address_deposit: 2000000,
+                stake_pool_deposit: 500000000,
+                pool_retire_max_epoch: 18,
+                stake_pool_target_num: 500,
+                min_pool_cost: 170000000,
+                utxo_cost_per_byte: Some(4310),
+                max_value_size: Some(5000),
+                collateral_percentage: Some(150),
+                max_collateral_inputs: Some(3),
+                execution_unit_prices: Some(ExecutionUnitPrices {
+                    price_memory: RationalNumber {
+                        numerator: 577,
+                        denominator: 10000,
+                    },
+                    price_steps: RationalNumber {
+                        numerator: 721,
+                        denominator: 10000000,
+                    },
+                }),
+                max_tx_execution_units: Some(ExecutionUnits {
+                    memory: 14000000,
+                    steps: 10000000000,
+                }),
+                max_block_execution_units: Some(ExecutionUnits {
+                    memory: 62000000,
+                    steps: 20000000000,
+                }),
+                governance: Some(LedgerGovernanceParameters {
+                    gov_action_deposit: 100000000000,
+                    drep_deposit: 500000000,
+                    drep_activity: 20,
+                    gov_action_lifetime: 6,
+                    committee_min_size: 7,
+                    committee_max_term_length: 146,
+                }),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn compute_hash_returns_same_hash_with_same_protocol_parameters() {
+        assert_eq!(
+            LedgerProtocolParameters::dummy().compute_hash(),
+            LedgerProtocolParameters::dummy().compute_hash()
+        );
+    }
+
+    #[test]
+    fn compute_hash_returns_different_hash_with_different_protocol_parameters() {
+        let reference_hash = LedgerProtocolParameters::dummy().compute_hash();
+
+        assert_ne!(
+            reference_hash,
+            LedgerProtocolParameters {
+                tx_fee_per_byte: 45,
+                ..LedgerProtocolParameters::dummy()
+            }
+            .compute_hash()
+        );
+        assert_ne!(
+            reference_hash,
+            LedgerProtocolParameters {
+                execution_unit_prices: Some(ExecutionUnitPrices {
+                    price_memory: RationalNumber {
+                        numerator: 578,
+                        denominator: 10000,
+                    },
+                    ..LedgerProtocolParameters::dummy()
+                        .execution_unit_prices
+                        .unwrap()
+                }),
+                ..LedgerProtocolParameters::dummy()
+            }
+            .compute_hash()
+        );
+        assert_ne!(
+            reference_hash,
+            LedgerProtocolParameters {
+                governance: Some(LedgerGovernanceParameters {
+                    drep_activity: 21,
+                    ..LedgerProtocolParameters::dummy().governance.unwrap()
+                }),
+                ..LedgerProtocolParameters::dummy()
+            }
+            .compute_hash()
+        );
+    }
+
+    #[test]
+    fn rational_number_is_reduced_to_its_irreducible_form() {
+        assert_eq!(
+            RationalNumber {
+                numerator: 577,
+                denominator: 10000,
+            },
+            RationalNumber::new(5770, 100000).unwrap()
+        );
+        assert_eq!(
+            RationalNumber {
+                numerator: 0,
+                denominator: 1,
+            },
+            RationalNumber::new(0, 12).unwrap()
+        );
+    }
+
+    #[test]
+    fn rational_number_with_zero_denominator_is_rejected() {
+        RationalNumber::new(1, 0).expect_err("A zero denominator should be rejected");
+    }
+
+    #[test]
+    fn compute_hash_distinguishes_absent_fields_from_zero_values() {
+        assert_ne!(
+            LedgerProtocolParameters {
+                max_value_size: None,
+                ..LedgerProtocolParameters::dummy()
+            }
+            .compute_hash(),
+            LedgerProtocolParameters {
+                max_value_size: Some(0),
+                ..LedgerProtocolParameters::dummy()
+            }
+            .compute_hash()
+        );
+    }
+}
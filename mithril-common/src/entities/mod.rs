@@ -3,12 +3,17 @@
 pub(crate) mod arithmetic_operation_wrapper;
 mod block_number;
 mod block_range;
+mod cardano_block;
+mod cardano_blocks_set_proof;
+mod cardano_blocks_snapshot;
 mod cardano_chain_point;
 mod cardano_database;
 mod cardano_db_beacon;
 mod cardano_network;
+mod cardano_protocol_parameters;
 mod cardano_stake_distribution;
 mod cardano_transaction;
+mod cardano_transaction_filter;
 mod cardano_transactions_set_proof;
 mod cardano_transactions_snapshot;
 mod certificate;
@@ -17,6 +22,7 @@ mod compression_algorithm;
 mod epoch;
 mod file_uri;
 mod http_server_error;
+mod ledger_protocol_parameters;
 mod mithril_stake_distribution;
 mod protocol_message;
 mod protocol_parameters;
@@ -32,6 +38,9 @@ mod type_alias;
 
 pub use block_number::BlockNumber;
 pub use block_range::{BlockRange, BlockRangeLength, BlockRangesSequence};
+pub use cardano_block::CardanoBlock;
+pub use cardano_blocks_set_proof::CardanoBlocksSetProof;
+pub use cardano_blocks_snapshot::CardanoBlocksSnapshot;
 pub use cardano_chain_point::{BlockHash, ChainPoint};
 pub use cardano_database::{
     AncillaryLocation, AncillaryLocations, CardanoDatabaseSnapshot,
@@ -40,8 +49,12 @@ pub use cardano_database::{
 };
 pub use cardano_db_beacon::CardanoDbBeacon;
 pub use cardano_network::CardanoNetwork;
+pub use cardano_protocol_parameters::CardanoProtocolParameters;
 pub use cardano_stake_distribution::CardanoStakeDistribution;
 pub use cardano_transaction::{CardanoTransaction, TransactionHash};
+pub use cardano_transaction_filter::{
+    CardanoTransactionFilter, CardanoTransactionIndexEntry, MetadataLabel,
+};
 pub use cardano_transactions_set_proof::CardanoTransactionsSetProof;
 pub use cardano_transactions_snapshot::CardanoTransactionsSnapshot;
 pub use certificate::{Certificate, CertificateSignature};
@@ -50,6 +63,10 @@ pub use compression_algorithm::*;
 pub use epoch::{Epoch, EpochError};
 pub use file_uri::{FileUri, MultiFilesUri, TemplateUri};
 pub use http_server_error::{ClientError, ServerError};
+pub use ledger_protocol_parameters::{
+    ExecutionUnitPrices, ExecutionUnits, LedgerGovernanceParameters, LedgerProtocolParameters,
+    RationalNumber,
+};
 pub use mithril_stake_distribution::MithrilStakeDistribution;
 pub use protocol_message::{ProtocolMessage, ProtocolMessagePartKey, ProtocolMessagePartValue};
 pub use protocol_parameters::ProtocolParameters;
@@ -87,6 +87,14 @@ impl SignedEntityConfig {
             SignedEntityTypeDiscriminants::CardanoDatabase => SignedEntityType::CardanoDatabase(
                 CardanoDbBeacon::new(*time_point.epoch, time_point.immutable_file_number),
             ),
+            SignedEntityTypeDiscriminants::CardanoBlocks => SignedEntityType::CardanoBlocks(
+                time_point.epoch,
+                self.cardano_transactions_signing_config
+                    .compute_block_number_to_be_signed(time_point.chain_point.block_number),
+            ),
+            SignedEntityTypeDiscriminants::CardanoProtocolParameters => {
+                SignedEntityType::CardanoProtocolParameters(time_point.epoch)
+            }
         };
 
         Ok(signed_entity_type)
@@ -252,6 +260,27 @@ mod tests {
                 )
                 .unwrap()
         );
+
+        // Cardano blocks share the Cardano transactions signing configuration.
+        assert_eq!(
+            SignedEntityType::CardanoBlocks(Epoch(1), BlockNumber(14)),
+            config
+                .time_point_to_signed_entity(
+                    SignedEntityTypeDiscriminants::CardanoBlocks,
+                    &time_point
+                )
+                .unwrap()
+        );
+
+        assert_eq!(
+            SignedEntityType::CardanoProtocolParameters(Epoch(1)),
+            config
+                .time_point_to_signed_entity(
+                    SignedEntityTypeDiscriminants::CardanoProtocolParameters,
+                    &time_point
+                )
+                .unwrap()
+        );
     }
 
     #[test]
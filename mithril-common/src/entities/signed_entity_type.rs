@@ -27,6 +27,12 @@ const ENTITY_TYPE_CARDANO_TRANSACTIONS: usize = 3;
 /// Database representation of the SignedEntityType::CardanoDatabase value
 const ENTITY_TYPE_CARDANO_DATABASE: usize = 4;
 
+/// Database representation of the SignedEntityType::CardanoBlocks value
+const ENTITY_TYPE_CARDANO_BLOCKS: usize = 5;
+
+/// Database representation of the SignedEntityType::CardanoProtocolParameters value
+const ENTITY_TYPE_CARDANO_PROTOCOL_PARAMETERS: usize = 6;
+
 /// The signed entity type that represents a type of data signed by the Mithril
 /// protocol Note: Each variant of this enum must be associated to an entry in
 /// the `signed_entity_type` table of the signer/aggregator nodes. The variant
@@ -61,6 +67,12 @@ pub enum SignedEntityType {
 
     /// Cardano Transactions
     CardanoTransactions(Epoch, BlockNumber),
+
+    /// Cardano Blocks
+    CardanoBlocks(Epoch, BlockNumber),
+
+    /// Cardano Protocol Parameters
+    CardanoProtocolParameters(Epoch),
 }
 
 impl SignedEntityType {
@@ -80,7 +92,9 @@ impl SignedEntityType {
             Self::CardanoImmutableFilesFull(b) | Self::CardanoDatabase(b) => b.epoch,
             Self::CardanoStakeDistribution(e)
             | Self::MithrilStakeDistribution(e)
-            | Self::CardanoTransactions(e, _) => *e,
+            | Self::CardanoTransactions(e, _)
+            | Self::CardanoBlocks(e, _)
+            | Self::CardanoProtocolParameters(e) => *e,
         }
     }
 
@@ -89,7 +103,10 @@ impl SignedEntityType {
         match self {
             Self::CardanoImmutableFilesFull(beacon) | Self::CardanoDatabase(beacon) => beacon.epoch,
             Self::CardanoStakeDistribution(epoch) => epoch.next(),
-            Self::MithrilStakeDistribution(epoch) | Self::CardanoTransactions(epoch, _) => *epoch,
+            Self::MithrilStakeDistribution(epoch)
+            | Self::CardanoTransactions(epoch, _)
+            | Self::CardanoBlocks(epoch, _)
+            | Self::CardanoProtocolParameters(epoch) => *epoch,
         }
     }
 
@@ -101,6 +118,8 @@ impl SignedEntityType {
             Self::CardanoImmutableFilesFull(_) => ENTITY_TYPE_CARDANO_IMMUTABLE_FILES_FULL,
             Self::CardanoTransactions(_, _) => ENTITY_TYPE_CARDANO_TRANSACTIONS,
             Self::CardanoDatabase(_) => ENTITY_TYPE_CARDANO_DATABASE,
+            Self::CardanoBlocks(_, _) => ENTITY_TYPE_CARDANO_BLOCKS,
+            Self::CardanoProtocolParameters(_) => ENTITY_TYPE_CARDANO_PROTOCOL_PARAMETERS,
         }
     }
 
@@ -110,10 +129,11 @@ impl SignedEntityType {
             Self::CardanoImmutableFilesFull(value) | Self::CardanoDatabase(value) => {
                 serde_json::to_string(value)?
             }
-            Self::CardanoStakeDistribution(value) | Self::MithrilStakeDistribution(value) => {
-                serde_json::to_string(value)?
-            }
-            Self::CardanoTransactions(epoch, block_number) => {
+            Self::CardanoStakeDistribution(value)
+            | Self::MithrilStakeDistribution(value)
+            | Self::CardanoProtocolParameters(value) => serde_json::to_string(value)?,
+            Self::CardanoTransactions(epoch, block_number)
+            | Self::CardanoBlocks(epoch, block_number) => {
                 let json = serde_json::json!({
                     "epoch": epoch,
                     "block_number": block_number,
@@ -129,8 +149,12 @@ impl SignedEntityType {
     pub fn get_open_message_timeout(&self) -> Option<Duration> {
         match self {
             Self::MithrilStakeDistribution(_) | Self::CardanoImmutableFilesFull(_) => None,
-            Self::CardanoStakeDistribution(_) => Some(Duration::from_secs(600)),
-            Self::CardanoTransactions(_, _) => Some(Duration::from_secs(1800)),
+            Self::CardanoStakeDistribution(_) | Self::CardanoProtocolParameters(_) => {
+                Some(Duration::from_secs(600))
+            }
+            Self::CardanoTransactions(_, _) | Self::CardanoBlocks(_, _) => {
+                Some(Duration::from_secs(1800))
+            }
             Self::CardanoDatabase(_) => Some(Duration::from_secs(1800)),
         }
     }
@@ -138,7 +162,8 @@ impl SignedEntityType {
     pub(crate) fn feed_hash(&self, hasher: &mut Sha256) {
         match self {
             SignedEntityType::MithrilStakeDistribution(epoch)
-            | SignedEntityType::CardanoStakeDistribution(epoch) => {
+            | SignedEntityType::CardanoStakeDistribution(epoch)
+            | SignedEntityType::CardanoProtocolParameters(epoch) => {
                 hasher.update(&epoch.to_be_bytes())
             }
             SignedEntityType::CardanoImmutableFilesFull(db_beacon)
@@ -146,7 +171,8 @@ impl SignedEntityType {
                 hasher.update(&db_beacon.epoch.to_be_bytes());
                 hasher.update(&db_beacon.immutable_file_number.to_be_bytes());
             }
-            SignedEntityType::CardanoTransactions(epoch, block_number) => {
+            SignedEntityType::CardanoTransactions(epoch, block_number)
+            | SignedEntityType::CardanoBlocks(epoch, block_number) => {
                 hasher.update(&epoch.to_be_bytes());
                 hasher.update(&block_number.to_be_bytes())
             }
@@ -168,6 +194,8 @@ impl SignedEntityTypeDiscriminants {
             Self::CardanoImmutableFilesFull => ENTITY_TYPE_CARDANO_IMMUTABLE_FILES_FULL,
             Self::CardanoTransactions => ENTITY_TYPE_CARDANO_TRANSACTIONS,
             Self::CardanoDatabase => ENTITY_TYPE_CARDANO_DATABASE,
+            Self::CardanoBlocks => ENTITY_TYPE_CARDANO_BLOCKS,
+            Self::CardanoProtocolParameters => ENTITY_TYPE_CARDANO_PROTOCOL_PARAMETERS,
         }
     }
 
@@ -179,6 +207,8 @@ impl SignedEntityTypeDiscriminants {
             ENTITY_TYPE_CARDANO_IMMUTABLE_FILES_FULL => Ok(Self::CardanoImmutableFilesFull),
             ENTITY_TYPE_CARDANO_TRANSACTIONS => Ok(Self::CardanoTransactions),
             ENTITY_TYPE_CARDANO_DATABASE => Ok(Self::CardanoDatabase),
+            ENTITY_TYPE_CARDANO_BLOCKS => Ok(Self::CardanoBlocks),
+            ENTITY_TYPE_CARDANO_PROTOCOL_PARAMETERS => Ok(Self::CardanoProtocolParameters),
             index => Err(anyhow!("Invalid entity_type_id {index}.")),
         }
     }
@@ -294,6 +324,26 @@ mod tests {
         );
     }
 
+    #[test]
+    fn get_epoch_when_signed_entity_type_is_signed_for_cardano_blocks_return_epoch_stored_in_signed_entity_type(
+    ) {
+        let signed_entity_type = SignedEntityType::CardanoBlocks(Epoch(3), BlockNumber(77));
+        assert_eq!(
+            signed_entity_type.get_epoch_when_signed_entity_type_is_signed(),
+            Epoch(3)
+        );
+    }
+
+    #[test]
+    fn get_epoch_when_signed_entity_type_is_signed_for_cardano_protocol_parameters_return_epoch_stored_in_signed_entity_type(
+    ) {
+        let signed_entity_type = SignedEntityType::CardanoProtocolParameters(Epoch(3));
+        assert_eq!(
+            signed_entity_type.get_epoch_when_signed_entity_type_is_signed(),
+            Epoch(3)
+        );
+    }
+
     #[test]
     fn verify_signed_entity_type_properties_are_included_in_computed_hash() {
         fn hash(signed_entity_type: SignedEntityType) -> String {
@@ -314,6 +364,16 @@ mod tests {
             hash(SignedEntityType::CardanoStakeDistribution(Epoch(15)))
         );
 
+        let reference_hash = hash(SignedEntityType::CardanoProtocolParameters(Epoch(5)));
+        assert_ne!(
+            reference_hash,
+            hash(SignedEntityType::CardanoProtocolParameters(Epoch(15)))
+        );
+        assert_ne!(
+            reference_hash,
+            hash(SignedEntityType::CardanoStakeDistribution(Epoch(5)))
+        );
+
         let reference_hash = hash(SignedEntityType::CardanoImmutableFilesFull(
             CardanoDbBeacon::new(5, 100),
         ));
@@ -349,6 +409,19 @@ mod tests {
             ))
         );
 
+        let reference_hash = hash(SignedEntityType::CardanoBlocks(Epoch(35), BlockNumber(77)));
+        assert_ne!(
+            reference_hash,
+            hash(SignedEntityType::CardanoBlocks(Epoch(3), BlockNumber(77)))
+        );
+        assert_ne!(
+            reference_hash,
+            hash(SignedEntityType::CardanoBlocks(
+                Epoch(35),
+                BlockNumber(98765)
+            ))
+        );
+
         let reference_hash = hash(SignedEntityType::CardanoDatabase(CardanoDbBeacon::new(
             12, 987,
         )));
@@ -382,6 +455,11 @@ mod tests {
             &cardano_transactions_json
         );
 
+        let cardano_blocks_json = SignedEntityType::CardanoBlocks(Epoch(35), BlockNumber(77))
+            .get_json_beacon()
+            .unwrap();
+        assert_same_json!(r#"{"epoch":35,"block_number":77}"#, &cardano_blocks_json);
+
         let cardano_immutable_files_full_json =
             SignedEntityType::CardanoImmutableFilesFull(CardanoDbBeacon::new(5, 100))
                 .get_json_beacon()
@@ -396,6 +474,12 @@ mod tests {
             .unwrap();
         assert_same_json!("15", &msd_json);
 
+        let cardano_protocol_parameters_json =
+            SignedEntityType::CardanoProtocolParameters(Epoch(42))
+                .get_json_beacon()
+                .unwrap();
+        assert_same_json!("42", &cardano_protocol_parameters_json);
+
         let cardano_database_full_json =
             SignedEntityType::CardanoDatabase(CardanoDbBeacon::new(12, 987))
                 .get_json_beacon()
@@ -407,10 +491,12 @@ mod tests {
     }
 
     // Expected ord:
-    // MithrilStakeDistribution < CardanoStakeDistribution < CardanoImmutableFilesFull < CardanoDatabase < CardanoTransactions
+    // MithrilStakeDistribution < CardanoStakeDistribution < CardanoImmutableFilesFull < CardanoDatabase < CardanoTransactions < CardanoBlocks < CardanoProtocolParameters
     #[test]
     fn ordering_discriminant() {
         let mut list = vec![
+            SignedEntityTypeDiscriminants::CardanoProtocolParameters,
+            SignedEntityTypeDiscriminants::CardanoBlocks,
             SignedEntityTypeDiscriminants::CardanoStakeDistribution,
             SignedEntityTypeDiscriminants::CardanoDatabase,
             SignedEntityTypeDiscriminants::CardanoTransactions,
@@ -427,6 +513,8 @@ mod tests {
                 SignedEntityTypeDiscriminants::CardanoImmutableFilesFull,
                 SignedEntityTypeDiscriminants::CardanoDatabase,
                 SignedEntityTypeDiscriminants::CardanoTransactions,
+                SignedEntityTypeDiscriminants::CardanoBlocks,
+                SignedEntityTypeDiscriminants::CardanoProtocolParameters,
             ]
         );
     }
@@ -20,6 +20,16 @@ macro_rules! cfg_fs {
     }
 }
 
+macro_rules! cfg_sqlite {
+    ($($item:item)*) => {
+        $(
+            #[cfg(feature = "sqlite")]
+            #[cfg_attr(docsrs, doc(cfg(feature = "sqlite")))]
+            $item
+        )*
+    }
+}
+
 macro_rules! cfg_test_tools {
     ($($item:item)*) => {
         $(
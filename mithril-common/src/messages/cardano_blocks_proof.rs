@@ -0,0 +1,345 @@
+use crate::entities::{
+    BlockHash, BlockNumber, CardanoBlock, CardanoBlocksSetProof, ProtocolMessage,
+    ProtocolMessagePartKey,
+};
+use crate::messages::CardanoBlocksSetProofMessagePart;
+use crate::StdError;
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+/// A cryptographic proof for a set of Cardano blocks
+#[derive(Clone, Debug, PartialEq, Default, Serialize, Deserialize)]
+pub struct CardanoBlocksProofsMessage {
+    /// Hash of the certificate that validate this proof merkle root
+    pub certificate_hash: String,
+
+    /// Blocks that have been certified
+    pub certified_blocks: Vec<CardanoBlocksSetProofMessagePart>,
+
+    /// Hashes of the blocks that could not be certified
+    pub non_certified_blocks: Vec<BlockHash>,
+
+    /// Latest block number that has been certified
+    pub latest_block_number: BlockNumber,
+}
+
+impl CardanoBlocksProofsMessage {
+    /// Blocks that have been certified
+    pub fn blocks(&self) -> Vec<CardanoBlock> {
+        self.certified_blocks
+            .iter()
+            .flat_map(|cb| cb.blocks.clone())
+            .collect::<Vec<_>>()
+    }
+}
+
+/// Set of blocks verified by [CardanoBlocksProofsMessage::verify].
+///
+/// Can be used to reconstruct part of a [ProtocolMessage] in order to check that
+/// it is indeed signed by a certificate.
+#[derive(Debug, Clone, PartialEq)]
+pub struct VerifiedCardanoBlocks {
+    certificate_hash: String,
+    merkle_root: String,
+    certified_blocks: Vec<CardanoBlock>,
+    latest_block_number: BlockNumber,
+}
+
+impl VerifiedCardanoBlocks {
+    /// Hash of the certificate that signs this struct Merkle root.
+    pub fn certificate_hash(&self) -> &str {
+        &self.certificate_hash
+    }
+
+    /// Certified blocks
+    pub fn certified_blocks(&self) -> &[CardanoBlock] {
+        &self.certified_blocks
+    }
+
+    /// Fill the given [ProtocolMessage] with the data associated with this
+    /// verified blocks set.
+    pub fn fill_protocol_message(&self, message: &mut ProtocolMessage) {
+        message.set_message_part(
+            ProtocolMessagePartKey::CardanoBlocksMerkleRoot,
+            self.merkle_root.clone(),
+        );
+
+        message.set_message_part(
+            ProtocolMessagePartKey::LatestBlockNumber,
+            self.latest_block_number.to_string(),
+        );
+    }
+}
+
+/// Error encountered or produced by the [cardano block proof verification][CardanoBlocksProofsMessage::verify].
+#[derive(Error, Debug)]
+pub enum VerifyCardanoBlocksProofsError {
+    /// The verification of an individual [CardanoBlocksSetProofMessagePart] failed.
+    #[error("Invalid set proof for blocks hashes: {blocks_hashes:?}")]
+    InvalidSetProof {
+        /// Hashes of the invalid blocks
+        blocks_hashes: Vec<BlockHash>,
+        /// Error source
+        source: StdError,
+    },
+
+    /// No certified blocks set proof to verify
+    #[error("There's no certified block to verify")]
+    NoCertifiedBlock,
+
+    /// Not all certified blocks set proof have the same merkle root.
+    ///
+    /// This is problematic because all the set proof should be generated from the same
+    /// merkle tree which root is signed in the [certificate][crate::entities::Certificate].
+    #[error("All certified blocks set proofs must share the same Merkle root")]
+    NonMatchingMerkleRoot,
+
+    /// A certified block is above the latest certified block number.
+    #[error(
+        "Block '{block_hash}' is above the latest certified block number '{latest_block_number}'"
+    )]
+    BlockAboveLatestCertifiedBlockNumber {
+        /// Hash of the offending block
+        block_hash: BlockHash,
+        /// Latest block number certified
+        latest_block_number: BlockNumber,
+    },
+
+    /// An individual [CardanoBlocksSetProofMessagePart] could not be converted to a
+    /// [CardanoBlocksSetProof] for verification.
+    #[error("Malformed data or unknown Cardano Set Proof format")]
+    MalformedData(#[source] StdError),
+}
+
+impl CardanoBlocksProofsMessage {
+    /// Create a new `CardanoBlocksProofsMessage`
+    pub fn new(
+        certificate_hash: &str,
+        certified_blocks: Vec<CardanoBlocksSetProofMessagePart>,
+        non_certified_blocks: Vec<BlockHash>,
+        latest_block_number: BlockNumber,
+    ) -> Self {
+        Self {
+            certificate_hash: certificate_hash.to_string(),
+            certified_blocks,
+            non_certified_blocks,
+            latest_block_number,
+        }
+    }
+
+    /// Verify that all the certified blocks proofs are valid
+    ///
+    /// The following checks will be executed:
+    ///
+    /// 1 - Check that each Merkle proof is valid
+    ///
+    /// 2 - Check that no certified block is above the latest certified block number
+    ///
+    /// 3 - Check that all proofs share the same Merkle root
+    ///
+    /// 4 - Assert that there's at least one certified block
+    ///
+    /// If every check is okay, the hex encoded Merkle root of the proof will be returned.
+    pub fn verify(&self) -> Result<VerifiedCardanoBlocks, VerifyCardanoBlocksProofsError> {
+        let mut merkle_root = None;
+
+        for certified_block in &self.certified_blocks {
+            let certified_block: CardanoBlocksSetProof = certified_block
+                .clone()
+                .try_into()
+                .map_err(VerifyCardanoBlocksProofsError::MalformedData)?;
+            certified_block.verify().map_err(|e| {
+                VerifyCardanoBlocksProofsError::InvalidSetProof {
+                    blocks_hashes: certified_block.blocks_hashes(),
+                    source: e,
+                }
+            })?;
+
+            if let Some(block) = certified_block
+                .blocks()
+                .iter()
+                .find(|b| b.block_number > self.latest_block_number)
+            {
+                return Err(
+                    VerifyCardanoBlocksProofsError::BlockAboveLatestCertifiedBlockNumber {
+                        block_hash: block.block_hash.clone(),
+                        latest_block_number: self.latest_block_number,
+                    },
+                );
+            }
+
+            let blocks_merkle_root = Some(certified_block.merkle_root());
+
+            if merkle_root.is_none() {
+                merkle_root = blocks_merkle_root;
+            } else if merkle_root != blocks_merkle_root {
+                return Err(VerifyCardanoBlocksProofsError::NonMatchingMerkleRoot);
+            }
+        }
+
+        Ok(VerifiedCardanoBlocks {
+            certificate_hash: self.certificate_hash.clone(),
+            merkle_root: merkle_root.ok_or(VerifyCardanoBlocksProofsError::NoCertifiedBlock)?,
+            certified_blocks: self.blocks(),
+            latest_block_number: self.latest_block_number,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::crypto_helper::{MKProof, MKTreeNode};
+    use crate::entities::SlotNumber;
+
+    use super::*;
+
+    #[test]
+    fn verify_malformed_proofs_fail() {
+        let blocks_proofs = CardanoBlocksProofsMessage::new(
+            "whatever",
+            vec![CardanoBlocksSetProofMessagePart {
+                blocks: vec![],
+                proof: "invalid".to_string(),
+            }],
+            vec![],
+            BlockNumber(99999),
+        );
+
+        let error = blocks_proofs
+            .verify()
+            .expect_err("Malformed blocks proofs should fail to verify itself");
+        assert!(
+            matches!(error, VerifyCardanoBlocksProofsError::MalformedData(_)),
+            "Expected 'MalformedData' error but got '{:?}'",
+            error
+        );
+    }
+
+    #[test]
+    fn verify_no_certified_block_fail() {
+        let blocks_proofs =
+            CardanoBlocksProofsMessage::new("whatever", vec![], vec![], BlockNumber(99999));
+
+        let error = blocks_proofs
+            .verify()
+            .expect_err("Proofs without certified blocks should fail to verify itself");
+        assert!(
+            matches!(error, VerifyCardanoBlocksProofsError::NoCertifiedBlock),
+            "Expected 'NoCertifiedBlock' error but got '{:?}'",
+            error
+        );
+    }
+
+    #[test]
+    fn verify_valid_proofs() {
+        let set_proof = CardanoBlocksSetProof::dummy();
+        let expected = VerifiedCardanoBlocks {
+            certificate_hash: "whatever".to_string(),
+            merkle_root: set_proof.merkle_root(),
+            certified_blocks: set_proof.blocks().to_vec(),
+            latest_block_number: BlockNumber(99999),
+        };
+        let blocks_proofs = CardanoBlocksProofsMessage::new(
+            "whatever",
+            vec![set_proof.try_into().unwrap()],
+            vec![],
+            BlockNumber(99999),
+        );
+
+        let verified_blocks = blocks_proofs
+            .verify()
+            .expect("Valid blocks proofs should verify itself");
+
+        assert_eq!(expected, verified_blocks);
+    }
+
+    #[test]
+    fn verify_invalid_proofs() {
+        let set_proof = CardanoBlocksSetProof::new(
+            vec![CardanoBlock::new(
+                "invalid1",
+                BlockNumber(1),
+                SlotNumber(10),
+            )],
+            MKProof::from_leaves(&["invalid2"]).unwrap(),
+        );
+        let blocks_proofs = CardanoBlocksProofsMessage::new(
+            "whatever",
+            vec![set_proof.try_into().unwrap()],
+            vec![],
+            BlockNumber(99999),
+        );
+
+        let error = blocks_proofs
+            .verify()
+            .expect_err("Invalid blocks proofs should fail to verify itself");
+
+        assert!(
+            matches!(
+                error,
+                VerifyCardanoBlocksProofsError::InvalidSetProof { .. }
+            ),
+            "Expected 'InvalidSetProof' error but got '{:?}'",
+            error
+        );
+    }
+
+    #[test]
+    fn verify_proof_with_a_block_above_latest_certified_block_number_fail() {
+        let set_proof = CardanoBlocksSetProof::dummy();
+        let blocks_proofs = CardanoBlocksProofsMessage::new(
+            "whatever",
+            vec![set_proof.try_into().unwrap()],
+            vec![],
+            BlockNumber(5),
+        );
+
+        let error = blocks_proofs
+            .verify()
+            .expect_err("Blocks proofs with a block above the latest block number should fail");
+
+        assert!(
+            matches!(
+                error,
+                VerifyCardanoBlocksProofsError::BlockAboveLatestCertifiedBlockNumber { .. }
+            ),
+            "Expected 'BlockAboveLatestCertifiedBlockNumber' error but got '{:?}'",
+            error
+        );
+    }
+
+    #[test]
+    fn verify_valid_proof_with_different_merkle_root_fail() {
+        let block_1 = CardanoBlock::new("block-1", BlockNumber(1), SlotNumber(10));
+        let block_2 = CardanoBlock::new("block-2", BlockNumber(2), SlotNumber(20));
+        let set_proofs = vec![
+            CardanoBlocksSetProof::new(
+                vec![block_1.clone()],
+                MKProof::from_leaves(&[MKTreeNode::from(block_1)]).unwrap(),
+            ),
+            CardanoBlocksSetProof::new(
+                vec![block_2.clone()],
+                MKProof::from_leaves(&[MKTreeNode::from(block_2)]).unwrap(),
+            ),
+        ];
+        let blocks_proofs = CardanoBlocksProofsMessage::new(
+            "whatever",
+            set_proofs
+                .into_iter()
+                .map(|p| p.try_into().unwrap())
+                .collect(),
+            vec![],
+            BlockNumber(99999),
+        );
+
+        let error = blocks_proofs
+            .verify()
+            .expect_err("Blocks proofs with non matching merkle root should fail to verify itself");
+
+        assert!(
+            matches!(error, VerifyCardanoBlocksProofsError::NonMatchingMerkleRoot),
+            "Expected 'NonMatchingMerkleRoot' error but got '{:?}'",
+            error
+        );
+    }
+}
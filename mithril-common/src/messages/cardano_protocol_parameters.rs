@@ -0,0 +1,155 @@
+use chrono::DateTime;
+use chrono::Utc;
+use serde::{Deserialize, Serialize};
+
+use crate::entities::Epoch;
+use crate::entities::LedgerProtocolParameters;
+
+/// Message structure of a Cardano Protocol Parameters
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct CardanoProtocolParametersMessage {
+    /// Epoch at which the Cardano protocol parameters are in effect
+    pub epoch: Epoch,
+
+    /// Hash of the Cardano Protocol Parameters
+    pub hash: String,
+
+    /// Hash of the associated certificate
+    pub certificate_hash: String,
+
+    /// Protocol parameters of the Cardano ledger
+    pub protocol_parameters: LedgerProtocolParameters,
+
+    /// DateTime of creation
+    pub created_at: DateTime<Utc>,
+}
+
+impl CardanoProtocolParametersMessage {
+    cfg_test_tools! {
+        /// Return a dummy test entity (test-only).
+        pub fn dummy() -> Self {
+            Self {
+                epoch: Epoch(1),
+                hash: "hash-123".to_string(),
+                certificate_hash: "cert-hash-123".to_string(),
+                protocol_parameters: LedgerProtocolParameters::dummy(),
+                created_at: DateTime::parse_from_rfc3339("2024-07-29T16:15:05.618857482Z")
+                    .unwrap()
+                    .with_timezone(&Utc),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::entities::{
+        ExecutionUnitPrices, ExecutionUnits, LedgerGovernanceParameters, RationalNumber,
+    };
+
+    use super::*;
+
+    fn golden_message_current() -> CardanoProtocolParametersMessage {
+        CardanoProtocolParametersMessage {
+            epoch: Epoch(1),
+            hash: "hash-123".to_string(),
+            certificate_hash: "cert-hash-123".to_string(),
+            protocol_parameters: LedgerProtocolParameters {
+                protocol_version_major: 10,
+                protocol_version_minor: 0,
+                tx_fee_per_byte: 44,
+                tx_fee_fixed: 155381,
+                max_block_body_size: 90112,
+                max_block_header_size: 1100,
+                max_tx_size: 16384,
+                stake_address_deposit: 2000000,
+                stake_pool_deposit: 500000000,
+                pool_retire_max_epoch: 18,
+                stake_pool_target_num: 500,
+                min_pool_cost: 170000000,
+                utxo_cost_per_byte: Some(4310),
+                max_value_size: Some(5000),
+                collateral_percentage: Some(150),
+                max_collateral_inputs: Some(3),
+                execution_unit_prices: Some(ExecutionUnitPrices {
+                    price_memory: RationalNumber {
+                        numerator: 577,
+                        denominator: 10000,
+                    },
+                    price_steps: RationalNumber {
+                        numerator: 721,
+                        denominator: 10000000,
+                    },
+                }),
+                max_tx_execution_units: Some(ExecutionUnits {
+                    memory: 14000000,
+                    steps: 10000000000,
+                }),
+                max_block_execution_units: Some(ExecutionUnits {
+                    memory: 62000000,
+                    steps: 20000000000,
+                }),
+                governance: Some(LedgerGovernanceParameters {
+                    gov_action_deposit: 100000000000,
+                    drep_deposit: 500000000,
+                    drep_activity: 20,
+                    gov_action_lifetime: 6,
+                    committee_min_size: 7,
+                    committee_max_term_length: 146,
+                }),
+            },
+            created_at: DateTime::parse_from_rfc3339("2024-07-29T16:15:05.618857482Z")
+                .unwrap()
+                .with_timezone(&Utc),
+        }
+    }
+
+    const CURRENT_JSON: &str = r#"{
+        "epoch": 1,
+        "hash": "hash-123",
+        "certificate_hash": "cert-hash-123",
+        "protocol_parameters": {
+            "protocol_version_major": 10,
+            "protocol_version_minor": 0,
+            "tx_fee_per_byte": 44,
+            "tx_fee_fixed": 155381,
+            "max_block_body_size": 90112,
+            "max_block_header_size": 1100,
+            "max_tx_size": 16384,
+            "stake_address_deposit": 2000000,
+            "stake_pool_deposit": 500000000,
+            "pool_retire_max_epoch": 18,
+            "stake_pool_target_num": 500,
+            "min_pool_cost": 170000000,
+            "utxo_cost_per_byte": 4310,
+            "max_value_size": 5000,
+            "collateral_percentage": 150,
+            "max_collateral_inputs": 3,
+            "execution_unit_prices": {
+                "price_memory": { "numerator": 577, "denominator": 10000 },
+                "price_steps": { "numerator": 721, "denominator": 10000000 }
+            },
+            "max_tx_execution_units": { "memory": 14000000, "steps": 10000000000 },
+            "max_block_execution_units": { "memory": 62000000, "steps": 20000000000 },
+            "governance": {
+                "gov_action_deposit": 100000000000,
+                "drep_deposit": 500000000,
+                "drep_activity": 20,
+                "gov_action_lifetime": 6,
+                "committee_min_size": 7,
+                "committee_max_term_length": 146
+            }
+        },
+        "created_at": "2024-07-29T16:15:05.618857482Z"
+    }"#;
+
+    #[test]
+    fn test_current_json_deserialized_into_current_message() {
+        let json = CURRENT_JSON;
+        let message: CardanoProtocolParametersMessage = serde_json::from_str(json).expect(
+            "This JSON is expected to be successfully parsed into a CardanoProtocolParametersMessage instance.",
+        );
+
+        assert_eq!(golden_message_current(), message);
+    }
+}
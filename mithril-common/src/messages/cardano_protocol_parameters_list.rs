@@ -0,0 +1,70 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::entities::Epoch;
+
+/// Message structure of a Cardano Protocol Parameters list
+pub type CardanoProtocolParametersListMessage = Vec<CardanoProtocolParametersListItemMessage>;
+
+/// Message structure of a Cardano Protocol Parameters list item
+#[derive(Clone, Debug, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub struct CardanoProtocolParametersListItemMessage {
+    /// Epoch at which the Cardano protocol parameters are in effect
+    pub epoch: Epoch,
+
+    /// Hash of the Cardano Protocol Parameters
+    pub hash: String,
+
+    /// Hash of the associated certificate
+    pub certificate_hash: String,
+
+    /// Date and time at which the Cardano Protocol Parameters were created
+    pub created_at: DateTime<Utc>,
+}
+
+impl CardanoProtocolParametersListItemMessage {
+    /// Return a dummy test entity (test-only).
+    pub fn dummy() -> Self {
+        Self {
+            epoch: Epoch(1),
+            hash: "hash-123".to_string(),
+            certificate_hash: "certificate-hash-123".to_string(),
+            created_at: DateTime::parse_from_rfc3339("2024-07-29T16:15:05.618857482Z")
+                .unwrap()
+                .with_timezone(&Utc),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn golden_message_current() -> CardanoProtocolParametersListMessage {
+        vec![CardanoProtocolParametersListItemMessage {
+            epoch: Epoch(1),
+            hash: "hash-123".to_string(),
+            certificate_hash: "cert-hash-123".to_string(),
+            created_at: DateTime::parse_from_rfc3339("2024-07-29T16:15:05.618857482Z")
+                .unwrap()
+                .with_timezone(&Utc),
+        }]
+    }
+
+    const CURRENT_JSON: &str = r#"[{
+        "epoch": 1,
+        "hash": "hash-123",
+        "certificate_hash": "cert-hash-123",
+        "created_at": "2024-07-29T16:15:05.618857482Z"
+    }]"#;
+
+    #[test]
+    fn test_current_json_deserialized_into_current_message() {
+        let json = CURRENT_JSON;
+        let message: CardanoProtocolParametersListMessage = serde_json::from_str(json).expect(
+            "This JSON is expected to be successfully parsed into a CardanoProtocolParametersListMessage instance.",
+        );
+
+        assert_eq!(golden_message_current(), message);
+    }
+}
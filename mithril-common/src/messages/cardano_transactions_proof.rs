@@ -58,6 +58,20 @@ pub struct VerifiedCardanoTransactions {
 }
 
 impl VerifiedCardanoTransactions {
+    pub(crate) fn new(
+        certificate_hash: String,
+        merkle_root: String,
+        certified_transactions: Vec<TransactionHash>,
+        latest_block_number: BlockNumber,
+    ) -> Self {
+        Self {
+            certificate_hash,
+            merkle_root,
+            certified_transactions,
+            latest_block_number,
+        }
+    }
+
     /// Hash of the certificate that signs this struct Merkle root.
     pub fn certificate_hash(&self) -> &str {
         &self.certificate_hash
@@ -0,0 +1,406 @@
+use std::ops::Range;
+
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+use crate::entities::{
+    BlockNumber, BlockRange, CardanoTransactionFilter, CardanoTransactionsSetProof,
+    ProtocolMessage, ProtocolMessagePartKey, TransactionHash,
+};
+use crate::messages::CardanoTransactionsSetProofMessagePart;
+use crate::StdError;
+
+/// A cryptographic proof of the Cardano transactions matching a filter (output address or
+/// metadata label) in a set of block ranges
+///
+/// The proof certifies all the transactions of each certified block range, but not their content:
+/// which of those transactions match the filter is claimed by the aggregator and can't be verified
+/// with this proof.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct CardanoTransactionsSearchProofsMessage {
+    /// Hash of the certificate that validate this proof merkle root
+    pub certificate_hash: String,
+
+    /// Filter used to search the transactions
+    pub filter: CardanoTransactionFilter,
+
+    /// Block ranges which all transactions are certified
+    pub certified_block_ranges: Vec<Range<BlockNumber>>,
+
+    /// Proof of all the transactions of the certified block ranges, its transactions hashes
+    /// are the hashes of the transactions that the aggregator claims to match the filter
+    ///
+    /// Not set if none of the requested block ranges could be certified.
+    pub certified_transactions: Option<CardanoTransactionsSetProofMessagePart>,
+
+    /// Latest block number that has been certified
+    pub latest_block_number: BlockNumber,
+}
+
+/// Result of a search of Cardano transactions checked by [CardanoTransactionsSearchProofsMessage::verify].
+///
+/// The certified block ranges are verified, but not the transactions matching the filter: they
+/// are claimed by the aggregator, which could omit matching transactions or return transactions
+/// that don't match.
+///
+/// Can be used to reconstruct part of a [ProtocolMessage] in order to check that
+/// it is indeed signed by a certificate.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CardanoTransactionsSearchResult {
+    certificate_hash: String,
+    merkle_root: String,
+    certified_block_ranges: Vec<Range<BlockNumber>>,
+    claimed_matching_transactions: Vec<TransactionHash>,
+    latest_block_number: BlockNumber,
+}
+
+impl CardanoTransactionsSearchResult {
+    /// Hash of the certificate that signs this struct Merkle root.
+    pub fn certificate_hash(&self) -> &str {
+        &self.certificate_hash
+    }
+
+    /// Block ranges which all transactions are certified
+    pub fn certified_block_ranges(&self) -> &[Range<BlockNumber>] {
+        &self.certified_block_ranges
+    }
+
+    /// Hashes of the transactions that the aggregator claims to match the filter
+    ///
+    /// They are certified to be in the certified block ranges, but their match with the filter
+    /// is **not** verified.
+    pub fn claimed_matching_transactions(&self) -> &[TransactionHash] {
+        &self.claimed_matching_transactions
+    }
+
+    /// Fill the given [ProtocolMessage] with the data associated with the certified block
+    /// ranges of this search.
+    pub fn fill_protocol_message(&self, message: &mut ProtocolMessage) {
+        message.set_message_part(
+            ProtocolMessagePartKey::CardanoTransactionsMerkleRoot,
+            self.merkle_root.clone(),
+        );
+
+        message.set_message_part(
+            ProtocolMessagePartKey::LatestBlockNumber,
+            self.latest_block_number.to_string(),
+        );
+    }
+}
+
+/// Error encountered or produced by the [cardano transaction search proof verification][CardanoTransactionsSearchProofsMessage::verify].
+#[derive(Error, Debug)]
+pub enum VerifyCardanoTransactionsSearchProofsError {
+    /// No block range could be certified
+    #[error("There's no certified block range to verify")]
+    NoCertifiedBlockRange,
+
+    /// The verification of the set proof failed.
+    #[error("Invalid set proof for transactions hashes: {transactions_hashes:?}")]
+    InvalidSetProof {
+        /// Hashes of the invalid transactions
+        transactions_hashes: Vec<TransactionHash>,
+        /// Error source
+        source: StdError,
+    },
+
+    /// The block ranges proven by the set proof are not the certified block ranges of the message
+    #[error("The block ranges of the set proof don't match the certified block ranges")]
+    NonMatchingBlockRanges,
+
+    /// The set proof does not contain all the transactions of its block ranges
+    #[error("The set proof does not contain all the transactions of its block ranges")]
+    IncompleteBlockRanges(#[source] StdError),
+
+    /// The set proof could not be converted to a [CardanoTransactionsSetProof] for verification.
+    #[error("Malformed data or unknown Cardano Set Proof format")]
+    MalformedData(#[source] StdError),
+}
+
+impl CardanoTransactionsSearchProofsMessage {
+    /// Create a new `CardanoTransactionsSearchProofsMessage`
+    pub fn new(
+        certificate_hash: &str,
+        filter: CardanoTransactionFilter,
+        certified_block_ranges: Vec<BlockRange>,
+        certified_transactions: Option<CardanoTransactionsSetProofMessagePart>,
+        latest_block_number: BlockNumber,
+    ) -> Self {
+        Self {
+            certificate_hash: certificate_hash.to_string(),
+            filter,
+            certified_block_ranges: certified_block_ranges
+                .into_iter()
+                .map(|block_range| (*block_range).clone())
+                .collect(),
+            certified_transactions,
+            latest_block_number,
+        }
+    }
+
+    /// Hashes of the transactions that the aggregator claims to match the filter
+    pub fn transactions_hashes(&self) -> Vec<TransactionHash> {
+        self.certified_transactions
+            .as_ref()
+            .map(|ct| ct.transactions_hashes.clone())
+            .unwrap_or_default()
+    }
+
+    /// Verify that the proof of the transactions of the certified block ranges is valid
+    ///
+    /// The match of the transactions with the filter is not verified: the certified data only
+    /// commits to the transactions hashes and their block ranges, see
+    /// [CardanoTransactionsSearchResult].
+    ///
+    /// The following checks will be executed:
+    ///
+    /// 1 - Check that there's a set proof
+    ///
+    /// 2 - Check that the Merkle proof is valid and contains the claimed matching transactions
+    ///
+    /// 3 - Check that the proven block ranges are the certified block ranges of the message
+    ///
+    /// 4 - Check that all the transactions of each certified block range are proven
+    ///
+    /// If every check is okay, the result of the search is returned.
+    pub fn verify(
+        &self,
+    ) -> Result<CardanoTransactionsSearchResult, VerifyCardanoTransactionsSearchProofsError> {
+        let set_proof: CardanoTransactionsSetProof = self
+            .certified_transactions
+            .clone()
+            .ok_or(VerifyCardanoTransactionsSearchProofsError::NoCertifiedBlockRange)?
+            .try_into()
+            .map_err(VerifyCardanoTransactionsSearchProofsError::MalformedData)?;
+
+        set_proof.verify().map_err(|e| {
+            VerifyCardanoTransactionsSearchProofsError::InvalidSetProof {
+                transactions_hashes: set_proof.transactions_hashes().to_vec(),
+                source: e,
+            }
+        })?;
+
+        let proven_block_ranges = set_proof
+            .block_ranges()
+            .into_iter()
+            .map(|block_range| (*block_range).clone())
+            .collect::<Vec<_>>();
+        if proven_block_ranges != self.certified_block_ranges {
+            return Err(VerifyCardanoTransactionsSearchProofsError::NonMatchingBlockRanges);
+        }
+
+        set_proof
+            .verify_block_ranges_completeness()
+            .map_err(VerifyCardanoTransactionsSearchProofsError::IncompleteBlockRanges)?;
+
+        Ok(CardanoTransactionsSearchResult {
+            certificate_hash: self.certificate_hash.clone(),
+            merkle_root: set_proof.merkle_root(),
+            certified_block_ranges: self.certified_block_ranges.clone(),
+            claimed_matching_transactions: set_proof.transactions_hashes().to_vec(),
+            latest_block_number: self.latest_block_number,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::crypto_helper::MKTreeStoreInMemory;
+
+    use super::*;
+
+    fn leaves() -> Vec<(BlockNumber, TransactionHash)> {
+        vec![
+            (BlockNumber(0), "tx-1".to_string()),
+            (BlockNumber(1), "tx-2".to_string()),
+            (BlockNumber(20), "tx-3".to_string()),
+            (BlockNumber(22), "tx-4".to_string()),
+        ]
+    }
+
+    fn block_ranges() -> Vec<BlockRange> {
+        vec![
+            BlockRange::from_block_number(BlockNumber(0)),
+            BlockRange::from_block_number(BlockNumber(20)),
+        ]
+    }
+
+    fn matching_set_proof(
+        leaves: &[(BlockNumber, TransactionHash)],
+        proven_hashes: &[TransactionHash],
+        matching_hashes: &[TransactionHash],
+    ) -> CardanoTransactionsSetProofMessagePart {
+        let set_proof = CardanoTransactionsSetProof::from_subset_of_leaves::<MKTreeStoreInMemory>(
+            leaves,
+            proven_hashes,
+        )
+        .unwrap();
+        let mut message_part: CardanoTransactionsSetProofMessagePart =
+            set_proof.try_into().unwrap();
+        message_part.transactions_hashes = matching_hashes.to_vec();
+
+        message_part
+    }
+
+    #[test]
+    fn verify_valid_and_complete_proof() {
+        let all_hashes = leaves().into_iter().map(|(_, h)| h).collect::<Vec<_>>();
+        let matching_hashes = vec!["tx-2".to_string(), "tx-3".to_string()];
+        let message = CardanoTransactionsSearchProofsMessage::new(
+            "whatever",
+            CardanoTransactionFilter::MetadataLabel(674),
+            block_ranges(),
+            Some(matching_set_proof(&leaves(), &all_hashes, &matching_hashes)),
+            BlockNumber(99999),
+        );
+
+        let search_result = message
+            .verify()
+            .expect("Valid and complete proof should verify itself");
+
+        assert_eq!("whatever", search_result.certificate_hash());
+        assert_eq!(
+            block_ranges()
+                .into_iter()
+                .map(|block_range| (*block_range).clone())
+                .collect::<Vec<_>>(),
+            search_result.certified_block_ranges()
+        );
+        assert_eq!(
+            matching_hashes,
+            search_result.claimed_matching_transactions()
+        );
+    }
+
+    #[test]
+    fn search_result_fill_the_protocol_message_with_the_merkle_root_of_the_proof() {
+        let all_hashes = leaves().into_iter().map(|(_, h)| h).collect::<Vec<_>>();
+        let set_proof = matching_set_proof(&leaves(), &all_hashes, &[]);
+        let expected_merkle_root = CardanoTransactionsSetProof::try_from(set_proof.clone())
+            .unwrap()
+            .merkle_root();
+        let message = CardanoTransactionsSearchProofsMessage::new(
+            "whatever",
+            CardanoTransactionFilter::MetadataLabel(674),
+            block_ranges(),
+            Some(set_proof),
+            BlockNumber(99999),
+        );
+
+        let search_result = message.verify().unwrap();
+        let mut protocol_message = ProtocolMessage::new();
+        search_result.fill_protocol_message(&mut protocol_message);
+
+        assert_eq!(
+            Some(&expected_merkle_root),
+            protocol_message
+                .get_message_part(&ProtocolMessagePartKey::CardanoTransactionsMerkleRoot)
+        );
+        assert_eq!(
+            Some(&"99999".to_string()),
+            protocol_message.get_message_part(&ProtocolMessagePartKey::LatestBlockNumber)
+        );
+    }
+
+    #[test]
+    fn verify_without_certified_block_range_fail() {
+        let message = CardanoTransactionsSearchProofsMessage::new(
+            "whatever",
+            CardanoTransactionFilter::MetadataLabel(674),
+            vec![],
+            None,
+            BlockNumber(99999),
+        );
+
+        let error = message
+            .verify()
+            .expect_err("Proof without certified block range should fail to verify itself");
+        assert!(
+            matches!(
+                error,
+                VerifyCardanoTransactionsSearchProofsError::NoCertifiedBlockRange
+            ),
+            "Expected 'NoCertifiedBlockRange' error but got '{:?}'",
+            error
+        );
+    }
+
+    #[test]
+    fn verify_proof_with_matching_transaction_not_in_proof_fail() {
+        let all_hashes = leaves().into_iter().map(|(_, h)| h).collect::<Vec<_>>();
+        let message = CardanoTransactionsSearchProofsMessage::new(
+            "whatever",
+            CardanoTransactionFilter::MetadataLabel(674),
+            block_ranges(),
+            Some(matching_set_proof(
+                &leaves(),
+                &all_hashes,
+                &["tx-not-in-proof".to_string()],
+            )),
+            BlockNumber(99999),
+        );
+
+        let error = message
+            .verify()
+            .expect_err("Proof not containing a matching transaction should fail to verify");
+        assert!(
+            matches!(
+                error,
+                VerifyCardanoTransactionsSearchProofsError::InvalidSetProof { .. }
+            ),
+            "Expected 'InvalidSetProof' error but got '{:?}'",
+            error
+        );
+    }
+
+    #[test]
+    fn verify_proof_with_non_matching_block_ranges_fail() {
+        let all_hashes = leaves().into_iter().map(|(_, h)| h).collect::<Vec<_>>();
+        let message = CardanoTransactionsSearchProofsMessage::new(
+            "whatever",
+            CardanoTransactionFilter::MetadataLabel(674),
+            block_ranges()[..1].to_vec(),
+            Some(matching_set_proof(&leaves(), &all_hashes, &[])),
+            BlockNumber(99999),
+        );
+
+        let error = message
+            .verify()
+            .expect_err("Proof with non matching block ranges should fail to verify");
+        assert!(
+            matches!(
+                error,
+                VerifyCardanoTransactionsSearchProofsError::NonMatchingBlockRanges
+            ),
+            "Expected 'NonMatchingBlockRanges' error but got '{:?}'",
+            error
+        );
+    }
+
+    #[test]
+    fn verify_proof_with_incomplete_block_range_fail() {
+        let message = CardanoTransactionsSearchProofsMessage::new(
+            "whatever",
+            CardanoTransactionFilter::MetadataLabel(674),
+            block_ranges(),
+            Some(matching_set_proof(
+                &leaves(),
+                &["tx-1".to_string(), "tx-2".to_string(), "tx-3".to_string()],
+                &["tx-3".to_string()],
+            )),
+            BlockNumber(99999),
+        );
+
+        let error = message
+            .verify()
+            .expect_err("Proof missing a transaction of a block range should fail to verify");
+        assert!(
+            matches!(
+                error,
+                VerifyCardanoTransactionsSearchProofsError::IncompleteBlockRanges(_)
+            ),
+            "Expected 'IncompleteBlockRanges' error but got '{:?}'",
+            error
+        );
+    }
+}
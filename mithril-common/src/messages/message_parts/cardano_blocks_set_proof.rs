@@ -0,0 +1,47 @@
+use crate::{
+    crypto_helper::ProtocolMkProof,
+    entities::{CardanoBlock, CardanoBlocksSetProof, HexEncodedKey},
+    StdError,
+};
+use serde::{Deserialize, Serialize};
+
+/// A cryptographic proof that a set of Cardano blocks is included in the global Cardano blocks set
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct CardanoBlocksSetProofMessagePart {
+    /// Certified blocks
+    pub blocks: Vec<CardanoBlock>,
+
+    /// Proof of the blocks
+    pub proof: HexEncodedKey,
+}
+
+impl CardanoBlocksSetProofMessagePart {
+    cfg_test_tools! {
+        /// Retrieve a dummy proof (for test only)
+        pub fn dummy() -> Self {
+            CardanoBlocksSetProof::dummy().try_into().unwrap()
+        }
+    }
+}
+
+impl TryFrom<CardanoBlocksSetProof> for CardanoBlocksSetProofMessagePart {
+    type Error = StdError;
+
+    fn try_from(proof: CardanoBlocksSetProof) -> Result<Self, Self::Error> {
+        Ok(Self {
+            blocks: proof.blocks,
+            proof: proof.blocks_proof.to_json_hex()?,
+        })
+    }
+}
+
+impl TryFrom<CardanoBlocksSetProofMessagePart> for CardanoBlocksSetProof {
+    type Error = StdError;
+
+    fn try_from(proof: CardanoBlocksSetProofMessagePart) -> Result<Self, Self::Error> {
+        Ok(Self {
+            blocks: proof.blocks,
+            blocks_proof: ProtocolMkProof::from_json_hex(&proof.proof)?,
+        })
+    }
+}
@@ -2,15 +2,19 @@
 //! This module aims at providing shared structures for API communications.
 mod aggregator_features;
 mod aggregator_status;
+mod cardano_blocks_proof;
 mod cardano_database;
 mod cardano_database_digest_list;
 mod cardano_database_immutable_files_restored;
 mod cardano_database_list;
+mod cardano_protocol_parameters;
+mod cardano_protocol_parameters_list;
 mod cardano_stake_distribution;
 mod cardano_stake_distribution_list;
 mod cardano_transaction_snapshot;
 mod cardano_transaction_snapshot_list;
 mod cardano_transactions_proof;
+mod cardano_transactions_search_proof;
 mod certificate;
 mod certificate_list;
 mod epoch_settings;
@@ -28,6 +32,9 @@ pub use aggregator_features::{
     AggregatorCapabilities, AggregatorFeaturesMessage, CardanoTransactionsProverCapabilities,
 };
 pub use aggregator_status::AggregatorStatusMessage;
+pub use cardano_blocks_proof::{
+    CardanoBlocksProofsMessage, VerifiedCardanoBlocks, VerifyCardanoBlocksProofsError,
+};
 pub use cardano_database::{
     AncillaryMessagePart, CardanoDatabaseSnapshotMessage, DigestsMessagePart, ImmutablesMessagePart,
 };
@@ -38,6 +45,10 @@ pub use cardano_database_immutable_files_restored::CardanoDatabaseImmutableFiles
 pub use cardano_database_list::{
     CardanoDatabaseSnapshotListItemMessage, CardanoDatabaseSnapshotListMessage,
 };
+pub use cardano_protocol_parameters::CardanoProtocolParametersMessage;
+pub use cardano_protocol_parameters_list::{
+    CardanoProtocolParametersListItemMessage, CardanoProtocolParametersListMessage,
+};
 pub use cardano_stake_distribution::CardanoStakeDistributionMessage;
 pub use cardano_stake_distribution_list::{
     CardanoStakeDistributionListItemMessage, CardanoStakeDistributionListMessage,
@@ -50,6 +61,10 @@ pub use cardano_transactions_proof::{
     CardanoTransactionsProofsMessage, VerifiedCardanoTransactions,
     VerifyCardanoTransactionsProofsError,
 };
+pub use cardano_transactions_search_proof::{
+    CardanoTransactionsSearchProofsMessage, CardanoTransactionsSearchResult,
+    VerifyCardanoTransactionsSearchProofsError,
+};
 pub use certificate::CertificateMessage;
 pub use certificate_list::{
     CertificateListItemMessage, CertificateListItemMessageMetadata, CertificateListMessage,
@@ -8,7 +8,7 @@ mod multi_signer;
 mod signer_builder;
 mod single_signer;
 
-pub use multi_signer::MultiSigner;
+pub use multi_signer::{MultiSigner, SingleSignaturesAggregator};
 pub use signer_builder::{SignerBuilder, SignerBuilderError};
 pub use single_signer::SingleSigner;
 
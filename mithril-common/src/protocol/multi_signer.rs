@@ -1,12 +1,16 @@
+use std::collections::BTreeMap;
+
 use anyhow::{anyhow, Context};
+use mithril_stm::aggregate_proof::AggregateSignature;
 use mithril_stm::stm::StmParameters;
 
 use crate::{
     crypto_helper::{
-        ProtocolAggregateVerificationKey, ProtocolAggregationError, ProtocolClerk,
-        ProtocolMultiSignature,
+        ProtocolAggregateVerificationKey, ProtocolAggregationError, ProtocolAggregationReport,
+        ProtocolClerk, ProtocolIncrementalAggregator, ProtocolMultiSignature,
+        ProtocolVerifiedSingleSignature,
     },
-    entities::SingleSignature,
+    entities::{PartyId, SingleSignature},
     protocol::ToMessage,
     StdResult,
 };
@@ -38,7 +42,18 @@ impl MultiSigner {
 
         self.protocol_clerk
             .aggregate(&protocol_signatures, message.to_message().as_bytes())
-            .map(|multi_sig| multi_sig.into())
+            .map(|multi_sig| AggregateSignature::from(multi_sig).into())
+    }
+
+    /// Create an aggregator of the single signatures of the given message, verifying each single
+    /// signature once, when it's added
+    pub fn incremental_aggregator<T: ToMessage>(&self, message: &T) -> SingleSignaturesAggregator {
+        SingleSignaturesAggregator {
+            protocol_aggregator: self
+                .protocol_clerk
+                .incremental_aggregator(message.to_message().as_bytes()),
+            party_ids: BTreeMap::new(),
+        }
     }
 
     /// Compute aggregate verification key from stake distribution
@@ -87,6 +102,81 @@ impl MultiSigner {
     }
 }
 
+/// SingleSignaturesAggregator aggregates the single signatures of a message as they are received
+pub struct SingleSignaturesAggregator {
+    protocol_aggregator: ProtocolIncrementalAggregator,
+    party_ids: BTreeMap<u64, PartyId>,
+}
+
+impl SingleSignaturesAggregator {
+    /// Verify a single signature and add it to the aggregation
+    pub fn add_single_signature(&mut self, single_signature: &SingleSignature) -> StdResult<()> {
+        let verified_single_signature = self.verify_single_signature(single_signature)?;
+
+        self.add_verified_single_signature(verified_single_signature)
+    }
+
+    /// Verify a single signature without adding it to the aggregation, it can be added
+    /// afterward with [Self::add_verified_single_signature]
+    pub fn verify_single_signature(
+        &mut self,
+        single_signature: &SingleSignature,
+    ) -> StdResult<ProtocolVerifiedSingleSignature> {
+        let protocol_signature = single_signature.to_protocol_signature();
+        self.party_ids
+            .entry(protocol_signature.signer_index)
+            .or_insert_with(|| single_signature.party_id.clone());
+
+        self.protocol_aggregator
+            .verify_signature(&protocol_signature)
+            .with_context(|| {
+                format!(
+                    "Invalid signature for party: '{}'",
+                    single_signature.party_id
+                )
+            })
+    }
+
+    /// Add a single signature verified by [Self::verify_single_signature] to the aggregation
+    pub fn add_verified_single_signature(
+        &mut self,
+        verified_single_signature: ProtocolVerifiedSingleSignature,
+    ) -> StdResult<()> {
+        self.protocol_aggregator
+            .add_verified_signature(verified_single_signature)
+            .with_context(|| "Could not add a verified single signature to the aggregation")
+    }
+
+    /// Check if a single signature of the signer of the given single signature was already
+    /// added, whether it was valid or not
+    pub fn has_signature_of_signer(&self, single_signature: &SingleSignature) -> bool {
+        self.party_ids
+            .contains_key(&single_signature.signature.signer_index)
+    }
+
+    /// Check if the added single signatures reach the quorum
+    pub fn is_quorum_reached(&self) -> bool {
+        self.protocol_aggregator.is_quorum_reached()
+    }
+
+    /// Aggregate the added single signatures into a multi-signature
+    pub fn aggregate(&self) -> Result<ProtocolMultiSignature, ProtocolAggregationError> {
+        self.protocol_aggregator
+            .aggregate()
+            .map(|multi_sig| AggregateSignature::from(multi_sig).into())
+    }
+
+    /// Build the diagnostic report of the aggregation of the added single signatures
+    pub fn aggregation_report(&self) -> ProtocolAggregationReport {
+        self.protocol_aggregator.aggregation_report()
+    }
+
+    /// Get the party id of the signer of an added single signature from its signer index
+    pub fn get_party_id(&self, signer_index: u64) -> Option<&PartyId> {
+        self.party_ids.get(&signer_index)
+    }
+}
+
 #[cfg(test)]
 mod test {
     use mithril_stm::StmSignatureError;
@@ -164,6 +254,123 @@ mod test {
             .expect("Multi-signature should be created even with one invalid signature");
     }
 
+    #[test]
+    fn incremental_aggregation_gives_the_same_multi_signature_as_aggregating_all_signatures() {
+        let fixture = MithrilFixtureBuilder::default().with_signers(10).build();
+        let multi_signer = build_multi_signer(&fixture);
+        let message = ProtocolMessage::default();
+        let signatures = fixture.sign_all(&message);
+
+        let mut aggregator = multi_signer.incremental_aggregator(&message);
+        for signature in &signatures {
+            aggregator.add_single_signature(signature).unwrap();
+        }
+
+        assert!(aggregator.is_quorum_reached());
+        assert_eq!(
+            multi_signer
+                .aggregate_single_signatures(&signatures, &message)
+                .unwrap()
+                .to_json_hex()
+                .unwrap(),
+            aggregator.aggregate().unwrap().to_json_hex().unwrap()
+        );
+    }
+
+    #[test]
+    fn incremental_aggregation_rejects_signature_of_another_message() {
+        let fixture = MithrilFixtureBuilder::default().with_signers(1).build();
+        let multi_signer = build_multi_signer(&fixture);
+        let mut signed_message = ProtocolMessage::default();
+        signed_message.set_message_part(
+            ProtocolMessagePartKey::SnapshotDigest,
+            "a_digest".to_string(),
+        );
+        let single_signature = fixture
+            .signers_fixture()
+            .first()
+            .unwrap()
+            .sign(&signed_message)
+            .unwrap();
+
+        let mut aggregator = multi_signer.incremental_aggregator(&ProtocolMessage::default());
+        aggregator
+            .add_single_signature(&single_signature)
+            .expect_err("Adding a signature of another message should fail");
+
+        assert!(!aggregator.is_quorum_reached());
+    }
+
+    #[test]
+    fn incremental_aggregation_knows_the_signers_of_the_added_signatures() {
+        let fixture = MithrilFixtureBuilder::default().with_signers(5).build();
+        let multi_signer = build_multi_signer(&fixture);
+        let message = ProtocolMessage::default();
+        let signatures = fixture.sign_all(&message);
+
+        let mut aggregator = multi_signer.incremental_aggregator(&message);
+        aggregator.add_single_signature(&signatures[0]).unwrap();
+
+        assert!(aggregator.has_signature_of_signer(&signatures[0]));
+        assert!(!aggregator.has_signature_of_signer(&signatures[1]));
+    }
+
+    #[test]
+    fn incremental_aggregation_only_aggregates_the_verified_signatures_once_added() {
+        let fixture = MithrilFixtureBuilder::default().with_signers(10).build();
+        let multi_signer = build_multi_signer(&fixture);
+        let message = ProtocolMessage::default();
+        let signatures = fixture.sign_all(&message);
+
+        let mut aggregator = multi_signer.incremental_aggregator(&message);
+        let verified_signatures: Vec<_> = signatures
+            .iter()
+            .map(|signature| aggregator.verify_single_signature(signature).unwrap())
+            .collect();
+        assert!(!aggregator.is_quorum_reached());
+
+        for verified_signature in verified_signatures {
+            aggregator
+                .add_verified_single_signature(verified_signature)
+                .unwrap();
+        }
+        assert!(aggregator.is_quorum_reached());
+    }
+
+    #[test]
+    fn incremental_aggregation_report_the_rejected_signatures() {
+        let fixture = MithrilFixtureBuilder::default().with_signers(3).build();
+        let multi_signer = build_multi_signer(&fixture);
+        let message = ProtocolMessage::default();
+        let mut other_message = ProtocolMessage::default();
+        other_message.set_message_part(
+            ProtocolMessagePartKey::SnapshotDigest,
+            "a_digest".to_string(),
+        );
+        let signers_fixture = fixture.signers_fixture();
+        let valid_signature = signers_fixture[0].sign(&message).unwrap();
+        let signature_of_other_message = signers_fixture[1].sign(&other_message).unwrap();
+
+        let mut aggregator = multi_signer.incremental_aggregator(&message);
+        aggregator.add_single_signature(&valid_signature).unwrap();
+        aggregator
+            .add_single_signature(&signature_of_other_message)
+            .expect_err("Adding a signature of another message should fail");
+        let report = aggregator.aggregation_report();
+
+        assert_eq!(1, report.valid_signatures_count());
+        assert_eq!(1, report.rejected_signatures_count());
+        let rejected_signature = report
+            .signatures
+            .iter()
+            .find(|signature| !signature.status.is_valid())
+            .unwrap();
+        assert_eq!(
+            Some(&signature_of_other_message.party_id),
+            aggregator.get_party_id(rejected_signature.signer_index)
+        );
+    }
+
     #[test]
     fn verify_single_signature_fail_if_signature_signer_isnt_in_the_registered_parties() {
         let multi_signer = build_multi_signer(
@@ -47,19 +47,24 @@ impl SignerBuilder {
             .collect::<ProtocolStakeDistribution>();
         let mut key_registration = ProtocolKeyRegistration::init(&stake_distribution);
 
+        let mut certified_signers = Vec::with_capacity(registered_signers.len());
         for signer in registered_signers {
-            key_registration
-                .register(
+            let party_id = key_registration
+                .certify(
                     Some(signer.party_id.to_owned()),
                     signer.operational_certificate.clone(),
                     signer.verification_key_signature,
                     signer.kes_period,
-                    signer.verification_key,
+                    &signer.verification_key,
                 )
                 .with_context(|| {
                     format!("Registration failed for signer: '{}'", signer.party_id)
                 })?;
+            certified_signers.push((party_id, signer.verification_key.clone()));
         }
+        key_registration
+            .register_all(&certified_signers)
+            .with_context(|| "Registration of the signers failed")?;
 
         let closed_registration = key_registration.close();
 
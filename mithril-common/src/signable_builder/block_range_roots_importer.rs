@@ -0,0 +1,218 @@
+use std::mem;
+use std::ops::Range;
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use slog::{debug, Logger};
+
+use crate::crypto_helper::{MKTree, MKTreeNode, MKTreeStoreInMemory};
+use crate::entities::{BlockNumber, BlockRange};
+use crate::logging::LoggerExtensions;
+use crate::StdResult;
+
+#[cfg(test)]
+use mockall::automock;
+
+/// Store of the leaves of the block ranges Merkle trees, and of the Merkle roots of these trees
+#[cfg_attr(test, automock)]
+#[async_trait]
+pub trait BlockRangeLeavesStore<L>: Send + Sync {
+    /// Get the highest stored block range root bounds
+    async fn get_highest_block_range(&self) -> StdResult<Option<BlockRange>>;
+
+    /// Get the leaves in an interval of blocks
+    async fn get_leaves_in_range(&self, range: Range<BlockNumber>) -> StdResult<Vec<L>>;
+
+    /// Store list of block ranges with the Merkle root of their leaves
+    async fn store_block_range_roots(
+        &self,
+        block_ranges: Vec<(BlockRange, MKTreeNode)>,
+    ) -> StdResult<()>;
+}
+
+/// Compute and store the Merkle roots of the block ranges of leaves of type `L`, such as the
+/// Cardano transactions or the Cardano blocks.
+///
+/// The computation resumes after the highest stored block range root, block ranges without
+/// leaves have no root.
+pub struct BlockRangeRootsImporter<L> {
+    leaves_store: Arc<dyn BlockRangeLeavesStore<L>>,
+    logger: Logger,
+}
+
+impl<L> BlockRangeRootsImporter<L>
+where
+    L: Into<MKTreeNode> + Clone + Send + Sync,
+{
+    /// Number of computed block range roots stored at once
+    const STORE_BATCH_SIZE: usize = 100;
+
+    /// Constructor
+    pub fn new(leaves_store: Arc<dyn BlockRangeLeavesStore<L>>, logger: Logger) -> Self {
+        Self {
+            leaves_store,
+            logger: logger.new_with_component_name::<Self>(),
+        }
+    }
+
+    /// Compute and store the roots of the block ranges strictly contained up to the given block
+    /// number
+    pub async fn import(&self, until: BlockNumber) -> StdResult<()> {
+        let block_ranges = match self.leaves_store.get_highest_block_range().await?.map(
+            |highest_stored_block_range| {
+                BlockRange::all_block_ranges_in(
+                    BlockRange::start(highest_stored_block_range.end)..=(until),
+                )
+            },
+        ) {
+            // No block range root stored yet, start from the beginning
+            None => BlockRange::all_block_ranges_in(BlockNumber(0)..=(until)),
+            // Not enough block to form at least one block range
+            Some(ranges) if ranges.is_empty() => return Ok(()),
+            Some(ranges) => ranges,
+        };
+
+        debug!(
+            self.logger, "Computing Block Range Roots";
+            "start_block" => *block_ranges.start(), "end_block" => *block_ranges.end(),
+        );
+
+        let mut block_ranges_with_merkle_root: Vec<(BlockRange, MKTreeNode)> = vec![];
+        for block_range in block_ranges {
+            let leaves = self
+                .leaves_store
+                .get_leaves_in_range(block_range.start..block_range.end)
+                .await?;
+
+            if leaves.is_empty() {
+                continue;
+            }
+
+            let merkle_root = MKTree::<MKTreeStoreInMemory>::new(&leaves)?.compute_root()?;
+            block_ranges_with_merkle_root.push((block_range, merkle_root));
+
+            if block_ranges_with_merkle_root.len() >= Self::STORE_BATCH_SIZE {
+                self.leaves_store
+                    .store_block_range_roots(mem::take(&mut block_ranges_with_merkle_root))
+                    .await?;
+            }
+        }
+
+        self.leaves_store
+            .store_block_range_roots(block_ranges_with_merkle_root)
+            .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use mockall::predicate::eq;
+
+    use crate::test_utils::TestLogger;
+
+    use super::*;
+
+    fn leaves_of_range(range: Range<BlockNumber>) -> Vec<String> {
+        (*range.start..*range.end)
+            .map(|block_number| format!("leaf-{block_number}"))
+            .collect()
+    }
+
+    fn merkle_root_of(range: Range<BlockNumber>) -> MKTreeNode {
+        MKTree::<MKTreeStoreInMemory>::new(&leaves_of_range(range))
+            .unwrap()
+            .compute_root()
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn compute_the_roots_of_the_block_ranges_after_the_highest_stored_one() {
+        let mut leaves_store = MockBlockRangeLeavesStore::<String>::new();
+        leaves_store
+            .expect_get_highest_block_range()
+            .returning(|| Ok(Some(BlockRange::from_block_number(BlockNumber(0)))));
+        leaves_store
+            .expect_get_leaves_in_range()
+            .returning(|range| Ok(leaves_of_range(range)));
+        leaves_store
+            .expect_store_block_range_roots()
+            .with(eq(vec![
+                (
+                    BlockRange::from_block_number(BlockRange::LENGTH),
+                    merkle_root_of(BlockRange::LENGTH..BlockRange::LENGTH * 2),
+                ),
+                (
+                    BlockRange::from_block_number(BlockRange::LENGTH * 2),
+                    merkle_root_of(BlockRange::LENGTH * 2..BlockRange::LENGTH * 3),
+                ),
+            ]))
+            .returning(|_| Ok(()))
+            .once();
+        let importer = BlockRangeRootsImporter::new(Arc::new(leaves_store), TestLogger::stdout());
+
+        // The last block range is not complete, its root is not computed
+        importer.import(BlockRange::LENGTH * 3 + 1).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn block_ranges_without_leaves_have_no_root() {
+        let mut leaves_store = MockBlockRangeLeavesStore::<String>::new();
+        leaves_store
+            .expect_get_highest_block_range()
+            .returning(|| Ok(None));
+        leaves_store
+            .expect_get_leaves_in_range()
+            .returning(|range| match range.start {
+                BlockNumber(0) => Ok(vec![]),
+                _ => Ok(leaves_of_range(range)),
+            });
+        leaves_store
+            .expect_store_block_range_roots()
+            .with(eq(vec![(
+                BlockRange::from_block_number(BlockRange::LENGTH),
+                merkle_root_of(BlockRange::LENGTH..BlockRange::LENGTH * 2),
+            )]))
+            .returning(|_| Ok(()))
+            .once();
+        let importer = BlockRangeRootsImporter::new(Arc::new(leaves_store), TestLogger::stdout());
+
+        importer.import(BlockRange::LENGTH * 2).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn nothing_is_computed_if_there_is_not_enough_blocks_to_form_a_new_block_range() {
+        let mut leaves_store = MockBlockRangeLeavesStore::<String>::new();
+        leaves_store
+            .expect_get_highest_block_range()
+            .returning(|| Ok(Some(BlockRange::from_block_number(BlockNumber(0)))));
+        leaves_store.expect_get_leaves_in_range().never();
+        leaves_store.expect_store_block_range_roots().never();
+        let importer = BlockRangeRootsImporter::new(Arc::new(leaves_store), TestLogger::stdout());
+
+        importer.import(BlockRange::LENGTH * 2 - 1).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn computed_roots_are_stored_by_batches() {
+        let mut leaves_store = MockBlockRangeLeavesStore::<String>::new();
+        leaves_store
+            .expect_get_highest_block_range()
+            .returning(|| Ok(None));
+        leaves_store
+            .expect_get_leaves_in_range()
+            .returning(|range| Ok(leaves_of_range(range)));
+        leaves_store
+            .expect_store_block_range_roots()
+            .withf(|block_ranges| block_ranges.len() == 100)
+            .returning(|_| Ok(()))
+            .once();
+        leaves_store
+            .expect_store_block_range_roots()
+            .withf(|block_ranges| block_ranges.len() == 5)
+            .returning(|_| Ok(()))
+            .once();
+        let importer = BlockRangeRootsImporter::new(Arc::new(leaves_store), TestLogger::stdout());
+
+        importer.import(BlockRange::LENGTH * 105).await.unwrap();
+    }
+}
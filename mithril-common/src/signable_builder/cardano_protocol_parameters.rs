@@ -0,0 +1,199 @@
+use anyhow::{anyhow, Context};
+use std::sync::Arc;
+
+use async_trait::async_trait;
+
+use crate::{
+    chain_observer::ChainObserver,
+    entities::{
+        CardanoProtocolParameters, Epoch, LedgerProtocolParameters, ProtocolMessage,
+        ProtocolMessagePartKey,
+    },
+    signable_builder::SignableBuilder,
+    StdResult,
+};
+
+#[cfg(test)]
+use mockall::automock;
+
+/// Cardano Protocol Parameters Retriever
+#[cfg_attr(test, automock)]
+#[async_trait]
+pub trait CardanoProtocolParametersRetriever: Send + Sync {
+    /// Retrieve the [LedgerProtocolParameters] in effect at the given epoch
+    async fn retrieve(&self, epoch: Epoch) -> StdResult<Option<LedgerProtocolParameters>>;
+}
+
+/// A [CardanoProtocolParametersRetriever] that reads the protocol parameters from a [ChainObserver].
+///
+/// The chain observer only knows the protocol parameters of the current epoch, retrieving the
+/// protocol parameters of another epoch fails.
+pub struct ChainObserverProtocolParametersRetriever {
+    chain_observer: Arc<dyn ChainObserver>,
+}
+
+impl ChainObserverProtocolParametersRetriever {
+    /// Constructor
+    pub fn new(chain_observer: Arc<dyn ChainObserver>) -> Self {
+        Self { chain_observer }
+    }
+}
+
+#[async_trait]
+impl CardanoProtocolParametersRetriever for ChainObserverProtocolParametersRetriever {
+    async fn retrieve(&self, epoch: Epoch) -> StdResult<Option<LedgerProtocolParameters>> {
+        let current_epoch = self
+            .chain_observer
+            .get_current_epoch()
+            .await
+            .with_context(|| {
+                "ChainObserverProtocolParametersRetriever can not get current epoch"
+            })?;
+        if current_epoch != Some(epoch) {
+            return Err(anyhow!(
+                "ChainObserverProtocolParametersRetriever can only read the protocol parameters of the current epoch: '{current_epoch:?}', requested epoch: '{epoch}'"
+            ));
+        }
+
+        let protocol_parameters = self
+            .chain_observer
+            .get_current_protocol_parameters()
+            .await
+            .with_context(|| {
+                "ChainObserverProtocolParametersRetriever can not get current protocol parameters"
+            })?;
+
+        Ok(protocol_parameters)
+    }
+}
+
+/// A [CardanoProtocolParametersSignableBuilder] builder
+pub struct CardanoProtocolParametersSignableBuilder {
+    cardano_protocol_parameters_retriever: Arc<dyn CardanoProtocolParametersRetriever>,
+}
+
+impl CardanoProtocolParametersSignableBuilder {
+    /// Constructor
+    pub fn new(
+        cardano_protocol_parameters_retriever: Arc<dyn CardanoProtocolParametersRetriever>,
+    ) -> Self {
+        Self {
+            cardano_protocol_parameters_retriever,
+        }
+    }
+}
+
+#[async_trait]
+impl SignableBuilder<Epoch> for CardanoProtocolParametersSignableBuilder {
+    async fn compute_protocol_message(&self, epoch: Epoch) -> StdResult<ProtocolMessage> {
+        let protocol_parameters = self
+            .cardano_protocol_parameters_retriever
+            .retrieve(epoch)
+            .await?
+            .ok_or(anyhow!(
+                "CardanoProtocolParametersSignableBuilder could not find the protocol parameters for epoch: '{epoch}'"
+            ))?;
+
+        let cardano_protocol_parameters =
+            CardanoProtocolParameters::new(epoch, protocol_parameters);
+
+        let mut protocol_message = ProtocolMessage::new();
+        protocol_message.set_message_part(
+            ProtocolMessagePartKey::CardanoProtocolParametersEpoch,
+            epoch.to_string(),
+        );
+        protocol_message.set_message_part(
+            ProtocolMessagePartKey::CardanoProtocolParametersHash,
+            cardano_protocol_parameters.hash,
+        );
+
+        Ok(protocol_message)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use mockall::predicate::eq;
+
+    use crate::chain_observer::FakeObserver;
+    use crate::entities::TimePoint;
+
+    use super::*;
+
+    #[tokio::test]
+    async fn compute_protocol_message_returns_error_when_no_protocol_parameters_found() {
+        let epoch = Epoch(1);
+
+        let mut protocol_parameters_retriever = MockCardanoProtocolParametersRetriever::new();
+        protocol_parameters_retriever
+            .expect_retrieve()
+            .return_once(move |_| Ok(None));
+        let signable_builder =
+            CardanoProtocolParametersSignableBuilder::new(Arc::new(protocol_parameters_retriever));
+
+        signable_builder
+            .compute_protocol_message(epoch)
+            .await
+            .expect_err("Should return an error when no protocol parameters found");
+    }
+
+    #[tokio::test]
+    async fn compute_protocol_message_set_epoch_and_hash_of_the_protocol_parameters() {
+        let epoch = Epoch(6);
+        let protocol_parameters = LedgerProtocolParameters::dummy();
+        let expected_hash = CardanoProtocolParameters::new(epoch, protocol_parameters.clone()).hash;
+
+        let mut protocol_parameters_retriever = MockCardanoProtocolParametersRetriever::new();
+        protocol_parameters_retriever
+            .expect_retrieve()
+            .with(eq(epoch))
+            .return_once(move |_| Ok(Some(protocol_parameters)));
+        let signable_builder =
+            CardanoProtocolParametersSignableBuilder::new(Arc::new(protocol_parameters_retriever));
+
+        let signable = signable_builder
+            .compute_protocol_message(epoch)
+            .await
+            .unwrap();
+
+        let mut signable_expected = ProtocolMessage::new();
+        signable_expected.set_message_part(
+            ProtocolMessagePartKey::CardanoProtocolParametersEpoch,
+            epoch.to_string(),
+        );
+        signable_expected.set_message_part(
+            ProtocolMessagePartKey::CardanoProtocolParametersHash,
+            expected_hash,
+        );
+        assert_eq!(signable_expected, signable);
+    }
+
+    #[tokio::test]
+    async fn chain_observer_retriever_returns_protocol_parameters_of_the_current_epoch() {
+        let time_point = TimePoint {
+            epoch: Epoch(12),
+            ..TimePoint::dummy()
+        };
+        let chain_observer = FakeObserver::new(Some(time_point));
+        let retriever = ChainObserverProtocolParametersRetriever::new(Arc::new(chain_observer));
+
+        let protocol_parameters = retriever.retrieve(Epoch(12)).await.unwrap();
+
+        assert_eq!(Some(LedgerProtocolParameters::dummy()), protocol_parameters);
+    }
+
+    #[tokio::test]
+    async fn chain_observer_retriever_fails_for_another_epoch_than_the_current_one() {
+        let time_point = TimePoint {
+            epoch: Epoch(12),
+            ..TimePoint::dummy()
+        };
+        let chain_observer = FakeObserver::new(Some(time_point));
+        let retriever = ChainObserverProtocolParametersRetriever::new(Arc::new(chain_observer));
+
+        retriever
+            .retrieve(Epoch(11))
+            .await
+            .expect_err("Retrieving the protocol parameters of a past epoch should fail");
+    }
+}
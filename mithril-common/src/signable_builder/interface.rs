@@ -3,9 +3,9 @@ use std::fmt::Debug;
 
 use crate::{
     entities::{
-        BlockNumber, CardanoDatabaseSnapshot, CardanoDbBeacon, CardanoStakeDistribution,
-        CardanoTransactionsSnapshot, Epoch, MithrilStakeDistribution, ProtocolMessage,
-        ProtocolMessagePartValue, Snapshot,
+        BlockNumber, CardanoBlocksSnapshot, CardanoDatabaseSnapshot, CardanoDbBeacon,
+        CardanoProtocolParameters, CardanoStakeDistribution, CardanoTransactionsSnapshot, Epoch,
+        MithrilStakeDistribution, ProtocolMessage, ProtocolMessagePartValue, Snapshot,
     },
     StdResult,
 };
@@ -54,6 +54,13 @@ impl Beacon for CardanoDbBeacon {}
 
 impl Beacon for Epoch {}
 
+#[cfg_attr(not(target_family = "wasm"), typetag::serde)]
+impl Artifact for CardanoBlocksSnapshot {
+    fn get_id(&self) -> String {
+        self.hash.clone()
+    }
+}
+
 #[cfg_attr(not(target_family = "wasm"), typetag::serde)]
 impl Artifact for CardanoDatabaseSnapshot {
     fn get_id(&self) -> String {
@@ -61,6 +68,13 @@ impl Artifact for CardanoDatabaseSnapshot {
     }
 }
 
+#[cfg_attr(not(target_family = "wasm"), typetag::serde)]
+impl Artifact for CardanoProtocolParameters {
+    fn get_id(&self) -> String {
+        self.hash.clone()
+    }
+}
+
 #[cfg_attr(not(target_family = "wasm"), typetag::serde)]
 impl Artifact for CardanoStakeDistribution {
     fn get_id(&self) -> String {
@@ -1,11 +1,13 @@
 //! The module used for building signables
 
+mod cardano_protocol_parameters;
 mod cardano_stake_distribution;
 mod interface;
 mod mithril_stake_distribution;
 mod signable_builder_service;
 mod signed_entity;
 
+pub use cardano_protocol_parameters::*;
 pub use cardano_stake_distribution::*;
 pub use interface::*;
 pub use mithril_stake_distribution::*;
@@ -13,10 +15,14 @@ pub use signable_builder_service::*;
 pub use signed_entity::*;
 
 cfg_fs! {
+    mod block_range_roots_importer;
+    mod cardano_blocks;
     mod cardano_database;
     mod cardano_immutable_full_signable_builder;
     mod cardano_transactions;
 
+    pub use block_range_roots_importer::*;
+    pub use cardano_blocks::*;
     pub use cardano_database::*;
     pub use cardano_immutable_full_signable_builder::*;
     pub use cardano_transactions::*;
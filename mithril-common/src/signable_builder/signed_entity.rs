@@ -2,14 +2,14 @@ use chrono::{DateTime, Utc};
 
 use super::Artifact;
 use crate::entities::{
-    CardanoStakeDistribution, CardanoTransactionsSnapshot, MithrilStakeDistribution,
-    SignedEntityType, Snapshot,
+    CardanoBlocksSnapshot, CardanoProtocolParameters, CardanoStakeDistribution,
+    CardanoTransactionsSnapshot, MithrilStakeDistribution, SignedEntityType, Snapshot,
 };
 #[cfg(any(test, feature = "test_tools"))]
 use crate::test_utils::fake_data;
 
 #[cfg(any(test, feature = "test_tools"))]
-use crate::entities::{CardanoDbBeacon, Epoch};
+use crate::entities::{CardanoDbBeacon, Epoch, LedgerProtocolParameters};
 
 /// Aggregate for signed entity
 #[derive(Debug, Clone)]
@@ -85,6 +85,24 @@ impl SignedEntity<CardanoTransactionsSnapshot> {
     }
 }
 
+impl SignedEntity<CardanoBlocksSnapshot> {
+    cfg_test_tools! {
+        /// Create a dummy [SignedEntity] for [CardanoBlocksSnapshot] entity
+        pub fn dummy() -> Self {
+            let block_number = crate::entities::BlockNumber(50);
+            SignedEntity {
+                signed_entity_id: "cardano-blocks-id-123".to_string(),
+                signed_entity_type: SignedEntityType::CardanoBlocks(Epoch(5), block_number),
+                certificate_id: "certificate-hash-123".to_string(),
+                artifact: CardanoBlocksSnapshot::new("mkroot123".to_string(), block_number),
+                created_at: DateTime::parse_from_rfc3339("2023-01-19T13:43:05.618857482Z")
+                    .unwrap()
+                    .with_timezone(&Utc),
+            }
+        }
+    }
+}
+
 impl SignedEntity<CardanoStakeDistribution> {
     cfg_test_tools! {
         /// Create a dummy [SignedEntity] for [CardanoStakeDistribution] entity
@@ -101,3 +119,21 @@ impl SignedEntity<CardanoStakeDistribution> {
         }
     }
 }
+
+impl SignedEntity<CardanoProtocolParameters> {
+    cfg_test_tools! {
+        /// Create a dummy [SignedEntity] for [CardanoProtocolParameters] entity
+        pub fn dummy() -> Self {
+            let epoch = Epoch(1);
+            SignedEntity {
+                signed_entity_id: "cardano-protocol-parameters-id-123".to_string(),
+                signed_entity_type: SignedEntityType::CardanoProtocolParameters(epoch),
+                certificate_id: "certificate-hash-123".to_string(),
+                artifact: CardanoProtocolParameters::new(epoch, LedgerProtocolParameters::dummy()),
+                created_at: DateTime::parse_from_rfc3339("2024-07-29T16:15:05.618857482Z")
+                    .unwrap()
+                    .with_timezone(&Utc),
+            }
+        }
+    }
+}
@@ -392,6 +392,7 @@ mod test {
     use super::*;
     use ed25519_dalek::VerifyingKey;
     use kes_summed_ed25519::kes::Sum6KesSig;
+    use mithril_stm::aggregate_proof::AggregateSignature;
     use mithril_stm::stm::{StmAggrSig, StmAggrVerificationKey, StmSig, StmVerificationKeyPoP};
     use serde::{de::DeserializeOwned, Serialize};
     use std::any::type_name;
@@ -458,8 +459,9 @@ mod test {
     #[test]
     fn assert_encoded_multi_signatures_are_still_matching_concrete_type() {
         assert_can_deserialize_using_key_decode_hex::<StmAggrSig<D>>(&multi_signature());
+        assert_can_deserialize_using_key_decode_hex::<AggregateSignature<D>>(&multi_signature());
 
-        assert_can_convert_to_protocol_key::<StmAggrSig<D>>(&multi_signature());
+        assert_can_convert_to_protocol_key::<AggregateSignature<D>>(&multi_signature());
     }
 
     #[test]
@@ -106,7 +106,9 @@ impl TickerService for MithrilTickerService {
 mod tests {
     use crate::chain_observer::{ChainAddress, ChainObserver, ChainObserverError, TxDatum};
     use crate::digesters::DumbImmutableFileObserver;
-    use crate::entities::{BlockNumber, ChainPoint, Epoch, SlotNumber, StakeDistribution};
+    use crate::entities::{
+        BlockNumber, ChainPoint, Epoch, LedgerProtocolParameters, SlotNumber, StakeDistribution,
+    };
     use anyhow::anyhow;
 
     use super::*;
@@ -145,6 +147,14 @@ mod tests {
                 "this should not be called in the TimePointProvider"
             )))
         }
+
+        async fn get_current_protocol_parameters(
+            &self,
+        ) -> Result<Option<LedgerProtocolParameters>, ChainObserverError> {
+            Err(ChainObserverError::General(anyhow!(
+                "this should not be called in the TimePointProvider"
+            )))
+        }
     }
 
     #[tokio::test]
@@ -3,11 +3,12 @@ use config::{ConfigError, Map, Source, Value};
 use mithril_doc::{Documenter, DocumenterDefault, StructDoc};
 use serde::{Deserialize, Serialize};
 use std::{path::PathBuf, sync::Arc};
+use zeroize::Zeroizing;
 
 use mithril_cli_helper::register_config_value;
 use mithril_common::{
     chain_observer::ChainObserver,
-    crypto_helper::tests_setup,
+    crypto_helper::{tests_setup, ProtocolInitializerEncryptionKey},
     entities::{BlockNumber, PartyId},
     era::{
         adapters::{EraReaderAdapterBuilder, EraReaderAdapterType},
@@ -78,6 +79,12 @@ pub struct Configuration {
     /// File path to the operational certificate of the pool
     pub operational_certificate_path: Option<PathBuf>,
 
+    /// File path to the hex encoded 32 bytes key used to encrypt the protocol initializers
+    /// stored in the database.
+    ///
+    /// If not set, the protocol initializers are stored unencrypted.
+    pub protocol_initializer_encryption_key_path: Option<PathBuf>,
+
     /// Disable immutables digests cache.
     pub disable_digests_cache: bool,
 
@@ -111,6 +118,11 @@ pub struct Configuration {
     /// `[default: true]`.
     pub enable_transaction_pruning: bool,
 
+    /// If set, the Cardano blocks are read from the completed immutable files of the
+    /// [db_directory][Self::db_directory] instead of the chain synchronization with the Cardano
+    /// node `[default: false]`.
+    pub read_blocks_from_immutable_files: bool,
+
     /// Chunk size for importing transactions, combined with transaction pruning it reduces the
     /// storage footprint of the signer by reducing the number of transactions stored on disk
     /// at any given time.
@@ -147,6 +159,7 @@ impl Configuration {
             operational_certificate_path: signer_temp_dir
                 .as_ref()
                 .map(|dir| dir.join("opcert.cert")),
+            protocol_initializer_encryption_key_path: None,
             disable_digests_cache: false,
             reset_digests_cache: false,
             era_reader_adapter_type: EraReaderAdapterType::Bootstrap,
@@ -156,6 +169,7 @@ impl Configuration {
             metrics_server_port: 9090,
             allow_unparsable_block: false,
             enable_transaction_pruning: false,
+            read_blocks_from_immutable_files: false,
             transactions_import_block_chunk_size: BlockNumber(1000),
             cardano_transactions_block_streamer_max_roll_forwards_per_poll: 1000,
             preloading_refresh_interval_in_seconds: 60,
@@ -172,6 +186,32 @@ impl Configuration {
         })
     }
 
+    /// Read the key used to encrypt the protocol initializers, if a key file is configured.
+    pub fn get_protocol_initializer_encryption_key(
+        &self,
+    ) -> StdResult<Option<ProtocolInitializerEncryptionKey>> {
+        let Some(key_path) = &self.protocol_initializer_encryption_key_path else {
+            return Ok(None);
+        };
+
+        let key_hex = Zeroizing::new(std::fs::read_to_string(key_path).with_context(|| {
+            format!(
+                "Could not read protocol initializer encryption key file '{}'.",
+                key_path.display()
+            )
+        })?);
+        let key_bytes = Zeroizing::new(hex::decode(key_hex.trim()).with_context(|| {
+            format!(
+                "Could not decode protocol initializer encryption key file '{}', expected an hex encoded key.",
+                key_path.display()
+            )
+        })?);
+        let key = ProtocolInitializerEncryptionKey::from_key_bytes(&key_bytes)
+            .with_context(|| "Invalid protocol initializer encryption key.")?;
+
+        Ok(Some(key))
+    }
+
     /// Create the SQL store directory if not exist and return the path of the
     /// SQLite3 file.
     pub fn get_sqlite_file(&self, sqlite_file_name: &str) -> StdResult<PathBuf> {
@@ -226,6 +266,9 @@ pub struct DefaultConfiguration {
     /// Transaction pruning toggle
     pub enable_transaction_pruning: bool,
 
+    /// Read the Cardano blocks from the immutable files toggle
+    pub read_blocks_from_immutable_files: bool,
+
     /// Preload security parameter
     pub preload_security_parameter: u64,
 
@@ -251,6 +294,7 @@ impl Default for DefaultConfiguration {
             network_security_parameter: 2160, // 2160 is the mainnet value
             preload_security_parameter: 1000,
             enable_transaction_pruning: true,
+            read_blocks_from_immutable_files: false,
             transactions_import_block_chunk_size: 1500,
             cardano_transactions_block_streamer_max_roll_forwards_per_poll: 10000,
         }
@@ -273,6 +317,7 @@ impl Source for DefaultConfiguration {
         register_config_value!(result, &namespace, myself.network_security_parameter);
         register_config_value!(result, &namespace, myself.preload_security_parameter);
         register_config_value!(result, &namespace, myself.enable_transaction_pruning);
+        register_config_value!(result, &namespace, myself.read_blocks_from_immutable_files);
         register_config_value!(
             result,
             &namespace,
@@ -126,6 +126,24 @@ alter table new_protocol_initializer rename to protocol_initializer;
             r#"
 insert into signed_entity_type (signed_entity_type_id, name) 
     values  (4, 'Cardano Database');
+"#,
+        ),
+        // Migration 8
+        // Add the `signed_entity_type` record for 'CardanoBlocks'
+        SqlMigration::new(
+            8,
+            r#"
+insert into signed_entity_type (signed_entity_type_id, name)
+    values  (5, 'Cardano Blocks');
+"#,
+        ),
+        // Migration 9
+        // Add the `signed_entity_type` record for 'CardanoProtocolParameters'
+        SqlMigration::new(
+            9,
+            r#"
+insert into signed_entity_type (signed_entity_type_id, name)
+    values  (6, 'Cardano Protocol Parameters');
 "#,
         ),
     ]
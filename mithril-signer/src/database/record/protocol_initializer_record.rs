@@ -1,16 +1,31 @@
 use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
 
 use mithril_common::{crypto_helper::ProtocolInitializer, entities::Epoch};
 use mithril_persistence::sqlite::{HydrationError, Projection, SqLiteEntity};
 
+/// Protocol initializer as stored in the database.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum StoredProtocolInitializer {
+    /// Protocol initializer encrypted with the signer encryption key
+    Encrypted {
+        /// Hex encoded bytes of the encrypted protocol initializer
+        encrypted_protocol_initializer: String,
+    },
+
+    /// Unencrypted protocol initializer, stored when no encryption key is configured
+    Plain(ProtocolInitializer),
+}
+
 /// Protocol initializer.
 #[derive(Debug)]
 pub struct ProtocolInitializerRecord {
     /// Epoch
     pub epoch: Epoch,
 
-    /// Protocol Initializer
-    pub protocol_initializer: ProtocolInitializer,
+    /// Protocol Initializer, encrypted if the signer has an encryption key
+    pub protocol_initializer: StoredProtocolInitializer,
 
     /// DateTime of the record creation.
     pub created_at: DateTime<Utc>,
@@ -26,10 +41,10 @@ impl SqLiteEntity for ProtocolInitializerRecord {
         let datetime = &row.read::<&str, _>(2);
 
         let record = Self {
+            // The stored value is not included in the error since it may hold the secret key
             protocol_initializer: serde_json::from_str(protocol).map_err(|e| {
                 HydrationError::InvalidData(format!(
-                    "Could not cast string ({}) to ProtocolInitializer. Error: '{e}'",
-                    protocol
+                    "Could not cast string to ProtocolInitializer. Error: '{e}'"
                 ))
             })?,
             epoch: Epoch(epoch_int.try_into().map_err(|e| {
@@ -1,17 +1,21 @@
 use std::sync::Arc;
 
-use anyhow::Ok;
+use anyhow::{anyhow, Context, Ok};
 use async_trait::async_trait;
 
 use crate::database::query::{
     DeleteProtocolInitializerQuery, InsertOrReplaceProtocolInitializerQuery,
 };
-use crate::database::record::ProtocolInitializerRecord;
+use crate::database::record::{ProtocolInitializerRecord, StoredProtocolInitializer};
 use crate::{
     database::query::GetProtocolInitializerQuery, services::EpochPruningTask,
     store::ProtocolInitializerStorer,
 };
-use mithril_common::{crypto_helper::ProtocolInitializer, entities::Epoch, StdResult};
+use mithril_common::{
+    crypto_helper::{ProtocolInitializer, ProtocolInitializerEncryptionKey},
+    entities::Epoch,
+    StdResult,
+};
 use mithril_persistence::sqlite::ConnectionExtensions;
 use mithril_persistence::{sqlite::SqliteConnection /*store::adapter::StoreAdapter*/};
 
@@ -19,6 +23,7 @@ use mithril_persistence::{sqlite::SqliteConnection /*store::adapter::StoreAdapte
 pub struct ProtocolInitializerRepository {
     connection: Arc<SqliteConnection>,
     retention_limit: Option<u64>,
+    encryption_key: Option<ProtocolInitializerEncryptionKey>,
 }
 
 impl ProtocolInitializerRepository {
@@ -27,7 +32,82 @@ impl ProtocolInitializerRepository {
         Self {
             connection,
             retention_limit,
+            encryption_key: None,
+        }
+    }
+
+    /// Encrypt the saved protocol initializers with the given key.
+    ///
+    /// Protocol initializers saved without encryption can still be read.
+    pub fn with_encryption_key(mut self, encryption_key: ProtocolInitializerEncryptionKey) -> Self {
+        self.encryption_key = Some(encryption_key);
+        self
+    }
+
+    fn to_stored(
+        &self,
+        protocol_initializer: ProtocolInitializer,
+    ) -> StdResult<StoredProtocolInitializer> {
+        match &self.encryption_key {
+            Some(key) => {
+                let encrypted_bytes =
+                    protocol_initializer.to_encrypted_bytes(key, &mut rand_core::OsRng)?;
+
+                Ok(StoredProtocolInitializer::Encrypted {
+                    encrypted_protocol_initializer: hex::encode(encrypted_bytes),
+                })
+            }
+            None => Ok(StoredProtocolInitializer::Plain(protocol_initializer)),
+        }
+    }
+
+    fn from_stored(&self, stored: StoredProtocolInitializer) -> StdResult<ProtocolInitializer> {
+        match stored {
+            StoredProtocolInitializer::Encrypted {
+                encrypted_protocol_initializer,
+            } => {
+                let key = self.encryption_key.as_ref().ok_or(anyhow!(
+                    "The protocol initializer is encrypted but no encryption key is configured"
+                ))?;
+                let encrypted_bytes = hex::decode(encrypted_protocol_initializer)
+                    .with_context(|| "Could not decode the encrypted protocol initializer")?;
+
+                ProtocolInitializer::from_encrypted_bytes(&encrypted_bytes, key)
+            }
+            StoredProtocolInitializer::Plain(protocol_initializer) => Ok(protocol_initializer),
+        }
+    }
+
+    /// Read the protocol initializer of a record.
+    ///
+    /// A protocol initializer stored without encryption is encrypted in place if an encryption
+    /// key is configured.
+    fn load(&self, record: ProtocolInitializerRecord) -> StdResult<ProtocolInitializer> {
+        let is_plain = matches!(
+            record.protocol_initializer,
+            StoredProtocolInitializer::Plain(_)
+        );
+        let protocol_initializer = self.from_stored(record.protocol_initializer)?;
+
+        if is_plain && self.encryption_key.is_some() {
+            let encrypted_record = ProtocolInitializerRecord {
+                epoch: record.epoch,
+                protocol_initializer: self.to_stored(protocol_initializer.clone())?,
+                created_at: record.created_at,
+            };
+            self.connection
+                .apply(InsertOrReplaceProtocolInitializerQuery::one(
+                    encrypted_record,
+                )?)
+                .with_context(|| {
+                    format!(
+                        "Could not encrypt the stored protocol initializer of epoch '{}'",
+                        record.epoch
+                    )
+                })?;
         }
+
+        Ok(protocol_initializer)
     }
 }
 
@@ -58,7 +138,7 @@ impl ProtocolInitializerStorer for ProtocolInitializerRepository {
         let previous_protocol_initializer = self.get_protocol_initializer(epoch).await?;
         let record = ProtocolInitializerRecord {
             epoch,
-            protocol_initializer: protocol_initializer.clone(),
+            protocol_initializer: self.to_stored(protocol_initializer)?,
             created_at: chrono::Utc::now(),
         };
         self.connection
@@ -75,7 +155,7 @@ impl ProtocolInitializerStorer for ProtocolInitializerRepository {
             .connection
             .fetch_first(GetProtocolInitializerQuery::for_epoch(epoch))?;
 
-        Ok(record.map(|record| record.protocol_initializer))
+        record.map(|record| self.load(record)).transpose()
     }
 
     async fn get_last_protocol_initializer(
@@ -86,9 +166,9 @@ impl ProtocolInitializerStorer for ProtocolInitializerRepository {
             .connection
             .fetch_collect(GetProtocolInitializerQuery::last_n(last))?;
 
-        Ok(record
-            .iter()
-            .map(|record| (record.epoch, record.protocol_initializer.to_owned()))
-            .collect())
+        record
+            .into_iter()
+            .map(|record| Ok((record.epoch, self.load(record)?)))
+            .collect()
     }
 }
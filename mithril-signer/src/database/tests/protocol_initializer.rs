@@ -1,7 +1,10 @@
 use std::sync::Arc;
 
 use mithril_common::test_utils::fake_data;
-use mithril_common::{crypto_helper::ProtocolInitializer, entities::Epoch};
+use mithril_common::{
+    crypto_helper::{ProtocolInitializer, ProtocolInitializerEncryptionKey},
+    entities::Epoch,
+};
 use mithril_persistence::sqlite::{ConnectionBuilder, ConnectionExtensions};
 
 use crate::database::repository::ProtocolInitializerRepository;
@@ -173,6 +176,125 @@ mod pruning {
     }
 }
 
+mod encryption {
+    use mithril_persistence::sqlite::SqliteConnection;
+
+    use super::*;
+
+    fn encryption_key() -> ProtocolInitializerEncryptionKey {
+        ProtocolInitializerEncryptionKey::generate(&mut rand_core::OsRng)
+    }
+
+    fn stored_protocol(connection: &SqliteConnection, epoch: Epoch) -> String {
+        connection
+            .query_single_cell(
+                "select protocol from protocol_initializer where epoch = ?",
+                &[sqlite::Value::Integer(epoch.try_into().unwrap())],
+            )
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn protocol_initializer_is_stored_encrypted_with_an_encryption_key() {
+        let connection = Arc::new(main_db_connection().unwrap());
+        let (epoch, protocol_initializer) = setup_protocol_initializers(1).remove(0);
+        let store = ProtocolInitializerRepository::new(connection.clone(), None)
+            .with_encryption_key(encryption_key());
+
+        store
+            .save_protocol_initializer(epoch, protocol_initializer.clone())
+            .await
+            .unwrap();
+
+        let stored_protocol = stored_protocol(&connection, epoch);
+        assert!(stored_protocol.contains("encrypted_protocol_initializer"));
+        assert!(!stored_protocol.contains("stm_initializer"));
+
+        let res = store
+            .get_protocol_initializer(epoch)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(
+            protocol_initializer.to_bytes(),
+            res.to_bytes(),
+            "Decrypted protocol initializer should be the saved one"
+        );
+    }
+
+    #[tokio::test]
+    async fn unencrypted_protocol_initializer_can_be_read_with_an_encryption_key() {
+        let connection = Arc::new(main_db_connection().unwrap());
+        let values = setup_protocol_initializers(2);
+        store_protocol_initializers(
+            &ProtocolInitializerRepository::new(connection.clone(), None),
+            &values,
+        )
+        .await;
+
+        let store = ProtocolInitializerRepository::new(connection.clone(), None)
+            .with_encryption_key(encryption_key());
+        let res = store.get_last_protocol_initializer(2).await.unwrap();
+
+        assert_eq!(2, res.len());
+        assert_eq!(values[1].1.to_bytes(), res[0].1.to_bytes());
+    }
+
+    #[tokio::test]
+    async fn unencrypted_protocol_initializer_is_encrypted_when_read_with_an_encryption_key() {
+        let connection = Arc::new(main_db_connection().unwrap());
+        let values = setup_protocol_initializers(1);
+        let (epoch, protocol_initializer) = &values[0];
+        store_protocol_initializers(
+            &ProtocolInitializerRepository::new(connection.clone(), None),
+            &values,
+        )
+        .await;
+        assert!(stored_protocol(&connection, *epoch).contains("stm_initializer"));
+
+        let encryption_key = encryption_key();
+        ProtocolInitializerRepository::new(connection.clone(), None)
+            .with_encryption_key(encryption_key.clone())
+            .get_protocol_initializer(*epoch)
+            .await
+            .unwrap();
+
+        let stored_protocol = stored_protocol(&connection, *epoch);
+        assert!(stored_protocol.contains("encrypted_protocol_initializer"));
+        assert!(!stored_protocol.contains("stm_initializer"));
+
+        let res = ProtocolInitializerRepository::new(connection.clone(), None)
+            .with_encryption_key(encryption_key)
+            .get_protocol_initializer(*epoch)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(protocol_initializer.to_bytes(), res.to_bytes());
+    }
+
+    #[tokio::test]
+    async fn encrypted_protocol_initializer_cannot_be_read_without_its_encryption_key() {
+        let connection = Arc::new(main_db_connection().unwrap());
+        let values = setup_protocol_initializers(1);
+        store_protocol_initializers(
+            &ProtocolInitializerRepository::new(connection.clone(), None)
+                .with_encryption_key(encryption_key()),
+            &values,
+        )
+        .await;
+
+        ProtocolInitializerRepository::new(connection.clone(), None)
+            .get_protocol_initializer(values[0].0)
+            .await
+            .expect_err("Reading an encrypted protocol initializer without key should fail");
+        ProtocolInitializerRepository::new(connection.clone(), None)
+            .with_encryption_key(encryption_key())
+            .get_protocol_initializer(values[0].0)
+            .await
+            .expect_err("Reading an encrypted protocol initializer with another key should fail");
+    }
+}
+
 mod migration {
     use super::*;
 
@@ -11,26 +11,32 @@ use mithril_common::cardano_block_scanner::CardanoBlockScanner;
 use mithril_common::chain_observer::{
     CardanoCliRunner, ChainObserver, ChainObserverBuilder, ChainObserverType,
 };
-use mithril_common::chain_reader::PallasChainReader;
-use mithril_common::crypto_helper::{OpCert, ProtocolPartyId, SerDeShelleyFileFormat};
-use mithril_common::digesters::cache::{
-    ImmutableFileDigestCacheProvider, JsonImmutableFileDigestCacheProviderBuilder,
+use mithril_common::chain_reader::{
+    ChainBlockReader, ImmutableFilesChainReader, PallasChainReader,
 };
+use mithril_common::crypto_helper::{
+    MKTreeStoreSqlite, OpCert, ProtocolPartyId, SerDeShelleyFileFormat,
+};
+use mithril_common::digesters::cache::ImmutableFileDigestCacheProvider;
 use mithril_common::digesters::{
     CardanoImmutableDigester, ImmutableFileObserver, ImmutableFileSystemObserver,
 };
 use mithril_common::era::{EraChecker, EraReader};
 use mithril_common::signable_builder::{
-    CardanoDatabaseSignableBuilder, CardanoImmutableFilesFullSignableBuilder,
+    CardanoBlocksSignableBuilder, CardanoDatabaseSignableBuilder,
+    CardanoImmutableFilesFullSignableBuilder, CardanoProtocolParametersSignableBuilder,
     CardanoStakeDistributionSignableBuilder, CardanoTransactionsSignableBuilder,
-    MithrilSignableBuilderService, MithrilStakeDistributionSignableBuilder,
-    SignableBuilderServiceDependencies,
+    ChainObserverProtocolParametersRetriever, MithrilSignableBuilderService,
+    MithrilStakeDistributionSignableBuilder, SignableBuilderServiceDependencies,
 };
 use mithril_common::{MithrilTickerService, StdResult, TickerService};
 use mithril_signed_entity_lock::SignedEntityTypeLock;
 use mithril_signed_entity_preloader::CardanoTransactionsPreloader;
 
-use mithril_persistence::database::repository::CardanoTransactionRepository;
+use mithril_persistence::database::repository::{
+    CardanoBlockRepository, CardanoTransactionRepository,
+    SqliteImmutableFileDigestCacheProviderBuilder,
+};
 use mithril_persistence::database::{ApplicationNodeType, SqlMigration};
 use mithril_persistence::sqlite::{ConnectionBuilder, SqliteConnection, SqliteConnectionPool};
 
@@ -45,10 +51,9 @@ use crate::services::{
     SignerSignedEntityConfigProvider, SignerUpkeepService, TransactionsImporterByChunk,
     TransactionsImporterWithPruner, TransactionsImporterWithVacuum,
 };
-use crate::store::MKTreeStoreSqlite;
 use crate::{
     Configuration, MetricsService, HTTP_REQUEST_TIMEOUT_DURATION, SQLITE_FILE,
-    SQLITE_FILE_CARDANO_TRANSACTION,
+    SQLITE_FILE_CARDANO_TRANSACTION, SQLITE_FILE_IMMUTABLE_FILE_DIGESTS_CACHE,
 };
 
 /// The `DependenciesBuilder` is intended to manage Services instance creation.
@@ -158,11 +163,19 @@ impl<'a> DependenciesBuilder<'a> {
             return Ok(None);
         }
 
-        let cache_provider = JsonImmutableFileDigestCacheProviderBuilder::new(
-            &self.config.data_stores_directory,
-            &format!("immutables_digests_{}.json", self.config.network),
+        // Digests previously cached in a JSON file are imported in the SQLite cache
+        let json_cache_file = self
+            .config
+            .data_stores_directory
+            .join(format!("immutables_digests_{}.json", self.config.network));
+        let cache_provider = SqliteImmutableFileDigestCacheProviderBuilder::new(
+            &self
+                .config
+                .get_sqlite_file(SQLITE_FILE_IMMUTABLE_FILE_DIGESTS_CACHE)?,
         )
+        .with_json_cache_to_migrate(&json_cache_file)
         .should_reset_digests_cache(self.config.reset_digests_cache)
+        .with_node_type(ApplicationNodeType::Signer)
         .with_logger(self.root_logger())
         .build()
         .await?;
@@ -215,10 +228,16 @@ impl<'a> DependenciesBuilder<'a> {
 
         let signed_entity_type_lock = Arc::new(SignedEntityTypeLock::default());
 
-        let protocol_initializer_store = Arc::new(ProtocolInitializerRepository::new(
-            sqlite_connection.clone(),
-            self.config.store_retention_limit.map(|limit| limit as u64),
-        ));
+        let protocol_initializer_store = {
+            let repository = ProtocolInitializerRepository::new(
+                sqlite_connection.clone(),
+                self.config.store_retention_limit.map(|limit| limit as u64),
+            );
+            match self.config.get_protocol_initializer_encryption_key()? {
+                Some(encryption_key) => Arc::new(repository.with_encryption_key(encryption_key)),
+                None => Arc::new(repository),
+            }
+        };
 
         let digester = Arc::new(CardanoImmutableDigester::new(
             network.to_string(),
@@ -273,13 +292,21 @@ impl<'a> DependenciesBuilder<'a> {
         let transaction_store = Arc::new(CardanoTransactionRepository::new(
             sqlite_connection_cardano_transaction_pool.clone(),
         ));
-        let chain_block_reader = PallasChainReader::new(
-            &self.config.cardano_node_socket_path,
-            network,
-            self.root_logger(),
-        );
+        let chain_block_reader: Arc<Mutex<dyn ChainBlockReader>> =
+            if self.config.read_blocks_from_immutable_files {
+                Arc::new(Mutex::new(ImmutableFilesChainReader::new(
+                    &self.config.db_directory,
+                    self.root_logger(),
+                )))
+            } else {
+                Arc::new(Mutex::new(PallasChainReader::new(
+                    &self.config.cardano_node_socket_path,
+                    network,
+                    self.root_logger(),
+                )))
+            };
         let block_scanner = Arc::new(CardanoBlockScanner::new(
-            Arc::new(Mutex::new(chain_block_reader)),
+            chain_block_reader,
             self.config
                 .cardano_transactions_block_streamer_max_roll_forwards_per_poll,
             self.root_logger(),
@@ -322,9 +349,16 @@ impl<'a> DependenciesBuilder<'a> {
         let cardano_transactions_builder = Arc::new(CardanoTransactionsSignableBuilder::<
             MKTreeStoreSqlite,
         >::new(
-            state_machine_transactions_importer,
+            state_machine_transactions_importer.clone(),
             block_range_root_retriever,
         ));
+        let cardano_blocks_builder =
+            Arc::new(CardanoBlocksSignableBuilder::<MKTreeStoreSqlite>::new(
+                state_machine_transactions_importer,
+                Arc::new(CardanoBlockRepository::new(
+                    sqlite_connection_cardano_transaction_pool.clone(),
+                )),
+            ));
         let cardano_stake_distribution_signable_builder = Arc::new(
             CardanoStakeDistributionSignableBuilder::new(stake_store.clone()),
         );
@@ -333,6 +367,10 @@ impl<'a> DependenciesBuilder<'a> {
             &self.config.db_directory,
             self.root_logger(),
         ));
+        let cardano_protocol_parameters_signable_builder =
+            Arc::new(CardanoProtocolParametersSignableBuilder::new(Arc::new(
+                ChainObserverProtocolParametersRetriever::new(chain_observer.clone()),
+            )));
         let epoch_service = Arc::new(RwLock::new(MithrilEpochService::new(
             stake_store.clone(),
             protocol_initializer_store.clone(),
@@ -353,6 +391,8 @@ impl<'a> DependenciesBuilder<'a> {
             cardano_transactions_builder,
             cardano_stake_distribution_signable_builder,
             cardano_database_signable_builder,
+            cardano_blocks_builder,
+            cardano_protocol_parameters_signable_builder,
         );
         let signable_builder_service = Arc::new(MithrilSignableBuilderService::new(
             signable_seed_builder_service,
@@ -30,6 +30,7 @@ const HTTP_REQUEST_TIMEOUT_DURATION: u64 = 30000;
 /// SQLite file names
 const SQLITE_FILE: &str = "signer.sqlite3";
 const SQLITE_FILE_CARDANO_TRANSACTION: &str = "cardano-transaction.sqlite3";
+const SQLITE_FILE_IMMUTABLE_FILE_DIGESTS_CACHE: &str = "immutable-file-digests-cache.sqlite3";
 
 // Memory allocator (to handle properly memory fragmentation)
 #[cfg(all(not(target_env = "msvc"), feature = "jemallocator"))]
@@ -328,7 +328,9 @@ impl Runner for SignerRunner {
 #[cfg(test)]
 mod tests {
     use mithril_common::signable_builder::{
-        CardanoDatabaseSignableBuilder, SignableBuilderServiceDependencies,
+        CardanoBlocksSignableBuilder, CardanoDatabaseSignableBuilder,
+        CardanoProtocolParametersSignableBuilder, ChainObserverProtocolParametersRetriever,
+        SignableBuilderServiceDependencies,
     };
     use mockall::mock;
     use mockall::predicate::eq;
@@ -445,6 +447,10 @@ mod tests {
             transactions_importer.clone(),
             block_range_root_retriever,
         ));
+        let cardano_blocks_builder = Arc::new(CardanoBlocksSignableBuilder::new(
+            transactions_importer.clone(),
+            Arc::new(MockBlockRangeRootRetrieverImpl::<MKTreeStoreInMemory>::new()),
+        ));
         let stake_store = Arc::new(StakePoolStore::new(sqlite_connection.clone(), None));
         let cardano_stake_distribution_builder = Arc::new(
             CardanoStakeDistributionSignableBuilder::new(stake_store.clone()),
@@ -454,6 +460,10 @@ mod tests {
             Path::new(""),
             logger.clone(),
         ));
+        let cardano_protocol_parameters_signable_builder =
+            Arc::new(CardanoProtocolParametersSignableBuilder::new(Arc::new(
+                ChainObserverProtocolParametersRetriever::new(chain_observer.clone()),
+            )));
         let protocol_initializer_store = Arc::new(ProtocolInitializerRepository::new(
             sqlite_connection.clone(),
             None,
@@ -478,6 +488,8 @@ mod tests {
             cardano_transactions_builder,
             cardano_stake_distribution_builder,
             cardano_database_signable_builder,
+            cardano_blocks_builder,
+            cardano_protocol_parameters_signable_builder,
         );
         let signable_builder_service = Arc::new(MithrilSignableBuilderService::new(
             signable_seed_builder_service,
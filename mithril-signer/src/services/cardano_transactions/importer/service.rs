@@ -1,4 +1,4 @@
-use std::mem;
+use std::collections::BTreeMap;
 use std::ops::Range;
 use std::sync::Arc;
 
@@ -8,12 +8,14 @@ use slog::{debug, Logger};
 use tokio::{runtime::Handle, sync::Mutex, task};
 
 use mithril_common::cardano_block_scanner::{BlockScanner, ChainScannedBlocks, RawCardanoPoint};
-use mithril_common::crypto_helper::{MKTree, MKTreeNode, MKTreeStoreInMemory};
+use mithril_common::crypto_helper::MKTreeNode;
 use mithril_common::entities::{
-    BlockNumber, BlockRange, CardanoTransaction, ChainPoint, SlotNumber,
+    BlockNumber, BlockRange, CardanoBlock, CardanoTransaction, ChainPoint, SlotNumber,
 };
 use mithril_common::logging::LoggerExtensions;
-use mithril_common::signable_builder::TransactionsImporter;
+use mithril_common::signable_builder::{
+    BlockRangeLeavesStore, BlockRangeRootsImporter, TransactionsImporter,
+};
 use mithril_common::StdResult;
 
 /// Cardano transactions store
@@ -23,6 +25,9 @@ pub trait TransactionStore: Send + Sync {
     /// Get the highest known transaction beacon
     async fn get_highest_beacon(&self) -> StdResult<Option<ChainPoint>>;
 
+    /// Get the highest known block beacon
+    async fn get_highest_block_beacon(&self) -> StdResult<Option<ChainPoint>>;
+
     /// Get the highest stored block range root bounds
     async fn get_highest_block_range(&self) -> StdResult<Option<BlockRange>>;
 
@@ -41,9 +46,24 @@ pub trait TransactionStore: Send + Sync {
         block_ranges: Vec<(BlockRange, MKTreeNode)>,
     ) -> StdResult<()>;
 
-    /// Remove transactions and block range roots that are in a rolled-back fork
+    /// Store list of blocks
+    async fn store_blocks(&self, blocks: Vec<CardanoBlock>) -> StdResult<()>;
+
+    /// Get blocks in an interval of blocks
+    async fn get_blocks_in_range(&self, range: Range<BlockNumber>) -> StdResult<Vec<CardanoBlock>>;
+
+    /// Get the highest stored blocks block range root bounds
+    async fn get_highest_blocks_block_range(&self) -> StdResult<Option<BlockRange>>;
+
+    /// Store list of block ranges with the merkle root of their blocks
+    async fn store_blocks_block_range_roots(
+        &self,
+        block_ranges: Vec<(BlockRange, MKTreeNode)>,
+    ) -> StdResult<()>;
+
+    /// Remove transactions, blocks and block range roots that are in a rolled-back fork
     ///
-    /// * Remove transactions with slot number strictly greater than the given slot number
+    /// * Remove transactions and blocks with slot number strictly greater than the given slot number
     /// * Remove block range roots that have lower bound range strictly above the given slot number
     async fn remove_rolled_back_transactions_and_block_range(
         &self,
@@ -51,27 +71,41 @@ pub trait TransactionStore: Send + Sync {
     ) -> StdResult<()>;
 }
 
-/// Import and store [CardanoTransaction].
+/// Import and store [CardanoTransaction] and [CardanoBlock].
 #[derive(Clone)]
 pub struct CardanoTransactionsImporter {
     block_scanner: Arc<dyn BlockScanner>,
     transaction_store: Arc<dyn TransactionStore>,
+    transactions_block_range_roots_importer: Arc<BlockRangeRootsImporter<CardanoTransaction>>,
+    blocks_block_range_roots_importer: Arc<BlockRangeRootsImporter<CardanoBlock>>,
     last_polled_point: Arc<Mutex<Option<RawCardanoPoint>>>,
     logger: Logger,
 }
 
 impl CardanoTransactionsImporter {
+    /// Number of block ranges whose blocks are backfilled at once
+    const BACKFILL_BLOCK_RANGES_BATCH_SIZE: u64 = 100;
+
     /// Constructor
     pub fn new(
         block_scanner: Arc<dyn BlockScanner>,
         transaction_store: Arc<dyn TransactionStore>,
         logger: Logger,
     ) -> Self {
+        let logger = logger.new_with_component_name::<Self>();
         Self {
             block_scanner,
+            transactions_block_range_roots_importer: Arc::new(BlockRangeRootsImporter::new(
+                Arc::new(TransactionsLeavesStore(transaction_store.clone())),
+                logger.clone(),
+            )),
+            blocks_block_range_roots_importer: Arc::new(BlockRangeRootsImporter::new(
+                Arc::new(BlocksLeavesStore(transaction_store.clone())),
+                logger.clone(),
+            )),
             transaction_store,
             last_polled_point: Arc::new(Mutex::new(None)),
-            logger: logger.new_with_component_name::<Self>(),
+            logger,
         }
     }
 
@@ -92,8 +126,76 @@ impl CardanoTransactionsImporter {
             .map(RawCardanoPoint::from)))
     }
 
+    /// Get the highest beacon up to which both the transactions and the blocks are stored.
+    ///
+    /// The blocks are missing if the transactions were imported by a previous version: in this
+    /// case they are backfilled from the stored transactions.
+    async fn get_highest_stored_beacon(&self) -> StdResult<Option<ChainPoint>> {
+        let highest_transaction_beacon = self.transaction_store.get_highest_beacon().await?;
+        let highest_block_beacon = self.transaction_store.get_highest_block_beacon().await?;
+
+        Ok(match (highest_transaction_beacon, highest_block_beacon) {
+            (Some(transaction_beacon), Some(block_beacon)) => {
+                if block_beacon.block_number < transaction_beacon.block_number {
+                    Some(block_beacon)
+                } else {
+                    Some(transaction_beacon)
+                }
+            }
+            (None, block_beacon) => block_beacon,
+            (Some(transaction_beacon), None) => {
+                self.backfill_blocks_from_stored_transactions(transaction_beacon.block_number)
+                    .await?;
+                Some(transaction_beacon)
+            }
+        })
+    }
+
+    /// Store the blocks of the transactions stored up to the given block number.
+    ///
+    /// Blocks without any transaction and blocks of the already pruned transactions can't be
+    /// recovered this way, they are only stored for the blocks scanned from now on.
+    async fn backfill_blocks_from_stored_transactions(
+        &self,
+        up_to_block_number: BlockNumber,
+    ) -> StdResult<()> {
+        debug!(
+            self.logger,
+            "Backfilling the blocks from the stored transactions up to block number '{up_to_block_number}'"
+        );
+
+        let mut start = BlockNumber(0);
+        while start <= up_to_block_number {
+            let end = start + BlockRange::LENGTH * Self::BACKFILL_BLOCK_RANGES_BATCH_SIZE;
+            let blocks: BTreeMap<BlockNumber, CardanoBlock> = self
+                .transaction_store
+                .get_transactions_in_range(start..end)
+                .await?
+                .into_iter()
+                .map(|transaction| {
+                    (
+                        transaction.block_number,
+                        CardanoBlock::new(
+                            transaction.block_hash,
+                            transaction.block_number,
+                            transaction.slot_number,
+                        ),
+                    )
+                })
+                .collect();
+            if !blocks.is_empty() {
+                self.transaction_store
+                    .store_blocks(blocks.into_values().collect())
+                    .await?;
+            }
+            start = end;
+        }
+
+        Ok(())
+    }
+
     async fn import_transactions(&self, up_to_beacon: BlockNumber) -> StdResult<()> {
-        let highest_stored_beacon = self.transaction_store.get_highest_beacon().await?;
+        let highest_stored_beacon = self.get_highest_stored_beacon().await?;
         let from = self.start_point(&highest_stored_beacon).await?;
 
         if highest_stored_beacon
@@ -128,11 +230,14 @@ impl CardanoTransactionsImporter {
         while let Some(blocks) = streamer.poll_next().await? {
             match blocks {
                 ChainScannedBlocks::RollForwards(forward_blocks) => {
+                    let parsed_blocks: Vec<CardanoBlock> =
+                        forward_blocks.iter().map(CardanoBlock::from).collect();
                     let parsed_transactions: Vec<CardanoTransaction> = forward_blocks
                         .into_iter()
                         .flat_map(|b| b.into_transactions())
                         .collect();
 
+                    self.transaction_store.store_blocks(parsed_blocks).await?;
                     self.transaction_store
                         .store_transactions(parsed_transactions)
                         .await?;
@@ -153,51 +258,55 @@ impl CardanoTransactionsImporter {
     }
 
     async fn import_block_ranges(&self, until: BlockNumber) -> StdResult<()> {
-        let block_ranges = match self.transaction_store.get_highest_block_range().await?.map(
-            |highest_stored_block_range| {
-                BlockRange::all_block_ranges_in(
-                    BlockRange::start(highest_stored_block_range.end)..=(until),
-                )
-            },
-        ) {
-            // No block range root stored yet, start from the beginning
-            None => BlockRange::all_block_ranges_in(BlockNumber(0)..=(until)),
-            // Not enough block to form at least one block range
-            Some(ranges) if ranges.is_empty() => return Ok(()),
-            Some(ranges) => ranges,
-        };
+        self.transactions_block_range_roots_importer
+            .import(until)
+            .await?;
+        self.blocks_block_range_roots_importer.import(until).await
+    }
+}
 
-        debug!(
-            self.logger, "Computing Block Range Roots";
-            "start_block" => *block_ranges.start(), "end_block" => *block_ranges.end(),
-        );
+/// Leaves of the transactions block ranges, read from a [TransactionStore]
+struct TransactionsLeavesStore(Arc<dyn TransactionStore>);
 
-        let mut block_ranges_with_merkle_root: Vec<(BlockRange, MKTreeNode)> = vec![];
-        for block_range in block_ranges {
-            let transactions = self
-                .transaction_store
-                .get_transactions_in_range(block_range.start..block_range.end)
-                .await?;
+#[async_trait]
+impl BlockRangeLeavesStore<CardanoTransaction> for TransactionsLeavesStore {
+    async fn get_highest_block_range(&self) -> StdResult<Option<BlockRange>> {
+        self.0.get_highest_block_range().await
+    }
 
-            if transactions.is_empty() {
-                continue;
-            }
+    async fn get_leaves_in_range(
+        &self,
+        range: Range<BlockNumber>,
+    ) -> StdResult<Vec<CardanoTransaction>> {
+        self.0.get_transactions_in_range(range).await
+    }
 
-            let merkle_root = MKTree::<MKTreeStoreInMemory>::new(&transactions)?.compute_root()?;
-            block_ranges_with_merkle_root.push((block_range, merkle_root));
+    async fn store_block_range_roots(
+        &self,
+        block_ranges: Vec<(BlockRange, MKTreeNode)>,
+    ) -> StdResult<()> {
+        self.0.store_block_range_roots(block_ranges).await
+    }
+}
 
-            if block_ranges_with_merkle_root.len() >= 100 {
-                let block_ranges_with_merkle_root_save =
-                    mem::take(&mut block_ranges_with_merkle_root);
-                self.transaction_store
-                    .store_block_range_roots(block_ranges_with_merkle_root_save)
-                    .await?;
-            }
-        }
+/// Leaves of the blocks block ranges, read from a [TransactionStore]
+struct BlocksLeavesStore(Arc<dyn TransactionStore>);
 
-        self.transaction_store
-            .store_block_range_roots(block_ranges_with_merkle_root)
-            .await
+#[async_trait]
+impl BlockRangeLeavesStore<CardanoBlock> for BlocksLeavesStore {
+    async fn get_highest_block_range(&self) -> StdResult<Option<BlockRange>> {
+        self.0.get_highest_blocks_block_range().await
+    }
+
+    async fn get_leaves_in_range(&self, range: Range<BlockNumber>) -> StdResult<Vec<CardanoBlock>> {
+        self.0.get_blocks_in_range(range).await
+    }
+
+    async fn store_block_range_roots(
+        &self,
+        block_ranges: Vec<(BlockRange, MKTreeNode)>,
+    ) -> StdResult<()> {
+        self.0.store_blocks_block_range_roots(block_ranges).await
     }
 }
 
@@ -227,9 +336,11 @@ mod tests {
     use mithril_common::cardano_block_scanner::{
         BlockStreamer, DumbBlockScanner, DumbBlockStreamer, ScannedBlock,
     };
-    use mithril_common::crypto_helper::MKTree;
+    use mithril_common::crypto_helper::{MKTree, MKTreeStoreInMemory};
     use mithril_common::entities::{BlockNumber, BlockRangesSequence};
-    use mithril_persistence::database::repository::CardanoTransactionRepository;
+    use mithril_persistence::database::repository::{
+        CardanoBlockRepository, CardanoTransactionRepository,
+    };
     use mithril_persistence::sqlite::SqliteConnectionPool;
 
     use crate::database::test_helper::cardano_tx_db_connection;
@@ -375,6 +486,53 @@ mod tests {
         );
     }
 
+    #[tokio::test]
+    async fn if_nothing_stored_parse_and_store_all_blocks_and_their_block_ranges() {
+        let connection_pool = Arc::new(SqliteConnectionPool::build_from_connection(
+            cardano_tx_db_connection().unwrap(),
+        ));
+        let repository = Arc::new(CardanoTransactionRepository::new(connection_pool.clone()));
+        let block_repository = CardanoBlockRepository::new(connection_pool);
+
+        let up_to_block_number = BlockRange::LENGTH * 2;
+        let blocks = build_blocks(BlockNumber(0), up_to_block_number + 1);
+        let expected_blocks: Vec<CardanoBlock> = blocks.iter().map(CardanoBlock::from).collect();
+
+        let importer = {
+            let mut scanner_mock = MockBlockScannerImpl::new();
+            scanner_mock.expect_scan().return_once(move |_, _| {
+                Ok(Box::new(DumbBlockStreamer::new().forwards(vec![blocks])))
+            });
+            CardanoTransactionsImporter::new_for_test(Arc::new(scanner_mock), repository.clone())
+        };
+
+        importer
+            .import(up_to_block_number)
+            .await
+            .expect("Transactions Importer should succeed");
+
+        let stored_blocks: Vec<CardanoBlock> = block_repository
+            .get_all_blocks()
+            .await
+            .unwrap()
+            .into_iter()
+            .map(Into::into)
+            .collect();
+        assert_eq!(expected_blocks, stored_blocks);
+
+        let blocks_block_range_roots = block_repository.get_all_block_range_root().unwrap();
+        assert_eq!(
+            vec![
+                BlockRange::from_block_number(BlockNumber(0)),
+                BlockRange::from_block_number(BlockRange::LENGTH),
+            ],
+            blocks_block_range_roots
+                .into_iter()
+                .map(|r| r.range)
+                .collect::<Vec<_>>()
+        );
+    }
+
     #[tokio::test]
     async fn if_theres_gap_between_two_stored_block_ranges_it_can_still_compute_their_root() {
         let connection = cardano_tx_db_connection().unwrap();
@@ -471,6 +629,14 @@ mod tests {
             .store_transactions(vec![last_tx.clone()])
             .await
             .unwrap();
+        repository
+            .store_blocks(vec![CardanoBlock::new(
+                hex::encode("block_hash-3"),
+                BlockNumber(30),
+                SlotNumber(35),
+            )])
+            .await
+            .unwrap();
 
         let importer =
             CardanoTransactionsImporter::new_for_test(Arc::new(scanner), repository.clone());
@@ -519,6 +685,10 @@ mod tests {
             .store_transactions(stored_block.clone().into_transactions())
             .await
             .unwrap();
+        repository
+            .store_blocks(vec![CardanoBlock::from(&stored_block)])
+            .await
+            .unwrap();
 
         let importer = {
             let scanned_blocks = vec![to_store_block.clone()];
@@ -550,6 +720,87 @@ mod tests {
         assert_eq!(expected_transactions, stored_transactions);
     }
 
+    #[tokio::test]
+    async fn if_blocks_are_missing_they_are_backfilled_from_the_stored_transactions() {
+        let connection_pool = Arc::new(SqliteConnectionPool::build_from_connection(
+            cardano_tx_db_connection().unwrap(),
+        ));
+        let repository = Arc::new(CardanoTransactionRepository::new(connection_pool.clone()));
+        let block_repository = CardanoBlockRepository::new(connection_pool);
+
+        let stored_blocks = vec![
+            ScannedBlock::new(
+                "block_hash-1",
+                BlockNumber(10),
+                SlotNumber(15),
+                vec!["tx_hash-1", "tx_hash-2"],
+            ),
+            // Further than a backfill batch of block ranges
+            ScannedBlock::new(
+                "block_hash-2",
+                BlockNumber(1510),
+                SlotNumber(1515),
+                vec!["tx_hash-3"],
+            ),
+        ];
+        let highest_stored_chain_point = ChainPoint::new(
+            SlotNumber(1515),
+            BlockNumber(1510),
+            hex::encode("block_hash-2"),
+        );
+        let to_store_block = ScannedBlock::new(
+            "block_hash-3",
+            BlockNumber(1520),
+            SlotNumber(1525),
+            Vec::<&str>::new(),
+        );
+        // Transactions imported by a previous version, without their blocks
+        repository
+            .store_transactions(into_transactions(&stored_blocks))
+            .await
+            .unwrap();
+
+        let importer = {
+            let scanned_blocks = vec![to_store_block.clone()];
+            let mut scanner_mock = MockBlockScannerImpl::new();
+            scanner_mock
+                .expect_scan()
+                .withf(move |from, _until| from == &Some(highest_stored_chain_point.clone().into()))
+                .return_once(move |_, _| {
+                    Ok(Box::new(
+                        DumbBlockStreamer::new().forwards(vec![scanned_blocks]),
+                    ))
+                })
+                .once();
+            CardanoTransactionsImporter::new_for_test(Arc::new(scanner_mock), repository.clone())
+        };
+
+        importer
+            .import_transactions(BlockNumber(1520))
+            .await
+            .expect("Transactions Importer should succeed");
+
+        let blocks: Vec<CardanoBlock> = block_repository
+            .get_all_blocks()
+            .await
+            .unwrap()
+            .into_iter()
+            .map(Into::into)
+            .collect();
+        assert_eq!(
+            [stored_blocks.as_slice(), &[to_store_block]]
+                .concat()
+                .iter()
+                .map(CardanoBlock::from)
+                .collect::<Vec<_>>(),
+            blocks
+        );
+        assert_eq!(
+            into_transactions(&stored_blocks),
+            repository.get_all().await.unwrap()
+        );
+    }
+
     #[tokio::test]
     async fn if_half_block_ranges_are_stored_the_other_half_is_computed_and_stored() {
         let connection = cardano_tx_db_connection().unwrap();
@@ -698,6 +949,14 @@ mod tests {
                     )))
                 })
                 .once();
+            store_mock
+                .expect_get_highest_blocks_block_range()
+                .returning(|| {
+                    Ok(Some(BlockRange::from_block_number(
+                        HIGHEST_BLOCK_RANGE_START,
+                    )))
+                })
+                .once();
             store_mock
                 .expect_get_transactions_in_range()
                 // Lower bound should be the end block number of the last known block range
@@ -707,9 +966,24 @@ mod tests {
                         .contains(range)
                 })
                 .returning(transactions_for_block);
+            store_mock
+                .expect_get_blocks_in_range()
+                .withf(move |range| {
+                    BlockRangesSequence::new(HIGHEST_BLOCK_RANGE_START..=up_to_block_number)
+                        .contains(range)
+                })
+                .returning(|range| {
+                    Ok(build_blocks(range.start, range.end - range.start)
+                        .iter()
+                        .map(CardanoBlock::from)
+                        .collect())
+                });
             store_mock
                 .expect_store_block_range_roots()
                 .returning(|_| Ok(()));
+            store_mock
+                .expect_store_blocks_block_range_roots()
+                .returning(|_| Ok(()));
 
             CardanoTransactionsImporter::new_for_test(
                 Arc::new(MockBlockScannerImpl::new()),
@@ -1175,6 +1449,11 @@ mod tests {
                 Ok(None)
             }
 
+            async fn get_highest_block_beacon(&self) -> StdResult<Option<ChainPoint>> {
+                self.block_thread();
+                Ok(None)
+            }
+
             async fn get_highest_block_range(&self) -> StdResult<Option<BlockRange>> {
                 self.block_thread();
                 Ok(None)
@@ -1201,6 +1480,32 @@ mod tests {
                 Ok(())
             }
 
+            async fn store_blocks(&self, _: Vec<CardanoBlock>) -> StdResult<()> {
+                self.block_thread();
+                Ok(())
+            }
+
+            async fn get_blocks_in_range(
+                &self,
+                _: Range<BlockNumber>,
+            ) -> StdResult<Vec<CardanoBlock>> {
+                self.block_thread();
+                Ok(vec![])
+            }
+
+            async fn get_highest_blocks_block_range(&self) -> StdResult<Option<BlockRange>> {
+                self.block_thread();
+                Ok(None)
+            }
+
+            async fn store_blocks_block_range_roots(
+                &self,
+                _: Vec<(BlockRange, MKTreeNode)>,
+            ) -> StdResult<()> {
+                self.block_thread();
+                Ok(())
+            }
+
             async fn remove_rolled_back_transactions_and_block_range(
                 &self,
                 _: SlotNumber,
@@ -31,8 +31,11 @@ impl CardanoTransactionsPreloaderChecker for CardanoTransactionsPreloaderActivat
 
         let activated_signed_entity_types = message.capabilities.signed_entity_types;
 
+        // Cardano blocks are imported alongside the Cardano transactions
         Ok(activated_signed_entity_types
-            .contains(&SignedEntityTypeDiscriminants::CardanoTransactions))
+            .contains(&SignedEntityTypeDiscriminants::CardanoTransactions)
+            || activated_signed_entity_types
+                .contains(&SignedEntityTypeDiscriminants::CardanoBlocks))
     }
 }
 
@@ -91,6 +94,27 @@ mod tests {
         assert!(is_activated);
     }
 
+    #[tokio::test]
+    async fn preloader_activation_state_activate_preloader_when_cardano_blocks_in_aggregator_capabilities(
+    ) {
+        let mut aggregator_client = MockAggregatorClient::new();
+        aggregator_client
+            .expect_retrieve_aggregator_features()
+            .times(1)
+            .returning(|| {
+                let mut message = AggregatorFeaturesMessage::dummy();
+                message.capabilities.signed_entity_types =
+                    BTreeSet::from([SignedEntityTypeDiscriminants::CardanoBlocks]);
+                Ok(message)
+            });
+        let preloader =
+            CardanoTransactionsPreloaderActivationSigner::new(Arc::new(aggregator_client));
+
+        let is_activated = preloader.is_activated().await.unwrap();
+
+        assert!(is_activated);
+    }
+
     #[tokio::test]
     async fn preloader_activation_state_activate_preloader_when_aggregator_call_fails() {
         let mut aggregator_client = MockAggregatorClient::new();
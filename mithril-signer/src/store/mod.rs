@@ -1,7 +1,5 @@
 //! Alternative storage backends when relational database capabilities are not needed.
 
-mod mktree_store_sqlite;
 mod protocol_initializer_store;
 
-pub use mktree_store_sqlite::*;
 pub use protocol_initializer_store::*;
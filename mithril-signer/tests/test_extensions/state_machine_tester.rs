@@ -19,6 +19,7 @@ use mithril_common::{
     api_version::APIVersionProvider,
     cardano_block_scanner::{DumbBlockScanner, ScannedBlock},
     chain_observer::{ChainObserver, FakeObserver},
+    crypto_helper::MKTreeStoreSqlite,
     digesters::{DumbImmutableDigester, DumbImmutableFileObserver, ImmutableFileObserver},
     entities::{
         BlockNumber, CardanoTransactionsSigningConfig, ChainPoint, Epoch, SignedEntityConfig,
@@ -26,15 +27,17 @@ use mithril_common::{
     },
     era::{adapters::EraReaderDummyAdapter, EraChecker, EraMarker, EraReader, SupportedEra},
     signable_builder::{
-        CardanoDatabaseSignableBuilder, CardanoImmutableFilesFullSignableBuilder,
+        CardanoBlocksSignableBuilder, CardanoDatabaseSignableBuilder,
+        CardanoImmutableFilesFullSignableBuilder, CardanoProtocolParametersSignableBuilder,
         CardanoStakeDistributionSignableBuilder, CardanoTransactionsSignableBuilder,
-        MithrilSignableBuilderService, MithrilStakeDistributionSignableBuilder,
-        SignableBuilderServiceDependencies,
+        ChainObserverProtocolParametersRetriever, MithrilSignableBuilderService,
+        MithrilStakeDistributionSignableBuilder, SignableBuilderServiceDependencies,
     },
     MithrilTickerService, StdError, TickerService,
 };
 use mithril_persistence::{
-    database::repository::CardanoTransactionRepository, sqlite::SqliteConnectionPool,
+    database::repository::{CardanoBlockRepository, CardanoTransactionRepository},
+    sqlite::SqliteConnectionPool,
     store::StakeStorer,
 };
 use mithril_signed_entity_lock::SignedEntityTypeLock;
@@ -50,7 +53,7 @@ use mithril_signer::{
         SignerCertifierService, SignerSignableSeedBuilder, SignerSignedEntityConfigProvider,
         SignerUpkeepService,
     },
-    store::{MKTreeStoreSqlite, ProtocolInitializerStorer},
+    store::ProtocolInitializerStorer,
     Configuration, MetricsService, RuntimeError, SignerRunner, SignerState, StateMachine,
 };
 
@@ -216,6 +219,13 @@ impl StateMachineTester {
             transactions_importer.clone(),
             block_range_root_retriever,
         ));
+        let cardano_blocks_builder =
+            Arc::new(CardanoBlocksSignableBuilder::<MKTreeStoreSqlite>::new(
+                transactions_importer.clone(),
+                Arc::new(CardanoBlockRepository::new(
+                    sqlite_connection_cardano_transaction_pool.clone(),
+                )),
+            ));
         let cardano_stake_distribution_builder = Arc::new(
             CardanoStakeDistributionSignableBuilder::new(stake_store.clone()),
         );
@@ -224,6 +234,10 @@ impl StateMachineTester {
             Path::new(""),
             logger.clone(),
         ));
+        let cardano_protocol_parameters_signable_builder =
+            Arc::new(CardanoProtocolParametersSignableBuilder::new(Arc::new(
+                ChainObserverProtocolParametersRetriever::new(chain_observer.clone()),
+            )));
         let epoch_service = Arc::new(RwLock::new(MithrilEpochService::new(
             stake_store.clone(),
             protocol_initializer_store.clone(),
@@ -244,6 +258,8 @@ impl StateMachineTester {
             cardano_transactions_builder,
             cardano_stake_distribution_builder,
             cardano_database_signable_builder,
+            cardano_blocks_builder,
+            cardano_protocol_parameters_signable_builder,
         );
         let signable_builder_service = Arc::new(MithrilSignableBuilderService::new(
             signable_seed_builder_service,
@@ -1,6 +1,8 @@
 use blake2::{digest::consts::U64, Blake2b, Digest};
 use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
-use mithril_stm::bls_multi_signature::{Signature, SigningKey, VerificationKey};
+use mithril_stm::bls_multi_signature::{
+    Signature, SigningKey, VerificationKey, VerificationKeyPoP,
+};
 use rand_chacha::ChaCha20Rng;
 use rand_core::{RngCore, SeedableRng};
 
@@ -77,17 +79,44 @@ fn aggregate_and_verify(c: &mut Criterion, nr_sigs: usize) {
     });
 }
 
+fn proof_of_possession_verification(c: &mut Criterion, array_nr_keys: &[usize]) {
+    let mut group = c.benchmark_group("PoP".to_string());
+    let mut rng = ChaCha20Rng::from_seed([0u8; 32]);
+
+    for &nr_keys in array_nr_keys {
+        let keys = (0..nr_keys)
+            .map(|_| VerificationKeyPoP::from(&SigningKey::gen(&mut rng)))
+            .collect::<Vec<_>>();
+
+        group.bench_function(BenchmarkId::new("Individual verif", nr_keys), |b| {
+            b.iter(|| {
+                for key in keys.iter() {
+                    assert!(key.check().is_ok());
+                }
+            })
+        });
+
+        group.bench_function(BenchmarkId::new("Batch Verification", nr_keys), |b| {
+            b.iter(|| assert!(VerificationKeyPoP::batch_check(&keys).is_ok()))
+        });
+    }
+}
+
 fn batch_multi_sig_benches(c: &mut Criterion) {
     batch_benches(c, &[1, 10, 20, 50, 100], 300);
 }
 fn batch_bls_benches(c: &mut Criterion) {
     aggregate_and_verify(c, 856);
 }
+fn pop_verification_benches(c: &mut Criterion) {
+    proof_of_possession_verification(c, &[100, 1000, 3000]);
+}
 
 criterion_group!(name = benches;
                  config = Criterion::default().nresamples(1000);
                  targets =
     batch_multi_sig_benches,
-    batch_bls_benches
+    batch_bls_benches,
+    pop_verification_benches
 );
 criterion_main!(benches);
@@ -5,8 +5,8 @@ use blake2::{
 };
 use mithril_stm::key_reg::KeyReg;
 use mithril_stm::stm::{
-    CoreVerifier, Stake, StmClerk, StmInitializer, StmParameters, StmSig, StmSigRegParty,
-    StmSigner, StmVerificationKey,
+    AggregationStrategy, CoreVerifier, Stake, StmClerk, StmInitializer, StmParameters, StmSig,
+    StmSigRegParty, StmSigner, StmVerificationKey,
 };
 use rand_chacha::ChaCha20Rng;
 use rand_core::{RngCore, SeedableRng};
@@ -53,13 +53,20 @@ where
 
     // Aggregate with random parties
     let aggr = clerk.aggregate(&sigs, &msg).unwrap();
+    let minimal_aggr = clerk
+        .aggregate_with_strategy(&sigs, &msg, AggregationStrategy::MinimalSigners)
+        .unwrap();
+    let size = aggr.to_bytes().len();
+    let minimal_size = minimal_aggr.to_bytes().len();
 
     println!(
-        "k = {} | m = {} | nr parties = {}; {} bytes",
+        "k = {} | m = {} | nr parties = {}; {} bytes, {} bytes with minimal signers ({:.2}% smaller)",
         k,
         m,
         nparties,
-        aggr.to_bytes().len(),
+        size,
+        minimal_size,
+        100.0 * (size as f64 - minimal_size as f64) / size as f64,
     );
 }
 
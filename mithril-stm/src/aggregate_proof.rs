@@ -0,0 +1,353 @@
+//! Proof systems of the aggregate signatures.
+//!
+//! An aggregate signature proves that the signers of a set of `StmSig` are registered and won
+//! the lottery for at least `k` unique indexes. The `AggregateProof` trait abstracts the
+//! aggregation and the verification of such a proof, so other proof systems can be implemented
+//! over the same `ClosedKeyReg` and `StmParameters` as the concatenation proof, `StmAggrSig`.
+//!
+//! `AggregateSignature` holds an aggregate signature of any of the supported proof systems, its
+//! bytes start with the tag of its proof system.
+
+use blake2::digest::{Digest, FixedOutput};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::error::StmAggregateSignatureError;
+use crate::merkle_tree::BatchPath;
+#[cfg(not(feature = "std"))]
+use crate::no_std_prelude::*;
+use crate::stm::{StmAggrSig, StmAggrVerificationKey, StmParameters};
+#[cfg(feature = "std")]
+use {
+    crate::error::AggregationError,
+    crate::key_reg::ClosedKeyReg,
+    crate::stm::{AggregationStrategy, StmClerk, StmSig},
+};
+
+/// Tag identifying the proof system of an aggregate signature.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+#[repr(u8)]
+pub enum AggregateProofType {
+    /// Concatenation proof, see [StmAggrSig]
+    Concatenation = 0,
+}
+
+impl TryFrom<u8> for AggregateProofType {
+    type Error = u8;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(Self::Concatenation),
+            _ => Err(value),
+        }
+    }
+}
+
+/// Proof system aggregating the signatures of a message into an aggregate signature.
+pub trait AggregateProof<D: Clone + Digest + FixedOutput>: Sized {
+    /// Tag of the proof system.
+    const PROOF_TYPE: AggregateProofType;
+
+    /// Aggregate the signatures of the message, of parties of the given registration.
+    ///
+    /// # Error
+    /// The function fails with `AggregationError::NotEnoughSignatures` if the valid signatures
+    /// don't cover `k` unique indexes.
+    #[cfg(feature = "std")]
+    fn aggregate(
+        closed_reg: &ClosedKeyReg<D>,
+        params: &StmParameters,
+        sigs: &[StmSig],
+        msg: &[u8],
+    ) -> Result<Self, AggregationError>;
+
+    /// Verify the aggregate signature of the message against the aggregate verification key of
+    /// the registration.
+    fn verify(
+        &self,
+        msg: &[u8],
+        avk: &StmAggrVerificationKey<D>,
+        parameters: &StmParameters,
+    ) -> Result<(), StmAggregateSignatureError<D>>;
+
+    /// Convert the aggregate signature to bytes.
+    fn to_bytes(&self) -> Vec<u8>;
+
+    /// Extract the aggregate signature from a byte slice.
+    fn from_bytes(bytes: &[u8]) -> Result<Self, StmAggregateSignatureError<D>>;
+}
+
+impl<D: Clone + Digest + FixedOutput + Send + Sync> AggregateProof<D> for StmAggrSig<D> {
+    const PROOF_TYPE: AggregateProofType = AggregateProofType::Concatenation;
+
+    #[cfg(feature = "std")]
+    fn aggregate(
+        closed_reg: &ClosedKeyReg<D>,
+        params: &StmParameters,
+        sigs: &[StmSig],
+        msg: &[u8],
+    ) -> Result<Self, AggregationError> {
+        StmClerk::aggregate_registered_signatures(
+            closed_reg,
+            params,
+            sigs,
+            msg,
+            AggregationStrategy::default(),
+        )
+    }
+
+    fn verify(
+        &self,
+        msg: &[u8],
+        avk: &StmAggrVerificationKey<D>,
+        parameters: &StmParameters,
+    ) -> Result<(), StmAggregateSignatureError<D>> {
+        StmAggrSig::verify(self, msg, avk, parameters)
+    }
+
+    fn to_bytes(&self) -> Vec<u8> {
+        StmAggrSig::to_bytes(self)
+    }
+
+    fn from_bytes(bytes: &[u8]) -> Result<Self, StmAggregateSignatureError<D>> {
+        StmAggrSig::from_bytes(bytes)
+    }
+}
+
+/// Aggregate signature of any of the supported proof systems.
+///
+/// The concatenation proof is serialized as a `StmAggrSig`, without tag, so the aggregate
+/// signatures produced before the other proof systems can still be deserialized and the ones
+/// produced with the concatenation proof remain readable by the verifiers that only know
+/// `StmAggrSig`. The serialized form tagged with the proof type,
+/// `{"proof_type": "concatenation", "proof": ...}`, is accepted as well.
+#[derive(Debug, Clone)]
+pub enum AggregateSignature<D: Clone + Digest + FixedOutput> {
+    /// Concatenation proof
+    Concatenation(StmAggrSig<D>),
+}
+
+impl<D: Clone + Digest + FixedOutput + Send + Sync> AggregateSignature<D> {
+    /// Tag of the proof system of the aggregate signature.
+    pub fn proof_type(&self) -> AggregateProofType {
+        match self {
+            Self::Concatenation(_) => AggregateProofType::Concatenation,
+        }
+    }
+
+    /// Verify the aggregate signature with its proof system.
+    pub fn verify(
+        &self,
+        msg: &[u8],
+        avk: &StmAggrVerificationKey<D>,
+        parameters: &StmParameters,
+    ) -> Result<(), StmAggregateSignatureError<D>> {
+        match self {
+            Self::Concatenation(proof) => AggregateProof::verify(proof, msg, avk, parameters),
+        }
+    }
+
+    /// Convert the aggregate signature to bytes
+    /// # Layout
+    /// * Proof type (as u8)
+    /// * Bytes of the proof
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let proof_bytes = match self {
+            Self::Concatenation(proof) => AggregateProof::to_bytes(proof),
+        };
+        let mut out = Vec::with_capacity(1 + proof_bytes.len());
+        out.push(self.proof_type() as u8);
+        out.extend_from_slice(&proof_bytes);
+
+        out
+    }
+
+    /// Extract an `AggregateSignature` from a byte slice.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, StmAggregateSignatureError<D>> {
+        let (proof_type, proof_bytes) = bytes
+            .split_first()
+            .ok_or(StmAggregateSignatureError::SerializationError)?;
+
+        match AggregateProofType::try_from(*proof_type)
+            .map_err(StmAggregateSignatureError::UnknownProofType)?
+        {
+            AggregateProofType::Concatenation => Ok(Self::Concatenation(
+                AggregateProof::from_bytes(proof_bytes)?,
+            )),
+        }
+    }
+}
+
+impl<D: Clone + Digest + FixedOutput> From<StmAggrSig<D>> for AggregateSignature<D> {
+    fn from(proof: StmAggrSig<D>) -> Self {
+        Self::Concatenation(proof)
+    }
+}
+
+impl<D: Clone + Digest + FixedOutput> Serialize for AggregateSignature<D>
+where
+    BatchPath<D>: Serialize,
+{
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            Self::Concatenation(proof) => proof.serialize(serializer),
+        }
+    }
+}
+
+impl<'de, D: Clone + Digest + FixedOutput> Deserialize<'de> for AggregateSignature<D>
+where
+    BatchPath<D>: Deserialize<'de>,
+{
+    fn deserialize<De: Deserializer<'de>>(deserializer: De) -> Result<Self, De::Error> {
+        /// Aggregate signature tagged with its proof type.
+        #[derive(Deserialize)]
+        #[serde(
+            tag = "proof_type",
+            content = "proof",
+            rename_all = "snake_case",
+            bound(deserialize = "BatchPath<D>: Deserialize<'de>")
+        )]
+        enum TaggedAggregateSignature<D: Clone + Digest + FixedOutput> {
+            Concatenation(StmAggrSig<D>),
+        }
+
+        #[derive(Deserialize)]
+        #[serde(untagged, bound(deserialize = "BatchPath<D>: Deserialize<'de>"))]
+        enum AggregateSignatureRepr<D: Clone + Digest + FixedOutput> {
+            Tagged(TaggedAggregateSignature<D>),
+            Concatenation(StmAggrSig<D>),
+        }
+
+        match AggregateSignatureRepr::deserialize(deserializer)? {
+            AggregateSignatureRepr::Tagged(TaggedAggregateSignature::Concatenation(proof))
+            | AggregateSignatureRepr::Concatenation(proof) => Ok(Self::Concatenation(proof)),
+        }
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use blake2::{digest::consts::U32, Blake2b};
+    use rand_chacha::ChaCha20Rng;
+    use rand_core::SeedableRng;
+
+    use crate::key_reg::KeyReg;
+    use crate::stm::{StmInitializer, StmSigner};
+
+    use super::*;
+
+    type D = Blake2b<U32>;
+
+    const MSG: [u8; 16] = [5u8; 16];
+
+    fn setup_clerk_and_signatures() -> (StmClerk<D>, Vec<StmSig>) {
+        let mut rng = ChaCha20Rng::from_seed([0u8; 32]);
+        let params = StmParameters {
+            m: 10,
+            k: 5,
+            phi_f: 1.0,
+        };
+        let mut key_reg = KeyReg::init();
+        let initializers = [1, 2, 3]
+            .into_iter()
+            .map(|stake| {
+                let initializer = StmInitializer::setup(params, stake, &mut rng);
+                key_reg
+                    .register(stake, initializer.verification_key())
+                    .unwrap();
+                initializer
+            })
+            .collect::<Vec<_>>();
+        let closed_reg = key_reg.close();
+        let signers = initializers
+            .into_iter()
+            .map(|initializer| initializer.new_signer(closed_reg.clone()).unwrap())
+            .collect::<Vec<StmSigner<D>>>();
+        let sigs = signers
+            .iter()
+            .filter_map(|signer| signer.sign(&MSG))
+            .collect();
+
+        (StmClerk::from_signer(&signers[0]), sigs)
+    }
+
+    #[test]
+    fn concatenation_proof_is_the_clerk_aggregate_signature() {
+        let (clerk, sigs) = setup_clerk_and_signatures();
+
+        let proof: StmAggrSig<D> = clerk.aggregate_proof(&sigs, &MSG).unwrap();
+
+        assert_eq!(
+            clerk.aggregate(&sigs, &MSG).unwrap().to_bytes(),
+            AggregateProof::to_bytes(&proof)
+        );
+        assert!(AggregateProof::verify(&proof, &MSG, &clerk.compute_avk(), &clerk.params).is_ok());
+        assert!(
+            AggregateProof::verify(&proof, &[1u8; 16], &clerk.compute_avk(), &clerk.params)
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn aggregate_signature_bytes_are_tagged_with_the_proof_type() {
+        let (clerk, sigs) = setup_clerk_and_signatures();
+        let proof = clerk.aggregate(&sigs, &MSG).unwrap();
+        let signature = AggregateSignature::from(proof.clone());
+
+        let bytes = signature.to_bytes();
+        let decoded = AggregateSignature::<D>::from_bytes(&bytes).unwrap();
+
+        assert_eq!(AggregateProofType::Concatenation as u8, bytes[0]);
+        assert_eq!(proof.to_bytes().as_slice(), &bytes[1..]);
+        assert_eq!(AggregateProofType::Concatenation, decoded.proof_type());
+        assert!(decoded
+            .verify(&MSG, &clerk.compute_avk(), &clerk.params)
+            .is_ok());
+    }
+
+    #[test]
+    fn aggregate_signature_from_bytes_fails_with_an_unknown_proof_type() {
+        let (clerk, sigs) = setup_clerk_and_signatures();
+        let mut bytes = AggregateSignature::from(clerk.aggregate(&sigs, &MSG).unwrap()).to_bytes();
+        bytes[0] = 42;
+
+        assert!(matches!(
+            AggregateSignature::<D>::from_bytes(&bytes),
+            Err(StmAggregateSignatureError::UnknownProofType(42))
+        ));
+        assert!(matches!(
+            AggregateSignature::<D>::from_bytes(&[]),
+            Err(StmAggregateSignatureError::SerializationError)
+        ));
+    }
+
+    #[test]
+    fn concatenation_proof_is_serialized_without_tag() {
+        let (clerk, sigs) = setup_clerk_and_signatures();
+        let proof = clerk.aggregate(&sigs, &MSG).unwrap();
+        let proof_json = serde_json::to_string(&proof).unwrap();
+
+        let json = serde_json::to_string(&AggregateSignature::from(proof)).unwrap();
+
+        assert_eq!(proof_json, json);
+    }
+
+    #[test]
+    fn deserialize_untagged_and_tagged_concatenation_proofs() {
+        let (clerk, sigs) = setup_clerk_and_signatures();
+        let proof = clerk.aggregate(&sigs, &MSG).unwrap();
+        let proof_json = serde_json::to_string(&proof).unwrap();
+        let tagged_json = format!(r#"{{"proof_type":"concatenation","proof":{proof_json}}}"#);
+
+        for json in [proof_json, tagged_json] {
+            let signature: AggregateSignature<D> = serde_json::from_str(&json).unwrap();
+
+            assert_eq!(AggregateProofType::Concatenation, signature.proof_type());
+            assert_eq!(
+                AggregateSignature::from(proof.clone()).to_bytes(),
+                signature.to_bytes()
+            );
+        }
+    }
+}
@@ -0,0 +1,397 @@
+//! Diagnostic of the aggregation of the signatures of a message.
+//!
+//! When the signatures of a message don't reach the quorum, the aggregation only fails with
+//! `AggregationError::NotEnoughSignatures`. The `StmAggregationReport` details, for each submitted
+//! signature, if it was valid, the lottery results of its signer and which indexes it contributed
+//! to the aggregation, in order to explain why the quorum was not reached.
+
+use std::collections::BTreeMap;
+
+use blake2::digest::{Digest, FixedOutput};
+
+use crate::eligibility_check::ev_lt_phi;
+use crate::error::StmSignatureError;
+use crate::key_reg::ClosedKeyReg;
+use crate::stm::{Index, Stake, StmParameters, StmSig};
+
+/// Status of a signature submitted to the aggregation.
+#[derive(Debug, Clone)]
+pub enum StmSignatureStatus {
+    /// The signature is valid and takes part in the aggregation.
+    Valid,
+
+    /// The signer already submitted a signature, this one is ignored.
+    AlreadySubmitted,
+
+    /// The signature is invalid and is ignored.
+    Invalid(StmSignatureError),
+}
+
+impl StmSignatureStatus {
+    /// Check if the signature takes part in the aggregation.
+    pub fn is_valid(&self) -> bool {
+        matches!(self, Self::Valid)
+    }
+}
+
+/// Diagnostic of a signature submitted to the aggregation.
+#[derive(Debug, Clone)]
+pub struct StmSignatureReport {
+    /// Merkle tree index of the signer.
+    pub signer_index: Index,
+
+    /// Stake of the signer, if it is registered.
+    pub stake: Option<Stake>,
+
+    /// Status of the signature.
+    pub status: StmSignatureStatus,
+
+    /// Indexes claimed by the signature.
+    pub claimed_indexes: Vec<Index>,
+
+    /// Claimed indexes for which the lottery is won by the signer.
+    pub won_indexes: Vec<Index>,
+
+    /// Claimed indexes for which the lottery is lost by the signer, or that are out of bounds.
+    pub lost_indexes: Vec<Index>,
+
+    /// Indexes for which this signature is kept in the aggregation.
+    pub contributed_indexes: Vec<Index>,
+
+    /// Won indexes for which the signature of another signer is kept in the aggregation.
+    pub duplicate_indexes: Vec<Index>,
+}
+
+/// Diagnostic of the aggregation of the signatures of a message.
+#[derive(Debug, Clone)]
+pub struct StmAggregationReport {
+    /// Parameters of the aggregation.
+    pub params: StmParameters,
+
+    /// Total stake of the registered signers.
+    pub total_stake: Stake,
+
+    /// Diagnostic of each submitted signature.
+    pub signatures: Vec<StmSignatureReport>,
+}
+
+impl StmAggregationReport {
+    /// Build the report of the given signatures and their status.
+    ///
+    /// The lottery results are evaluated again but the signatures are not verified: the status
+    /// must be the result of their verification. As done by the aggregation, for each index, only
+    /// the valid signature with the smallest `sigma` is kept.
+    pub(crate) fn from_signatures<'a, D: Digest + Clone + FixedOutput>(
+        closed_reg: &ClosedKeyReg<D>,
+        params: &StmParameters,
+        msgp: &[u8],
+        signatures: impl IntoIterator<Item = (&'a StmSig, StmSignatureStatus)>,
+    ) -> Self {
+        let mut signatures_reports = Vec::new();
+        let mut best_signature_by_index: BTreeMap<Index, (usize, &StmSig)> = BTreeMap::new();
+
+        for (position, (sig, status)) in signatures.into_iter().enumerate() {
+            let stake = closed_reg
+                .reg_parties
+                .get(sig.signer_index as usize)
+                .map(|reg_party| reg_party.1);
+            let (won_indexes, lost_indexes) = match stake {
+                Some(stake) => sig.indexes.iter().partition(|&&index| {
+                    index <= params.m
+                        && ev_lt_phi(
+                            params.phi_f,
+                            sig.sigma.eval(msgp, index),
+                            stake,
+                            closed_reg.total_stake,
+                        )
+                }),
+                None => (vec![], sig.indexes.clone()),
+            };
+
+            if status.is_valid() {
+                for index in &won_indexes {
+                    let is_best_signature = match best_signature_by_index.get(index) {
+                        Some((_, best_sig)) => sig.sigma < best_sig.sigma,
+                        None => true,
+                    };
+                    if is_best_signature {
+                        best_signature_by_index.insert(*index, (position, sig));
+                    }
+                }
+            }
+
+            signatures_reports.push(StmSignatureReport {
+                signer_index: sig.signer_index,
+                stake,
+                status,
+                claimed_indexes: sig.indexes.clone(),
+                won_indexes,
+                lost_indexes,
+                contributed_indexes: vec![],
+                duplicate_indexes: vec![],
+            });
+        }
+
+        for report in signatures_reports
+            .iter_mut()
+            .filter(|report| report.status.is_valid())
+        {
+            report.duplicate_indexes = report.won_indexes.clone();
+        }
+        for (index, (position, _)) in best_signature_by_index {
+            let report = &mut signatures_reports[position];
+            report.contributed_indexes.push(index);
+            report.duplicate_indexes.retain(|i| *i != index);
+        }
+
+        Self {
+            params: *params,
+            total_stake: closed_reg.total_stake,
+            signatures: signatures_reports,
+        }
+    }
+
+    /// Number of unique indexes covered by the valid signatures.
+    pub fn unique_indexes_count(&self) -> u64 {
+        self.signatures
+            .iter()
+            .map(|report| report.contributed_indexes.len() as u64)
+            .sum()
+    }
+
+    /// Check if the valid signatures cover at least `k` unique indexes.
+    pub fn is_quorum_reached(&self) -> bool {
+        self.unique_indexes_count() >= self.params.k
+    }
+
+    /// Number of valid signatures.
+    pub fn valid_signatures_count(&self) -> u64 {
+        self.signatures
+            .iter()
+            .filter(|report| report.status.is_valid())
+            .count() as u64
+    }
+
+    /// Number of submitted signatures that are not part of the aggregation.
+    pub fn rejected_signatures_count(&self) -> u64 {
+        self.signatures.len() as u64 - self.valid_signatures_count()
+    }
+
+    /// Stake of the signers of the valid signatures.
+    pub fn valid_signers_stake(&self) -> Stake {
+        self.signatures
+            .iter()
+            .filter(|report| report.status.is_valid())
+            .filter_map(|report| report.stake)
+            .sum()
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use blake2::{digest::consts::U32, Blake2b};
+    use rand_chacha::ChaCha20Rng;
+    use rand_core::SeedableRng;
+
+    use crate::key_reg::KeyReg;
+    use crate::stm::{StmClerk, StmInitializer, StmSigner};
+
+    use super::*;
+
+    type D = Blake2b<U32>;
+
+    fn setup_signers(params: StmParameters, stakes: &[Stake]) -> Vec<StmSigner<D>> {
+        let mut rng = ChaCha20Rng::from_seed([0u8; 32]);
+        let mut key_reg = KeyReg::init();
+        let initializers = stakes
+            .iter()
+            .map(|&stake| {
+                let initializer = StmInitializer::setup(params, stake, &mut rng);
+                key_reg
+                    .register(stake, initializer.verification_key())
+                    .unwrap();
+                initializer
+            })
+            .collect::<Vec<_>>();
+        let closed_reg = key_reg.close();
+
+        initializers
+            .into_iter()
+            .map(|initializer| initializer.new_signer(closed_reg.clone()).unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn report_valid_signatures_when_quorum_is_reached() {
+        let msg = [5u8; 16];
+        let params = StmParameters {
+            m: 10,
+            k: 5,
+            phi_f: 1.0,
+        };
+        let signers = setup_signers(params, &[1, 2, 3]);
+        let sigs = signers
+            .iter()
+            .map(|signer| signer.sign(&msg).unwrap())
+            .collect::<Vec<_>>();
+
+        let report = StmClerk::from_signer(&signers[0]).aggregation_report(&sigs, &msg);
+
+        assert_eq!(3, report.valid_signatures_count());
+        assert_eq!(0, report.rejected_signatures_count());
+        assert_eq!(6, report.valid_signers_stake());
+        assert_eq!(10, report.unique_indexes_count());
+        assert!(report.is_quorum_reached());
+        for (signature_report, sig) in report.signatures.iter().zip(&sigs) {
+            assert_eq!(sig.indexes, signature_report.won_indexes);
+            assert!(signature_report.lost_indexes.is_empty());
+            let mut indexes = [
+                signature_report.contributed_indexes.clone(),
+                signature_report.duplicate_indexes.clone(),
+            ]
+            .concat();
+            indexes.sort();
+            assert_eq!(sig.indexes, indexes);
+        }
+    }
+
+    #[test]
+    fn report_invalid_and_already_submitted_signatures() {
+        let msg = [5u8; 16];
+        let params = StmParameters {
+            m: 10,
+            k: 15,
+            phi_f: 1.0,
+        };
+        let signers = setup_signers(params, &[1, 2]);
+        let valid_sig = signers[0].sign(&msg).unwrap();
+        let sig_of_other_msg = signers[1].sign(&[1u8; 16]).unwrap();
+        let mut sig_of_unregistered_signer = valid_sig.clone();
+        sig_of_unregistered_signer.signer_index = 5;
+        let sigs = vec![
+            valid_sig.clone(),
+            sig_of_other_msg,
+            sig_of_unregistered_signer,
+            valid_sig.clone(),
+        ];
+
+        let report = StmClerk::from_signer(&signers[0]).aggregation_report(&sigs, &msg);
+
+        assert_eq!(1, report.valid_signatures_count());
+        assert_eq!(3, report.rejected_signatures_count());
+        assert_eq!(valid_sig.indexes, report.signatures[0].contributed_indexes);
+        assert!(matches!(
+            report.signatures[1].status,
+            StmSignatureStatus::Invalid(StmSignatureError::SignatureInvalid(_))
+        ));
+        assert!(report.signatures[1].contributed_indexes.is_empty());
+        assert!(matches!(
+            report.signatures[2].status,
+            StmSignatureStatus::Invalid(StmSignatureError::UnregisteredSigner(5))
+        ));
+        assert_eq!(None, report.signatures[2].stake);
+        assert_eq!(valid_sig.indexes, report.signatures[2].lost_indexes);
+        assert!(matches!(
+            report.signatures[3].status,
+            StmSignatureStatus::AlreadySubmitted
+        ));
+        assert_eq!(10, report.unique_indexes_count());
+        assert!(!report.is_quorum_reached());
+    }
+
+    #[test]
+    fn report_valid_signature_submitted_after_an_invalid_one_of_the_same_signer() {
+        let msg = [5u8; 16];
+        let params = StmParameters {
+            m: 10,
+            k: 15,
+            phi_f: 1.0,
+        };
+        let signers = setup_signers(params, &[1, 2]);
+        let sig_of_other_msg = signers[0].sign(&[1u8; 16]).unwrap();
+        let valid_sig = signers[0].sign(&msg).unwrap();
+
+        let report = StmClerk::from_signer(&signers[0])
+            .aggregation_report(&[sig_of_other_msg, valid_sig.clone()], &msg);
+
+        assert!(matches!(
+            report.signatures[0].status,
+            StmSignatureStatus::Invalid(StmSignatureError::SignatureInvalid(_))
+        ));
+        assert!(report.signatures[1].status.is_valid());
+        assert_eq!(1, report.valid_signatures_count());
+        assert_eq!(1, report.rejected_signatures_count());
+        assert_eq!(valid_sig.indexes, report.signatures[1].contributed_indexes);
+    }
+
+    #[test]
+    fn report_lost_and_out_of_bound_indexes() {
+        let msg = [5u8; 16];
+        let params = StmParameters {
+            m: 10,
+            k: 1,
+            phi_f: 0.2,
+        };
+        let signers = setup_signers(params, &[1, 1000]);
+        let mut sig = signers[1].sign(&msg).unwrap();
+        let claimed_indexes = (0..=params.m + 1).collect::<Vec<_>>();
+        sig.indexes.clone_from(&claimed_indexes);
+
+        let report = StmClerk::from_signer(&signers[0]).aggregation_report(&[sig.clone()], &msg);
+
+        let signature_report = &report.signatures[0];
+        assert!(matches!(
+            signature_report.status,
+            StmSignatureStatus::Invalid(_)
+        ));
+        assert_eq!(claimed_indexes, signature_report.claimed_indexes);
+        assert!(signature_report.lost_indexes.contains(&(params.m + 1)));
+        assert_eq!(
+            claimed_indexes.len(),
+            signature_report.won_indexes.len() + signature_report.lost_indexes.len()
+        );
+        assert_eq!(0, report.unique_indexes_count());
+    }
+
+    #[test]
+    fn incremental_aggregator_report_matches_clerk_report() {
+        let msg = [5u8; 16];
+        let params = StmParameters {
+            m: 20,
+            k: 30,
+            phi_f: 0.5,
+        };
+        let signers = setup_signers(params, &[1, 2, 3, 400]);
+        let clerk = StmClerk::from_signer(&signers[0]);
+        let mut sigs = signers[..3]
+            .iter()
+            .filter_map(|signer| signer.sign(&msg))
+            .collect::<Vec<_>>();
+        sigs.push(signers[3].sign(&[1u8; 16]).unwrap());
+
+        let mut aggregator = clerk.incremental_aggregator(&msg);
+        for sig in &sigs {
+            let _ = aggregator.add_signature(sig);
+        }
+        let expected = clerk.aggregation_report(&sigs, &msg);
+        let report = aggregator.aggregation_report();
+
+        assert_eq!(
+            expected.unique_indexes_count(),
+            report.unique_indexes_count()
+        );
+        assert_eq!(
+            aggregator.unique_indexes_count(),
+            report.unique_indexes_count()
+        );
+        assert_eq!(
+            expected.valid_signatures_count(),
+            report.valid_signatures_count()
+        );
+        assert_eq!(
+            expected.rejected_signatures_count(),
+            report.rejected_signatures_count()
+        );
+        assert_eq!(expected.valid_signers_stake(), report.valid_signers_stake());
+    }
+}
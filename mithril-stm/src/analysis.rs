@@ -0,0 +1,375 @@
+//! Security analysis of the protocol parameters.
+//!
+//! Each of the `m` lottery indexes is won by a party of relative stake `w` with probability
+//! `phi(w) = 1 - (1 - phi_f)^w`, independently of the other indexes. As `phi` is additive in the
+//! exponent, a coalition holding a ratio `a` of the stake wins each index with probability
+//! `phi(a)`, so the number of indexes it wins follows a `Binomial(m, phi(a))` distribution.
+//!
+//! Parameters are considered secure when an adversary holding a ratio `a` of the stake can not
+//! win the `k` indexes required for a quorum, except with a negligible probability:
+//! `1 - BinomialCDF(k - 1, m, phi(a)) <= 2^-security_level`.
+
+use crate::stm::{Stake, StmParameters};
+
+/// Size, in bytes, of a registered party (verification key and stake) in an aggregate signature.
+const REG_PARTY_SIZE: u64 = 104;
+
+/// Size, in bytes, of a single signature without its indexes (indexes count, sigma and signer
+/// index).
+const SIGNATURE_BASE_SIZE: u64 = 8 + 48 + 8;
+
+/// Size, in bytes, of a lottery index.
+const INDEX_SIZE: u64 = 8;
+
+/// Size, in bytes, of a node of the merkle tree of the registered parties.
+const MERKLE_NODE_SIZE: u64 = 32;
+
+/// Probability for a party holding a ratio `stake_ratio` of the total stake to win a lottery.
+pub fn phi(phi_f: f64, stake_ratio: f64) -> f64 {
+    1.0 - (1.0 - phi_f).powf(stake_ratio)
+}
+
+/// Natural logarithm of the probability for a `Binomial(m, p)` variable to be at least `k`.
+fn ln_binomial_tail(k: u64, m: u64, p: f64) -> f64 {
+    if k == 0 || p >= 1.0 {
+        return 0.0;
+    }
+    if k > m || p <= 0.0 {
+        return f64::NEG_INFINITY;
+    }
+
+    let (ln_p, ln_q) = (p.ln(), (1.0 - p).ln());
+    // ln(C(m, k) * p^k * (1 - p)^(m - k))
+    let mut ln_term = (0..k)
+        .map(|j| ((m - j) as f64).ln() - ((j + 1) as f64).ln())
+        .sum::<f64>()
+        + k as f64 * ln_p
+        + (m - k) as f64 * ln_q;
+    let mut ln_sum = ln_term;
+    let mode = m as f64 * p;
+
+    for i in k..m {
+        ln_term += ((m - i) as f64).ln() - ((i + 1) as f64).ln() + ln_p - ln_q;
+        // Past the mode the terms are decreasing, stop when they become negligible
+        if (i as f64) > mode && ln_term < ln_sum - 50.0 {
+            break;
+        }
+        let max = ln_sum.max(ln_term);
+        ln_sum = max + ((ln_sum - max).exp() + (ln_term - max).exp()).ln();
+    }
+
+    ln_sum
+}
+
+impl StmParameters {
+    /// Security level, in bits, of the parameters against an adversary holding a ratio
+    /// `adversarial_stake_ratio` of the total stake.
+    ///
+    /// The adversary can reach a quorum on its own with a probability of
+    /// `2^-security_level`, the security level is infinite if it can never reach one.
+    pub fn security_level(&self, adversarial_stake_ratio: f64) -> f64 {
+        let p = phi(self.phi_f, adversarial_stake_ratio);
+
+        -ln_binomial_tail(self.k, self.m, p) / std::f64::consts::LN_2
+    }
+
+    /// Probability that the signers holding a ratio `stake_ratio` of the total stake reach a
+    /// quorum.
+    pub fn quorum_probability(&self, stake_ratio: f64) -> f64 {
+        let p = phi(self.phi_f, stake_ratio);
+
+        ln_binomial_tail(self.k, self.m, p).exp()
+    }
+}
+
+/// Analysis of protocol parameters applied to a stake distribution.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ParametersAnalysis {
+    /// Analyzed parameters
+    pub params: StmParameters,
+    /// Ratio of the total stake assumed to be held by an adversary
+    pub adversarial_stake_ratio: f64,
+    /// Security level, in bits, against the adversary
+    pub security_level: f64,
+    /// Probability that the honest signers reach a quorum without the adversary
+    pub honest_quorum_probability: f64,
+    /// Expected number of signers winning at least one lottery, i.e. sending a signature to the
+    /// aggregator
+    pub expected_signers: f64,
+    /// Expected number of signers whose signatures are included in the aggregate signature
+    pub expected_aggregated_signers: f64,
+    /// Estimated size of the aggregate signature, in bytes
+    pub expected_aggregate_signature_size: u64,
+    /// Number of lotteries evaluated by each signer for each message
+    pub lotteries_per_signer: u64,
+    /// Expected number of lotteries won by the signer with the largest stake
+    pub max_expected_won_lotteries: f64,
+}
+
+impl ParametersAnalysis {
+    /// Analyze the given parameters for a stake distribution, assuming an adversary holding a
+    /// ratio `adversarial_stake_ratio` of the total stake.
+    pub fn new(params: StmParameters, adversarial_stake_ratio: f64, stakes: &[Stake]) -> Self {
+        let total_stake = stakes.iter().sum::<Stake>().max(1) as f64;
+        let win_probabilities = stakes
+            .iter()
+            .map(|stake| phi(params.phi_f, *stake as f64 / total_stake))
+            .collect::<Vec<_>>();
+        let total_win_probability = win_probabilities.iter().sum::<f64>();
+
+        let expected_signers = win_probabilities
+            .iter()
+            .map(|p| 1.0 - (1.0 - p).powf(params.m as f64))
+            .sum::<f64>();
+        // Each of the `k` indexes of the aggregate signature is won by a signer with a
+        // probability proportional to its own winning probability
+        let expected_aggregated_signers = if total_win_probability > 0.0 {
+            win_probabilities
+                .iter()
+                .map(|p| 1.0 - (1.0 - p / total_win_probability).powf(params.k as f64))
+                .sum::<f64>()
+        } else {
+            0.0
+        };
+        let max_expected_won_lotteries = win_probabilities
+            .iter()
+            .fold(0.0_f64, |max, p| max.max(p * params.m as f64));
+
+        Self {
+            params,
+            adversarial_stake_ratio,
+            security_level: params.security_level(adversarial_stake_ratio),
+            honest_quorum_probability: params.quorum_probability(1.0 - adversarial_stake_ratio),
+            expected_signers,
+            expected_aggregated_signers,
+            expected_aggregate_signature_size: Self::estimate_aggregate_signature_size(
+                params.k,
+                expected_aggregated_signers.ceil() as u64,
+                stakes.len() as u64,
+            ),
+            lotteries_per_signer: params.m,
+            max_expected_won_lotteries,
+        }
+    }
+
+    /// Estimate the size of an aggregate signature with `k` indexes from `signers` signers among
+    /// `total_parties` registered parties.
+    fn estimate_aggregate_signature_size(k: u64, signers: u64, total_parties: u64) -> u64 {
+        if signers == 0 {
+            return 0;
+        }
+
+        // The batch proof holds, for each signer, the nodes from its leaf up to the level where
+        // the paths of the signers merge
+        let tree_depth = total_parties.max(1).next_power_of_two().ilog2() as u64;
+        let shared_depth = signers.ilog2() as u64;
+        let batch_proof_nodes = signers * tree_depth.saturating_sub(shared_depth);
+        let batch_proof_size = 16 + batch_proof_nodes * MERKLE_NODE_SIZE + signers * INDEX_SIZE;
+
+        16 + signers * (REG_PARTY_SIZE + SIGNATURE_BASE_SIZE) + k * INDEX_SIZE + batch_proof_size
+    }
+}
+
+/// Bounds that protocol parameters must meet.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SecurityTarget {
+    /// Ratio of the total stake assumed to be held by an adversary
+    pub adversarial_stake_ratio: f64,
+    /// Minimum security level, in bits, against the adversary
+    pub min_security_level: f64,
+    /// Minimum probability that the honest signers reach a quorum without the adversary
+    pub min_honest_quorum_probability: f64,
+}
+
+impl SecurityTarget {
+    /// Check if the given parameters meet the target.
+    pub fn is_met_by(&self, params: &StmParameters) -> bool {
+        params.security_level(self.adversarial_stake_ratio) >= self.min_security_level
+            && params.quorum_probability(1.0 - self.adversarial_stake_ratio)
+                >= self.min_honest_quorum_probability
+    }
+
+    /// Search the parameters meeting the target for each combination of `phi_f` and `m`.
+    ///
+    /// For each combination the smallest secure quorum `k` is selected, since it maximizes the
+    /// probability of the honest signers reaching it, and kept only if it meets the target.
+    pub fn search_parameters(
+        &self,
+        phi_f_values: &[f64],
+        m_values: impl IntoIterator<Item = u64>,
+    ) -> Vec<StmParameters> {
+        let mut found = vec![];
+
+        for m in m_values {
+            for &phi_f in phi_f_values {
+                if let Some(params) = self.smallest_secure_quorum(m, phi_f) {
+                    if self.is_met_by(&params) {
+                        found.push(params);
+                    }
+                }
+            }
+        }
+
+        found
+    }
+
+    /// Binary search of the smallest `k` reaching the minimum security level, the security
+    /// level increasing with `k`.
+    fn smallest_secure_quorum(&self, m: u64, phi_f: f64) -> Option<StmParameters> {
+        let is_secure = |k: u64| {
+            StmParameters { m, k, phi_f }.security_level(self.adversarial_stake_ratio)
+                >= self.min_security_level
+        };
+        if m == 0 || !is_secure(m) {
+            return None;
+        }
+
+        let (mut low, mut high) = (1, m);
+        while low < high {
+            let middle = low + (high - low) / 2;
+            if is_secure(middle) {
+                high = middle;
+            } else {
+                low = middle + 1;
+            }
+        }
+
+        Some(StmParameters { m, k: low, phi_f })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_approx_eq(expected: f64, actual: f64, tolerance: f64) {
+        assert!(
+            (expected - actual).abs() <= tolerance,
+            "expected {expected}, got {actual}"
+        );
+    }
+
+    #[test]
+    fn phi_of_full_stake_is_phi_f() {
+        assert_approx_eq(0.2, phi(0.2, 1.0), 1e-12);
+        assert_approx_eq(0.0, phi(0.2, 0.0), 1e-12);
+    }
+
+    #[test]
+    fn binomial_tail_matches_exact_values() {
+        // P[Binomial(4, 0.5) >= 2] = 11 / 16
+        assert_approx_eq((11.0_f64 / 16.0).ln(), ln_binomial_tail(2, 4, 0.5), 1e-12);
+        // P[Binomial(10, 0.1) >= 10] = 10^-10
+        assert_approx_eq(-10.0 * 10.0_f64.ln(), ln_binomial_tail(10, 10, 0.1), 1e-9);
+        assert_eq!(0.0, ln_binomial_tail(0, 10, 0.1));
+        assert_eq!(f64::NEG_INFINITY, ln_binomial_tail(11, 10, 0.1));
+    }
+
+    #[test]
+    fn security_level_does_not_underflow() {
+        let params = StmParameters {
+            m: 20973,
+            k: 2422,
+            phi_f: 0.2,
+        };
+
+        let security_level = params.security_level(0.05);
+
+        assert!(security_level.is_finite());
+        assert!(security_level > 1000.0);
+    }
+
+    #[test]
+    fn security_level_increases_with_k_and_decreases_with_adversarial_stake() {
+        let params = StmParameters {
+            m: 2000,
+            k: 300,
+            phi_f: 0.2,
+        };
+        let stronger_params = StmParameters { k: 400, ..params };
+
+        assert!(stronger_params.security_level(0.3) > params.security_level(0.3));
+        assert!(params.security_level(0.2) > params.security_level(0.3));
+    }
+
+    #[test]
+    fn quorum_probability_of_the_whole_stake_is_high_for_mainnet_parameters() {
+        let params = StmParameters {
+            m: 20973,
+            k: 2422,
+            phi_f: 0.2,
+        };
+
+        assert!(params.quorum_probability(1.0) > 0.999);
+        assert!(params.quorum_probability(0.1) < 1e-6);
+    }
+
+    #[test]
+    fn analysis_of_a_uniform_stake_distribution() {
+        let params = StmParameters {
+            m: 100,
+            k: 10,
+            phi_f: 0.2,
+        };
+        let stakes = vec![10; 100];
+
+        let analysis = ParametersAnalysis::new(params, 0.2, &stakes);
+
+        assert_eq!(params.security_level(0.2), analysis.security_level);
+        assert_eq!(
+            params.quorum_probability(0.8),
+            analysis.honest_quorum_probability
+        );
+        assert_eq!(100, analysis.lotteries_per_signer);
+        // Each signer wins a lottery with probability phi(0.01) ~= 0.00223
+        assert_approx_eq(
+            100.0 * phi(0.2, 0.01),
+            analysis.max_expected_won_lotteries,
+            1e-9,
+        );
+        assert_approx_eq(
+            100.0 * (1.0 - (1.0 - phi(0.2, 0.01)).powi(100)),
+            analysis.expected_signers,
+            1e-9,
+        );
+        assert!(analysis.expected_aggregated_signers <= 10.0);
+        assert!(analysis.expected_aggregate_signature_size > 0);
+    }
+
+    #[test]
+    fn search_parameters_only_returns_parameters_meeting_the_target() {
+        let target = SecurityTarget {
+            adversarial_stake_ratio: 0.3,
+            min_security_level: 40.0,
+            min_honest_quorum_probability: 0.99,
+        };
+
+        let found = target.search_parameters(&[0.2, 0.65], (500..=3000).step_by(500));
+
+        assert!(!found.is_empty());
+        for params in &found {
+            assert!(target.is_met_by(params));
+            let smaller_quorum = StmParameters {
+                k: params.k - 1,
+                ..*params
+            };
+            assert!(
+                smaller_quorum.security_level(target.adversarial_stake_ratio)
+                    < target.min_security_level
+            );
+        }
+    }
+
+    #[test]
+    fn search_parameters_returns_nothing_for_a_majority_adversary() {
+        let target = SecurityTarget {
+            adversarial_stake_ratio: 0.6,
+            min_security_level: 100.0,
+            min_honest_quorum_probability: 0.99,
+        };
+
+        let found = target.search_parameters(&[0.2], (500..=3000).step_by(500));
+
+        assert!(found.is_empty());
+    }
+}
@@ -14,7 +14,7 @@ pub(crate) mod unsafe_helpers {
     pub(crate) fn verify_pairing(vk: &VerificationKey, pop: &ProofOfPossession) -> bool {
         unsafe {
             let g1_p = *blst_p1_affine_generator();
-            let mvk_p = std::mem::transmute::<BlstVk, blst_p2_affine>(vk.to_blst_vk());
+            let mvk_p = core::mem::transmute::<BlstVk, blst_p2_affine>(vk.to_blst_vk());
             let ml_lhs = blst_fp12::miller_loop(&mvk_p, &g1_p);
 
             let mut k2_p = blst_p1_affine::default();
@@ -46,9 +46,15 @@ pub(crate) mod unsafe_helpers {
         }
     }
 
+    /// Check if the G1 point is in the prime order subgroup.
+    #[cfg(feature = "std")]
+    pub(crate) fn p1_in_g1(point: &blst_p1) -> bool {
+        unsafe { blst::blst_p1_in_g1(point) }
+    }
+
     pub(crate) fn scalar_to_pk_in_g1(sk: &BlstSk) -> blst_p1 {
         unsafe {
-            let sk_scalar = std::mem::transmute::<&BlstSk, &blst_scalar>(sk);
+            let sk_scalar = core::mem::transmute::<&BlstSk, &blst_scalar>(sk);
             let mut out = blst_p1::default();
             blst_sk_to_pk_in_g1(&mut out, sk_scalar);
             out
@@ -60,7 +66,7 @@ pub(crate) mod unsafe_helpers {
             let mut projective_p2 = blst_p2::default();
             blst_p2_from_affine(
                 &mut projective_p2,
-                &std::mem::transmute::<BlstVk, blst_p2_affine>(vk.to_blst_vk()),
+                &core::mem::transmute::<BlstVk, blst_p2_affine>(vk.to_blst_vk()),
             );
             projective_p2
         }
@@ -71,7 +77,7 @@ pub(crate) mod unsafe_helpers {
             let mut projective_p1 = blst_p1::default();
             blst_p1_from_affine(
                 &mut projective_p1,
-                &std::mem::transmute::<BlstSig, blst_p1_affine>(*sig),
+                &core::mem::transmute::<BlstSig, blst_p1_affine>(*sig),
             );
             projective_p1
         }
@@ -81,7 +87,7 @@ pub(crate) mod unsafe_helpers {
         unsafe {
             let mut affine_p2 = blst_p2_affine::default();
             blst_p2_to_affine(&mut affine_p2, grouped_vks);
-            std::mem::transmute::<blst_p2_affine, BlstVk>(affine_p2)
+            core::mem::transmute::<blst_p2_affine, BlstVk>(affine_p2)
         }
     }
 
@@ -89,7 +95,7 @@ pub(crate) mod unsafe_helpers {
         unsafe {
             let mut affine_p1 = blst_p1_affine::default();
             blst_p1_to_affine(&mut affine_p1, grouped_sigs);
-            std::mem::transmute::<blst_p1_affine, BlstSig>(affine_p1)
+            core::mem::transmute::<blst_p1_affine, BlstSig>(affine_p1)
         }
     }
 }
@@ -11,6 +11,8 @@ pub use crate::bls_multi_signature::signature::Signature;
 pub use crate::bls_multi_signature::signing_key::SigningKey;
 pub use crate::bls_multi_signature::verification_key::{VerificationKey, VerificationKeyPoP};
 
+#[cfg(not(feature = "std"))]
+use crate::no_std_prelude::*;
 use serde::de::Visitor;
 use serde::{Deserialize, Deserializer, Serialize, Serializer};
 
@@ -85,7 +87,7 @@ impl_serde!(VerificationKey, VerificationKeyVisitor, 96);
 impl_serde!(ProofOfPossession, ProofOfPossessionVisitor, 96);
 impl_serde!(Signature, SignatureVisitor, 48);
 
-#[cfg(test)]
+#[cfg(all(test, feature = "std"))]
 mod tests {
     use super::*;
     use crate::bls_multi_signature::helper::unsafe_helpers::{p1_affine_to_sig, p2_affine_to_vk};
@@ -94,7 +96,8 @@ mod tests {
     use blst::{blst_p1, blst_p2};
     use proptest::prelude::*;
     use rand_chacha::ChaCha20Rng;
-    use rand_core::SeedableRng;
+    use rand_core::{RngCore, SeedableRng};
+    use zeroize::Zeroize;
 
     impl PartialEq for SigningKey {
         fn eq(&self, other: &Self) -> bool {
@@ -179,6 +182,44 @@ mod tests {
             assert_eq!(result, Err(RegisterError::VerificationKeyInfinity(Box::new(vkpop_infinity.vk))));
         }
 
+        #[test]
+        fn test_batch_check_pop(num_keys in 2..16usize, seed in any::<[u8;32]>()) {
+            let mut rng = ChaCha20Rng::from_seed(seed);
+            let mut keys = (0..num_keys)
+                .map(|_| VerificationKeyPoP::from(&SigningKey::gen(&mut rng)))
+                .collect::<Vec<_>>();
+            assert!(VerificationKeyPoP::batch_check(&keys).is_ok());
+
+            // If a proof of possession is invalid, the batch verification fails
+            let invalid_key_index = (rng.next_u32() as usize) % num_keys;
+            keys[invalid_key_index].pop = VerificationKeyPoP::from(&SigningKey::gen(&mut rng)).pop;
+            assert_eq!(VerificationKeyPoP::batch_check(&keys), Err(MultiSignatureError::BatchInvalid));
+
+            // Swapping the proofs of possession of two keys also fails
+            let mut keys = (0..num_keys)
+                .map(|_| VerificationKeyPoP::from(&SigningKey::gen(&mut rng)))
+                .collect::<Vec<_>>();
+            let first_pop = keys[0].pop;
+            keys[0].pop = keys[1].pop;
+            keys[1].pop = first_pop;
+            assert_eq!(VerificationKeyPoP::batch_check(&keys), Err(MultiSignatureError::BatchInvalid));
+        }
+
+        #[test]
+        fn test_batch_check_pop_with_infinity_vk(num_keys in 2..16usize, seed in any::<[u8;32]>()) {
+            let mut rng = ChaCha20Rng::from_seed(seed);
+            let mut keys = (0..num_keys)
+                .map(|_| VerificationKeyPoP::from(&SigningKey::gen(&mut rng)))
+                .collect::<Vec<_>>();
+            let vk_infinity = VerificationKey(p2_affine_to_vk(&blst_p2::default()));
+            keys[num_keys - 1].vk = vk_infinity;
+
+            assert_eq!(
+                VerificationKeyPoP::batch_check(&keys),
+                Err(MultiSignatureError::VerificationKeyInfinity(Box::new(vk_infinity)))
+            );
+        }
+
         #[test]
         fn test_aggregate_sig(msg in prop::collection::vec(any::<u8>(), 1..128),
                               num_sigs in 2..16,
@@ -290,4 +331,20 @@ mod tests {
             assert_eq!(batch_result, Err(MultiSignatureError::BatchInvalid));
         }
     }
+
+    #[test]
+    fn signing_key_debug_is_redacted() {
+        let sk = SigningKey::gen(&mut ChaCha20Rng::from_seed([0u8; 32]));
+
+        assert_eq!("SigningKey(<redacted>)", format!("{sk:?}"));
+    }
+
+    #[test]
+    fn zeroized_signing_key_is_cleared() {
+        let mut sk = SigningKey::gen(&mut ChaCha20Rng::from_seed([0u8; 32]));
+
+        sk.zeroize();
+
+        assert_eq!([0u8; 32], sk.to_bytes());
+    }
 }
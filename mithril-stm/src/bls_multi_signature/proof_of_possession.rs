@@ -48,6 +48,11 @@ impl ProofOfPossession {
         Ok(Self { k1, k2 })
     }
 
+    #[cfg(feature = "std")]
+    pub(crate) fn from_points(k1: BlstSig, k2: blst_p1) -> Self {
+        Self { k1, k2 }
+    }
+
     pub(crate) fn to_k1(self) -> BlstSig {
         self.k1
     }
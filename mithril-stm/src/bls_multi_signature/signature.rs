@@ -3,14 +3,16 @@ use crate::bls_multi_signature::helper::unsafe_helpers::{
 };
 use crate::bls_multi_signature::verification_key::VerificationKey;
 use crate::error::{blst_err_to_mithril, MultiSignatureError};
+#[cfg(not(feature = "std"))]
+use crate::no_std_prelude::*;
 use crate::stm::Index;
 use blake2::Blake2b;
 use blake2::{Blake2b512, Digest};
 use blst::min_sig::{AggregateSignature, PublicKey as BlstVk, Signature as BlstSig};
 use blst::{blst_p1, blst_p2, p1_affines, p2_affines};
+use core::cmp::Ordering;
+use core::iter::Sum;
 use digest::consts::U16;
-use std::cmp::Ordering;
-use std::iter::Sum;
 
 /// MultiSig signature, which is a wrapper over the `BlstSig` type.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -142,6 +144,12 @@ impl Signature {
             Some(aggr_sig),
             None,
         )
+        .map_err(|e| match e {
+            MultiSignatureError::SignatureInvalid(_) => {
+                MultiSignatureError::AggregateSignatureInvalid
+            }
+            e => e,
+        })
     }
 
     /// Batch verify several sets of signatures with their corresponding verification keys.
@@ -192,7 +200,7 @@ impl<'a> Sum<&'a Self> for Signature {
 
 impl PartialOrd for Signature {
     fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
-        Some(std::cmp::Ord::cmp(self, other))
+        Some(core::cmp::Ord::cmp(self, other))
     }
 }
 
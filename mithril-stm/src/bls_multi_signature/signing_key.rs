@@ -1,14 +1,20 @@
 use crate::bls_multi_signature::signature::Signature;
 use crate::error::{blst_err_to_mithril, MultiSignatureError};
 use blst::min_sig::SecretKey as BlstSk;
+use core::fmt;
+#[cfg(feature = "std")]
 use rand_core::{CryptoRng, RngCore};
+use zeroize::{Zeroize, ZeroizeOnDrop};
 
 /// MultiSig secret key, which is a wrapper over the BlstSk type from the blst
 /// library.
-#[derive(Debug, Clone)]
+///
+/// The key is zeroized when dropped, and its `Debug` output is redacted.
+#[derive(Clone)]
 pub struct SigningKey(pub BlstSk);
 
 impl SigningKey {
+    #[cfg(feature = "std")]
     /// Generate a secret key
     pub fn gen(rng: &mut (impl RngCore + CryptoRng)) -> Self {
         let mut ikm = [0u8; 32];
@@ -45,3 +51,18 @@ impl SigningKey {
         self.0.clone()
     }
 }
+
+impl fmt::Debug for SigningKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("SigningKey(<redacted>)")
+    }
+}
+
+impl Zeroize for SigningKey {
+    fn zeroize(&mut self) {
+        self.0.zeroize();
+    }
+}
+
+// The blst secret key is zeroized when dropped.
+impl ZeroizeOnDrop for SigningKey {}
@@ -3,15 +3,25 @@ use crate::bls_multi_signature::proof_of_possession::ProofOfPossession;
 use crate::bls_multi_signature::signing_key::SigningKey;
 use crate::bls_multi_signature::POP;
 use crate::error::{blst_err_to_mithril, MultiSignatureError};
+#[cfg(not(feature = "std"))]
+use crate::no_std_prelude::*;
 use blst::min_sig::{AggregatePublicKey, PublicKey as BlstVk};
 use blst::BLST_ERROR;
-use serde::{Deserialize, Serialize};
-use std::{
+use core::{
     cmp::Ordering,
     fmt::{Display, Formatter},
     hash::{Hash, Hasher},
     iter::Sum,
 };
+use serde::{Deserialize, Serialize};
+#[cfg(feature = "std")]
+use {
+    crate::bls_multi_signature::helper::unsafe_helpers::{
+        p1_affine_to_sig, p1_in_g1, p2_affine_to_vk, sig_to_p1, vk_from_p2_affine,
+    },
+    blake2::{digest::consts::U16, Blake2b, Digest},
+    blst::{blst_p1, blst_p2, p1_affines, p2_affines},
+};
 
 /// MultiSig verification key, which is a wrapper over the BlstVk (element in G2)
 /// from the blst library.
@@ -60,7 +70,7 @@ impl VerificationKey {
 }
 
 impl Display for VerificationKey {
-    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
         write!(f, "{:?}", self.to_bytes())
     }
 }
@@ -81,7 +91,7 @@ impl Eq for VerificationKey {}
 
 impl PartialOrd for VerificationKey {
     fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
-        Some(std::cmp::Ord::cmp(self, other))
+        Some(core::cmp::Ord::cmp(self, other))
     }
 }
 
@@ -135,14 +145,7 @@ impl VerificationKeyPoP {
     pub fn check(&self) -> Result<(), MultiSignatureError> {
         match self.vk.to_blst_vk().validate() {
             Ok(_) => {
-                let result = verify_pairing(&self.vk, &self.pop);
-                if !(self
-                    .pop
-                    .to_k1()
-                    .verify(false, POP, &[], &[], &self.vk.to_blst_vk(), false)
-                    == BLST_ERROR::BLST_SUCCESS
-                    && result)
-                {
+                if !self.is_proof_of_possession_valid() {
                     return Err(MultiSignatureError::KeyInvalid(Box::new(*self)));
                 }
                 Ok(())
@@ -151,6 +154,73 @@ impl VerificationKeyPoP {
         }
     }
 
+    /// Check the proofs of possession of a batch of keys.
+    ///
+    /// The verification keys and the proofs of possession are combined with pseudo-random scalars,
+    /// derived from the hash of all the keys, so the two pairing checks of `check` are done once
+    /// for the whole batch. For the combination to be sound, the points of each proof of
+    /// possession must be in the prime order subgroup, which is checked individually.
+    /// # Error
+    /// The function fails with `MultiSignatureError::BatchInvalid` if the combined proof of
+    /// possession is invalid, which means that at least one key is invalid: `check` must then be
+    /// used to identify the invalid keys.
+    #[cfg(feature = "std")]
+    pub fn batch_check(keys: &[Self]) -> Result<(), MultiSignatureError> {
+        match keys {
+            [] => return Ok(()),
+            [key] => return key.check(),
+            _ => {}
+        }
+
+        let mut hashed_keys = Blake2b::<U16>::new();
+        for key in keys {
+            if let Err(e) = key.vk.to_blst_vk().validate() {
+                return blst_err_to_mithril(e, None, Some(key.vk));
+            }
+            if key.pop.to_k1().validate(true).is_err() || !p1_in_g1(&key.pop.to_k2()) {
+                return Err(MultiSignatureError::KeyInvalid(Box::new(*key)));
+            }
+            hashed_keys.update(key.to_bytes());
+        }
+
+        let mut scalars = Vec::with_capacity(keys.len() * 16);
+        for index in 0..keys.len() {
+            let mut hasher = hashed_keys.clone();
+            hasher.update(index.to_be_bytes());
+            scalars.extend_from_slice(hasher.finalize().as_slice());
+        }
+
+        let vks: Vec<blst_p2> = keys.iter().map(|key| vk_from_p2_affine(&key.vk)).collect();
+        let k1s: Vec<blst_p1> = keys.iter().map(|key| sig_to_p1(&key.pop.to_k1())).collect();
+        let k2s: Vec<blst_p1> = keys.iter().map(|key| key.pop.to_k2()).collect();
+
+        let combined_key = Self {
+            vk: VerificationKey(p2_affine_to_vk(
+                &p2_affines::from(vks.as_slice()).mult(&scalars, 128),
+            )),
+            pop: ProofOfPossession::from_points(
+                p1_affine_to_sig(&p1_affines::from(k1s.as_slice()).mult(&scalars, 128)),
+                p1_affines::from(k2s.as_slice()).mult(&scalars, 128),
+            ),
+        };
+
+        if combined_key.is_proof_of_possession_valid() {
+            Ok(())
+        } else {
+            Err(MultiSignatureError::BatchInvalid)
+        }
+    }
+
+    /// Check the two pairings of the proof of possession, without validating the verification key.
+    fn is_proof_of_possession_valid(&self) -> bool {
+        verify_pairing(&self.vk, &self.pop)
+            && self
+                .pop
+                .to_k1()
+                .verify(false, POP, &[], &[], &self.vk.to_blst_vk(), false)
+                == BLST_ERROR::BLST_SUCCESS
+    }
+
     /// Convert to a 144 byte string.
     ///
     /// # Layout
@@ -1,10 +1,10 @@
 use crate::stm::Stake;
 #[cfg(any(feature = "num-integer-backend", target_family = "wasm", windows))]
 use {
+    core::ops::Neg,
     num_bigint::{BigInt, Sign},
     num_rational::Ratio,
     num_traits::{One, Signed},
-    std::ops::Neg,
 };
 
 #[cfg(any(feature = "num-integer-backend", target_family = "wasm", windows))]
@@ -42,13 +42,27 @@ pub(crate) fn ev_lt_phi(phi_f: f64, ev: [u8; 64], stake: Stake, total_stake: Sta
     let q = Ratio::new_raw(ev_max.clone(), ev_max - ev);
 
     let c =
-        Ratio::from_float((1.0 - phi_f).ln()).expect("Only fails if the float is infinite or NaN.");
+        Ratio::from_float(ln(1.0 - phi_f)).expect("Only fails if the float is infinite or NaN.");
     let w = Ratio::new_raw(BigInt::from(stake), BigInt::from(total_stake));
     let x = (w * c).neg();
     // Now we compute a taylor function that breaks when the result is known.
     taylor_comparison(1000, q, x)
 }
 
+#[cfg(all(
+    any(feature = "num-integer-backend", target_family = "wasm", windows),
+    feature = "std"
+))]
+fn ln(x: f64) -> f64 {
+    x.ln()
+}
+
+#[cfg(not(feature = "std"))]
+/// Natural logarithm, computed by `libm` as the standard library is not available.
+fn ln(x: f64) -> f64 {
+    libm::log(x)
+}
+
 #[cfg(any(feature = "num-integer-backend", target_family = "wasm", windows))]
 /// Checks if cmp < exp(x). Uses error approximation for an early stop. Whenever the value being
 /// compared, `cmp`, is smaller (or greater) than the current approximation minus an `error_term`
@@ -0,0 +1,300 @@
+//! Versioned, self-describing binary encoding of the STM types.
+//!
+//! The `to_bytes` functions of the STM types produce raw layouts, without any information about
+//! the type or the layout of the encoded value. The versioned encoding wraps these layouts in an
+//! envelope:
+//!
+//! | Field          | Size (bytes) | Content                              |
+//! |----------------|--------------|--------------------------------------|
+//! | Magic          | 4            | [ENVELOPE_MAGIC], `MSTM` in ASCII    |
+//! | Type tag       | 1            | [StmTypeTag] of the encoded value    |
+//! | Format version | 1            | Layout of the payload                |
+//! | Payload        | variable     | Encoded value                        |
+//!
+//! The payload of the format version `1` is the legacy layout produced by `to_bytes`. A new
+//! layout gets a new format version, while the decoders keep accepting the previous versions, so
+//! values encoded with an older version, such as stored certificates, can still be read.
+//!
+//! The decoders also accept the legacy layouts without envelope. All of them start with a big
+//! endian `u64` (a length, a stake or an index) that would need to be higher than `0x4D << 56` to
+//! start with the magic, which is far above any realistic value, so an envelope and a legacy
+//! layout can't be confused. The layout of an [AggregateSignature] starts with its proof type,
+//! which is lower than `0x4D` as well.
+
+use blake2::digest::{consts::U32, Digest, FixedOutput};
+use blake2::Blake2b;
+
+use crate::aggregate_proof::AggregateSignature;
+use crate::error::StmEncodingError;
+use crate::merkle_tree::{BatchPath, Path};
+#[cfg(not(feature = "std"))]
+use crate::no_std_prelude::*;
+#[cfg(feature = "std")]
+use crate::stm::StmInitializer;
+use crate::stm::{StmAggrSig, StmSig};
+
+/// Magic bytes starting the versioned encodings, `MSTM` in ASCII.
+pub const ENVELOPE_MAGIC: [u8; 4] = *b"MSTM";
+
+/// Size, in bytes, of the envelope header: magic, type tag and format version.
+pub const ENVELOPE_HEADER_SIZE: usize = ENVELOPE_MAGIC.len() + 2;
+
+/// Format version whose payload is the legacy layout produced by `to_bytes`.
+pub const FORMAT_VERSION_LEGACY_LAYOUT: u8 = 1;
+
+/// Format version used by the encoders.
+pub const CURRENT_FORMAT_VERSION: u8 = FORMAT_VERSION_LEGACY_LAYOUT;
+
+/// Tag identifying the type of a value in the versioned encoding.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[repr(u8)]
+pub enum StmTypeTag {
+    /// A [StmSig]
+    Signature = 1,
+    /// A [StmAggrSig]
+    AggregateSignature = 2,
+    /// A [StmInitializer]
+    Initializer = 3,
+    /// A merkle tree path
+    Path = 4,
+    /// A merkle tree batch path
+    BatchPath = 5,
+    /// An encrypted [StmInitializer], see the `encryption` module
+    EncryptedInitializer = 6,
+    /// An [AggregateSignature] tagged with its proof type
+    TaggedAggregateSignature = 7,
+}
+
+impl TryFrom<u8> for StmTypeTag {
+    type Error = StmEncodingError;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            1 => Ok(Self::Signature),
+            2 => Ok(Self::AggregateSignature),
+            3 => Ok(Self::Initializer),
+            4 => Ok(Self::Path),
+            5 => Ok(Self::BatchPath),
+            6 => Ok(Self::EncryptedInitializer),
+            7 => Ok(Self::TaggedAggregateSignature),
+            _ => Err(StmEncodingError::UnknownTypeTag(value)),
+        }
+    }
+}
+
+/// Versioned, self-describing binary encoding of a STM type.
+pub trait StmVersionedEncoding: Sized {
+    /// Tag of the type in the envelope.
+    const TYPE_TAG: StmTypeTag;
+
+    /// Encode the value with the legacy layout, without envelope.
+    fn to_legacy_bytes(&self) -> Vec<u8>;
+
+    /// Decode a value encoded with the legacy layout, without envelope.
+    fn from_legacy_bytes(bytes: &[u8]) -> Result<Self, StmEncodingError>;
+
+    /// Encode the value in an envelope, with the [CURRENT_FORMAT_VERSION].
+    fn to_versioned_bytes(&self) -> Vec<u8> {
+        let payload = self.to_legacy_bytes();
+        let mut out = Vec::with_capacity(ENVELOPE_HEADER_SIZE + payload.len());
+        out.extend_from_slice(&ENVELOPE_MAGIC);
+        out.push(Self::TYPE_TAG as u8);
+        out.push(CURRENT_FORMAT_VERSION);
+        out.extend_from_slice(&payload);
+
+        out
+    }
+
+    /// Decode a value encoded in an envelope with any supported format version, or encoded with
+    /// the legacy layout without envelope.
+    ///
+    /// # Error
+    /// The function fails if the envelope holds another type, if its format version is not
+    /// supported or if the payload can't be decoded.
+    fn from_versioned_bytes(bytes: &[u8]) -> Result<Self, StmEncodingError> {
+        if !bytes.starts_with(&ENVELOPE_MAGIC) {
+            return Self::from_legacy_bytes(bytes);
+        }
+        if bytes.len() < ENVELOPE_HEADER_SIZE {
+            return Err(StmEncodingError::HeaderTruncated);
+        }
+
+        let type_tag = StmTypeTag::try_from(bytes[ENVELOPE_MAGIC.len()])?;
+        if type_tag != Self::TYPE_TAG {
+            return Err(StmEncodingError::TypeMismatch {
+                expected: Self::TYPE_TAG,
+                found: type_tag,
+            });
+        }
+
+        let payload = &bytes[ENVELOPE_HEADER_SIZE..];
+        match bytes[ENVELOPE_MAGIC.len() + 1] {
+            FORMAT_VERSION_LEGACY_LAYOUT => Self::from_legacy_bytes(payload),
+            version => Err(StmEncodingError::UnsupportedVersion(type_tag, version)),
+        }
+    }
+}
+
+impl StmVersionedEncoding for StmSig {
+    const TYPE_TAG: StmTypeTag = StmTypeTag::Signature;
+
+    fn to_legacy_bytes(&self) -> Vec<u8> {
+        self.to_bytes()
+    }
+
+    fn from_legacy_bytes(bytes: &[u8]) -> Result<Self, StmEncodingError> {
+        // The digest is not used to decode a single signature
+        StmSig::from_bytes::<Blake2b<U32>>(bytes)
+            .map_err(|_| StmEncodingError::PayloadInvalid(Self::TYPE_TAG))
+    }
+}
+
+impl<D: Clone + Digest + FixedOutput + Send + Sync> StmVersionedEncoding for StmAggrSig<D> {
+    const TYPE_TAG: StmTypeTag = StmTypeTag::AggregateSignature;
+
+    fn to_legacy_bytes(&self) -> Vec<u8> {
+        self.to_bytes()
+    }
+
+    fn from_legacy_bytes(bytes: &[u8]) -> Result<Self, StmEncodingError> {
+        StmAggrSig::from_bytes(bytes).map_err(|_| StmEncodingError::PayloadInvalid(Self::TYPE_TAG))
+    }
+}
+
+impl<D: Clone + Digest + FixedOutput + Send + Sync> StmVersionedEncoding for AggregateSignature<D> {
+    const TYPE_TAG: StmTypeTag = StmTypeTag::TaggedAggregateSignature;
+
+    fn to_legacy_bytes(&self) -> Vec<u8> {
+        self.to_bytes()
+    }
+
+    fn from_legacy_bytes(bytes: &[u8]) -> Result<Self, StmEncodingError> {
+        AggregateSignature::from_bytes(bytes)
+            .map_err(|_| StmEncodingError::PayloadInvalid(Self::TYPE_TAG))
+    }
+}
+
+#[cfg(feature = "std")]
+impl StmVersionedEncoding for StmInitializer {
+    const TYPE_TAG: StmTypeTag = StmTypeTag::Initializer;
+
+    fn to_legacy_bytes(&self) -> Vec<u8> {
+        self.to_bytes().to_vec()
+    }
+
+    fn from_legacy_bytes(bytes: &[u8]) -> Result<Self, StmEncodingError> {
+        StmInitializer::from_bytes(bytes)
+            .map_err(|_| StmEncodingError::PayloadInvalid(Self::TYPE_TAG))
+    }
+}
+
+impl<D: Clone + Digest + FixedOutput> StmVersionedEncoding for Path<D> {
+    const TYPE_TAG: StmTypeTag = StmTypeTag::Path;
+
+    fn to_legacy_bytes(&self) -> Vec<u8> {
+        self.to_bytes()
+    }
+
+    fn from_legacy_bytes(bytes: &[u8]) -> Result<Self, StmEncodingError> {
+        Path::from_bytes(bytes).map_err(|_| StmEncodingError::PayloadInvalid(Self::TYPE_TAG))
+    }
+}
+
+impl<D: Clone + Digest + FixedOutput> StmVersionedEncoding for BatchPath<D> {
+    const TYPE_TAG: StmTypeTag = StmTypeTag::BatchPath;
+
+    fn to_legacy_bytes(&self) -> Vec<u8> {
+        self.to_bytes()
+    }
+
+    fn from_legacy_bytes(bytes: &[u8]) -> Result<Self, StmEncodingError> {
+        BatchPath::from_bytes(bytes).map_err(|_| StmEncodingError::PayloadInvalid(Self::TYPE_TAG))
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use rand_chacha::ChaCha20Rng;
+    use rand_core::SeedableRng;
+
+    use crate::stm::StmParameters;
+
+    use super::*;
+
+    fn initializer() -> StmInitializer {
+        let mut rng = ChaCha20Rng::from_seed([0u8; 32]);
+        let params = StmParameters {
+            m: 10,
+            k: 3,
+            phi_f: 1.0,
+        };
+
+        StmInitializer::setup(params, 10, &mut rng)
+    }
+
+    #[test]
+    fn versioned_bytes_starts_with_the_envelope_header() {
+        let initializer = initializer();
+
+        let bytes = initializer.to_versioned_bytes();
+
+        assert_eq!(ENVELOPE_MAGIC, bytes[..4]);
+        assert_eq!(StmTypeTag::Initializer as u8, bytes[4]);
+        assert_eq!(CURRENT_FORMAT_VERSION, bytes[5]);
+        assert_eq!(initializer.to_bytes().as_slice(), &bytes[6..]);
+    }
+
+    #[test]
+    fn decode_both_versioned_and_legacy_bytes() {
+        let initializer = initializer();
+
+        let from_versioned =
+            StmInitializer::from_versioned_bytes(&initializer.to_versioned_bytes()).unwrap();
+        let from_legacy = StmInitializer::from_versioned_bytes(&initializer.to_bytes()).unwrap();
+
+        assert_eq!(initializer.to_bytes(), from_versioned.to_bytes());
+        assert_eq!(initializer.to_bytes(), from_legacy.to_bytes());
+    }
+
+    #[test]
+    fn decode_fails_if_the_envelope_holds_another_type() {
+        let mut bytes = initializer().to_versioned_bytes();
+        bytes[4] = StmTypeTag::Signature as u8;
+
+        assert_eq!(
+            Some(StmEncodingError::TypeMismatch {
+                expected: StmTypeTag::Initializer,
+                found: StmTypeTag::Signature
+            }),
+            StmInitializer::from_versioned_bytes(&bytes).err()
+        );
+
+        bytes[4] = 42;
+        assert_eq!(
+            Some(StmEncodingError::UnknownTypeTag(42)),
+            StmInitializer::from_versioned_bytes(&bytes).err()
+        );
+    }
+
+    #[test]
+    fn decode_fails_if_the_format_version_is_not_supported() {
+        let mut bytes = initializer().to_versioned_bytes();
+        bytes[5] = CURRENT_FORMAT_VERSION + 1;
+
+        assert_eq!(
+            Some(StmEncodingError::UnsupportedVersion(
+                StmTypeTag::Initializer,
+                CURRENT_FORMAT_VERSION + 1
+            )),
+            StmInitializer::from_versioned_bytes(&bytes).err()
+        );
+    }
+
+    #[test]
+    fn decode_fails_if_the_envelope_header_is_truncated() {
+        assert_eq!(
+            Some(StmEncodingError::HeaderTruncated),
+            StmInitializer::from_versioned_bytes(&ENVELOPE_MAGIC).err()
+        );
+    }
+}
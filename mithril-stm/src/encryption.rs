@@ -0,0 +1,391 @@
+//! Authenticated encryption of the STM secret key material.
+//!
+//! A [StmInitializer] holds the secret key of a party, it must be encrypted before being
+//! persisted. The encrypted export uses the [encoding](crate::encoding) envelope, with the
+//! [StmTypeTag::EncryptedInitializer] type tag:
+//!
+//! | Field          | Size (bytes) | Content                                          |
+//! |----------------|--------------|--------------------------------------------------|
+//! | Envelope       | 6            | Magic, type tag and format version               |
+//! | Key derivation | 1            | [StmKeyDerivation] used to derive the cipher key |
+//! | Salt           | 0 or 16      | Argon2id salt, only for a passphrase             |
+//! | Nonce          | 12           | ChaCha20-Poly1305 nonce                          |
+//! | Ciphertext     | 272          | Encrypted `StmInitializer::to_bytes` and its tag |
+//!
+//! The cipher is ChaCha20-Poly1305, whose associated data is the header preceding the nonce, so
+//! the envelope, the key derivation and the salt are authenticated along with the ciphertext.
+//! The cipher key is either a 32 bytes key, usually read from a key file, or derived from a
+//! passphrase with Argon2id (with its default parameters: 19 MiB of memory, 2 iterations).
+
+use argon2::Argon2;
+use chacha20poly1305::aead::{Aead, KeyInit, Payload};
+use chacha20poly1305::{ChaCha20Poly1305, Key, Nonce};
+use core::fmt;
+use rand_core::{CryptoRng, RngCore};
+use zeroize::Zeroizing;
+
+use crate::encoding::{StmTypeTag, ENVELOPE_HEADER_SIZE, ENVELOPE_MAGIC};
+use crate::error::{StmEncodingError, StmEncryptionError};
+use crate::stm::StmInitializer;
+
+/// Format version of the encrypted initializers.
+pub const ENCRYPTED_FORMAT_VERSION: u8 = 1;
+
+/// Size, in bytes, of a cipher key.
+pub const ENCRYPTION_KEY_SIZE: usize = 32;
+
+const SALT_SIZE: usize = 16;
+const NONCE_SIZE: usize = 12;
+
+/// Key derivation used to obtain the cipher key of an encrypted export.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[repr(u8)]
+pub enum StmKeyDerivation {
+    /// The cipher key is used as is
+    None = 0,
+    /// The cipher key is derived from a passphrase with Argon2id
+    Argon2id = 1,
+}
+
+impl TryFrom<u8> for StmKeyDerivation {
+    type Error = StmEncryptionError;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(Self::None),
+            1 => Ok(Self::Argon2id),
+            _ => Err(StmEncryptionError::UnknownKeyDerivation(value)),
+        }
+    }
+}
+
+/// Secret protecting an encrypted export: either a key or a passphrase.
+///
+/// The secret is zeroized when dropped, and its `Debug` output is redacted.
+#[derive(Clone)]
+pub enum StmEncryptionKey {
+    /// A random key, usually read from a key file
+    Key(Zeroizing<[u8; ENCRYPTION_KEY_SIZE]>),
+    /// A passphrase, stretched with Argon2id
+    Passphrase(Zeroizing<String>),
+}
+
+impl StmEncryptionKey {
+    /// Generate a random key, to be stored in a key file.
+    pub fn generate<R: RngCore + CryptoRng>(rng: &mut R) -> Self {
+        let mut key = Zeroizing::new([0u8; ENCRYPTION_KEY_SIZE]);
+        rng.fill_bytes(key.as_mut());
+
+        Self::Key(key)
+    }
+
+    /// Build a key from its bytes.
+    ///
+    /// # Error
+    /// The function fails if the bytes are not [ENCRYPTION_KEY_SIZE] long.
+    pub fn from_key_bytes(bytes: &[u8]) -> Result<Self, StmEncryptionError> {
+        let key: [u8; ENCRYPTION_KEY_SIZE] = bytes
+            .try_into()
+            .map_err(|_| StmEncryptionError::InvalidKeyLength(bytes.len()))?;
+
+        Ok(Self::Key(Zeroizing::new(key)))
+    }
+
+    /// Build a key from a passphrase.
+    pub fn from_passphrase(passphrase: &str) -> Self {
+        Self::Passphrase(Zeroizing::new(passphrase.to_string()))
+    }
+
+    /// Bytes of the key, if it's not a passphrase.
+    pub fn key_bytes(&self) -> Option<&[u8; ENCRYPTION_KEY_SIZE]> {
+        match self {
+            Self::Key(key) => Some(key),
+            Self::Passphrase(_) => None,
+        }
+    }
+
+    fn key_derivation(&self) -> StmKeyDerivation {
+        match self {
+            Self::Key(_) => StmKeyDerivation::None,
+            Self::Passphrase(_) => StmKeyDerivation::Argon2id,
+        }
+    }
+
+    fn cipher(&self, salt: &[u8]) -> Result<ChaCha20Poly1305, StmEncryptionError> {
+        match self {
+            Self::Key(key) => Ok(ChaCha20Poly1305::new(Key::from_slice(key.as_ref()))),
+            Self::Passphrase(passphrase) => {
+                let mut key = Zeroizing::new([0u8; ENCRYPTION_KEY_SIZE]);
+                Argon2::default()
+                    .hash_password_into(passphrase.as_bytes(), salt, key.as_mut())
+                    .map_err(|_| StmEncryptionError::KeyDerivation)?;
+
+                Ok(ChaCha20Poly1305::new(Key::from_slice(key.as_ref())))
+            }
+        }
+    }
+}
+
+impl fmt::Debug for StmEncryptionKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Key(_) => f.write_str("Key(<redacted>)"),
+            Self::Passphrase(_) => f.write_str("Passphrase(<redacted>)"),
+        }
+    }
+}
+
+fn salt_size(key_derivation: StmKeyDerivation) -> usize {
+    match key_derivation {
+        StmKeyDerivation::None => 0,
+        StmKeyDerivation::Argon2id => SALT_SIZE,
+    }
+}
+
+impl StmInitializer {
+    /// Encrypt the initializer with the given key, see the [module documentation](self) for
+    /// the layout.
+    ///
+    /// # Error
+    /// The function fails if the cipher key can't be derived from the passphrase.
+    pub fn to_encrypted_bytes<R: RngCore + CryptoRng>(
+        &self,
+        key: &StmEncryptionKey,
+        rng: &mut R,
+    ) -> Result<Vec<u8>, StmEncryptionError> {
+        let key_derivation = key.key_derivation();
+        let mut header = Vec::with_capacity(ENVELOPE_HEADER_SIZE + 1 + SALT_SIZE);
+        header.extend_from_slice(&ENVELOPE_MAGIC);
+        header.push(StmTypeTag::EncryptedInitializer as u8);
+        header.push(ENCRYPTED_FORMAT_VERSION);
+        header.push(key_derivation as u8);
+        let mut salt = [0u8; SALT_SIZE];
+        rng.fill_bytes(&mut salt);
+        header.extend_from_slice(&salt[..salt_size(key_derivation)]);
+
+        let mut nonce = [0u8; NONCE_SIZE];
+        rng.fill_bytes(&mut nonce);
+        let plaintext = Zeroizing::new(self.to_bytes());
+        let ciphertext = key
+            .cipher(&header[ENVELOPE_HEADER_SIZE + 1..])?
+            .encrypt(
+                Nonce::from_slice(&nonce),
+                Payload {
+                    msg: plaintext.as_ref(),
+                    aad: &header,
+                },
+            )
+            .map_err(|_| StmEncryptionError::EncryptionFailed)?;
+
+        let mut out = header;
+        out.extend_from_slice(&nonce);
+        out.extend_from_slice(&ciphertext);
+
+        Ok(out)
+    }
+
+    /// Decrypt an initializer encrypted by [to_encrypted_bytes](Self::to_encrypted_bytes).
+    ///
+    /// # Error
+    /// The function fails if the envelope or the key derivation are not supported, if the key
+    /// doesn't match the key derivation, or if the decryption fails, which happens with a wrong
+    /// key or when the bytes were altered.
+    pub fn from_encrypted_bytes(
+        bytes: &[u8],
+        key: &StmEncryptionKey,
+    ) -> Result<Self, StmEncryptionError> {
+        let type_tag = StmTypeTag::EncryptedInitializer;
+        if !bytes.starts_with(&ENVELOPE_MAGIC) || bytes.len() < ENVELOPE_HEADER_SIZE + 1 {
+            return Err(StmEncodingError::HeaderTruncated.into());
+        }
+        let found = StmTypeTag::try_from(bytes[ENVELOPE_MAGIC.len()])?;
+        if found != type_tag {
+            return Err(StmEncodingError::TypeMismatch {
+                expected: type_tag,
+                found,
+            }
+            .into());
+        }
+        let version = bytes[ENVELOPE_MAGIC.len() + 1];
+        if version != ENCRYPTED_FORMAT_VERSION {
+            return Err(StmEncodingError::UnsupportedVersion(type_tag, version).into());
+        }
+
+        let key_derivation = StmKeyDerivation::try_from(bytes[ENVELOPE_HEADER_SIZE])?;
+        if key_derivation != key.key_derivation() {
+            return Err(StmEncryptionError::KeyDerivationMismatch(key_derivation));
+        }
+        let header_size = ENVELOPE_HEADER_SIZE + 1 + salt_size(key_derivation);
+        if bytes.len() < header_size + NONCE_SIZE {
+            return Err(StmEncryptionError::Truncated);
+        }
+        let (header, body) = bytes.split_at(header_size);
+        let (nonce, ciphertext) = body.split_at(NONCE_SIZE);
+
+        let plaintext = Zeroizing::new(
+            key.cipher(&header[ENVELOPE_HEADER_SIZE + 1..])?
+                .decrypt(
+                    Nonce::from_slice(nonce),
+                    Payload {
+                        msg: ciphertext,
+                        aad: header,
+                    },
+                )
+                .map_err(|_| StmEncryptionError::DecryptionFailed)?,
+        );
+
+        StmInitializer::from_bytes(&plaintext)
+            .map_err(|_| StmEncodingError::PayloadInvalid(type_tag).into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rand_chacha::ChaCha20Rng;
+    use rand_core::SeedableRng;
+
+    use crate::stm::StmParameters;
+
+    use super::*;
+
+    fn initializer() -> StmInitializer {
+        let mut rng = ChaCha20Rng::from_seed([0u8; 32]);
+        let params = StmParameters {
+            m: 10,
+            k: 3,
+            phi_f: 1.0,
+        };
+
+        StmInitializer::setup(params, 10, &mut rng)
+    }
+
+    fn key() -> StmEncryptionKey {
+        StmEncryptionKey::generate(&mut ChaCha20Rng::from_seed([1u8; 32]))
+    }
+
+    #[test]
+    fn encrypted_bytes_do_not_contain_the_secret_key() {
+        let initializer = initializer();
+        let mut rng = ChaCha20Rng::from_seed([2u8; 32]);
+
+        let bytes = initializer.to_encrypted_bytes(&key(), &mut rng).unwrap();
+
+        let secret_key = &initializer.to_bytes()[32..64];
+        assert!(!bytes.windows(secret_key.len()).any(|w| w == secret_key));
+        assert_eq!(
+            ENVELOPE_HEADER_SIZE + 1 + NONCE_SIZE + 256 + 16,
+            bytes.len()
+        );
+    }
+
+    #[test]
+    fn decrypt_with_the_key_used_to_encrypt() {
+        let initializer = initializer();
+        let key = key();
+        let mut rng = ChaCha20Rng::from_seed([2u8; 32]);
+
+        let bytes = initializer.to_encrypted_bytes(&key, &mut rng).unwrap();
+        let decrypted = StmInitializer::from_encrypted_bytes(&bytes, &key).unwrap();
+
+        assert_eq!(initializer.to_bytes(), decrypted.to_bytes());
+    }
+
+    #[test]
+    fn decrypt_with_the_passphrase_used_to_encrypt() {
+        let initializer = initializer();
+        let key = StmEncryptionKey::from_passphrase("correct horse battery staple");
+        let mut rng = ChaCha20Rng::from_seed([2u8; 32]);
+
+        let bytes = initializer.to_encrypted_bytes(&key, &mut rng).unwrap();
+        let decrypted = StmInitializer::from_encrypted_bytes(&bytes, &key).unwrap();
+
+        assert_eq!(initializer.to_bytes(), decrypted.to_bytes());
+        assert_eq!(
+            StmEncryptionError::DecryptionFailed,
+            StmInitializer::from_encrypted_bytes(
+                &bytes,
+                &StmEncryptionKey::from_passphrase("wrong passphrase")
+            )
+            .unwrap_err()
+        );
+    }
+
+    #[test]
+    fn decrypt_fails_with_another_key() {
+        let mut rng = ChaCha20Rng::from_seed([2u8; 32]);
+        let bytes = initializer().to_encrypted_bytes(&key(), &mut rng).unwrap();
+        let other_key = StmEncryptionKey::generate(&mut rng);
+
+        assert_eq!(
+            StmEncryptionError::DecryptionFailed,
+            StmInitializer::from_encrypted_bytes(&bytes, &other_key).unwrap_err()
+        );
+        assert_eq!(
+            StmEncryptionError::KeyDerivationMismatch(StmKeyDerivation::None),
+            StmInitializer::from_encrypted_bytes(
+                &bytes,
+                &StmEncryptionKey::from_passphrase("passphrase")
+            )
+            .unwrap_err()
+        );
+    }
+
+    #[test]
+    fn decrypt_fails_if_the_bytes_are_altered() {
+        let key = key();
+        let mut rng = ChaCha20Rng::from_seed([2u8; 32]);
+        let bytes = initializer().to_encrypted_bytes(&key, &mut rng).unwrap();
+
+        for index in [ENVELOPE_HEADER_SIZE + 1, bytes.len() - 1] {
+            let mut altered_bytes = bytes.clone();
+            altered_bytes[index] ^= 1;
+
+            assert_eq!(
+                StmEncryptionError::DecryptionFailed,
+                StmInitializer::from_encrypted_bytes(&altered_bytes, &key).unwrap_err()
+            );
+        }
+        assert_eq!(
+            StmEncryptionError::Truncated,
+            StmInitializer::from_encrypted_bytes(&bytes[..ENVELOPE_HEADER_SIZE + 4], &key)
+                .unwrap_err()
+        );
+    }
+
+    #[test]
+    fn decrypt_fails_if_the_envelope_holds_another_type() {
+        let initializer = initializer();
+
+        assert_eq!(
+            StmEncryptionError::Encoding(StmEncodingError::TypeMismatch {
+                expected: StmTypeTag::EncryptedInitializer,
+                found: StmTypeTag::Initializer,
+            }),
+            StmInitializer::from_encrypted_bytes(
+                &crate::encoding::StmVersionedEncoding::to_versioned_bytes(&initializer),
+                &key()
+            )
+            .unwrap_err()
+        );
+    }
+
+    #[test]
+    fn encryption_keys_debug_is_redacted() {
+        assert_eq!("Key(<redacted>)", format!("{:?}", key()));
+        assert_eq!(
+            "Passphrase(<redacted>)",
+            format!("{:?}", StmEncryptionKey::from_passphrase("secret"))
+        );
+        assert!(!format!("{:?}", initializer())
+            .contains(&format!("{:?}", &initializer().to_bytes()[32..64])));
+    }
+
+    #[test]
+    fn key_from_bytes_must_have_the_key_size() {
+        assert_eq!(
+            StmEncryptionError::InvalidKeyLength(31),
+            StmEncryptionKey::from_key_bytes(&[0u8; 31]).unwrap_err()
+        );
+        assert!(StmEncryptionKey::from_key_bytes(&[0u8; ENCRYPTION_KEY_SIZE]).is_ok());
+    }
+}
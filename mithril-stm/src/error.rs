@@ -1,6 +1,11 @@
 //! Crate specific errors
 
+use crate::encoding::StmTypeTag;
+#[cfg(feature = "std")]
+use crate::encryption::StmKeyDerivation;
 use crate::merkle_tree::{BatchPath, Path};
+#[cfg(not(feature = "std"))]
+use crate::no_std_prelude::*;
 use blake2::digest::{Digest, FixedOutput};
 use {
     crate::bls_multi_signature::{Signature, VerificationKey, VerificationKeyPoP},
@@ -81,6 +86,14 @@ pub enum StmSignatureError {
     /// This error occurs when the the serialization of the raw bytes failed
     #[error("Invalid bytes")]
     SerializationError,
+
+    /// The signer index of the signature is not in the closed registration
+    #[error("Signer index {0} is not registered.")]
+    UnregisteredSigner(u64),
+
+    /// The signature was verified by an incremental aggregator of another message
+    #[error("The signature was verified for another message.")]
+    VerifiedForAnotherMessage,
 }
 
 impl From<MultiSignatureError> for StmSignatureError {
@@ -189,6 +202,10 @@ pub enum StmAggregateSignatureError<D: Digest + FixedOutput> {
     /// `CoreVerifier` check failed
     #[error("Core verification error: {0}")]
     CoreVerificationError(#[source] CoreVerifierError),
+
+    /// The proof type of a tagged aggregate signature is unknown
+    #[error("Unknown aggregate proof type {0}.")]
+    UnknownProofType(u8),
 }
 
 impl<D: Digest + FixedOutput> From<MerkleTreeError<D>> for StmAggregateSignatureError<D> {
@@ -238,6 +255,74 @@ impl<D: Digest + FixedOutput> From<StmSignatureError> for StmAggregateSignatureE
     }
 }
 
+/// Errors which can be output when decoding a versioned encoding.
+#[derive(Debug, Clone, thiserror::Error, PartialEq, Eq)]
+pub enum StmEncodingError {
+    /// The bytes start with the magic but are too short to hold the envelope header
+    #[error("The envelope header is truncated.")]
+    HeaderTruncated,
+
+    /// The type tag of the envelope is unknown
+    #[error("Unknown type tag {0}.")]
+    UnknownTypeTag(u8),
+
+    /// The envelope holds another type than the decoded one
+    #[error("Expected a {expected:?} but the envelope holds a {found:?}.")]
+    TypeMismatch {
+        /// Tag of the decoded type
+        expected: StmTypeTag,
+        /// Tag found in the envelope
+        found: StmTypeTag,
+    },
+
+    /// The format version of the envelope is not supported
+    #[error("Format version {1} is not supported for {0:?}.")]
+    UnsupportedVersion(StmTypeTag, u8),
+
+    /// The payload can't be decoded
+    #[error("Invalid {0:?} payload.")]
+    PayloadInvalid(StmTypeTag),
+}
+
+/// Errors which can be output when encrypting or decrypting the secret key material.
+#[cfg(feature = "std")]
+#[derive(Debug, Clone, thiserror::Error, PartialEq, Eq)]
+pub enum StmEncryptionError {
+    /// The envelope of the encrypted bytes is invalid
+    #[error("Invalid envelope of the encrypted bytes.")]
+    Encoding(#[from] StmEncodingError),
+
+    /// The key derivation is unknown
+    #[error("Unknown key derivation {0}.")]
+    UnknownKeyDerivation(u8),
+
+    /// The bytes were encrypted with a key derivation that doesn't match the given key
+    #[error(
+        "The bytes were encrypted using the key derivation {0:?} that doesn't match the given key."
+    )]
+    KeyDerivationMismatch(StmKeyDerivation),
+
+    /// A key must be 32 bytes long
+    #[error("Invalid key length {0}, expected 32 bytes.")]
+    InvalidKeyLength(usize),
+
+    /// The cipher key can't be derived from the passphrase
+    #[error("The cipher key can't be derived from the passphrase.")]
+    KeyDerivation,
+
+    /// The encrypted bytes are too short
+    #[error("The encrypted bytes are truncated.")]
+    Truncated,
+
+    /// The encryption failed
+    #[error("Encryption failed.")]
+    EncryptionFailed,
+
+    /// The decryption failed, either because of a wrong key or because the bytes were altered
+    #[error("Decryption failed: wrong key or altered bytes.")]
+    DecryptionFailed,
+}
+
 /// Errors which can be outputted by key registration.
 #[derive(Debug, Clone, thiserror::Error, PartialEq, Eq)]
 pub enum RegisterError {
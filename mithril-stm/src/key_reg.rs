@@ -1,16 +1,23 @@
 //! Key registration functionality.
-use super::stm::Stake;
-use crate::bls_multi_signature::{VerificationKey, VerificationKeyPoP};
-use crate::error::RegisterError;
-use crate::merkle_tree::{MTLeaf, MerkleTree};
-use blake2::digest::{Digest, FixedOutput};
-use std::collections::hash_map::Entry;
-use std::collections::HashMap;
-use std::sync::Arc;
+//!
+//! Only the [RegParty] type is available without the `std` feature.
+use crate::merkle_tree::MTLeaf;
+#[cfg(feature = "std")]
+use {
+    super::stm::Stake,
+    crate::bls_multi_signature::{VerificationKey, VerificationKeyPoP},
+    crate::error::RegisterError,
+    crate::merkle_tree::MerkleTree,
+    blake2::digest::{Digest, FixedOutput},
+    std::collections::hash_map::Entry,
+    std::collections::{HashMap, HashSet},
+    std::sync::Arc,
+};
 
 /// Stores a registered party with its public key and the associated stake.
 pub type RegParty = MTLeaf;
 
+#[cfg(feature = "std")]
 /// Struct that collects public keys and stakes of parties.
 /// Each participant (both the signers and the clerks) need to run their own instance of the key registration.
 // todo: replace with KeyReg
@@ -19,6 +26,7 @@ pub struct KeyReg {
     keys: HashMap<VerificationKey, Stake>,
 }
 
+#[cfg(feature = "std")]
 impl KeyReg {
     /// Initialize an empty `KeyReg`.
     pub fn init() -> Self {
@@ -37,6 +45,37 @@ impl KeyReg {
         Err(RegisterError::KeyRegistered(Box::new(pk.vk)))
     }
 
+    /// Verify and register the public keys and stakes of several parties.
+    ///
+    /// The proofs of possession are verified in a single batch, and individually only if the
+    /// batch verification fails, to identify an invalid key. The keys are registered only if all
+    /// of them can be registered.
+    /// # Error
+    /// The function fails when a key is already registered or is given twice, or when a proof of
+    /// possession is invalid. The error reports the first of these keys, in the given order.
+    pub fn register_all(
+        &mut self,
+        parties: &[(Stake, VerificationKeyPoP)],
+    ) -> Result<(), RegisterError> {
+        let mut new_keys = HashSet::with_capacity(parties.len());
+        for (_, pk) in parties {
+            if self.keys.contains_key(&pk.vk) || !new_keys.insert(pk.vk) {
+                return Err(RegisterError::KeyRegistered(Box::new(pk.vk)));
+            }
+        }
+
+        let pks = parties.iter().map(|(_, pk)| *pk).collect::<Vec<_>>();
+        if VerificationKeyPoP::batch_check(&pks).is_err() {
+            for pk in &pks {
+                pk.check()?;
+            }
+        }
+
+        self.keys
+            .extend(parties.iter().map(|(stake, pk)| (pk.vk, *stake)));
+        Ok(())
+    }
+
     /// Finalize the key registration.
     /// This function disables `KeyReg::register`, consumes the instance of `self`, and returns a `ClosedKeyReg`.
     pub fn close<D>(self) -> ClosedKeyReg<D>
@@ -66,6 +105,7 @@ impl KeyReg {
     }
 }
 
+#[cfg(feature = "std")]
 /// Structure generated out of a closed registration containing the registered parties, total stake, and the merkle tree.
 /// One can only get a global `avk` out of a closed key registration.
 #[derive(Clone, Debug, PartialEq, Eq)]
@@ -78,7 +118,7 @@ pub struct ClosedKeyReg<D: Digest> {
     pub merkle_tree: Arc<MerkleTree<D>>,
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "std"))]
 mod tests {
     use super::*;
     use crate::bls_multi_signature::SigningKey;
@@ -141,5 +181,55 @@ mod tests {
                 assert!(retrieved_keys == keys);
             }
         }
+
+        #[test]
+        fn test_keyreg_register_all(stake in vec(1..1u64 << 60, 2..=10),
+                       invalid_key_index in 0..12usize,
+                       seed in any::<[u8;32]>()) {
+            let mut rng = ChaCha20Rng::from_seed(seed);
+            let mut parties = stake.iter().map(|&stake| {
+                let sk = SigningKey::gen(&mut rng);
+                (stake, VerificationKeyPoP::from(&sk))
+            }).collect::<Vec<_>>();
+            let fake_pop = VerificationKeyPoP::from(&SigningKey::gen(&mut rng)).pop;
+
+            let mut kr = KeyReg::init();
+            if invalid_key_index < parties.len() {
+                parties[invalid_key_index].1.pop = fake_pop;
+                let invalid_key = parties[invalid_key_index].1;
+
+                assert_eq!(
+                    kr.register_all(&parties),
+                    Err(RegisterError::KeyInvalid(Box::new(invalid_key)))
+                );
+                assert!(kr.keys.is_empty());
+            } else {
+                kr.register_all(&parties).unwrap();
+
+                let mut expected_kr = KeyReg::init();
+                for &(stake, pk) in &parties {
+                    expected_kr.register(stake, pk).unwrap();
+                }
+                assert_eq!(expected_kr, kr);
+
+                assert_eq!(
+                    kr.register_all(&parties[..1]),
+                    Err(RegisterError::KeyRegistered(Box::new(parties[0].1.vk)))
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_keyreg_register_all_rejects_a_key_given_twice() {
+        let mut rng = ChaCha20Rng::from_seed([0u8; 32]);
+        let pk = VerificationKeyPoP::from(&SigningKey::gen(&mut rng));
+        let mut kr = KeyReg::init();
+
+        assert_eq!(
+            kr.register_all(&[(1, pk), (2, pk)]),
+            Err(RegisterError::KeyRegistered(Box::new(pk.vk)))
+        );
+        assert!(kr.keys.is_empty());
     }
 }
@@ -1,18 +1,32 @@
 #![warn(missing_docs)]
+#![cfg_attr(not(feature = "std"), no_std)]
 #![doc = include_str!("../README.md")]
 //! Implementation of Stake-based Threshold Multisignatures
 
+extern crate alloc;
 extern crate core;
 
+pub mod aggregate_proof;
+#[cfg(feature = "std")]
+pub mod aggregation_report;
+#[cfg(feature = "std")]
+pub mod analysis;
 mod eligibility_check;
+pub mod encoding;
+#[cfg(feature = "std")]
+pub mod encryption;
 mod error;
 pub mod key_reg;
-mod merkle_tree;
+pub mod merkle_tree;
+#[cfg(feature = "std")]
+pub mod simulation;
 pub mod stm;
 
+#[cfg(feature = "std")]
+pub use crate::error::StmEncryptionError;
 pub use crate::error::{
     AggregationError, CoreVerifierError, RegisterError, StmAggregateSignatureError,
-    StmSignatureError,
+    StmEncodingError, StmSignatureError,
 };
 
 #[cfg(feature = "benchmark-internals")]
@@ -20,3 +34,18 @@ pub mod bls_multi_signature;
 
 #[cfg(not(feature = "benchmark-internals"))]
 mod bls_multi_signature;
+
+#[cfg(not(any(
+    feature = "std",
+    feature = "num-integer-backend",
+    target_family = "wasm",
+    windows
+)))]
+compile_error!("The `num-integer-backend` feature is required to build without the `std` feature");
+
+/// Items of the standard prelude that are missing from the `core` prelude, imported by the
+/// modules when the crate is built without the standard library.
+#[cfg(not(feature = "std"))]
+mod no_std_prelude {
+    pub(crate) use alloc::{boxed::Box, format, string::ToString, vec, vec::Vec};
+}
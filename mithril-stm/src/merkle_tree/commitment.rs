@@ -1,9 +1,11 @@
 use crate::error::MerkleTreeError;
 use crate::merkle_tree::{parent, sibling};
 use crate::merkle_tree::{BatchPath, MTLeaf, Path};
+#[cfg(not(feature = "std"))]
+use crate::no_std_prelude::*;
 use blake2::digest::{Digest, FixedOutput};
+use core::marker::PhantomData;
 use serde::{Deserialize, Serialize};
-use std::marker::PhantomData;
 
 /// `MerkleTree` commitment.
 /// This structure differs from `MerkleTree` in that it does not contain all elements, which are not always necessary.
@@ -2,9 +2,9 @@ use crate::bls_multi_signature::VerificationKey;
 use crate::error::MerkleTreeError;
 use crate::stm::{Stake, StmVerificationKey};
 use blake2::Blake2b;
+use core::cmp::Ordering;
 use digest::consts::U32;
 use serde::{Deserialize, Serialize};
-use std::cmp::Ordering;
 
 /// The values that are committed in the Merkle Tree.
 /// Namely, a verified `VerificationKey` and its corresponding stake.
@@ -42,7 +42,7 @@ impl PartialOrd for MTLeaf {
     /// meaning that the probability of having several signatures in the same side of the tree, is higher.
     /// This allows us to produce a more efficient batch opening of the merkle tree.
     fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
-        Some(std::cmp::Ord::cmp(self, other))
+        Some(core::cmp::Ord::cmp(self, other))
     }
 }
 
@@ -1,7 +1,9 @@
 use crate::error::MerkleTreeError;
+#[cfg(not(feature = "std"))]
+use crate::no_std_prelude::*;
 use blake2::digest::{Digest, FixedOutput};
+use core::marker::PhantomData;
 use serde::{Deserialize, Serialize};
-use std::marker::PhantomData;
 
 /// Path of hashes from root to leaf in a Merkle Tree.
 /// Contains all hashes on the path, and the index of the leaf.
@@ -3,9 +3,11 @@ use crate::merkle_tree::{
     left_child, parent, right_child, sibling, BatchPath, MTLeaf, MerkleTreeCommitment,
     MerkleTreeCommitmentBatchCompat, Path,
 };
+#[cfg(not(feature = "std"))]
+use crate::no_std_prelude::*;
 use blake2::digest::{Digest, FixedOutput};
+use core::marker::PhantomData;
 use serde::{Deserialize, Serialize};
-use std::marker::PhantomData;
 
 /// Tree of hashes, providing a commitment of data and its ordering.
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
@@ -0,0 +1,270 @@
+//! Monte Carlo simulation of the signature aggregation.
+//!
+//! A stake distribution is replayed over random messages: for each message every party takes part
+//! in the signing round with its own participation probability. The participating parties sign
+//! with [StmSigner::sign], so their lottery is evaluated by [StmSigner::check_lottery] with the
+//! eligibility check of the enabled backend, and the signatures are aggregated by [StmClerk].
+//! Nothing of the lottery is re-implemented, the simulation measures the production behavior.
+
+use std::collections::BTreeMap;
+
+use blake2::digest::{Digest, FixedOutput};
+use rand_core::{CryptoRng, RngCore};
+use rayon::prelude::*;
+
+use crate::error::{AggregationError, RegisterError};
+use crate::key_reg::KeyReg;
+use crate::stm::{Stake, StmClerk, StmInitializer, StmParameters, StmSig, StmSigner};
+
+/// Size, in bytes, of the random messages signed during the simulation.
+const MESSAGE_SIZE: usize = 32;
+
+/// A party of the simulated stake distribution.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SimulatedParty {
+    /// Stake of the party
+    pub stake: Stake,
+    /// Probability, in `[0, 1]`, that the party takes part in a signing round
+    pub participation_probability: f64,
+}
+
+/// Monte Carlo simulator of the quorum, with a registered signer for each simulated party.
+pub struct QuorumSimulator<D: Clone + Digest + FixedOutput> {
+    params: StmParameters,
+    signers: Vec<(StmSigner<D>, f64)>,
+    clerk: StmClerk<D>,
+}
+
+impl<D: Clone + Digest + FixedOutput + Send + Sync> QuorumSimulator<D> {
+    /// Register a signer for each of the given parties.
+    pub fn new<R: RngCore + CryptoRng>(
+        params: StmParameters,
+        parties: &[SimulatedParty],
+        rng: &mut R,
+    ) -> Result<Self, RegisterError> {
+        let mut key_reg = KeyReg::init();
+        let initializers = parties
+            .iter()
+            .map(|party| {
+                let initializer = StmInitializer::setup(params, party.stake, rng);
+                key_reg.register(party.stake, initializer.verification_key())?;
+
+                Ok((initializer, party.participation_probability))
+            })
+            .collect::<Result<Vec<_>, RegisterError>>()?;
+
+        let closed_reg = key_reg.close();
+        let clerk = StmClerk::from_registration(&params, &closed_reg);
+        let signers = initializers
+            .into_iter()
+            .map(|(initializer, participation_probability)| {
+                Ok((
+                    initializer.new_signer(closed_reg.clone())?,
+                    participation_probability,
+                ))
+            })
+            .collect::<Result<Vec<_>, RegisterError>>()?;
+
+        Ok(Self {
+            params,
+            signers,
+            clerk,
+        })
+    }
+
+    /// Simulate the signature of `runs` random messages.
+    ///
+    /// # Error
+    /// The function fails if the aggregation fails for another reason than a missing quorum.
+    pub fn run<R: RngCore>(
+        &self,
+        runs: u64,
+        rng: &mut R,
+    ) -> Result<QuorumSimulationReport, AggregationError> {
+        let mut report = QuorumSimulationReport {
+            params: self.params,
+            runs,
+            quorum_failures: 0,
+            signers_needed: BTreeMap::new(),
+            aggregate_signature_sizes: BTreeMap::new(),
+        };
+
+        for _ in 0..runs {
+            let mut msg = [0u8; MESSAGE_SIZE];
+            rng.fill_bytes(&mut msg);
+            // Draw the participation sequentially to keep the simulation reproducible from the
+            // rng, the signatures themselves are computed in parallel
+            let participating_signers = self
+                .signers
+                .iter()
+                .filter(|(_, participation_probability)| {
+                    uniform_f64(rng) < *participation_probability
+                })
+                .map(|(signer, _)| signer)
+                .collect::<Vec<_>>();
+            let signatures = participating_signers
+                .par_iter()
+                .filter_map(|signer| signer.sign(&msg))
+                .collect::<Vec<StmSig>>();
+
+            match self.clerk.aggregate(&signatures, &msg) {
+                Ok(aggregate_signature) => {
+                    *report
+                        .signers_needed
+                        .entry(aggregate_signature.signatures.len())
+                        .or_default() += 1;
+                    *report
+                        .aggregate_signature_sizes
+                        .entry(aggregate_signature.to_bytes().len())
+                        .or_default() += 1;
+                }
+                Err(AggregationError::NotEnoughSignatures(..)) => report.quorum_failures += 1,
+                Err(error) => return Err(error),
+            }
+        }
+
+        Ok(report)
+    }
+}
+
+/// Draw a float uniformly in `[0, 1)`.
+fn uniform_f64<R: RngCore>(rng: &mut R) -> f64 {
+    (rng.next_u64() >> 11) as f64 / (1u64 << 53) as f64
+}
+
+/// Mean of a histogram mapping values to their number of occurrences.
+fn histogram_mean(histogram: &BTreeMap<usize, u64>) -> Option<f64> {
+    let count = histogram.values().sum::<u64>();
+    if count == 0 {
+        return None;
+    }
+
+    let sum = histogram
+        .iter()
+        .map(|(value, occurrences)| *value as f64 * *occurrences as f64)
+        .sum::<f64>();
+
+    Some(sum / count as f64)
+}
+
+/// Result of a quorum simulation.
+#[derive(Debug, Clone, PartialEq)]
+pub struct QuorumSimulationReport {
+    /// Simulated parameters
+    pub params: StmParameters,
+    /// Number of simulated messages
+    pub runs: u64,
+    /// Number of messages for which the quorum was not reached
+    pub quorum_failures: u64,
+    /// Number of signers included in the aggregate signatures, mapped to the number of messages
+    pub signers_needed: BTreeMap<usize, u64>,
+    /// Size, in bytes, of the aggregate signatures, mapped to the number of messages
+    pub aggregate_signature_sizes: BTreeMap<usize, u64>,
+}
+
+impl QuorumSimulationReport {
+    /// Ratio of the simulated messages for which the quorum was not reached.
+    pub fn quorum_failure_rate(&self) -> f64 {
+        if self.runs == 0 {
+            return 0.0;
+        }
+
+        self.quorum_failures as f64 / self.runs as f64
+    }
+
+    /// Mean number of signers included in the aggregate signatures, if any was produced.
+    pub fn mean_signers_needed(&self) -> Option<f64> {
+        histogram_mean(&self.signers_needed)
+    }
+
+    /// Mean size, in bytes, of the aggregate signatures, if any was produced.
+    pub fn mean_aggregate_signature_size(&self) -> Option<f64> {
+        histogram_mean(&self.aggregate_signature_sizes)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use blake2::{digest::consts::U32, Blake2b};
+    use rand_chacha::ChaCha20Rng;
+    use rand_core::SeedableRng;
+
+    use super::*;
+
+    type D = Blake2b<U32>;
+
+    fn parties(nparties: usize, participation_probability: f64) -> Vec<SimulatedParty> {
+        (0..nparties)
+            .map(|i| SimulatedParty {
+                stake: 1 + i as Stake,
+                participation_probability,
+            })
+            .collect()
+    }
+
+    fn params() -> StmParameters {
+        StmParameters {
+            m: 50,
+            k: 5,
+            phi_f: 0.8,
+        }
+    }
+
+    #[test]
+    fn full_participation_always_reaches_the_quorum() {
+        let mut rng = ChaCha20Rng::from_seed([0u8; 32]);
+        let simulator = QuorumSimulator::<D>::new(params(), &parties(10, 1.0), &mut rng).unwrap();
+
+        let report = simulator.run(10, &mut rng).unwrap();
+
+        assert_eq!(10, report.runs);
+        assert_eq!(0, report.quorum_failures);
+        assert_eq!(10, report.signers_needed.values().sum::<u64>());
+        assert_eq!(10, report.aggregate_signature_sizes.values().sum::<u64>());
+        assert!(report
+            .signers_needed
+            .keys()
+            .all(|signers| (1..=10).contains(signers)));
+        assert!(report.mean_aggregate_signature_size().unwrap() > 0.0);
+    }
+
+    #[test]
+    fn no_participation_never_reaches_the_quorum() {
+        let mut rng = ChaCha20Rng::from_seed([0u8; 32]);
+        let simulator = QuorumSimulator::<D>::new(params(), &parties(10, 0.0), &mut rng).unwrap();
+
+        let report = simulator.run(5, &mut rng).unwrap();
+
+        assert_eq!(5, report.quorum_failures);
+        assert_eq!(1.0, report.quorum_failure_rate());
+        assert!(report.signers_needed.is_empty());
+        assert_eq!(None, report.mean_signers_needed());
+    }
+
+    #[test]
+    fn simulation_is_reproducible_from_the_rng_seed() {
+        let run_simulation = || {
+            let mut rng = ChaCha20Rng::from_seed([1u8; 32]);
+            let simulator =
+                QuorumSimulator::<D>::new(params(), &parties(10, 0.5), &mut rng).unwrap();
+            simulator.run(10, &mut rng).unwrap()
+        };
+
+        assert_eq!(run_simulation(), run_simulation());
+    }
+
+    #[test]
+    fn report_statistics() {
+        let report = QuorumSimulationReport {
+            params: params(),
+            runs: 4,
+            quorum_failures: 1,
+            signers_needed: BTreeMap::from([(2, 1), (4, 2)]),
+            aggregate_signature_sizes: BTreeMap::from([(100, 3)]),
+        };
+
+        assert_eq!(0.25, report.quorum_failure_rate());
+        assert_eq!(Some(10.0 / 3.0), report.mean_signers_needed());
+        assert_eq!(Some(100.0), report.mean_aggregate_signature_size());
+    }
+}
@@ -106,22 +106,32 @@
 //! # }
 //! ```
 
-use crate::bls_multi_signature::{Signature, SigningKey, VerificationKey, VerificationKeyPoP};
+use crate::bls_multi_signature::{Signature, VerificationKey, VerificationKeyPoP};
 use crate::eligibility_check::ev_lt_phi;
 use crate::error::{
-    AggregationError, CoreVerifierError, RegisterError, StmAggregateSignatureError,
-    StmSignatureError,
+    CoreVerifierError, RegisterError, StmAggregateSignatureError, StmSignatureError,
 };
-use crate::key_reg::{ClosedKeyReg, RegParty};
+use crate::key_reg::RegParty;
 use crate::merkle_tree::{BatchPath, MTLeaf, MerkleTreeCommitmentBatchCompat};
+#[cfg(not(feature = "std"))]
+use crate::no_std_prelude::*;
+use alloc::collections::BTreeSet;
 use blake2::digest::{Digest, FixedOutput};
-use rand_core::{CryptoRng, RngCore};
+use core::cmp::Ordering;
+use core::hash::{Hash, Hasher};
 use serde::ser::SerializeTuple;
 use serde::{Deserialize, Serialize, Serializer};
-use std::cmp::Ordering;
-use std::collections::{BTreeMap, HashMap, HashSet};
-use std::convert::{From, TryFrom, TryInto};
-use std::hash::{Hash, Hasher};
+#[cfg(feature = "std")]
+use {
+    crate::aggregate_proof::AggregateProof,
+    crate::aggregation_report::{StmAggregationReport, StmSignatureStatus},
+    crate::bls_multi_signature::SigningKey,
+    crate::error::AggregationError,
+    crate::key_reg::ClosedKeyReg,
+    rand_core::{CryptoRng, RngCore},
+    std::collections::{BTreeMap, HashMap, HashSet},
+    zeroize::{Zeroize, ZeroizeOnDrop},
+};
 
 /// The quantity of stake held by a party, represented as a `u64`.
 pub type Stake = u64;
@@ -187,9 +197,13 @@ impl StmParameters {
     }
 }
 
+#[cfg(feature = "std")]
 /// Initializer for `StmSigner`.
 /// This is the data that is used during the key registration procedure.
 /// Once the latter is finished, this instance is consumed into an `StmSigner`.
+///
+/// The secret key is zeroized when dropped and redacted from the `Debug` output. Use
+/// [to_encrypted_bytes](Self::to_encrypted_bytes) to persist an initializer.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct StmInitializer {
     /// This participant's stake.
@@ -202,6 +216,7 @@ pub struct StmInitializer {
     pub(crate) pk: StmVerificationKeyPoP,
 }
 
+#[cfg(feature = "std")]
 impl StmInitializer {
     /// Builds an `StmInitializer` that is ready to register with the key registration service.
     /// This function generates the signing and verification key with a PoP, and initialises the structure.
@@ -295,6 +310,9 @@ impl StmInitializer {
     /// * Params
     /// * Secret Key
     /// * Public key (including PoP)
+    ///
+    /// The bytes hold the secret key in clear: they should be wrapped in a
+    /// [Zeroizing](zeroize::Zeroizing) buffer and never stored unencrypted.
     pub fn to_bytes(&self) -> [u8; 256] {
         let mut out = [0u8; 256];
         out[..8].copy_from_slice(&self.stake.to_be_bytes());
@@ -324,6 +342,18 @@ impl StmInitializer {
     }
 }
 
+#[cfg(feature = "std")]
+impl Zeroize for StmInitializer {
+    fn zeroize(&mut self) {
+        self.sk.zeroize();
+    }
+}
+
+// The secret key is zeroized when dropped.
+#[cfg(feature = "std")]
+impl ZeroizeOnDrop for StmInitializer {}
+
+#[cfg(feature = "std")]
 /// Participant in the protocol can sign messages.
 /// * If the signer has `closed_reg`, then it can generate Stm certificate.
 ///     * This kind of signer can only be generated out of an `StmInitializer` and a `ClosedKeyReg`.
@@ -341,6 +371,7 @@ pub struct StmSigner<D: Digest> {
     closed_reg: Option<ClosedKeyReg<D>>,
 }
 
+#[cfg(feature = "std")]
 impl<D: Clone + Digest + FixedOutput> StmSigner<D> {
     /// This function produces a signature following the description of Section 2.4.
     /// Once the signature is produced, this function checks whether any index in `[0,..,self.params.m]`
@@ -410,6 +441,17 @@ impl<D: Clone + Digest + FixedOutput> StmSigner<D> {
     }
 }
 
+#[cfg(feature = "std")]
+impl<D: Digest> Zeroize for StmSigner<D> {
+    fn zeroize(&mut self) {
+        self.sk.zeroize();
+    }
+}
+
+// The secret key is zeroized when dropped.
+#[cfg(feature = "std")]
+impl<D: Digest> ZeroizeOnDrop for StmSigner<D> {}
+
 /// Stm aggregate key (batch compatible), which contains the merkle tree commitment and the total stake of the system.
 /// Batch Compat Merkle tree commitment includes the number of leaves in the tree in order to obtain batch path.
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -430,6 +472,7 @@ impl<D: Digest + Clone + FixedOutput> PartialEq for StmAggrVerificationKey<D> {
 
 impl<D: Digest + Clone + FixedOutput> Eq for StmAggrVerificationKey<D> {}
 
+#[cfg(feature = "std")]
 impl<D: Clone + Digest + FixedOutput> From<&ClosedKeyReg<D>> for StmAggrVerificationKey<D> {
     fn from(reg: &ClosedKeyReg<D>) -> Self {
         Self {
@@ -578,7 +621,7 @@ impl Eq for StmSig {}
 
 impl PartialOrd for StmSig {
     fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
-        Some(std::cmp::Ord::cmp(self, other))
+        Some(core::cmp::Ord::cmp(self, other))
     }
 }
 
@@ -632,6 +675,23 @@ impl Serialize for StmSigRegParty {
     }
 }
 
+#[cfg(feature = "std")]
+/// Strategy used to select the signatures included in an aggregate signature.
+///
+/// All the strategies produce aggregate signatures verifiable with `StmAggrSig::verify`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum AggregationStrategy {
+    /// Signatures are selected following the order of the indexes they won, keeping for each
+    /// index the signature with the smallest `sigma`, until `k` indexes are covered.
+    #[default]
+    IndexOrder,
+    /// Signatures winning the most indexes not yet covered are selected first, and exactly `k`
+    /// indexes are kept, to minimize the number of signers and so the size of the aggregate
+    /// signature and of its batch proof.
+    MinimalSigners,
+}
+
+#[cfg(feature = "std")]
 /// `StmClerk` can verify and aggregate `StmSig`s and verify `StmMultiSig`s.
 /// Clerks can only be generated with the registration closed.
 /// This avoids that a Merkle Tree is computed before all parties have registered.
@@ -641,6 +701,7 @@ pub struct StmClerk<D: Clone + Digest> {
     pub(crate) params: StmParameters,
 }
 
+#[cfg(feature = "std")]
 impl<D: Digest + Clone + FixedOutput> StmClerk<D> {
     /// Create a new `Clerk` from a closed registration instance.
     pub fn from_registration(params: &StmParameters, closed_reg: &ClosedKeyReg<D>) -> Self {
@@ -673,24 +734,133 @@ impl<D: Digest + Clone + FixedOutput> StmClerk<D> {
         &self,
         sigs: &[StmSig],
         msg: &[u8],
+    ) -> Result<StmAggrSig<D>, AggregationError> {
+        self.aggregate_with_strategy(sigs, msg, AggregationStrategy::default())
+    }
+
+    /// Aggregate a set of signatures, selecting the signatures to include with the given
+    /// strategy.
+    ///
+    /// It returns an instance of `StmAggrSig`.
+    pub fn aggregate_with_strategy(
+        &self,
+        sigs: &[StmSig],
+        msg: &[u8],
+        strategy: AggregationStrategy,
+    ) -> Result<StmAggrSig<D>, AggregationError> {
+        Self::aggregate_registered_signatures(&self.closed_reg, &self.params, sigs, msg, strategy)
+    }
+
+    /// Aggregate a set of signatures with the proof system `P`.
+    ///
+    /// `StmClerk::aggregate` is equivalent to aggregating a `StmAggrSig`, the concatenation proof.
+    pub fn aggregate_proof<P: AggregateProof<D>>(
+        &self,
+        sigs: &[StmSig],
+        msg: &[u8],
+    ) -> Result<P, AggregationError> {
+        P::aggregate(&self.closed_reg, &self.params, sigs, msg)
+    }
+
+    /// Create an aggregator of the signatures of the given message, that verifies and
+    /// deduplicates the signatures one at a time as they are received.
+    pub fn incremental_aggregator(&self, msg: &[u8]) -> StmIncrementalAggregator<D> {
+        let avk = StmAggrVerificationKey::from(&self.closed_reg);
+
+        StmIncrementalAggregator {
+            closed_reg: self.closed_reg.clone(),
+            params: self.params,
+            msgp: avk.mt_commitment.concat_with_msg(msg),
+            signatures: BTreeMap::new(),
+            signer_by_index: BTreeMap::new(),
+            rejected_signatures: BTreeMap::new(),
+        }
+    }
+
+    /// Build the diagnostic report of the aggregation of the given signatures.
+    ///
+    /// Each signature is verified, and for each index only the valid signature with the smallest
+    /// `sigma` is kept, as done by `StmClerk::aggregate`. A valid signature of a signer that
+    /// already submitted a valid signature is reported as already submitted, invalid signatures
+    /// do not count as submitted.
+    pub fn aggregation_report(&self, sigs: &[StmSig], msg: &[u8]) -> StmAggregationReport {
+        let avk = StmAggrVerificationKey::from(&self.closed_reg);
+        let msgp = avk.mt_commitment.concat_with_msg(msg);
+        let mut submitted_signers = HashSet::new();
+
+        let statuses = sigs.iter().map(|sig| {
+            let verification = match self.closed_reg.reg_parties.get(sig.signer_index as usize) {
+                Some(reg_party) => sig.verify_core(
+                    &self.params,
+                    &reg_party.0,
+                    &reg_party.1,
+                    &msgp,
+                    &self.closed_reg.total_stake,
+                ),
+                None => Err(StmSignatureError::UnregisteredSigner(sig.signer_index)),
+            };
+            match verification {
+                Ok(()) if !submitted_signers.insert(sig.signer_index) => {
+                    StmSignatureStatus::AlreadySubmitted
+                }
+                Ok(()) => StmSignatureStatus::Valid,
+                Err(error) => StmSignatureStatus::Invalid(error),
+            }
+        });
+
+        StmAggregationReport::from_signatures(
+            &self.closed_reg,
+            &self.params,
+            &msgp,
+            sigs.iter().zip(statuses.collect::<Vec<_>>()),
+        )
+    }
+
+    /// Aggregate a set of signatures of parties of the given registration with the concatenation
+    /// proof, selecting the signatures to include with the given strategy.
+    pub(crate) fn aggregate_registered_signatures(
+        closed_reg: &ClosedKeyReg<D>,
+        params: &StmParameters,
+        sigs: &[StmSig],
+        msg: &[u8],
+        strategy: AggregationStrategy,
     ) -> Result<StmAggrSig<D>, AggregationError> {
         let sig_reg_list = sigs
             .iter()
             .map(|sig| StmSigRegParty {
                 sig: sig.clone(),
-                reg_party: self.closed_reg.reg_parties[sig.signer_index as usize],
+                reg_party: closed_reg.reg_parties[sig.signer_index as usize],
             })
             .collect::<Vec<StmSigRegParty>>();
 
-        let avk = StmAggrVerificationKey::from(&self.closed_reg);
+        let avk = StmAggrVerificationKey::from(closed_reg);
         let msgp = avk.mt_commitment.concat_with_msg(msg);
-        let mut unique_sigs = CoreVerifier::dedup_sigs_for_indices(
-            &self.closed_reg.total_stake,
-            &self.params,
-            &msgp,
-            &sig_reg_list,
-        )?;
+        let unique_sigs = match strategy {
+            AggregationStrategy::IndexOrder => CoreVerifier::dedup_sigs_for_indices(
+                &closed_reg.total_stake,
+                params,
+                &msgp,
+                &sig_reg_list,
+            )?,
+            AggregationStrategy::MinimalSigners => {
+                CoreVerifier::select_minimal_signers_for_indices(
+                    &closed_reg.total_stake,
+                    params,
+                    &msgp,
+                    &sig_reg_list,
+                )?
+            }
+        };
+
+        Ok(Self::aggregate_unique_signatures(closed_reg, unique_sigs))
+    }
 
+    /// Build the aggregate signature of deduplicated signatures, with the batch proof of their
+    /// registration.
+    fn aggregate_unique_signatures(
+        closed_reg: &ClosedKeyReg<D>,
+        mut unique_sigs: Vec<StmSigRegParty>,
+    ) -> StmAggrSig<D> {
         unique_sigs.sort_unstable();
 
         let mt_index_list = unique_sigs
@@ -698,12 +868,12 @@ impl<D: Digest + Clone + FixedOutput> StmClerk<D> {
             .map(|sig_reg| sig_reg.sig.signer_index as usize)
             .collect::<Vec<usize>>();
 
-        let batch_proof = self.closed_reg.merkle_tree.get_batched_path(mt_index_list);
+        let batch_proof = closed_reg.merkle_tree.get_batched_path(mt_index_list);
 
-        Ok(StmAggrSig {
+        StmAggrSig {
             signatures: unique_sigs,
             batch_proof,
-        })
+        }
     }
 
     /// Compute the `StmAggrVerificationKey` related to the used registration.
@@ -720,7 +890,222 @@ impl<D: Digest + Clone + FixedOutput> StmClerk<D> {
     }
 }
 
+#[cfg(feature = "std")]
+/// A `StmSig` verified by a `StmIncrementalAggregator`, that can be added to the aggregation
+/// later on without being verified again.
+#[derive(Debug, Clone)]
+pub struct StmVerifiedSig {
+    sig: StmSig,
+    reg_party: RegParty,
+    msgp: Vec<u8>,
+}
+
+#[cfg(feature = "std")]
+impl StmVerifiedSig {
+    /// The verified signature.
+    pub fn sig(&self) -> &StmSig {
+        &self.sig
+    }
+}
+
+#[cfg(feature = "std")]
+/// `StmIncrementalAggregator` aggregates the `StmSig`s of a message as they are received.
+///
+/// Each signature is verified once, when it is added, and for each won index only the signature
+/// with the smallest `sigma` is kept, as done by `StmClerk::aggregate`. The aggregate signature
+/// can then be produced as soon as `k` unique indexes are covered, without verifying the
+/// signatures again.
+#[derive(Debug, Clone)]
+pub struct StmIncrementalAggregator<D: Clone + Digest> {
+    closed_reg: ClosedKeyReg<D>,
+    params: StmParameters,
+    msgp: Vec<u8>,
+    signatures: BTreeMap<Index, StmSigRegParty>,
+    signer_by_index: BTreeMap<Index, Index>,
+    rejected_signatures: BTreeMap<Index, (StmSig, StmSignatureError)>,
+}
+
+#[cfg(feature = "std")]
+impl<D: Digest + Clone + FixedOutput> StmIncrementalAggregator<D> {
+    /// Verify a signature and add it to the aggregation.
+    ///
+    /// A valid signature of a signer that already has a signature in the aggregation is ignored.
+    /// # Error
+    /// The function fails if the signer is not registered or if the signature is invalid.
+    pub fn add_signature(&mut self, sig: &StmSig) -> Result<(), StmSignatureError> {
+        let verified_sig = self.verify_signature(sig)?;
+
+        self.add_verified_signature(verified_sig)
+    }
+
+    /// Verify a signature without adding it to the aggregation, it can be added afterward with
+    /// `add_verified_signature`.
+    ///
+    /// An invalid signature is kept for the aggregation report, unless its signer already has a
+    /// valid signature in the aggregation.
+    /// # Error
+    /// The function fails if the signer is not registered or if the signature is invalid.
+    pub fn verify_signature(&mut self, sig: &StmSig) -> Result<StmVerifiedSig, StmSignatureError> {
+        match self.verify_core_signature(sig) {
+            Ok(reg_party) => Ok(StmVerifiedSig {
+                sig: sig.clone(),
+                reg_party,
+                msgp: self.msgp.clone(),
+            }),
+            Err(error) => {
+                if !self.signatures.contains_key(&sig.signer_index) {
+                    self.rejected_signatures
+                        .insert(sig.signer_index, (sig.clone(), error.clone()));
+                }
+                Err(error)
+            }
+        }
+    }
+
+    /// Add a signature verified by `verify_signature` to the aggregation.
+    ///
+    /// A signature of a signer that already has a signature in the aggregation is ignored.
+    /// # Error
+    /// The function fails if the signature was verified by an aggregator of another message or
+    /// registration.
+    pub fn add_verified_signature(
+        &mut self,
+        verified_sig: StmVerifiedSig,
+    ) -> Result<(), StmSignatureError> {
+        if verified_sig.msgp != self.msgp {
+            return Err(StmSignatureError::VerifiedForAnotherMessage);
+        }
+        let StmVerifiedSig { sig, reg_party, .. } = verified_sig;
+        if self.signatures.contains_key(&sig.signer_index) {
+            return Ok(());
+        }
+        self.rejected_signatures.remove(&sig.signer_index);
+
+        for index in &sig.indexes {
+            let is_best_signature = match self.signer_by_index.get(index) {
+                Some(previous_signer) => sig.sigma < self.signatures[previous_signer].sig.sigma,
+                None => true,
+            };
+            if is_best_signature {
+                self.signer_by_index.insert(*index, sig.signer_index);
+            }
+        }
+        self.signatures
+            .insert(sig.signer_index, StmSigRegParty { sig, reg_party });
+
+        Ok(())
+    }
+
+    fn verify_core_signature(&self, sig: &StmSig) -> Result<RegParty, StmSignatureError> {
+        let reg_party = *self
+            .closed_reg
+            .reg_parties
+            .get(sig.signer_index as usize)
+            .ok_or(StmSignatureError::UnregisteredSigner(sig.signer_index))?;
+        sig.verify_core(
+            &self.params,
+            &reg_party.0,
+            &reg_party.1,
+            &self.msgp,
+            &self.closed_reg.total_stake,
+        )?;
+
+        Ok(reg_party)
+    }
+
+    /// Build the diagnostic report of the aggregation, without verifying the signatures again.
+    ///
+    /// The report contains the added signatures, ordered by signer index, followed by the last
+    /// rejected signature of each signer that has no valid signature.
+    pub fn aggregation_report(&self) -> StmAggregationReport {
+        let valid_signatures = self
+            .signatures
+            .values()
+            .map(|sig_reg| (&sig_reg.sig, StmSignatureStatus::Valid));
+        let rejected_signatures = self
+            .rejected_signatures
+            .values()
+            .map(|(sig, error)| (sig, StmSignatureStatus::Invalid(error.clone())));
+
+        StmAggregationReport::from_signatures(
+            &self.closed_reg,
+            &self.params,
+            &self.msgp,
+            valid_signatures.chain(rejected_signatures),
+        )
+    }
+
+    /// Number of unique indexes covered by the added signatures.
+    pub fn unique_indexes_count(&self) -> u64 {
+        self.signer_by_index.len() as u64
+    }
+
+    /// Check if the added signatures cover at least `k` unique indexes.
+    pub fn is_quorum_reached(&self) -> bool {
+        self.unique_indexes_count() >= self.params.k
+    }
+
+    /// Aggregate the added signatures, without verifying them again.
+    ///
+    /// It returns an instance of `StmAggrSig`.
+    pub fn aggregate(&self) -> Result<StmAggrSig<D>, AggregationError> {
+        self.aggregate_with_strategy(AggregationStrategy::default())
+    }
+
+    /// Aggregate the added signatures, without verifying them again, selecting the signatures to
+    /// include with the given strategy.
+    ///
+    /// It returns an instance of `StmAggrSig`.
+    pub fn aggregate_with_strategy(
+        &self,
+        strategy: AggregationStrategy,
+    ) -> Result<StmAggrSig<D>, AggregationError> {
+        match strategy {
+            AggregationStrategy::IndexOrder => self.aggregate_in_index_order(),
+            AggregationStrategy::MinimalSigners => {
+                let sigs = self.signatures.values().cloned().collect::<Vec<_>>();
+                let unique_sigs = CoreVerifier::select_minimal_signers(&self.params, &sigs)?;
+
+                Ok(StmClerk::aggregate_unique_signatures(
+                    &self.closed_reg,
+                    unique_sigs,
+                ))
+            }
+        }
+    }
+
+    fn aggregate_in_index_order(&self) -> Result<StmAggrSig<D>, AggregationError> {
+        let mut unique_sigs = Vec::new();
+        let mut aggregated_signers = HashSet::new();
+        let mut count: u64 = 0;
+
+        for signer_index in self.signer_by_index.values() {
+            if !aggregated_signers.insert(*signer_index) {
+                continue;
+            }
+
+            let mut deduped_sig = self.signatures[signer_index].clone();
+            deduped_sig
+                .sig
+                .indexes
+                .retain(|index| self.signer_by_index.get(index) == Some(signer_index));
+            count += deduped_sig.sig.indexes.len() as u64;
+            unique_sigs.push(deduped_sig);
+
+            if count >= self.params.k {
+                return Ok(StmClerk::aggregate_unique_signatures(
+                    &self.closed_reg,
+                    unique_sigs,
+                ));
+            }
+        }
+
+        Err(AggregationError::NotEnoughSignatures(count, self.params.k))
+    }
+}
+
 /// `StmMultiSig` uses the "concatenation" proving system (as described in Section 4.3 of the original paper.)
+/// It is the `AggregateProofType::Concatenation` implementation of `AggregateProof`.
 /// This means that the aggregated signature contains a vector with all individual signatures.
 /// BatchPath is also a part of the aggregate signature which covers path for all signatures.
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -898,12 +1283,12 @@ pub struct CoreVerifier {
 
 impl CoreVerifier {
     /// Setup a core verifier for given list of signers.
-    ///     * Collect the unique signers in a hash set,
+    ///     * Collect the unique signers in a set,
     ///     * Calculate the total stake of the eligible signers,
     ///     * Sort the eligible signers.
     pub fn setup(public_signers: &[(VerificationKey, Stake)]) -> Self {
         let mut total_stake: Stake = 0;
-        let mut unique_parties = HashSet::new();
+        let mut unique_parties = BTreeSet::new();
         for signer in public_signers.iter() {
             let (res, overflow) = total_stake.overflowing_add(signer.1);
             if overflow {
@@ -929,7 +1314,7 @@ impl CoreVerifier {
         msg: &[u8],
     ) -> Result<(), CoreVerifierError> {
         let mut nr_indices = 0;
-        let mut unique_indices = HashSet::new();
+        let mut unique_indices = BTreeSet::new();
 
         for sig_reg in signatures {
             sig_reg
@@ -951,6 +1336,7 @@ impl CoreVerifier {
         Ok(())
     }
 
+    #[cfg(feature = "std")]
     /// Given a slice of `sig_reg_list`, this function returns a new list of `sig_reg_list` with only valid indices.
     /// In case of conflict (having several signatures for the same index)
     /// it selects the smallest signature (i.e. takes the signature with the smallest scalar).
@@ -1042,6 +1428,103 @@ impl CoreVerifier {
         Err(AggregationError::NotEnoughSignatures(count, params.k))
     }
 
+    #[cfg(feature = "std")]
+    /// Given a slice of `sig_reg_list`, this function returns a list of `sig_reg_list` with as few
+    /// signatures as possible covering exactly `self.k` unique indexes, keeping only valid
+    /// signatures.
+    ///  # Error
+    /// If there is no sufficient signatures, then the function fails.
+    pub fn select_minimal_signers_for_indices(
+        total_stake: &Stake,
+        params: &StmParameters,
+        msg: &[u8],
+        sigs: &[StmSigRegParty],
+    ) -> Result<Vec<StmSigRegParty>, AggregationError> {
+        let valid_sigs = sigs
+            .iter()
+            .filter(|sig_reg| {
+                sig_reg
+                    .sig
+                    .verify_core(
+                        params,
+                        &sig_reg.reg_party.0,
+                        &sig_reg.reg_party.1,
+                        msg,
+                        total_stake,
+                    )
+                    .is_ok()
+            })
+            .cloned()
+            .collect::<Vec<_>>();
+
+        Self::select_minimal_signers(params, &valid_sigs)
+    }
+
+    #[cfg(feature = "std")]
+    /// Select, among valid signatures, the fewest signatures covering exactly `k` unique indexes.
+    ///
+    /// Finding the smallest cover is NP-hard, so the signatures are selected greedily: the
+    /// signature winning the most indexes not yet covered is selected first, and only the
+    /// indexes it is the first to cover are kept.
+    // `Option::is_none_or` is not used, it would require Rust 1.82
+    #[allow(clippy::unnecessary_map_or)]
+    pub(crate) fn select_minimal_signers(
+        params: &StmParameters,
+        valid_sigs: &[StmSigRegParty],
+    ) -> Result<Vec<StmSigRegParty>, AggregationError> {
+        let mut covered_indexes: HashSet<Index> = HashSet::new();
+        // Sort the signatures to break the ties the same way whatever the order they were given in
+        let mut remaining_sigs = valid_sigs.iter().collect::<Vec<_>>();
+        remaining_sigs.sort_by_key(|sig_reg| sig_reg.sig.signer_index);
+        let mut selected_sigs = Vec::new();
+
+        while (covered_indexes.len() as u64) < params.k {
+            let uncovered_indexes = |sig_reg: &StmSigRegParty| {
+                let mut seen_indexes = HashSet::new();
+                sig_reg
+                    .sig
+                    .indexes
+                    .iter()
+                    .filter(|&&index| {
+                        !covered_indexes.contains(&index) && seen_indexes.insert(index)
+                    })
+                    .copied()
+                    .collect::<Vec<Index>>()
+            };
+
+            let mut best: Option<(usize, Vec<Index>)> = None;
+            for (position, sig_reg) in remaining_sigs.iter().enumerate() {
+                let indexes = uncovered_indexes(sig_reg);
+                if best
+                    .as_ref()
+                    .map_or(true, |(_, best_indexes)| indexes.len() > best_indexes.len())
+                {
+                    best = Some((position, indexes));
+                }
+            }
+
+            match best {
+                Some((position, mut indexes)) if !indexes.is_empty() => {
+                    let missing_indexes = params.k - covered_indexes.len() as u64;
+                    indexes.truncate(missing_indexes.try_into().unwrap_or(usize::MAX));
+                    covered_indexes.extend(indexes.iter().copied());
+
+                    let mut selected_sig = remaining_sigs.remove(position).clone();
+                    selected_sig.sig.indexes = indexes;
+                    selected_sigs.push(selected_sig);
+                }
+                _ => {
+                    return Err(AggregationError::NotEnoughSignatures(
+                        covered_indexes.len() as u64,
+                        params.k,
+                    ));
+                }
+            }
+        }
+
+        Ok(selected_sigs)
+    }
+
     /// Collect and return `Vec<Signature>, Vec<VerificationKey>` which will be used
     /// by the aggregate verification.
     fn collect_sigs_vks(sig_reg_list: &[StmSigRegParty]) -> (Vec<Signature>, Vec<VerificationKey>) {
@@ -1057,6 +1540,7 @@ impl CoreVerifier {
         (sigs, vks)
     }
 
+    #[cfg(feature = "std")]
     /// Core verification
     ///
     /// Verify a list of signatures with respect to given message with given parameters.
@@ -1087,7 +1571,7 @@ impl CoreVerifier {
     }
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "std"))]
 mod tests {
     use super::*;
     use crate::key_reg::*;
@@ -1333,6 +1817,148 @@ mod tests {
             }
         }
 
+        #[test]
+        /// Test that the aggregate signature of the minimal signers strategy is verifiable, covers
+        /// exactly `k` indexes and is the same when aggregated incrementally.
+        fn test_aggregate_sig_minimal_signers(nparties in 2_usize..30,
+                              m in 10_u64..20,
+                              k in 1_u64..5,
+                              msg in any::<[u8;16]>()) {
+            let params = StmParameters { m, k, phi_f: 0.2 };
+            let ps = setup_equal_parties(params, nparties);
+            let clerk = StmClerk::from_signer(&ps[0]);
+
+            let all_ps: Vec<usize> = (0..nparties).collect();
+            let sigs = find_signatures(&msg, &ps, &all_ps);
+            let mut aggregator = clerk.incremental_aggregator(&msg);
+            for sig in &sigs {
+                aggregator.add_signature(sig).unwrap();
+            }
+
+            match (
+                clerk.aggregate(&sigs, &msg),
+                clerk.aggregate_with_strategy(&sigs, &msg, AggregationStrategy::MinimalSigners),
+            ) {
+                (Ok(_), Ok(aggr)) => {
+                    let verify_result = aggr.verify(&msg, &clerk.compute_avk(), &params);
+                    assert!(verify_result.is_ok(), "Verification failed: {verify_result:?}");
+                    let covered_indexes = aggr
+                        .signatures
+                        .iter()
+                        .map(|sig_reg| sig_reg.sig.indexes.len() as u64)
+                        .sum::<u64>();
+                    assert_eq!(params.k, covered_indexes);
+
+                    let incremental_aggr = aggregator
+                        .aggregate_with_strategy(AggregationStrategy::MinimalSigners)
+                        .unwrap();
+                    assert_eq!(aggr.to_bytes(), incremental_aggr.to_bytes());
+                }
+                (Err(expected), Err(error)) =>
+                    assert_eq!(expected.to_string(), error.to_string()),
+                (expected, result) => panic!("Expected {expected:?}, got {result:?}"),
+            }
+        }
+
+        #[test]
+        /// Test that the incremental aggregation produces the same aggregate signature as the
+        /// aggregation of all the signatures at once.
+        fn test_incremental_aggregate_sig(nparties in 2_usize..30,
+                              m in 10_u64..20,
+                              k in 1_u64..5,
+                              msg in any::<[u8;16]>()) {
+            let params = StmParameters { m, k, phi_f: 0.2 };
+            let ps = setup_equal_parties(params, nparties);
+            let clerk = StmClerk::from_signer(&ps[0]);
+
+            let all_ps: Vec<usize> = (0..nparties).collect();
+            let sigs = find_signatures(&msg, &ps, &all_ps);
+            let mut aggregator = clerk.incremental_aggregator(&msg);
+            for sig in &sigs {
+                aggregator.add_signature(sig).unwrap();
+            }
+
+            match (clerk.aggregate(&sigs, &msg), aggregator.aggregate()) {
+                (Ok(expected), Ok(aggr)) => {
+                    assert!(aggregator.is_quorum_reached());
+                    assert_eq!(expected.to_bytes(), aggr.to_bytes());
+                    assert!(aggr.verify(&msg, &clerk.compute_avk(), &params).is_ok());
+                }
+                (Err(expected), Err(error)) => {
+                    assert!(!aggregator.is_quorum_reached());
+                    assert_eq!(expected.to_string(), error.to_string());
+                }
+                (expected, result) => panic!("Expected {expected:?}, got {result:?}"),
+            }
+        }
+
+        #[test]
+        /// Test that the incremental aggregation rejects invalid signatures.
+        fn test_incremental_aggregate_rejects_invalid_sig(msg in any::<[u8;16]>()) {
+            let params = StmParameters { m: 10, k: 1, phi_f: 1.0 };
+            let ps = setup_equal_parties(params, 2);
+            let clerk = StmClerk::from_signer(&ps[0]);
+            let mut aggregator = clerk.incremental_aggregator(&msg);
+
+            let sig_of_other_msg = ps[0].sign(&[1u8; 20]).unwrap();
+            assert!(aggregator.add_signature(&sig_of_other_msg).is_err());
+
+            let mut sig_of_unregistered_signer = ps[0].sign(&msg).unwrap();
+            sig_of_unregistered_signer.signer_index = 2;
+            assert!(matches!(
+                aggregator.add_signature(&sig_of_unregistered_signer),
+                Err(StmSignatureError::UnregisteredSigner(2))
+            ));
+
+            assert_eq!(0, aggregator.unique_indexes_count());
+            assert!(aggregator.aggregate().is_err());
+        }
+
+        #[test]
+        /// Test that the incremental aggregation verifies the signatures of a signer that already
+        /// has a signature in the aggregation.
+        fn test_incremental_aggregate_verifies_resubmitted_sig(msg in any::<[u8;16]>()) {
+            let params = StmParameters { m: 10, k: 1, phi_f: 1.0 };
+            let ps = setup_equal_parties(params, 2);
+            let clerk = StmClerk::from_signer(&ps[0]);
+            let mut aggregator = clerk.incremental_aggregator(&msg);
+
+            let sig = ps[0].sign(&msg).unwrap();
+            aggregator.add_signature(&sig).unwrap();
+            let unique_indexes_count = aggregator.unique_indexes_count();
+
+            let sig_of_other_msg = ps[0].sign(&[1u8; 20]).unwrap();
+            assert!(aggregator.add_signature(&sig_of_other_msg).is_err());
+            aggregator.add_signature(&sig).unwrap();
+
+            assert_eq!(unique_indexes_count, aggregator.unique_indexes_count());
+            assert_eq!(0, aggregator.aggregation_report().rejected_signatures_count());
+        }
+
+        #[test]
+        /// Test that a verified signature is only aggregated once added, and only to an
+        /// aggregator of the message it was verified for.
+        fn test_incremental_aggregate_adds_verified_sig(msg in any::<[u8;16]>()) {
+            let params = StmParameters { m: 10, k: 1, phi_f: 1.0 };
+            let ps = setup_equal_parties(params, 2);
+            let clerk = StmClerk::from_signer(&ps[0]);
+            let mut aggregator = clerk.incremental_aggregator(&msg);
+            let mut aggregator_of_other_msg = clerk.incremental_aggregator(&[1u8; 20]);
+
+            let sig = ps[0].sign(&msg).unwrap();
+            let verified_sig = aggregator.verify_signature(&sig).unwrap();
+            assert_eq!(0, aggregator.unique_indexes_count());
+
+            assert!(matches!(
+                aggregator_of_other_msg.add_verified_signature(verified_sig.clone()),
+                Err(StmSignatureError::VerifiedForAnotherMessage)
+            ));
+            aggregator.add_verified_signature(verified_sig).unwrap();
+
+            assert_eq!(sig.indexes.len() as u64, aggregator.unique_indexes_count());
+            assert_eq!(0, aggregator_of_other_msg.unique_indexes_count());
+        }
+
         #[test]
         /// Test that batch verification of certificates works
         fn batch_verify(nparties in 2_usize..30,
@@ -0,0 +1,221 @@
+//! Golden vectors of the legacy layouts and of the versioned encodings of the STM types.
+//!
+//! The vectors are stored in `tests/golden_vectors` and must never change once released: they
+//! ensure that the values encoded by a previous version, such as stored certificates, can still be
+//! decoded. When a new format version is introduced, add its vectors next to the existing ones.
+//!
+//! The aggregate signatures verification vectors, used to check the `no_std` build, are also
+//! generated here.
+//!
+//! Missing vector files can be generated by running the tests with the
+//! `MITHRIL_STM_GENERATE_GOLDEN_VECTORS` environment variable set.
+#![cfg(feature = "std")]
+
+use std::path::PathBuf;
+
+use blake2::Blake2b;
+use digest::consts::U32;
+use mithril_stm::encoding::StmVersionedEncoding;
+use mithril_stm::key_reg::KeyReg;
+use mithril_stm::stm::{Stake, StmAggrSig, StmClerk, StmInitializer, StmParameters, StmSig};
+use rand_chacha::ChaCha20Rng;
+use rand_core::SeedableRng;
+use serde_json::json;
+
+type D = Blake2b<U32>;
+
+struct Fixture {
+    initializer: StmInitializer,
+    signature: StmSig,
+    aggregate_signature: StmAggrSig<D>,
+}
+
+/// Build the encoded values from a seeded rng.
+///
+/// With `phi_f = 1`, every signer wins every lottery, so the values don't depend on the
+/// arithmetic backend used to evaluate the lottery.
+fn fixture() -> Fixture {
+    let mut rng = ChaCha20Rng::from_seed([0u8; 32]);
+    let msg = [7u8; 16];
+    let params = StmParameters {
+        m: 10,
+        k: 3,
+        phi_f: 1.0,
+    };
+
+    let mut key_reg = KeyReg::init();
+    let initializers = [1, 2, 3]
+        .into_iter()
+        .map(|stake| {
+            let initializer = StmInitializer::setup(params, stake, &mut rng);
+            key_reg
+                .register(stake, initializer.verification_key())
+                .unwrap();
+            initializer
+        })
+        .collect::<Vec<_>>();
+    let closed_reg = key_reg.close::<D>();
+    let signers = initializers
+        .iter()
+        .map(|initializer| initializer.clone().new_signer(closed_reg.clone()).unwrap())
+        .collect::<Vec<_>>();
+    let signatures = signers
+        .iter()
+        .map(|signer| signer.sign(&msg).unwrap())
+        .collect::<Vec<_>>();
+    let aggregate_signature = StmClerk::from_signer(&signers[0])
+        .aggregate(&signatures, &msg)
+        .unwrap();
+
+    Fixture {
+        initializer: initializers[0].clone(),
+        signature: signatures[0].clone(),
+        aggregate_signature,
+    }
+}
+
+/// Build a verification vector: a message, the parameters, the aggregate verification key and
+/// the aggregate signature of the message, both serialized as JSON like in the certificates.
+fn verification_vector(params: StmParameters, stakes: &[Stake], msg: &[u8]) -> serde_json::Value {
+    let mut rng = ChaCha20Rng::from_seed([0u8; 32]);
+    let mut key_reg = KeyReg::init();
+    let initializers = stakes
+        .iter()
+        .map(|&stake| {
+            let initializer = StmInitializer::setup(params, stake, &mut rng);
+            key_reg
+                .register(stake, initializer.verification_key())
+                .unwrap();
+            initializer
+        })
+        .collect::<Vec<_>>();
+    let closed_reg = key_reg.close::<D>();
+    let signers = initializers
+        .into_iter()
+        .map(|initializer| initializer.new_signer(closed_reg.clone()).unwrap())
+        .collect::<Vec<_>>();
+    let signatures = signers
+        .iter()
+        .filter_map(|signer| signer.sign(msg))
+        .collect::<Vec<_>>();
+    let clerk = StmClerk::from_signer(&signers[0]);
+    let aggregate_signature = clerk.aggregate(&signatures, msg).unwrap();
+
+    json!({
+        "message": hex::encode(msg),
+        "parameters": params,
+        "aggregate_verification_key": clerk.compute_avk(),
+        "aggregate_signature": aggregate_signature,
+    })
+}
+
+fn golden_vector_path(file_name: &str) -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("tests")
+        .join("golden_vectors")
+        .join(file_name)
+}
+
+/// Read a golden vector file, generating it with the given content if it's missing and the
+/// generation is enabled.
+fn read_golden_vector_file(file_name: &str, content: &str) -> String {
+    let path = golden_vector_path(file_name);
+    if !path.exists() && std::env::var("MITHRIL_STM_GENERATE_GOLDEN_VECTORS").is_ok() {
+        std::fs::write(&path, format!("{content}\n")).unwrap();
+    }
+
+    std::fs::read_to_string(&path)
+        .unwrap_or_else(|e| panic!("Could not read golden vector '{}': {e}", path.display()))
+}
+
+fn golden_vector(name: &str, bytes: &[u8]) -> Vec<u8> {
+    let content = read_golden_vector_file(&format!("{name}.hex"), &hex::encode(bytes));
+    hex::decode(content.trim()).unwrap()
+}
+
+/// Check that the value is encoded as the golden vectors, and that the golden vectors decode to
+/// the same value.
+fn assert_golden_vectors<T: StmVersionedEncoding>(name: &str, value: &T) {
+    let legacy_bytes = golden_vector(&format!("{name}.legacy"), &value.to_legacy_bytes());
+    let versioned_bytes = golden_vector(&format!("{name}.v1"), &value.to_versioned_bytes());
+
+    assert_eq!(legacy_bytes, value.to_legacy_bytes());
+    assert_eq!(versioned_bytes, value.to_versioned_bytes());
+
+    for bytes in [&legacy_bytes, &versioned_bytes] {
+        let decoded = T::from_versioned_bytes(bytes).unwrap();
+        assert_eq!(legacy_bytes, decoded.to_legacy_bytes());
+    }
+}
+
+#[test]
+fn initializer_golden_vectors() {
+    assert_golden_vectors("stm_initializer", &fixture().initializer);
+}
+
+#[test]
+fn signature_golden_vectors() {
+    assert_golden_vectors("stm_sig", &fixture().signature);
+}
+
+#[test]
+fn aggregate_signature_golden_vectors() {
+    assert_golden_vectors("stm_aggr_sig", &fixture().aggregate_signature);
+}
+
+#[test]
+fn batch_path_golden_vectors() {
+    assert_golden_vectors("batch_path", &fixture().aggregate_signature.batch_proof);
+}
+
+#[test]
+fn path_golden_vectors() {
+    let mut rng = ChaCha20Rng::from_seed([0u8; 32]);
+    let params = StmParameters {
+        m: 10,
+        k: 3,
+        phi_f: 1.0,
+    };
+    let mut key_reg = KeyReg::init();
+    for stake in [1, 2, 3] {
+        let initializer = StmInitializer::setup(params, stake, &mut rng);
+        key_reg
+            .register(stake, initializer.verification_key())
+            .unwrap();
+    }
+    let closed_reg = key_reg.close::<D>();
+
+    assert_golden_vectors("path", &closed_reg.merkle_tree.get_path(1));
+}
+
+#[test]
+fn aggregate_signature_verification_golden_vectors() {
+    let vectors = json!([
+        verification_vector(
+            StmParameters {
+                m: 10,
+                k: 3,
+                phi_f: 1.0,
+            },
+            &[1, 2, 3],
+            &[7u8; 16],
+        ),
+        verification_vector(
+            StmParameters {
+                m: 100,
+                k: 10,
+                phi_f: 0.2,
+            },
+            &[10, 20, 30, 40, 50, 60, 70, 80, 90, 100],
+            &[42u8; 32],
+        ),
+    ]);
+
+    let content = read_golden_vector_file(
+        "aggregate_signature_verification.json",
+        &serde_json::to_string_pretty(&vectors).unwrap(),
+    );
+    let golden_vectors: serde_json::Value = serde_json::from_str(&content).unwrap();
+
+    assert_eq!(golden_vectors, vectors);
+}
@@ -1,3 +1,5 @@
+#![cfg(feature = "std")]
+
 use blake2::Blake2b;
 use digest::consts::U32;
 use mithril_stm::stm::{
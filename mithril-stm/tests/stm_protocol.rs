@@ -1,3 +1,5 @@
+#![cfg(feature = "std")]
+
 use mithril_stm::key_reg::KeyReg;
 use mithril_stm::stm::{
     Stake, StmAggrSig, StmAggrVerificationKey, StmClerk, StmInitializer, StmParameters, StmSig,
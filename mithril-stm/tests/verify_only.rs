@@ -0,0 +1,87 @@
+//! Verification of the aggregate signatures golden vectors.
+//!
+//! This test only uses the API available without the `std` feature, it's run against both
+//! the default and the `no_std` builds:
+//! `cargo test --no-default-features --features num-integer-backend --test verify_only`.
+
+use blake2::Blake2b;
+use digest::consts::U32;
+use mithril_stm::aggregate_proof::AggregateSignature;
+use mithril_stm::stm::{StmAggrSig, StmAggrVerificationKey, StmParameters};
+
+type D = Blake2b<U32>;
+
+struct VerificationVector {
+    message: Vec<u8>,
+    parameters: StmParameters,
+    avk: StmAggrVerificationKey<D>,
+    aggregate_signature: StmAggrSig<D>,
+}
+
+fn verification_vectors() -> Vec<VerificationVector> {
+    let content = include_str!("golden_vectors/aggregate_signature_verification.json");
+    let vectors: Vec<serde_json::Value> = serde_json::from_str(content).unwrap();
+
+    vectors
+        .into_iter()
+        .map(|vector| VerificationVector {
+            message: hex::decode(vector["message"].as_str().unwrap()).unwrap(),
+            parameters: serde_json::from_value(vector["parameters"].clone()).unwrap(),
+            avk: serde_json::from_value(vector["aggregate_verification_key"].clone()).unwrap(),
+            aggregate_signature: serde_json::from_value(vector["aggregate_signature"].clone())
+                .unwrap(),
+        })
+        .collect()
+}
+
+#[test]
+fn verify_aggregate_signature_golden_vectors() {
+    for vector in verification_vectors() {
+        vector
+            .aggregate_signature
+            .verify(&vector.message, &vector.avk, &vector.parameters)
+            .expect("golden vector aggregate signature should verify");
+    }
+}
+
+#[test]
+fn verify_aggregate_signature_golden_vectors_as_aggregate_signatures() {
+    for vector in verification_vectors() {
+        let aggregate_signature = AggregateSignature::from(vector.aggregate_signature);
+        let json = serde_json::to_string(&aggregate_signature).unwrap();
+        let decoded: AggregateSignature<D> =
+            serde_json::from_str(&json).expect("aggregate signature should be deserialized");
+
+        decoded
+            .verify(&vector.message, &vector.avk, &vector.parameters)
+            .expect("golden vector aggregate signature should verify");
+    }
+}
+
+#[test]
+fn verify_aggregate_signature_golden_vectors_fails_for_another_message() {
+    for vector in verification_vectors() {
+        let mut message = vector.message.clone();
+        message[0] ^= 1;
+
+        vector
+            .aggregate_signature
+            .verify(&message, &vector.avk, &vector.parameters)
+            .expect_err("aggregate signature of another message should not verify");
+    }
+}
+
+#[test]
+fn verify_aggregate_signature_golden_vectors_fails_with_other_parameters() {
+    for vector in verification_vectors() {
+        let parameters = StmParameters {
+            k: vector.parameters.k + 100,
+            ..vector.parameters
+        };
+
+        vector
+            .aggregate_signature
+            .verify(&vector.message, &vector.avk, &parameters)
+            .expect_err("aggregate signature should not verify without the quorum");
+    }
+}